@@ -103,6 +103,12 @@ pub fn apply_extraction_overrides(
                 language: "eng".to_string(),
                 tesseract_config: None,
                 output_format: None,
+                dpi: None,
+                psm: None,
+                oem: None,
+                binarization: None,
+                ocr_pages: None,
+                language_fallback: None,
             });
         } else {
             config.ocr = None;
@@ -70,8 +70,12 @@ fn create_test_result(content_size: usize, chunk_count: usize) -> ExtractionResu
         detected_languages: Some(vec!["en".to_string(), "de".to_string()]),
         chunks,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
         elements: None,
+        layout: None,
         djot_content: None,
     }
 }
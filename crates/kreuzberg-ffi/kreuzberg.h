@@ -25,6 +25,15 @@ typedef struct ExtractionConfig ExtractionConfig;
 typedef struct ExtractionResult ExtractionResult;
 
 
+/**
+ * ABI version of the C interface.
+ *
+ * Incremented whenever the layout of any `#[repr(C)]` struct crossing the FFI
+ * boundary changes. Callers should compare this header constant with
+ * `kreuzberg_abi_version()` at startup and refuse to run on a mismatch.
+ */
+#define KREUZBERG_ABI_VERSION 3
+
 /**
  * Opaque builder struct for constructing ExtractionConfig.
  *
@@ -33,6 +42,18 @@ typedef struct ExtractionResult ExtractionResult;
  */
 typedef struct ConfigBuilder ConfigBuilder;
 
+/**
+ * Long-lived extractor holding a configuration.
+ *
+ * # Thread Safety
+ *
+ * The handle is immutable after construction. Any number of threads may call
+ * `kreuzberg_extractor_extract_file` on the same handle concurrently without
+ * external locking. `kreuzberg_extractor_free` must not run concurrently with
+ * any other call on the same handle.
+ */
+typedef struct KreuzbergExtractor KreuzbergExtractor;
+
 typedef struct Option_ErrorCallback Option_ErrorCallback;
 
 /**
@@ -173,6 +194,32 @@ typedef int (*ResultCallback)(const struct CExtractionResultView *result,
                               uintptr_t file_index,
                               void *user_data);
 
+/**
+ * Snapshot of the process-level string caches.
+ */
+typedef struct CCacheStats {
+  /**
+   * Strings held by the MIME type intern pool
+   */
+  uintptr_t mime_entries;
+  /**
+   * Strings held by the language code intern pool
+   */
+  uintptr_t language_entries;
+  /**
+   * Total capacity in bytes of idle buffers in the string buffer pool
+   */
+  uintptr_t buffer_pool_bytes;
+  /**
+   * Buffer acquisitions served from the pool (0 unless built with `pool-metrics`)
+   */
+  uintptr_t buffer_pool_hits;
+  /**
+   * Buffer acquisitions that allocated a new buffer (0 unless built with `pool-metrics`)
+   */
+  uintptr_t buffer_pool_misses;
+} CCacheStats;
+
 /**
  * C-compatible structured error details returned by `kreuzberg_get_error_details()`.
  *
@@ -223,7 +270,9 @@ typedef struct CErrorDetails {
  * # Memory Layout
  *
  * Must be kept in sync with the Java side's MemoryLayout definition in KreuzbergFFI.java
- * Field order: 13 pointers (8 bytes each) + 1 bool + 7 bytes padding = 112 bytes total
+ * Field order: 13 pointers (8 bytes each) + 1 bool + 7 bytes padding + 1 pointer = 120 bytes total
+ *
+ * `links_json` follows the original 112-byte prefix, so readers of the prefix keep working.
  *
  * The `#[repr(C)]` attribute ensures the struct follows C's memory layout rules:
  * - Fields are laid out in order
@@ -296,6 +345,10 @@ typedef struct CExtractionResult {
    * Padding to match Java MemoryLayout (7 bytes padding to align to 8-byte boundary)
    */
   uint8_t _padding1[7];
+  /**
+   * Hyperlinks and image references as JSON array (null-terminated string, or NULL if none, must be freed with kreuzberg_free_string)
+   */
+  char *links_json;
 } CExtractionResult;
 
 /**
@@ -304,14 +357,17 @@ typedef struct CExtractionResult {
  * # Memory Layout
  *
  * Must be kept in sync with the Java side's MemoryLayout definition in KreuzbergFFI.java
- * Field order: 1 pointer (8 bytes) + 1 usize (8 bytes) + 1 bool + 7 bytes padding = 24 bytes total
+ * Field order: 1 pointer (8 bytes) + 1 usize (8 bytes) + 1 bool + 7 bytes padding
+ * + 2 usize (16 bytes) + 1 pointer (8 bytes) = 48 bytes total
  *
- * The padding ensures the struct is properly aligned for 64-bit architectures.
+ * The padding ensures the struct is properly aligned for 64-bit architectures. The summary
+ * fields follow the original 24-byte prefix, so readers of the prefix keep working.
  *
  * # Memory Management
  *
  * - The `results` array must be freed using `kreuzberg_free_batch_result`
  * - Each individual result in the array must also be freed
+ * - `errors` is owned by the batch and freed with it
  */
 typedef struct CBatchResult {
   /**
@@ -330,6 +386,19 @@ typedef struct CBatchResult {
    * Padding to match Java MemoryLayout (7 bytes padding to align to 8-byte boundary)
    */
   uint8_t _padding2[7];
+  /**
+   * Number of results whose `success` flag is set
+   */
+  uintptr_t success_count;
+  /**
+   * Number of results whose `success` flag is cleared (the item failed to extract)
+   */
+  uintptr_t failure_count;
+  /**
+   * Newline-joined `<item>: <error>` lines for failed items, or NULL if none failed.
+   * Owned by the batch; use `kreuzberg_batch_result_errors` for a copy that outlives it.
+   */
+  char *errors;
 } CBatchResult;
 
 /**
@@ -649,6 +718,23 @@ int kreuzberg_extract_batch_parallel(const char *const *files,
                                      struct Option_ErrorCallback error_callback,
                                      uintptr_t max_parallel);
 
+/**
+ * Fill `out` with the current process-level cache statistics.
+ *
+ * # Arguments
+ *
+ * * `out` - Non-null pointer to a caller-allocated `CCacheStats`
+ *
+ * # Returns
+ *
+ * 0 on success, -1 on error (NULL `out`)
+ *
+ * # Safety
+ *
+ * - `out` must be a valid, properly aligned pointer to writable `CCacheStats` memory
+ */
+int32_t kreuzberg_cache_stats(struct CCacheStats *out);
+
 /**
  * Parse an ExtractionConfig from a JSON string.
  *
@@ -698,6 +784,25 @@ void kreuzberg_config_free(ExtractionConfig *config);
  */
 int32_t kreuzberg_config_is_valid(const char *json_config);
 
+/**
+ * Validate every field of an ExtractionConfig.
+ *
+ * Unlike `kreuzberg_config_is_valid`, which only checks that JSON parses, this runs
+ * all value checks (DPI ranges, Tesseract PSM/OEM, chunk overlap, ...) and reports
+ * every problem at once.
+ *
+ * # Returns
+ *
+ * A JSON array of `{"field": ..., "message": ...}` objects, empty (`[]`) when the
+ * config is valid, or NULL on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - `config` must be a valid pointer to an ExtractionConfig
+ * - The returned pointer must be freed with `kreuzberg_free_string`
+ */
+char *kreuzberg_config_validate(const ExtractionConfig *config);
+
 /**
  * Serialize an ExtractionConfig to JSON string.
  *
@@ -1404,6 +1509,61 @@ struct CExtractionResult *kreuzberg_extract_bytes_sync_with_config(const uint8_t
                                                                    const char *mime_type,
                                                                    const char *config_json);
 
+/**
+ * Extract text and metadata from a byte array with a trusted MIME type (synchronous).
+ *
+ * Skips MIME validation and dispatches directly to the extractor registered for
+ * `mime_type`, e.g. a `Content-Type` received with an upload. Parameters such as
+ * `; charset=binary` are ignored. Fails with an "Unsupported format" error naming the
+ * MIME type when no extractor is registered for it.
+ *
+ * # Safety
+ *
+ * - `data` must be a valid pointer to a byte array of length `data_len`
+ * - `mime_type` must be a valid null-terminated C string
+ * - `config_json` must be a valid null-terminated C string containing JSON, or NULL for default config
+ * - The returned pointer must be freed with `kreuzberg_free_result`
+ * - Returns NULL on error (check `kreuzberg_last_error` for details)
+ *
+ * # Example (C)
+ *
+ * ```c
+ * const char* mime = "application/pdf; charset=binary"; // from the upload's Content-Type
+ * CExtractionResult* result = kreuzberg_extract_bytes_with_mime_sync(data, len, mime, NULL);
+ * if (result != NULL && result->success) {
+ *     printf("Content: %s\n", result->content);
+ *     kreuzberg_free_result(result);
+ * }
+ * ```
+ */
+struct CExtractionResult *kreuzberg_extract_bytes_with_mime_sync(const uint8_t *data,
+                                                                 uintptr_t data_len,
+                                                                 const char *mime_type,
+                                                                 const char *config_json);
+
+/**
+ * Count the pages of a PDF without extracting its content.
+ *
+ * Much cheaper than a full extraction when only the page count is needed, e.g. for
+ * pagination or quota decisions.
+ *
+ * # Safety
+ *
+ * - `data` must be a valid pointer to a byte array of length `data_len`
+ * - Returns the page count, or -1 on error (check `kreuzberg_last_error` for details)
+ * - Encrypted PDFs fail with a "password-protected" error; non-PDF input fails with "Invalid PDF"
+ *
+ * # Example (C)
+ *
+ * ```c
+ * int32_t pages = kreuzberg_pdf_page_count(data, len);
+ * if (pages < 0) {
+ *     printf("Error: %s\n", kreuzberg_last_error());
+ * }
+ * ```
+ */
+int32_t kreuzberg_pdf_page_count(const uint8_t *data, uintptr_t data_len);
+
 /**
  * Batch extract text and metadata from multiple files (synchronous).
  *
@@ -1454,6 +1614,97 @@ struct CBatchResult *kreuzberg_batch_extract_bytes_sync(const struct CBytesWithM
                                                         uintptr_t count,
                                                         const char *config_json);
 
+/**
+ * Get a summary of the failed items of a batch result.
+ *
+ * Returns one `<item>: <error>` line per failed item, joined by newlines. Items are
+ * named by their file path, or by `item <index>` for byte batches. The counts are
+ * available directly as `success_count` and `failure_count` on the batch.
+ *
+ * # Safety
+ *
+ * - `batch_result` must be NULL or a pointer returned by a batch extraction function
+ * - The returned string must be freed with `kreuzberg_free_string`
+ * - Returns NULL if `batch_result` is NULL or no item failed
+ *
+ * # Example (C)
+ *
+ * ```c
+ * CBatchResult* batch = kreuzberg_batch_extract_files_sync(paths, count, NULL);
+ * if (batch != NULL && batch->failure_count > 0) {
+ *     char* errors = kreuzberg_batch_result_errors(batch);
+ *     fprintf(stderr, "%zu of %zu failed:\n%s\n", batch->failure_count, batch->count, errors);
+ *     kreuzberg_free_string(errors);
+ * }
+ * ```
+ */
+char *kreuzberg_batch_result_errors(const struct CBatchResult *batch_result);
+
+/**
+ * Create a reusable extractor.
+ *
+ * # Arguments
+ *
+ * * `config` - Configuration to use for every extraction, or NULL for the default
+ *   configuration. The configuration is copied, so the caller may free it afterwards.
+ *
+ * # Returns
+ *
+ * Pointer to the extractor, or NULL on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - `config` must be NULL or a valid pointer returned by a config creation function
+ * - The returned pointer must be freed with `kreuzberg_extractor_free`
+ *
+ * # Example (C)
+ *
+ * ```c
+ * KreuzbergExtractor* extractor = kreuzberg_extractor_new(NULL);
+ * if (extractor == NULL) {
+ *     printf("Error: %s\n", kreuzberg_last_error());
+ * }
+ * ```
+ */
+struct KreuzbergExtractor *kreuzberg_extractor_new(const ExtractionConfig *config);
+
+/**
+ * Extract text and metadata from a file using an extractor's configuration.
+ *
+ * May be called concurrently from multiple threads on the same extractor.
+ *
+ * # Safety
+ *
+ * - `extractor` must be a valid pointer returned by `kreuzberg_extractor_new`
+ * - `file_path` must be a valid null-terminated C string
+ * - The returned pointer must be freed with `kreuzberg_free_result`
+ * - Returns NULL on error (check `kreuzberg_last_error` for details)
+ *
+ * # Example (C)
+ *
+ * ```c
+ * CExtractionResult* result = kreuzberg_extractor_extract_file(extractor, "document.pdf");
+ * if (result != NULL && result->success) {
+ *     printf("Content: %s\n", result->content);
+ *     kreuzberg_free_result(result);
+ * }
+ * ```
+ */
+struct CExtractionResult *kreuzberg_extractor_extract_file(const struct KreuzbergExtractor *extractor,
+                                                           const char *file_path);
+
+/**
+ * Free an extractor created by `kreuzberg_extractor_new`.
+ *
+ * # Safety
+ *
+ * - `extractor` must be a valid pointer returned by `kreuzberg_extractor_new`
+ * - `extractor` can be NULL (no-op)
+ * - Must not be called twice on the same extractor (double-free)
+ * - Must not be called while other threads are still using the extractor
+ */
+void kreuzberg_extractor_free(struct KreuzbergExtractor *extractor);
+
 /**
  * Parse HeadingStyle from string to discriminant.
  *
@@ -1583,7 +1834,7 @@ const char *kreuzberg_preprocessing_preset_to_string(int32_t discriminant);
  * - `batch_result` must be a pointer previously returned by a batch extraction function
  * - `batch_result` can be NULL (no-op)
  * - `batch_result` must not be used after this call
- * - All individual results in the batch will be freed automatically
+ * - All individual results in the batch and its `errors` summary will be freed automatically
  *
  * # Memory Layout
  *
@@ -1658,6 +1909,7 @@ char *kreuzberg_clone_string(const char *s);
  * 11. page_structure_json (FIXED: was missing before PR #3)
  * 12. pages_json (FIXED: was missing before PR #3)
  * 13. elements_json (ADDED: for element-based extraction support)
+ * 14. links_json
  *
  * # Example (C)
  *
@@ -1739,6 +1991,58 @@ char *kreuzberg_detect_mime_type_from_bytes(const uint8_t *bytes, uintptr_t len)
  */
 char *kreuzberg_detect_mime_type_from_path(const char *file_path);
 
+/**
+ * Detect the MIME type of a file on disk, falling back to `application/octet-stream`.
+ *
+ * The first 64 KiB of the file are read and their content type and the extension are
+ * compared, with the detected content type winning when they disagree. Unlike `kreuzberg_detect_mime_type_from_path`,
+ * a readable file whose type cannot be identified yields `application/octet-stream`
+ * instead of an error.
+ *
+ * # Safety
+ *
+ * - `path` must be a valid null-terminated C string
+ * - The returned string must be freed with `kreuzberg_free_string`
+ * - Returns NULL if the file cannot be read (check `kreuzberg_last_error`)
+ *
+ * # Example (C)
+ *
+ * ```c
+ * char* mime = kreuzberg_detect_mime_file("upload.bin");
+ * if (mime == NULL) {
+ *     printf("Cannot read file: %s\n", kreuzberg_last_error());
+ * } else {
+ *     printf("MIME type: %s\n", mime);
+ *     kreuzberg_free_string(mime);
+ * }
+ * ```
+ */
+char *kreuzberg_detect_mime_file(const char *path);
+
+/**
+ * Detect the MIME type of an in-memory buffer, falling back to `application/octet-stream`.
+ *
+ * Unlike `kreuzberg_detect_mime_type_from_bytes`, content whose type cannot be
+ * identified yields `application/octet-stream` instead of an error.
+ *
+ * # Safety
+ *
+ * - `data` must point to a valid buffer of at least `len` bytes
+ * - The returned string must be freed with `kreuzberg_free_string`
+ * - Returns NULL if `data` is NULL (check `kreuzberg_last_error`)
+ *
+ * # Example (C)
+ *
+ * ```c
+ * char* mime = kreuzberg_detect_mime_bytes(buffer, buffer_len);
+ * if (mime != NULL) {
+ *     printf("MIME type: %s\n", mime);
+ *     kreuzberg_free_string(mime);
+ * }
+ * ```
+ */
+char *kreuzberg_detect_mime_bytes(const uint8_t *data, uintptr_t len);
+
 /**
  * Get file extensions for a MIME type.
  *
@@ -2159,6 +2463,27 @@ char *kreuzberg_list_post_processors(void);
  */
 bool kreuzberg_register_validator(const char *name, ValidatorCallback callback, int32_t priority);
 
+/**
+ * Register the built-in minimum text length validator.
+ *
+ * Adds a `validation` warning to the result's warnings when the
+ * extracted text has fewer than `min_length` characters. Extraction never fails.
+ *
+ * # Safety
+ *
+ * - Returns true on success, false on error (check kreuzberg_last_error)
+ * - Replaces a previously registered `min-text-length` validator
+ *
+ * # Example (C)
+ *
+ * ```c
+ * if (!kreuzberg_register_min_text_length_validator(50)) {
+ *     printf("Failed to register: %s\n", kreuzberg_last_error());
+ * }
+ * ```
+ */
+bool kreuzberg_register_min_text_length_validator(uintptr_t min_length);
+
 /**
  * Unregister a Validator by name.
  *
@@ -2305,6 +2630,107 @@ int32_t kreuzberg_result_get_chunk_count(const ExtractionResult *result);
  */
 char *kreuzberg_result_get_detected_language(const ExtractionResult *result);
 
+/**
+ * Get validator warnings from extraction result.
+ *
+ * Returns the messages of the result's `validation` warnings (non-fatal warnings
+ * attached by validators, see `kreuzberg::plugins::ValidationOutcome`) as a JSON
+ * array of strings.
+ *
+ * # Arguments
+ *
+ * * `result` - Pointer to an ExtractionResult structure
+ *
+ * # Returns
+ *
+ * A pointer to a C string containing a JSON array (e.g., `["low text density"]`),
+ * or NULL if no warnings were reported or on error (check `kreuzberg_last_error`).
+ *
+ * The returned pointer must be freed with `kreuzberg_free_string()`.
+ *
+ * # Safety
+ *
+ * - `result` must be a valid pointer to an ExtractionResult
+ * - `result` cannot be NULL
+ * - The returned pointer (if non-NULL) must be freed with `kreuzberg_free_string`
+ *
+ * # Example (C)
+ *
+ * ```c
+ * ExtractionResult* result = kreuzberg_extract_file("document.pdf", NULL);
+ * if (result != NULL) {
+ *     char* warnings = kreuzberg_result_get_validation_warnings(result);
+ *     if (warnings != NULL) {
+ *         printf("Validation warnings: %s\n", warnings);
+ *         kreuzberg_free_string(warnings);
+ *     }
+ *     kreuzberg_result_free(result);
+ * }
+ * ```
+ */
+char *kreuzberg_result_get_validation_warnings(const ExtractionResult *result);
+
+/**
+ * Get the number of extraction warnings in a result.
+ *
+ * # Arguments
+ *
+ * * `result` - Pointer to an ExtractionResult structure
+ *
+ * # Returns
+ *
+ * The warning count (>= 0) if successful, or -1 on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - `result` must be a valid pointer to an ExtractionResult
+ * - `result` cannot be NULL
+ */
+int32_t kreuzberg_result_get_warning_count(const ExtractionResult *result);
+
+/**
+ * Get an extraction warning by index.
+ *
+ * Returns the warning as a JSON object with a `kind` (e.g., `"missing_font"`) and a
+ * human-readable `message`.
+ *
+ * # Arguments
+ *
+ * * `result` - Pointer to an ExtractionResult structure
+ * * `index` - Zero-based warning index, less than `kreuzberg_result_get_warning_count`
+ *
+ * # Returns
+ *
+ * A pointer to a C string containing the JSON object, or NULL if the index is out of
+ * range or on error (check `kreuzberg_last_error`).
+ *
+ * The returned pointer must be freed with `kreuzberg_free_string()`.
+ *
+ * # Safety
+ *
+ * - `result` must be a valid pointer to an ExtractionResult
+ * - `result` cannot be NULL
+ * - The returned pointer (if non-NULL) must be freed with `kreuzberg_free_string`
+ *
+ * # Example (C)
+ *
+ * ```c
+ * ExtractionResult* result = kreuzberg_extract_file("document.pdf", NULL);
+ * if (result != NULL) {
+ *     int count = kreuzberg_result_get_warning_count(result);
+ *     for (int i = 0; i < count; i++) {
+ *         char* warning = kreuzberg_result_warning(result, i);
+ *         if (warning != NULL) {
+ *             printf("Warning: %s\n", warning);
+ *             kreuzberg_free_string(warning);
+ *         }
+ *     }
+ *     kreuzberg_result_free(result);
+ * }
+ * ```
+ */
+char *kreuzberg_result_warning(const ExtractionResult *result, uintptr_t index);
+
 /**
  * Get a metadata field by name.
  *
@@ -2810,6 +3236,13 @@ void kreuzberg_string_intern_reset(void);
  * - Returns NULL if no error has occurred
  * - The returned string is valid until the next Kreuzberg function call on the same thread
  *
+ * # Nested calls
+ *
+ * Calls made from inside a callback (batch result or error callbacks, plugin callbacks)
+ * start with a fresh error state and report their errors here as usual while the callback
+ * runs. When the callback returns, the error state of the enclosing call is restored, so
+ * nested calls never overwrite an error the outer call has not reported yet.
+ *
  * # Example (C)
  *
  * ```c
@@ -2900,6 +3333,112 @@ char *kreuzberg_last_panic_context(void);
  */
 const char *kreuzberg_version(void);
 
+/**
+ * Get the version of the core kreuzberg library linked into this library.
+ *
+ * # Safety
+ *
+ * - Returns a static string that does not need to be freed
+ * - The returned string is always valid
+ */
+const char *kreuzberg_core_version(void);
+
+/**
+ * Get the version of the FFI binding layer.
+ *
+ * Same as `kreuzberg_version()`; provided alongside `kreuzberg_core_version()`.
+ *
+ * # Safety
+ *
+ * - Returns a static string that does not need to be freed
+ * - The returned string is always valid
+ */
+const char *kreuzberg_binding_version(void);
+
+/**
+ * Check that a binding version is compatible with the core library.
+ *
+ * Versions are compatible when their semver major versions match. Language
+ * bindings pass their own package version; NULL checks the FFI binding version.
+ *
+ * # Returns
+ *
+ * - `1` if compatible
+ * - `0` if incompatible (error message naming both versions available via `kreuzberg_last_error()`)
+ *
+ * # Safety
+ *
+ * * `binding_version` must be NULL or a valid pointer to a null-terminated UTF-8 string
+ *
+ * # Example (C)
+ *
+ * ```c
+ * if (kreuzberg_check_compatibility("4.2.0") != 1) {
+ *     fprintf(stderr, "%s\n", kreuzberg_last_error());
+ *     abort();
+ * }
+ * ```
+ */
+int32_t kreuzberg_check_compatibility(const char *binding_version);
+
+/**
+ * Get the ABI version of the loaded library.
+ *
+ * Compare against `KREUZBERG_ABI_VERSION` from the header to detect a header
+ * compiled against a different library build than the one loaded at runtime.
+ *
+ * # Safety
+ *
+ * - Always safe to call
+ *
+ * # Example (C)
+ *
+ * ```c
+ * if (kreuzberg_abi_version() != KREUZBERG_ABI_VERSION) {
+ *     fprintf(stderr, "kreuzberg ABI mismatch: header %u, library %u\n",
+ *             KREUZBERG_ABI_VERSION, kreuzberg_abi_version());
+ *     abort();
+ * }
+ * ```
+ */
+uint32_t kreuzberg_abi_version(void);
+
+/**
+ * Get the size in bytes of `CExtractionResult` as compiled into the library.
+ *
+ * Bindings that declare the struct layout themselves (e.g. via dynamic loading)
+ * can compare this against their own definition.
+ *
+ * # Safety
+ *
+ * - Always safe to call
+ *
+ * # Example (C)
+ *
+ * ```c
+ * assert(kreuzberg_sizeof_extraction_result() == sizeof(CExtractionResult));
+ * ```
+ */
+uintptr_t kreuzberg_sizeof_extraction_result(void);
+
+/**
+ * Get the size in bytes of `CBatchResult` as compiled into the library.
+ *
+ * # Safety
+ *
+ * - Always safe to call
+ */
+uintptr_t kreuzberg_sizeof_batch_result(void);
+
+/**
+ * Get the size in bytes of `CBytesWithMime` as compiled into the library.
+ *
+ * # Safety
+ *
+ * - Always safe to call
+ */
+uintptr_t kreuzberg_sizeof_bytes_with_mime(void);
+
 /**
  * Validates a binarization method string.
  *
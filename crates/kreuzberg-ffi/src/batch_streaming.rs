@@ -36,6 +36,7 @@
 //! );
 //! ```
 
+use crate::helpers::call_reentrant;
 use crate::result_view::{CExtractionResultView, create_result_view};
 use crate::{FfiResult, clear_last_error, parse_extraction_config_from_json, set_last_error};
 use kreuzberg::types::ExtractionResult;
@@ -183,7 +184,7 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_streaming(
             if let Some(err_cb) = error_callback
                 && let Ok(err_msg) = CString::new("File path is NULL")
             {
-                unsafe { err_cb(i, err_msg.as_ptr(), user_data) };
+                call_reentrant(|| unsafe { err_cb(i, err_msg.as_ptr(), user_data) });
             }
             continue;
         }
@@ -194,7 +195,7 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_streaming(
                 if let Some(err_cb) = error_callback
                     && let Ok(err_msg) = CString::new(format!("Invalid UTF-8 in file path: {}", e))
                 {
-                    unsafe { err_cb(i, err_msg.as_ptr(), user_data) };
+                    call_reentrant(|| unsafe { err_cb(i, err_msg.as_ptr(), user_data) });
                 }
                 continue;
             }
@@ -204,7 +205,8 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_streaming(
             Ok(result) => {
                 let view = create_result_view(&result);
 
-                let continue_processing = unsafe { result_callback(&view as *const _, i, user_data) };
+                let continue_processing =
+                    call_reentrant(|| unsafe { result_callback(&view as *const _, i, user_data) });
 
                 if continue_processing != 0 {
                     return 0;
@@ -214,7 +216,7 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_streaming(
                 if let Some(err_cb) = error_callback
                     && let Ok(err_msg) = CString::new(e)
                 {
-                    unsafe { err_cb(i, err_msg.as_ptr(), user_data) };
+                    call_reentrant(|| unsafe { err_cb(i, err_msg.as_ptr(), user_data) });
                 }
             }
         }
@@ -319,7 +321,7 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_parallel(
             if let Some(err_cb) = error_callback
                 && let Ok(err_msg) = CString::new("File path is NULL")
             {
-                unsafe { err_cb(i, err_msg.as_ptr(), user_data) };
+                call_reentrant(|| unsafe { err_cb(i, err_msg.as_ptr(), user_data) });
             }
             continue;
         }
@@ -330,7 +332,7 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_parallel(
                 if let Some(err_cb) = error_callback
                     && let Ok(err_msg) = CString::new(format!("Invalid UTF-8: {}", e))
                 {
-                    unsafe { err_cb(i, err_msg.as_ptr(), user_data) };
+                    call_reentrant(|| unsafe { err_cb(i, err_msg.as_ptr(), user_data) });
                 }
             }
         }
@@ -369,8 +371,9 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_parallel(
                     Ok(result) => {
                         let view = create_result_view(&result);
 
-                        let should_cancel =
-                            unsafe { result_callback(&view as *const _, *index, user_data_ptr as *mut c_void) };
+                        let should_cancel = call_reentrant(|| unsafe {
+                            result_callback(&view as *const _, *index, user_data_ptr as *mut c_void)
+                        });
 
                         if should_cancel != 0 {
                             cancelled.store(true, Ordering::Relaxed);
@@ -380,7 +383,9 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_parallel(
                         if let Some(err_cb) = error_callback
                             && let Ok(err_msg) = CString::new(e)
                         {
-                            unsafe { err_cb(*index, err_msg.as_ptr(), user_data_ptr as *mut c_void) };
+                            call_reentrant(|| unsafe {
+                                err_cb(*index, err_msg.as_ptr(), user_data_ptr as *mut c_void)
+                            });
                         }
                     }
                 }
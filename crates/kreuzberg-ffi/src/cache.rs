@@ -0,0 +1,111 @@
+//! Process-level cache statistics FFI module.
+//!
+//! Exposes `kreuzberg::cache_stats()` so C callers can monitor the memory held by
+//! the MIME type and language code intern pools and the string buffer pool.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! CCacheStats stats;
+//! if (kreuzberg_cache_stats(&stats) == 0) {
+//!     printf("MIME types: %zu, languages: %zu, buffers: %zu bytes\n",
+//!            stats.mime_entries, stats.language_entries, stats.buffer_pool_bytes);
+//! }
+//! ```
+
+use crate::ffi_panic_guard_i32;
+use crate::helpers::{clear_last_error, set_last_error};
+
+/// Snapshot of the process-level string caches.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CCacheStats {
+    /// Strings held by the MIME type intern pool
+    pub mime_entries: usize,
+
+    /// Strings held by the language code intern pool
+    pub language_entries: usize,
+
+    /// Total capacity in bytes of idle buffers in the string buffer pool
+    pub buffer_pool_bytes: usize,
+
+    /// Buffer acquisitions served from the pool (0 unless built with `pool-metrics`)
+    pub buffer_pool_hits: usize,
+
+    /// Buffer acquisitions that allocated a new buffer (0 unless built with `pool-metrics`)
+    pub buffer_pool_misses: usize,
+}
+
+impl From<kreuzberg::CacheStats> for CCacheStats {
+    fn from(stats: kreuzberg::CacheStats) -> Self {
+        Self {
+            mime_entries: stats.mime_entries,
+            language_entries: stats.language_entries,
+            buffer_pool_bytes: stats.buffer_pool_bytes,
+            #[cfg(feature = "pool-metrics")]
+            buffer_pool_hits: stats.buffer_pool_hits,
+            #[cfg(not(feature = "pool-metrics"))]
+            buffer_pool_hits: 0,
+            #[cfg(feature = "pool-metrics")]
+            buffer_pool_misses: stats.buffer_pool_misses,
+            #[cfg(not(feature = "pool-metrics"))]
+            buffer_pool_misses: 0,
+        }
+    }
+}
+
+/// Fill `out` with the current process-level cache statistics.
+///
+/// # Arguments
+///
+/// * `out` - Non-null pointer to a caller-allocated `CCacheStats`
+///
+/// # Returns
+///
+/// 0 on success, -1 on error (NULL `out`)
+///
+/// # Safety
+///
+/// - `out` must be a valid, properly aligned pointer to writable `CCacheStats` memory
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_cache_stats(out: *mut CCacheStats) -> i32 {
+    ffi_panic_guard_i32!("kreuzberg_cache_stats", {
+        if out.is_null() {
+            set_last_error("CCacheStats output pointer cannot be NULL".to_string());
+            return -1;
+        }
+
+        clear_last_error();
+        unsafe { out.write(kreuzberg::cache_stats().into()) };
+        0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kreuzberg_last_error;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_cache_stats_populates_struct() {
+        kreuzberg::utils::intern_mime_type("application/x-kreuzberg-ffi-stats");
+        kreuzberg::utils::intern_language_code("x-kreuzberg-ffi");
+
+        let mut stats = CCacheStats::default();
+        let rc = unsafe { kreuzberg_cache_stats(&mut stats) };
+
+        assert_eq!(rc, 0);
+        assert!(stats.mime_entries > 0);
+        assert!(stats.language_entries > 0);
+    }
+
+    #[test]
+    fn test_cache_stats_null_out() {
+        let rc = unsafe { kreuzberg_cache_stats(std::ptr::null_mut()) };
+        assert_eq!(rc, -1);
+
+        let error = unsafe { CStr::from_ptr(kreuzberg_last_error()) };
+        assert!(error.to_str().unwrap().contains("NULL"));
+    }
+}
@@ -17,7 +17,34 @@ pub fn merge_configs(base: &mut ExtractionConfig, override_config: &ExtractionCo
     base.use_cache = override_config.use_cache;
     base.enable_quality_processing = override_config.enable_quality_processing;
     base.force_ocr = override_config.force_ocr;
+    base.detect_image_tables = override_config.detect_image_tables;
+    base.ocr_embedded_images = override_config.ocr_embedded_images;
+    base.ocr_optional = override_config.ocr_optional;
     base.max_concurrent_extractions = override_config.max_concurrent_extractions;
+    base.force_single_threaded = override_config.force_single_threaded;
+    base.allow_partial = override_config.allow_partial;
+    base.detect_language = override_config.detect_language;
+    base.extract_form_fields = override_config.extract_form_fields;
+    base.normalize_whitespace = override_config.normalize_whitespace;
+    base.strip_running_headers = override_config.strip_running_headers;
+    base.include_layout = override_config.include_layout;
+    base.include_speaker_notes = override_config.include_speaker_notes;
+
+    if override_config.max_input_bytes.is_some() {
+        base.max_input_bytes = override_config.max_input_bytes;
+    }
+
+    if override_config.max_pages.is_some() {
+        base.max_pages = override_config.max_pages;
+    }
+
+    if override_config.encoding_override.is_some() {
+        base.encoding_override = override_config.encoding_override.clone();
+    }
+
+    if override_config.csv_delimiter.is_some() {
+        base.csv_delimiter = override_config.csv_delimiter;
+    }
 
     if override_config.ocr.is_some() {
         base.ocr = override_config.ocr.clone();
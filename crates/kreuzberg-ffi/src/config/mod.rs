@@ -128,6 +128,43 @@ pub unsafe extern "C" fn kreuzberg_config_is_valid(json_config: *const c_char) -
     }
 }
 
+/// Validate every field of an ExtractionConfig.
+///
+/// Unlike `kreuzberg_config_is_valid`, which only checks that JSON parses, this runs
+/// all value checks (DPI ranges, Tesseract PSM/OEM, chunk overlap, ...) and reports
+/// every problem at once.
+///
+/// # Returns
+///
+/// A JSON array of `{"field": ..., "message": ...}` objects, empty (`[]`) when the
+/// config is valid, or NULL on error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - `config` must be a valid pointer to an ExtractionConfig
+/// - The returned pointer must be freed with `kreuzberg_free_string`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_config_validate(config: *const ExtractionConfig) -> *mut c_char {
+    ffi_panic_guard!("kreuzberg_config_validate", {
+        if config.is_null() {
+            set_last_error("Config cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        clear_last_error();
+
+        let errors = unsafe { &*config }.validate().err().unwrap_or_default();
+
+        match serde_json::to_string(&errors) {
+            Ok(json) => json_to_c_string(json),
+            Err(e) => {
+                set_last_error(format!("Failed to serialize validation errors: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
 /// Serialize an ExtractionConfig to JSON string.
 ///
 /// # Safety
@@ -383,3 +420,54 @@ pub unsafe extern "C" fn kreuzberg_get_embedding_preset(name: *const c_char) ->
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kreuzberg_free_string;
+
+    #[test]
+    fn test_config_validate_reports_all_errors() {
+        let json =
+            CString::new(r#"{"chunking": {"max_chars": 100, "max_overlap": 150}, "max_concurrent_extractions": 0}"#)
+                .unwrap();
+        let config = unsafe { kreuzberg_config_from_json(json.as_ptr()) };
+        assert!(!config.is_null());
+
+        let errors_ptr = unsafe { kreuzberg_config_validate(config) };
+        assert!(!errors_ptr.is_null());
+
+        let errors: serde_json::Value =
+            serde_json::from_str(unsafe { CStr::from_ptr(errors_ptr) }.to_str().unwrap()).unwrap();
+        let fields: Vec<&str> = errors
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|error| error["field"].as_str().unwrap())
+            .collect();
+        assert_eq!(fields, vec!["chunking", "max_concurrent_extractions"]);
+
+        unsafe {
+            kreuzberg_free_string(errors_ptr);
+            kreuzberg_config_free(config);
+        }
+    }
+
+    #[test]
+    fn test_config_validate_valid_config() {
+        let config = Box::into_raw(Box::new(ExtractionConfig::default()));
+
+        let errors_ptr = unsafe { kreuzberg_config_validate(config) };
+        assert_eq!(unsafe { CStr::from_ptr(errors_ptr) }.to_str().unwrap(), "[]");
+
+        unsafe {
+            kreuzberg_free_string(errors_ptr);
+            kreuzberg_config_free(config);
+        }
+    }
+
+    #[test]
+    fn test_config_validate_null() {
+        assert!(unsafe { kreuzberg_config_validate(ptr::null()) }.is_null());
+    }
+}
@@ -21,14 +21,16 @@
 //! - Deallocation must reconstruct the slice before freeing
 //! - This is handled by `kreuzberg_free_batch_result` in the memory module
 
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use std::path::Path;
 use std::ptr;
 
 use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::types::ExtractionResult;
 
 use crate::ffi_panic_guard;
+use crate::ffi_panic_guard_i32;
 use crate::helpers::{clear_last_error, parse_extraction_config_from_json, set_last_error, to_c_extraction_result};
 use crate::memory::kreuzberg_free_result;
 use crate::types::{CBatchResult, CBytesWithMime, CExtractionResult};
@@ -331,6 +333,138 @@ pub unsafe extern "C" fn kreuzberg_extract_bytes_sync_with_config(
     })
 }
 
+/// Extract text and metadata from a byte array with a trusted MIME type (synchronous).
+///
+/// Skips MIME validation and dispatches directly to the extractor registered for
+/// `mime_type`, e.g. a `Content-Type` received with an upload. Parameters such as
+/// `; charset=binary` are ignored. Fails with an "Unsupported format" error naming the
+/// MIME type when no extractor is registered for it.
+///
+/// # Safety
+///
+/// - `data` must be a valid pointer to a byte array of length `data_len`
+/// - `mime_type` must be a valid null-terminated C string
+/// - `config_json` must be a valid null-terminated C string containing JSON, or NULL for default config
+/// - The returned pointer must be freed with `kreuzberg_free_result`
+/// - Returns NULL on error (check `kreuzberg_last_error` for details)
+///
+/// # Example (C)
+///
+/// ```c
+/// const char* mime = "application/pdf; charset=binary"; // from the upload's Content-Type
+/// CExtractionResult* result = kreuzberg_extract_bytes_with_mime_sync(data, len, mime, NULL);
+/// if (result != NULL && result->success) {
+///     printf("Content: %s\n", result->content);
+///     kreuzberg_free_result(result);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_extract_bytes_with_mime_sync(
+    data: *const u8,
+    data_len: usize,
+    mime_type: *const c_char,
+    config_json: *const c_char,
+) -> *mut CExtractionResult {
+    ffi_panic_guard!("kreuzberg_extract_bytes_with_mime_sync", {
+        clear_last_error();
+
+        if data.is_null() {
+            set_last_error("data cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        if mime_type.is_null() {
+            set_last_error("mime_type cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        let bytes = unsafe { std::slice::from_raw_parts(data, data_len) };
+
+        let mime_str = match unsafe { CStr::from_ptr(mime_type) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in MIME type: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let config = if config_json.is_null() {
+            ExtractionConfig::default()
+        } else {
+            let config_str = match unsafe { CStr::from_ptr(config_json) }.to_str() {
+                Ok(s) => s,
+                Err(e) => {
+                    set_last_error(format!("Invalid UTF-8 in config JSON: {}", e));
+                    return ptr::null_mut();
+                }
+            };
+
+            match parse_extraction_config_from_json(config_str) {
+                Ok(cfg) => cfg,
+                Err(e) => {
+                    set_last_error(e);
+                    return ptr::null_mut();
+                }
+            }
+        };
+
+        match kreuzberg::extract_bytes_with_mime_sync(bytes, mime_str, &config) {
+            Ok(result) => match to_c_extraction_result(result) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_last_error(e.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Count the pages of a PDF without extracting its content.
+///
+/// Much cheaper than a full extraction when only the page count is needed, e.g. for
+/// pagination or quota decisions.
+///
+/// # Safety
+///
+/// - `data` must be a valid pointer to a byte array of length `data_len`
+/// - Returns the page count, or -1 on error (check `kreuzberg_last_error` for details)
+/// - Encrypted PDFs fail with a "password-protected" error; non-PDF input fails with "Invalid PDF"
+///
+/// # Example (C)
+///
+/// ```c
+/// int32_t pages = kreuzberg_pdf_page_count(data, len);
+/// if (pages < 0) {
+///     printf("Error: %s\n", kreuzberg_last_error());
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_pdf_page_count(data: *const u8, data_len: usize) -> i32 {
+    ffi_panic_guard_i32!("kreuzberg_pdf_page_count", {
+        clear_last_error();
+
+        if data.is_null() {
+            set_last_error("data cannot be NULL".to_string());
+            return -1;
+        }
+
+        let bytes = unsafe { std::slice::from_raw_parts(data, data_len) };
+
+        match kreuzberg::pdf::pdf_page_count(bytes) {
+            Ok(count) => i32::try_from(count).unwrap_or(i32::MAX),
+            Err(e) => {
+                set_last_error(kreuzberg::KreuzbergError::from(e).to_string());
+                -1
+            }
+        }
+    })
+}
+
 /// Batch extract text and metadata from multiple files (synchronous).
 ///
 /// # Safety
@@ -409,32 +543,13 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_files_sync(
 
         let path_refs: Vec<&std::path::Path> = paths.iter().map(|p| p.as_path()).collect();
         match kreuzberg::batch_extract_file_sync(path_refs, &config) {
-            Ok(results) => {
-                let mut c_results = Vec::with_capacity(results.len());
-                for result in results {
-                    match to_c_extraction_result(result) {
-                        Ok(ptr) => c_results.push(ptr),
-                        Err(e) => {
-                            for c_res in c_results {
-                                unsafe { kreuzberg_free_result(c_res) };
-                            }
-                            set_last_error(e);
-                            return ptr::null_mut();
-                        }
-                    }
+            Ok(results) => match to_c_batch_result(results, |index| paths[index].display().to_string()) {
+                Ok(batch) => batch,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
                 }
-
-                let actual_count = c_results.len();
-                let results_array = c_results.into_boxed_slice();
-                let results_ptr = Box::into_raw(results_array) as *mut *mut CExtractionResult;
-
-                Box::into_raw(Box::new(CBatchResult {
-                    results: results_ptr,
-                    count: actual_count,
-                    success: true,
-                    _padding2: [0u8; 7],
-                }))
-            }
+            },
             Err(e) => {
                 set_last_error(e.to_string());
                 ptr::null_mut()
@@ -443,6 +558,63 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_files_sync(
     })
 }
 
+/// Convert batch extraction results into a `CBatchResult`.
+///
+/// Failed items are counted and listed in the `errors` summary, each named by
+/// `item_label(index)`. Follows the boxed slice allocation pattern described on
+/// `kreuzberg_batch_extract_files_sync`.
+fn to_c_batch_result(
+    results: Vec<ExtractionResult>,
+    item_label: impl Fn(usize) -> String,
+) -> std::result::Result<*mut CBatchResult, String> {
+    let failures: Vec<String> = results
+        .iter()
+        .enumerate()
+        .filter_map(|(index, result)| {
+            let error = result.metadata.error.as_ref()?;
+            Some(format!("{}: {}", item_label(index), error.message))
+        })
+        .collect();
+    let errors = if failures.is_empty() {
+        ptr::null_mut()
+    } else {
+        CString::new(failures.join("\n").replace('\0', "\u{FFFD}"))
+            .map_err(|e| format!("Failed to convert batch errors to C string: {}", e))?
+            .into_raw()
+    };
+
+    let mut c_results = Vec::with_capacity(results.len());
+    for result in results {
+        match to_c_extraction_result(result) {
+            Ok(ptr) => c_results.push(ptr),
+            Err(e) => {
+                for c_res in c_results {
+                    unsafe { kreuzberg_free_result(c_res) };
+                }
+                if !errors.is_null() {
+                    unsafe { drop(CString::from_raw(errors)) };
+                }
+                return Err(e);
+            }
+        }
+    }
+
+    let actual_count = c_results.len();
+    let success_count = c_results.iter().filter(|&&c_res| unsafe { (*c_res).success }).count();
+    let results_array = c_results.into_boxed_slice();
+    let results_ptr = Box::into_raw(results_array) as *mut *mut CExtractionResult;
+
+    Ok(Box::into_raw(Box::new(CBatchResult {
+        results: results_ptr,
+        count: actual_count,
+        success: true,
+        _padding2: [0u8; 7],
+        success_count,
+        failure_count: actual_count - success_count,
+        errors,
+    })))
+}
+
 /// Batch extract text and metadata from multiple byte arrays (synchronous).
 ///
 /// # Safety
@@ -520,32 +692,13 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_bytes_sync(
         }
 
         match kreuzberg::batch_extract_bytes_sync(contents, &config) {
-            Ok(results) => {
-                let mut c_results = Vec::with_capacity(results.len());
-                for result in results {
-                    match to_c_extraction_result(result) {
-                        Ok(ptr) => c_results.push(ptr),
-                        Err(e) => {
-                            for c_res in c_results {
-                                unsafe { kreuzberg_free_result(c_res) };
-                            }
-                            set_last_error(e);
-                            return ptr::null_mut();
-                        }
-                    }
+            Ok(results) => match to_c_batch_result(results, |index| format!("item {}", index)) {
+                Ok(batch) => batch,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
                 }
-
-                let actual_count = c_results.len();
-                let results_array = c_results.into_boxed_slice();
-                let results_ptr = Box::into_raw(results_array) as *mut *mut CExtractionResult;
-
-                Box::into_raw(Box::new(CBatchResult {
-                    results: results_ptr,
-                    count: actual_count,
-                    success: true,
-                    _padding2: [0u8; 7],
-                }))
-            }
+            },
             Err(e) => {
                 set_last_error(e.to_string());
                 ptr::null_mut()
@@ -553,3 +706,41 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_bytes_sync(
         }
     })
 }
+
+/// Get a summary of the failed items of a batch result.
+///
+/// Returns one `<item>: <error>` line per failed item, joined by newlines. Items are
+/// named by their file path, or by `item <index>` for byte batches. The counts are
+/// available directly as `success_count` and `failure_count` on the batch.
+///
+/// # Safety
+///
+/// - `batch_result` must be NULL or a pointer returned by a batch extraction function
+/// - The returned string must be freed with `kreuzberg_free_string`
+/// - Returns NULL if `batch_result` is NULL or no item failed
+///
+/// # Example (C)
+///
+/// ```c
+/// CBatchResult* batch = kreuzberg_batch_extract_files_sync(paths, count, NULL);
+/// if (batch != NULL && batch->failure_count > 0) {
+///     char* errors = kreuzberg_batch_result_errors(batch);
+///     fprintf(stderr, "%zu of %zu failed:\n%s\n", batch->failure_count, batch->count, errors);
+///     kreuzberg_free_string(errors);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_batch_result_errors(batch_result: *const CBatchResult) -> *mut c_char {
+    ffi_panic_guard!("kreuzberg_batch_result_errors", {
+        if batch_result.is_null() {
+            return ptr::null_mut();
+        }
+
+        let errors = unsafe { (*batch_result).errors };
+        if errors.is_null() {
+            return ptr::null_mut();
+        }
+
+        unsafe { CStr::from_ptr(errors) }.to_owned().into_raw()
+    })
+}
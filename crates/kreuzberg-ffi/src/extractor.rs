@@ -0,0 +1,285 @@
+//! Reusable extractor handle FFI module.
+//!
+//! `kreuzberg_extract_file_sync` and friends build a configuration on every call and
+//! pay one-time initialization costs (extractor registration, system font discovery)
+//! on the first extraction. A `KreuzbergExtractor` holds a configuration and warms that
+//! shared state once at construction, so long-lived services can reuse it across calls.
+//!
+//! # Usage Pattern
+//!
+//! 1. Create a handle: `kreuzberg_extractor_new(config)` (config may be NULL)
+//! 2. Extract any number of files: `kreuzberg_extractor_extract_file(extractor, path)`
+//! 3. Free the handle when done: `kreuzberg_extractor_free(extractor)`
+//!
+//! # Example (C)
+//!
+//! ```c
+//! ExtractionConfig* config = kreuzberg_config_from_json("{\"use_cache\": false}");
+//! KreuzbergExtractor* extractor = kreuzberg_extractor_new(config);
+//! kreuzberg_config_free(config); // The extractor keeps its own copy
+//!
+//! for (int i = 0; i < file_count; i++) {
+//!     CExtractionResult* result = kreuzberg_extractor_extract_file(extractor, files[i]);
+//!     if (result != NULL) {
+//!         printf("%s\n", result->content);
+//!         kreuzberg_free_result(result);
+//!     }
+//! }
+//!
+//! kreuzberg_extractor_free(extractor);
+//! ```
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::path::Path;
+use std::ptr;
+
+use kreuzberg::core::config::ExtractionConfig;
+
+use crate::ffi_panic_guard;
+use crate::helpers::{clear_last_error, set_last_error, to_c_extraction_result};
+use crate::types::CExtractionResult;
+
+/// Long-lived extractor holding a configuration.
+///
+/// # Thread Safety
+///
+/// The handle is immutable after construction. Any number of threads may call
+/// `kreuzberg_extractor_extract_file` on the same handle concurrently without
+/// external locking. `kreuzberg_extractor_free` must not run concurrently with
+/// any other call on the same handle.
+pub struct KreuzbergExtractor {
+    config: ExtractionConfig,
+}
+
+impl KreuzbergExtractor {
+    /// Create an extractor and initialize the shared state its extractions depend on.
+    fn new(config: ExtractionConfig) -> Result<Self, String> {
+        kreuzberg::extractors::ensure_initialized().map_err(|e| e.to_string())?;
+
+        // Font discovery failure only degrades PDF rendering, so it must not prevent
+        // the extractor from being created.
+        if let Err(e) = kreuzberg::pdf::initialize_font_cache() {
+            log::warn!("Failed to initialize font cache: {}", e);
+        }
+
+        Ok(Self { config })
+    }
+}
+
+/// Create a reusable extractor.
+///
+/// # Arguments
+///
+/// * `config` - Configuration to use for every extraction, or NULL for the default
+///   configuration. The configuration is copied, so the caller may free it afterwards.
+///
+/// # Returns
+///
+/// Pointer to the extractor, or NULL on error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - `config` must be NULL or a valid pointer returned by a config creation function
+/// - The returned pointer must be freed with `kreuzberg_extractor_free`
+///
+/// # Example (C)
+///
+/// ```c
+/// KreuzbergExtractor* extractor = kreuzberg_extractor_new(NULL);
+/// if (extractor == NULL) {
+///     printf("Error: %s\n", kreuzberg_last_error());
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_extractor_new(config: *const ExtractionConfig) -> *mut KreuzbergExtractor {
+    ffi_panic_guard!("kreuzberg_extractor_new", {
+        clear_last_error();
+
+        let config = if config.is_null() {
+            ExtractionConfig::default()
+        } else {
+            unsafe { &*config }.clone()
+        };
+
+        match KreuzbergExtractor::new(config) {
+            Ok(extractor) => Box::into_raw(Box::new(extractor)),
+            Err(e) => {
+                set_last_error(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Extract text and metadata from a file using an extractor's configuration.
+///
+/// May be called concurrently from multiple threads on the same extractor.
+///
+/// # Safety
+///
+/// - `extractor` must be a valid pointer returned by `kreuzberg_extractor_new`
+/// - `file_path` must be a valid null-terminated C string
+/// - The returned pointer must be freed with `kreuzberg_free_result`
+/// - Returns NULL on error (check `kreuzberg_last_error` for details)
+///
+/// # Example (C)
+///
+/// ```c
+/// CExtractionResult* result = kreuzberg_extractor_extract_file(extractor, "document.pdf");
+/// if (result != NULL && result->success) {
+///     printf("Content: %s\n", result->content);
+///     kreuzberg_free_result(result);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_extractor_extract_file(
+    extractor: *const KreuzbergExtractor,
+    file_path: *const c_char,
+) -> *mut CExtractionResult {
+    ffi_panic_guard!("kreuzberg_extractor_extract_file", {
+        clear_last_error();
+
+        if extractor.is_null() {
+            set_last_error("extractor cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        if file_path.is_null() {
+            set_last_error("file_path cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        let path_str = match unsafe { CStr::from_ptr(file_path) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in file path: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let extractor = unsafe { &*extractor };
+
+        match kreuzberg::extract_file_sync(Path::new(path_str), None, &extractor.config) {
+            Ok(result) => match to_c_extraction_result(result) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_last_error(e.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Free an extractor created by `kreuzberg_extractor_new`.
+///
+/// # Safety
+///
+/// - `extractor` must be a valid pointer returned by `kreuzberg_extractor_new`
+/// - `extractor` can be NULL (no-op)
+/// - Must not be called twice on the same extractor (double-free)
+/// - Must not be called while other threads are still using the extractor
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_extractor_free(extractor: *mut KreuzbergExtractor) {
+    if !extractor.is_null() {
+        let _ = unsafe { Box::from_raw(extractor) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::kreuzberg_free_result;
+    use std::ffi::CString;
+
+    fn extract_content(extractor: *const KreuzbergExtractor, path: &Path) -> String {
+        let path_cstr = CString::new(path.to_str().unwrap()).unwrap();
+
+        unsafe {
+            let result = kreuzberg_extractor_extract_file(extractor, path_cstr.as_ptr());
+            assert!(!result.is_null());
+            let content = CStr::from_ptr((*result).content).to_str().unwrap().to_string();
+            kreuzberg_free_result(result);
+            content
+        }
+    }
+
+    #[test]
+    fn test_extractor_extracts_multiple_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let first = temp_dir.path().join("first.txt");
+        let second = temp_dir.path().join("second.txt");
+        std::fs::write(&first, "First document").unwrap();
+        std::fs::write(&second, "Second document").unwrap();
+
+        let extractor = unsafe { kreuzberg_extractor_new(ptr::null()) };
+        assert!(!extractor.is_null());
+
+        assert!(extract_content(extractor, &first).contains("First document"));
+        assert!(extract_content(extractor, &second).contains("Second document"));
+
+        unsafe { kreuzberg_extractor_free(extractor) };
+    }
+
+    #[test]
+    fn test_extractor_copies_config() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("test.txt");
+        std::fs::write(&file_path, "Configured content").unwrap();
+
+        let json = CString::new(r#"{"use_cache": false}"#).unwrap();
+        let config = unsafe { crate::kreuzberg_config_from_json(json.as_ptr()) };
+        assert!(!config.is_null());
+
+        let extractor = unsafe { kreuzberg_extractor_new(config) };
+        unsafe { crate::kreuzberg_config_free(config) };
+        assert!(!extractor.is_null());
+        assert!(!unsafe { &*extractor }.config.use_cache);
+
+        assert!(extract_content(extractor, &file_path).contains("Configured content"));
+
+        unsafe { kreuzberg_extractor_free(extractor) };
+    }
+
+    #[test]
+    fn test_extractor_shared_across_threads() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("shared.txt");
+        std::fs::write(&file_path, "Shared content").unwrap();
+
+        let extractor = unsafe { kreuzberg_extractor_new(ptr::null()) };
+        assert!(!extractor.is_null());
+        let shared: &KreuzbergExtractor = unsafe { &*extractor };
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    assert!(extract_content(shared, &file_path).contains("Shared content"));
+                });
+            }
+        });
+
+        unsafe { kreuzberg_extractor_free(extractor) };
+    }
+
+    #[test]
+    fn test_extractor_null_arguments() {
+        let path = CString::new("/nonexistent/file.txt").unwrap();
+
+        unsafe {
+            assert!(kreuzberg_extractor_extract_file(ptr::null(), path.as_ptr()).is_null());
+
+            let extractor = kreuzberg_extractor_new(ptr::null());
+            assert!(kreuzberg_extractor_extract_file(extractor, ptr::null()).is_null());
+            assert!(kreuzberg_extractor_extract_file(extractor, path.as_ptr()).is_null());
+            kreuzberg_extractor_free(extractor);
+
+            kreuzberg_extractor_free(ptr::null_mut());
+        }
+    }
+}
@@ -11,7 +11,9 @@ use std::ptr;
 use kreuzberg::core::config::ExtractionConfig;
 use kreuzberg::types::ExtractionResult;
 
-use crate::panic_shield::{ErrorCode, StructuredError, clear_structured_error, set_structured_error};
+use crate::panic_shield::{
+    ErrorCode, StructuredError, clear_structured_error, set_structured_error, take_structured_error,
+};
 use crate::types::{CExtractionResult, CStringGuard};
 
 /// Type alias for FFI results that can fail with a String error message
@@ -38,6 +40,47 @@ pub fn clear_last_error() {
     clear_structured_error();
 }
 
+/// Keeps the calling thread's last error intact across a call into consumer code.
+///
+/// Consumer callbacks (batch result handlers, plugin callbacks) may call back into the
+/// library, and those nested calls clear and set the thread-local error like any other
+/// call. While the guard is held, nested calls work on a fresh error state, so the
+/// callback can inspect their errors as usual. Dropping the guard, including during
+/// unwinding, restores the error state of the outer call, along with the pointer
+/// previously returned by `kreuzberg_last_error`.
+#[must_use = "the outer error is restored when the guard is dropped"]
+pub(crate) struct ReentrantErrorGuard {
+    message: Option<CString>,
+    structured: Option<StructuredError>,
+}
+
+impl ReentrantErrorGuard {
+    /// Set aside the current error state until the guard is dropped.
+    pub(crate) fn enter() -> Self {
+        Self {
+            message: LAST_ERROR_C_STRING.with(|last| last.borrow_mut().take()),
+            structured: take_structured_error(),
+        }
+    }
+}
+
+impl Drop for ReentrantErrorGuard {
+    fn drop(&mut self) {
+        let message = self.message.take();
+        LAST_ERROR_C_STRING.with(|last| *last.borrow_mut() = message);
+        match self.structured.take() {
+            Some(error) => set_structured_error(error),
+            None => clear_structured_error(),
+        }
+    }
+}
+
+/// Invoke consumer code under a [`ReentrantErrorGuard`].
+pub(crate) fn call_reentrant<T>(callback: impl FnOnce() -> T) -> T {
+    let _guard = ReentrantErrorGuard::enter();
+    callback()
+}
+
 /// Convert a Rust String to a C string pointer
 pub fn string_to_c_string(value: String) -> std::result::Result<*mut c_char, String> {
     CString::new(value)
@@ -65,11 +108,18 @@ pub fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*
         detected_languages,
         chunks,
         images,
+        links,
+        warnings: _,
+        partial: _,
         pages,
+        layout: _,
         djot_content: _,
         elements,
     } = result;
 
+    // Batch extraction reports failed items as results carrying error metadata
+    let success = metadata.error.is_none();
+
     let sanitized_content = if content.contains('\0') {
         content.replace('\0', "\u{FFFD}")
     } else {
@@ -190,6 +240,15 @@ pub fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*
         _ => None,
     };
 
+    let links_json_guard = if !links.is_empty() {
+        let json = serde_json::to_string(&links).map_err(|e| format!("Failed to serialize links to JSON: {}", e))?;
+        Some(CStringGuard::new(CString::new(json).map_err(|e| {
+            format!("Failed to convert links JSON to C string: {}", e)
+        })?))
+    } else {
+        None
+    };
+
     Ok(Box::into_raw(Box::new(CExtractionResult {
         content: content_guard.into_raw(),
         mime_type: mime_type_guard.into_raw(),
@@ -204,15 +263,16 @@ pub fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*
         page_structure_json: page_structure_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
         pages_json: pages_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
         elements_json: elements_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
-        success: true,
+        success,
         _padding1: [0u8; 7],
+        links_json: links_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
     })))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use kreuzberg::types::{Chunk, ChunkMetadata, ExtractionResult, Metadata, Table};
+    use kreuzberg::types::{Chunk, ChunkMetadata, ExtractedLink, ExtractionResult, LinkKind, Metadata, Table};
     use std::borrow::Cow;
     use std::ffi::CStr;
 
@@ -223,6 +283,47 @@ mod tests {
         clear_last_error();
     }
 
+    #[test]
+    fn test_nested_call_does_not_clobber_outer_error() {
+        use crate::panic_shield::get_last_error_code;
+        use crate::util::kreuzberg_last_error;
+
+        let last_error = || unsafe {
+            let ptr = kreuzberg_last_error();
+            (!ptr.is_null()).then(|| CStr::from_ptr(ptr).to_string_lossy().into_owned())
+        };
+
+        clear_last_error();
+        set_structured_error(StructuredError::from_message(
+            "outer failure".to_string(),
+            ErrorCode::IoError,
+        ));
+        LAST_ERROR_C_STRING.with(|last| *last.borrow_mut() = Some(CString::new("outer failure").unwrap()));
+
+        {
+            let _guard = ReentrantErrorGuard::enter();
+            assert_eq!(last_error(), None, "nested calls start without an error");
+
+            // A nested call made from a callback clears and then sets the error
+            clear_last_error();
+            set_last_error("inner failure".to_string());
+            assert_eq!(last_error().as_deref(), Some("inner failure"));
+        }
+
+        assert_eq!(last_error().as_deref(), Some("outer failure"));
+        assert_eq!(get_last_error_code(), ErrorCode::IoError);
+
+        let unwound = std::panic::catch_unwind(|| {
+            let _guard = ReentrantErrorGuard::enter();
+            set_last_error("inner failure".to_string());
+            panic!("callback unwound");
+        });
+        assert!(unwound.is_err());
+        assert_eq!(last_error().as_deref(), Some("outer failure"));
+
+        clear_last_error();
+    }
+
     #[test]
     fn test_string_to_c_string_success() {
         let result = string_to_c_string("hello world".to_string());
@@ -359,7 +460,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -398,7 +503,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -447,7 +556,11 @@ mod tests {
             detected_languages: Some(vec!["en".to_string(), "de".to_string()]),
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -502,6 +615,7 @@ mod tests {
             ],
             markdown: "| Col1 | Col2 |\n|------|------|\n| A1 | A2 |\n| B1 | B2 |".to_string(),
             page_number: 1,
+            caption: None,
         };
 
         let chunk = Chunk {
@@ -526,7 +640,11 @@ mod tests {
             detected_languages: None,
             chunks: Some(vec![chunk]),
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -562,4 +680,38 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_to_c_extraction_result_with_links() {
+        let result = ExtractionResult {
+            content: "See the docs".to_string(),
+            mime_type: Cow::Borrowed("text/x-djot"),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            links: vec![ExtractedLink {
+                url: "https://example.com/docs".to_string(),
+                text: "docs".to_string(),
+                title: None,
+                kind: LinkKind::Hyperlink,
+            }],
+            warnings: vec![],
+            partial: false,
+            pages: None,
+            layout: None,
+            djot_content: None,
+            elements: None,
+        };
+
+        let ptr = to_c_extraction_result(result).expect("conversion should succeed");
+        unsafe {
+            let links_json = CStr::from_ptr((*ptr).links_json).to_str().unwrap();
+            let links: serde_json::Value = serde_json::from_str(links_json).unwrap();
+            assert_eq!(links[0]["url"], "https://example.com/docs");
+            assert_eq!(links[0]["kind"], "hyperlink");
+            crate::kreuzberg_free_result(ptr);
+        }
+    }
 }
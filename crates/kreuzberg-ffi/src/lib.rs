@@ -4,10 +4,12 @@
 //! Go (cgo), C# (P/Invoke), Zig, and other languages with C FFI support.
 
 mod batch_streaming;
+mod cache;
 mod config;
 mod config_builder;
 mod error;
 mod extraction;
+mod extractor;
 mod helpers;
 mod html_options;
 mod memory;
@@ -25,10 +27,12 @@ mod validation;
 pub use batch_streaming::{
     ErrorCallback, ResultCallback, kreuzberg_extract_batch_parallel, kreuzberg_extract_batch_streaming,
 };
+pub use cache::{CCacheStats, kreuzberg_cache_stats};
 pub use config::{
     kreuzberg_config_discover, kreuzberg_config_free, kreuzberg_config_from_file, kreuzberg_config_from_json,
     kreuzberg_config_get_field, kreuzberg_config_is_valid, kreuzberg_config_merge, kreuzberg_config_to_json,
-    kreuzberg_get_embedding_preset, kreuzberg_list_embedding_presets, kreuzberg_load_extraction_config_from_file,
+    kreuzberg_config_validate, kreuzberg_get_embedding_preset, kreuzberg_list_embedding_presets,
+    kreuzberg_load_extraction_config_from_file,
 };
 pub use config_builder::{
     kreuzberg_config_builder_build, kreuzberg_config_builder_free, kreuzberg_config_builder_new,
@@ -45,8 +49,12 @@ pub use error::{
     kreuzberg_error_code_unsupported_format, kreuzberg_error_code_validation, kreuzberg_get_error_details,
 };
 pub use extraction::{
-    kreuzberg_batch_extract_bytes_sync, kreuzberg_batch_extract_files_sync, kreuzberg_extract_bytes_sync,
-    kreuzberg_extract_bytes_sync_with_config, kreuzberg_extract_file_sync, kreuzberg_extract_file_sync_with_config,
+    kreuzberg_batch_extract_bytes_sync, kreuzberg_batch_extract_files_sync, kreuzberg_batch_result_errors,
+    kreuzberg_extract_bytes_sync, kreuzberg_extract_bytes_sync_with_config, kreuzberg_extract_bytes_with_mime_sync,
+    kreuzberg_extract_file_sync, kreuzberg_extract_file_sync_with_config, kreuzberg_pdf_page_count,
+};
+pub use extractor::{
+    KreuzbergExtractor, kreuzberg_extractor_extract_file, kreuzberg_extractor_free, kreuzberg_extractor_new,
 };
 pub use helpers::*;
 pub use html_options::{
@@ -58,8 +66,9 @@ pub use html_options::{
 };
 pub use memory::{kreuzberg_clone_string, kreuzberg_free_batch_result, kreuzberg_free_result, kreuzberg_free_string};
 pub use mime::{
-    kreuzberg_detect_mime_type, kreuzberg_detect_mime_type_from_bytes, kreuzberg_detect_mime_type_from_path,
-    kreuzberg_get_extensions_for_mime, kreuzberg_validate_mime_type,
+    kreuzberg_detect_mime_bytes, kreuzberg_detect_mime_file, kreuzberg_detect_mime_type,
+    kreuzberg_detect_mime_type_from_bytes, kreuzberg_detect_mime_type_from_path, kreuzberg_get_extensions_for_mime,
+    kreuzberg_validate_mime_type,
 };
 pub use panic_shield::{
     ErrorCode, StructuredError, clear_structured_error, get_last_error_code, get_last_error_message,
@@ -82,7 +91,12 @@ pub use string_intern::{
     kreuzberg_string_intern_stats,
 };
 pub use types::*;
-pub use util::{kreuzberg_last_error, kreuzberg_last_error_code, kreuzberg_last_panic_context, kreuzberg_version};
+pub use util::{
+    KREUZBERG_ABI_VERSION, kreuzberg_abi_version, kreuzberg_binding_version, kreuzberg_check_compatibility,
+    kreuzberg_core_version, kreuzberg_last_error, kreuzberg_last_error_code, kreuzberg_last_panic_context,
+    kreuzberg_sizeof_batch_result, kreuzberg_sizeof_bytes_with_mime, kreuzberg_sizeof_extraction_result,
+    kreuzberg_version,
+};
 pub use validation::*;
 
 #[cfg(test)]
@@ -134,8 +148,8 @@ mod tests {
         // Test size
         assert_eq!(
             std::mem::size_of::<CExtractionResult>(),
-            112,
-            "CExtractionResult must be exactly 112 bytes"
+            120,
+            "CExtractionResult must be exactly 120 bytes"
         );
 
         // Test alignment
@@ -151,8 +165,8 @@ mod tests {
         // Test size
         assert_eq!(
             std::mem::size_of::<CBatchResult>(),
-            24,
-            "CBatchResult must be exactly 24 bytes"
+            48,
+            "CBatchResult must be exactly 48 bytes"
         );
 
         // Test alignment
@@ -200,6 +214,7 @@ mod tests {
             elements_json: ptr::null_mut(),
             success: true,
             _padding1: [0u8; 7],
+            links_json: ptr::null_mut(),
         }))
     }
 
@@ -224,6 +239,9 @@ mod tests {
                 count: actual_count,
                 success: true,
                 _padding2: [0u8; 7],
+                success_count: actual_count,
+                failure_count: 0,
+                errors: ptr::null_mut(),
             }));
 
             // Verify the batch result is valid
@@ -275,6 +293,9 @@ mod tests {
                 count: 0,
                 success: true,
                 _padding2: [0u8; 7],
+                success_count: 0,
+                failure_count: 0,
+                errors: ptr::null_mut(),
             }));
 
             assert!(!batch_result.is_null());
@@ -304,6 +325,9 @@ mod tests {
                 count: actual_count,
                 success: true,
                 _padding2: [0u8; 7],
+                success_count: actual_count,
+                failure_count: 0,
+                errors: ptr::null_mut(),
             }));
 
             // Free should handle NULL elements gracefully
@@ -327,6 +351,9 @@ mod tests {
                 count: actual_count,
                 success: true,
                 _padding2: [0u8; 7],
+                success_count: actual_count,
+                failure_count: 0,
+                errors: ptr::null_mut(),
             }));
 
             // Verify the batch result is valid
@@ -359,6 +386,9 @@ mod tests {
                 count: actual_count,
                 success: true,
                 _padding2: [0u8; 7],
+                success_count: actual_count,
+                failure_count: 0,
+                errors: ptr::null_mut(),
             }));
 
             // Verify the batch result is valid
@@ -494,6 +524,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_batch_result_summarizes_failures() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let present = temp_dir.path().join("present.txt");
+        std::fs::write(&present, "hello batch").unwrap();
+        let missing = temp_dir.path().join("missing.txt");
+
+        let path1 = CString::new(present.to_str().unwrap()).unwrap();
+        let path2 = CString::new(missing.to_str().unwrap()).unwrap();
+        let paths = [path1.as_ptr(), path2.as_ptr()];
+
+        unsafe {
+            let batch = kreuzberg_batch_extract_files_sync(paths.as_ptr(), 2, ptr::null());
+            assert!(!batch.is_null());
+
+            assert_eq!((*batch).count, 2);
+            assert_eq!((*batch).success_count, 1);
+            assert_eq!((*batch).failure_count, 1);
+            let results = std::slice::from_raw_parts((*batch).results, (*batch).count);
+            let failed = results.iter().filter(|&&result| !(*result).success).count();
+            assert_eq!(failed, (*batch).failure_count);
+
+            let errors = kreuzberg_batch_result_errors(batch);
+            assert!(!errors.is_null());
+            let summary = CStr::from_ptr(errors).to_str().unwrap().to_string();
+            kreuzberg_free_string(errors);
+            assert!(summary.contains(missing.to_str().unwrap()));
+            assert!(!summary.contains(present.to_str().unwrap()));
+
+            kreuzberg_free_batch_result(batch);
+        }
+    }
+
     #[test]
     fn test_extraction_result_free_with_null_fields() {
         unsafe {
@@ -514,6 +577,7 @@ mod tests {
                 elements_json: ptr::null_mut(),
                 success: true,
                 _padding1: [0u8; 7],
+                links_json: ptr::null_mut(),
             }));
 
             // Should not crash when freeing result with NULL fields
@@ -542,6 +606,7 @@ mod tests {
                 elements_json: CString::new("[]").unwrap().into_raw(),
                 success: true,
                 _padding1: [0u8; 7],
+                links_json: ptr::null_mut(),
             }));
 
             // Should properly free all 13 allocated string fields without leaking memory
@@ -595,6 +660,9 @@ mod tests {
                 count: 0,
                 success: true,
                 _padding2: [0u8; 7],
+                success_count: 0,
+                failure_count: 0,
+                errors: ptr::null_mut(),
             }));
 
             assert!((*batch_result).success, "Success field should be true");
@@ -624,14 +692,14 @@ mod tests {
     /// Test CExtractionResult size exactly matches FFI contract
     #[test]
     fn test_c_extraction_result_size() {
-        assert_eq!(std::mem::size_of::<CExtractionResult>(), 112);
+        assert_eq!(std::mem::size_of::<CExtractionResult>(), 120);
         assert_eq!(std::mem::align_of::<CExtractionResult>(), 8);
     }
 
     /// Test CBatchResult size exactly matches FFI contract
     #[test]
     fn test_c_batch_result_size() {
-        assert_eq!(std::mem::size_of::<CBatchResult>(), 24);
+        assert_eq!(std::mem::size_of::<CBatchResult>(), 48);
         assert_eq!(std::mem::align_of::<CBatchResult>(), 8);
     }
 
@@ -662,6 +730,39 @@ mod tests {
         }
     }
 
+    /// Test that kreuzberg_extract_bytes_with_mime_sync dispatches on the given MIME type
+    #[test]
+    fn test_extract_bytes_with_mime_sync() {
+        unsafe {
+            let data = b"%PDF-1.4 but declared as text";
+            let mime = CString::new("text/plain; charset=utf-8").unwrap();
+            let result = kreuzberg_extract_bytes_with_mime_sync(data.as_ptr(), data.len(), mime.as_ptr(), ptr::null());
+            assert!(!result.is_null());
+            let content = CStr::from_ptr((*result).content).to_str().unwrap();
+            assert!(content.starts_with("%PDF-1.4"), "content: {}", content);
+            kreuzberg_free_result(result);
+
+            let mime = CString::new("application/x-no-such-format").unwrap();
+            let result = kreuzberg_extract_bytes_with_mime_sync(data.as_ptr(), data.len(), mime.as_ptr(), ptr::null());
+            assert!(result.is_null());
+            let error = CStr::from_ptr(kreuzberg_last_error()).to_str().unwrap();
+            assert!(error.contains("application/x-no-such-format"), "error: {}", error);
+        }
+    }
+
+    /// Test that kreuzberg_pdf_page_count rejects NULL and non-PDF input
+    #[test]
+    fn test_pdf_page_count_invalid_input() {
+        unsafe {
+            assert_eq!(kreuzberg_pdf_page_count(ptr::null(), 0), -1);
+
+            let data = b"not a pdf";
+            assert_eq!(kreuzberg_pdf_page_count(data.as_ptr(), data.len()), -1);
+            let error = CStr::from_ptr(kreuzberg_last_error()).to_str().unwrap();
+            assert!(error.contains("Invalid PDF"), "error: {}", error);
+        }
+    }
+
     /// Test that kreuzberg_batch_extract_files_sync handles NULL paths pointer
     #[test]
     fn test_batch_extract_null_paths() {
@@ -869,6 +970,22 @@ mod tests {
         }
     }
 
+    /// Test registering the built-in min-text-length validator
+    #[test]
+    fn test_register_min_text_length_validator() {
+        unsafe {
+            assert!(kreuzberg_register_min_text_length_validator(50));
+
+            let list = kreuzberg_list_validators();
+            assert!(!list.is_null());
+            let names = CStr::from_ptr(list).to_str().unwrap().to_string();
+            kreuzberg_free_string(list);
+            assert!(names.contains("min-text-length"), "names: {}", names);
+
+            assert!(kreuzberg_unregister_validator(c"min-text-length".as_ptr()));
+        }
+    }
+
     /// Test clearing validators doesn't crash
     #[test]
     fn test_clear_validators_doesnt_crash() {
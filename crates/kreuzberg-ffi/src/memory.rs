@@ -30,7 +30,7 @@ use crate::types::{CBatchResult, CExtractionResult};
 /// - `batch_result` must be a pointer previously returned by a batch extraction function
 /// - `batch_result` can be NULL (no-op)
 /// - `batch_result` must not be used after this call
-/// - All individual results in the batch will be freed automatically
+/// - All individual results in the batch and its `errors` summary will be freed automatically
 ///
 /// # Memory Layout
 ///
@@ -54,6 +54,10 @@ pub unsafe extern "C" fn kreuzberg_free_batch_result(batch_result: *mut CBatchRe
 
     let batch = unsafe { Box::from_raw(batch_result) };
 
+    if !batch.errors.is_null() {
+        unsafe { kreuzberg_free_string(batch.errors) };
+    }
+
     // Free individual results first, then the array
     if !batch.results.is_null() {
         if batch.count > 0 {
@@ -160,6 +164,7 @@ pub unsafe extern "C" fn kreuzberg_clone_string(s: *const c_char) -> *mut c_char
 /// 11. page_structure_json (FIXED: was missing before PR #3)
 /// 12. pages_json (FIXED: was missing before PR #3)
 /// 13. elements_json (ADDED: for element-based extraction support)
+/// 14. links_json
 ///
 /// # Example (C)
 ///
@@ -213,6 +218,9 @@ pub unsafe extern "C" fn kreuzberg_free_result(result: *mut CExtractionResult) {
         if !result_box.elements_json.is_null() {
             unsafe { drop(CString::from_raw(result_box.elements_json)) };
         }
+        if !result_box.links_json.is_null() {
+            unsafe { drop(CString::from_raw(result_box.links_json)) };
+        }
     }
 }
 
@@ -239,6 +247,7 @@ mod tests {
             elements_json: CString::new("[]").unwrap().into_raw(),
             success: true,
             _padding1: [0u8; 7],
+            links_json: ptr::null_mut(),
         }))
     }
 
@@ -260,6 +269,7 @@ mod tests {
             elements_json: ptr::null_mut(),
             success: true,
             _padding1: [0u8; 7],
+            links_json: ptr::null_mut(),
         }))
     }
 
@@ -352,6 +362,7 @@ mod tests {
             elements_json: ptr::null_mut(),
             success: true,
             _padding1: [0u8; 7],
+            links_json: ptr::null_mut(),
         }));
 
         unsafe { kreuzberg_free_result(result) };
@@ -379,6 +390,7 @@ mod tests {
                 .into_raw(),
             success: true,
             _padding1: [0u8; 7],
+            links_json: ptr::null_mut(),
         }));
 
         unsafe { kreuzberg_free_result(result) };
@@ -399,6 +411,9 @@ mod tests {
             count: 0,
             success: true,
             _padding2: [0u8; 7],
+            success_count: 0,
+            failure_count: 0,
+            errors: ptr::null_mut(),
         }));
 
         unsafe { kreuzberg_free_batch_result(batch) };
@@ -416,6 +431,9 @@ mod tests {
             count: 1,
             success: true,
             _padding2: [0u8; 7],
+            success_count: 1,
+            failure_count: 0,
+            errors: ptr::null_mut(),
         }));
 
         unsafe { kreuzberg_free_batch_result(batch) };
@@ -437,6 +455,9 @@ mod tests {
             count: 100,
             success: true,
             _padding2: [0u8; 7],
+            success_count: 100,
+            failure_count: 0,
+            errors: ptr::null_mut(),
         }));
 
         unsafe { kreuzberg_free_batch_result(batch) };
@@ -464,6 +485,9 @@ mod tests {
             count,
             success: true,
             _padding2: [0u8; 7],
+            success_count: count,
+            failure_count: 0,
+            errors: ptr::null_mut(),
         }));
 
         // This should NOT segfault
@@ -488,6 +512,9 @@ mod tests {
             count: 4,
             success: true,
             _padding2: [0u8; 7],
+            success_count: 2,
+            failure_count: 0,
+            errors: ptr::null_mut(),
         }));
 
         unsafe { kreuzberg_free_batch_result(batch) };
@@ -519,6 +546,9 @@ mod tests {
                 count: 10,
                 success: true,
                 _padding2: [0u8; 7],
+                success_count: 10,
+                failure_count: 0,
+                errors: ptr::null_mut(),
             }));
 
             unsafe { kreuzberg_free_batch_result(batch) };
@@ -7,10 +7,20 @@
 
 use crate::ffi_panic_guard;
 use crate::helpers::{clear_last_error, set_last_error, string_to_c_string};
+use kreuzberg::core::mime::MimePolicy;
 use std::ffi::CStr;
+use std::io::Read;
 use std::os::raw::c_char;
 use std::ptr;
 
+/// MIME type returned when content is readable but cannot be identified.
+const OCTET_STREAM_MIME_TYPE: &str = "application/octet-stream";
+
+/// Number of leading bytes read from a file to detect its content type.
+///
+/// Large enough to cover magic bytes and the ZIP entry names of Office Open XML files.
+const MIME_SNIFF_BYTES: u64 = 64 * 1024;
+
 /// Detect MIME type from a file path.
 ///
 /// # Safety
@@ -194,6 +204,119 @@ pub unsafe extern "C" fn kreuzberg_detect_mime_type_from_path(file_path: *const
     })
 }
 
+/// Detect the MIME type of a file on disk, falling back to `application/octet-stream`.
+///
+/// The first 64 KiB of the file are read and their content type and the extension are
+/// compared, with the detected content type winning when they disagree. Unlike `kreuzberg_detect_mime_type_from_path`,
+/// a readable file whose type cannot be identified yields `application/octet-stream`
+/// instead of an error.
+///
+/// # Safety
+///
+/// - `path` must be a valid null-terminated C string
+/// - The returned string must be freed with `kreuzberg_free_string`
+/// - Returns NULL if the file cannot be read (check `kreuzberg_last_error`)
+///
+/// # Example (C)
+///
+/// ```c
+/// char* mime = kreuzberg_detect_mime_file("upload.bin");
+/// if (mime == NULL) {
+///     printf("Cannot read file: %s\n", kreuzberg_last_error());
+/// } else {
+///     printf("MIME type: %s\n", mime);
+///     kreuzberg_free_string(mime);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_detect_mime_file(path: *const c_char) -> *mut c_char {
+    ffi_panic_guard!("kreuzberg_detect_mime_file", {
+        clear_last_error();
+
+        if path.is_null() {
+            set_last_error("path cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        let path_str = match unsafe { CStr::from_ptr(path) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in file path: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let content = match std::fs::File::open(path_str).and_then(|file| {
+            let mut content = Vec::new();
+            file.take(MIME_SNIFF_BYTES).read_to_end(&mut content)?;
+            Ok(content)
+        }) {
+            Ok(content) => content,
+            Err(e) => {
+                set_last_error(format!("Failed to read file '{}': {}", path_str, e));
+                return ptr::null_mut();
+            }
+        };
+
+        let mime = kreuzberg::core::mime::detect_mime(path_str, &content, MimePolicy::PreferContent)
+            .map(|detection| detection.mime_type)
+            .unwrap_or_else(|_| OCTET_STREAM_MIME_TYPE.to_string());
+
+        match string_to_c_string(mime) {
+            Ok(ptr) => ptr,
+            Err(e) => {
+                set_last_error(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Detect the MIME type of an in-memory buffer, falling back to `application/octet-stream`.
+///
+/// Unlike `kreuzberg_detect_mime_type_from_bytes`, content whose type cannot be
+/// identified yields `application/octet-stream` instead of an error.
+///
+/// # Safety
+///
+/// - `data` must point to a valid buffer of at least `len` bytes
+/// - The returned string must be freed with `kreuzberg_free_string`
+/// - Returns NULL if `data` is NULL (check `kreuzberg_last_error`)
+///
+/// # Example (C)
+///
+/// ```c
+/// char* mime = kreuzberg_detect_mime_bytes(buffer, buffer_len);
+/// if (mime != NULL) {
+///     printf("MIME type: %s\n", mime);
+///     kreuzberg_free_string(mime);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_detect_mime_bytes(data: *const u8, len: usize) -> *mut c_char {
+    ffi_panic_guard!("kreuzberg_detect_mime_bytes", {
+        clear_last_error();
+
+        if data.is_null() {
+            set_last_error("data cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        let slice = unsafe { std::slice::from_raw_parts(data, len) };
+
+        let mime = kreuzberg::core::mime::detect_mime_type_from_bytes(slice)
+            .unwrap_or_else(|_| OCTET_STREAM_MIME_TYPE.to_string());
+
+        match string_to_c_string(mime) {
+            Ok(ptr) => ptr,
+            Err(e) => {
+                set_last_error(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
 /// Get file extensions for a MIME type.
 ///
 /// Returns a JSON array of file extensions (e.g., ["pdf"] for "application/pdf").
@@ -313,6 +436,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_detect_mime_file_larger_than_sniff_prefix() {
+        let mut file = tempfile::Builder::new().suffix(".bin").tempfile().unwrap();
+        std::io::Write::write_all(&mut file, b"%PDF-1.4\n").unwrap();
+        std::io::Write::write_all(&mut file, &vec![b' '; 2 * MIME_SNIFF_BYTES as usize]).unwrap();
+        let path = CString::new(file.path().to_str().unwrap()).unwrap();
+
+        let result = unsafe { kreuzberg_detect_mime_file(path.as_ptr()) };
+        assert!(!result.is_null());
+
+        let mime_str = unsafe { CStr::from_ptr(result).to_str().unwrap() };
+        assert_eq!(mime_str, "application/pdf");
+
+        unsafe {
+            crate::kreuzberg_free_string(result);
+        }
+    }
+
     #[test]
     fn test_get_extensions_for_mime_pdf() {
         let mime = CString::new("application/pdf").unwrap();
@@ -326,4 +467,68 @@ mod tests {
             crate::kreuzberg_free_string(result);
         }
     }
+
+    #[test]
+    fn test_detect_mime_file_pdf() {
+        let mut file = tempfile::Builder::new().suffix(".pdf").tempfile().unwrap();
+        std::io::Write::write_all(&mut file, b"%PDF-1.4\n%%EOF\n").unwrap();
+        let path = CString::new(file.path().to_str().unwrap()).unwrap();
+
+        let result = unsafe { kreuzberg_detect_mime_file(path.as_ptr()) };
+        assert!(!result.is_null());
+
+        let mime_str = unsafe { CStr::from_ptr(result).to_str().unwrap() };
+        assert_eq!(mime_str, "application/pdf");
+
+        unsafe {
+            crate::kreuzberg_free_string(result);
+        }
+    }
+
+    #[test]
+    fn test_detect_mime_file_nonexistent() {
+        let path = CString::new("/nonexistent/path/document.pdf").unwrap();
+
+        let result = unsafe { kreuzberg_detect_mime_file(path.as_ptr()) };
+        assert!(result.is_null());
+
+        let error = unsafe { crate::kreuzberg_last_error() };
+        assert!(!error.is_null());
+        let error_str = unsafe { CStr::from_ptr(error).to_str().unwrap() };
+        assert!(error_str.contains("Failed to read file"));
+    }
+
+    #[test]
+    fn test_detect_mime_bytes_pdf() {
+        let pdf_bytes = b"%PDF-1.7\n1 0 obj\n";
+        let result = unsafe { kreuzberg_detect_mime_bytes(pdf_bytes.as_ptr(), pdf_bytes.len()) };
+        assert!(!result.is_null());
+
+        let mime_str = unsafe { CStr::from_ptr(result).to_str().unwrap() };
+        assert_eq!(mime_str, "application/pdf");
+
+        unsafe {
+            crate::kreuzberg_free_string(result);
+        }
+    }
+
+    #[test]
+    fn test_detect_mime_bytes_unknown_is_octet_stream() {
+        let bytes = [0x00u8, 0xFF, 0xFE, 0x80, 0x81, 0x00, 0x9C];
+        let result = unsafe { kreuzberg_detect_mime_bytes(bytes.as_ptr(), bytes.len()) };
+        assert!(!result.is_null());
+
+        let mime_str = unsafe { CStr::from_ptr(result).to_str().unwrap() };
+        assert_eq!(mime_str, "application/octet-stream");
+
+        unsafe {
+            crate::kreuzberg_free_string(result);
+        }
+    }
+
+    #[test]
+    fn test_detect_mime_bytes_null() {
+        let result = unsafe { kreuzberg_detect_mime_bytes(ptr::null(), 0) };
+        assert!(result.is_null());
+    }
 }
@@ -92,6 +92,11 @@ pub fn clear_structured_error() {
     LAST_STRUCTURED_ERROR.with(|last| *last.borrow_mut() = None);
 }
 
+/// Removes and returns the last structured error, leaving none set.
+pub(crate) fn take_structured_error() -> Option<StructuredError> {
+    LAST_STRUCTURED_ERROR.with(|last| last.borrow_mut().take())
+}
+
 /// Macro to wrap FFI functions with panic catching.
 ///
 /// This macro catches panics at FFI boundaries and converts them to structured errors.
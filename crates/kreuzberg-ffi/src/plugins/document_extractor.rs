@@ -14,7 +14,7 @@ use kreuzberg::plugins::Plugin;
 use kreuzberg::types::ExtractionResult;
 use kreuzberg::{KreuzbergError, Result};
 
-use crate::helpers::{clear_last_error, set_last_error};
+use crate::helpers::{call_reentrant, clear_last_error, set_last_error};
 use crate::{ffi_panic_guard, ffi_panic_guard_bool};
 
 /// Type alias for the DocumentExtractor callback function.
@@ -138,14 +138,14 @@ impl kreuzberg::plugins::DocumentExtractor for FfiDocumentExtractor {
                 }
             };
 
-            let result_ptr = unsafe {
+            let result_ptr = call_reentrant(|| unsafe {
                 callback(
                     content_vec.as_ptr(),
                     content_vec.len(),
                     mime_cstr.as_ptr(),
                     config_cstr.as_ptr(),
                 )
-            };
+            });
 
             if result_ptr.is_null() {
                 return Err(KreuzbergError::Parsing {
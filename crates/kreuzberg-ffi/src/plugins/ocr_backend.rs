@@ -14,7 +14,7 @@ use kreuzberg::plugins::{OcrBackend, Plugin};
 use kreuzberg::types::ExtractionResult;
 use kreuzberg::{KreuzbergError, Result};
 
-use crate::helpers::{clear_last_error, set_last_error};
+use crate::helpers::{call_reentrant, clear_last_error, set_last_error};
 use crate::memory::kreuzberg_free_string;
 // Macros are exported at the crate root due to #[macro_export]
 use crate::{ffi_panic_guard, ffi_panic_guard_bool, ffi_panic_guard_i32};
@@ -130,7 +130,8 @@ impl OcrBackend for FfiOcrBackend {
                 source: Some(Box::new(e)),
             })?;
 
-            let result_ptr = unsafe { callback(image_data.as_ptr(), image_data.len(), config_cstring.as_ptr()) };
+            let result_ptr =
+                call_reentrant(|| unsafe { callback(image_data.as_ptr(), image_data.len(), config_cstring.as_ptr()) });
 
             if result_ptr.is_null() {
                 return Err(KreuzbergError::Ocr {
@@ -166,7 +167,11 @@ impl OcrBackend for FfiOcrBackend {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         })
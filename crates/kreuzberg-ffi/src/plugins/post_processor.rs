@@ -14,7 +14,7 @@ use kreuzberg::plugins::{Plugin, ProcessingStage};
 use kreuzberg::types::ExtractionResult;
 use kreuzberg::{KreuzbergError, Result};
 
-use crate::helpers::{clear_last_error, set_last_error};
+use crate::helpers::{call_reentrant, clear_last_error, set_last_error};
 use crate::memory::kreuzberg_free_string;
 use crate::{ffi_panic_guard, ffi_panic_guard_bool};
 
@@ -90,7 +90,7 @@ impl kreuzberg::plugins::PostProcessor for FfiPostProcessor {
                 source: Some(Box::new(e)),
             })?;
 
-            let processed_ptr = unsafe { callback(result_cstring.as_ptr()) };
+            let processed_ptr = call_reentrant(|| unsafe { callback(result_cstring.as_ptr()) });
 
             if processed_ptr.is_null() {
                 return Err(KreuzbergError::Plugin {
@@ -13,7 +13,7 @@ use kreuzberg::plugins::Plugin;
 use kreuzberg::types::ExtractionResult;
 use kreuzberg::{KreuzbergError, Result};
 
-use crate::helpers::{clear_last_error, set_last_error};
+use crate::helpers::{call_reentrant, clear_last_error, set_last_error};
 use crate::{ffi_panic_guard, ffi_panic_guard_bool};
 
 /// Validator callback function type for FFI.
@@ -90,7 +90,7 @@ impl kreuzberg::plugins::Validator for FfiValidator {
                 source: Some(Box::new(e)),
             })?;
 
-            let error_ptr = unsafe { callback(result_cstring.as_ptr()) };
+            let error_ptr = call_reentrant(|| unsafe { callback(result_cstring.as_ptr()) });
 
             if error_ptr.is_null() {
                 return Ok::<Option<String>, KreuzbergError>(None);
@@ -211,6 +211,48 @@ pub unsafe extern "C" fn kreuzberg_register_validator(
     })
 }
 
+/// Register the built-in minimum text length validator.
+///
+/// Adds a `validation` warning to the result's warnings when the
+/// extracted text has fewer than `min_length` characters. Extraction never fails.
+///
+/// # Safety
+///
+/// - Returns true on success, false on error (check kreuzberg_last_error)
+/// - Replaces a previously registered `min-text-length` validator
+///
+/// # Example (C)
+///
+/// ```c
+/// if (!kreuzberg_register_min_text_length_validator(50)) {
+///     printf("Failed to register: %s\n", kreuzberg_last_error());
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_register_min_text_length_validator(min_length: usize) -> bool {
+    ffi_panic_guard_bool!("kreuzberg_register_min_text_length_validator", {
+        clear_last_error();
+
+        let registry = kreuzberg::plugins::registry::get_validator_registry();
+        let mut registry_guard = match registry.write() {
+            Ok(guard) => guard,
+            Err(e) => {
+                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+                set_last_error(format!("Failed to acquire registry write lock: {}", e));
+                return false;
+            }
+        };
+
+        match registry_guard.register(kreuzberg::plugins::min_text_length_validator(min_length)) {
+            Ok(()) => true,
+            Err(e) => {
+                set_last_error(format!("Failed to register Validator: {}", e));
+                false
+            }
+        }
+    })
+}
+
 /// Unregister a Validator by name.
 ///
 /// # Safety
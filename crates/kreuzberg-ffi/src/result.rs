@@ -8,7 +8,7 @@
 //! with `kreuzberg_free_string()`.
 
 use crate::{clear_last_error, ffi_panic_guard, set_last_error};
-use kreuzberg::types::ExtractionResult;
+use kreuzberg::types::{ExtractionResult, ExtractionWarningKind};
 use std::ffi::CString;
 use std::os::raw::c_char;
 use std::ptr;
@@ -191,6 +191,186 @@ pub unsafe extern "C" fn kreuzberg_result_get_detected_language(result: *const E
     })
 }
 
+/// Get validator warnings from extraction result.
+///
+/// Returns the messages of the result's `validation` warnings (non-fatal warnings
+/// attached by validators, see `kreuzberg::plugins::ValidationOutcome`) as a JSON
+/// array of strings.
+///
+/// # Arguments
+///
+/// * `result` - Pointer to an ExtractionResult structure
+///
+/// # Returns
+///
+/// A pointer to a C string containing a JSON array (e.g., `["low text density"]`),
+/// or NULL if no warnings were reported or on error (check `kreuzberg_last_error`).
+///
+/// The returned pointer must be freed with `kreuzberg_free_string()`.
+///
+/// # Safety
+///
+/// - `result` must be a valid pointer to an ExtractionResult
+/// - `result` cannot be NULL
+/// - The returned pointer (if non-NULL) must be freed with `kreuzberg_free_string`
+///
+/// # Example (C)
+///
+/// ```c
+/// ExtractionResult* result = kreuzberg_extract_file("document.pdf", NULL);
+/// if (result != NULL) {
+///     char* warnings = kreuzberg_result_get_validation_warnings(result);
+///     if (warnings != NULL) {
+///         printf("Validation warnings: %s\n", warnings);
+///         kreuzberg_free_string(warnings);
+///     }
+///     kreuzberg_result_free(result);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_result_get_validation_warnings(result: *const ExtractionResult) -> *mut c_char {
+    ffi_panic_guard!("kreuzberg_result_get_validation_warnings", {
+        if result.is_null() {
+            set_last_error("Result cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        clear_last_error();
+
+        let result_ref = unsafe { &*result };
+
+        let warnings: Vec<&str> = result_ref
+            .warnings
+            .iter()
+            .filter(|warning| warning.kind == ExtractionWarningKind::Validation)
+            .map(|warning| warning.message.as_str())
+            .collect();
+        if warnings.is_empty() {
+            return ptr::null_mut();
+        }
+
+        match CString::new(serde_json::Value::from(warnings).to_string()) {
+            Ok(c_string) => c_string.into_raw(),
+            Err(e) => {
+                set_last_error(format!("Failed to convert validation warnings to C string: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Get the number of extraction warnings in a result.
+///
+/// # Arguments
+///
+/// * `result` - Pointer to an ExtractionResult structure
+///
+/// # Returns
+///
+/// The warning count (>= 0) if successful, or -1 on error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - `result` must be a valid pointer to an ExtractionResult
+/// - `result` cannot be NULL
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_result_get_warning_count(result: *const ExtractionResult) -> i32 {
+    ffi_panic_guard!(
+        "kreuzberg_result_get_warning_count",
+        {
+            if result.is_null() {
+                set_last_error("Result cannot be NULL".to_string());
+                return -1;
+            }
+
+            clear_last_error();
+
+            let result_ref = unsafe { &*result };
+
+            result_ref.warnings.len() as i32
+        },
+        -1
+    )
+}
+
+/// Get an extraction warning by index.
+///
+/// Returns the warning as a JSON object with a `kind` (e.g., `"missing_font"`) and a
+/// human-readable `message`.
+///
+/// # Arguments
+///
+/// * `result` - Pointer to an ExtractionResult structure
+/// * `index` - Zero-based warning index, less than `kreuzberg_result_get_warning_count`
+///
+/// # Returns
+///
+/// A pointer to a C string containing the JSON object, or NULL if the index is out of
+/// range or on error (check `kreuzberg_last_error`).
+///
+/// The returned pointer must be freed with `kreuzberg_free_string()`.
+///
+/// # Safety
+///
+/// - `result` must be a valid pointer to an ExtractionResult
+/// - `result` cannot be NULL
+/// - The returned pointer (if non-NULL) must be freed with `kreuzberg_free_string`
+///
+/// # Example (C)
+///
+/// ```c
+/// ExtractionResult* result = kreuzberg_extract_file("document.pdf", NULL);
+/// if (result != NULL) {
+///     int count = kreuzberg_result_get_warning_count(result);
+///     for (int i = 0; i < count; i++) {
+///         char* warning = kreuzberg_result_warning(result, i);
+///         if (warning != NULL) {
+///             printf("Warning: %s\n", warning);
+///             kreuzberg_free_string(warning);
+///         }
+///     }
+///     kreuzberg_result_free(result);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_result_warning(result: *const ExtractionResult, index: usize) -> *mut c_char {
+    ffi_panic_guard!("kreuzberg_result_warning", {
+        if result.is_null() {
+            set_last_error("Result cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        clear_last_error();
+
+        let result_ref = unsafe { &*result };
+
+        let Some(warning) = result_ref.warnings.get(index) else {
+            set_last_error(format!(
+                "Warning index {} out of range ({} warnings)",
+                index,
+                result_ref.warnings.len()
+            ));
+            return ptr::null_mut();
+        };
+
+        let json = match serde_json::to_string(warning) {
+            Ok(json) => json,
+            Err(e) => {
+                set_last_error(format!("Failed to serialize warning: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        match CString::new(json) {
+            Ok(c_string) => c_string.into_raw(),
+            Err(e) => {
+                set_last_error(format!("Failed to convert warning to C string: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
 /// Metadata field accessor structure
 ///
 /// Returned by `kreuzberg_result_get_metadata_field()`. Contains the field value
@@ -423,7 +603,11 @@ mod tests {
                 },
             ]),
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         }
@@ -490,6 +674,83 @@ mod tests {
         assert!(lang_ptr.is_null());
     }
 
+    #[test]
+    fn test_result_get_validation_warnings() {
+        use kreuzberg::types::ExtractionWarning;
+
+        let mut result = create_test_result();
+        result.warnings = vec![
+            ExtractionWarning::new(
+                ExtractionWarningKind::Truncated,
+                "Only the first 10 pages were extracted",
+            ),
+            ExtractionWarning::new(ExtractionWarningKind::Validation, "low text density"),
+        ];
+        let result_ptr = Box::into_raw(Box::new(result));
+
+        let warnings_ptr = unsafe { kreuzberg_result_get_validation_warnings(result_ptr) };
+        assert!(!warnings_ptr.is_null());
+
+        let warnings_str = unsafe { CStr::from_ptr(warnings_ptr).to_str().unwrap() };
+        assert_eq!(warnings_str, r#"["low text density"]"#);
+
+        unsafe {
+            crate::kreuzberg_free_string(warnings_ptr);
+            let _ = Box::from_raw(result_ptr);
+        }
+    }
+
+    #[test]
+    fn test_result_get_validation_warnings_none() {
+        let result = create_test_result();
+        let result_ptr = Box::into_raw(Box::new(result));
+
+        let warnings_ptr = unsafe { kreuzberg_result_get_validation_warnings(result_ptr) };
+        assert!(warnings_ptr.is_null());
+
+        unsafe {
+            let _ = Box::from_raw(result_ptr);
+        }
+    }
+
+    #[test]
+    fn test_result_warning() {
+        use kreuzberg::types::{ExtractionWarning, ExtractionWarningKind};
+
+        let mut result = create_test_result();
+        result.warnings.push(ExtractionWarning::new(
+            ExtractionWarningKind::MissingFont,
+            "Font 'Foo' is not embedded",
+        ));
+        let result_ptr = Box::into_raw(Box::new(result));
+
+        let count = unsafe { kreuzberg_result_get_warning_count(result_ptr) };
+        assert_eq!(count, 1);
+
+        let warning_ptr = unsafe { kreuzberg_result_warning(result_ptr, 0) };
+        assert!(!warning_ptr.is_null());
+
+        let warning_str = unsafe { CStr::from_ptr(warning_ptr).to_str().unwrap() };
+        assert_eq!(
+            warning_str,
+            r#"{"kind":"missing_font","message":"Font 'Foo' is not embedded"}"#
+        );
+
+        let out_of_range = unsafe { kreuzberg_result_warning(result_ptr, 1) };
+        assert!(out_of_range.is_null());
+
+        unsafe {
+            crate::kreuzberg_free_string(warning_ptr);
+            let _ = Box::from_raw(result_ptr);
+        }
+    }
+
+    #[test]
+    fn test_result_warning_null() {
+        assert_eq!(unsafe { kreuzberg_result_get_warning_count(ptr::null()) }, -1);
+        assert!(unsafe { kreuzberg_result_warning(ptr::null(), 0) }.is_null());
+    }
+
     #[test]
     fn test_result_get_metadata_field_title() {
         let result = create_test_result();
@@ -510,6 +771,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_result_get_metadata_field_keywords() {
+        let mut result = create_test_result();
+        result.metadata.keywords = Some(vec!["invoice".to_string(), "finance".to_string()]);
+        let result_ptr = Box::into_raw(Box::new(result));
+
+        let field_name = std::ffi::CString::new("keywords").unwrap();
+        let field = unsafe { kreuzberg_result_get_metadata_field(result_ptr, field_name.as_ptr()) };
+
+        assert_eq!(field.is_null, 0);
+        let value_str = unsafe { CStr::from_ptr(field.json_value).to_str().unwrap() };
+        assert_eq!(value_str, r#"["invoice","finance"]"#);
+
+        unsafe {
+            crate::kreuzberg_free_string(field.json_value);
+            let _ = Box::from_raw(result_ptr);
+        }
+    }
+
     #[test]
     fn test_result_get_metadata_field_missing() {
         let result = create_test_result();
@@ -453,7 +453,11 @@ mod tests {
                 },
             ]),
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         }
@@ -719,7 +723,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -3,6 +3,8 @@
 //! This module contains all C-compatible struct definitions used across the FFI boundary.
 //! These types must maintain strict memory layout guarantees to ensure compatibility with
 //! other languages (Java via Panama FFI, Go via cgo, C# via P/Invoke, etc.).
+//!
+//! Any layout change to these structs must bump `KREUZBERG_ABI_VERSION` in `util.rs`.
 
 use std::ffi::CString;
 use std::os::raw::c_char;
@@ -51,7 +53,9 @@ impl Drop for CStringGuard {
 /// # Memory Layout
 ///
 /// Must be kept in sync with the Java side's MemoryLayout definition in KreuzbergFFI.java
-/// Field order: 13 pointers (8 bytes each) + 1 bool + 7 bytes padding = 112 bytes total
+/// Field order: 13 pointers (8 bytes each) + 1 bool + 7 bytes padding + 1 pointer = 120 bytes total
+///
+/// `links_json` follows the original 112-byte prefix, so readers of the prefix keep working.
 ///
 /// The `#[repr(C)]` attribute ensures the struct follows C's memory layout rules:
 /// - Fields are laid out in order
@@ -94,6 +98,8 @@ pub struct CExtractionResult {
     pub success: bool,
     /// Padding to match Java MemoryLayout (7 bytes padding to align to 8-byte boundary)
     pub _padding1: [u8; 7],
+    /// Hyperlinks and image references as JSON array (null-terminated string, or NULL if none, must be freed with kreuzberg_free_string)
+    pub links_json: *mut c_char,
 }
 
 /// C-compatible structure for passing byte array with MIME type in batch operations
@@ -124,14 +130,17 @@ pub struct CBytesWithMime {
 /// # Memory Layout
 ///
 /// Must be kept in sync with the Java side's MemoryLayout definition in KreuzbergFFI.java
-/// Field order: 1 pointer (8 bytes) + 1 usize (8 bytes) + 1 bool + 7 bytes padding = 24 bytes total
+/// Field order: 1 pointer (8 bytes) + 1 usize (8 bytes) + 1 bool + 7 bytes padding
+/// + 2 usize (16 bytes) + 1 pointer (8 bytes) = 48 bytes total
 ///
-/// The padding ensures the struct is properly aligned for 64-bit architectures.
+/// The padding ensures the struct is properly aligned for 64-bit architectures. The summary
+/// fields follow the original 24-byte prefix, so readers of the prefix keep working.
 ///
 /// # Memory Management
 ///
 /// - The `results` array must be freed using `kreuzberg_free_batch_result`
 /// - Each individual result in the array must also be freed
+/// - `errors` is owned by the batch and freed with it
 #[repr(C)]
 pub struct CBatchResult {
     /// Array of extraction results
@@ -142,6 +151,13 @@ pub struct CBatchResult {
     pub success: bool,
     /// Padding to match Java MemoryLayout (7 bytes padding to align to 8-byte boundary)
     pub _padding2: [u8; 7],
+    /// Number of results whose `success` flag is set
+    pub success_count: usize,
+    /// Number of results whose `success` flag is cleared (the item failed to extract)
+    pub failure_count: usize,
+    /// Newline-joined `<item>: <error>` lines for failed items, or NULL if none failed.
+    /// Owned by the batch; use `kreuzberg_batch_result_errors` for a copy that outlives it.
+    pub errors: *mut c_char,
 }
 
 /// Compile-time layout assertions to ensure ABI stability.
@@ -152,7 +168,7 @@ pub struct CBatchResult {
 const _: () = {
     const fn assert_c_extraction_result_size() {
         const SIZE: usize = std::mem::size_of::<CExtractionResult>();
-        const _: () = assert!(SIZE == 112, "CExtractionResult size must be 112 bytes");
+        const _: () = assert!(SIZE == 120, "CExtractionResult size must be 120 bytes");
     }
 
     const fn assert_c_extraction_result_alignment() {
@@ -162,7 +178,7 @@ const _: () = {
 
     const fn assert_c_batch_result_size() {
         const SIZE: usize = std::mem::size_of::<CBatchResult>();
-        const _: () = assert!(SIZE == 24, "CBatchResult size must be 24 bytes");
+        const _: () = assert!(SIZE == 48, "CBatchResult size must be 48 bytes");
     }
 
     const fn assert_c_batch_result_alignment() {
@@ -197,8 +213,8 @@ mod tests {
     fn test_c_extraction_result_size() {
         assert_eq!(
             std::mem::size_of::<CExtractionResult>(),
-            112,
-            "CExtractionResult must be exactly 112 bytes"
+            120,
+            "CExtractionResult must be exactly 120 bytes"
         );
     }
 
@@ -217,8 +233,8 @@ mod tests {
     fn test_c_batch_result_size() {
         assert_eq!(
             std::mem::size_of::<CBatchResult>(),
-            24,
-            "CBatchResult must be exactly 24 bytes"
+            48,
+            "CBatchResult must be exactly 48 bytes"
         );
     }
 
@@ -341,6 +357,9 @@ mod tests {
         assert_eq!(offset_of!(CBatchResult, results), 0);
         assert_eq!(offset_of!(CBatchResult, count), 8);
         assert_eq!(offset_of!(CBatchResult, success), 16);
+        assert_eq!(offset_of!(CBatchResult, success_count), 24);
+        assert_eq!(offset_of!(CBatchResult, failure_count), 32);
+        assert_eq!(offset_of!(CBatchResult, errors), 40);
     }
 
     /// Verify field offsets in CBytesWithMime match expectations
@@ -1,15 +1,29 @@
 //! Utility functions for version and error reporting.
 //!
 //! This module provides FFI functions for:
-//! - Getting the library version
+//! - Getting the library version and ABI version
+//! - Checking that a binding version is compatible with the core library
+//! - Querying the sizes of `#[repr(C)]` structs for layout checks
 //! - Retrieving error information (message, code, panic context)
 
 use crate::ffi_panic_guard;
-use crate::helpers::LAST_ERROR_C_STRING;
+use crate::helpers::{LAST_ERROR_C_STRING, set_last_error};
 use crate::panic_shield::{get_last_error_code, get_last_panic_context};
-use std::ffi::CString;
+use crate::types::{CBatchResult, CBytesWithMime, CExtractionResult};
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use std::ptr;
+use std::sync::LazyLock;
+
+/// ABI version of the C interface.
+///
+/// Incremented whenever the layout of any `#[repr(C)]` struct crossing the FFI
+/// boundary changes. Callers should compare this header constant with
+/// `kreuzberg_abi_version()` at startup and refuse to run on a mismatch.
+pub const KREUZBERG_ABI_VERSION: u32 = 3;
+
+static CORE_VERSION: LazyLock<CString> =
+    LazyLock::new(|| CString::new(kreuzberg::VERSION).expect("version contains no NUL bytes"));
 
 /// Get the last error message from a failed operation.
 ///
@@ -19,6 +33,13 @@ use std::ptr;
 /// - Returns NULL if no error has occurred
 /// - The returned string is valid until the next Kreuzberg function call on the same thread
 ///
+/// # Nested calls
+///
+/// Calls made from inside a callback (batch result or error callbacks, plugin callbacks)
+/// start with a fresh error state and report their errors here as usual while the callback
+/// runs. When the callback returns, the error state of the enclosing call is restored, so
+/// nested calls never overwrite an error the outer call has not reported yet.
+///
 /// # Example (C)
 ///
 /// ```c
@@ -146,6 +167,137 @@ pub unsafe extern "C" fn kreuzberg_version() -> *const c_char {
     concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
 }
 
+/// Get the version of the core kreuzberg library linked into this library.
+///
+/// # Safety
+///
+/// - Returns a static string that does not need to be freed
+/// - The returned string is always valid
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_core_version() -> *const c_char {
+    CORE_VERSION.as_ptr()
+}
+
+/// Get the version of the FFI binding layer.
+///
+/// Same as `kreuzberg_version()`; provided alongside `kreuzberg_core_version()`.
+///
+/// # Safety
+///
+/// - Returns a static string that does not need to be freed
+/// - The returned string is always valid
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_binding_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
+}
+
+/// Check that a binding version is compatible with the core library.
+///
+/// Versions are compatible when their semver major versions match. Language
+/// bindings pass their own package version; NULL checks the FFI binding version.
+///
+/// # Returns
+///
+/// - `1` if compatible
+/// - `0` if incompatible (error message naming both versions available via `kreuzberg_last_error()`)
+///
+/// # Safety
+///
+/// * `binding_version` must be NULL or a valid pointer to a null-terminated UTF-8 string
+///
+/// # Example (C)
+///
+/// ```c
+/// if (kreuzberg_check_compatibility("4.2.0") != 1) {
+///     fprintf(stderr, "%s\n", kreuzberg_last_error());
+///     abort();
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_check_compatibility(binding_version: *const c_char) -> i32 {
+    let binding_version = if binding_version.is_null() {
+        env!("CARGO_PKG_VERSION")
+    } else {
+        match unsafe { CStr::from_ptr(binding_version) }.to_str() {
+            Ok(s) => s,
+            Err(_) => {
+                set_last_error("Invalid UTF-8 in binding_version".to_string());
+                return 0;
+            }
+        }
+    };
+
+    match kreuzberg::check_version_compatibility(binding_version) {
+        Ok(()) => 1,
+        Err(e) => {
+            set_last_error(e.to_string());
+            0
+        }
+    }
+}
+
+/// Get the ABI version of the loaded library.
+///
+/// Compare against `KREUZBERG_ABI_VERSION` from the header to detect a header
+/// compiled against a different library build than the one loaded at runtime.
+///
+/// # Safety
+///
+/// - Always safe to call
+///
+/// # Example (C)
+///
+/// ```c
+/// if (kreuzberg_abi_version() != KREUZBERG_ABI_VERSION) {
+///     fprintf(stderr, "kreuzberg ABI mismatch: header %u, library %u\n",
+///             KREUZBERG_ABI_VERSION, kreuzberg_abi_version());
+///     abort();
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_abi_version() -> u32 {
+    KREUZBERG_ABI_VERSION
+}
+
+/// Get the size in bytes of `CExtractionResult` as compiled into the library.
+///
+/// Bindings that declare the struct layout themselves (e.g. via dynamic loading)
+/// can compare this against their own definition.
+///
+/// # Safety
+///
+/// - Always safe to call
+///
+/// # Example (C)
+///
+/// ```c
+/// assert(kreuzberg_sizeof_extraction_result() == sizeof(CExtractionResult));
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_sizeof_extraction_result() -> usize {
+    std::mem::size_of::<CExtractionResult>()
+}
+
+/// Get the size in bytes of `CBatchResult` as compiled into the library.
+///
+/// # Safety
+///
+/// - Always safe to call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_sizeof_batch_result() -> usize {
+    std::mem::size_of::<CBatchResult>()
+}
+
+/// Get the size in bytes of `CBytesWithMime` as compiled into the library.
+///
+/// # Safety
+///
+/// - Always safe to call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_sizeof_bytes_with_mime() -> usize {
+    std::mem::size_of::<CBytesWithMime>()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,4 +359,47 @@ mod tests {
         assert!(code >= 0);
         assert!(code < 10); // Should be within reasonable bounds
     }
+
+    #[test]
+    fn test_check_compatibility_within_build() {
+        let core = unsafe { CStr::from_ptr(kreuzberg_core_version()) }.to_str().unwrap();
+        let binding = unsafe { CStr::from_ptr(kreuzberg_binding_version()) }.to_str().unwrap();
+        assert_eq!(core, kreuzberg::VERSION);
+        assert!(binding.contains('.'));
+
+        assert_eq!(unsafe { kreuzberg_check_compatibility(ptr::null()) }, 1);
+        assert_eq!(unsafe { kreuzberg_check_compatibility(kreuzberg_core_version()) }, 1);
+    }
+
+    #[test]
+    fn test_check_compatibility_reports_both_versions() {
+        clear_last_error();
+        let binding = CString::new("0.0.1").unwrap();
+
+        assert_eq!(unsafe { kreuzberg_check_compatibility(binding.as_ptr()) }, 0);
+
+        let error = unsafe { CStr::from_ptr(kreuzberg_last_error()) }.to_str().unwrap();
+        assert!(error.contains("0.0.1"));
+        assert!(error.contains(kreuzberg::VERSION));
+        clear_last_error();
+    }
+
+    #[test]
+    fn test_abi_version_nonzero_and_stable() {
+        let first = unsafe { kreuzberg_abi_version() };
+        let second = unsafe { kreuzberg_abi_version() };
+
+        assert_ne!(first, 0);
+        assert_eq!(first, second);
+        assert_eq!(first, KREUZBERG_ABI_VERSION);
+    }
+
+    #[test]
+    fn test_sizeof_accessors_match_layout() {
+        unsafe {
+            assert_eq!(kreuzberg_sizeof_extraction_result(), size_of::<CExtractionResult>());
+            assert_eq!(kreuzberg_sizeof_batch_result(), size_of::<CBatchResult>());
+            assert_eq!(kreuzberg_sizeof_bytes_with_mime(), size_of::<CBytesWithMime>());
+        }
+    }
 }
@@ -137,9 +137,19 @@ export interface PdfMetadata {
 	isEncrypted?: boolean;
 	width?: number;
 	height?: number;
+	formFields?: Record<string, string>;
+	pageReports?: PdfPageReport[];
 	summary?: string;
 }
 
+/** Text yield of a single PDF page; `charCount` is zero for pages that produced no text */
+export interface PdfPageReport {
+	page: number;
+	charCount: number;
+	hasImages: boolean;
+	ocrUsed: boolean;
+}
+
 export interface ExcelMetadata {
 	sheetCount: number;
 	sheetNames: string[];
@@ -320,6 +330,8 @@ export interface BaseMetadata {
 	isEncrypted?: boolean;
 	width?: number;
 	height?: number;
+	formFields?: Record<string, string>;
+	pageReports?: PdfPageReport[];
 	summary?: string;
 
 	sheetCount?: number;
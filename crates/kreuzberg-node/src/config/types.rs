@@ -57,6 +57,12 @@ impl From<JsOcrConfig> for RustOcrConfig {
             language: val.language.unwrap_or_else(|| "eng".to_string()),
             tesseract_config: val.tesseract_config.map(Into::into),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }
     }
 }
@@ -209,15 +215,26 @@ pub struct JsHierarchyConfig {
     pub k_clusters: Option<i32>,
     pub include_bbox: Option<bool>,
     pub ocr_coverage_threshold: Option<f64>,
+    pub max_columns: Option<u32>,
+    pub min_column_width: Option<f64>,
+    pub use_outline: Option<bool>,
 }
 
 impl From<JsHierarchyConfig> for RustHierarchyConfig {
     fn from(val: JsHierarchyConfig) -> Self {
+        let defaults = RustHierarchyConfig::default();
         RustHierarchyConfig {
             enabled: val.enabled.unwrap_or(true),
             k_clusters: val.k_clusters.map(|v| v as usize).unwrap_or(6),
             include_bbox: val.include_bbox.unwrap_or(true),
             ocr_coverage_threshold: val.ocr_coverage_threshold.map(|v| v as f32),
+            max_columns: val.max_columns.map(|v| v as usize).unwrap_or(defaults.max_columns),
+            min_column_width: val
+                .min_column_width
+                .map(|v| v as f32)
+                .unwrap_or(defaults.min_column_width),
+            column_mode: defaults.column_mode,
+            use_outline: val.use_outline.unwrap_or(defaults.use_outline),
         }
     }
 }
@@ -840,6 +857,11 @@ pub struct JsExtractionConfig {
     pub enable_quality_processing: Option<bool>,
     pub ocr: Option<JsOcrConfig>,
     pub force_ocr: Option<bool>,
+    pub detect_image_tables: Option<bool>,
+    /// OCR images embedded in PDF pages and append their text to the content
+    pub ocr_embedded_images: Option<bool>,
+    /// Extract without OCR instead of failing when the OCR backend is unavailable
+    pub ocr_optional: Option<bool>,
     pub chunking: Option<JsChunkingConfig>,
     pub images: Option<JsImageExtractionConfig>,
     pub pdf_options: Option<JsPdfConfig>,
@@ -849,11 +871,35 @@ pub struct JsExtractionConfig {
     pub keywords: Option<JsKeywordConfig>,
     pub html_options: Option<JsHtmlOptions>,
     pub max_concurrent_extractions: Option<u32>,
+    /// Process batch extractions sequentially instead of in parallel
+    pub force_single_threaded: Option<bool>,
     pub pages: Option<JsPageConfig>,
     /// Output text format: "plain" | "markdown" | "djot" | "html"
     pub output_format: Option<String>,
     /// Result structure format: "unified" | "element_based"
     pub result_format: Option<String>,
+    /// Encoding label for plain-text and HTML input (e.g. "windows-1252"); detected when unset
+    pub encoding_override: Option<String>,
+    /// Maximum input size in bytes; unlimited when unset
+    pub max_input_bytes: Option<i64>,
+    /// Maximum number of pages to extract; all pages when unset
+    pub max_pages: Option<u32>,
+    /// Return the pages read before a damaged page instead of failing
+    pub allow_partial: Option<bool>,
+    /// Detect the content language and store it in the metadata when the document declares none
+    pub detect_language: Option<bool>,
+    /// Append filled PDF form field values to the content as a labeled section
+    pub extract_form_fields: Option<bool>,
+    /// Normalize whitespace in the extracted content
+    pub normalize_whitespace: Option<bool>,
+    /// Single-character field delimiter for CSV/TSV input; detected when unset
+    pub csv_delimiter: Option<String>,
+    /// Remove running headers and footers repeated across PDF pages
+    pub strip_running_headers: Option<bool>,
+    /// Report word positions (page and bounding box) in the result layout
+    pub include_layout: Option<bool>,
+    /// Include speaker notes in presentation output
+    pub include_speaker_notes: Option<bool>,
 }
 
 impl TryFrom<JsPageConfig> for kreuzberg::core::config::PageConfig {
@@ -899,6 +945,9 @@ impl TryFrom<JsExtractionConfig> for ExtractionConfig {
             enable_quality_processing: val.enable_quality_processing.unwrap_or(true),
             ocr: val.ocr.map(Into::into),
             force_ocr: val.force_ocr.unwrap_or(false),
+            detect_image_tables: val.detect_image_tables.unwrap_or(false),
+            ocr_embedded_images: val.ocr_embedded_images.unwrap_or(false),
+            ocr_optional: val.ocr_optional.unwrap_or(false),
             chunking: val.chunking.map(Into::into),
             images: val.images.map(Into::into),
             pdf_options: val.pdf_options.map(Into::into),
@@ -908,6 +957,7 @@ impl TryFrom<JsExtractionConfig> for ExtractionConfig {
             postprocessor: val.postprocessor.map(Into::into),
             html_options,
             max_concurrent_extractions: val.max_concurrent_extractions.map(|v| v as usize),
+            force_single_threaded: val.force_single_threaded.unwrap_or(false),
             pages: val.pages.map(|p| p.try_into()).transpose()?,
             output_format: val
                 .output_format
@@ -930,6 +980,33 @@ impl TryFrom<JsExtractionConfig> for ExtractionConfig {
                 })
                 .transpose()?
                 .unwrap_or_default(),
+            encoding_override: val.encoding_override,
+            max_input_bytes: val
+                .max_input_bytes
+                .map(u64::try_from)
+                .transpose()
+                .map_err(|_| Error::new(Status::InvalidArg, "maxInputBytes must not be negative"))?,
+            max_pages: val.max_pages.map(|v| v as usize),
+            allow_partial: val.allow_partial.unwrap_or(false),
+            detect_language: val.detect_language.unwrap_or(false),
+            extract_form_fields: val.extract_form_fields.unwrap_or(false),
+            normalize_whitespace: val.normalize_whitespace.unwrap_or(false),
+            csv_delimiter: val
+                .csv_delimiter
+                .map(|s| {
+                    let mut chars = s.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Ok(c),
+                        _ => Err(Error::new(
+                            Status::InvalidArg,
+                            format!("Invalid csvDelimiter: '{}'. Expected a single character", s),
+                        )),
+                    }
+                })
+                .transpose()?,
+            strip_running_headers: val.strip_running_headers.unwrap_or(false),
+            include_layout: val.include_layout.unwrap_or(false),
+            include_speaker_notes: val.include_speaker_notes.unwrap_or(true),
         })
     }
 }
@@ -955,6 +1032,9 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                 }),
             }),
             force_ocr: Some(val.force_ocr),
+            detect_image_tables: Some(val.detect_image_tables),
+            ocr_embedded_images: Some(val.ocr_embedded_images),
+            ocr_optional: Some(val.ocr_optional),
             chunking: val.chunking.map(|chunk| JsChunkingConfig {
                 max_chars: Some(chunk.max_characters as u32),
                 max_overlap: Some(chunk.overlap as u32),
@@ -1000,6 +1080,9 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                     k_clusters: Some(h.k_clusters as i32),
                     include_bbox: Some(h.include_bbox),
                     ocr_coverage_threshold: h.ocr_coverage_threshold.map(|v| v as f64),
+                    max_columns: Some(h.max_columns as u32),
+                    min_column_width: Some(h.min_column_width as f64),
+                    use_outline: Some(h.use_outline),
                 }),
             }),
             token_reduction: val.token_reduction.map(|tr| JsTokenReductionConfig {
@@ -1019,12 +1102,24 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
             keywords: val.keywords.map(JsKeywordConfig::from),
             html_options: val.html_options.as_ref().map(JsHtmlOptions::from),
             max_concurrent_extractions: val.max_concurrent_extractions.map(|v| v as u32),
+            force_single_threaded: Some(val.force_single_threaded),
             pages: val.pages.map(JsPageConfig::from),
             output_format: Some(val.output_format.to_string()),
             result_format: Some(match val.result_format {
                 kreuzberg::types::OutputFormat::Unified => "unified".to_string(),
                 kreuzberg::types::OutputFormat::ElementBased => "element_based".to_string(),
             }),
+            encoding_override: val.encoding_override,
+            max_input_bytes: val.max_input_bytes.map(|v| v as i64),
+            max_pages: val.max_pages.map(|v| v as u32),
+            allow_partial: Some(val.allow_partial),
+            detect_language: Some(val.detect_language),
+            extract_form_fields: Some(val.extract_form_fields),
+            normalize_whitespace: Some(val.normalize_whitespace),
+            csv_delimiter: val.csv_delimiter.map(|c| c.to_string()),
+            strip_running_headers: Some(val.strip_running_headers),
+            include_layout: Some(val.include_layout),
+            include_speaker_notes: Some(val.include_speaker_notes),
         })
     }
 }
@@ -1131,3 +1226,47 @@ pub fn discover_extraction_config() -> Result<Option<JsExtractionConfig>> {
         None => Ok(None),
     }
 }
+
+/// A single invalid configuration value reported by `validateExtractionConfig`.
+#[napi(object)]
+pub struct JsConfigError {
+    /// Dotted path of the offending field (e.g. `"ocr.tesseract_config.psm"`)
+    pub field: String,
+    /// Description of the problem
+    pub message: String,
+}
+
+/// Validate every field of an extraction configuration.
+///
+/// Unlike extraction, which fails on the first invalid setting, this reports all
+/// invalid values at once.
+///
+/// # Returns
+///
+/// Array of `{ field, message }` objects; empty when the configuration is valid.
+///
+/// # Example
+///
+/// ```typescript
+/// import { validateExtractionConfig } from 'kreuzberg';
+///
+/// const errors = validateExtractionConfig({ chunking: { maxChars: 100, maxOverlap: 200 } });
+/// for (const error of errors) {
+///   console.error(`${error.field}: ${error.message}`);
+/// }
+/// ```
+#[napi(js_name = "validateExtractionConfig")]
+pub fn validate_extraction_config(config: JsExtractionConfig) -> Result<Vec<JsConfigError>> {
+    let rust_config = ExtractionConfig::try_from(config)?;
+
+    Ok(rust_config
+        .validate()
+        .err()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|error| JsConfigError {
+            field: error.field,
+            message: error.message,
+        })
+        .collect())
+}
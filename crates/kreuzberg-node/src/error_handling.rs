@@ -21,6 +21,8 @@ use super::kreuzberg_error_code_name;
 /// - `Plugin` → GenericFailure (plugin-specific errors)
 /// - `LockPoisoned` → GenericFailure (lock poisoning, should not happen)
 /// - `UnsupportedFormat` → InvalidArg (unsupported MIME types)
+/// - `InputTooLarge` → InvalidArg (input exceeds `max_input_bytes`)
+/// - `Network` → GenericFailure (fetching a remote document failed)
 /// - `Other` → GenericFailure (catch-all)
 ///
 /// # Usage
@@ -73,6 +75,12 @@ pub(crate) fn convert_error(err: kreuzberg::KreuzbergError) -> napi::Error {
             Error::new(Status::InvalidArg, format!("Unsupported format: {}", format))
         }
 
+        err @ KreuzbergError::InputTooLarge { .. } => Error::new(Status::InvalidArg, err.to_string()),
+
+        KreuzbergError::Network { message, .. } => {
+            Error::new(Status::GenericFailure, format!("Network error: {}", message))
+        }
+
         KreuzbergError::Other(msg) => Error::new(Status::GenericFailure, msg),
     }
 }
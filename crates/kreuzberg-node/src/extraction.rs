@@ -69,6 +69,74 @@ pub async fn extract_file(
     JsExtractionResult::try_from(result)
 }
 
+/// Background task backing `extractFileWithSignal`.
+///
+/// Runs on the libuv thread pool so an `AbortSignal` can cancel work that has not
+/// started yet. Extraction has no cancellation points, so `compute` cannot be
+/// interrupted once it is running: it finishes on the worker thread, its result is
+/// discarded, and the promise rejects with an `AbortError` instead of resolving.
+pub struct ExtractFileTask {
+    file_path: String,
+    mime_type: Option<String>,
+    config: kreuzberg::ExtractionConfig,
+}
+
+impl Task for ExtractFileTask {
+    type Output = kreuzberg::ExtractionResult;
+    type JsValue = JsExtractionResult;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        kreuzberg::extract_file_sync(&self.file_path, self.mime_type.as_deref(), &self.config).map_err(convert_error)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        JsExtractionResult::try_from(output)
+    }
+}
+
+/// Extract content from a file (asynchronous, cancellable).
+///
+/// Same as `extractFile`, but accepts an optional `AbortSignal`. Aborting the signal
+/// rejects the returned promise with an `AbortError`. Extraction that has not started
+/// yet is skipped; extraction that is already running only detaches from the promise.
+/// It keeps its worker thread busy until it completes, and the result is dropped.
+///
+/// # Parameters
+///
+/// * `file_path` - Path to the file to extract (absolute or relative)
+/// * `mime_type` - Optional MIME type hint (auto-detected if omitted)
+/// * `config` - Optional extraction configuration (OCR, chunking, etc.)
+/// * `signal` - Optional `AbortSignal` used to cancel the extraction
+///
+/// # Example
+///
+/// ```typescript
+/// import { extractFile } from '@kreuzberg/node';
+///
+/// const controller = new AbortController();
+/// const pending = extractFile('scan.pdf', null, null, controller.signal);
+/// controller.abort();
+/// await pending; // rejects with AbortError
+/// ```
+#[napi(ts_return_type = "Promise<JsExtractionResult>")]
+pub fn extract_file_with_signal(
+    file_path: String,
+    mime_type: Option<String>,
+    config: Option<JsExtractionConfig>,
+    signal: Option<AbortSignal>,
+) -> Result<AsyncTask<ExtractFileTask>> {
+    let rust_config = resolve_config(config)?;
+
+    Ok(AsyncTask::with_optional_signal(
+        ExtractFileTask {
+            file_path,
+            mime_type,
+            config: rust_config,
+        },
+        signal,
+    ))
+}
+
 /// Extract content from bytes (synchronous).
 ///
 /// Synchronously extracts content from a byte buffer without requiring a file path.
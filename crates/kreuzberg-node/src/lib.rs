@@ -24,10 +24,11 @@ mod validation;
 pub use error_handling::{ErrorClassification, classify_error, get_error_code_description, get_error_code_name};
 
 pub use config::{
-    JsChunkingConfig, JsEmbeddingConfig, JsEmbeddingModelType, JsExtractionConfig, JsHierarchyConfig, JsHtmlOptions,
-    JsHtmlPreprocessingOptions, JsImageExtractionConfig, JsKeywordConfig, JsLanguageDetectionConfig, JsOcrConfig,
-    JsPageConfig, JsPdfConfig, JsPostProcessorConfig, JsRakeParams, JsTesseractConfig, JsTokenReductionConfig,
-    JsYakeParams, discover_extraction_config, load_extraction_config_from_file,
+    JsChunkingConfig, JsConfigError, JsEmbeddingConfig, JsEmbeddingModelType, JsExtractionConfig, JsHierarchyConfig,
+    JsHtmlOptions, JsHtmlPreprocessingOptions, JsImageExtractionConfig, JsKeywordConfig, JsLanguageDetectionConfig,
+    JsOcrConfig, JsPageConfig, JsPdfConfig, JsPostProcessorConfig, JsRakeParams, JsTesseractConfig,
+    JsTokenReductionConfig, JsYakeParams, discover_extraction_config, load_extraction_config_from_file,
+    validate_extraction_config,
 };
 
 pub use result::{
@@ -35,7 +36,9 @@ pub use result::{
     JsPageHierarchy, JsTable,
 };
 
-pub use extraction::{extract_bytes, extract_bytes_sync, extract_file, extract_file_sync};
+pub use extraction::{
+    ExtractFileTask, extract_bytes, extract_bytes_sync, extract_file, extract_file_sync, extract_file_with_signal,
+};
 
 pub use batch::{batch_extract_bytes, batch_extract_bytes_sync, batch_extract_files, batch_extract_files_sync};
 
@@ -48,16 +51,16 @@ pub use validation::{
 };
 
 pub use metadata::{
-    clear_document_extractors, detect_mime_type_from_bytes, detect_mime_type_from_path, list_document_extractors,
-    unregister_document_extractor,
+    MimeDetection, clear_document_extractors, detect_mime, detect_mime_type_from_bytes, detect_mime_type_from_path,
+    list_document_extractors, unregister_document_extractor,
 };
 
 pub use embedding_presets::{EmbeddingPreset, get_embedding_preset, list_embedding_presets};
 
 pub use plugins::{
     clear_ocr_backends, clear_post_processors, clear_validators, list_ocr_backends, list_post_processors,
-    list_validators, register_ocr_backend, register_post_processor, register_validator, unregister_ocr_backend,
-    unregister_post_processor, unregister_validator,
+    list_validators, register_min_text_length_validator, register_ocr_backend, register_post_processor,
+    register_validator, unregister_ocr_backend, unregister_post_processor, unregister_validator,
 };
 
 // Core imports for utilities and FFI types
@@ -117,3 +117,52 @@ pub fn detect_mime_type_from_bytes(bytes: Buffer) -> Result<String> {
 pub fn detect_mime_type_from_path(path: String, check_exists: Option<bool>) -> Result<String> {
     kreuzberg::core::mime::detect_mime_type(&path, check_exists.unwrap_or(true)).map_err(convert_error)
 }
+
+/// Result of combined path and content MIME detection.
+#[napi(object)]
+pub struct MimeDetection {
+    /// MIME type chosen according to the policy
+    pub mime_type: String,
+    /// Whether the content signature and the file extension disagreed
+    pub mismatch: bool,
+}
+
+/// Detect MIME type from both a file path and its content.
+///
+/// Compares the extension-based and content-based MIME types and resolves any
+/// disagreement according to `policy`.
+///
+/// # Parameters
+///
+/// * `path` - File path or name whose extension is checked (need not exist)
+/// * `bytes` - Raw file content as Buffer
+/// * `policy` - "prefer_content" (default), "prefer_extension", or "strict"
+///
+/// # Errors
+///
+/// Throws an error if the policy is invalid, if the sources disagree under
+/// "strict", or if no MIME type can be determined.
+///
+/// # Example
+///
+/// ```typescript
+/// import { detectMime } from 'kreuzberg';
+///
+/// const { mimeType, mismatch } = detectMime('upload.txt', content, 'strict');
+/// ```
+#[napi(js_name = "detectMime")]
+pub fn detect_mime(path: String, bytes: Buffer, policy: Option<String>) -> Result<MimeDetection> {
+    let policy = match policy {
+        Some(policy) => policy
+            .parse::<kreuzberg::core::mime::MimePolicy>()
+            .map_err(|e| Error::new(Status::InvalidArg, e))?,
+        None => kreuzberg::core::mime::MimePolicy::default(),
+    };
+
+    let detection = kreuzberg::core::mime::detect_mime(&path, bytes.as_ref(), policy).map_err(convert_error)?;
+
+    Ok(MimeDetection {
+        mime_type: detection.mime_type,
+        mismatch: detection.mismatch,
+    })
+}
@@ -138,8 +138,12 @@ impl RustOcrBackend for JsOcrBackend {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         })
     }
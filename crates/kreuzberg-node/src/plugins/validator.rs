@@ -200,6 +200,34 @@ pub fn register_validator(_env: Env, validator: Object) -> Result<()> {
     Ok(())
 }
 
+/// Register the built-in minimum text length validator
+///
+/// Adds a `validation` warning to the result's warnings when the
+/// extracted text has fewer than `minLength` characters. Extraction never fails.
+///
+/// # Example
+///
+/// ```typescript
+/// import { registerMinTextLengthValidator } from '@kreuzberg/node';
+///
+/// registerMinTextLengthValidator(50);
+/// ```
+#[napi]
+pub fn register_min_text_length_validator(min_length: u32) -> Result<()> {
+    kreuzberg::plugins::register_validator(kreuzberg::plugins::min_text_length_validator(min_length as usize)).map_err(
+        |e| {
+            napi::Error::new(
+                napi::Status::GenericFailure,
+                format!(
+                    "Failed to register Validator '{}': {}",
+                    kreuzberg::plugins::MinTextLengthValidator::NAME,
+                    e
+                ),
+            )
+        },
+    )
+}
+
 /// Unregister a validator by name
 #[napi]
 pub fn unregister_validator(name: String) -> Result<()> {
@@ -41,6 +41,7 @@ pub struct JsTable {
     pub cells: Vec<Vec<String>>,
     pub markdown: String,
     pub page_number: u32,
+    pub caption: Option<String>,
 }
 
 #[napi(object)]
@@ -140,6 +141,8 @@ pub struct JsExtractionResult {
     #[serde(skip)]
     pub pages: Option<Vec<JsPageContent>>,
     pub elements: Option<Vec<JsElement>>,
+    /// Whether extraction stopped early and only part of the document was extracted
+    pub partial: Option<bool>,
 }
 
 impl TryFrom<RustExtractionResult> for JsExtractionResult {
@@ -148,6 +151,7 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
     fn try_from(val: RustExtractionResult) -> Result<Self> {
         let metadata = serde_json::to_value(&val.metadata)
             .map_err(|e| Error::new(Status::GenericFailure, format!("Failed to serialize metadata: {}", e)))?;
+        let partial = val.partial;
 
         let images = if let Some(imgs) = val.images {
             let mut js_images = Vec::with_capacity(imgs.len());
@@ -194,6 +198,7 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                         cells: t.cells.clone(),
                         markdown: t.markdown.clone(),
                         page_number: t.page_number as u32,
+                        caption: t.caption.clone(),
                     })
                     .collect();
 
@@ -309,6 +314,7 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number as u32,
+                    caption: t.caption,
                 })
                 .collect(),
             detected_languages: val.detected_languages,
@@ -345,6 +351,7 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
             images,
             pages,
             elements,
+            partial: Some(partial),
         })
     }
 }
@@ -498,11 +505,15 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number as usize,
+                    caption: t.caption,
                 })
                 .collect(),
             detected_languages: val.detected_languages,
             chunks,
             images,
+            links: vec![],
+            warnings: vec![],
+            partial: val.partial.unwrap_or(false),
             pages: None,
             elements: val.elements.map(|elems| {
                 elems
@@ -536,6 +547,7 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                     })
                     .collect()
             }),
+            layout: None,
             djot_content: None,
         })
     }
@@ -354,6 +354,43 @@ pub fn validate_language_code(code: String) -> Result<bool> {
     Ok(result == 1)
 }
 
+/// Detected language of a text.
+#[napi(object)]
+pub struct LanguageGuess {
+    /// ISO 639-3 language code (e.g., "eng", "deu")
+    pub code: String,
+    /// Detection confidence (0.0-1.0)
+    pub confidence: f64,
+}
+
+/// Detects the most likely language of a text.
+///
+/// # Arguments
+///
+/// * `text` - The text to analyze
+///
+/// # Returns
+///
+/// The detected language with its confidence, or `null` if the text is too short to detect.
+///
+/// # Example
+///
+/// ```typescript
+/// import { detectLanguage } from '@kreuzberg/node';
+///
+/// const guess = detectLanguage('Das ist ein deutscher Beispielsatz.');
+/// if (guess) {
+///   console.log(`${guess.code} (${guess.confidence})`);
+/// }
+/// ```
+#[napi(js_name = "detectLanguage")]
+pub fn detect_language(text: String) -> Option<LanguageGuess> {
+    kreuzberg::language_detection::detect_language(&text).map(|guess| LanguageGuess {
+        code: guess.code,
+        confidence: guess.confidence as f64,
+    })
+}
+
 /// Validates a token reduction level string.
 ///
 /// Valid levels: "off", "light", "moderate", "aggressive", "maximum"
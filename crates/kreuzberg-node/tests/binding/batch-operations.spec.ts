@@ -452,6 +452,32 @@ describe("Batch Bytes Extraction (Node.js Bindings)", () => {
 		});
 	});
 
+	describe("batch bytes result ordering", () => {
+		// Larger documents come first so later ones tend to finish earlier.
+		const documents = Array.from({ length: 32 }, (_, i) => Buffer.from(`document ${i} `.repeat((32 - i) * 500)));
+		const mimeTypes = documents.map(() => "text/plain");
+		const config: ExtractionConfig = { maxConcurrentExtractions: 8 };
+
+		const isOrderPreserved = (contents: string[]) =>
+			contents.every((content, i) => content.startsWith(`document ${i} `));
+
+		it("should map sync results positionally to inputs", () => {
+			const results = batchExtractBytesSync(documents, mimeTypes, config);
+
+			expect(results.length).toBe(documents.length);
+			const orderPreserved = isOrderPreserved(results.map((result) => result.content));
+			expect(orderPreserved).toBe(true);
+		});
+
+		it("should map async results positionally to inputs", async () => {
+			const results = await batchExtractBytes(documents, mimeTypes, config);
+
+			expect(results.length).toBe(documents.length);
+			const orderPreserved = isOrderPreserved(results.map((result) => result.content));
+			expect(orderPreserved).toBe(true);
+		});
+	});
+
 	describe("batch bytes extraction error handling", () => {
 		it("should reject non-Uint8Array data", () => {
 			const invalidData = ["not bytes" as any];
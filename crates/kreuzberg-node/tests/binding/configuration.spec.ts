@@ -548,4 +548,25 @@ describe("Configuration Options", () => {
 			expect(yamlConfig.useCache).toBe(true);
 		});
 	});
+
+	describe("ExtractionConfig.validate()", () => {
+		it("should return no errors for a valid config", () => {
+			expect(ExtractionConfig.validate({ chunking: { maxChars: 1000, maxOverlap: 100 } })).toEqual([]);
+		});
+
+		it("should report every invalid field together", () => {
+			const errors = ExtractionConfig.validate({
+				chunking: { maxChars: 100, maxOverlap: 200 },
+				tokenReduction: { mode: "extreme" },
+				maxConcurrentExtractions: 0,
+			});
+
+			expect(errors.map((error) => error.field)).toEqual([
+				"chunking",
+				"token_reduction.mode",
+				"max_concurrent_extractions",
+			]);
+			expect(errors[0].message).toContain("max_overlap");
+		});
+	});
 });
@@ -146,6 +146,32 @@ describe("extractFile - comprehensive async tests", () => {
 		expect(result).toBeTruthy();
 		expect(result.content).toBeTruthy();
 	});
+
+	it("should resolve with an AbortSignal that is never aborted", async () => {
+		const controller = new AbortController();
+		const result = await extractFile(samplePdfPath, null, { useCache: false }, controller.signal);
+
+		expect(result).toBeTruthy();
+		expect(result.content).toBeTruthy();
+	});
+
+	it("should resolve concurrent cancellable calls without deadlocking", async () => {
+		const controller = new AbortController();
+		const [first, second] = await Promise.all([
+			extractFile(samplePdfPath, null, { useCache: false }, controller.signal),
+			extractFile(sampleTextPath, null, { useCache: false }, controller.signal),
+		]);
+
+		expect(first.content).toBeTruthy();
+		expect(second.content).toBeTruthy();
+	});
+
+	it("should reject when the signal is already aborted", async () => {
+		const controller = new AbortController();
+		controller.abort();
+
+		await expect(extractFile(samplePdfPath, null, null, controller.signal)).rejects.toThrow();
+	});
 });
 
 describe("batchExtractFilesSync - comprehensive tests", () => {
@@ -11,6 +11,7 @@ import {
 	type ExtractionResult,
 	extractBytes,
 	listValidators,
+	registerMinTextLengthValidator,
 	registerValidator,
 	unregisterValidator,
 	type ValidatorProtocol,
@@ -229,6 +230,7 @@ describe("Validator Plugin System", () => {
 describe("Validator bridge wiring", () => {
 	const createValidatorBinding = () => ({
 		registerValidator: vi.fn(),
+		registerMinTextLengthValidator: vi.fn(),
 		unregisterValidator: vi.fn(),
 		clearValidators: vi.fn(),
 		listValidators: vi.fn().mockReturnValue(["alpha", "beta"]),
@@ -318,10 +320,12 @@ describe("Validator bridge wiring", () => {
 		const mockBinding = createValidatorBinding();
 		__setBindingForTests(mockBinding);
 
+		registerMinTextLengthValidator(50);
 		unregisterValidator("foo");
 		clearValidators();
 		const names = listValidators();
 
+		expect(mockBinding.registerMinTextLengthValidator).toHaveBeenCalledWith(50);
 		expect(mockBinding.unregisterValidator).toHaveBeenCalledWith("foo");
 		expect(mockBinding.clearValidators).toHaveBeenCalledTimes(1);
 		expect(mockBinding.listValidators).toHaveBeenCalledTimes(1);
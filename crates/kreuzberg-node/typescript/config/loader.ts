@@ -1,5 +1,6 @@
 import { getBinding } from "../core/binding.js";
-import type { ExtractionConfig as ExtractionConfigType } from "../types.js";
+import { normalizeExtractionConfig } from "../core/config-normalizer.js";
+import type { ConfigError, ExtractionConfig as ExtractionConfigType } from "../types.js";
 
 /**
  * ExtractionConfig namespace with static methods for loading configuration from files.
@@ -88,6 +89,35 @@ export const ExtractionConfig = {
 		const binding = getBinding();
 		return binding.discoverExtractionConfig();
 	},
+
+	/**
+	 * Validate every field of an extraction configuration.
+	 *
+	 * Unlike extraction, which fails on the first invalid setting, this reports
+	 * all invalid values at once.
+	 *
+	 * @param config - Configuration to validate
+	 * @returns Array of errors, empty when the configuration is valid
+	 *
+	 * @throws {Error} If the configuration cannot be converted (e.g. wrong field types)
+	 *
+	 * @example
+	 * ```typescript
+	 * import { ExtractionConfig } from '@kreuzberg/node';
+	 *
+	 * const errors = ExtractionConfig.validate({
+	 *   chunking: { maxChars: 100, maxOverlap: 200 },
+	 *   maxConcurrentExtractions: 0,
+	 * });
+	 * for (const error of errors) {
+	 *   console.error(`${error.field}: ${error.message}`);
+	 * }
+	 * ```
+	 */
+	validate(config: ExtractionConfigType): ConfigError[] {
+		const binding = getBinding();
+		return binding.validateExtractionConfig(normalizeExtractionConfig(config) ?? {});
+	},
 };
 
 /**
@@ -27,6 +27,12 @@ export interface NativeBinding {
 		mimeType: string | null,
 		config: Record<string, unknown> | null,
 	): Promise<Record<string, unknown>>;
+	extractFileWithSignal(
+		filePath: string,
+		mimeType: string | null,
+		config: Record<string, unknown> | null,
+		signal: AbortSignal | null,
+	): Promise<Record<string, unknown>>;
 	extractBytesSync(data: Buffer, mimeType: string, config: Record<string, unknown> | null): Record<string, unknown>;
 	extractBytes(
 		data: Buffer,
@@ -50,6 +56,7 @@ export interface NativeBinding {
 	clearPostProcessors(): void;
 	listPostProcessors(): string[];
 	registerValidator(validator: Record<string, unknown>): void;
+	registerMinTextLengthValidator(minLength: number): void;
 	unregisterValidator(name: string): void;
 	clearValidators(): void;
 	listValidators(): string[];
@@ -64,6 +71,7 @@ export interface NativeBinding {
 	detectMimeType(filePath: string): string;
 	detectMimeTypeFromBytes(data: Buffer): string;
 	detectMimeTypeFromPath(filePath: string, checkExists?: boolean): string;
+	detectMime(filePath: string, data: Buffer, policy?: string): { mimeType: string; mismatch: boolean };
 	validateMimeType(mimeType: string): string;
 	getExtensionsForMime(mimeType: string): string[];
 	listEmbeddingPresets(): string[];
@@ -75,6 +83,7 @@ export interface NativeBinding {
 	getLastPanicContext(): Record<string, unknown> | null;
 	loadExtractionConfigFromFile(filePath: string): Record<string, unknown>;
 	discoverExtractionConfig(): Record<string, unknown> | null;
+	validateExtractionConfig(config: Record<string, unknown>): Array<{ field: string; message: string }>;
 	createWorkerPool(size?: number): Record<string, unknown>;
 	getWorkerPoolStats(pool: Record<string, unknown>): Record<string, unknown>;
 	extractFileInWorker(
@@ -341,7 +341,22 @@ function normalizeExtractionConfig(config: ExtractionConfig | null): NativeExtra
 	setIfDefined(normalized, "useCache", config.useCache);
 	setIfDefined(normalized, "enableQualityProcessing", config.enableQualityProcessing);
 	setIfDefined(normalized, "forceOcr", config.forceOcr);
+	setIfDefined(normalized, "detectImageTables", config.detectImageTables);
+	setIfDefined(normalized, "ocrEmbeddedImages", config.ocrEmbeddedImages);
+	setIfDefined(normalized, "ocrOptional", config.ocrOptional);
+	setIfDefined(normalized, "encodingOverride", config.encodingOverride);
 	setIfDefined(normalized, "maxConcurrentExtractions", config.maxConcurrentExtractions);
+	setIfDefined(normalized, "forceSingleThreaded", config.forceSingleThreaded);
+	setIfDefined(normalized, "maxInputBytes", config.maxInputBytes);
+	setIfDefined(normalized, "maxPages", config.maxPages);
+	setIfDefined(normalized, "allowPartial", config.allowPartial);
+	setIfDefined(normalized, "detectLanguage", config.detectLanguage);
+	setIfDefined(normalized, "extractFormFields", config.extractFormFields);
+	setIfDefined(normalized, "normalizeWhitespace", config.normalizeWhitespace);
+	setIfDefined(normalized, "csvDelimiter", config.csvDelimiter);
+	setIfDefined(normalized, "stripRunningHeaders", config.stripRunningHeaders);
+	setIfDefined(normalized, "includeLayout", config.includeLayout);
+	setIfDefined(normalized, "includeSpeakerNotes", config.includeSpeakerNotes);
 
 	const ocr = normalizeOcrConfig(config.ocr);
 	setIfDefined(normalized, "ocr", ocr);
@@ -94,8 +94,13 @@ export function extractFileSync(
  *   If null, MIME type is auto-detected from file extension or content.
  * @param maybeConfig - Extraction configuration object. If null, uses default extraction settings.
  *   Only used if second parameter is a MIME type string.
+ * @param signal - Optional AbortSignal. Aborting rejects the promise with an `AbortError`
+ *   and cancels the native extraction if it has not started yet. An extraction that is
+ *   already running is not interrupted: it completes in the background and its result
+ *   is discarded.
  * @returns Promise<ExtractionResult> containing extracted content, metadata, tables, and optional chunks/images
  * @throws {Error} If file doesn't exist, cannot be accessed, or cannot be read
+ * @throws {DOMException} With name `AbortError` when the signal is aborted
  * @throws {ParsingError} When document format is invalid or corrupted
  * @throws {OcrError} When OCR processing fails (if OCR is enabled)
  * @throws {ValidationError} When extraction result fails validation (if validators registered)
@@ -118,12 +123,18 @@ export function extractFileSync(
  * };
  * const result2 = await extractFile('long_document.pdf', null, config);
  * console.log(result2.chunks); // Array of text chunks
+ *
+ * // With cancellation
+ * const controller = new AbortController();
+ * setTimeout(() => controller.abort(), 5000);
+ * const result3 = await extractFile('scan.pdf', null, null, controller.signal);
  * ```
  */
 export async function extractFile(
 	filePath: string,
 	mimeTypeOrConfig?: string | null | ExtractionConfig,
 	maybeConfig?: ExtractionConfig | null,
+	signal?: AbortSignal | null,
 ): Promise<ExtractionResult> {
 	let mimeType: string | null = null;
 	let config: ExtractionConfig | null = null;
@@ -140,6 +151,11 @@ export async function extractFile(
 	}
 
 	const normalizedConfig = normalizeExtractionConfig(config);
+	if (signal) {
+		signal.throwIfAborted();
+		const rawResult = await getBinding().extractFileWithSignal(filePath, mimeType, normalizedConfig, signal);
+		return convertResult(rawResult);
+	}
 	const rawResult = await getBinding().extractFile(filePath, mimeType, normalizedConfig);
 	return convertResult(rawResult);
 }
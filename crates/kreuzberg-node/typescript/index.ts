@@ -67,6 +67,7 @@
 export type {
 	Chunk,
 	ChunkingConfig,
+	ConfigError,
 	ErrorClassification,
 	ExtractedImage,
 	ExtractionConfig,
@@ -162,6 +163,7 @@ export {
 export {
 	clearValidators,
 	listValidators,
+	registerMinTextLengthValidator,
 	registerValidator,
 	unregisterValidator,
 } from "./plugins/validators.js";
@@ -198,7 +200,9 @@ export * from "./config/loader.js";
 // MIME Type Utilities
 // ============================================================================
 
+export type { MimeDetection, MimePolicy } from "./mime/utilities.js";
 export {
+	detectMime,
 	detectMimeType,
 	detectMimeTypeFromPath,
 	getExtensionsForMime,
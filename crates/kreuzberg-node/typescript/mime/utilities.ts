@@ -61,6 +61,56 @@ export function detectMimeTypeFromPath(filePath: string, checkExists?: boolean):
 	return binding.detectMimeTypeFromPath(filePath, checkExists);
 }
 
+/**
+ * How {@link detectMime} resolves disagreement between file content and extension.
+ *
+ * - `prefer_content`: trust the content signature (default)
+ * - `prefer_extension`: trust the file extension
+ * - `strict`: throw when content and extension disagree
+ */
+export type MimePolicy = "prefer_content" | "prefer_extension" | "strict";
+
+/**
+ * Result of {@link detectMime}.
+ */
+export interface MimeDetection {
+	/** MIME type chosen according to the policy */
+	mimeType: string;
+	/** Whether the content signature and the file extension disagreed */
+	mismatch: boolean;
+}
+
+/**
+ * Detect MIME type from both a file path and its content.
+ *
+ * Compares the extension-based and content-based MIME types. Text content is
+ * considered compatible with any text-based extension (e.g. Markdown, CSV).
+ * When the two disagree, `policy` decides which one wins.
+ *
+ * @param filePath - File path or name whose extension is checked (need not exist)
+ * @param bytes - Raw file content as Buffer
+ * @param policy - Mismatch resolution policy (default: 'prefer_content')
+ * @returns The chosen MIME type and whether content and extension disagreed
+ *
+ * @throws {Error} If the sources disagree under 'strict', or no MIME type can be determined
+ *
+ * @example
+ * ```typescript
+ * import { detectMime } from '@kreuzberg/node';
+ * import * as fs from 'fs';
+ *
+ * const content = fs.readFileSync('upload.txt');
+ * const { mimeType, mismatch } = detectMime('upload.txt', content);
+ * if (mismatch) {
+ *   console.warn(`Extension does not match content (${mimeType})`);
+ * }
+ * ```
+ */
+export function detectMime(filePath: string, bytes: Buffer, policy?: MimePolicy): MimeDetection {
+	const binding = getBinding();
+	return binding.detectMime(filePath, bytes, policy);
+}
+
 /**
  * Validate that a MIME type is supported by Kreuzberg.
  *
@@ -67,6 +67,28 @@ export function registerValidator(validator: ValidatorProtocol): void {
 	binding.registerValidator(wrappedValidator);
 }
 
+/**
+ * Register the built-in minimum text length validator.
+ *
+ * The validator never fails an extraction. When the extracted text (ignoring
+ * surrounding whitespace) is shorter than `minLength` characters, it adds a
+ * `"validation"` warning to `result.warnings`, which usually points at a scanned
+ * document processed without OCR.
+ *
+ * @param minLength - Minimum number of characters expected in the extracted text
+ *
+ * @example
+ * ```typescript
+ * import { registerMinTextLengthValidator } from '@kreuzberg/node';
+ *
+ * registerMinTextLengthValidator(50);
+ * ```
+ */
+export function registerMinTextLengthValidator(minLength: number): void {
+	const binding = getBinding();
+	binding.registerMinTextLengthValidator(minLength);
+}
+
 /**
  * Unregister a validator by name.
  *
@@ -146,6 +146,15 @@ export interface HierarchyConfig {
 
 	/** OCR coverage threshold (0.0-1.0). Default: null. */
 	ocrCoverageThreshold?: number | null;
+
+	/** Maximum number of text columns per page; narrowest columns are merged beyond this. Default: 3. */
+	maxColumns?: number;
+
+	/** Minimum column width in PDF points; narrower columns are merged into a neighbour. Default: 50. */
+	minColumnWidth?: number;
+
+	/** Take heading levels from the PDF outline (bookmarks) when present. Default: false. */
+	useOutline?: boolean;
 }
 
 /**
@@ -438,6 +447,18 @@ export interface ExtractionConfig {
 	/** Force OCR processing even for documents with selectable text. Useful for scanned documents. Default: false. */
 	forceOcr?: boolean;
 
+	/** Reconstruct tables from OCR word layout on scanned pages and images. Heuristic and slower. Default: false. */
+	detectImageTables?: boolean;
+
+	/** OCR images embedded in PDF pages and append their text as captioned blocks. Images reused on several pages are recognized once. Costly. Default: false. */
+	ocrEmbeddedImages?: boolean;
+
+	/** Extract without OCR instead of failing when the OCR backend is unavailable. The embedded text is returned with an "ocr_unavailable" warning. Default: false. */
+	ocrOptional?: boolean;
+
+	/** Encoding label for plain-text and HTML input (e.g. "windows-1252", "shift_jis"). Detected automatically when unset. */
+	encodingOverride?: string;
+
 	/** Chunking configuration for splitting documents into smaller pieces for RAG or vector DB. */
 	chunking?: ChunkingConfig;
 
@@ -468,6 +489,76 @@ export interface ExtractionConfig {
 	/** Maximum number of concurrent extractions in batch operations. Default: 4. */
 	maxConcurrentExtractions?: number;
 
+	/**
+	 * Process batch extractions sequentially instead of in parallel. Use in environments
+	 * without a working thread pool. Default: false.
+	 */
+	forceSingleThreaded?: boolean;
+
+	/**
+	 * Maximum input size in bytes. Larger files and buffers are rejected before they are read or
+	 * processed. Default: unlimited.
+	 */
+	maxInputBytes?: number;
+
+	/**
+	 * Maximum number of pages to extract. Only the first N pages are processed and a
+	 * "truncated" warning is added when pages were dropped. Currently enforced for PDFs.
+	 * Default: unlimited.
+	 */
+	maxPages?: number;
+
+	/**
+	 * Return the pages read before a damaged page instead of failing. The result has
+	 * `partial: true` and a "partial_content" warning. Currently supported for PDFs.
+	 * Default: false.
+	 */
+	allowPartial?: boolean;
+
+	/**
+	 * Detect the primary content language and store it in `metadata.language` when the document
+	 * does not declare one. Default: false.
+	 */
+	detectLanguage?: boolean;
+
+	/**
+	 * Append filled PDF form field values to `content` as a labeled "Form Fields" section.
+	 * Values are always available in `metadata.formFields`. Default: false.
+	 */
+	extractFormFields?: boolean;
+
+	/**
+	 * Normalize whitespace in `content`: collapse repeated and non-breaking spaces, join words
+	 * hyphenated across line breaks, and normalize line endings. Paragraph breaks, indentation,
+	 * and fenced code blocks are preserved. Default: false.
+	 */
+	normalizeWhitespace?: boolean;
+
+	/**
+	 * Field delimiter for CSV and TSV input, as a single character.
+	 * Detected from the data when unset (TSV defaults to tab).
+	 */
+	csvDelimiter?: string;
+
+	/**
+	 * Remove running headers and footers (page numbers, repeated titles) from PDF text.
+	 * Lines repeated at the same position on more than half of the pages are stripped;
+	 * documents with fewer than three pages are unchanged. Default: false.
+	 */
+	stripRunningHeaders?: boolean;
+
+	/**
+	 * Report the position of every word (text, page number, bounding box in PDF points)
+	 * in the result's `layout` field. Currently populated for PDFs only. Default: false.
+	 */
+	includeLayout?: boolean;
+
+	/**
+	 * Include speaker notes from `ppt/notesSlides` in presentation output,
+	 * appended to their slide under a "Notes" heading. Default: true.
+	 */
+	includeSpeakerNotes?: boolean;
+
 	/**
 	 * Output text format for extracted content. Default: "plain".
 	 *
@@ -487,6 +578,16 @@ export interface ExtractionConfig {
 	resultFormat?: "unified" | "element_based";
 }
 
+/**
+ * A single invalid configuration value reported by `ExtractionConfig.validate()`.
+ */
+export interface ConfigError {
+	/** Dotted path of the offending field (e.g. "ocr.tesseract_config.psm") */
+	field: string;
+	/** Description of the problem */
+	message: string;
+}
+
 /**
  * Extracted table data from document.
  *
@@ -501,6 +602,9 @@ export interface Table {
 
 	/** Page number where this table was found (1-indexed) */
 	pageNumber: number;
+
+	/** Table caption, when the source format provides one */
+	caption?: string | null;
 }
 
 export interface ExcelMetadata {
@@ -710,6 +814,23 @@ export interface PageStructure {
 	pages?: PageInfo[] | null;
 }
 
+/**
+ * Document outline (bookmark) entry.
+ */
+export interface OutlineEntry {
+	/** Entry title as shown in the outline */
+	title: string;
+	/** Nesting level, starting at 1 for top-level entries */
+	level: number;
+	/** Target page number (1-indexed), if the entry points to a page */
+	page?: number | null;
+}
+
+/**
+ * Whether a document's text is embedded (digital), image-only (scanned), or both.
+ */
+export type DocumentKind = "digital" | "scanned" | "mixed";
+
 /**
  * Metadata about a chunk's position and properties in the document.
  *
@@ -893,6 +1014,10 @@ export interface Metadata {
 
 	page_structure?: PageStructure | null;
 
+	outline?: OutlineEntry[] | null;
+
+	document_kind?: DocumentKind | null;
+
 	error?: ErrorMetadata | null;
 
 	/**
@@ -1008,6 +1133,9 @@ export interface ExtractionResult {
 
 	/** Extracted keywords when keyword extraction is enabled, null otherwise */
 	keywords?: ExtractedKeyword[] | null;
+
+	/** True when `allowPartial` was set and extraction stopped at a damaged page; only earlier pages are included */
+	partial?: boolean;
 }
 
 /** Post-processor execution stage in the extraction pipeline. */
@@ -16,6 +16,7 @@ pub fn to_php_exception(error: kreuzberg::KreuzbergError) -> PhpException {
     match error {
         KreuzbergError::Validation { .. } => PhpException::default(format!("[Validation] {}", message)),
         KreuzbergError::UnsupportedFormat(_) => PhpException::default(format!("[UnsupportedFormat] {}", message)),
+        KreuzbergError::InputTooLarge { .. } => PhpException::default(format!("[InputTooLarge] {}", message)),
         KreuzbergError::Parsing { .. } => PhpException::default(format!("[Parsing] {}", message)),
         KreuzbergError::Io(_) => PhpException::default(format!("[IO] {}", message)),
         KreuzbergError::Ocr { .. } => PhpException::default(format!("[OCR] {}", message)),
@@ -25,6 +26,7 @@ pub fn to_php_exception(error: kreuzberg::KreuzbergError) -> PhpException {
         KreuzbergError::ImageProcessing { .. } => PhpException::default(format!("[ImageProcessing] {}", message)),
         KreuzbergError::Serialization { .. } => PhpException::default(format!("[Serialization] {}", message)),
         KreuzbergError::MissingDependency(_) => PhpException::default(format!("[MissingDependency] {}", message)),
+        KreuzbergError::Network { .. } => PhpException::default(format!("[Network] {}", message)),
         KreuzbergError::Other(_) => PhpException::default(format!("[Other] {}", message)),
     }
 }
@@ -42,6 +44,7 @@ fn format_error_message(error: &kreuzberg::KreuzbergError) -> String {
             }
         }
         KreuzbergError::UnsupportedFormat(msg) => msg.clone(),
+        KreuzbergError::InputTooLarge { .. } => error.to_string(),
         KreuzbergError::Parsing { message, source } => {
             if let Some(src) = source {
                 format!("{}: {}", message, src)
@@ -83,6 +86,13 @@ fn format_error_message(error: &kreuzberg::KreuzbergError) -> String {
             }
         }
         KreuzbergError::MissingDependency(msg) => msg.clone(),
+        KreuzbergError::Network { message, source } => {
+            if let Some(src) = source {
+                format!("{}: {}", message, src)
+            } else {
+                message.clone()
+            }
+        }
         KreuzbergError::Other(msg) => msg.clone(),
     }
 }
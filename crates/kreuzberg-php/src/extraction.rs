@@ -174,8 +174,12 @@ pub fn kreuzberg_extract_bytes(
                         detected_languages: None,
                         chunks: None,
                         images: None,
+                        links: vec![],
+                        warnings: vec![],
+                        partial: false,
                         pages: None,
                         elements: None,
+                        layout: None,
                         djot_content: None,
                     };
 
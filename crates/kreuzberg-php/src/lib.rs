@@ -161,5 +161,8 @@ pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
     // Error module
     module = module.class::<error::ErrorClassification>();
 
+    // Validation module
+    module = module.class::<validation::LanguageGuess>();
+
     module
 }
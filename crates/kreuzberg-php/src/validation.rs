@@ -85,6 +85,51 @@ pub fn kreuzberg_validate_language_code(code: String) -> bool {
     kreuzberg::core::config_validation::validate_language_code(&code).is_ok()
 }
 
+/// Detected language of a text.
+///
+/// # Properties
+///
+/// - `code` (string): ISO 639-3 language code (e.g. "eng", "deu")
+/// - `confidence` (float): Detection confidence (0.0-1.0)
+#[php_class]
+#[php(name = "Kreuzberg\\Validation\\LanguageGuess")]
+#[derive(Clone)]
+pub struct LanguageGuess {
+    #[php(prop)]
+    pub code: String,
+    #[php(prop)]
+    pub confidence: f64,
+}
+
+#[php_impl]
+impl LanguageGuess {}
+
+/// Detect the most likely language of a text.
+///
+/// # Parameters
+///
+/// - `text` (string): The text to analyze
+///
+/// # Returns
+///
+/// `LanguageGuess` object, or `null` if the text is too short to detect
+///
+/// # Example
+///
+/// ```php
+/// $guess = kreuzberg_detect_language("Das ist ein deutscher Beispielsatz.");
+/// if ($guess !== null) {
+///     echo "{$guess->code} ({$guess->confidence})\n";
+/// }
+/// ```
+#[php_function]
+pub fn kreuzberg_detect_language(text: String) -> Option<LanguageGuess> {
+    kreuzberg::language_detection::detect_language(&text).map(|guess| LanguageGuess {
+        code: guess.code,
+        confidence: guess.confidence as f64,
+    })
+}
+
 /// Validate a token reduction level.
 ///
 /// # Parameters
@@ -343,6 +388,7 @@ pub fn get_function_builders() -> Vec<ext_php_rs::builders::FunctionBuilder<'sta
         wrap_function!(kreuzberg_validate_binarization_method),
         wrap_function!(kreuzberg_validate_ocr_backend),
         wrap_function!(kreuzberg_validate_language_code),
+        wrap_function!(kreuzberg_detect_language),
         wrap_function!(kreuzberg_validate_token_reduction_level),
         wrap_function!(kreuzberg_validate_tesseract_psm),
         wrap_function!(kreuzberg_validate_tesseract_oem),
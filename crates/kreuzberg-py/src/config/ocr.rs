@@ -193,6 +193,12 @@ impl OcrConfig {
                 language: language.unwrap_or_else(|| "eng".to_string()),
                 tesseract_config: tesseract_config.map(Into::into),
                 output_format: None,
+                dpi: None,
+                psm: None,
+                oem: None,
+                binarization: None,
+                ocr_pages: None,
+                language_fallback: None,
             },
         }
     }
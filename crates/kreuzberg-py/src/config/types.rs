@@ -56,7 +56,22 @@ impl ExtractionConfig {
         max_concurrent_extractions=None,
         pages=None,
         result_format=None,
-        output_format=None
+        output_format=None,
+        detect_image_tables=None,
+        ocr_embedded_images=None,
+        ocr_optional=None,
+        encoding_override=None,
+        force_single_threaded=None,
+        max_input_bytes=None,
+        max_pages=None,
+        allow_partial=None,
+        detect_language=None,
+        extract_form_fields=None,
+        normalize_whitespace=None,
+        csv_delimiter=None,
+        strip_running_headers=None,
+        include_layout=None,
+        include_speaker_notes=None
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -76,6 +91,21 @@ impl ExtractionConfig {
         pages: Option<PageConfig>,
         result_format: Option<String>,
         output_format: Option<String>,
+        detect_image_tables: Option<bool>,
+        ocr_embedded_images: Option<bool>,
+        ocr_optional: Option<bool>,
+        encoding_override: Option<String>,
+        force_single_threaded: Option<bool>,
+        max_input_bytes: Option<u64>,
+        max_pages: Option<usize>,
+        allow_partial: Option<bool>,
+        detect_language: Option<bool>,
+        extract_form_fields: Option<bool>,
+        normalize_whitespace: Option<bool>,
+        csv_delimiter: Option<char>,
+        strip_running_headers: Option<bool>,
+        include_layout: Option<bool>,
+        include_speaker_notes: Option<bool>,
     ) -> PyResult<Self> {
         let (html_options_inner, html_options_dict) = parse_html_options_dict(html_options)?;
         Ok(Self {
@@ -84,6 +114,9 @@ impl ExtractionConfig {
                 enable_quality_processing: enable_quality_processing.unwrap_or(true),
                 ocr: ocr.map(Into::into),
                 force_ocr: force_ocr.unwrap_or(false),
+                detect_image_tables: detect_image_tables.unwrap_or(false),
+                ocr_embedded_images: ocr_embedded_images.unwrap_or(false),
+                ocr_optional: ocr_optional.unwrap_or(false),
                 chunking: chunking.map(Into::into),
                 images: images.map(Into::into),
                 pdf_options: pdf_options.map(Into::into),
@@ -93,6 +126,7 @@ impl ExtractionConfig {
                 postprocessor: postprocessor.map(Into::into),
                 html_options: html_options_inner,
                 max_concurrent_extractions,
+                force_single_threaded: force_single_threaded.unwrap_or(false),
                 pages: pages.map(Into::into),
                 result_format: if let Some(rf) = result_format {
                     match rf.to_lowercase().as_str() {
@@ -124,6 +158,17 @@ impl ExtractionConfig {
                 } else {
                     kreuzberg::core::config::formats::OutputFormat::Plain
                 },
+                encoding_override,
+                max_input_bytes,
+                max_pages,
+                allow_partial: allow_partial.unwrap_or(false),
+                detect_language: detect_language.unwrap_or(false),
+                extract_form_fields: extract_form_fields.unwrap_or(false),
+                normalize_whitespace: normalize_whitespace.unwrap_or(false),
+                csv_delimiter,
+                strip_running_headers: strip_running_headers.unwrap_or(false),
+                include_layout: include_layout.unwrap_or(false),
+                include_speaker_notes: include_speaker_notes.unwrap_or(true),
             },
             html_options_dict,
         })
@@ -169,6 +214,46 @@ impl ExtractionConfig {
         self.inner.force_ocr = value;
     }
 
+    #[getter]
+    fn detect_image_tables(&self) -> bool {
+        self.inner.detect_image_tables
+    }
+
+    #[setter]
+    fn set_detect_image_tables(&mut self, value: bool) {
+        self.inner.detect_image_tables = value;
+    }
+
+    #[getter]
+    fn ocr_embedded_images(&self) -> bool {
+        self.inner.ocr_embedded_images
+    }
+
+    #[setter]
+    fn set_ocr_embedded_images(&mut self, value: bool) {
+        self.inner.ocr_embedded_images = value;
+    }
+
+    #[getter]
+    fn ocr_optional(&self) -> bool {
+        self.inner.ocr_optional
+    }
+
+    #[setter]
+    fn set_ocr_optional(&mut self, value: bool) {
+        self.inner.ocr_optional = value;
+    }
+
+    #[getter]
+    fn encoding_override(&self) -> Option<String> {
+        self.inner.encoding_override.clone()
+    }
+
+    #[setter]
+    fn set_encoding_override(&mut self, value: Option<String>) {
+        self.inner.encoding_override = value;
+    }
+
     #[getter]
     fn chunking(&self) -> Option<ChunkingConfig> {
         self.inner.chunking.clone().map(Into::into)
@@ -249,6 +334,116 @@ impl ExtractionConfig {
         self.inner.max_concurrent_extractions = value;
     }
 
+    #[getter]
+    fn force_single_threaded(&self) -> bool {
+        self.inner.force_single_threaded
+    }
+
+    #[setter]
+    fn set_force_single_threaded(&mut self, value: bool) {
+        self.inner.force_single_threaded = value;
+    }
+
+    #[getter]
+    fn max_input_bytes(&self) -> Option<u64> {
+        self.inner.max_input_bytes
+    }
+
+    #[setter]
+    fn set_max_input_bytes(&mut self, value: Option<u64>) {
+        self.inner.max_input_bytes = value;
+    }
+
+    #[getter]
+    fn max_pages(&self) -> Option<usize> {
+        self.inner.max_pages
+    }
+
+    #[setter]
+    fn set_max_pages(&mut self, value: Option<usize>) {
+        self.inner.max_pages = value;
+    }
+
+    #[getter]
+    fn allow_partial(&self) -> bool {
+        self.inner.allow_partial
+    }
+
+    #[setter]
+    fn set_allow_partial(&mut self, value: bool) {
+        self.inner.allow_partial = value;
+    }
+
+    #[getter]
+    fn detect_language(&self) -> bool {
+        self.inner.detect_language
+    }
+
+    #[setter]
+    fn set_detect_language(&mut self, value: bool) {
+        self.inner.detect_language = value;
+    }
+
+    #[getter]
+    fn extract_form_fields(&self) -> bool {
+        self.inner.extract_form_fields
+    }
+
+    #[setter]
+    fn set_extract_form_fields(&mut self, value: bool) {
+        self.inner.extract_form_fields = value;
+    }
+
+    #[getter]
+    fn normalize_whitespace(&self) -> bool {
+        self.inner.normalize_whitespace
+    }
+
+    #[setter]
+    fn set_normalize_whitespace(&mut self, value: bool) {
+        self.inner.normalize_whitespace = value;
+    }
+
+    #[getter]
+    fn csv_delimiter(&self) -> Option<char> {
+        self.inner.csv_delimiter
+    }
+
+    #[setter]
+    fn set_csv_delimiter(&mut self, value: Option<char>) {
+        self.inner.csv_delimiter = value;
+    }
+
+    #[getter]
+    fn strip_running_headers(&self) -> bool {
+        self.inner.strip_running_headers
+    }
+
+    #[setter]
+    fn set_strip_running_headers(&mut self, value: bool) {
+        self.inner.strip_running_headers = value;
+    }
+
+    #[getter]
+    fn include_layout(&self) -> bool {
+        self.inner.include_layout
+    }
+
+    #[setter]
+    fn set_include_layout(&mut self, value: bool) {
+        self.inner.include_layout = value;
+    }
+
+    #[getter]
+    fn include_speaker_notes(&self) -> bool {
+        self.inner.include_speaker_notes
+    }
+
+    #[setter]
+    fn set_include_speaker_notes(&mut self, value: bool) {
+        self.inner.include_speaker_notes = value;
+    }
+
     #[getter]
     fn html_options<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>> {
         self.html_options_dict.as_ref().map(|dict| dict.bind(py).clone())
@@ -405,6 +600,12 @@ impl OcrConfig {
                 language: language.unwrap_or_else(|| "eng".to_string()),
                 tesseract_config: tesseract_config.map(Into::into),
                 output_format: None,
+                dpi: None,
+                psm: None,
+                oem: None,
+                binarization: None,
+                ocr_pages: None,
+                language_fallback: None,
             },
         }
     }
@@ -1611,19 +1812,27 @@ pub struct HierarchyConfig {
 #[pymethods]
 impl HierarchyConfig {
     #[new]
-    #[pyo3(signature = (enabled=None, k_clusters=None, include_bbox=None, ocr_coverage_threshold=None))]
+    #[pyo3(signature = (enabled=None, k_clusters=None, include_bbox=None, ocr_coverage_threshold=None, max_columns=None, min_column_width=None, use_outline=None))]
     fn new(
         enabled: Option<bool>,
         k_clusters: Option<usize>,
         include_bbox: Option<bool>,
         ocr_coverage_threshold: Option<f32>,
+        max_columns: Option<usize>,
+        min_column_width: Option<f32>,
+        use_outline: Option<bool>,
     ) -> Self {
+        let defaults = kreuzberg::core::config::HierarchyConfig::default();
         Self {
             inner: kreuzberg::core::config::HierarchyConfig {
                 enabled: enabled.unwrap_or(true),
                 k_clusters: k_clusters.unwrap_or(6),
                 include_bbox: include_bbox.unwrap_or(true),
                 ocr_coverage_threshold,
+                max_columns: max_columns.unwrap_or(defaults.max_columns),
+                min_column_width: min_column_width.unwrap_or(defaults.min_column_width),
+                column_mode: defaults.column_mode,
+                use_outline: use_outline.unwrap_or(defaults.use_outline),
             },
         }
     }
@@ -1668,10 +1877,46 @@ impl HierarchyConfig {
         self.inner.ocr_coverage_threshold = value;
     }
 
+    #[getter]
+    fn max_columns(&self) -> usize {
+        self.inner.max_columns
+    }
+
+    #[setter]
+    fn set_max_columns(&mut self, value: usize) {
+        self.inner.max_columns = value;
+    }
+
+    #[getter]
+    fn min_column_width(&self) -> f32 {
+        self.inner.min_column_width
+    }
+
+    #[setter]
+    fn set_min_column_width(&mut self, value: f32) {
+        self.inner.min_column_width = value;
+    }
+
+    #[getter]
+    fn use_outline(&self) -> bool {
+        self.inner.use_outline
+    }
+
+    #[setter]
+    fn set_use_outline(&mut self, value: bool) {
+        self.inner.use_outline = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
-            "HierarchyConfig(enabled={}, k_clusters={}, include_bbox={}, ocr_coverage_threshold={:?})",
-            self.inner.enabled, self.inner.k_clusters, self.inner.include_bbox, self.inner.ocr_coverage_threshold
+            "HierarchyConfig(enabled={}, k_clusters={}, include_bbox={}, ocr_coverage_threshold={:?}, max_columns={}, min_column_width={}, use_outline={})",
+            self.inner.enabled,
+            self.inner.k_clusters,
+            self.inner.include_bbox,
+            self.inner.ocr_coverage_threshold,
+            self.inner.max_columns,
+            self.inner.min_column_width,
+            self.inner.use_outline
         )
     }
 }
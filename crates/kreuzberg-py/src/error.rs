@@ -86,6 +86,7 @@ fn exception_from_module(name: &str, message: String) -> PyErr {
 /// Maps error variants to appropriate Python exception types:
 /// - `Validation` → `ValidationError` (custom exception)
 /// - `UnsupportedFormat` → `ValidationError` (custom exception)
+/// - `InputTooLarge` → `ValidationError` (custom exception)
 /// - `Parsing` → `ParsingError` (custom exception)
 /// - `Io` → `OSError` (system error - must bubble up!)
 /// - `Ocr` → `OCRError` (custom exception)
@@ -95,6 +96,7 @@ fn exception_from_module(name: &str, message: String) -> PyErr {
 /// - `ImageProcessing` → `ImageProcessingError` (custom exception)
 /// - `Serialization` → `ParsingError` (document processing failure)
 /// - `MissingDependency` → `MissingDependencyError` (custom exception)
+/// - `Network` → `KreuzbergError` (base exception)
 /// - `Other` → `RuntimeError` (runtime error - must bubble up!)
 ///
 /// All errors preserve their source chain for better debugging.
@@ -111,6 +113,7 @@ pub fn to_py_err(error: kreuzberg::KreuzbergError) -> PyErr {
             exception_from_module("ValidationError", format_error_with_source(message, source))
         }
         KreuzbergError::UnsupportedFormat(msg) => exception_from_module("ValidationError", msg),
+        err @ KreuzbergError::InputTooLarge { .. } => exception_from_module("ValidationError", err.to_string()),
         KreuzbergError::Parsing { message, source } => {
             exception_from_module("ParsingError", format_error_with_source(message, source))
         }
@@ -134,6 +137,9 @@ pub fn to_py_err(error: kreuzberg::KreuzbergError) -> PyErr {
             exception_from_module("ParsingError", format_error_with_source(message, source))
         }
         KreuzbergError::MissingDependency(msg) => exception_from_module("MissingDependencyError", msg),
+        KreuzbergError::Network { message, source } => {
+            exception_from_module("KreuzbergError", format_error_with_source(message, source))
+        }
         // RuntimeError must bubble up - unexpected errors need user reports ~keep
         KreuzbergError::Other(msg) => PyRuntimeError::new_err(msg),
     }
@@ -105,6 +105,7 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(plugins::clear_post_processors, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::list_post_processors, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::register_validator, m)?)?;
+    m.add_function(wrap_pyfunction!(plugins::register_min_text_length_validator, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::unregister_validator, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::clear_validators, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::list_validators, m)?)?;
@@ -120,6 +121,7 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_function(wrap_pyfunction!(detect_mime_type_from_bytes, m)?)?;
     m.add_function(wrap_pyfunction!(detect_mime_type_from_path, m)?)?;
+    m.add_function(wrap_pyfunction!(detect_mime, m)?)?;
     m.add_function(wrap_pyfunction!(validate_mime_type, m)?)?;
     m.add_function(wrap_pyfunction!(get_extensions_for_mime, m)?)?;
     m.add_function(wrap_pyfunction!(get_last_error_code, m)?)?;
@@ -128,6 +130,7 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(validation::validate_binarization_method, m)?)?;
     m.add_function(wrap_pyfunction!(validation::validate_ocr_backend, m)?)?;
     m.add_function(wrap_pyfunction!(validation::validate_language_code, m)?)?;
+    m.add_function(wrap_pyfunction!(validation::detect_language, m)?)?;
     m.add_function(wrap_pyfunction!(validation::validate_token_reduction_level, m)?)?;
     m.add_function(wrap_pyfunction!(validation::validate_tesseract_psm, m)?)?;
     m.add_function(wrap_pyfunction!(validation::validate_tesseract_oem, m)?)?;
@@ -296,6 +299,38 @@ fn detect_mime_type_from_path(path: &str) -> PyResult<String> {
     kreuzberg::detect_mime_type(path, true).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
 }
 
+/// Detect MIME type from both a file path and its content.
+///
+/// Compares the extension-based and content-based MIME types and resolves any
+/// disagreement according to `policy`.
+///
+/// Args:
+///     path (str): File path or name whose extension is checked (need not exist)
+///     data (bytes): File content as bytes
+///     policy (str): "prefer_content" (default), "prefer_extension", or "strict"
+///
+/// Returns:
+///     tuple[str, bool]: The chosen MIME type and whether content and extension disagreed
+///
+/// Raises:
+///     ValueError: If the policy is invalid
+///     RuntimeError: If the sources disagree under "strict", or no MIME type can be determined
+///
+/// Example:
+///     >>> from kreuzberg import detect_mime
+///     >>> mime_type, mismatch = detect_mime("upload.txt", b"%PDF-1.4\n")
+///     >>> assert mime_type == "application/pdf" and mismatch
+#[pyfunction]
+#[pyo3(signature = (path, data, policy="prefer_content"))]
+fn detect_mime(path: &str, data: &[u8], policy: &str) -> PyResult<(String, bool)> {
+    let policy = policy
+        .parse::<kreuzberg::MimePolicy>()
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let detection = kreuzberg::detect_mime(path, data, policy)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    Ok((detection.mime_type, detection.mismatch))
+}
+
 /// Validate and normalize a MIME type.
 ///
 /// Checks if the provided MIME type is supported. Accepts specific supported types
@@ -126,7 +126,9 @@ pub use ocr_bridge::register_ocr_backend;
 pub use processor_bridge::{
     clear_post_processors, list_post_processors, register_post_processor, unregister_post_processor,
 };
-pub use validator_bridge::{clear_validators, list_validators, register_validator, unregister_validator};
+pub use validator_bridge::{
+    clear_validators, list_validators, register_min_text_length_validator, register_validator, unregister_validator,
+};
 
 // OCR backend management functions
 #[pyo3::pyfunction]
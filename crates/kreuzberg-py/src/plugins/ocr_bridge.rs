@@ -383,8 +383,12 @@ fn dict_to_extraction_result(_py: Python<'_>, dict: &Bound<'_, PyAny>) -> Result
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
         elements: None,
+        layout: None,
         djot_content: None,
     })
 }
@@ -541,6 +545,7 @@ fn extract_tables(obj: &Bound<'_, PyAny>) -> Result<Vec<kreuzberg::types::Table>
             cells,
             markdown,
             page_number,
+            caption: None,
         });
     }
 
@@ -340,6 +340,37 @@ pub fn register_validator(py: Python<'_>, validator: Py<PyAny>) -> PyResult<()>
     Ok(())
 }
 
+/// Register the built-in minimum text length validator.
+///
+/// Adds a `validation` warning to the result's warnings when the
+/// extracted text has fewer than `min_length` characters. Extraction never fails.
+///
+/// # Arguments
+///
+/// * `min_length` - Minimum number of characters, ignoring surrounding whitespace
+///
+/// # Example
+///
+/// ```python
+/// from kreuzberg import register_min_text_length_validator
+///
+/// register_min_text_length_validator(50)
+/// ```
+///
+/// Calling this again replaces the previously registered threshold.
+#[pyfunction]
+pub fn register_min_text_length_validator(py: Python<'_>, min_length: usize) -> PyResult<()> {
+    py.detach(|| {
+        kreuzberg::plugins::register_validator(kreuzberg::plugins::min_text_length_validator(min_length)).map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!(
+                "Failed to register Validator '{}': {}",
+                kreuzberg::plugins::MinTextLengthValidator::NAME,
+                e
+            ))
+        })
+    })
+}
+
 /// Unregister a Validator by name.
 ///
 /// Removes a previously registered validator from the global registry and
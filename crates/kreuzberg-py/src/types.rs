@@ -57,6 +57,9 @@ pub struct ExtractionResult {
     pub result_format: Option<String>,
 
     djot_content: Option<Py<PyAny>>,
+
+    #[pyo3(get)]
+    pub partial: bool,
 }
 
 #[pymethods]
@@ -516,6 +519,7 @@ impl ExtractionResult {
             output_format,
             result_format,
             djot_content,
+            partial: result.partial,
         })
     }
 }
@@ -550,8 +554,12 @@ mod tests {
                 detected_languages: Some(vec!["en".to_string()]),
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
                 pages: None,
                 elements: None,
+                layout: None,
                 djot_content: None,
             };
 
@@ -580,8 +588,12 @@ mod tests {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
                 pages: None,
                 elements: None,
+                layout: None,
                 djot_content: None,
             };
             rust_result
@@ -671,6 +683,7 @@ impl PyChunk {
 ///     cells (list[list[str]]): Table data as nested lists (rows of columns)
 ///     markdown (str): Markdown representation of the table
 ///     page_number (int): Page number where table was found
+///     caption (str | None): Table caption, when the source format provides one
 ///
 /// Example:
 ///     >>> result = extract_file_sync("document.pdf", None, ExtractionConfig())
@@ -687,6 +700,9 @@ pub struct ExtractedTable {
 
     #[pyo3(get)]
     pub page_number: usize,
+
+    #[pyo3(get)]
+    pub caption: Option<String>,
 }
 
 #[pymethods]
@@ -734,6 +750,7 @@ impl ExtractedTable {
             cells: cells.unbind(),
             markdown: table.markdown,
             page_number: table.page_number,
+            caption: table.caption,
         })
     }
 }
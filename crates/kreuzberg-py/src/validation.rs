@@ -12,6 +12,7 @@ use kreuzberg::core::config_validation::{
     validate_token_reduction_level as validate_token_reduction_level_core,
 };
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 /// Validate a binarization method.
 ///
@@ -49,6 +50,26 @@ pub fn validate_language_code(code: &str) -> PyResult<bool> {
     Ok(validate_language_code_core(code).is_ok())
 }
 
+/// Detect the most likely language of a text.
+///
+/// Args:
+///     text (str): The text to analyze
+///
+/// Returns:
+///     dict | None: ``{"code": str, "confidence": float}`` with an ISO 639-3 code
+///     (e.g. "eng", "deu"), or None if the text is too short to detect
+#[pyfunction]
+pub fn detect_language<'py>(py: Python<'py>, text: &str) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let Some(guess) = kreuzberg::language_detection::detect_language(text) else {
+        return Ok(None);
+    };
+
+    let dict = PyDict::new(py);
+    dict.set_item("code", guess.code)?;
+    dict.set_item("confidence", guess.confidence)?;
+    Ok(Some(dict))
+}
+
 /// Validate a token reduction level.
 ///
 /// Args:
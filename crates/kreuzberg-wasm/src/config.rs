@@ -115,6 +115,43 @@ pub fn discover_config() -> Result<JsValue, JsValue> {
     ))
 }
 
+/// Validate every field of an extraction configuration.
+///
+/// Unlike extraction, which fails on the first invalid setting, this reports all
+/// invalid values at once.
+///
+/// # JavaScript Parameters
+///
+/// * `config: object` - The extraction configuration to validate
+///
+/// # Returns
+///
+/// `Array<{field: string, message: string}>` - Every invalid field; empty when the
+/// configuration is valid
+///
+/// # Throws
+///
+/// Throws an error if the object cannot be parsed as an ExtractionConfig.
+///
+/// # Example
+///
+/// ```javascript
+/// import { validateConfig } from '@kreuzberg/wasm';
+///
+/// const errors = validateConfig({ max_concurrent_extractions: 0 });
+/// for (const error of errors) {
+///   console.error(`${error.field}: ${error.message}`);
+/// }
+/// ```
+#[wasm_bindgen(js_name = validateConfig)]
+pub fn validate_config(config: JsValue) -> Result<JsValue, JsValue> {
+    let config = crate::types::parse_config(Some(config))?;
+    let errors = config.validate().err().unwrap_or_default();
+
+    serde_wasm_bindgen::to_value(&errors)
+        .map_err(|e| JsValue::from_str(&format!("Failed to convert validation errors to JS value: {}", e)))
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(target_arch = "wasm32")]
@@ -162,6 +199,14 @@ enable_quality_processing: true"#;
         assert!(result.is_err());
     }
 
+    #[test]
+    #[cfg(target_arch = "wasm32")]
+    fn test_validate_config_reports_errors() {
+        let config = js_sys::JSON::parse(r#"{"max_concurrent_extractions": 0}"#).unwrap();
+        let errors = validate_config(config).unwrap();
+        assert_eq!(js_sys::Array::from(&errors).length(), 1);
+    }
+
     #[test]
     #[cfg(target_arch = "wasm32")]
     fn test_discover_config_not_available() {
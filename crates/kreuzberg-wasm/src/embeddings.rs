@@ -2,10 +2,15 @@
 //!
 //! This module provides functions for accessing and managing text embedding presets
 //! in WebAssembly environments. Presets provide pre-configured models optimized for
-//! different use cases (fast, balanced, quality, multilingual).
+//! different use cases (fast, balanced, quality, multilingual), for generating
+//! embeddings from text or document chunks with a preset, and for comparing embedding vectors.
 
 #[cfg(feature = "embeddings")]
-use js_sys::Array;
+use crate::errors::convert_error;
+#[cfg(feature = "embeddings")]
+use crate::types::parse_config;
+#[cfg(feature = "embeddings")]
+use js_sys::{Array, Float32Array, Uint8Array};
 #[cfg(feature = "embeddings")]
 use wasm_bindgen::prelude::*;
 
@@ -124,6 +129,178 @@ pub fn get_embedding_preset(name: String) -> Option<JsValue> {
     Some(obj.into())
 }
 
+/// Generate an embedding vector for a text using a named preset.
+///
+/// Texts longer than the preset's chunk size are split into overlapping windows,
+/// embedded separately, and mean-pooled into a single L2-normalized vector.
+///
+/// # JavaScript Parameters
+///
+/// * `text: string` - The text to embed
+/// * `preset: string` - The preset name (e.g., "balanced", "fast")
+///
+/// # Returns
+///
+/// `Float32Array` - Embedding vector with the preset's dimensions
+///
+/// # Throws
+///
+/// Throws an error if the text is empty, the preset is unknown, or the model
+/// fails to load or run.
+///
+/// # Example
+///
+/// ```javascript
+/// import { generateEmbeddings, getEmbeddingPreset } from '@kreuzberg/wasm';
+///
+/// const embedding = generateEmbeddings('Kreuzberg extracts text from documents.', 'fast');
+/// console.log(embedding.length === getEmbeddingPreset('fast').dimensions); // true
+/// ```
+#[cfg(feature = "embeddings")]
+#[wasm_bindgen(js_name = generateEmbeddings)]
+pub fn generate_embeddings(text: String, preset: String) -> Result<Float32Array, JsValue> {
+    let embedding = kreuzberg::generate_embeddings(&text, &preset).map_err(convert_error)?;
+    Ok(Float32Array::from(embedding.as_slice()))
+}
+
+/// Extract a document, chunk its content and embed every chunk with a preset.
+///
+/// Chunking follows `config.chunking` when set (including the chunker type);
+/// otherwise the preset's chunk size and overlap are used.
+///
+/// # JavaScript Parameters
+///
+/// * `data: Uint8Array` - The document bytes
+/// * `mimeType: string` - MIME type of the data (e.g., "application/pdf")
+/// * `config?: object` - Optional extraction configuration
+/// * `preset: string` - The preset name (e.g., "balanced", "fast")
+///
+/// # Returns
+///
+/// `Array<{ text: string, embedding: Float32Array, page: number | null, offset: number }>` -
+/// One entry per chunk in document order; `page` is set when page tracking is enabled
+///
+/// # Throws
+///
+/// Throws an error if the preset is unknown, extraction or chunking fails, or the
+/// model fails to load or run.
+///
+/// # Example
+///
+/// ```javascript
+/// import { extractAndEmbed } from '@kreuzberg/wasm';
+///
+/// const chunks = extractAndEmbed(data, 'application/pdf', null, 'fast');
+/// for (const { text, embedding, offset } of chunks) {
+///   console.log(offset, embedding.length, text.slice(0, 40));
+/// }
+/// ```
+#[cfg(feature = "embeddings")]
+#[wasm_bindgen(js_name = extractAndEmbed)]
+pub fn extract_and_embed(
+    data: Uint8Array,
+    mime_type: String,
+    config: Option<JsValue>,
+    preset: String,
+) -> Result<Array, JsValue> {
+    let extraction_config = parse_config(config)?;
+    let chunks = kreuzberg::extract_bytes_and_embed_sync(&data.to_vec(), &mime_type, &extraction_config, &preset)
+        .map_err(convert_error)?;
+
+    let results = Array::new();
+    for chunk in chunks {
+        let entry = js_sys::Object::new();
+        js_sys::Reflect::set(&entry, &"text".into(), &chunk.text.into())?;
+        js_sys::Reflect::set(
+            &entry,
+            &"embedding".into(),
+            &Float32Array::from(chunk.embedding.as_slice()).into(),
+        )?;
+        let page = chunk.page.map_or(JsValue::NULL, |page| (page as u32).into());
+        js_sys::Reflect::set(&entry, &"page".into(), &page)?;
+        js_sys::Reflect::set(&entry, &"offset".into(), &(chunk.offset as u32).into())?;
+        results.push(&entry);
+    }
+
+    Ok(results)
+}
+
+/// Compute the cosine similarity between two embedding vectors.
+///
+/// # JavaScript Parameters
+///
+/// * `a: Float32Array` - First embedding vector
+/// * `b: Float32Array` - Second embedding vector
+///
+/// # Returns
+///
+/// `number` - Similarity in `[-1, 1]`; `0` when either vector is all zeros
+///
+/// # Throws
+///
+/// Throws an error if the vectors have different lengths.
+///
+/// # Example
+///
+/// ```javascript
+/// import { cosineSimilarity, generateEmbeddings } from '@kreuzberg/wasm';
+///
+/// const a = generateEmbeddings('invoice total', 'fast');
+/// const b = generateEmbeddings('amount due', 'fast');
+/// console.log(cosineSimilarity(a, b)); // e.g. 0.71
+/// ```
+#[cfg(feature = "embeddings")]
+#[wasm_bindgen(js_name = cosineSimilarity)]
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> Result<f32, JsValue> {
+    kreuzberg::cosine_similarity(a, b).map_err(convert_error)
+}
+
+/// Find the `k` corpus vectors most similar to a query vector.
+///
+/// # JavaScript Parameters
+///
+/// * `query: Float32Array` - Query embedding vector
+/// * `corpus: Array<Float32Array | number[]>` - Candidate embedding vectors
+/// * `k: number` - Maximum number of results
+///
+/// # Returns
+///
+/// `Array<{ index: number, score: number }>` - Matches sorted by descending score
+///
+/// # Throws
+///
+/// Throws an error if any corpus vector differs in length from the query.
+///
+/// # Example
+///
+/// ```javascript
+/// import { topKSimilar } from '@kreuzberg/wasm';
+///
+/// const matches = topKSimilar(queryEmbedding, chunkEmbeddings, 3);
+/// for (const { index, score } of matches) {
+///   console.log(`chunk ${index}: ${score.toFixed(3)}`);
+/// }
+/// ```
+#[cfg(feature = "embeddings")]
+#[wasm_bindgen(js_name = topKSimilar)]
+pub fn top_k_similar(query: &[f32], corpus: Array, k: usize) -> Result<Array, JsValue> {
+    let corpus: Vec<Vec<f32>> = corpus
+        .iter()
+        .map(|vector| Float32Array::new(&vector).to_vec())
+        .collect();
+    let matches = kreuzberg::top_k_similar(query, &corpus, k).map_err(convert_error)?;
+
+    let results = Array::new();
+    for (index, score) in matches {
+        let entry = js_sys::Object::new();
+        js_sys::Reflect::set(&entry, &"index".into(), &(index as u32).into())?;
+        js_sys::Reflect::set(&entry, &"score".into(), &score.into())?;
+        results.push(&entry);
+    }
+
+    Ok(results)
+}
+
 #[cfg(all(test, feature = "embeddings"))]
 mod tests {
     use super::*;
@@ -142,6 +319,42 @@ mod tests {
         assert!(preset.is_some());
     }
 
+    #[test]
+    #[cfg(target_arch = "wasm32")]
+    fn test_generate_embeddings_unknown_preset() {
+        let result = generate_embeddings("text".to_string(), "nonexistent".to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[cfg(target_arch = "wasm32")]
+    fn test_cosine_similarity_identical() {
+        let score = cosine_similarity(&[0.5, 0.5], &[0.5, 0.5]).unwrap();
+        assert!((score - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    #[cfg(target_arch = "wasm32")]
+    fn test_top_k_similar_ordering() {
+        let corpus = Array::new();
+        corpus.push(&Float32Array::from(&[0.0f32, 1.0][..]));
+        corpus.push(&Float32Array::from(&[1.0f32, 0.0][..]));
+
+        let matches = top_k_similar(&[1.0, 0.0], corpus, 1).unwrap();
+
+        assert_eq!(matches.length(), 1);
+        let index = js_sys::Reflect::get(&matches.get(0), &"index".into()).unwrap();
+        assert_eq!(index.as_f64(), Some(1.0));
+    }
+
+    #[test]
+    #[cfg(target_arch = "wasm32")]
+    fn test_extract_and_embed_unknown_preset() {
+        let data = Uint8Array::from(&b"Some text to embed."[..]);
+        let result = extract_and_embed(data, "text/plain".to_string(), None, "nonexistent".to_string());
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_get_embedding_preset_invalid() {
         let preset = get_embedding_preset("nonexistent".to_string());
@@ -4,12 +4,27 @@
 //! Converts Kreuzberg errors to JsValue for JavaScript/TypeScript consumers.
 
 use kreuzberg::KreuzbergError;
+use serde::Serialize;
+use serde_json::json;
 use wasm_bindgen::prelude::*;
 
+/// Machine-readable error details attached to errors thrown from WASM functions.
+///
+/// `kind` is the name of the `KreuzbergError` variant, so TypeScript callers can
+/// `switch (err.kind)` instead of matching on the message text.
+#[derive(Debug, Serialize)]
+struct ErrorPayload {
+    kind: &'static str,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<serde_json::Value>,
+}
+
 /// Converts KreuzbergError to JsValue with detailed error information.
 ///
-/// Maps Kreuzberg error variants to JavaScript error objects with appropriate
-/// error types and messages:
+/// The returned value is a JavaScript `Error` whose `message` stays human-readable and
+/// which additionally carries `kind` (the `KreuzbergError` variant name) and, where the
+/// variant has structured data, a `context` object. The error `name` reflects the variant:
 ///
 /// - `Io` → Generic I/O error
 /// - `Parsing` → Parsing/malformed document error
@@ -18,47 +33,15 @@ use wasm_bindgen::prelude::*;
 /// - `Cache` → Cache-related error
 /// - `ImageProcessing` → Image manipulation error
 /// - `Serialization` → JSON/serialization error
-/// - `MissingDependency` → Missing system dependency
-/// - `Plugin` → Plugin-specific error
+/// - `MissingDependency` → Missing system dependency (context: `dependency`)
+/// - `Plugin` → Plugin-specific error (context: `pluginName`)
 /// - `LockPoisoned` → Lock poisoning (internal error)
-/// - `UnsupportedFormat` → Unsupported MIME type
+/// - `UnsupportedFormat` → Unsupported MIME type (context: `format`)
+/// - `InputTooLarge` → Input exceeds the configured size limit (context: `size`, `limit`)
+/// - `Network` → Fetching a remote document failed
 /// - `Other` → Generic error
 pub fn convert_error(err: KreuzbergError) -> JsValue {
-    use kreuzberg::KreuzbergError;
-
-    let (error_type, message) = match err {
-        KreuzbergError::Io(e) => ("IOError", format!("IO error: {}", e)),
-
-        KreuzbergError::Parsing { message, .. } => ("ParsingError", format!("Parsing error: {}", message)),
-
-        KreuzbergError::Ocr { message, .. } => ("OCRError", format!("OCR error: {}", message)),
-
-        KreuzbergError::Validation { message, .. } => ("ValidationError", format!("Validation error: {}", message)),
-
-        KreuzbergError::Cache { message, .. } => ("CacheError", format!("Cache error: {}", message)),
-
-        KreuzbergError::ImageProcessing { message, .. } => {
-            ("ImageProcessingError", format!("Image processing error: {}", message))
-        }
-
-        KreuzbergError::Serialization { message, .. } => {
-            ("SerializationError", format!("Serialization error: {}", message))
-        }
-
-        KreuzbergError::MissingDependency(dep) => ("MissingDependencyError", format!("Missing dependency: {}", dep)),
-
-        KreuzbergError::Plugin { message, plugin_name } => {
-            ("PluginError", format!("Plugin error in '{}': {}", plugin_name, message))
-        }
-
-        KreuzbergError::LockPoisoned(msg) => ("LockPoisonedError", format!("Lock poisoned: {}", msg)),
-
-        KreuzbergError::UnsupportedFormat(format) => {
-            ("UnsupportedFormatError", format!("Unsupported format: {}", format))
-        }
-
-        KreuzbergError::Other(msg) => ("Error", msg),
-    };
+    let (error_type, payload) = error_payload(err);
 
     let error_constructor = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("Error"))
         .ok()
@@ -70,11 +53,97 @@ pub fn convert_error(err: KreuzbergError) -> JsValue {
             }
         });
 
-    match error_constructor {
-        Some(ctor) => js_sys::Reflect::construct(&ctor, &js_sys::Array::of1(&JsValue::from_str(&message)))
-            .unwrap_or_else(|_| JsValue::from_str(&format!("{}: {}", error_type, message))),
-        None => JsValue::from_str(&format!("{}: {}", error_type, message)),
+    let Some(ctor) = error_constructor else {
+        return JsValue::from_str(&format!("{}: {}", error_type, payload.message));
+    };
+    let Ok(error) = js_sys::Reflect::construct(&ctor, &js_sys::Array::of1(&JsValue::from_str(&payload.message))) else {
+        return JsValue::from_str(&format!("{}: {}", error_type, payload.message));
+    };
+
+    let _ = js_sys::Reflect::set(&error, &JsValue::from_str("name"), &JsValue::from_str(error_type));
+    if let Ok(details) = payload.serialize(&serde_wasm_bindgen::Serializer::json_compatible()) {
+        js_sys::Object::assign(error.unchecked_ref(), details.unchecked_ref());
     }
+    error
+}
+
+/// Map an error to its JavaScript error name and structured payload.
+fn error_payload(err: KreuzbergError) -> (&'static str, ErrorPayload) {
+    let (error_type, kind, message, context) = match err {
+        KreuzbergError::Io(e) => ("IOError", "Io", format!("IO error: {}", e), None),
+
+        KreuzbergError::Parsing { message, .. } => {
+            ("ParsingError", "Parsing", format!("Parsing error: {}", message), None)
+        }
+
+        KreuzbergError::Ocr { message, .. } => ("OCRError", "Ocr", format!("OCR error: {}", message), None),
+
+        KreuzbergError::Validation { message, .. } => (
+            "ValidationError",
+            "Validation",
+            format!("Validation error: {}", message),
+            None,
+        ),
+
+        KreuzbergError::Cache { message, .. } => ("CacheError", "Cache", format!("Cache error: {}", message), None),
+
+        KreuzbergError::ImageProcessing { message, .. } => (
+            "ImageProcessingError",
+            "ImageProcessing",
+            format!("Image processing error: {}", message),
+            None,
+        ),
+
+        KreuzbergError::Serialization { message, .. } => (
+            "SerializationError",
+            "Serialization",
+            format!("Serialization error: {}", message),
+            None,
+        ),
+
+        KreuzbergError::MissingDependency(dep) => (
+            "MissingDependencyError",
+            "MissingDependency",
+            format!("Missing dependency: {}", dep),
+            Some(json!({ "dependency": dep })),
+        ),
+
+        KreuzbergError::Plugin { message, plugin_name } => (
+            "PluginError",
+            "Plugin",
+            format!("Plugin error in '{}': {}", plugin_name, message),
+            Some(json!({ "pluginName": plugin_name })),
+        ),
+
+        KreuzbergError::LockPoisoned(msg) => (
+            "LockPoisonedError",
+            "LockPoisoned",
+            format!("Lock poisoned: {}", msg),
+            None,
+        ),
+
+        KreuzbergError::UnsupportedFormat(format) => (
+            "UnsupportedFormatError",
+            "UnsupportedFormat",
+            format!("Unsupported format: {}", format),
+            Some(json!({ "format": format })),
+        ),
+
+        ref err @ KreuzbergError::InputTooLarge { size, limit } => (
+            "InputTooLargeError",
+            "InputTooLarge",
+            err.to_string(),
+            Some(json!({ "size": size, "limit": limit })),
+        ),
+
+        KreuzbergError::Network { message, .. } => {
+            ("NetworkError", "Network", format!("Network error: {}", message), None)
+        }
+
+        KreuzbergError::Other(msg) => ("Error", "Other", msg, None),
+    };
+
+    (error_type, ErrorPayload { kind, message, context })
 }
 
 #[cfg(test)]
@@ -194,6 +263,24 @@ mod tests {
         assert!(!result.is_null());
     }
 
+    #[wasm_bindgen_test]
+    fn test_convert_error_carries_kind_and_context() {
+        let err = KreuzbergError::UnsupportedFormat("application/x-custom".to_string());
+        let result = convert_error(err);
+
+        let get = |target: &JsValue, key: &str| js_sys::Reflect::get(target, &JsValue::from_str(key)).unwrap();
+        assert_eq!(get(&result, "kind").as_string().as_deref(), Some("UnsupportedFormat"));
+        assert_eq!(
+            get(&result, "message").as_string().as_deref(),
+            Some("Unsupported format: application/x-custom")
+        );
+        let context = get(&result, "context");
+        assert_eq!(
+            get(&context, "format").as_string().as_deref(),
+            Some("application/x-custom")
+        );
+    }
+
     #[wasm_bindgen_test]
     fn test_convert_error_other_returns_jsvalue() {
         let err = KreuzbergError::Other("Unknown error occurred".to_string());
@@ -7,7 +7,10 @@
 use crate::errors::convert_error;
 use crate::types::{parse_config, result_to_js_value, results_to_js_value};
 use js_sys::Uint8Array;
-use kreuzberg::{batch_extract_bytes_sync, extract_bytes, extract_bytes_sync};
+use kreuzberg::{
+    batch_extract_bytes_sync, detect_mime_type_from_bytes, extract_bytes, extract_bytes_sync,
+    extract_bytes_with_mime_sync,
+};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{File, FileReader};
@@ -57,6 +60,137 @@ pub fn extract_bytes_sync_wasm(
         .and_then(|result| result_to_js_value(&result))
 }
 
+/// Extract content from a byte array with a trusted MIME type (synchronous).
+///
+/// Dispatches directly to the extractor registered for `mimeType` without validating
+/// it against the supported list, e.g. for a `Content-Type` received with an upload.
+/// Parameters such as `; charset=binary` are ignored.
+///
+/// # JavaScript Parameters
+///
+/// * `data: Uint8Array` - The document bytes to extract
+/// * `mimeType: string` - MIME type of the data, used as given
+/// * `config?: object` - Optional extraction configuration
+///
+/// # Returns
+///
+/// `object` - ExtractionResult with extracted content and metadata
+///
+/// # Throws
+///
+/// Throws an error if data is malformed or no extractor is registered for the MIME type.
+///
+/// # Example
+///
+/// ```javascript
+/// import { extractBytesWithMime } from '@kreuzberg/wasm';
+///
+/// const data = new Uint8Array(await request.arrayBuffer());
+/// const result = extractBytesWithMime(data, request.headers.get('content-type'), null);
+/// console.log(result.content);
+/// ```
+#[wasm_bindgen(js_name = extractBytesWithMime)]
+pub fn extract_bytes_with_mime_wasm(
+    data: Uint8Array,
+    mime_type: String,
+    config: Option<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let extraction_config = parse_config(config)?;
+    let bytes = data.to_vec();
+
+    extract_bytes_with_mime_sync(&bytes, &mime_type, &extraction_config)
+        .map_err(convert_error)
+        .and_then(|result| result_to_js_value(&result))
+}
+
+/// Count the pages of a PDF without extracting its content.
+///
+/// Only the document structure is loaded, which is far cheaper than a full extraction
+/// when all that is needed is the page count (e.g. to route documents by size).
+///
+/// # JavaScript Parameters
+///
+/// * `data: Uint8Array` - The PDF bytes
+///
+/// # Returns
+///
+/// `number` - The number of pages
+///
+/// # Throws
+///
+/// Throws an error if the data is not a PDF or the PDF is password-protected.
+///
+/// # Example
+///
+/// ```javascript
+/// import { pdfPageCount } from '@kreuzberg/wasm';
+///
+/// const pages = pdfPageCount(new Uint8Array(buffer));
+/// console.log(`${pages} pages`);
+/// ```
+#[wasm_bindgen(js_name = pdfPageCount)]
+pub fn pdf_page_count_wasm(data: Uint8Array) -> Result<u32, JsValue> {
+    let bytes = data.to_vec();
+
+    kreuzberg::pdf::pdf_page_count(&bytes)
+        .map(|count| count as u32)
+        .map_err(|e| convert_error(e.into()))
+}
+
+/// Extract content from a byte array on the calling thread only.
+///
+/// Fallback for runtimes that cannot host the rayon worker pool, such as Deno or a
+/// Web Worker without `SharedArrayBuffer`. Extraction always runs single-threaded,
+/// even after `initThreadPool` has succeeded, so it never waits on a worker.
+///
+/// The trade-off is throughput: the threaded `extractBytes` path can spread work
+/// across the pool, while this function blocks the caller for the whole extraction.
+/// Prefer `extractBytes` when the thread pool is available and the document is large.
+///
+/// # JavaScript Parameters
+///
+/// * `data: Uint8Array` - The document bytes to extract
+/// * `mimeHint?: string` - Optional MIME type (detected from the bytes if omitted)
+/// * `config?: object` - Optional extraction configuration
+///
+/// # Returns
+///
+/// `object` - ExtractionResult with the same shape as `extractBytes`
+///
+/// # Throws
+///
+/// Throws an error if the MIME type cannot be detected, the data is malformed, or
+/// the MIME type is unsupported.
+///
+/// # Example
+///
+/// ```javascript
+/// import { extractBytesSingleThreaded } from '@kreuzberg/wasm';
+///
+/// const data = await Deno.readFile('document.pdf');
+/// const result = extractBytesSingleThreaded(data);
+/// console.log(result.content);
+/// ```
+#[wasm_bindgen(js_name = extractBytesSingleThreaded)]
+pub fn extract_bytes_single_threaded_wasm(
+    data: Uint8Array,
+    mime_hint: Option<String>,
+    config: Option<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let mut extraction_config = parse_config(config)?;
+    extraction_config.force_single_threaded = true;
+    let bytes = data.to_vec();
+
+    let mime_type = match mime_hint {
+        Some(mime_type) => mime_type,
+        None => detect_mime_type_from_bytes(&bytes).map_err(convert_error)?,
+    };
+
+    extract_bytes_sync(&bytes, &mime_type, &extraction_config)
+        .map_err(convert_error)
+        .and_then(|result| result_to_js_value(&result))
+}
+
 /// Extract content from a byte array (asynchronous).
 ///
 /// Asynchronously extracts text, tables, images, and metadata from a document.
@@ -104,6 +238,92 @@ pub fn extract_bytes_wasm(data: Uint8Array, mime_type: String, config: Option<Js
     })
 }
 
+/// Extract content from a base64-encoded document (asynchronous).
+///
+/// Convenience entry point for contexts that move binary data as strings, such as
+/// `postMessage` payloads or JSON APIs. The string is decoded inside the module and
+/// the bytes are extracted exactly as `extractBytes` would.
+///
+/// Both the standard (`+/`) and URL-safe (`-_`) alphabets are accepted, with or
+/// without padding. ASCII whitespace (e.g. MIME line breaks) is ignored.
+///
+/// # Memory
+///
+/// The string is copied into WASM memory as UTF-8 and then decoded, so peak usage
+/// is roughly 1.75x the encoded length on top of the extraction itself. For large
+/// documents, decode on the JS side and call `extractBytes` with a `Uint8Array`.
+///
+/// # JavaScript Parameters
+///
+/// * `data: string` - The base64-encoded document
+/// * `mimeHint?: string` - Optional MIME type (detected from the bytes if omitted)
+/// * `config?: object` - Optional extraction configuration
+///
+/// # Returns
+///
+/// `Promise<object>` - Promise resolving to ExtractionResult
+///
+/// # Throws
+///
+/// Rejects if the string is not valid base64, the MIME type cannot be detected, or
+/// extraction fails.
+///
+/// # Example
+///
+/// ```javascript
+/// import { extractBase64 } from '@kreuzberg/wasm';
+///
+/// self.onmessage = async (event) => {
+///     const result = await extractBase64(event.data.document, 'application/pdf');
+///     self.postMessage(result.content);
+/// };
+/// ```
+#[wasm_bindgen(js_name = extractBase64)]
+pub fn extract_base64_wasm(data: String, mime_hint: Option<String>, config: Option<JsValue>) -> js_sys::Promise {
+    wasm_bindgen_futures::future_to_promise(async move {
+        let bytes = decode_base64(&data)?;
+        drop(data);
+
+        let extraction_config = parse_config(config)?;
+        let mime_type = match mime_hint {
+            Some(mime_type) => mime_type,
+            None => detect_mime_type_from_bytes(&bytes).map_err(convert_error)?,
+        };
+
+        let result = extract_bytes(&bytes, &mime_type, &extraction_config)
+            .await
+            .map_err(convert_error)?;
+
+        result_to_js_value(&result)
+    })
+}
+
+/// Decode standard or URL-safe base64, with or without padding.
+fn decode_base64(data: &str) -> Result<Vec<u8>, JsValue> {
+    use base64::Engine;
+    use base64::alphabet;
+    use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+
+    const CONFIG: GeneralPurposeConfig =
+        GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+    const STANDARD: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, CONFIG);
+    const URL_SAFE: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, CONFIG);
+
+    let compact: String = data.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+    if compact.is_empty() {
+        return Err(JsError::new("Invalid base64 input: string is empty").into());
+    }
+
+    let engine = if compact.contains(['-', '_']) {
+        URL_SAFE
+    } else {
+        STANDARD
+    };
+    engine
+        .decode(compact.as_bytes())
+        .map_err(|e| JsError::new(&format!("Invalid base64 input: {}", e)).into())
+}
+
 /// Extract content from a web File or Blob (asynchronous).
 ///
 /// Extracts content from a web File (from `<input type="file">`) or Blob object
@@ -464,6 +684,28 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[wasm_bindgen_test]
+    fn test_extract_bytes_with_mime_wasm_strips_parameters() {
+        let data = unsafe { Uint8Array::view(TEXT_DATA) };
+        let mime_type = "Text/Plain; charset=utf-8".to_string();
+
+        let result = extract_bytes_with_mime_wasm(data, mime_type, None).unwrap();
+
+        let mime = js_sys::Reflect::get(&result, &"mimeType".into())
+            .unwrap()
+            .as_string()
+            .unwrap();
+        assert_eq!(mime, "text/plain");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_extract_bytes_with_mime_wasm_unregistered_mime_returns_error() {
+        let data = unsafe { Uint8Array::view(TEXT_DATA) };
+        let mime_type = "application/x-no-such-format".to_string();
+
+        assert!(extract_bytes_with_mime_wasm(data, mime_type, None).is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_extract_bytes_wasm_returns_promise() {
         let data = unsafe { Uint8Array::view(VALID_PDF_DATA) };
@@ -498,6 +740,51 @@ mod tests {
         assert!(!promise.is_null());
     }
 
+    #[wasm_bindgen_test]
+    fn test_decode_base64_accepts_standard_and_url_safe() {
+        let bytes = [0xfb, 0xff, 0xbf, b'a'];
+
+        assert_eq!(decode_base64("+/+/YQ==").unwrap(), bytes);
+        assert_eq!(decode_base64("-_-_YQ").unwrap(), bytes);
+        assert_eq!(decode_base64("+/+/\nYQ==").unwrap(), bytes);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decode_base64_rejects_invalid_input() {
+        assert!(decode_base64("not base64!").is_err());
+        assert!(decode_base64("").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_extract_base64_wasm_round_trips_text() {
+        use base64::Engine;
+
+        let encoded = base64::engine::general_purpose::STANDARD.encode(TEXT_DATA);
+        let result = JsFuture::from(extract_base64_wasm(encoded, Some("text/plain".to_string()), None))
+            .await
+            .expect("base64 text extraction should succeed");
+
+        let content = js_sys::Reflect::get(&result, &"content".into())
+            .unwrap()
+            .as_string()
+            .unwrap();
+        assert_eq!(content.trim(), std::str::from_utf8(TEXT_DATA).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_extract_base64_wasm_invalid_input_rejects() {
+        let result = JsFuture::from(extract_base64_wasm("%%%".to_string(), None, None)).await;
+
+        assert!(result.is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_pdf_page_count_wasm_invalid_data_returns_error() {
+        let data = unsafe { Uint8Array::view(INVALID_DATA) };
+
+        assert!(pdf_page_count_wasm(data).is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_extract_file_sync_wasm_always_returns_error() {
         let result = extract_file_sync_wasm();
@@ -76,8 +76,20 @@
 //! Non-browser WASM runtimes (Deno, Cloudflare Workers) cannot use PDF extraction.
 //! For server-side PDF processing, use the native library bindings.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use wasm_bindgen::prelude::*;
 
+/// Whether `init_thread_pool_safe` successfully initialized the thread pool.
+static THREAD_POOL_READY: AtomicBool = AtomicBool::new(false);
+
+/// Check whether a thread pool is available for parallel extraction.
+///
+/// Returns false until `init_thread_pool_safe` succeeds. Configurations parsed while
+/// this is false have `force_single_threaded` set automatically.
+pub(crate) fn thread_pool_ready() -> bool {
+    THREAD_POOL_READY.load(Ordering::Acquire)
+}
+
 /// Initialize a WebAssembly thread pool (optional).
 ///
 /// By default, Kreuzberg's WASM build ships without multi-threading enabled.
@@ -107,8 +119,9 @@ pub mod types;
 
 // Re-export all public plugin APIs
 pub use plugins::{
-    clear_ocr_backends, clear_post_processors, clear_validators, list_ocr_backends, list_post_processors,
-    list_validators, register_ocr_backend, register_post_processor, register_validator, unregister_ocr_backend,
+    clear_document_extractors, clear_ocr_backends, clear_post_processors, clear_validators, list_document_extractors,
+    list_ocr_backends, list_post_processors, list_validators, register_ocr_backend, register_ocr_backend_fn,
+    register_post_processor, register_validator, unregister_document_extractor, unregister_ocr_backend,
     unregister_post_processor, unregister_validator,
 };
 
@@ -125,6 +138,27 @@ pub fn version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
+/// Version of the core kreuzberg library compiled into this module
+#[wasm_bindgen(js_name = coreVersion)]
+pub fn core_version() -> String {
+    kreuzberg::VERSION.to_string()
+}
+
+/// Version of the kreuzberg-wasm binding, same as `version()`
+#[wasm_bindgen(js_name = bindingVersion)]
+pub fn binding_version() -> String {
+    version()
+}
+
+/// Check that the binding and core library versions are compatible
+///
+/// Versions are compatible when their semver major versions match. Throws an error
+/// naming both versions otherwise.
+#[wasm_bindgen(js_name = checkCompatibility)]
+pub fn check_compatibility() -> Result<(), JsValue> {
+    kreuzberg::check_version_compatibility(&binding_version()).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
 /// Initialize the WASM module
 /// This function should be called once at application startup
 #[wasm_bindgen]
@@ -168,9 +202,16 @@ pub fn get_module_info() -> ModuleInfo {
 ///
 /// This function wraps init_thread_pool with panic handling to ensure graceful
 /// degradation if thread pool initialization fails. The application will continue
-/// to work in single-threaded mode if the thread pool cannot be initialized.
+/// to work in single-threaded mode if the thread pool cannot be initialized: every
+/// extraction config is then parsed with `force_single_threaded` enabled.
 #[wasm_bindgen]
 pub fn init_thread_pool_safe(num_threads: u32) -> bool {
+    let ready = try_init_thread_pool(num_threads);
+    THREAD_POOL_READY.store(ready, Ordering::Release);
+    ready
+}
+
+fn try_init_thread_pool(num_threads: u32) -> bool {
     #[cfg(not(feature = "threads"))]
     {
         let _ = num_threads;
@@ -274,6 +315,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_check_compatibility_within_build() {
+        assert_eq!(core_version(), kreuzberg::VERSION);
+        assert_eq!(binding_version(), version());
+        assert!(check_compatibility().is_ok());
+    }
+
     #[test]
     fn test_module_info_consistency() {
         let info = get_module_info();
@@ -41,6 +41,57 @@ pub fn detect_mime_from_bytes(data: js_sys::Uint8Array) -> Result<String, JsValu
     kreuzberg::detect_mime_type_from_bytes(&bytes).map_err(|e| JsValue::from_str(&e.to_string()))
 }
 
+/// Detect MIME type from both a file name and its content.
+///
+/// Compares the extension-based and content-based MIME types and resolves any
+/// disagreement according to `policy`. Useful for uploads, where a mismatched
+/// extension may be suspicious.
+///
+/// # JavaScript Parameters
+///
+/// * `fileName: string` - File name or path whose extension is checked
+/// * `data: Uint8Array` - The raw file bytes
+/// * `policy?: string` - "prefer_content" (default), "prefer_extension", or "strict"
+///
+/// # Returns
+///
+/// `{ mimeType: string, mismatch: boolean }` - The chosen MIME type and whether
+/// content and extension disagreed
+///
+/// # Throws
+///
+/// Throws an error if the policy is invalid, if the sources disagree under
+/// "strict", or if no MIME type can be determined.
+///
+/// # Example
+///
+/// ```javascript
+/// import { detectMime } from '@kreuzberg/wasm';
+///
+/// const bytes = new Uint8Array(await file.arrayBuffer());
+/// const { mimeType, mismatch } = detectMime(file.name, bytes, 'prefer_content');
+/// if (mismatch) {
+///   console.warn(`Extension does not match content (${mimeType})`);
+/// }
+/// ```
+#[wasm_bindgen(js_name = detectMime)]
+pub fn detect_mime(file_name: String, data: js_sys::Uint8Array, policy: Option<String>) -> Result<JsValue, JsValue> {
+    let policy = match policy {
+        Some(policy) => policy
+            .parse::<kreuzberg::MimePolicy>()
+            .map_err(|e| JsValue::from_str(&e))?,
+        None => kreuzberg::MimePolicy::default(),
+    };
+
+    let detection =
+        kreuzberg::detect_mime(&file_name, &data.to_vec(), policy).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let obj = js_sys::Object::new();
+    js_sys::Reflect::set(&obj, &"mimeType".into(), &detection.mime_type.into())?;
+    js_sys::Reflect::set(&obj, &"mismatch".into(), &detection.mismatch.into())?;
+    Ok(obj.into())
+}
+
 /// Get MIME type from file extension.
 ///
 /// Looks up the MIME type associated with a given file extension.
@@ -0,0 +1,197 @@
+//! Document extractor management for WASM bindings
+//!
+//! This module exposes listing, unregistration, and clearing of document extractors
+//! registered in the core `DocumentExtractorRegistry`, matching the dynamic extractor
+//! management available in the other language bindings.
+
+use super::{acquire_read_lock, acquire_write_lock};
+use wasm_bindgen::prelude::*;
+
+/// List all registered document extractor names.
+///
+/// # Returns
+///
+/// Array of extractor names, or Err if the registry lock is poisoned.
+///
+/// # Example
+///
+/// ```javascript
+/// const extractors = list_document_extractors();
+/// console.log(extractors); // ["pdf-extractor", "html-extractor", ...]
+/// ```
+#[wasm_bindgen]
+pub fn list_document_extractors() -> Result<js_sys::Array, JsError> {
+    let registry = kreuzberg::plugins::registry::get_document_extractor_registry();
+    let registry = acquire_read_lock(&registry, "DOCUMENT_EXTRACTORS").map_err(|e| JsError::new(&e))?;
+
+    let arr = js_sys::Array::new();
+    for name in registry.list() {
+        arr.push(&JsValue::from_str(&name));
+    }
+
+    Ok(arr)
+}
+
+/// Unregister a document extractor by name.
+///
+/// Unknown names are ignored, mirroring the core registry behavior.
+///
+/// # Arguments
+///
+/// * `name` - Name of the document extractor to unregister
+///
+/// # Returns
+///
+/// Ok if unregistration succeeds, Err if the extractor fails to shut down.
+///
+/// # Example
+///
+/// ```javascript
+/// unregister_document_extractor("custom-extractor");
+/// ```
+#[wasm_bindgen]
+pub fn unregister_document_extractor(name: String) -> Result<(), JsError> {
+    let registry = kreuzberg::plugins::registry::get_document_extractor_registry();
+    let mut registry = acquire_write_lock(&registry, "DOCUMENT_EXTRACTORS").map_err(|e| JsError::new(&e))?;
+
+    registry
+        .remove(&name)
+        .map_err(|e| JsError::new(&format!("Unregistration failed: {}", e)))
+}
+
+/// Clear all registered document extractors.
+///
+/// # Returns
+///
+/// Ok if clearing succeeds, Err if an extractor fails to shut down.
+///
+/// # Example
+///
+/// ```javascript
+/// clear_document_extractors();
+/// ```
+#[wasm_bindgen]
+pub fn clear_document_extractors() -> Result<(), JsError> {
+    let registry = kreuzberg::plugins::registry::get_document_extractor_registry();
+    let mut registry = acquire_write_lock(&registry, "DOCUMENT_EXTRACTORS").map_err(|e| JsError::new(&e))?;
+
+    registry
+        .shutdown_all()
+        .map_err(|e| JsError::new(&format!("Failed to clear document extractors: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use kreuzberg::plugins::{DocumentExtractor, Plugin};
+    use kreuzberg::{ExtractionConfig, ExtractionResult};
+    use std::borrow::Cow;
+    use std::sync::Arc;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    struct MockExtractor {
+        name: String,
+    }
+
+    impl Plugin for MockExtractor {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> kreuzberg::Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> kreuzberg::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl DocumentExtractor for MockExtractor {
+        async fn extract_bytes(
+            &self,
+            content: &[u8],
+            _mime_type: &str,
+            _config: &ExtractionConfig,
+        ) -> kreuzberg::Result<ExtractionResult> {
+            Ok(ExtractionResult {
+                content: String::from_utf8_lossy(content).into_owned(),
+                mime_type: Cow::Borrowed("text/x-mock"),
+                metadata: Default::default(),
+                tables: vec![],
+                detected_languages: None,
+                chunks: None,
+                images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
+                pages: None,
+                elements: None,
+                layout: None,
+                djot_content: None,
+            })
+        }
+
+        fn supported_mime_types(&self) -> &[&str] {
+            &["text/x-mock"]
+        }
+    }
+
+    fn register_mock_extractor(name: &str) {
+        let registry = kreuzberg::plugins::registry::get_document_extractor_registry();
+        let mut registry = registry.write().expect("document extractor registry lock poisoned");
+        registry
+            .register(Arc::new(MockExtractor { name: name.to_string() }))
+            .expect("Failed to register mock extractor");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_list_document_extractors_returns_array() {
+        let result = list_document_extractors();
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_array());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_list_document_extractors_after_register_contains_name() {
+        clear_document_extractors().ok();
+        register_mock_extractor("mock-extractor");
+
+        let list = list_document_extractors().unwrap();
+
+        assert!(list.includes(&JsValue::from_str("mock-extractor"), 0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_unregister_document_extractor_shrinks_list() {
+        clear_document_extractors().ok();
+        register_mock_extractor("mock-a");
+        register_mock_extractor("mock-b");
+        let before = list_document_extractors().unwrap().length();
+
+        assert!(unregister_document_extractor("mock-a".to_string()).is_ok());
+
+        let after = list_document_extractors().unwrap();
+        assert_eq!(after.length(), before - 1);
+        assert!(!after.includes(&JsValue::from_str("mock-a"), 0));
+        assert!(after.includes(&JsValue::from_str("mock-b"), 0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_clear_document_extractors_removes_all() {
+        register_mock_extractor("mock-extractor");
+
+        assert!(clear_document_extractors().is_ok());
+
+        assert_eq!(list_document_extractors().unwrap().length(), 0);
+    }
+}
@@ -32,6 +32,7 @@
 //!
 //! See: https://github.com/rustwasm/wasm-bindgen/issues/... (threading docs)
 
+pub mod extractor_bridge;
 pub mod ocr_bridge;
 pub mod processor_bridge;
 pub mod validator_bridge;
@@ -41,7 +42,10 @@ use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use wasm_bindgen::prelude::*;
 
 // Re-export public API
-pub use ocr_bridge::{clear_ocr_backends, list_ocr_backends, register_ocr_backend, unregister_ocr_backend};
+pub use extractor_bridge::{clear_document_extractors, list_document_extractors, unregister_document_extractor};
+pub use ocr_bridge::{
+    clear_ocr_backends, list_ocr_backends, register_ocr_backend, register_ocr_backend_fn, unregister_ocr_backend,
+};
 pub use processor_bridge::{
     clear_post_processors, list_post_processors, register_post_processor, unregister_post_processor,
 };
@@ -163,8 +163,12 @@ impl OcrBackend for JsOcrBackendWrapper {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         })
     }
@@ -201,8 +205,12 @@ impl OcrBackend for JsOcrBackendWrapper {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         })
     }
@@ -216,8 +224,12 @@ impl OcrBackend for JsOcrBackendWrapper {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         })
     }
@@ -235,6 +247,110 @@ impl OcrBackend for JsOcrBackendWrapper {
     }
 }
 
+/// Wrapper that exposes a single JavaScript function as an OCR backend.
+///
+/// Unlike [`JsOcrBackendWrapper`], the callback only returns recognized text, which is
+/// wrapped into a plain-text [`ExtractionResult`]. The backend accepts every language
+/// and forwards the requested language to the callback.
+///
+/// # Thread Safety
+///
+/// The callback is a JavaScript function and can only be invoked on the main JS thread.
+/// Pages routed to this backend are therefore processed sequentially, even when the
+/// rayon thread pool has been initialized via `initThreadPool()`.
+struct JsOcrFunctionBackend {
+    name: String,
+    callback: JsPluginValue,
+}
+
+impl Plugin for JsOcrFunctionBackend {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> String {
+        "1.0.0".to_string()
+    }
+
+    fn initialize(&self) -> kreuzberg::Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> kreuzberg::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl OcrBackend for JsOcrFunctionBackend {
+    async fn process_image(&self, image_bytes: &[u8], config: &OcrConfig) -> kreuzberg::Result<ExtractionResult> {
+        let promise = {
+            let callback = self
+                .callback
+                .0
+                .dyn_ref::<js_sys::Function>()
+                .ok_or_else(|| KreuzbergError::Ocr {
+                    message: format!("OCR backend '{}' callback is not a function", self.name),
+                    source: None,
+                })?;
+
+            let image = js_sys::Uint8Array::from(image_bytes);
+            let promise_val = callback
+                .call2(&JsValue::NULL, &image, &JsValue::from_str(&config.language))
+                .map_err(|e| KreuzbergError::Ocr {
+                    message: format!("OCR backend '{}' callback failed: {:?}", self.name, e),
+                    source: None,
+                })?;
+
+            Promise::resolve(&promise_val)
+        };
+
+        let result_val = MakeSend(JsFuture::from(promise))
+            .await
+            .map_err(|e| KreuzbergError::Ocr {
+                message: format!("OCR backend '{}' promise failed: {:?}", self.name, e),
+                source: None,
+            })?;
+
+        let content = result_val.as_string().ok_or_else(|| KreuzbergError::Ocr {
+            message: format!("OCR backend '{}' callback must return a string", self.name),
+            source: None,
+        })?;
+
+        Ok(ExtractionResult {
+            content,
+            mime_type: Cow::Borrowed("text/plain"),
+            metadata: Default::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            pages: None,
+            elements: None,
+            layout: None,
+            djot_content: None,
+        })
+    }
+
+    async fn process_file(&self, path: &std::path::Path, config: &OcrConfig) -> kreuzberg::Result<ExtractionResult> {
+        use kreuzberg::core::io;
+        let bytes = io::read_file_sync(path)?;
+        self.process_image(&bytes, config).await
+    }
+
+    fn supports_language(&self, _lang: &str) -> bool {
+        true
+    }
+
+    fn backend_type(&self) -> OcrBackendType {
+        OcrBackendType::Custom
+    }
+}
+
 /// Register a custom OCR backend.
 ///
 /// # Arguments
@@ -324,6 +440,60 @@ pub fn register_ocr_backend(backend: JsValue) -> Result<(), JsValue> {
         .map_err(|e| JsValue::from_str(&format!("Registration failed: {}", e)))
 }
 
+/// Register a JavaScript function as a named OCR backend.
+///
+/// This is a lightweight alternative to [`register_ocr_backend`] for callers that only
+/// need to map image bytes to text, e.g. when delegating to a cloud OCR API.
+///
+/// # Arguments
+///
+/// * `name` - Unique backend name, referenced from `ocr.backend` in the extraction config
+/// * `callback` - `(image: Uint8Array, language: string) => string | Promise<string>`
+///   returning the recognized text
+///
+/// # Returns
+///
+/// Ok if registration succeeds, Err if the name is empty or already registered.
+///
+/// # Threading
+///
+/// JavaScript callbacks cannot be invoked from rayon worker threads, so pages handled by
+/// a function backend are processed one at a time on the main thread.
+///
+/// # Example
+///
+/// ```javascript
+/// register_ocr_backend_fn("cloud-ocr", async (image, language) => {
+///   const response = await fetch("https://ocr.example.com", { method: "POST", body: image });
+///   return await response.text();
+/// });
+/// ```
+#[wasm_bindgen]
+pub fn register_ocr_backend_fn(name: String, callback: js_sys::Function) -> Result<(), JsValue> {
+    if name.is_empty() {
+        return Err(JsValue::from_str("OCR backend name cannot be empty"));
+    }
+
+    let registry = kreuzberg::plugins::registry::get_ocr_backend_registry();
+    let mut registry = acquire_write_lock(&registry, "OCR_BACKENDS").map_err(|e| JsValue::from_str(&e))?;
+
+    if registry.list().iter().any(|existing| existing == &name) {
+        return Err(JsValue::from_str(&format!(
+            "OCR backend '{}' is already registered",
+            name
+        )));
+    }
+
+    let backend = JsOcrFunctionBackend {
+        name,
+        callback: JsPluginValue(callback.into()),
+    };
+
+    registry
+        .register(Arc::new(backend))
+        .map_err(|e| JsValue::from_str(&format!("Registration failed: {}", e)))
+}
+
 /// Unregister an OCR backend by name.
 ///
 /// # Arguments
@@ -611,6 +781,33 @@ mod tests {
         assert!(!wrapper.supports_language("fr"));
     }
 
+    #[wasm_bindgen_test]
+    fn test_register_ocr_backend_fn_is_listed() {
+        clear_ocr_backends().ok();
+        let callback = js_sys::Function::new_with_args("image,language", "return 'stub text'");
+
+        assert!(register_ocr_backend_fn("stub-ocr".to_string(), callback).is_ok());
+
+        let list = list_ocr_backends().unwrap();
+        assert!(list.includes(&JsValue::from_str("stub-ocr"), 0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_register_ocr_backend_fn_rejects_duplicates() {
+        clear_ocr_backends().ok();
+        let callback = js_sys::Function::new_with_args("image,language", "return 'stub text'");
+
+        assert!(register_ocr_backend_fn("stub-ocr".to_string(), callback.clone()).is_ok());
+        assert!(register_ocr_backend_fn("stub-ocr".to_string(), callback).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_register_ocr_backend_fn_empty_name_fails() {
+        let callback = js_sys::Function::new_with_args("image,language", "return ''");
+
+        assert!(register_ocr_backend_fn(String::new(), callback).is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_register_multiple_ocr_backends() {
         clear_ocr_backends().ok();
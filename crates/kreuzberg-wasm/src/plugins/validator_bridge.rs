@@ -219,6 +219,35 @@ pub fn register_validator(validator: JsValue) -> Result<(), JsValue> {
         .map_err(|e| JsValue::from_str(&format!("Registration failed: {}", e)))
 }
 
+/// Register the built-in minimum text length validator.
+///
+/// Adds a `validation` warning to the result's warnings when the
+/// extracted text has fewer than `min_length` characters. Extraction never fails.
+///
+/// # Arguments
+///
+/// * `min_length` - Minimum number of characters, ignoring surrounding whitespace
+///
+/// # Returns
+///
+/// Ok if registration succeeds, Err with description if it fails. A previously
+/// registered `min-text-length` validator is replaced.
+///
+/// # Example
+///
+/// ```javascript
+/// register_min_text_length_validator(50);
+/// ```
+#[wasm_bindgen]
+pub fn register_min_text_length_validator(min_length: u32) -> Result<(), JsValue> {
+    let registry = kreuzberg::plugins::registry::get_validator_registry();
+    let mut registry = acquire_write_lock(&registry, "VALIDATORS").map_err(|e| JsValue::from_str(&e))?;
+
+    registry
+        .register(kreuzberg::plugins::min_text_length_validator(min_length as usize))
+        .map_err(|e| JsValue::from_str(&format!("Registration failed: {}", e)))
+}
+
 /// Unregister a validator by name.
 ///
 /// # Arguments
@@ -450,6 +479,25 @@ mod tests {
         assert!(arr.length() > 0);
     }
 
+    #[wasm_bindgen_test]
+    fn test_register_min_text_length_validator() {
+        clear_validators().ok();
+
+        let result = register_min_text_length_validator(50);
+
+        assert!(result.is_ok());
+        let names: Vec<String> = list_validators()
+            .expect("Failed to list validators")
+            .iter()
+            .filter_map(|name| name.as_string())
+            .collect();
+        assert_eq!(
+            names,
+            vec![kreuzberg::plugins::MinTextLengthValidator::NAME.to_string()]
+        );
+        clear_validators().ok();
+    }
+
     #[wasm_bindgen_test]
     fn test_js_validator_wrapper_implements_plugin() {
         let validator = create_mock_validator("test").expect("Failed to create mock validator");
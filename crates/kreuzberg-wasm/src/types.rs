@@ -11,6 +11,9 @@ use wasm_bindgen::prelude::*;
 /// Converts a JavaScript object to a Rust ExtractionConfig structure.
 /// If config is None, returns the default ExtractionConfig.
 ///
+/// When no thread pool has been initialized via `initThreadPoolSafe`,
+/// `force_single_threaded` is enabled so extraction never depends on worker threads.
+///
 /// # Arguments
 ///
 /// * `config` - JavaScript object with extraction configuration (optional)
@@ -19,11 +22,17 @@ use wasm_bindgen::prelude::*;
 ///
 /// Result containing the parsed ExtractionConfig or a JsValue error
 pub fn parse_config(config: Option<JsValue>) -> Result<ExtractionConfig, JsValue> {
-    match config {
+    let mut config: ExtractionConfig = match config {
         Some(js_config) => serde_wasm_bindgen::from_value(js_config)
-            .map_err(|e| JsValue::from_str(&format!("Failed to parse config: {}", e))),
-        None => Ok(ExtractionConfig::default()),
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse config: {}", e)))?,
+        None => ExtractionConfig::default(),
+    };
+
+    if !crate::thread_pool_ready() {
+        config.force_single_threaded = true;
     }
+
+    Ok(config)
 }
 
 /// Convert extraction result to JsValue for JavaScript consumption.
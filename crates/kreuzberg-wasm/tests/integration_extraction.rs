@@ -143,6 +143,17 @@ fn test_extract_bytes_sync_empty_data_returns_error() {
     assert!(result.is_err(), "Empty data should return error");
 }
 
+/// Test that errors carry the machine-readable kind of the Rust error
+#[wasm_bindgen_test]
+fn test_extract_bytes_sync_unsupported_mime_error_kind() {
+    let data = Uint8Array::from(&b"plain bytes"[..]);
+    let err = extract_bytes_sync_wasm(data, "application/x-unknown-format".to_string(), None)
+        .expect_err("Unknown MIME type should return error");
+
+    let kind = js_sys::Reflect::get(&err, &"kind".into()).unwrap();
+    assert_eq!(kind.as_string().as_deref(), Some("UnsupportedFormat"));
+}
+
 /// Test synchronous extraction preserves Uint8Array length
 #[wasm_bindgen_test]
 fn test_extract_bytes_sync_preserves_data_integrity() {
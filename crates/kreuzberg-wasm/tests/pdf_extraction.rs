@@ -244,3 +244,28 @@ fn test_pdf_multiple_extractions_no_state_leak() {
         "Corrupted PDF should fail even between valid extractions"
     );
 }
+
+#[wasm_bindgen_test::wasm_bindgen_test]
+async fn test_pdf_single_threaded_matches_async_content() {
+    let content_of = |value: &wasm_bindgen::JsValue| {
+        js_sys::Reflect::get(value, &"content".into())
+            .unwrap()
+            .as_string()
+            .unwrap()
+    };
+
+    let data = unsafe { Uint8Array::view(MINIMAL_PDF_BYTES) };
+    let single_threaded = extract_bytes_single_threaded_wasm(data, None, None)
+        .expect("Single-threaded extraction should succeed with valid PDF bytes");
+
+    let data = unsafe { Uint8Array::view(MINIMAL_PDF_BYTES) };
+    let threaded = wasm_bindgen_futures::JsFuture::from(extract_bytes_wasm(data, "application/pdf".to_string(), None))
+        .await
+        .expect("Async extraction should succeed with valid PDF bytes");
+
+    assert_eq!(
+        content_of(&single_threaded),
+        content_of(&threaded),
+        "Single-threaded and async extraction should produce identical content"
+    );
+}
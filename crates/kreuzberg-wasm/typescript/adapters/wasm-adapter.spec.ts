@@ -1,5 +1,5 @@
 import { describe, expect, it } from "vitest";
-import type { ExtractionConfig } from "../types.js";
+import type { ExtractionConfig, KreuzbergError } from "../types.js";
 import {
 	configToJS,
 	fileToUint8Array,
@@ -435,6 +435,17 @@ describe("WASM Adapter", () => {
 			expect(wrapped.cause).toBe(original);
 		});
 
+		it("should carry over the error kind and context", () => {
+			const original = Object.assign(new Error("Unsupported format: application/x-unknown"), {
+				kind: "UnsupportedFormat",
+				context: { format: "application/x-unknown" },
+			});
+			const wrapped = wrapWasmError(original, "context") as KreuzbergError;
+
+			expect(wrapped.kind).toBe("UnsupportedFormat");
+			expect(wrapped.context).toEqual({ format: "application/x-unknown" });
+		});
+
 		it("should handle unknown error types", () => {
 			const wrapped = wrapWasmError({}, "context");
 
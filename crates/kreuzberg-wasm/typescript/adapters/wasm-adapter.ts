@@ -26,7 +26,18 @@
  * ```
  */
 
-import type { Chunk, ExtractedImage, ExtractionConfig, ExtractionResult, Metadata, Table } from "../types.js";
+import type {
+	Chunk,
+	ExtractedImage,
+	ExtractionConfig,
+	ExtractionResult,
+	ExtractionWarning,
+	ExtractionWarningKind,
+	KreuzbergError,
+	Metadata,
+	PositionedText,
+	Table,
+} from "../types.js";
 
 /**
  * Maximum file size for processing (512 MB)
@@ -352,6 +363,19 @@ export function jsToExtractionResult(jsValue: unknown): ExtractionResult {
 		detectedLanguages = detectedLanguagesRaw;
 	}
 
+	const warnings: ExtractionWarning[] = Array.isArray(result.warnings)
+		? result.warnings.map((warning) => {
+				if (!warning || typeof warning !== "object") {
+					throw new Error("Invalid warning structure");
+				}
+				const w = warning as Record<string, unknown>;
+				if (typeof w.kind !== "string" || typeof w.message !== "string") {
+					throw new Error("Invalid warning: kind and message must be strings");
+				}
+				return { kind: w.kind as ExtractionWarningKind, message: w.message };
+			})
+		: [];
+
 	return {
 		content: result.content,
 		mimeType,
@@ -360,6 +384,9 @@ export function jsToExtractionResult(jsValue: unknown): ExtractionResult {
 		detectedLanguages,
 		chunks,
 		images,
+		warnings,
+		partial: result.partial === true,
+		layout: Array.isArray(result.layout) ? (result.layout as PositionedText[]) : null,
 	};
 }
 
@@ -367,7 +394,8 @@ export function jsToExtractionResult(jsValue: unknown): ExtractionResult {
  * Wrap and format WASM errors with context
  *
  * Converts WASM error messages to JavaScript Error objects with proper context
- * and stack trace information when available.
+ * and stack trace information when available. The `kind` and `context` fields
+ * of errors thrown by the WASM module are carried over to the wrapped error.
  *
  * @param error - The error from WASM
  * @param context - Additional context about what operation failed
@@ -386,9 +414,14 @@ export function jsToExtractionResult(jsValue: unknown): ExtractionResult {
  */
 export function wrapWasmError(error: unknown, context: string): Error {
 	if (error instanceof Error) {
-		return new Error(`Error ${context}: ${error.message}`, {
+		const wrapped = new Error(`Error ${context}: ${error.message}`, {
 			cause: error,
 		});
+		const { kind, context: details } = error as Partial<KreuzbergError>;
+		if (typeof kind === "string") {
+			Object.assign(wrapped, { kind, context: details });
+		}
+		return wrapped;
 	}
 
 	const message = String(error);
@@ -0,0 +1,50 @@
+/**
+ * Configuration validation
+ *
+ * Reports every invalid field of an extraction configuration at once.
+ */
+
+import { configToJS, wrapWasmError } from "../adapters/wasm-adapter.js";
+import { getWasmModule, isInitialized } from "../extraction/internal.js";
+import type { ConfigError, ExtractionConfig as ExtractionConfigType } from "../types.js";
+
+/**
+ * Validate every field of an extraction configuration
+ *
+ * Unlike extraction, which fails on the first invalid setting, this reports all
+ * invalid values at once.
+ *
+ * @param config - The extraction configuration to validate
+ * @returns Every invalid field; empty when the configuration is valid
+ * @throws {Error} If WASM module is not initialized or the configuration cannot be parsed
+ *
+ * @example
+ * ```typescript
+ * const errors = validateConfig({
+ *   chunking: { maxChars: 100, maxOverlap: 200 },
+ *   maxConcurrentExtractions: 0,
+ * });
+ * for (const error of errors) {
+ *   console.error(`${error.field}: ${error.message}`);
+ * }
+ * ```
+ */
+export function validateConfig(config: ExtractionConfigType): ConfigError[] {
+	if (!isInitialized()) {
+		throw new Error("WASM module not initialized. Call initWasm() first.");
+	}
+
+	const wasm = getWasmModule();
+
+	try {
+		const errors = wasm.validateConfig(configToJS(config));
+
+		if (!Array.isArray(errors)) {
+			throw new Error("Invalid validation result: expected an array from WASM module");
+		}
+
+		return errors as ConfigError[];
+	} catch (error) {
+		throw wrapWasmError(error, "validating config");
+	}
+}
@@ -135,3 +135,199 @@ export function extractBytesSync(
 		throw wrapWasmError(error, "extracting from bytes (sync)");
 	}
 }
+
+/**
+ * Extract content from bytes with a trusted MIME type
+ *
+ * Dispatches directly to the extractor registered for the given MIME type without
+ * validating it against the supported list, e.g. for a `Content-Type` received with
+ * an upload. Parameters such as `; charset=binary` are ignored.
+ *
+ * @param data - The document bytes to extract from
+ * @param mimeType - MIME type of the document, used as given
+ * @param config - Optional extraction configuration
+ * @returns The extraction result
+ * @throws {Error} If WASM module is not initialized, no extractor is registered for the MIME type, or extraction fails
+ *
+ * @example
+ * ```typescript
+ * const bytes = new Uint8Array(await request.arrayBuffer());
+ * const result = extractBytesWithMime(bytes, request.headers.get('content-type') ?? 'application/octet-stream');
+ * console.log(result.content);
+ * ```
+ */
+export function extractBytesWithMime(
+	data: Uint8Array,
+	mimeType: string,
+	config?: ExtractionConfigType | null,
+): ExtractionResult {
+	if (!isInitialized()) {
+		throw new Error("WASM module not initialized. Call initWasm() first.");
+	}
+
+	const wasm = getWasmModule();
+
+	try {
+		if (!data || data.length === 0) {
+			throw new Error("Document data cannot be empty");
+		}
+
+		if (!mimeType) {
+			throw new Error("MIME type is required");
+		}
+
+		const normalizedConfig = configToJS(config ?? null);
+
+		const result = wasm.extractBytesWithMime(data, mimeType, normalizedConfig);
+
+		if (!result) {
+			throw new Error("Invalid extraction result: no result from WASM module");
+		}
+
+		return jsToExtractionResult(result);
+	} catch (error) {
+		throw wrapWasmError(error, "extracting from bytes with MIME type");
+	}
+}
+
+/**
+ * Count the pages of a PDF without extracting its content
+ *
+ * Only the document structure is loaded, which is far cheaper than a full extraction
+ * when all that is needed is the page count.
+ *
+ * @param data - The PDF bytes
+ * @returns The number of pages
+ * @throws {Error} If WASM module is not initialized, the data is not a PDF, or the PDF is password-protected
+ *
+ * @example
+ * ```typescript
+ * const pages = pdfPageCount(new Uint8Array(buffer));
+ * console.log(`${pages} pages`);
+ * ```
+ */
+export function pdfPageCount(data: Uint8Array): number {
+	if (!isInitialized()) {
+		throw new Error("WASM module not initialized. Call initWasm() first.");
+	}
+
+	const wasm = getWasmModule();
+
+	try {
+		if (!data || data.length === 0) {
+			throw new Error("Document data cannot be empty");
+		}
+
+		return wasm.pdfPageCount(data);
+	} catch (error) {
+		throw wrapWasmError(error, "counting PDF pages");
+	}
+}
+
+/**
+ * Extract content from bytes on the calling thread only
+ *
+ * Fallback for runtimes that cannot host the WASM thread pool, such as Deno or a Web Worker
+ * without `SharedArrayBuffer`. Extraction always runs single-threaded and synchronously, even
+ * when a thread pool has been initialized.
+ *
+ * This trades throughput for portability: `extractBytes` can spread work across worker threads,
+ * while this function blocks the caller for the whole extraction. Prefer `extractBytes` when the
+ * thread pool is available and documents are large.
+ *
+ * @param data - The document bytes to extract from
+ * @param mimeHint - Optional MIME type of the document (detected from the bytes if omitted)
+ * @param config - Optional extraction configuration
+ * @returns The extraction result, with the same shape as `extractBytes`
+ * @throws {Error} If WASM module is not initialized or extraction fails
+ *
+ * @example Extract in Deno
+ * ```typescript
+ * const bytes = await Deno.readFile('document.pdf');
+ * const result = extractBytesSingleThreaded(bytes);
+ * console.log(result.content);
+ * ```
+ */
+export function extractBytesSingleThreaded(
+	data: Uint8Array,
+	mimeHint?: string | null,
+	config?: ExtractionConfigType | null,
+): ExtractionResult {
+	if (!isInitialized()) {
+		throw new Error("WASM module not initialized. Call initWasm() first.");
+	}
+
+	const wasm = getWasmModule();
+
+	try {
+		if (!data || data.length === 0) {
+			throw new Error("Document data cannot be empty");
+		}
+
+		const normalizedConfig = configToJS(config ?? null);
+
+		const result = wasm.extractBytesSingleThreaded(data, mimeHint ?? null, normalizedConfig);
+
+		if (!result) {
+			throw new Error("Invalid extraction result: no result from WASM module");
+		}
+
+		return jsToExtractionResult(result);
+	} catch (error) {
+		throw wrapWasmError(error, "extracting from bytes (single-threaded)");
+	}
+}
+
+/**
+ * Extract content from a base64-encoded document
+ *
+ * For contexts that move binary data as strings, such as `postMessage` payloads or JSON APIs.
+ * The string is decoded inside the WASM module and extracted exactly like `extractBytes`.
+ * Standard and URL-safe base64 are both accepted, with or without padding.
+ *
+ * Decoding happens in WASM memory, so peak usage is roughly 1.75x the encoded length on top of
+ * the extraction itself. For large documents, decode to a `Uint8Array` and call `extractBytes`.
+ *
+ * @param data - The base64-encoded document
+ * @param mimeHint - Optional MIME type of the document (detected from the bytes if omitted)
+ * @param config - Optional extraction configuration
+ * @returns Promise resolving to the extraction result
+ * @throws {Error} If WASM module is not initialized, the input is not valid base64, or extraction fails
+ *
+ * @example Extract a document received from the main thread
+ * ```typescript
+ * self.onmessage = async (event) => {
+ *   const result = await extractBase64(event.data.document, 'application/pdf');
+ *   self.postMessage(result.content);
+ * };
+ * ```
+ */
+export async function extractBase64(
+	data: string,
+	mimeHint?: string | null,
+	config?: ExtractionConfigType | null,
+): Promise<ExtractionResult> {
+	if (!isInitialized()) {
+		throw new Error("WASM module not initialized. Call initWasm() first.");
+	}
+
+	const wasm = getWasmModule();
+
+	try {
+		if (!data) {
+			throw new Error("Document data cannot be empty");
+		}
+
+		const normalizedConfig = configToJS(config ?? null);
+
+		const result = await wasm.extractBase64(data, mimeHint ?? null, normalizedConfig);
+
+		if (!result) {
+			throw new Error("Invalid extraction result: no result from WASM module");
+		}
+
+		return jsToExtractionResult(result);
+	} catch (error) {
+		throw wrapWasmError(error, "extracting from base64");
+	}
+}
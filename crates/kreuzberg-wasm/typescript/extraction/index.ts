@@ -7,5 +7,12 @@
 
 export type { ExtractionConfig, ExtractionResult } from "../types.js";
 export { batchExtractBytes, batchExtractBytesSync, batchExtractFiles } from "./batch.js";
-export { extractBytes, extractBytesSync } from "./bytes.js";
+export {
+	extractBase64,
+	extractBytes,
+	extractBytesSingleThreaded,
+	extractBytesSync,
+	extractBytesWithMime,
+	pdfPageCount,
+} from "./bytes.js";
 export { extractFile, extractFromFile } from "./files.js";
@@ -120,7 +120,14 @@ export {
 // Extraction Exports - Bytes
 // ============================================================================
 
-export { extractBytes, extractBytesSync } from "./extraction/bytes.js";
+export {
+	extractBase64,
+	extractBytes,
+	extractBytesSingleThreaded,
+	extractBytesSync,
+	extractBytesWithMime,
+	pdfPageCount,
+} from "./extraction/bytes.js";
 
 // ============================================================================
 // Extraction Exports - Files
@@ -138,6 +145,12 @@ export {
 	batchExtractFiles,
 } from "./extraction/batch.js";
 
+// ============================================================================
+// Configuration Exports
+// ============================================================================
+
+export { validateConfig } from "./config/validation.js";
+
 // ============================================================================
 // OCR Exports
 // ============================================================================
@@ -182,6 +195,7 @@ export {
 	listPostProcessors,
 	listValidators,
 	type PostProcessor,
+	registerMinTextLengthValidator,
 	registerPostProcessor,
 	registerValidator,
 	unregisterPostProcessor,
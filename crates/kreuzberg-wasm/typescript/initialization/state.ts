@@ -9,6 +9,14 @@
 export type WasmModule = {
 	extractBytes: (data: Uint8Array, mimeType: string, config: Record<string, unknown> | null) => Promise<unknown>;
 	extractBytesSync: (data: Uint8Array, mimeType: string, config: Record<string, unknown> | null) => unknown;
+	extractBytesWithMime: (data: Uint8Array, mimeType: string, config: Record<string, unknown> | null) => unknown;
+	extractBytesSingleThreaded: (
+		data: Uint8Array,
+		mimeHint: string | null,
+		config: Record<string, unknown> | null,
+	) => unknown;
+	extractBase64: (data: string, mimeHint: string | null, config: Record<string, unknown> | null) => Promise<unknown>;
+	pdfPageCount: (data: Uint8Array) => number;
 	batchExtractBytes: (
 		dataList: Uint8Array[],
 		mimeTypes: string[],
@@ -29,11 +37,16 @@ export type WasmModule = {
 
 	loadConfigFromString: (content: string, format: string) => Record<string, unknown>;
 	discoverConfig: () => Record<string, unknown>;
+	validateConfig: (config: Record<string, unknown>) => unknown;
 
 	version: () => string;
 	get_module_info: () => ModuleInfo;
 
 	register_ocr_backend: (backend: unknown) => void;
+	register_ocr_backend_fn: (
+		name: string,
+		callback: (image: Uint8Array, language: string) => string | Promise<string>,
+	) => void;
 	unregister_ocr_backend: (name: string) => void;
 	list_ocr_backends: () => string[];
 	clear_ocr_backends: () => void;
@@ -44,10 +57,15 @@ export type WasmModule = {
 	clear_post_processors: () => void;
 
 	register_validator: (validator: unknown) => void;
+	register_min_text_length_validator: (minLength: number) => void;
 	unregister_validator: (name: string) => void;
 	list_validators: () => string[];
 	clear_validators: () => void;
 
+	list_document_extractors: () => string[];
+	unregister_document_extractor: (name: string) => void;
+	clear_document_extractors: () => void;
+
 	initialize_pdfium_render: (pdfiumWasmModule: unknown, localWasmModule: unknown, debug: boolean) => boolean;
 	read_block_from_callback_wasm: (param: number, position: number, pBuf: number, size: number) => number;
 	write_block_from_callback_wasm: (param: number, buf: number, size: number) => number;
@@ -13,6 +13,7 @@
  * - `__kreuzberg_execute_validator`: Execute a registered validator
  */
 
+import { getWasmModule } from "./extraction/internal.js";
 import type { ExtractionResult } from "./types.js";
 
 /**
@@ -301,6 +302,26 @@ export function registerValidator(validator: Validator): void {
 	validators.set(name, validator);
 }
 
+/**
+ * Register the built-in minimum text length validator
+ *
+ * Unlike {@link registerValidator}, this validator runs inside the WASM module. It
+ * never fails an extraction; when the extracted text (ignoring surrounding whitespace)
+ * is shorter than `minLength` characters, it adds a `"validation"` warning to
+ * `result.warnings`.
+ *
+ * @param minLength - Minimum number of characters expected in the extracted text
+ * @throws {Error} If the WASM module is not loaded or the validator is already registered
+ *
+ * @example
+ * ```typescript
+ * registerMinTextLengthValidator(50);
+ * ```
+ */
+export function registerMinTextLengthValidator(minLength: number): void {
+	getWasmModule().register_min_text_length_validator(minLength);
+}
+
 /**
  * Get a registered validator by name
  *
@@ -206,8 +206,32 @@ export interface ExtractionConfig {
 	enableQualityProcessing?: boolean;
 	/** Force OCR even if text is available */
 	forceOcr?: boolean;
+	/** OCR images embedded in PDF pages and append their text to the content */
+	ocrEmbeddedImages?: boolean;
+	/** Extract without OCR instead of failing when the OCR backend is unavailable */
+	ocrOptional?: boolean;
 	/** Maximum concurrent extractions */
 	maxConcurrentExtractions?: number;
+	/** Maximum input size in bytes; larger inputs are rejected before processing */
+	maxInputBytes?: number;
+	/** Maximum number of pages to extract; later pages are dropped with a warning */
+	maxPages?: number;
+	/** Return the pages read before a damaged page instead of failing */
+	allowPartial?: boolean;
+	/** Detect the content language and store it in metadata when the document declares none */
+	detectLanguage?: boolean;
+	/** Append filled PDF form field values to the content as a labeled section */
+	extractFormFields?: boolean;
+	/** Normalize whitespace in the content, keeping paragraph breaks and code blocks */
+	normalizeWhitespace?: boolean;
+	/** Single-character field delimiter for CSV/TSV input; detected when unset */
+	csvDelimiter?: string;
+	/** Remove running headers and footers repeated across PDF pages */
+	stripRunningHeaders?: boolean;
+	/** Report word positions (page and bounding box) in the result layout */
+	includeLayout?: boolean;
+	/** Include speaker notes in presentation output */
+	includeSpeakerNotes?: boolean;
 	/**
 	 * Content output format.
 	 * Controls the format of the extracted content:
@@ -226,6 +250,16 @@ export interface ExtractionConfig {
 	resultFormat?: "unified" | "element_based";
 }
 
+/**
+ * A single invalid configuration value reported by `validateConfig()`
+ */
+export interface ConfigError {
+	/** Dotted path of the offending field (e.g. "ocr.tesseract_config.psm") */
+	field: string;
+	/** Description of the problem */
+	message: string;
+}
+
 /**
  * Tesseract OCR configuration
  */
@@ -426,6 +460,81 @@ export interface ExtractionResult {
 	keywords?: ExtractedKeyword[] | null;
 	/** Semantic elements when element-based output format is used */
 	elements?: Element[] | null;
+	/** Non-fatal issues encountered during extraction */
+	warnings?: ExtractionWarning[];
+	/** True when extraction stopped at a damaged page and only earlier pages are included */
+	partial?: boolean;
+	/** Positioned words when `includeLayout` is enabled */
+	layout?: PositionedText[] | null;
+}
+
+/**
+ * A word of document text with its position on the page.
+ *
+ * Coordinates are in PDF points with the origin at the bottom-left corner of the page.
+ */
+export interface PositionedText {
+	/** The word text */
+	text: string;
+	/** Page number (1-indexed) */
+	page_number: number;
+	/** Bounding box of the word */
+	bbox: BoundingBox;
+}
+
+/**
+ * Category of an extraction warning
+ */
+export type ExtractionWarningKind = "missing_font" | "partial_content" | "missing_page_markers" | "ocr_recommended" | "ocr_unavailable" | "truncated" | "validation" | "encoding";
+
+/**
+ * Variant of the Rust `KreuzbergError` an extraction failure originated from
+ */
+export type KreuzbergErrorKind =
+	| "Io"
+	| "Parsing"
+	| "Ocr"
+	| "Validation"
+	| "Cache"
+	| "ImageProcessing"
+	| "Serialization"
+	| "MissingDependency"
+	| "Plugin"
+	| "LockPoisoned"
+	| "UnsupportedFormat"
+	| "InputTooLarge"
+	| "Network"
+	| "Other";
+
+/**
+ * Error thrown by extraction functions, carrying a machine-readable kind
+ *
+ * @example
+ * ```typescript
+ * try {
+ *   await extractBytes(data, mimeType);
+ * } catch (error) {
+ *   if ((error as KreuzbergError).kind === "UnsupportedFormat") {
+ *     // fall back to another tool
+ *   }
+ * }
+ * ```
+ */
+export interface KreuzbergError extends Error {
+	/** Machine-readable error category */
+	kind: KreuzbergErrorKind;
+	/** Structured details for the error kind (e.g. `format`, `pluginName`, `size` and `limit`) */
+	context?: Record<string, unknown>;
+}
+
+/**
+ * A non-fatal issue encountered while extracting a document
+ */
+export interface ExtractionWarning {
+	/** Machine-readable category of the warning */
+	kind: ExtractionWarningKind;
+	/** Human-readable description */
+	message: string;
 }
 
 /**
@@ -452,6 +561,8 @@ export interface Metadata {
 	lastModifiedBy?: string;
 	/** Number of pages/slides */
 	pageCount?: number;
+	/** Whether the document's text is digital, scanned (image-only), or mixed */
+	document_kind?: "digital" | "scanned" | "mixed";
 	/** Format-specific metadata */
 	formatMetadata?: unknown;
 	/**
@@ -471,6 +582,8 @@ export interface Table {
 	markdown?: string;
 	/** Page number if available */
 	pageNumber?: number;
+	/** Table caption if available */
+	caption?: string | null;
 	/** Table headers */
 	headers?: string[];
 	/** Table rows */
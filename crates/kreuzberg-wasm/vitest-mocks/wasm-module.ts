@@ -14,6 +14,32 @@ export const extractBytesSync = () => ({
 	detectedLanguages: ["en"],
 });
 
+export const extractBytesWithMime = () => ({
+	content: "test content",
+	mimeType: "application/pdf",
+	metadata: { pageCount: 1 },
+	tables: [],
+	detectedLanguages: ["en"],
+});
+
+export const extractBytesSingleThreaded = () => ({
+	content: "test content",
+	mimeType: "application/pdf",
+	metadata: { pageCount: 1 },
+	tables: [],
+	detectedLanguages: ["en"],
+});
+
+export const extractBase64 = async () => ({
+	content: "test content",
+	mimeType: "text/plain",
+	metadata: {},
+	tables: [],
+	detectedLanguages: ["en"],
+});
+
+export const pdfPageCount = () => 1;
+
 export const batchExtractBytes = async () => [
 	{
 		content: "test content 1",
@@ -44,4 +70,6 @@ export const normalizeMimeType = (mime: string) => mime;
 
 export const version = () => "4.0.0";
 
+export const register_min_text_length_validator = () => undefined;
+
 export default async () => undefined;
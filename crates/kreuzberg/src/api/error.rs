@@ -115,6 +115,8 @@ impl ApiError {
             KreuzbergError::Plugin { .. } => "PluginError",
             KreuzbergError::LockPoisoned(_) => "LockPoisonedError",
             KreuzbergError::UnsupportedFormat(_) => "UnsupportedFormatError",
+            KreuzbergError::InputTooLarge { .. } => "InputTooLargeError",
+            KreuzbergError::Network { .. } => "NetworkError",
             KreuzbergError::Other(_) => "Error",
         };
 
@@ -156,6 +158,8 @@ impl From<KreuzbergError> for ApiError {
         match &error {
             KreuzbergError::Validation { .. } => Self::validation(error),
             KreuzbergError::Parsing { .. } | KreuzbergError::Ocr { .. } => Self::unprocessable(error),
+            KreuzbergError::InputTooLarge { .. } => Self::new(StatusCode::PAYLOAD_TOO_LARGE, error),
+            KreuzbergError::Network { .. } => Self::new(StatusCode::BAD_GATEWAY, error),
             _ => Self::internal(error),
         }
     }
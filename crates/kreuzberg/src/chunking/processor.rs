@@ -105,6 +105,10 @@ mod tests {
 	            detected_languages: None,
 	            chunks: None,
 	            images: None,
+	            links: vec![],
+	            warnings: vec![],
+	            partial: false,
+            layout: None,
             djot_content: None,
 	            pages: None,
 	            elements: None,
@@ -130,6 +134,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -167,6 +175,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -201,6 +213,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -214,6 +230,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
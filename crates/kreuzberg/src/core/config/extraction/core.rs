@@ -45,6 +45,31 @@ pub struct ExtractionConfig {
     #[serde(default)]
     pub force_ocr: bool,
 
+    /// Reconstruct tables from OCR word layout on scanned pages and images
+    ///
+    /// Groups recognized words into rows and columns by bounding-box alignment.
+    /// Heuristic and comparatively costly, so disabled by default.
+    #[serde(default)]
+    pub detect_image_tables: bool,
+
+    /// OCR images embedded in PDF pages and append their text to the content (default: false)
+    ///
+    /// Recovers text that only exists inside figures, screenshots, or infographics of
+    /// otherwise digital documents. Each image is recognized once, even when it is reused
+    /// on several pages. Uses `ocr` when set and the default Tesseract configuration
+    /// otherwise. Costly, so disabled by default. Requires the `ocr` feature.
+    #[serde(default)]
+    pub ocr_embedded_images: bool,
+
+    /// Extract without OCR instead of failing when the OCR backend is unavailable (default: false)
+    ///
+    /// When the configured backend (or the default Tesseract backend used by
+    /// `ocr_embedded_images`) is not registered, OCR is skipped, the embedded text is
+    /// returned, and an `ExtractionWarningKind::OcrUnavailable` warning is added. Useful
+    /// when the same configuration is deployed to hosts with and without OCR installed.
+    #[serde(default)]
+    pub ocr_optional: bool,
+
     /// Text chunking configuration (None = chunking disabled)
     #[serde(default)]
     pub chunking: Option<ChunkingConfig>,
@@ -94,6 +119,14 @@ pub struct ExtractionConfig {
     #[serde(default)]
     pub max_concurrent_extractions: Option<usize>,
 
+    /// Process batch extractions sequentially on the calling task instead of in parallel.
+    ///
+    /// Guarantees correct results in environments without a working thread pool
+    /// (e.g. browsers without `SharedArrayBuffer`). The WASM bindings enable this
+    /// automatically when the thread pool could not be initialized.
+    #[serde(default)]
+    pub force_single_threaded: bool,
+
     /// Result structure format
     ///
     /// Controls whether results are returned in unified format (default) with all
@@ -115,6 +148,90 @@ pub struct ExtractionConfig {
     /// when format conversion is applied.
     #[serde(default)]
     pub output_format: OutputFormat,
+
+    /// Character encoding for plain-text and HTML input (None = detect automatically)
+    ///
+    /// Accepts any WHATWG encoding label (e.g. `"windows-1252"`, `"shift_jis"`).
+    /// When set, BOM sniffing, `<meta charset>` declarations, and detection are skipped.
+    /// Encodings other than UTF-8 require the `quality` feature; without it such an
+    /// override fails validation and is otherwise ignored with a decoding warning.
+    #[serde(default)]
+    pub encoding_override: Option<String>,
+
+    /// Maximum input size in bytes (None = unlimited)
+    ///
+    /// Files are checked against their metadata before being read, and byte slices
+    /// before processing. Larger inputs fail with `KreuzbergError::InputTooLarge`.
+    #[serde(default)]
+    pub max_input_bytes: Option<u64>,
+
+    /// Maximum number of pages to extract (None = all pages)
+    ///
+    /// A safety cap for very long documents: only the first N pages are extracted and an
+    /// `ExtractionWarningKind::Truncated` warning is added to the result when pages were
+    /// dropped. Currently enforced by the PDF extractor.
+    #[serde(default)]
+    pub max_pages: Option<usize>,
+
+    /// Return the readable part of a damaged document instead of failing (default: false)
+    ///
+    /// When a page past the first cannot be read, extraction stops there and the earlier
+    /// pages are returned with `ExtractionResult::partial` set and an
+    /// `ExtractionWarningKind::PartialContent` warning naming the page. Currently
+    /// supported by the PDF extractor.
+    #[serde(default)]
+    pub allow_partial: bool,
+
+    /// Detect the primary content language and store it in `Metadata::language` (default: false)
+    ///
+    /// Only fills in the language when the document itself does not declare one.
+    /// Requires the `language-detection` feature.
+    #[serde(default)]
+    pub detect_language: bool,
+
+    /// Append filled PDF form field values to the content as a labeled section (default: false)
+    ///
+    /// Form field values are always reported in the PDF metadata; this additionally
+    /// renders them as `name: value` lines after the document text.
+    #[serde(default)]
+    pub extract_form_fields: bool,
+
+    /// Normalize whitespace in the extracted content (default: false)
+    ///
+    /// Collapses repeated and non-breaking spaces, joins words hyphenated across line
+    /// breaks, and normalizes line endings, while keeping paragraph breaks, indentation,
+    /// and fenced code blocks. See [`crate::text::normalize_text`].
+    #[serde(default)]
+    pub normalize_whitespace: bool,
+
+    /// Field delimiter for CSV and TSV input (None = detect automatically)
+    ///
+    /// Detection picks whichever of `,`, tab, `;`, and `|` splits the leading rows most
+    /// consistently; TSV input defaults to tab.
+    #[serde(default)]
+    pub csv_delimiter: Option<char>,
+
+    /// Remove running headers and footers from PDF text (default: false)
+    ///
+    /// Lines such as page numbers and document titles that repeat at the same vertical
+    /// position on more than half of the pages (and at least three) are left out of
+    /// the native text layer. Documents with fewer than three pages are not changed.
+    #[serde(default)]
+    pub strip_running_headers: bool,
+
+    /// Report the position of every word in `ExtractionResult::layout` (default: false)
+    ///
+    /// Each entry carries the word text, its 1-indexed page number, and its bounding
+    /// box in PDF points with the origin at the bottom-left corner of the page.
+    /// Currently only the PDF extractor populates the layout.
+    #[serde(default)]
+    pub include_layout: bool,
+
+    /// Include speaker notes in presentation output (default: true)
+    ///
+    /// Notes from `ppt/notesSlides` are appended to their slide under a "Notes" heading.
+    #[serde(default = "default_true")]
+    pub include_speaker_notes: bool,
 }
 
 impl Default for ExtractionConfig {
@@ -124,6 +241,9 @@ impl Default for ExtractionConfig {
             enable_quality_processing: true,
             ocr: None,
             force_ocr: false,
+            detect_image_tables: false,
+            ocr_embedded_images: false,
+            ocr_optional: false,
             chunking: None,
             images: None,
             #[cfg(feature = "pdf")]
@@ -137,8 +257,20 @@ impl Default for ExtractionConfig {
             #[cfg(feature = "html")]
             html_options: None,
             max_concurrent_extractions: None,
+            force_single_threaded: false,
             result_format: crate::types::OutputFormat::Unified,
             output_format: OutputFormat::Plain,
+            encoding_override: None,
+            max_input_bytes: None,
+            max_pages: None,
+            allow_partial: false,
+            detect_language: false,
+            extract_form_fields: false,
+            normalize_whitespace: false,
+            csv_delimiter: None,
+            strip_running_headers: false,
+            include_layout: false,
+            include_speaker_notes: true,
         }
     }
 }
@@ -9,6 +9,7 @@ use super::super::ocr::OcrConfig;
 use super::super::processing::ChunkingConfig;
 use super::core::ExtractionConfig;
 use super::types::TokenReductionConfig;
+use super::validation::describe_errors;
 
 impl ExtractionConfig {
     /// Apply environment variable overrides to configuration.
@@ -22,6 +23,8 @@ impl ExtractionConfig {
     /// - `KREUZBERG_CHUNKING_MAX_OVERLAP`: Maximum overlap between chunks (non-negative integer)
     /// - `KREUZBERG_CACHE_ENABLED`: Cache enabled flag ("true" or "false")
     /// - `KREUZBERG_TOKEN_REDUCTION_MODE`: Token reduction mode ("off", "light", "moderate", "aggressive", or "maximum")
+    /// - `KREUZBERG_MAX_CONCURRENT`: Maximum concurrent extractions in batch operations (positive integer)
+    /// - `KREUZBERG_OUTPUT_FORMAT`: Content format ("plain", "markdown", "djot", or "html")
     ///
     /// # Behavior
     ///
@@ -29,6 +32,7 @@ impl ExtractionConfig {
     /// - If a required parent config is `None` (e.g., `self.ocr` is None), it's created with defaults before applying the override
     /// - Invalid values return a `KreuzbergError::Validation` with helpful error messages
     /// - Missing or unset environment variables are silently ignored
+    /// - The resulting configuration is checked with [`ExtractionConfig::validate`]
     ///
     /// # Example
     ///
@@ -48,6 +52,7 @@ impl ExtractionConfig {
     /// - An environment variable contains an invalid value
     /// - A number cannot be parsed as the expected type
     /// - A boolean is not "true" or "false"
+    /// - The configuration is invalid once the overrides are applied
     pub fn apply_env_overrides(&mut self) -> Result<()> {
         use crate::core::config_validation::{
             validate_chunking_params, validate_language_code, validate_ocr_backend, validate_token_reduction_level,
@@ -170,6 +175,22 @@ impl ExtractionConfig {
             }
         }
 
+        // KREUZBERG_MAX_CONCURRENT override
+        if let Ok(max_concurrent_str) = std::env::var("KREUZBERG_MAX_CONCURRENT") {
+            let max_concurrent = max_concurrent_str
+                .parse::<usize>()
+                .ok()
+                .filter(|&max_concurrent| max_concurrent > 0)
+                .ok_or_else(|| KreuzbergError::Validation {
+                    message: format!(
+                        "Invalid value for KREUZBERG_MAX_CONCURRENT: '{}'. Must be a positive integer.",
+                        max_concurrent_str
+                    ),
+                    source: None,
+                })?;
+            self.max_concurrent_extractions = Some(max_concurrent);
+        }
+
         // KREUZBERG_OUTPUT_FORMAT override
         if let Ok(val) = std::env::var("KREUZBERG_OUTPUT_FORMAT") {
             self.output_format = val.parse().map_err(|e: String| KreuzbergError::Validation {
@@ -178,6 +199,12 @@ impl ExtractionConfig {
             })?;
         }
 
-        Ok(())
+        self.validate().map_err(|errors| KreuzbergError::Validation {
+            message: format!(
+                "Invalid configuration after applying environment overrides: {}",
+                describe_errors(&errors)
+            ),
+            source: None,
+        })
     }
 }
@@ -10,6 +10,7 @@ use std::sync::{Arc, LazyLock};
 use std::time::SystemTime;
 
 use super::core::ExtractionConfig;
+use super::validation::describe_errors;
 
 static CONFIG_CACHE: LazyLock<DashMap<PathBuf, (SystemTime, Arc<ExtractionConfig>)>> = LazyLock::new(DashMap::new);
 
@@ -22,7 +23,8 @@ impl ExtractionConfig {
     ///
     /// # Errors
     ///
-    /// Returns `KreuzbergError::Validation` if file doesn't exist or is invalid TOML.
+    /// Returns `KreuzbergError::Validation` if the file doesn't exist, is invalid TOML,
+    /// contains unknown keys, or holds invalid values.
     pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self> {
         let path = path.as_ref();
 
@@ -41,8 +43,7 @@ impl ExtractionConfig {
         let content = std::fs::read_to_string(path)
             .map_err(|e| KreuzbergError::validation(format!("Failed to read config file {}: {}", path.display(), e)))?;
 
-        let config: Self = toml::from_str(&content)
-            .map_err(|e| KreuzbergError::validation(format!("Invalid TOML in {}: {}", path.display(), e)))?;
+        let config = parse_config(&content, ConfigFormat::Toml, path)?;
 
         let config_arc = Arc::new(config);
         CONFIG_CACHE.insert(path.to_path_buf(), (mtime, config_arc.clone()));
@@ -69,8 +70,7 @@ impl ExtractionConfig {
         let content = std::fs::read_to_string(path)
             .map_err(|e| KreuzbergError::validation(format!("Failed to read config file {}: {}", path.display(), e)))?;
 
-        let config: Self = serde_yaml_ng::from_str(&content)
-            .map_err(|e| KreuzbergError::validation(format!("Invalid YAML in {}: {}", path.display(), e)))?;
+        let config = parse_config(&content, ConfigFormat::Yaml, path)?;
 
         let config_arc = Arc::new(config);
         CONFIG_CACHE.insert(path.to_path_buf(), (mtime, config_arc.clone()));
@@ -97,8 +97,7 @@ impl ExtractionConfig {
         let content = std::fs::read_to_string(path)
             .map_err(|e| KreuzbergError::validation(format!("Failed to read config file {}: {}", path.display(), e)))?;
 
-        let config: Self = serde_json::from_str(&content)
-            .map_err(|e| KreuzbergError::validation(format!("Invalid JSON in {}: {}", path.display(), e)))?;
+        let config = parse_config(&content, ConfigFormat::Json, path)?;
 
         let config_arc = Arc::new(config);
         CONFIG_CACHE.insert(path.to_path_buf(), (mtime, config_arc.clone()));
@@ -123,6 +122,8 @@ impl ExtractionConfig {
     /// - File doesn't exist
     /// - File extension is not supported
     /// - File content is invalid for the detected format
+    /// - File contains keys that are not configuration fields
+    /// - A configuration value is invalid (see [`ExtractionConfig::validate`])
     ///
     /// # Example
     ///
@@ -184,21 +185,126 @@ impl ExtractionConfig {
     /// - `Some(config)` if found
     /// - `None` if no config file found
     pub fn discover() -> Result<Option<Self>> {
-        let mut current = std::env::current_dir().map_err(KreuzbergError::Io)?;
+        let current = std::env::current_dir().map_err(KreuzbergError::Io)?;
+        Self::discover_from(current)
+    }
 
-        loop {
-            let kreuzberg_toml = current.join("kreuzberg.toml");
-            if kreuzberg_toml.exists() {
+    /// Discover configuration file starting from `start_dir`.
+    ///
+    /// Searches for `kreuzberg.toml` in `start_dir` and each of its parent directories,
+    /// loading the closest one found.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(config)` if found
+    /// - `None` if no config file found
+    pub fn discover_from(start_dir: impl AsRef<Path>) -> Result<Option<Self>> {
+        for dir in start_dir.as_ref().ancestors() {
+            let kreuzberg_toml = dir.join("kreuzberg.toml");
+            if kreuzberg_toml.is_file() {
                 return Ok(Some(Self::from_toml_file(kreuzberg_toml)?));
             }
-
-            if let Some(parent) = current.parent() {
-                current = parent.to_path_buf();
-            } else {
-                break;
-            }
         }
 
         Ok(None)
     }
 }
+
+#[derive(Debug, Clone, Copy)]
+enum ConfigFormat {
+    Toml,
+    Yaml,
+    Json,
+}
+
+/// Top-level sections that may share a config file with the extraction settings.
+const SHARED_SECTIONS: &[&str] = &["server"];
+
+/// Top-level fields whose features are compiled out of this build. They are accepted
+/// and ignored so the same file can be used with builds that enable them.
+const FEATURE_GATED_SECTIONS: &[&str] = &[
+    #[cfg(not(feature = "pdf"))]
+    "pdf_options",
+    #[cfg(not(any(feature = "keywords-yake", feature = "keywords-rake")))]
+    "keywords",
+    #[cfg(not(feature = "html"))]
+    "html_options",
+];
+
+/// Parse and validate configuration file content.
+///
+/// Unlike plain deserialization, keys that don't correspond to a configuration field
+/// are rejected rather than silently ignored, so typos surface when the file is loaded.
+fn parse_config(content: &str, format: ConfigFormat, path: &Path) -> Result<ExtractionConfig> {
+    let mut unknown_keys = Vec::new();
+    let on_unknown = |key: serde_ignored::Path<'_>| {
+        let key = key_path(&key);
+        if !SHARED_SECTIONS.contains(&key.as_str()) && !FEATURE_GATED_SECTIONS.contains(&key.as_str()) {
+            unknown_keys.push(key);
+        }
+    };
+
+    let parsed: std::result::Result<ExtractionConfig, String> = match format {
+        ConfigFormat::Toml => toml::Deserializer::parse(content)
+            .and_then(|deserializer| serde_ignored::deserialize(deserializer, on_unknown))
+            .map_err(|e| e.to_string()),
+        ConfigFormat::Yaml => serde_ignored::deserialize(serde_yaml_ng::Deserializer::from_str(content), on_unknown)
+            .map_err(|e| e.to_string()),
+        ConfigFormat::Json => {
+            let mut deserializer = serde_json::Deserializer::from_str(content);
+            serde_ignored::deserialize(&mut deserializer, on_unknown)
+                .and_then(|config| deserializer.end().map(|()| config))
+                .map_err(|e| e.to_string())
+        }
+    };
+    let config = parsed.map_err(|e| {
+        let format_name = match format {
+            ConfigFormat::Toml => "TOML",
+            ConfigFormat::Yaml => "YAML",
+            ConfigFormat::Json => "JSON",
+        };
+        KreuzbergError::validation(format!("Invalid {} in {}: {}", format_name, path.display(), e))
+    })?;
+
+    if !unknown_keys.is_empty() {
+        return Err(KreuzbergError::validation(format!(
+            "Unknown configuration keys in {}: {}",
+            path.display(),
+            unknown_keys.join(", ")
+        )));
+    }
+
+    config.validate().map_err(|errors| {
+        KreuzbergError::validation(format!(
+            "Invalid configuration in {}: {}",
+            path.display(),
+            describe_errors(&errors)
+        ))
+    })?;
+
+    Ok(config)
+}
+
+/// Dotted path of an ignored key, e.g. `ocr.languagee`, skipping `Option` and newtype levels.
+fn key_path(path: &serde_ignored::Path<'_>) -> String {
+    let mut segments = Vec::new();
+    let mut current = path;
+    loop {
+        current = match current {
+            serde_ignored::Path::Root => break,
+            serde_ignored::Path::Seq { parent, index } => {
+                segments.push(index.to_string());
+                parent
+            }
+            serde_ignored::Path::Map { parent, key } => {
+                segments.push(key.clone());
+                parent
+            }
+            serde_ignored::Path::Some { parent }
+            | serde_ignored::Path::NewtypeStruct { parent }
+            | serde_ignored::Path::NewtypeVariant { parent } => parent,
+        };
+    }
+    segments.reverse();
+    segments.join(".")
+}
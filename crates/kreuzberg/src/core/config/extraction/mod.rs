@@ -8,15 +8,21 @@
 //! - `core`: Main ExtractionConfig struct and implementation
 //! - `env`: Environment variable override support
 //! - `loaders`: Configuration file loading with caching
+//! - `validation`: Whole-configuration validation
+//! - `prepared`: Configuration validated once for repeated extraction
 
 mod core;
 mod env;
 mod loaders;
+mod prepared;
 mod types;
+mod validation;
 
 // Re-export all public types for backward compatibility
 pub use self::core::ExtractionConfig;
+pub use self::prepared::PreparedConfig;
 pub use self::types::{ImageExtractionConfig, LanguageDetectionConfig, TokenReductionConfig};
+pub use self::validation::ConfigError;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,152 @@
+//! Configuration checked once for repeated extraction.
+//!
+//! Extraction only rejects an invalid setting when it reaches it, which may be deep
+//! into a document or never for formats that ignore it. Services running many
+//! extractions with one configuration can instead call [`ExtractionConfig::prepare`]
+//! at startup, so a bad configuration fails there, and share the resulting
+//! [`PreparedConfig`] across tasks.
+
+use std::sync::Arc;
+
+use crate::{KreuzbergError, Result};
+
+use super::core::ExtractionConfig;
+use super::validation::describe_errors;
+
+/// An [`ExtractionConfig`] that passed validation, ready to be shared across extractions.
+///
+/// Created by [`ExtractionConfig::prepare`] and used with
+/// [`extract_file_with_prepared`](crate::core::extractor::extract_file_with_prepared).
+/// Cloning is cheap; clones share the same configuration.
+#[derive(Debug, Clone)]
+pub struct PreparedConfig {
+    config: Arc<ExtractionConfig>,
+}
+
+impl PreparedConfig {
+    /// The validated configuration.
+    pub fn config(&self) -> &ExtractionConfig {
+        &self.config
+    }
+}
+
+impl ExtractionConfig {
+    /// Validate the configuration once for repeated use.
+    ///
+    /// Besides running [`ExtractionConfig::validate`], this checks that the configured
+    /// OCR backend is registered and registers the built-in extractors, so none of
+    /// these can fail later during extraction. Custom OCR backends must therefore be
+    /// registered before preparing a config that uses them. With `ocr_optional`, a
+    /// missing backend is accepted; extraction then skips OCR with a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` listing every invalid value, or
+    /// `KreuzbergError::Plugin` when the OCR backend is not registered.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kreuzberg::core::extractor::extract_file_with_prepared;
+    /// use kreuzberg::ExtractionConfig;
+    ///
+    /// # async fn example() -> kreuzberg::Result<()> {
+    /// let prepared = ExtractionConfig::default().prepare()?;
+    /// for path in ["a.pdf", "b.docx"] {
+    ///     let result = extract_file_with_prepared(path, None, &prepared).await?;
+    ///     println!("{}", result.content);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prepare(&self) -> Result<PreparedConfig> {
+        self.validate().map_err(|errors| KreuzbergError::Validation {
+            message: format!("Invalid configuration: {}", describe_errors(&errors)),
+            source: None,
+        })?;
+
+        #[cfg(feature = "ocr")]
+        if let Some(ocr) = &self.ocr {
+            ocr.validate()?;
+
+            if !self.ocr_optional {
+                let registry = crate::plugins::registry::get_ocr_backend_registry();
+                let registry = registry.read().map_err(|e| KreuzbergError::Plugin {
+                    message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
+                    plugin_name: "ocr-registry".to_string(),
+                })?;
+                registry.get(&ocr.backend)?;
+            }
+        }
+
+        crate::extractors::ensure_initialized()?;
+
+        Ok(PreparedConfig {
+            config: Arc::new(self.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::ChunkingConfig;
+
+    #[test]
+    fn test_prepared_config_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<PreparedConfig>();
+    }
+
+    #[test]
+    fn test_prepare_keeps_config() {
+        let config = ExtractionConfig {
+            max_pages: Some(3),
+            ..Default::default()
+        };
+        let prepared = config.prepare().unwrap();
+        assert_eq!(prepared.config().max_pages, Some(3));
+        assert_eq!(prepared.clone().config().max_pages, Some(3));
+    }
+
+    #[test]
+    fn test_invalid_config_fails_at_prepare() {
+        let config = ExtractionConfig {
+            chunking: Some(ChunkingConfig {
+                max_characters: 100,
+                overlap: 200,
+                ..Default::default()
+            }),
+            max_concurrent_extractions: Some(0),
+            ..Default::default()
+        };
+
+        let err = config.prepare().unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+        let message = err.to_string();
+        assert!(message.contains("chunking"), "{}", message);
+        assert!(message.contains("max_concurrent_extractions"), "{}", message);
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_unregistered_ocr_backend_fails_at_prepare() {
+        let config = ExtractionConfig {
+            ocr: Some(crate::core::config::OcrConfig {
+                backend: "no-such-backend".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let err = config.prepare().unwrap_err();
+        assert!(matches!(err, KreuzbergError::Plugin { .. }));
+        assert!(err.to_string().contains("no-such-backend"));
+
+        let optional = ExtractionConfig {
+            ocr_optional: true,
+            ..config
+        };
+        assert!(optional.prepare().is_ok());
+    }
+}
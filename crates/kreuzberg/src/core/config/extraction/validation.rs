@@ -0,0 +1,332 @@
+//! Whole-configuration validation.
+//!
+//! The per-field validators in [`crate::core::config_validation`] stop at the first
+//! problem. [`ExtractionConfig::validate`] runs all of them against a complete config
+//! and collects every problem, so callers can report them together.
+
+use serde::{Deserialize, Serialize};
+
+use crate::KreuzbergError;
+use crate::core::config_validation::{
+    validate_binarization_method, validate_chunking_params, validate_confidence, validate_dpi, validate_tesseract_oem,
+    validate_tesseract_psm, validate_token_reduction_level,
+};
+
+use super::core::ExtractionConfig;
+
+/// A single invalid configuration value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[error("{field}: {message}")]
+pub struct ConfigError {
+    /// Dotted path of the offending field (e.g. `"ocr.tesseract_config.psm"`)
+    pub field: String,
+
+    /// Description of the problem
+    pub message: String,
+}
+
+impl ConfigError {
+    fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Join validation failures into a single message, e.g. for a `KreuzbergError::Validation`.
+pub(super) fn describe_errors(errors: &[ConfigError]) -> String {
+    errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+}
+
+/// Collects validation failures, keyed by field path.
+#[derive(Default)]
+struct ConfigErrors(Vec<ConfigError>);
+
+impl ConfigErrors {
+    fn check(&mut self, field: &str, result: crate::Result<()>) {
+        if let Err(err) = result {
+            let message = match err {
+                KreuzbergError::Validation { message, .. } => message,
+                other => other.to_string(),
+            };
+            self.0.push(ConfigError::new(field, message));
+        }
+    }
+
+    fn require(&mut self, field: &str, valid: bool, message: impl FnOnce() -> String) {
+        if !valid {
+            self.0.push(ConfigError::new(field, message()));
+        }
+    }
+}
+
+impl ExtractionConfig {
+    /// Validate every field of the configuration.
+    ///
+    /// Unlike extraction, which fails on the first invalid setting it reaches, this
+    /// reports all invalid values at once. OCR backend and language names are not
+    /// checked, since custom backends and installed language data are only known
+    /// at extraction time.
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`ConfigError`] found, in field order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kreuzberg::core::config::{ChunkingConfig, ExtractionConfig};
+    ///
+    /// let config = ExtractionConfig {
+    ///     chunking: Some(ChunkingConfig {
+    ///         max_characters: 100,
+    ///         overlap: 200,
+    ///         ..Default::default()
+    ///     }),
+    ///     max_concurrent_extractions: Some(0),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let errors = config.validate().unwrap_err();
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = ConfigErrors::default();
+
+        if let Some(ocr) = &self.ocr {
+            if let Some(dpi) = ocr.dpi {
+                errors.check("ocr.dpi", validate_dpi(i32::try_from(dpi).unwrap_or(i32::MAX)));
+            }
+            if let Some(psm) = ocr.psm {
+                errors.check("ocr.psm", validate_tesseract_psm(i32::from(psm)));
+            }
+            if let Some(oem) = ocr.oem {
+                errors.check("ocr.oem", validate_tesseract_oem(i32::from(oem)));
+            }
+        }
+
+        if let Some(tesseract) = self.ocr.as_ref().and_then(|ocr| ocr.tesseract_config.as_ref()) {
+            errors.check("ocr.tesseract_config.psm", validate_tesseract_psm(tesseract.psm));
+            errors.check("ocr.tesseract_config.oem", validate_tesseract_oem(tesseract.oem));
+            errors.require(
+                "ocr.tesseract_config.min_confidence",
+                (0.0..=100.0).contains(&tesseract.min_confidence),
+                || {
+                    format!(
+                        "Invalid minimum confidence '{}'. Must be between 0.0 and 100.0.",
+                        tesseract.min_confidence
+                    )
+                },
+            );
+            errors.check(
+                "ocr.tesseract_config.table_min_confidence",
+                validate_confidence(tesseract.table_min_confidence),
+            );
+
+            if let Some(preprocessing) = &tesseract.preprocessing {
+                errors.check(
+                    "ocr.tesseract_config.preprocessing.target_dpi",
+                    validate_dpi(preprocessing.target_dpi),
+                );
+                errors.check(
+                    "ocr.tesseract_config.preprocessing.binarization_method",
+                    validate_binarization_method(&preprocessing.binarization_method),
+                );
+            }
+        }
+
+        if let Some(chunking) = &self.chunking {
+            errors.check(
+                "chunking",
+                validate_chunking_params(chunking.max_characters, chunking.overlap),
+            );
+        }
+
+        if let Some(images) = &self.images {
+            errors.check("images.target_dpi", validate_dpi(images.target_dpi));
+            errors.check("images.min_dpi", validate_dpi(images.min_dpi));
+            errors.check("images.max_dpi", validate_dpi(images.max_dpi));
+            errors.require("images.min_dpi", images.min_dpi <= images.max_dpi, || {
+                format!(
+                    "min_dpi ({}) must not be greater than max_dpi ({})",
+                    images.min_dpi, images.max_dpi
+                )
+            });
+            errors.require("images.max_image_dimension", images.max_image_dimension > 0, || {
+                format!(
+                    "Invalid max_image_dimension '{}'. Must be greater than 0.",
+                    images.max_image_dimension
+                )
+            });
+        }
+
+        #[cfg(feature = "pdf")]
+        if let Some(hierarchy) = self.pdf_options.as_ref().and_then(|pdf| pdf.hierarchy.as_ref()) {
+            errors.require(
+                "pdf_options.hierarchy.k_clusters",
+                (1..=7).contains(&hierarchy.k_clusters),
+                || {
+                    format!(
+                        "Invalid k_clusters '{}'. Must be between 1 and 7.",
+                        hierarchy.k_clusters
+                    )
+                },
+            );
+            if let Some(threshold) = hierarchy.ocr_coverage_threshold {
+                errors.check(
+                    "pdf_options.hierarchy.ocr_coverage_threshold",
+                    validate_confidence(threshold as f64),
+                );
+            }
+            errors.require("pdf_options.hierarchy.max_columns", hierarchy.max_columns > 0, || {
+                "max_columns must be greater than 0".to_string()
+            });
+            errors.require(
+                "pdf_options.hierarchy.min_column_width",
+                hierarchy.min_column_width >= 0.0,
+                || {
+                    format!(
+                        "Invalid min_column_width '{}'. Must not be negative.",
+                        hierarchy.min_column_width
+                    )
+                },
+            );
+            errors.require(
+                "pdf_options.hierarchy.column_mode",
+                hierarchy.column_mode != crate::core::config::ColumnMode::Fixed(0),
+                || "Fixed column count must be greater than 0".to_string(),
+            );
+        }
+
+        if let Some(token_reduction) = &self.token_reduction {
+            errors.check(
+                "token_reduction.mode",
+                validate_token_reduction_level(&token_reduction.mode),
+            );
+        }
+
+        if let Some(language_detection) = &self.language_detection {
+            errors.check(
+                "language_detection.min_confidence",
+                validate_confidence(language_detection.min_confidence),
+            );
+        }
+
+        errors.require(
+            "max_concurrent_extractions",
+            self.max_concurrent_extractions != Some(0),
+            || "max_concurrent_extractions must be greater than 0".to_string(),
+        );
+
+        #[cfg(feature = "quality")]
+        if let Some(label) = &self.encoding_override {
+            errors.require(
+                "encoding_override",
+                encoding_rs::Encoding::for_label(label.trim().as_bytes()).is_some(),
+                || format!("Unknown encoding label '{}'", label),
+            );
+        }
+
+        #[cfg(not(feature = "quality"))]
+        if let Some(label) = &self.encoding_override {
+            errors.require("encoding_override", crate::text::encoding::is_utf8_label(label), || {
+                format!("Encoding override '{}' requires the `quality` feature", label)
+            });
+        }
+
+        if let Some(delimiter) = self.csv_delimiter {
+            errors.require("csv_delimiter", !matches!(delimiter, '"' | '\r' | '\n'), || {
+                format!("csv_delimiter cannot be {:?}", delimiter)
+            });
+        }
+
+        errors.require("max_input_bytes", self.max_input_bytes != Some(0), || {
+            "max_input_bytes must be greater than 0".to_string()
+        });
+
+        errors.require("max_pages", self.max_pages != Some(0), || {
+            "max_pages must be greater than 0".to_string()
+        });
+
+        if errors.0.is_empty() { Ok(()) } else { Err(errors.0) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{ChunkingConfig, LanguageDetectionConfig, OcrConfig, TokenReductionConfig};
+    use crate::types::TesseractConfig;
+
+    #[test]
+    fn test_default_config_is_valid() {
+        assert_eq!(ExtractionConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_reports_all_invalid_fields() {
+        let config = ExtractionConfig {
+            ocr: Some(OcrConfig {
+                tesseract_config: Some(TesseractConfig {
+                    psm: 42,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            chunking: Some(ChunkingConfig {
+                max_characters: 100,
+                overlap: 100,
+                ..Default::default()
+            }),
+            token_reduction: Some(TokenReductionConfig {
+                mode: "extreme".to_string(),
+                preserve_important_words: true,
+            }),
+            language_detection: Some(LanguageDetectionConfig {
+                enabled: true,
+                min_confidence: 1.5,
+                detect_multiple: false,
+            }),
+            max_concurrent_extractions: Some(0),
+            csv_delimiter: Some('"'),
+            max_pages: Some(0),
+            ..Default::default()
+        };
+
+        let errors = config.validate().expect_err("config should be invalid");
+        let fields: Vec<&str> = errors.iter().map(|error| error.field.as_str()).collect();
+
+        assert_eq!(
+            fields,
+            vec![
+                "ocr.tesseract_config.psm",
+                "chunking",
+                "token_reduction.mode",
+                "language_detection.min_confidence",
+                "max_concurrent_extractions",
+                "csv_delimiter",
+                "max_pages",
+            ]
+        );
+        assert!(errors[0].message.contains("Invalid tesseract PSM value '42'"));
+        assert!(errors[1].to_string().starts_with("chunking: max_overlap (100)"));
+    }
+
+    #[cfg(not(feature = "quality"))]
+    #[test]
+    fn test_non_utf8_encoding_override_requires_quality() {
+        let config = ExtractionConfig {
+            encoding_override: Some("shift_jis".to_string()),
+            ..Default::default()
+        };
+        let errors = config.validate().expect_err("config should be invalid");
+        assert_eq!(errors[0].field, "encoding_override");
+
+        let config = ExtractionConfig {
+            encoding_override: Some("utf8".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.validate(), Ok(()));
+    }
+}
@@ -11,10 +11,12 @@ pub mod pdf;
 pub mod processing;
 
 // Re-export main types for backward compatibility
-pub use extraction::{ExtractionConfig, ImageExtractionConfig, LanguageDetectionConfig, TokenReductionConfig};
+pub use extraction::{
+    ConfigError, ExtractionConfig, ImageExtractionConfig, LanguageDetectionConfig, PreparedConfig, TokenReductionConfig,
+};
 pub use formats::OutputFormat;
-pub use ocr::OcrConfig;
+pub use ocr::{BinarizationMethod, DEFAULT_OCR_DPI, OcrConfig, OcrPageSelection};
 pub use page::PageConfig;
 #[cfg(feature = "pdf")]
-pub use pdf::{HierarchyConfig, PdfConfig};
+pub use pdf::{ColumnMode, HierarchyConfig, PdfConfig};
 pub use processing::{ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, PostProcessorConfig};
@@ -3,9 +3,14 @@
 //! Defines OCR-specific configuration including backend selection, language settings,
 //! and Tesseract-specific parameters.
 
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
 use super::formats::OutputFormat;
+use crate::core::config_validation::{
+    validate_binarization_method, validate_dpi, validate_tesseract_oem, validate_tesseract_psm,
+};
 
 /// OCR configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,6 +20,10 @@ pub struct OcrConfig {
     pub backend: String,
 
     /// Language code (e.g., "eng", "deu")
+    ///
+    /// `"auto"` detects the language from a first OCR pass over the first page and
+    /// selects the matching installed language pack for the whole document, see
+    /// [`OcrConfig::is_auto_language`].
     #[serde(default = "default_eng")]
     pub language: String,
 
@@ -25,8 +34,110 @@ pub struct OcrConfig {
     /// Output format for OCR results (optional, for format conversion)
     #[serde(default)]
     pub output_format: Option<OutputFormat>,
+
+    /// Resolution at which PDF pages are rasterized for OCR (optional)
+    ///
+    /// Defaults to [`DEFAULT_OCR_DPI`]. Lower values OCR faster; higher values help with
+    /// small print. Must be between 1 and 2400.
+    #[serde(default)]
+    pub dpi: Option<u32>,
+
+    /// Tesseract page segmentation mode, 0-13 (optional)
+    ///
+    /// Overrides `tesseract_config.psm`. Tesseract defaults to 3 (fully automatic).
+    #[serde(default)]
+    pub psm: Option<u8>,
+
+    /// Tesseract OCR engine mode, 0-3 (optional)
+    ///
+    /// Overrides `tesseract_config.oem`. Tesseract defaults to 3 (whichever engine is available).
+    #[serde(default)]
+    pub oem: Option<u8>,
+
+    /// Binarization applied to images before they are handed to Tesseract (optional)
+    ///
+    /// Defaults to [`BinarizationMethod::None`], leaving thresholding to Tesseract.
+    #[serde(default)]
+    pub binarization: Option<BinarizationMethod>,
+
+    /// Which PDF pages to OCR (optional)
+    ///
+    /// Defaults to [`OcrPageSelection::All`]. Other selections OCR only the chosen pages
+    /// and keep the native text layer for the rest, which saves time on mostly digital
+    /// documents.
+    #[serde(default)]
+    pub ocr_pages: Option<OcrPageSelection>,
+
+    /// Language used with `language: "auto"` when detection fails (optional)
+    ///
+    /// Also used for the first OCR pass that detection runs on. Defaults to `"eng"`.
+    #[serde(default)]
+    pub language_fallback: Option<String>,
+}
+
+/// `OcrConfig::language` value that detects the language from the page content.
+pub const AUTO_OCR_LANGUAGE: &str = "auto";
+
+/// Selection of PDF pages to run OCR on.
+///
+/// Serialized as `"all"`, `"image_only"`, or `{"explicit": [1, 3]}`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OcrPageSelection {
+    /// OCR every page whenever OCR runs (forced, or when the native text is unusable)
+    #[default]
+    All,
+    /// OCR only pages whose native text layer is missing or unusable, e.g. scanned pages
+    ImageOnly,
+    /// OCR exactly these pages (1-indexed); page numbers beyond the document are ignored
+    Explicit(Vec<usize>),
+}
+
+/// Binarization applied to images before OCR.
+///
+/// Parsed case-insensitively from `"none"` or any name accepted by
+/// [`validate_binarization_method`]. `"sauvola"` is a local thresholding method and
+/// maps to [`BinarizationMethod::Adaptive`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", try_from = "String")]
+pub enum BinarizationMethod {
+    /// Leave the image unchanged; Tesseract applies its own global threshold
+    #[default]
+    None,
+    /// Single global threshold chosen with Otsu's method
+    Otsu,
+    /// Per-pixel threshold against the neighbourhood mean, which copes with uneven scan lighting
+    Adaptive,
+}
+
+impl FromStr for BinarizationMethod {
+    type Err = crate::KreuzbergError;
+
+    fn from_str(value: &str) -> crate::Result<Self> {
+        let method = value.trim().to_lowercase();
+        if method == "none" {
+            return Ok(Self::None);
+        }
+
+        validate_binarization_method(&method)?;
+        match method.as_str() {
+            "otsu" => Ok(Self::Otsu),
+            _ => Ok(Self::Adaptive),
+        }
+    }
 }
 
+impl TryFrom<String> for BinarizationMethod {
+    type Error = crate::KreuzbergError;
+
+    fn try_from(value: String) -> crate::Result<Self> {
+        value.parse()
+    }
+}
+
+/// Resolution at which PDF pages are rasterized for OCR when `OcrConfig::dpi` is unset.
+pub const DEFAULT_OCR_DPI: u32 = 300;
+
 impl Default for OcrConfig {
     fn default() -> Self {
         Self {
@@ -34,7 +145,101 @@ impl Default for OcrConfig {
             language: default_eng(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
+        }
+    }
+}
+
+impl OcrConfig {
+    /// Validate the `dpi`, `psm`, `oem`, `ocr_pages` and `language_fallback` settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` for the first value that is out of range.
+    pub fn validate(&self) -> crate::Result<()> {
+        if let Some(dpi) = self.dpi {
+            validate_dpi(i32::try_from(dpi).unwrap_or(i32::MAX))?;
+        }
+        if let Some(psm) = self.psm {
+            validate_tesseract_psm(i32::from(psm))?;
+        }
+        if let Some(oem) = self.oem {
+            validate_tesseract_oem(i32::from(oem))?;
+        }
+        if let Some(OcrPageSelection::Explicit(pages)) = &self.ocr_pages
+            && pages.contains(&0)
+        {
+            return Err(crate::KreuzbergError::validation(
+                "Invalid ocr_pages value: page numbers start at 1",
+            ));
+        }
+        if let Some(fallback) = &self.language_fallback
+            && (fallback.trim().is_empty() || fallback.trim().eq_ignore_ascii_case(AUTO_OCR_LANGUAGE))
+        {
+            return Err(crate::KreuzbergError::validation(format!(
+                "Invalid language_fallback value: '{}'. Use an installed language code such as 'eng'",
+                fallback
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether the OCR language is detected from the page content (`"auto"`).
+    ///
+    /// The language of `tesseract_config`, when present, takes precedence over `language`.
+    /// Detection runs once per document; backends that cannot detect languages use
+    /// [`OcrConfig::fallback_language`].
+    pub fn is_auto_language(&self) -> bool {
+        let language = self
+            .tesseract_config
+            .as_ref()
+            .map_or(self.language.as_str(), |tesseract_config| {
+                tesseract_config.language.as_str()
+            });
+        language.trim().eq_ignore_ascii_case(AUTO_OCR_LANGUAGE)
+    }
+
+    /// Language used when `"auto"` detection fails, `"eng"` unless `language_fallback` is set.
+    pub fn fallback_language(&self) -> &str {
+        self.language_fallback.as_deref().unwrap_or("eng")
+    }
+
+    /// Return a copy of this config that OCRs with the given language.
+    ///
+    /// Sets both `language` and the language of `tesseract_config`, if present.
+    pub fn with_language(&self, language: &str) -> Self {
+        let mut config = self.clone();
+        config.language = language.to_string();
+        if let Some(tesseract_config) = config.tesseract_config.as_mut() {
+            tesseract_config.language = language.to_string();
         }
+        config
+    }
+
+    /// Resolution at which PDF pages are rasterized for OCR.
+    pub fn render_dpi(&self) -> u32 {
+        self.dpi.unwrap_or(DEFAULT_OCR_DPI)
+    }
+
+    /// Return a copy of this config with Tesseract word-layout table detection enabled.
+    ///
+    /// Used when `ExtractionConfig::detect_image_tables` is set. When no Tesseract
+    /// configuration is present, one is created from defaults using this config's language.
+    pub fn with_table_detection(&self) -> Self {
+        let mut config = self.clone();
+        let tesseract_config = config
+            .tesseract_config
+            .get_or_insert_with(|| crate::types::TesseractConfig {
+                language: self.language.clone(),
+                ..Default::default()
+            });
+        tesseract_config.enable_table_detection = true;
+        config
     }
 }
 
@@ -57,6 +262,70 @@ mod tests {
         assert_eq!(config.language, "eng");
         assert!(config.tesseract_config.is_none());
         assert!(config.output_format.is_none());
+        assert!(config.dpi.is_none());
+        assert_eq!(config.render_dpi(), DEFAULT_OCR_DPI);
+    }
+
+    #[test]
+    fn test_ocr_config_validate_accepts_in_range_values() {
+        let config = OcrConfig {
+            dpi: Some(150),
+            psm: Some(6),
+            oem: Some(1),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(config.render_dpi(), 150);
+    }
+
+    #[test]
+    fn test_ocr_config_validate_rejects_out_of_range_values() {
+        let invalid = [
+            OcrConfig {
+                dpi: Some(0),
+                ..Default::default()
+            },
+            OcrConfig {
+                dpi: Some(10_000),
+                ..Default::default()
+            },
+            OcrConfig {
+                psm: Some(14),
+                ..Default::default()
+            },
+            OcrConfig {
+                oem: Some(4),
+                ..Default::default()
+            },
+        ];
+
+        for config in invalid {
+            let err = config.validate().expect_err("out-of-range value should be rejected");
+            assert!(matches!(err, crate::KreuzbergError::Validation { .. }), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_ocr_config_auto_language() {
+        let config = OcrConfig {
+            language: "AUTO".to_string(),
+            language_fallback: Some("fra".to_string()),
+            ..Default::default()
+        };
+        assert!(config.is_auto_language());
+        assert_eq!(config.fallback_language(), "fra");
+        assert_eq!(OcrConfig::default().fallback_language(), "eng");
+
+        let resolved = config.with_table_detection().with_language("deu");
+        assert!(!resolved.is_auto_language());
+        assert_eq!(resolved.language, "deu");
+        assert_eq!(resolved.tesseract_config.unwrap().language, "deu");
+
+        let invalid = OcrConfig {
+            language_fallback: Some("auto".to_string()),
+            ..config
+        };
+        assert!(invalid.validate().is_err());
     }
 
     #[test]
@@ -66,8 +335,72 @@ mod tests {
             language: "fra".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         };
         assert_eq!(config.backend, "tesseract");
         assert_eq!(config.language, "fra");
     }
+
+    #[test]
+    fn test_binarization_method_parsing() {
+        assert_eq!("none".parse::<BinarizationMethod>().unwrap(), BinarizationMethod::None);
+        assert_eq!("OTSU".parse::<BinarizationMethod>().unwrap(), BinarizationMethod::Otsu);
+        assert_eq!(
+            "adaptive".parse::<BinarizationMethod>().unwrap(),
+            BinarizationMethod::Adaptive
+        );
+        assert_eq!(
+            "sauvola".parse::<BinarizationMethod>().unwrap(),
+            BinarizationMethod::Adaptive
+        );
+
+        let err = "threshold".parse::<BinarizationMethod>().unwrap_err();
+        assert!(err.to_string().contains("Invalid binarization method"));
+    }
+
+    #[test]
+    fn test_binarization_method_serde() {
+        let config: OcrConfig = serde_json::from_str(r#"{"binarization": "adaptive"}"#).unwrap();
+        assert_eq!(config.binarization, Some(BinarizationMethod::Adaptive));
+        assert_eq!(
+            serde_json::to_value(BinarizationMethod::Otsu).unwrap(),
+            serde_json::json!("otsu")
+        );
+
+        let err = serde_json::from_str::<OcrConfig>(r#"{"binarization": "threshold"}"#).unwrap_err();
+        assert!(err.to_string().contains("Invalid binarization method"));
+    }
+
+    #[test]
+    fn test_ocr_page_selection_serde() {
+        let config: OcrConfig = serde_json::from_str(r#"{"ocr_pages": "image_only"}"#).unwrap();
+        assert_eq!(config.ocr_pages, Some(OcrPageSelection::ImageOnly));
+
+        let config: OcrConfig = serde_json::from_str(r#"{"ocr_pages": {"explicit": [2, 5]}}"#).unwrap();
+        assert_eq!(config.ocr_pages, Some(OcrPageSelection::Explicit(vec![2, 5])));
+        assert!(config.validate().is_ok());
+
+        let config = OcrConfig {
+            ocr_pages: Some(OcrPageSelection::Explicit(vec![0, 1])),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_ocr_config_with_table_detection_creates_tesseract_config() {
+        let config = OcrConfig {
+            language: "deu".to_string(),
+            ..Default::default()
+        };
+
+        let tesseract_config = config.with_table_detection().tesseract_config.unwrap();
+        assert!(tesseract_config.enable_table_detection);
+        assert_eq!(tesseract_config.language, "deu");
+    }
 }
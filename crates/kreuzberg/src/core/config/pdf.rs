@@ -55,6 +55,40 @@ pub struct HierarchyConfig {
     /// Default: 0.5 (trigger OCR if less than 50% of page has text)
     #[serde(default = "default_ocr_coverage_threshold")]
     pub ocr_coverage_threshold: Option<f32>,
+
+    /// Maximum number of text columns detected per page
+    ///
+    /// Complex layouts (forms, newspapers) can produce many narrow column candidates,
+    /// which scrambles reading order. When more columns are found, the narrowest ones
+    /// are merged into their neighbours. Set to 1 to always read pages as a single column.
+    /// Default: 3
+    #[serde(default = "default_max_columns")]
+    pub max_columns: usize,
+
+    /// Minimum column width in PDF points
+    ///
+    /// Column candidates narrower than this are merged into a neighbouring column.
+    /// Default: 50.0
+    #[serde(default = "default_min_column_width")]
+    pub min_column_width: f32,
+
+    /// How pages are split into text columns
+    ///
+    /// Column detection can split full-width tables or code listings into columns.
+    /// `Single` reads every page as one column and `Fixed(n)` splits the text into
+    /// `n` equal-width columns; both skip detection entirely.
+    /// Default: `Auto`
+    #[serde(default)]
+    pub column_mode: ColumnMode,
+
+    /// Assign heading levels from the document outline (bookmarks) when present
+    ///
+    /// Blocks matching an outline entry on the same page take the entry's nesting level,
+    /// and all other blocks are body text. Documents without an outline fall back to
+    /// font size clustering.
+    /// Default: false
+    #[serde(default)]
+    pub use_outline: bool,
 }
 
 impl Default for HierarchyConfig {
@@ -64,10 +98,29 @@ impl Default for HierarchyConfig {
             k_clusters: 6,
             include_bbox: true,
             ocr_coverage_threshold: None,
+            max_columns: default_max_columns(),
+            min_column_width: default_min_column_width(),
+            column_mode: ColumnMode::Auto,
+            use_outline: false,
         }
     }
 }
 
+/// Column layout used when ordering text blocks for reading.
+///
+/// Serialized as `"auto"`, `"single"`, or `{"fixed": 2}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnMode {
+    /// Detect columns from the gutters between text blocks
+    #[default]
+    Auto,
+    /// Read every page as a single column, top to bottom
+    Single,
+    /// Split the text extent of every page into this many equal-width columns
+    Fixed(usize),
+}
+
 fn default_true() -> bool {
     true
 }
@@ -80,6 +133,14 @@ fn default_ocr_coverage_threshold() -> Option<f32> {
     None
 }
 
+fn default_max_columns() -> usize {
+    3
+}
+
+fn default_min_column_width() -> f32 {
+    50.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,6 +153,10 @@ mod tests {
         assert_eq!(config.k_clusters, 6);
         assert!(config.include_bbox);
         assert!(config.ocr_coverage_threshold.is_none());
+        assert_eq!(config.max_columns, 3);
+        assert_eq!(config.min_column_width, 50.0);
+        assert_eq!(config.column_mode, ColumnMode::Auto);
+        assert!(!config.use_outline);
     }
 
     #[test]
@@ -102,10 +167,29 @@ mod tests {
             k_clusters: 3,
             include_bbox: false,
             ocr_coverage_threshold: Some(0.7),
+            max_columns: 1,
+            min_column_width: 50.0,
+            column_mode: ColumnMode::Single,
+            use_outline: false,
         };
         assert!(!config.enabled);
         assert_eq!(config.k_clusters, 3);
         assert!(!config.include_bbox);
         assert_eq!(config.ocr_coverage_threshold, Some(0.7));
+        assert_eq!(config.max_columns, 1);
+        assert_eq!(config.column_mode, ColumnMode::Single);
+    }
+
+    #[test]
+    #[cfg(feature = "pdf")]
+    fn test_column_mode_serde() {
+        let config: HierarchyConfig = serde_json::from_str(r#"{"column_mode": {"fixed": 2}}"#).unwrap();
+        assert_eq!(config.column_mode, ColumnMode::Fixed(2));
+
+        let config: HierarchyConfig = serde_json::from_str(r#"{"column_mode": "single"}"#).unwrap();
+        assert_eq!(config.column_mode, ColumnMode::Single);
+
+        let config: HierarchyConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.column_mode, ColumnMode::Auto);
     }
 }
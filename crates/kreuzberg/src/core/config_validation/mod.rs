@@ -149,6 +149,12 @@ mod tests {
         assert!(validate_language_code("*").is_ok());
     }
 
+    #[test]
+    fn test_validate_language_code_auto() {
+        assert!(validate_language_code("auto").is_ok());
+        assert!(validate_language_code("Auto").is_ok());
+    }
+
     #[test]
     fn test_validate_language_code_invalid() {
         let result = validate_language_code("invalid");
@@ -4,6 +4,7 @@
 //! and their specific parameters. Each function validates a specific aspect of
 //! the configuration and returns detailed error messages when validation fails.
 
+use crate::core::config::ocr::AUTO_OCR_LANGUAGE;
 use crate::{KreuzbergError, Result};
 
 /// Valid binarization methods for image preprocessing.
@@ -143,7 +144,8 @@ pub fn validate_ocr_backend(backend: &str) -> Result<()> {
 /// Validate a language code (ISO 639-1 or 639-3 format).
 ///
 /// Accepts both 2-letter ISO 639-1 codes (e.g., "en", "de") and
-/// 3-letter ISO 639-3 codes (e.g., "eng", "deu") for broader compatibility.
+/// 3-letter ISO 639-3 codes (e.g., "eng", "deu") for broader compatibility, as well
+/// as `"auto"` to detect the language from the page content.
 ///
 /// # Arguments
 ///
@@ -162,6 +164,7 @@ pub fn validate_ocr_backend(backend: &str) -> Result<()> {
 /// assert!(validate_language_code("eng").is_ok());
 /// assert!(validate_language_code("de").is_ok());
 /// assert!(validate_language_code("deu").is_ok());
+/// assert!(validate_language_code("auto").is_ok());
 /// assert!(validate_language_code("invalid").is_err());
 /// ```
 pub fn validate_language_code(code: &str) -> Result<()> {
@@ -172,6 +175,10 @@ pub fn validate_language_code(code: &str) -> Result<()> {
         return Ok(());
     }
 
+    if code_lower == AUTO_OCR_LANGUAGE {
+        return Ok(());
+    }
+
     if VALID_LANGUAGE_CODES.contains(&code_lower.as_str()) {
         return Ok(());
     }
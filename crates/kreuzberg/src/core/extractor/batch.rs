@@ -2,6 +2,8 @@
 //!
 //! This module provides parallel extraction capabilities for processing
 //! multiple files or byte arrays concurrently with automatic resource management.
+//! When `ExtractionConfig::force_single_threaded` is set, items are instead extracted
+//! one after another on the calling task.
 
 use crate::core::config::ExtractionConfig;
 use crate::types::{ErrorMetadata, ExtractionResult, Metadata};
@@ -19,7 +21,8 @@ use super::file::extract_file;
 /// This function processes multiple files in parallel, automatically managing
 /// concurrency to prevent resource exhaustion. The concurrency limit can be
 /// configured via `ExtractionConfig::max_concurrent_extractions` or defaults
-/// to `num_cpus * 2`.
+/// to `num_cpus * 2`. Set `ExtractionConfig::force_single_threaded` to process
+/// the files sequentially instead.
 ///
 /// # Arguments
 ///
@@ -28,7 +31,8 @@ use super::file::extract_file;
 ///
 /// # Returns
 ///
-/// A vector of `ExtractionResult` in the same order as the input paths.
+/// A vector of `ExtractionResult` in the same order as the input paths: `results[i]`
+/// always corresponds to `paths[i]`, regardless of the order in which extractions finish.
 ///
 /// # Errors
 ///
@@ -67,6 +71,18 @@ pub async fn batch_extract_file(
         return Ok(vec![]);
     }
 
+    if config.force_single_threaded {
+        let mut results = Vec::with_capacity(paths.len());
+        for path in paths {
+            let path_buf = path.as_ref().to_path_buf();
+            let start = Instant::now();
+            let result =
+                crate::core::batch_mode::with_batch_mode(async { extract_file(&path_buf, None, config).await }).await;
+            results.push(finish_batch_item(result, start));
+        }
+        return Ok(results);
+    }
+
     let config_arc = Arc::new(config.clone());
 
     let max_concurrent = config_arc
@@ -74,6 +90,7 @@ pub async fn batch_extract_file(
         .unwrap_or_else(|| (num_cpus::get() as f64 * 1.5).ceil() as usize);
     let semaphore = Arc::new(Semaphore::new(max_concurrent));
 
+    let len = paths.len();
     let mut tasks = JoinSet::new();
 
     for (index, path) in paths.into_iter().enumerate() {
@@ -84,61 +101,14 @@ pub async fn batch_extract_file(
         tasks.spawn(async move {
             let _permit = semaphore_clone.acquire().await.unwrap();
             let start = Instant::now();
-            let mut result =
+            let result =
                 crate::core::batch_mode::with_batch_mode(async { extract_file(&path_buf, None, &config_clone).await })
                     .await;
-            let elapsed_ms = start.elapsed().as_millis() as u64;
-
-            // Add extraction timing to result metadata for benchmarking
-            if let Ok(ref mut r) = result {
-                r.metadata.extraction_duration_ms = Some(elapsed_ms);
-            }
-
-            (index, result, elapsed_ms)
+            (index, finish_batch_item(result, start))
         });
     }
 
-    let mut results: Vec<Option<ExtractionResult>> = vec![None; tasks.len()];
-
-    while let Some(task_result) = tasks.join_next().await {
-        match task_result {
-            Ok((index, Ok(result), _elapsed_ms)) => {
-                // Timing already added to result.metadata.extraction_duration_ms
-                results[index] = Some(result);
-            }
-            Ok((index, Err(e), elapsed_ms)) => {
-                // All errors (including Io) should create error results
-                // instead of causing early return that abandons running tasks
-                let metadata = Metadata {
-                    error: Some(ErrorMetadata {
-                        error_type: format!("{:?}", e),
-                        message: e.to_string(),
-                    }),
-                    extraction_duration_ms: Some(elapsed_ms),
-                    ..Default::default()
-                };
-
-                results[index] = Some(ExtractionResult {
-                    content: format!("Error: {}", e),
-                    mime_type: Cow::Borrowed("text/plain"),
-                    metadata,
-                    tables: vec![],
-                    detected_languages: None,
-                    chunks: None,
-                    images: None,
-                    djot_content: None,
-                    pages: None,
-                    elements: None,
-                });
-            }
-            Err(join_err) => {
-                return Err(KreuzbergError::Other(format!("Task panicked: {}", join_err)));
-            }
-        }
-    }
-
-    #[allow(clippy::unwrap_used)]
-    Ok(results.into_iter().map(|r| r.unwrap()).collect())
+    collect_in_input_order(tasks, len).await
 }
 
 /// Extract content from multiple byte arrays concurrently.
@@ -146,7 +116,8 @@ pub async fn batch_extract_file(
 /// This function processes multiple byte arrays in parallel, automatically managing
 /// concurrency to prevent resource exhaustion. The concurrency limit can be
 /// configured via `ExtractionConfig::max_concurrent_extractions` or defaults
-/// to `num_cpus * 2`.
+/// to `num_cpus * 2`. Set `ExtractionConfig::force_single_threaded` to process
+/// the items sequentially instead.
 ///
 /// # Arguments
 ///
@@ -155,7 +126,8 @@ pub async fn batch_extract_file(
 ///
 /// # Returns
 ///
-/// A vector of `ExtractionResult` in the same order as the input.
+/// A vector of `ExtractionResult` in the same order as the input: `results[i]` always
+/// corresponds to `contents[i]`, regardless of the order in which extractions finish.
 ///
 /// # Example
 ///
@@ -192,6 +164,18 @@ pub async fn batch_extract_bytes(
         return Ok(vec![]);
     }
 
+    if config.force_single_threaded {
+        let mut results = Vec::with_capacity(contents.len());
+        for (bytes, mime_type) in contents {
+            let start = Instant::now();
+            let result =
+                crate::core::batch_mode::with_batch_mode(async { extract_bytes(&bytes, &mime_type, config).await })
+                    .await;
+            results.push(finish_batch_item(result, start));
+        }
+        return Ok(results);
+    }
+
     let config_arc = Arc::new(config.clone());
 
     let max_concurrent = config_arc
@@ -199,6 +183,7 @@ pub async fn batch_extract_bytes(
         .unwrap_or_else(|| (num_cpus::get() as f64 * 1.5).ceil() as usize);
     let semaphore = Arc::new(Semaphore::new(max_concurrent));
 
+    let len = contents.len();
     let mut tasks = JoinSet::new();
 
     for (index, (bytes, mime_type)) in contents.into_iter().enumerate() {
@@ -208,60 +193,78 @@ pub async fn batch_extract_bytes(
         tasks.spawn(async move {
             let _permit = semaphore_clone.acquire().await.unwrap();
             let start = Instant::now();
-            let mut result = crate::core::batch_mode::with_batch_mode(async {
+            let result = crate::core::batch_mode::with_batch_mode(async {
                 extract_bytes(&bytes, &mime_type, &config_clone).await
             })
             .await;
-            let elapsed_ms = start.elapsed().as_millis() as u64;
-
-            // Add extraction timing to result metadata for benchmarking
-            if let Ok(ref mut r) = result {
-                r.metadata.extraction_duration_ms = Some(elapsed_ms);
-            }
-
-            (index, result, elapsed_ms)
+            (index, finish_batch_item(result, start))
         });
     }
 
-    let mut results: Vec<Option<ExtractionResult>> = vec![None; tasks.len()];
+    collect_in_input_order(tasks, len).await
+}
+
+/// Collect the results of spawned batch tasks in input order.
+///
+/// Tasks complete in arbitrary order, so each one carries the index of its input and
+/// its result is written into that slot of a pre-sized vector. This guarantees that
+/// `results[i]` corresponds to input `i` regardless of completion order.
+#[cfg(feature = "tokio-runtime")]
+async fn collect_in_input_order(
+    mut tasks: tokio::task::JoinSet<(usize, ExtractionResult)>,
+    len: usize,
+) -> Result<Vec<ExtractionResult>> {
+    let mut slots: Vec<Option<ExtractionResult>> = vec![None; len];
 
     while let Some(task_result) = tasks.join_next().await {
-        match task_result {
-            Ok((index, Ok(result), _elapsed_ms)) => {
-                // Timing already added to result.metadata.extraction_duration_ms
-                results[index] = Some(result);
-            }
-            Ok((index, Err(e), elapsed_ms)) => {
-                // All errors (including Io) should create error results
-                // instead of causing early return that abandons running tasks
-                let metadata = Metadata {
-                    error: Some(ErrorMetadata {
-                        error_type: format!("{:?}", e),
-                        message: e.to_string(),
-                    }),
-                    extraction_duration_ms: Some(elapsed_ms),
-                    ..Default::default()
-                };
+        let (index, result) =
+            task_result.map_err(|join_err| KreuzbergError::Other(format!("Task panicked: {}", join_err)))?;
+        slots[index] = Some(result);
+    }
+
+    slots
+        .into_iter()
+        .enumerate()
+        .map(|(index, slot)| {
+            slot.ok_or_else(|| KreuzbergError::Other(format!("Batch item {} produced no result", index)))
+        })
+        .collect()
+}
 
-                results[index] = Some(ExtractionResult {
-                    content: format!("Error: {}", e),
-                    mime_type: Cow::Borrowed("text/plain"),
-                    metadata,
-                    tables: vec![],
-                    detected_languages: None,
-                    chunks: None,
-                    images: None,
-                    djot_content: None,
-                    pages: None,
-                    elements: None,
-                });
-            }
-            Err(join_err) => {
-                return Err(KreuzbergError::Other(format!("Task panicked: {}", join_err)));
-            }
+/// Convert a single batch item's outcome into a result, recording its duration.
+///
+/// Errors become error results so one failing item does not abort the batch.
+#[cfg(feature = "tokio-runtime")]
+fn finish_batch_item(result: Result<ExtractionResult>, start: Instant) -> ExtractionResult {
+    let elapsed_ms = start.elapsed().as_millis() as u64;
+
+    match result {
+        Ok(mut result) => {
+            result.metadata.extraction_duration_ms = Some(elapsed_ms);
+            result
         }
+        Err(e) => ExtractionResult {
+            content: format!("Error: {}", e),
+            mime_type: Cow::Borrowed("text/plain"),
+            metadata: Metadata {
+                error: Some(ErrorMetadata {
+                    error_type: format!("{:?}", e),
+                    message: e.to_string(),
+                }),
+                extraction_duration_ms: Some(elapsed_ms),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
+            djot_content: None,
+            pages: None,
+            elements: None,
+        },
     }
-
-    #[allow(clippy::unwrap_used)]
-    Ok(results.into_iter().map(|r| r.unwrap()).collect())
 }
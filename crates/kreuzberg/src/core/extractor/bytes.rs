@@ -1,7 +1,7 @@
 //! Byte array extraction operations.
 //!
 //! This module handles extraction from in-memory byte arrays, including:
-//! - MIME type validation
+//! - MIME type validation, or dispatch on a trusted MIME type
 //! - Legacy format conversion (DOC, PPT)
 //! - Extraction pipeline orchestration
 
@@ -19,6 +19,7 @@ use super::file::apply_libreoffice_metadata;
 use super::file::extract_bytes_with_extractor;
 #[cfg(feature = "otel")]
 use super::file::record_error;
+use super::helpers::{check_input_size, trusted_mime_type};
 
 /// Extract content from a byte array.
 ///
@@ -41,6 +42,7 @@ use super::file::record_error;
 ///
 /// # Errors
 ///
+/// Returns `KreuzbergError::InputTooLarge` if `content` exceeds `config.max_input_bytes`.
 /// Returns `KreuzbergError::Validation` if MIME type is invalid.
 /// Returns `KreuzbergError::UnsupportedFormat` if MIME type is not supported.
 ///
@@ -69,6 +71,8 @@ pub async fn extract_bytes(content: &[u8], mime_type: &str, config: &ExtractionC
     use crate::core::mime;
 
     let result = async {
+        check_input_size(content.len() as u64, config)?;
+
         let validated_mime = mime::validate_mime_type(mime_type)?;
 
         match validated_mime.as_str() {
@@ -114,3 +118,56 @@ pub async fn extract_bytes(content: &[u8], mime_type: &str, config: &ExtractionC
 
     result
 }
+
+/// Extract content from a byte array whose MIME type is already known.
+///
+/// Unlike [`extract_bytes`], the MIME type is trusted as given: it is not checked
+/// against the list of known formats, and the extractor registered for it is used
+/// directly. This suits MIME types from an authoritative source, such as an upload's
+/// `Content-Type`, and formats handled only by custom extractors. Parameters such as
+/// `; charset=binary` are ignored. Legacy DOC and PPT files are not converted; use
+/// [`extract_bytes`] for those.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::InputTooLarge` if `content` exceeds `config.max_input_bytes`.
+/// Returns `KreuzbergError::UnsupportedFormat` naming the MIME type if no extractor is registered for it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::extract_bytes_with_mime;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// # async fn example(upload: &[u8]) -> kreuzberg::Result<()> {
+/// let config = ExtractionConfig::default();
+/// let result = extract_bytes_with_mime(upload, "application/pdf; charset=binary", &config).await?;
+/// println!("Content: {}", result.content);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(feature = "otel", tracing::instrument(
+    skip(config, content),
+    fields(
+        extraction.mime_type = mime_type,
+        extraction.size_bytes = content.len(),
+    )
+))]
+pub async fn extract_bytes_with_mime(
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> Result<ExtractionResult> {
+    let result = async {
+        check_input_size(content.len() as u64, config)?;
+        extract_bytes_with_extractor(content, &trusted_mime_type(mime_type), config).await
+    }
+    .await;
+
+    #[cfg(feature = "otel")]
+    if let Err(ref e) = result {
+        record_error(e);
+    }
+
+    result
+}
@@ -9,7 +9,7 @@
 #[cfg(any(feature = "otel", not(feature = "office")))]
 use crate::KreuzbergError;
 use crate::Result;
-use crate::core::config::ExtractionConfig;
+use crate::core::config::{ExtractionConfig, PreparedConfig};
 use crate::core::mime::{LEGACY_POWERPOINT_MIME_TYPE, LEGACY_WORD_MIME_TYPE};
 #[cfg(feature = "office")]
 use crate::extraction::libreoffice::{convert_doc_to_docx, convert_ppt_to_pptx};
@@ -25,7 +25,9 @@ use std::path::Path;
 #[cfg(feature = "office")]
 use super::helpers::pool_mime_type;
 
-use super::helpers::get_extractor;
+#[cfg(feature = "ocr")]
+use super::helpers::without_unavailable_ocr;
+use super::helpers::{check_input_size, get_extractor};
 
 /// Sanitize a file path to return only the filename.
 ///
@@ -109,6 +111,7 @@ pub(in crate::core::extractor) fn record_error(error: &KreuzbergError) {
 /// # Errors
 ///
 /// Returns `KreuzbergError::Io` if the file doesn't exist (NotFound) or for other file I/O errors.
+/// Returns `KreuzbergError::InputTooLarge` if the file exceeds `config.max_input_bytes`.
 /// Returns `KreuzbergError::UnsupportedFormat` if MIME type is not supported.
 ///
 /// # Example
@@ -148,6 +151,10 @@ pub async fn extract_file(
     let result = async {
         io::validate_file_exists(path)?;
 
+        if config.max_input_bytes.is_some() {
+            check_input_size(std::fs::metadata(path)?.len(), config)?;
+        }
+
         let detected_mime = mime::detect_or_validate(Some(path), mime_type)?;
 
         match detected_mime.as_str() {
@@ -196,6 +203,33 @@ pub async fn extract_file(
     result
 }
 
+/// Extract content from a file with a configuration validated by [`ExtractionConfig::prepare`].
+///
+/// Behaves exactly like [`extract_file`] with the prepared configuration. Preparing the
+/// configuration once moves every configuration error to startup and lets concurrent
+/// tasks share one configuration without cloning it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::extract_file_with_prepared;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let prepared = ExtractionConfig::default().prepare()?;
+/// let result = extract_file_with_prepared("document.pdf", None, &prepared).await?;
+/// println!("Content: {}", result.content);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn extract_file_with_prepared(
+    path: impl AsRef<Path>,
+    mime_type: Option<&str>,
+    prepared: &PreparedConfig,
+) -> Result<ExtractionResult> {
+    extract_file(path, mime_type, prepared.config()).await
+}
+
 pub(in crate::core::extractor) async fn extract_file_with_extractor(
     path: &Path,
     mime_type: &str,
@@ -203,8 +237,15 @@ pub(in crate::core::extractor) async fn extract_file_with_extractor(
 ) -> Result<ExtractionResult> {
     crate::extractors::ensure_initialized()?;
 
+    #[cfg(feature = "ocr")]
+    let (config, ocr_warning) = without_unavailable_ocr(config)?;
+    #[cfg(feature = "ocr")]
+    let config = config.as_ref();
+
     let extractor = get_extractor(mime_type)?;
     let mut result = extractor.extract_file(path, mime_type, config).await?;
+    #[cfg(feature = "ocr")]
+    result.warnings.extend(ocr_warning);
     result = crate::core::pipeline::run_pipeline(result, config).await?;
     Ok(result)
 }
@@ -216,8 +257,15 @@ pub(in crate::core::extractor) async fn extract_bytes_with_extractor(
 ) -> Result<ExtractionResult> {
     crate::extractors::ensure_initialized()?;
 
+    #[cfg(feature = "ocr")]
+    let (config, ocr_warning) = without_unavailable_ocr(config)?;
+    #[cfg(feature = "ocr")]
+    let config = config.as_ref();
+
     let extractor = get_extractor(mime_type)?;
     let mut result = extractor.extract_bytes(content, mime_type, config).await?;
+    #[cfg(feature = "ocr")]
+    result.warnings.extend(ocr_warning);
     result = crate::core::pipeline::run_pipeline(result, config).await?;
     Ok(result)
 }
@@ -2,11 +2,16 @@
 //!
 //! This module provides shared utilities used across extraction modules.
 
+use crate::core::config::ExtractionConfig;
 use crate::plugins::DocumentExtractor;
+#[cfg(feature = "ocr")]
+use crate::types::{ExtractionWarning, ExtractionWarningKind};
 #[cfg(feature = "office")]
 use crate::utils::intern_mime_type;
 use crate::utils::{PoolSizeHint, estimate_pool_size};
 use crate::{KreuzbergError, Result};
+#[cfg(feature = "ocr")]
+use std::borrow::Cow;
 use std::sync::Arc;
 
 /// Get an extractor from the registry.
@@ -26,6 +31,77 @@ pub(in crate::core::extractor) fn get_extractor(mime_type: &str) -> Result<Arc<d
     registry_read.get(mime_type)
 }
 
+/// Reduce a MIME type from an authoritative source, such as a `Content-Type` header,
+/// to the form extractors are registered under.
+///
+/// Parameters are dropped and the type is lowercased, so
+/// `"Application/PDF; charset=binary"` becomes `"application/pdf"`.
+pub(in crate::core::extractor) fn trusted_mime_type(mime_type: &str) -> String {
+    mime_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// Reject input larger than `ExtractionConfig::max_input_bytes`.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::InputTooLarge` if a limit is configured and `size` exceeds it.
+pub(in crate::core::extractor) fn check_input_size(size: u64, config: &ExtractionConfig) -> Result<()> {
+    match config.max_input_bytes {
+        Some(limit) if size > limit => Err(KreuzbergError::InputTooLarge { size, limit }),
+        _ => Ok(()),
+    }
+}
+
+/// Drop OCR from the configuration when `ocr_optional` is set and the OCR backend is not registered.
+///
+/// The backend is the one of `config.ocr`, or the default backend when only
+/// `ocr_embedded_images` asks for OCR. Returns the configuration to extract with and
+/// the warning to add to the result when OCR was dropped.
+#[cfg(feature = "ocr")]
+pub(in crate::core::extractor) fn without_unavailable_ocr(
+    config: &ExtractionConfig,
+) -> Result<(Cow<'_, ExtractionConfig>, Option<ExtractionWarning>)> {
+    let backend = match &config.ocr {
+        Some(ocr) => ocr.backend.clone(),
+        None if config.ocr_embedded_images => crate::core::config::OcrConfig::default().backend,
+        None => return Ok((Cow::Borrowed(config), None)),
+    };
+    if !config.ocr_optional {
+        return Ok((Cow::Borrowed(config), None));
+    }
+
+    let available = {
+        let registry = crate::plugins::registry::get_ocr_backend_registry();
+        let registry = registry.read().map_err(|e| KreuzbergError::Plugin {
+            message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
+            plugin_name: "ocr-registry".to_string(),
+        })?;
+        registry.get(&backend).is_ok()
+    };
+    if available {
+        return Ok((Cow::Borrowed(config), None));
+    }
+
+    let warning = ExtractionWarning::new(
+        ExtractionWarningKind::OcrUnavailable,
+        format!(
+            "OCR backend '{}' is not available; OCR was skipped and only embedded text was extracted (ocr_optional)",
+            backend
+        ),
+    );
+    let config = ExtractionConfig {
+        ocr: None,
+        ocr_embedded_images: false,
+        ..config.clone()
+    };
+    Ok((Cow::Owned(config), Some(warning)))
+}
+
 /// Get optimal pool sizing hint for a document.
 ///
 /// This function calculates recommended pool sizes based on the document's
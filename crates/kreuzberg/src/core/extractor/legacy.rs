@@ -29,11 +29,13 @@ pub(super) fn extract_bytes_sync_impl(
     config: Option<&crate::core::config::ExtractionConfig>,
 ) -> crate::Result<crate::types::ExtractionResult> {
     use crate::KreuzbergError;
-    use crate::core::extractor::helpers::get_extractor;
+    use crate::core::extractor::helpers::check_input_size;
     use crate::core::mime;
 
     let cfg = config.cloned().unwrap_or_default();
 
+    check_input_size(content.len() as u64, &cfg)?;
+
     let validated_mime = if let Some(mime) = mime_type {
         mime::validate_mime_type(mime)?
     } else {
@@ -43,20 +45,50 @@ pub(super) fn extract_bytes_sync_impl(
         });
     };
 
+    extract_with_sync_extractor(content, &validated_mime, &cfg)
+}
+
+/// Synchronous extraction with a trusted MIME type, for WASM compatibility.
+///
+/// Like [`extract_bytes_sync_impl`], but dispatches to the extractor registered for
+/// `mime_type` without checking it against the known formats.
+#[cfg(not(feature = "tokio-runtime"))]
+pub(super) fn extract_bytes_with_mime_sync_impl(
+    content: &[u8],
+    mime_type: &str,
+    config: &crate::core::config::ExtractionConfig,
+) -> crate::Result<crate::types::ExtractionResult> {
+    use crate::core::extractor::helpers::{check_input_size, trusted_mime_type};
+
+    check_input_size(content.len() as u64, config)?;
+
+    extract_with_sync_extractor(content, &trusted_mime_type(mime_type), config)
+}
+
+/// Run the registered extractor for `mime_type` synchronously, then the sync pipeline.
+#[cfg(not(feature = "tokio-runtime"))]
+fn extract_with_sync_extractor(
+    content: &[u8],
+    mime_type: &str,
+    config: &crate::core::config::ExtractionConfig,
+) -> crate::Result<crate::types::ExtractionResult> {
+    use crate::KreuzbergError;
+    use crate::core::extractor::helpers::get_extractor;
+
     crate::extractors::ensure_initialized()?;
 
-    let extractor = get_extractor(&validated_mime)?;
+    let extractor = get_extractor(mime_type)?;
 
     let sync_extractor = extractor.as_sync_extractor().ok_or_else(|| {
         KreuzbergError::UnsupportedFormat(format!(
             "Extractor for '{}' does not support synchronous extraction",
-            validated_mime
+            mime_type
         ))
     })?;
 
-    let mut result = sync_extractor.extract_sync(content, &validated_mime, &cfg)?;
+    let mut result = sync_extractor.extract_sync(content, mime_type, config)?;
 
-    result = crate::core::pipeline::run_pipeline_sync(result, &cfg)?;
+    result = crate::core::pipeline::run_pipeline_sync(result, config)?;
 
     Ok(result)
 }
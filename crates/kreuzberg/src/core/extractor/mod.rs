@@ -7,9 +7,12 @@
 //! # Functions
 //!
 //! - [`extract_file`] - Extract content from a file path
+//! - [`extract_file_with_prepared`] - Extract content from a file path with a [`PreparedConfig`](crate::core::config::PreparedConfig)
 //! - [`extract_bytes`] - Extract content from a byte array
+//! - [`extract_bytes_with_mime`] - Extract content from a byte array with a trusted MIME type, skipping validation
 //! - [`batch_extract_file`] - Extract content from multiple files concurrently
 //! - [`batch_extract_bytes`] - Extract content from multiple byte arrays concurrently
+//! - `extract_url` - Fetch and extract a remote document (requires the `http` feature)
 
 mod bytes;
 mod file;
@@ -19,12 +22,14 @@ mod sync;
 
 #[cfg(feature = "tokio-runtime")]
 mod batch;
+#[cfg(feature = "http")]
+mod url;
 
 // Re-export public API
-pub use bytes::extract_bytes;
-pub use file::extract_file;
+pub use bytes::{extract_bytes, extract_bytes_with_mime};
+pub use file::{extract_file, extract_file_with_prepared};
 pub use helpers::get_pool_sizing_hint;
-pub use sync::{batch_extract_bytes_sync, extract_bytes_sync};
+pub use sync::{batch_extract_bytes_sync, extract_bytes_sync, extract_bytes_with_mime_sync};
 
 #[cfg(feature = "tokio-runtime")]
 pub use sync::extract_file_sync;
@@ -34,6 +39,9 @@ pub use batch::{batch_extract_bytes, batch_extract_file};
 #[cfg(feature = "tokio-runtime")]
 pub use sync::batch_extract_file_sync;
 
+#[cfg(feature = "http")]
+pub use url::{FETCH_TIMEOUT, MAX_REDIRECTS, extract_url};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,6 +72,30 @@ mod tests {
         assert_eq!(result.mime_type, "text/plain");
     }
 
+    #[tokio::test]
+    async fn test_extract_file_with_prepared_matches_extract_file() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("test.md");
+        let mut file = File::create(&file_path).unwrap();
+        file.write_all(b"# Title\n\nSome  text   with  spacing.\n").unwrap();
+
+        let config = ExtractionConfig {
+            use_cache: false,
+            normalize_whitespace: true,
+            ..Default::default()
+        };
+        let prepared = config.prepare().unwrap();
+
+        let direct = extract_file(&file_path, None, &config).await.unwrap();
+        for _ in 0..2 {
+            let result = extract_file_with_prepared(&file_path, None, &prepared).await.unwrap();
+            assert_eq!(
+                serde_json::to_value(&result).unwrap(),
+                serde_json::to_value(&direct).unwrap()
+            );
+        }
+    }
+
     #[tokio::test]
     async fn test_extract_file_with_mime_override() {
         let dir = tempdir().unwrap();
@@ -104,6 +136,39 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[cfg(feature = "pdf")]
+    #[tokio::test]
+    async fn test_extract_bytes_with_mime_uses_given_pdf_mime() {
+        let path =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test_documents/pdf/mixed_scanned_page.pdf");
+        let pdf_bytes = std::fs::read(&path).expect("mixed_scanned_page.pdf fixture should be readable");
+        let config = ExtractionConfig::default();
+
+        let result = extract_bytes_with_mime(&pdf_bytes, "Application/PDF; charset=binary", &config)
+            .await
+            .unwrap();
+        assert_eq!(result.mime_type, "application/pdf");
+        assert!(result.content.contains("Quarterly results"), "{}", result.content);
+
+        // The bytes are never sniffed: a plain text MIME sends the PDF to the text extractor
+        let result = extract_bytes_with_mime(&pdf_bytes, "text/plain", &config)
+            .await
+            .unwrap();
+        assert_eq!(result.mime_type, "text/plain");
+        assert!(result.content.contains("%PDF-"));
+    }
+
+    #[tokio::test]
+    async fn test_extract_bytes_with_mime_unregistered_mime() {
+        let config = ExtractionConfig::default();
+        let err = extract_bytes_with_mime(b"test", "application/x-no-such-format", &config)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, crate::KreuzbergError::UnsupportedFormat(_)));
+        assert!(err.to_string().contains("application/x-no-such-format"));
+    }
+
     #[tokio::test]
     async fn test_batch_extract_file() {
         let dir = tempdir().unwrap();
@@ -351,6 +416,57 @@ mod tests {
         assert_eq!(trimmed_len, 10_000_000);
     }
 
+    #[tokio::test]
+    async fn test_extract_file_exceeds_max_input_bytes() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("large.txt");
+        File::create(&file_path).unwrap().write_all(&[b'a'; 2048]).unwrap();
+
+        let config = ExtractionConfig {
+            max_input_bytes: Some(1024),
+            ..Default::default()
+        };
+        let result = extract_file(&file_path, None, &config).await;
+
+        use crate::KreuzbergError;
+        assert!(matches!(
+            result.unwrap_err(),
+            KreuzbergError::InputTooLarge {
+                size: 2048,
+                limit: 1024
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_extract_bytes_exceeds_max_input_bytes() {
+        let config = ExtractionConfig {
+            max_input_bytes: Some(1024),
+            ..Default::default()
+        };
+        let result = extract_bytes(&[b'a'; 2048], "text/plain", &config).await;
+
+        use crate::KreuzbergError;
+        assert!(matches!(
+            result.unwrap_err(),
+            KreuzbergError::InputTooLarge {
+                size: 2048,
+                limit: 1024
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_extract_bytes_within_max_input_bytes() {
+        let config = ExtractionConfig {
+            max_input_bytes: Some(1024),
+            ..Default::default()
+        };
+        let result = extract_bytes(&[b'a'; 1024], "text/plain", &config).await;
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_batch_extract_large_count() {
         let dir = tempdir().unwrap();
@@ -16,7 +16,7 @@ use once_cell::sync::Lazy;
 #[cfg(feature = "tokio-runtime")]
 use super::batch::{batch_extract_bytes, batch_extract_file};
 #[cfg(feature = "tokio-runtime")]
-use super::bytes::extract_bytes;
+use super::bytes::{extract_bytes, extract_bytes_with_mime};
 #[cfg(feature = "tokio-runtime")]
 use super::file::extract_file;
 
@@ -110,10 +110,47 @@ pub fn extract_bytes_sync(content: &[u8], mime_type: &str, config: &ExtractionCo
     super::legacy::extract_bytes_sync_impl(content, Some(mime_type), Some(config))
 }
 
+/// Synchronous wrapper for `extract_bytes_with_mime`.
+///
+/// Uses the global Tokio runtime, like `extract_bytes_sync`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::extract_bytes_with_mime_sync;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// let config = ExtractionConfig::default();
+/// let bytes = std::fs::read("upload.bin")?;
+/// let result = extract_bytes_with_mime_sync(&bytes, "application/pdf", &config)?;
+/// println!("Content: {}", result.content);
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// ```
+#[cfg(feature = "tokio-runtime")]
+pub fn extract_bytes_with_mime_sync(
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> Result<ExtractionResult> {
+    GLOBAL_RUNTIME.block_on(extract_bytes_with_mime(content, mime_type, config))
+}
+
+/// Synchronous wrapper for `extract_bytes_with_mime` (WASM-compatible version).
+///
+/// This is a truly synchronous implementation without tokio runtime dependency.
+#[cfg(not(feature = "tokio-runtime"))]
+pub fn extract_bytes_with_mime_sync(
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> Result<ExtractionResult> {
+    super::legacy::extract_bytes_with_mime_sync_impl(content, mime_type, config)
+}
+
 /// Synchronous wrapper for `batch_extract_file`.
 ///
 /// Uses the global Tokio runtime for 100x+ performance improvement over creating
-/// a new runtime per call.
+/// a new runtime per call. Results are returned in the same order as `paths`.
 ///
 /// This function is only available with the `tokio-runtime` feature. For WASM targets,
 /// use a truly synchronous extraction approach instead.
@@ -141,7 +178,7 @@ pub fn batch_extract_file_sync(
 /// Synchronous wrapper for `batch_extract_bytes`.
 ///
 /// Uses the global Tokio runtime for 100x+ performance improvement over creating
-/// a new runtime per call.
+/// a new runtime per call. Results are returned in the same order as `contents`.
 ///
 /// With the `tokio-runtime` feature, this blocks the current thread using the global
 /// Tokio runtime. Without it (WASM), this calls a truly synchronous implementation
@@ -199,6 +236,10 @@ pub fn batch_extract_bytes_sync(
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
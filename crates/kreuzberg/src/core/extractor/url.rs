@@ -0,0 +1,164 @@
+//! URL extraction operations.
+//!
+//! This module fetches remote documents over HTTP(S) and hands the downloaded
+//! bytes to the regular byte extraction pipeline. Fetch failures surface as
+//! `KreuzbergError::Network`, so callers can tell them apart from extraction failures.
+
+use crate::core::config::ExtractionConfig;
+use crate::core::mime;
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use std::time::Duration;
+
+use super::bytes::extract_bytes;
+use super::helpers::check_input_size;
+
+/// Maximum number of redirects followed before a fetch is aborted.
+pub const MAX_REDIRECTS: usize = 10;
+
+/// Upper bound for a complete fetch, including connecting, redirects and the body download.
+pub const FETCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Extract content from a document at a remote URL.
+///
+/// Downloads the document, then delegates to [`extract_bytes`]. The MIME type is taken
+/// from the `Content-Type` response header when it names a supported format, and is
+/// otherwise detected from the downloaded bytes.
+///
+/// # Arguments
+///
+/// * `url` - An `http` or `https` URL
+/// * `config` - Extraction configuration
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if `url` is not a valid HTTP(S) URL.
+/// Returns `KreuzbergError::Network` if the request fails, times out, follows more than
+/// [`MAX_REDIRECTS`] redirects, or the server responds with a non-success status.
+/// Returns `KreuzbergError::InputTooLarge` if the response exceeds `config.max_input_bytes`.
+/// Any error from [`extract_bytes`] is returned unchanged.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::extract_url;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let config = ExtractionConfig::default();
+/// let result = extract_url("https://example.com/report.pdf", &config).await?;
+/// println!("Content: {}", result.content);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(feature = "otel", tracing::instrument(skip(config), fields(extraction.url = url)))]
+pub async fn extract_url(url: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    let (content, mime_type) = fetch_url(url, config).await?;
+    extract_bytes(&content, &mime_type, config).await
+}
+
+/// Download `url`, enforcing the redirect cap, fetch timeout and `max_input_bytes`.
+///
+/// Returns the response body and its resolved MIME type.
+async fn fetch_url(url: &str, config: &ExtractionConfig) -> Result<(Vec<u8>, String)> {
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|e| KreuzbergError::validation_with_source(format!("Invalid URL '{}'", url), e))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(KreuzbergError::validation(format!(
+            "Unsupported URL scheme '{}': only http and https are supported",
+            parsed.scheme()
+        )));
+    }
+
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::limited(MAX_REDIRECTS))
+        .timeout(FETCH_TIMEOUT)
+        .build()
+        .map_err(|e| KreuzbergError::network_with_source("Failed to create HTTP client", e))?;
+
+    let mut response = client
+        .get(parsed)
+        .send()
+        .await
+        .map_err(|e| KreuzbergError::network_with_source(format!("Failed to fetch '{}'", url), e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(KreuzbergError::network(format!(
+            "Failed to fetch '{}': HTTP {}",
+            url, status
+        )));
+    }
+
+    // Reject oversized responses up front when the server announces the size,
+    // and keep checking while streaming since Content-Length may be absent or wrong.
+    if let Some(length) = response.content_length() {
+        check_input_size(length, config)?;
+    }
+
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let mut content = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| KreuzbergError::network_with_source(format!("Failed to read response from '{}'", url), e))?
+    {
+        content.extend_from_slice(&chunk);
+        check_input_size(content.len() as u64, config)?;
+    }
+
+    let mime_type = resolve_mime_type(content_type.as_deref(), &content)?;
+    Ok((content, mime_type))
+}
+
+/// Pick the MIME type from the `Content-Type` header, falling back to content sniffing.
+///
+/// Servers frequently send `application/octet-stream` or a header with parameters
+/// (`text/html; charset=utf-8`), so only the essence is used and generic types are ignored.
+fn resolve_mime_type(content_type: Option<&str>, content: &[u8]) -> Result<String> {
+    let declared = content_type
+        .and_then(|value| value.split(';').next())
+        .map(|essence| essence.trim().to_ascii_lowercase())
+        .filter(|essence| !essence.is_empty() && essence != "application/octet-stream");
+
+    if let Some(declared) = declared
+        && let Ok(validated) = mime::validate_mime_type(&declared)
+    {
+        return Ok(validated);
+    }
+
+    mime::detect_mime_type_from_bytes(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_mime_type_prefers_header_essence() {
+        let mime_type = resolve_mime_type(Some("text/html; charset=utf-8"), b"plain").unwrap();
+        assert_eq!(mime_type, "text/html");
+    }
+
+    #[test]
+    fn test_resolve_mime_type_sniffs_generic_header() {
+        let mime_type = resolve_mime_type(Some("application/octet-stream"), b"%PDF-1.4\n").unwrap();
+        assert_eq!(mime_type, "application/pdf");
+
+        let mime_type = resolve_mime_type(None, b"%PDF-1.4\n").unwrap();
+        assert_eq!(mime_type, "application/pdf");
+    }
+
+    #[tokio::test]
+    async fn test_extract_url_rejects_unsupported_scheme() {
+        let err = extract_url("file:///etc/passwd", &ExtractionConfig::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+    }
+}
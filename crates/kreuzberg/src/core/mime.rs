@@ -5,8 +5,10 @@
 
 use crate::{KreuzbergError, Result};
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::str::FromStr;
 
 pub const HTML_MIME_TYPE: &str = "text/html";
 pub const MARKDOWN_MIME_TYPE: &str = "text/markdown";
@@ -339,6 +341,10 @@ pub fn detect_or_validate(path: Option<&Path>, mime_type: Option<&str>) -> Resul
 ///
 /// Returns `KreuzbergError::UnsupportedFormat` if MIME type cannot be determined.
 pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
+    if let Some(office_mime) = detect_office_container_format(content) {
+        return Ok(office_mime.to_string());
+    }
+
     if let Some(kind) = infer::get(content) {
         let mime_type = kind.mime_type();
 
@@ -383,6 +389,460 @@ pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
     ))
 }
 
+/// How [`detect_mime`] resolves disagreement between file content and extension.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MimePolicy {
+    /// Trust the content signature over the extension (default)
+    #[default]
+    PreferContent,
+    /// Trust the extension over the content signature
+    PreferExtension,
+    /// Reject inputs whose content and extension disagree
+    Strict,
+}
+
+impl std::fmt::Display for MimePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MimePolicy::PreferContent => write!(f, "prefer_content"),
+            MimePolicy::PreferExtension => write!(f, "prefer_extension"),
+            MimePolicy::Strict => write!(f, "strict"),
+        }
+    }
+}
+
+impl FromStr for MimePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "prefer_content" | "content" => Ok(MimePolicy::PreferContent),
+            "prefer_extension" | "extension" => Ok(MimePolicy::PreferExtension),
+            "strict" => Ok(MimePolicy::Strict),
+            _ => Err(format!(
+                "Invalid MIME policy: '{}'. Valid values: 'prefer_content', 'prefer_extension', 'strict'",
+                s
+            )),
+        }
+    }
+}
+
+/// Result of [`detect_mime`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MimeDetection {
+    /// MIME type chosen according to the policy
+    pub mime_type: String,
+    /// Whether the content signature and the file extension disagreed
+    pub mismatch: bool,
+}
+
+/// Detect a MIME type from both a file path and its content.
+///
+/// The extension and the content signature are detected independently. When they
+/// agree, or the content is plain text compatible with a text-based extension (e.g.
+/// Markdown or CSV), the more specific extension-based type is returned. When they
+/// disagree, `policy` decides which one wins, or rejects the input with
+/// [`MimePolicy::Strict`]. If only one source yields a type, it is used as is.
+///
+/// # Arguments
+///
+/// * `path` - File path (or name) whose extension is checked; the file need not exist
+/// * `content` - Raw file bytes
+/// * `policy` - How to resolve a mismatch
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` on a mismatch under [`MimePolicy::Strict`].
+/// Returns `KreuzbergError::UnsupportedFormat` if neither source yields a MIME type.
+///
+/// # Example
+///
+/// ```
+/// use kreuzberg::core::mime::{MimePolicy, detect_mime};
+///
+/// let detection = detect_mime("report.txt", b"%PDF-1.7\n", MimePolicy::PreferContent).unwrap();
+/// assert_eq!(detection.mime_type, "application/pdf");
+/// assert!(detection.mismatch);
+/// ```
+pub fn detect_mime(path: impl AsRef<Path>, content: &[u8], policy: MimePolicy) -> Result<MimeDetection> {
+    let from_extension = detect_mime_type(path.as_ref(), false).ok();
+    let from_content = detect_mime_type_from_bytes(content);
+
+    let (extension_mime, content_mime) = match (from_extension, from_content) {
+        (Some(extension_mime), Ok(content_mime)) => (extension_mime, content_mime),
+        (Some(mime_type), Err(_)) | (None, Ok(mime_type)) => {
+            return Ok(MimeDetection {
+                mime_type,
+                mismatch: false,
+            });
+        }
+        (None, Err(e)) => return Err(e),
+    };
+
+    if mime_types_compatible(&extension_mime, &content_mime) {
+        return Ok(MimeDetection {
+            mime_type: extension_mime,
+            mismatch: false,
+        });
+    }
+
+    let mime_type = match policy {
+        MimePolicy::PreferContent => content_mime,
+        MimePolicy::PreferExtension => extension_mime,
+        MimePolicy::Strict => {
+            return Err(KreuzbergError::validation(format!(
+                "MIME type mismatch for {}: content is {} but extension indicates {}",
+                path.as_ref().display(),
+                content_mime,
+                extension_mime
+            )));
+        }
+    };
+
+    Ok(MimeDetection {
+        mime_type,
+        mismatch: true,
+    })
+}
+
+/// Where a [`MimeCandidate`] was detected from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MimeSource {
+    /// Content signature or container structure
+    Magic,
+    /// File name extension
+    Extension,
+    /// Main part declared in the `[Content_Types].xml` of an Office Open XML package
+    ContentTypesXml,
+}
+
+/// A plausible MIME type for an input, as returned by [`detect_mime_candidates`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MimeCandidate {
+    /// The MIME type
+    pub mime: String,
+    /// Confidence in `[0.0, 1.0]`; only meaningful relative to other candidates
+    pub confidence: f32,
+    /// Where the candidate was detected from
+    pub source: MimeSource,
+}
+
+/// Generic MIME type of OLE compound files.
+const OLE_CONTAINER_MIME_TYPE: &str = "application/x-ole-storage";
+const ZIP_MIME_TYPE: &str = "application/zip";
+
+/// Detect every plausible MIME type of an input, most likely first.
+///
+/// Unlike [`detect_mime_type_from_bytes`] and [`detect_mime`], which pick one type, this
+/// reports each type suggested by the content and the file name so callers can apply their
+/// own tie-breaking policy. Container formats yield both the specific format and the generic
+/// container, e.g. a DOCX yields the DOCX type from `[Content_Types].xml` ranked above
+/// `application/zip`. Text content that only sniffs as plain text ranks below a text-based
+/// extension such as `.md`.
+///
+/// A MIME type suggested by several sources appears once per source. Candidates with equal
+/// confidence keep their detection order: content before extension.
+///
+/// # Arguments
+///
+/// * `content` - Raw file bytes
+/// * `filename` - Optional file name (or path) whose extension is checked
+///
+/// # Example
+///
+/// ```
+/// use kreuzberg::core::mime::{MimeSource, detect_mime_candidates};
+///
+/// let candidates = detect_mime_candidates(b"%PDF-1.7\n", Some("report.txt"));
+/// assert_eq!(candidates[0].mime, "application/pdf");
+/// assert_eq!(candidates[0].source, MimeSource::Magic);
+/// assert!(candidates.iter().any(|c| c.mime == "text/plain" && c.source == MimeSource::Extension));
+/// ```
+pub fn detect_mime_candidates(content: &[u8], filename: Option<&str>) -> Vec<MimeCandidate> {
+    let mut candidates = Vec::new();
+
+    let container = if content.starts_with(ZIP_MAGIC) {
+        let specific = match detect_office_format_from_content_types(content) {
+            Some(mime_type) => Some((mime_type, 0.95, MimeSource::ContentTypesXml)),
+            None => detect_office_format_from_zip(content).map(|mime_type| (mime_type, 0.8, MimeSource::Magic)),
+        };
+        Some((ZIP_MIME_TYPE, specific))
+    } else if content.starts_with(OLE_MAGIC) {
+        let specific = detect_ole_format(content).map(|mime_type| (mime_type, 0.9, MimeSource::Magic));
+        Some((OLE_CONTAINER_MIME_TYPE, specific))
+    } else {
+        None
+    };
+
+    if let Some((container_mime, specific)) = container {
+        // The generic container is only a fallback once the format inside is known
+        let container_confidence = match specific {
+            Some((mime_type, confidence, source)) => {
+                add_candidate(&mut candidates, mime_type, confidence, source);
+                0.5
+            }
+            None => 0.9,
+        };
+        add_candidate(&mut candidates, container_mime, container_confidence, MimeSource::Magic);
+    }
+
+    if let Some(kind) = infer::get(content) {
+        add_candidate(&mut candidates, kind.mime_type(), 0.9, MimeSource::Magic);
+    }
+
+    if candidates.is_empty()
+        && let Ok(text) = std::str::from_utf8(content)
+    {
+        let trimmed = text.trim_start();
+
+        if (trimmed.starts_with('{') || trimmed.starts_with('['))
+            && serde_json::from_str::<serde_json::Value>(text).is_ok()
+        {
+            add_candidate(&mut candidates, JSON_MIME_TYPE, 0.8, MimeSource::Magic);
+        }
+
+        let lowercase_start = trimmed.chars().take(16).collect::<String>().to_lowercase();
+        if lowercase_start.starts_with("<!doctype html") || lowercase_start.starts_with("<html") {
+            add_candidate(&mut candidates, HTML_MIME_TYPE, 0.7, MimeSource::Magic);
+        }
+
+        if trimmed.starts_with('<') {
+            add_candidate(&mut candidates, XML_MIME_TYPE, 0.5, MimeSource::Magic);
+        }
+
+        add_candidate(&mut candidates, PLAIN_TEXT_MIME_TYPE, 0.3, MimeSource::Magic);
+    }
+
+    if let Some(filename) = filename {
+        let path = Path::new(filename);
+        let extension = path.extension().and_then(|ext| ext.to_str()).map(|s| s.to_lowercase());
+
+        if let Some(mime_type) = extension.as_deref().and_then(|ext| EXT_TO_MIME.get(ext)) {
+            add_candidate(&mut candidates, mime_type, 0.7, MimeSource::Extension);
+        } else if let Some(mime) = mime_guess::from_path(path).first() {
+            add_candidate(&mut candidates, mime.essence_str(), 0.6, MimeSource::Extension);
+        }
+    }
+
+    candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    candidates
+}
+
+/// Add a candidate unless the same MIME type was already detected from the same source.
+fn add_candidate(candidates: &mut Vec<MimeCandidate>, mime_type: &str, confidence: f32, source: MimeSource) {
+    if candidates
+        .iter()
+        .any(|candidate| candidate.mime == mime_type && candidate.source == source)
+    {
+        return;
+    }
+
+    candidates.push(MimeCandidate {
+        mime: mime_type.to_string(),
+        confidence,
+        source,
+    });
+}
+
+/// Check whether an extension-based and a content-based MIME type describe the same input.
+///
+/// Content sniffing can only tell generic text apart from JSON and XML, so any text-based
+/// extension is accepted for text content.
+fn mime_types_compatible(extension_mime: &str, content_mime: &str) -> bool {
+    extension_mime == content_mime || (is_text_mime(extension_mime) && is_text_mime(content_mime))
+}
+
+fn is_text_mime(mime_type: &str) -> bool {
+    mime_type.starts_with("text/")
+        || matches!(
+            mime_type,
+            JSON_MIME_TYPE | YAML_MIME_TYPE | TOML_MIME_TYPE | XML_MIME_TYPE | SVG_MIME_TYPE | EML_MIME_TYPE
+        )
+}
+
+/// Signature of OLE compound files, used by legacy Office formats (DOC, XLS, PPT, MSG).
+const OLE_MAGIC: &[u8] = &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+/// Signature of a ZIP local file header, used by Office Open XML formats.
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+
+/// Identify an Office document from its container.
+///
+/// Legacy Office formats are OLE compound files and are told apart by the streams at
+/// the root of the file. Office Open XML formats are ZIP archives and are told apart by
+/// the main part declared in `[Content_Types].xml`.
+fn detect_office_container_format(content: &[u8]) -> Option<&'static str> {
+    if content.starts_with(OLE_MAGIC) {
+        return detect_ole_format(content);
+    }
+    if content.starts_with(ZIP_MAGIC) {
+        return detect_office_format_from_content_types(content);
+    }
+    None
+}
+
+/// Streams at the root of an OLE compound file that identify the document format.
+///
+/// Checked in order; embedded objects live in nested storages, so they don't affect the result.
+const OLE_FORMAT_STREAMS: &[(&str, &str)] = &[
+    ("__properties_version1.0", MSG_MIME_TYPE),
+    ("WordDocument", LEGACY_WORD_MIME_TYPE),
+    ("Workbook", EXCEL_BINARY_MIME_TYPE),
+    ("Book", EXCEL_BINARY_MIME_TYPE),
+    ("PowerPoint Document", LEGACY_POWERPOINT_MIME_TYPE),
+];
+
+/// Detect the legacy Office format of an OLE compound file from its root streams.
+fn detect_ole_format(content: &[u8]) -> Option<&'static str> {
+    let root_names = ole_root_entry_names(content)?;
+    OLE_FORMAT_STREAMS
+        .iter()
+        .find(|(stream, _)| root_names.iter().any(|name| name == stream))
+        .map(|&(_, mime_type)| mime_type)
+}
+
+/// Read the names of the entries directly below the root storage of an OLE compound file.
+///
+/// Follows the directory sector chain through the FAT sectors listed in the header. Files
+/// that need the extended DIFAT (several hundred megabytes) or are truncated yield `None`.
+fn ole_root_entry_names(content: &[u8]) -> Option<Vec<String>> {
+    const DIR_ENTRY_SIZE: usize = 128;
+    const HEADER_FAT_SECTORS: usize = 109;
+    const END_OF_CHAIN: u32 = 0xFFFF_FFFE;
+    const NO_STREAM: u32 = 0xFFFF_FFFF;
+    const MAX_DIR_SECTORS: usize = 1024;
+
+    let sector_shift = read_u16_le(content, 0x1E)?;
+    if !(7..=16).contains(&sector_shift) {
+        return None;
+    }
+    let sector_size = 1usize << sector_shift;
+    // The header occupies the first sector
+    let sector_offset = |sector: u32| (sector as usize).checked_add(1)?.checked_mul(sector_size);
+
+    let next_sector = |sector: u32| {
+        let entries_per_fat_sector = sector_size / 4;
+        let fat_index = sector as usize / entries_per_fat_sector;
+        if fat_index >= HEADER_FAT_SECTORS {
+            return None;
+        }
+        let fat_sector = read_u32_le(content, 0x4C + fat_index * 4)?;
+        read_u32_le(
+            content,
+            sector_offset(fat_sector)? + (sector as usize % entries_per_fat_sector) * 4,
+        )
+    };
+
+    let mut directory = Vec::new();
+    let mut sector = read_u32_le(content, 0x30)?;
+    while sector != END_OF_CHAIN {
+        if directory.len() >= MAX_DIR_SECTORS * sector_size {
+            return None;
+        }
+        let start = sector_offset(sector)?;
+        directory.extend_from_slice(content.get(start..start.checked_add(sector_size)?)?);
+        sector = next_sector(sector)?;
+    }
+
+    let entry = |id: u32| {
+        let start = (id as usize).checked_mul(DIR_ENTRY_SIZE)?;
+        directory.get(start..start + DIR_ENTRY_SIZE)
+    };
+
+    // Children of a storage form a binary tree linked through the left and right sibling ids
+    let mut pending = vec![read_u32_le(entry(0)?, 0x4C)?];
+    let mut names = Vec::new();
+    while let Some(id) = pending.pop() {
+        if id == NO_STREAM {
+            continue;
+        }
+        // A well-formed tree visits each entry once; anything more is a cycle
+        if names.len() >= directory.len() / DIR_ENTRY_SIZE {
+            return None;
+        }
+        let child = entry(id)?;
+        let name_len = usize::from(read_u16_le(child, 0x40)?).min(64);
+        let name_units: Vec<u16> = child[..name_len]
+            .chunks_exact(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .take_while(|&unit| unit != 0)
+            .collect();
+        names.push(String::from_utf16_lossy(&name_units));
+        pending.push(read_u32_le(child, 0x44)?);
+        pending.push(read_u32_le(child, 0x48)?);
+    }
+
+    Some(names)
+}
+
+fn read_u16_le(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        bytes.get(offset..offset.checked_add(2)?)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32_le(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        bytes.get(offset..offset.checked_add(4)?)?.try_into().ok()?,
+    ))
+}
+
+/// Main part content types in `[Content_Types].xml` that identify an Office Open XML format.
+#[cfg(any(feature = "office", feature = "archives"))]
+const OOXML_MAIN_CONTENT_TYPES: &[(&str, &str)] = &[
+    (
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml",
+        DOCX_MIME_TYPE,
+    ),
+    (
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml",
+        EXCEL_MIME_TYPE,
+    ),
+    (
+        "application/vnd.ms-excel.sheet.macroEnabled.main+xml",
+        EXCEL_MACRO_MIME_TYPE,
+    ),
+    (
+        "application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml",
+        POWER_POINT_MIME_TYPE,
+    ),
+    (
+        "application/vnd.ms-powerpoint.presentation.macroEnabled.main+xml",
+        "application/vnd.ms-powerpoint.presentation.macroEnabled.12",
+    ),
+];
+
+/// Detect Office Open XML format from the main part declared in `[Content_Types].xml`.
+///
+/// Requires a ZIP reader, so without the `office` or `archives` feature this always
+/// yields `None` and detection falls back to [`detect_office_format_from_zip`].
+#[cfg(any(feature = "office", feature = "archives"))]
+fn detect_office_format_from_content_types(content: &[u8]) -> Option<&'static str> {
+    use std::io::Read;
+
+    const MAX_CONTENT_TYPES_BYTES: u64 = 1024 * 1024;
+
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(content)).ok()?;
+    let mut content_types = String::new();
+    archive
+        .by_name("[Content_Types].xml")
+        .ok()?
+        .take(MAX_CONTENT_TYPES_BYTES)
+        .read_to_string(&mut content_types)
+        .ok()?;
+
+    OOXML_MAIN_CONTENT_TYPES
+        .iter()
+        .find(|(content_type, _)| content_types.contains(content_type))
+        .map(|&(_, mime_type)| mime_type)
+}
+
+#[cfg(not(any(feature = "office", feature = "archives")))]
+fn detect_office_format_from_content_types(_content: &[u8]) -> Option<&'static str> {
+    None
+}
+
 /// Detect Office Open XML format from ZIP content by scanning for marker files.
 ///
 /// Office Open XML formats (DOCX, XLSX, PPTX) are ZIP archives containing specific
@@ -730,4 +1190,125 @@ mod tests {
         let mime = detect_mime_type_from_bytes(plain_zip_bytes).unwrap();
         assert_eq!(mime, "application/zip", "Plain ZIP should remain as application/zip");
     }
+
+    fn ooxml_zip(main_content_type: &str, main_part: &str) -> Vec<u8> {
+        use std::io::Write;
+
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        let mut zip = zip::ZipWriter::new(&mut buffer);
+        let options = zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        zip.start_file("[Content_Types].xml", options).unwrap();
+        write!(
+            zip,
+            r#"<?xml version="1.0"?><Types><Override PartName="/{}" ContentType="{}"/></Types>"#,
+            main_part, main_content_type
+        )
+        .unwrap();
+        zip.start_file(main_part, options).unwrap();
+        zip.write_all(b"<document/>").unwrap();
+        zip.finish().unwrap();
+        buffer.into_inner()
+    }
+
+    #[test]
+    fn test_detect_mime_candidates_ranks_docx_above_zip() {
+        let docx = ooxml_zip(
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml",
+            "word/document.xml",
+        );
+
+        let candidates = detect_mime_candidates(&docx, None);
+        let position = |mime_type: &str| candidates.iter().position(|candidate| candidate.mime == mime_type);
+
+        let docx_rank = position(DOCX_MIME_TYPE).expect("docx candidate");
+        let zip_rank = position(ZIP_MIME_TYPE).expect("generic zip candidate");
+        assert!(docx_rank < zip_rank, "{:?}", candidates);
+        assert!(candidates[docx_rank].confidence > candidates[zip_rank].confidence);
+        assert_eq!(candidates[zip_rank].source, MimeSource::Magic);
+        assert!(
+            candidates
+                .windows(2)
+                .all(|pair| pair[0].confidence >= pair[1].confidence)
+        );
+    }
+
+    #[test]
+    fn test_detect_mime_candidates_includes_extension() {
+        let candidates = detect_mime_candidates(b"# Title\n\nSome text.", Some("notes.md"));
+
+        assert_eq!(candidates[0].mime, MARKDOWN_MIME_TYPE);
+        assert_eq!(candidates[0].source, MimeSource::Extension);
+        assert!(
+            candidates
+                .iter()
+                .any(|candidate| candidate.mime == PLAIN_TEXT_MIME_TYPE && candidate.source == MimeSource::Magic)
+        );
+
+        let plain_zip = detect_mime_candidates(b"PK\x03\x04 not an office file", None);
+        assert_eq!(plain_zip[0].mime, ZIP_MIME_TYPE);
+        assert_eq!(plain_zip[0].confidence, 0.9);
+    }
+
+    #[test]
+    fn test_detect_mime_pdf_with_txt_extension_prefer_content() {
+        let detection = detect_mime(
+            "upload.txt",
+            b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n",
+            MimePolicy::PreferContent,
+        )
+        .unwrap();
+
+        assert_eq!(detection.mime_type, PDF_MIME_TYPE);
+        assert!(detection.mismatch);
+    }
+
+    #[test]
+    fn test_detect_mime_pdf_with_txt_extension_prefer_extension() {
+        let detection = detect_mime(
+            "upload.txt",
+            b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n",
+            MimePolicy::PreferExtension,
+        )
+        .unwrap();
+
+        assert_eq!(detection.mime_type, PLAIN_TEXT_MIME_TYPE);
+        assert!(detection.mismatch);
+    }
+
+    #[test]
+    fn test_detect_mime_pdf_with_txt_extension_strict() {
+        let result = detect_mime("upload.txt", b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n", MimePolicy::Strict);
+
+        assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+    }
+
+    #[test]
+    fn test_detect_mime_matching_sources() {
+        let detection = detect_mime("report.pdf", b"%PDF-1.4\n", MimePolicy::Strict).unwrap();
+        assert_eq!(detection.mime_type, PDF_MIME_TYPE);
+        assert!(!detection.mismatch);
+
+        let detection = detect_mime("notes.md", b"# Heading\n\nBody", MimePolicy::Strict).unwrap();
+        assert_eq!(detection.mime_type, MARKDOWN_MIME_TYPE);
+        assert!(!detection.mismatch);
+    }
+
+    #[test]
+    fn test_detect_mime_without_extension_uses_content() {
+        let detection = detect_mime("upload", b"%PDF-1.4\n", MimePolicy::Strict).unwrap();
+
+        assert_eq!(detection.mime_type, PDF_MIME_TYPE);
+        assert!(!detection.mismatch);
+    }
+
+    #[test]
+    fn test_mime_policy_from_str() {
+        assert_eq!("prefer_content".parse::<MimePolicy>(), Ok(MimePolicy::PreferContent));
+        assert_eq!(
+            "prefer-extension".parse::<MimePolicy>(),
+            Ok(MimePolicy::PreferExtension)
+        );
+        assert_eq!("STRICT".parse::<MimePolicy>(), Ok(MimePolicy::Strict));
+        assert!("loose".parse::<MimePolicy>().is_err());
+    }
 }
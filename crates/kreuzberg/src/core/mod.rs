@@ -40,12 +40,14 @@ pub mod io;
 pub mod mime;
 pub mod pipeline;
 pub mod server_config;
+pub mod version;
 
 #[cfg(feature = "pdf")]
 pub use config::HierarchyConfig;
 pub use config::{
     ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig, ImageExtractionConfig,
-    LanguageDetectionConfig, OcrConfig, OutputFormat, PageConfig, PostProcessorConfig, TokenReductionConfig,
+    LanguageDetectionConfig, OcrConfig, OutputFormat, PageConfig, PostProcessorConfig, PreparedConfig,
+    TokenReductionConfig,
 };
 pub use config_validation::{
     validate_binarization_method, validate_chunking_params, validate_confidence, validate_dpi, validate_language_code,
@@ -61,4 +63,4 @@ pub use batch_optimizations::{BatchProcessor, BatchProcessorConfig};
 pub use config::PdfConfig;
 #[cfg(feature = "tokio-runtime")]
 pub use extractor::{batch_extract_bytes, batch_extract_file};
-pub use extractor::{extract_bytes, extract_file};
+pub use extractor::{extract_bytes, extract_bytes_with_mime, extract_file, extract_file_with_prepared};
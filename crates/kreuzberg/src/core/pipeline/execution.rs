@@ -5,7 +5,7 @@
 
 use crate::core::config::ExtractionConfig;
 use crate::plugins::ProcessingStage;
-use crate::types::ExtractionResult;
+use crate::types::{ExtractionResult, ExtractionWarning, ExtractionWarningKind};
 use crate::{KreuzbergError, Result};
 use std::borrow::Cow;
 
@@ -69,7 +69,10 @@ fn should_processor_run(pp_config: &Option<&crate::core::config::PostProcessorCo
 }
 
 /// Execute all registered validators.
-pub(super) async fn execute_validators(result: &ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+///
+/// Warnings reported by passing validators are appended to the result's warnings as
+/// [`ExtractionWarningKind::Validation`](crate::types::ExtractionWarningKind::Validation).
+pub(super) async fn execute_validators(result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
     let validator_registry = crate::plugins::registry::get_validator_registry();
     let validators = {
         let registry = validator_registry
@@ -78,11 +81,22 @@ pub(super) async fn execute_validators(result: &ExtractionResult, config: &Extra
         registry.get_all()
     };
 
-    if !validators.is_empty() {
-        for validator in validators {
-            if validator.should_validate(result, config) {
-                validator.validate(result, config).await?;
+    for validator in validators {
+        if validator.should_validate(result, config) {
+            let outcome = validator.validate_with_outcome(result, config).await?;
+            if !outcome.passed {
+                return Err(KreuzbergError::validation(format!(
+                    "Validator '{}' failed: {}",
+                    validator.name(),
+                    outcome.warnings.join("; ")
+                )));
             }
+            result.warnings.extend(
+                outcome
+                    .warnings
+                    .into_iter()
+                    .map(|warning| ExtractionWarning::new(ExtractionWarningKind::Validation, warning)),
+            );
         }
     }
 
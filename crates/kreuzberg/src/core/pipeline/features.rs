@@ -8,6 +8,46 @@ use crate::core::config::ExtractionConfig;
 use crate::types::ExtractionResult;
 use std::borrow::Cow;
 
+/// Normalize whitespace in the content if configured.
+///
+/// Runs before chunking so chunks see the normalized text. Page boundaries are
+/// remapped to the normalized content; if they cannot be, they are dropped rather
+/// than left pointing at the wrong bytes.
+pub(super) fn execute_whitespace_normalization(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    if !config.normalize_whitespace {
+        return;
+    }
+
+    let boundaries = result
+        .metadata
+        .pages
+        .as_mut()
+        .and_then(|pages| pages.boundaries.as_mut());
+    match boundaries {
+        Some(boundaries) => {
+            match crate::text::whitespace::normalize_text_with_boundaries(&result.content, boundaries) {
+                Some((content, remapped)) => {
+                    result.content = content;
+                    *boundaries = remapped;
+                }
+                None => {
+                    result.content = crate::text::normalize_text(&result.content);
+                    if let Some(pages) = result.metadata.pages.as_mut() {
+                        pages.boundaries = None;
+                    }
+                }
+            }
+        }
+        None => result.content = crate::text::normalize_text(&result.content),
+    }
+
+    if let Some(ref mut pages) = result.pages {
+        for page in pages.iter_mut() {
+            page.content = crate::text::normalize_text(&page.content);
+        }
+    }
+}
+
 /// Execute chunking if configured.
 pub(super) fn execute_chunking(result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
     #[cfg(feature = "chunking")]
@@ -90,8 +130,16 @@ pub(super) fn execute_language_detection(result: &mut ExtractionResult, config:
         }
     }
 
+    #[cfg(feature = "language-detection")]
+    if config.detect_language
+        && result.metadata.language.is_none()
+        && let Some(guess) = crate::language_detection::detect_language(&result.content)
+    {
+        result.metadata.language = Some(guess.code);
+    }
+
     #[cfg(not(feature = "language-detection"))]
-    if config.language_detection.is_some() {
+    if config.language_detection.is_some() || config.detect_language {
         result.metadata.additional.insert(
             Cow::Borrowed("language_detection_error"),
             serde_json::Value::String("Language detection feature not enabled".to_string()),
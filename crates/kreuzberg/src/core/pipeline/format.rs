@@ -135,7 +135,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -158,8 +162,12 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: Some(DjotContent {
                 plain_text: "Hello World".to_string(),
                 blocks: vec![FormattedBlock {
@@ -201,7 +209,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -223,7 +235,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -246,7 +262,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -269,7 +289,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -299,7 +323,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -322,6 +350,7 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |".to_string(),
             page_number: 1,
+            caption: None,
         };
 
         let mut result = ExtractionResult {
@@ -332,7 +361,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -380,8 +413,12 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: Some(djot_content),
         };
 
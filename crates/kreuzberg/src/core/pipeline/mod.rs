@@ -20,7 +20,7 @@ use crate::core::config::ExtractionConfig;
 use crate::types::ExtractionResult;
 
 use execution::{execute_processors, execute_validators};
-use features::{execute_chunking, execute_language_detection};
+use features::{execute_chunking, execute_language_detection, execute_whitespace_normalization};
 use initialization::{get_processors_from_cache, initialize_features, initialize_processor_cache};
 
 /// Run the post-processing pipeline on an extraction result.
@@ -28,8 +28,9 @@ use initialization::{get_processors_from_cache, initialize_features, initialize_
 /// Executes post-processing in the following order:
 /// 1. Post-Processors - Execute by stage (Early, Middle, Late) to modify/enhance the result
 /// 2. Quality Processing - Text cleaning and quality scoring
-/// 3. Chunking - Text splitting if enabled
-/// 4. Validators - Run validation hooks on the processed result (can fail fast)
+/// 3. Whitespace Normalization - If `normalize_whitespace` is enabled
+/// 4. Chunking - Text splitting if enabled
+/// 5. Validators - Run validation hooks on the processed result (can fail fast or attach warnings)
 ///
 /// # Arguments
 ///
@@ -73,9 +74,10 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
         .await?;
     }
 
+    execute_whitespace_normalization(&mut result, config);
     execute_chunking(&mut result, config)?;
     execute_language_detection(&mut result, config)?;
-    execute_validators(&result, config).await?;
+    execute_validators(&mut result, config).await?;
 
     // Transform to element-based output if requested
     if config.result_format == crate::types::OutputFormat::ElementBased {
@@ -110,6 +112,7 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
 /// This function is only available when the `tokio-runtime` feature is disabled.
 /// It handles:
 /// - Quality processing (if enabled)
+/// - Whitespace normalization (if enabled)
 /// - Chunking (if enabled)
 /// - Language detection (if enabled)
 ///
@@ -118,6 +121,7 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
 /// - Async validators
 #[cfg(not(feature = "tokio-runtime"))]
 pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    execute_whitespace_normalization(&mut result, config);
     execute_chunking(&mut result, config)?;
     execute_language_detection(&mut result, config)?;
 
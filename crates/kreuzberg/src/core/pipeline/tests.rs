@@ -11,6 +11,7 @@ const VALIDATION_MARKER_KEY: &str = "registry_validation_marker";
 const QUALITY_VALIDATION_MARKER: &str = "quality_validation_test";
 const POSTPROCESSOR_VALIDATION_MARKER: &str = "postprocessor_validation_test";
 const ORDER_VALIDATION_MARKER: &str = "order_validation_test";
+const WARNING_VALIDATION_MARKER: &str = "warning_validation_test";
 
 lazy_static! {
     static ref REGISTRY_TEST_GUARD: std::sync::Mutex<()> = std::sync::Mutex::new(());
@@ -26,6 +27,10 @@ async fn test_run_pipeline_basic() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -40,6 +45,68 @@ async fn test_run_pipeline_basic() {
     assert_eq!(processed.content, "test");
 }
 
+#[tokio::test]
+#[cfg(feature = "language-detection")]
+async fn test_pipeline_detect_language_populates_metadata() {
+    let result = ExtractionResult {
+        content: "The quarterly report shows steady growth across all of our regional offices.".to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+    };
+    let config = ExtractionConfig {
+        detect_language: true,
+        ..Default::default()
+    };
+
+    let processed = run_pipeline(result, &config).await.unwrap();
+    assert_eq!(processed.metadata.language.as_deref(), Some("eng"));
+}
+
+#[tokio::test]
+async fn test_pipeline_normalize_whitespace() {
+    let result = ExtractionResult {
+        content: "A hyphen-\nated\u{a0}\u{a0}word\r\n\r\n\r\nSecond paragraph".to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+    };
+    let config = ExtractionConfig {
+        normalize_whitespace: true,
+        postprocessor: Some(crate::core::config::PostProcessorConfig {
+            enabled: false,
+            enabled_processors: None,
+            disabled_processors: None,
+            enabled_set: None,
+            disabled_set: None,
+        }),
+        ..Default::default()
+    };
+
+    let processed = run_pipeline(result, &config).await.unwrap();
+    assert_eq!(processed.content, "A hyphenated word\n\nSecond paragraph");
+}
+
 #[tokio::test]
 #[cfg(feature = "quality")]
 async fn test_pipeline_with_quality_processing() {
@@ -51,6 +118,10 @@ async fn test_pipeline_with_quality_processing() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -74,6 +145,10 @@ async fn test_pipeline_without_quality_processing() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -98,6 +173,10 @@ async fn test_pipeline_with_chunking() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -130,6 +209,10 @@ async fn test_pipeline_without_chunking() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -162,6 +245,10 @@ async fn test_pipeline_preserves_metadata() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -186,6 +273,7 @@ async fn test_pipeline_preserves_tables() {
         cells: vec![vec!["A".to_string(), "B".to_string()]],
         markdown: "| A | B |".to_string(),
         page_number: 0,
+        caption: None,
     };
 
     let result = ExtractionResult {
@@ -196,6 +284,10 @@ async fn test_pipeline_preserves_tables() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -228,6 +320,10 @@ async fn test_pipeline_empty_content() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -251,6 +347,10 @@ async fn test_pipeline_with_all_features() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -306,6 +406,10 @@ Natural language processing enables computers to understand human language.
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -353,6 +457,10 @@ async fn test_pipeline_without_keyword_config() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -391,6 +499,10 @@ async fn test_pipeline_keyword_extraction_short_content() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -528,6 +640,10 @@ async fn test_postprocessor_runs_before_validator() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -625,6 +741,10 @@ async fn test_quality_processing_runs_before_validator() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -823,6 +943,10 @@ async fn test_multiple_postprocessors_run_before_validator() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
         djot_content: None,
         pages: None,
         elements: None,
@@ -840,6 +964,106 @@ async fn test_multiple_postprocessors_run_before_validator() {
     assert!(processed.is_ok(), "All processors should run before validator");
 }
 
+#[tokio::test]
+async fn test_validator_warnings_propagate_to_result() {
+    let _guard = REGISTRY_TEST_GUARD.lock().unwrap();
+    use crate::plugins::{Plugin, ValidationOutcome, Validator};
+    use crate::types::{ExtractionWarning, ExtractionWarningKind};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    struct DensityValidator;
+    impl Plugin for DensityValidator {
+        fn name(&self) -> &str {
+            "density-validator"
+        }
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Validator for DensityValidator {
+        async fn validate(&self, _result: &ExtractionResult, _config: &ExtractionConfig) -> Result<()> {
+            Ok(())
+        }
+
+        async fn validate_with_outcome(
+            &self,
+            result: &ExtractionResult,
+            _config: &ExtractionConfig,
+        ) -> Result<ValidationOutcome> {
+            let marked = result
+                .metadata
+                .additional
+                .get(VALIDATION_MARKER_KEY)
+                .and_then(|v| v.as_str())
+                == Some(WARNING_VALIDATION_MARKER);
+
+            if !marked {
+                return Ok(ValidationOutcome::pass());
+            }
+
+            Ok(ValidationOutcome::pass_with_warnings(vec![
+                "low text density, likely scanned".to_string(),
+            ]))
+        }
+    }
+
+    let val_registry = crate::plugins::registry::get_validator_registry();
+    {
+        let mut registry = val_registry.write().unwrap();
+        registry.register(Arc::new(DensityValidator)).unwrap();
+    }
+
+    let mut result = ExtractionResult {
+        content: "x".to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
+        layout: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+    };
+    result.metadata.additional.insert(
+        Cow::Borrowed(VALIDATION_MARKER_KEY),
+        serde_json::json!(WARNING_VALIDATION_MARKER),
+    );
+
+    let config = ExtractionConfig::default();
+
+    drop(_guard);
+
+    let processed = run_pipeline(result, &config).await;
+
+    {
+        let mut registry = val_registry.write().unwrap();
+        registry.remove("density-validator").unwrap();
+    }
+
+    let processed = processed.expect("Validator with warnings should still pass");
+    assert_eq!(
+        processed.warnings,
+        vec![ExtractionWarning::new(
+            ExtractionWarningKind::Validation,
+            "low text density, likely scanned"
+        )]
+    );
+}
+
 #[tokio::test]
 async fn test_run_pipeline_with_output_format_plain() {
     let result = ExtractionResult {
@@ -850,7 +1074,11 @@ async fn test_run_pipeline_with_output_format_plain() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -876,8 +1104,12 @@ async fn test_run_pipeline_with_output_format_djot() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
         elements: None,
+        layout: None,
         djot_content: Some(DjotContent {
             plain_text: "test content".to_string(),
             blocks: vec![FormattedBlock {
@@ -923,7 +1155,11 @@ async fn test_run_pipeline_with_output_format_html() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -953,8 +1189,12 @@ async fn test_run_pipeline_applies_output_format_last() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
         elements: None,
+        layout: None,
         djot_content: Some(DjotContent {
             plain_text: "test".to_string(),
             blocks: vec![],
@@ -0,0 +1,81 @@
+//! Library version and compatibility checks for language bindings.
+//!
+//! Bindings are built against the core library but may be upgraded separately, for
+//! example when a package manager updates the binding but not the native library it
+//! loads. [`check_version_compatibility`] lets a binding detect that at startup
+//! instead of failing later with confusing errors.
+
+use thiserror::Error;
+
+/// Version of the core library, as published on crates.io.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The core library and a binding have incompatible versions.
+///
+/// Versions are compatible when their semver major versions are equal.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error(
+    "kreuzberg binding {binding_version} is incompatible with core library {core_version} (major versions differ); \
+     install matching versions of the binding and the native library"
+)]
+pub struct VersionMismatch {
+    /// Version of the core library
+    pub core_version: String,
+    /// Version of the binding
+    pub binding_version: String,
+}
+
+/// Check that a binding version is compatible with this core library.
+///
+/// Fails when the semver major versions differ or either version cannot be parsed.
+pub fn check_version_compatibility(binding_version: &str) -> Result<(), VersionMismatch> {
+    match (major_version(VERSION), major_version(binding_version)) {
+        (Some(core), Some(binding)) if core == binding => Ok(()),
+        _ => Err(VersionMismatch {
+            core_version: VERSION.to_string(),
+            binding_version: binding_version.to_string(),
+        }),
+    }
+}
+
+fn major_version(version: &str) -> Option<u64> {
+    let version = version.trim().trim_start_matches('v');
+    version.split(['.', '-', '+']).next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_build_is_compatible() {
+        assert_eq!(check_version_compatibility(VERSION), Ok(()));
+    }
+
+    #[test]
+    fn test_minor_and_prerelease_differences_are_compatible() {
+        let major = major_version(VERSION).unwrap();
+        assert!(check_version_compatibility(&format!("{}.999.0", major)).is_ok());
+        assert!(check_version_compatibility(&format!("v{}.0.0-rc.1", major)).is_ok());
+    }
+
+    #[test]
+    fn test_major_mismatch_lists_both_versions() {
+        let major = major_version(VERSION).unwrap();
+        let binding = format!("{}.0.0", major + 1);
+
+        let mismatch = check_version_compatibility(&binding).unwrap_err();
+        assert_eq!(mismatch.core_version, VERSION);
+        assert_eq!(mismatch.binding_version, binding);
+
+        let message = mismatch.to_string();
+        assert!(message.contains(VERSION));
+        assert!(message.contains(&binding));
+        assert!(message.contains("major versions differ"));
+    }
+
+    #[test]
+    fn test_unparseable_version_is_incompatible() {
+        assert!(check_version_compatibility("unknown").is_err());
+    }
+}
@@ -530,10 +530,7 @@ pub fn generate_embeddings_for_chunks(
 
     for (chunk, mut embedding) in chunks.iter_mut().zip(embeddings_result.into_iter()) {
         if config.normalize {
-            let magnitude: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
-            if magnitude > 0.0 {
-                embedding.iter_mut().for_each(|x| *x /= magnitude);
-            }
+            l2_normalize(&mut embedding);
         }
 
         chunk.embedding = Some(embedding);
@@ -542,6 +539,304 @@ pub fn generate_embeddings_for_chunks(
     Ok(())
 }
 
+/// Generate a single embedding vector for a text using a named preset.
+///
+/// The preset determines the model and the maximum input length. Texts up to the
+/// preset's `chunk_size` (in characters) are embedded in one pass. Longer texts are
+/// split into windows of `chunk_size` characters overlapping by the preset's
+/// `overlap`, each window is embedded, and the window vectors are mean-pooled
+/// (element-wise average, every window weighted equally). The pooled vector is
+/// L2-normalized and has the preset's `dimensions` entries.
+///
+/// # Arguments
+///
+/// * `text` - Text to embed
+/// * `preset` - Preset name, one of [`list_presets`]
+///
+/// # Returns
+///
+/// The embedding vector, or an error if the text is empty, the preset is unknown,
+/// or model initialization or inference fails.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use kreuzberg::generate_embeddings;
+///
+/// let embedding = generate_embeddings("Kreuzberg extracts text from documents.", "fast")?;
+/// assert_eq!(embedding.len(), 384);
+/// ```
+#[cfg(feature = "embeddings")]
+pub fn generate_embeddings(text: &str, preset: &str) -> crate::Result<Vec<f32>> {
+    if text.trim().is_empty() {
+        return Err(crate::KreuzbergError::validation(
+            "Cannot generate embeddings for empty text",
+        ));
+    }
+
+    let preset = get_preset(preset).ok_or_else(|| crate::KreuzbergError::Plugin {
+        message: format!("Unknown embedding preset: {}", preset),
+        plugin_name: "embeddings".to_string(),
+    })?;
+
+    let windows = split_into_windows(text, preset.chunk_size, preset.overlap);
+    let model = get_or_init_model(preset.model.clone(), None)?;
+
+    let window_embeddings = {
+        let locked_model = model.lock().map_err(|e| crate::KreuzbergError::Plugin {
+            message: format!("Failed to acquire model lock: {}", e),
+            plugin_name: "embeddings".to_string(),
+        })?;
+
+        #[allow(unsafe_code)]
+        let model_mut = unsafe { locked_model.get_mut() };
+
+        model_mut
+            .embed(windows, None)
+            .map_err(|e| crate::KreuzbergError::Plugin {
+                message: format!("Failed to generate embeddings: {}", e),
+                plugin_name: "embeddings".to_string(),
+            })?
+    };
+
+    let mut embedding = mean_pool(&window_embeddings);
+    l2_normalize(&mut embedding);
+
+    Ok(embedding)
+}
+
+/// A chunk of extracted text together with its embedding.
+#[cfg(feature = "embeddings")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddedChunk {
+    /// Text of the chunk
+    pub text: String,
+    /// Embedding vector with the preset's dimensions
+    pub embedding: Vec<f32>,
+    /// First page the chunk spans (1-indexed), when page tracking is enabled
+    pub page: Option<usize>,
+    /// Byte offset of the chunk in the extracted content
+    pub offset: usize,
+}
+
+/// Extract a document, chunk its content and embed every chunk using a named preset.
+///
+/// Chunking follows `config.chunking` when it is set, keeping the chosen
+/// [`ChunkerType`](crate::ChunkerType) and chunk sizes; otherwise the preset's `chunk_size`
+/// and `overlap` are used. The chunks are then embedded in batches with the preset's model,
+/// so each chunk is tokenized once. The model of any `config.chunking.embedding` is replaced
+/// by the preset, its other settings (batch size, normalization, cache directory) are kept.
+///
+/// # Arguments
+///
+/// * `path` - Path to the document
+/// * `config` - Extraction configuration
+/// * `preset` - Preset name, one of [`list_presets`]
+///
+/// # Returns
+///
+/// One [`EmbeddedChunk`] per chunk, in document order, or an error if the preset is
+/// unknown, extraction or chunking fails, or model initialization or inference fails.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use kreuzberg::{ExtractionConfig, extract_and_embed};
+///
+/// let chunks = extract_and_embed("document.pdf", &ExtractionConfig::default(), "fast").await?;
+/// for chunk in chunks {
+///     println!("{} bytes in: {} dims", chunk.offset, chunk.embedding.len());
+/// }
+/// ```
+#[cfg(feature = "embeddings")]
+pub async fn extract_and_embed(
+    path: impl AsRef<std::path::Path>,
+    config: &crate::ExtractionConfig,
+    preset: &str,
+) -> crate::Result<Vec<EmbeddedChunk>> {
+    let (config, embedding_config) = embedding_extraction_config(config, preset)?;
+    let result = crate::extract_file(path, None, &config).await?;
+    embed_extracted_chunks(result, &embedding_config)
+}
+
+/// Synchronous variant of [`extract_and_embed`] for documents held in memory.
+#[cfg(feature = "embeddings")]
+pub fn extract_bytes_and_embed_sync(
+    content: &[u8],
+    mime_type: &str,
+    config: &crate::ExtractionConfig,
+    preset: &str,
+) -> crate::Result<Vec<EmbeddedChunk>> {
+    let (config, embedding_config) = embedding_extraction_config(config, preset)?;
+    let result = crate::extract_bytes_sync(content, mime_type, &config)?;
+    embed_extracted_chunks(result, &embedding_config)
+}
+
+/// Split an extraction config into one that only chunks, and the embedding config for the preset.
+///
+/// Embeddings are generated after extraction instead of in the pipeline, which records
+/// embedding failures in the result metadata rather than returning them.
+#[cfg(feature = "embeddings")]
+fn embedding_extraction_config(
+    config: &crate::ExtractionConfig,
+    preset: &str,
+) -> crate::Result<(crate::ExtractionConfig, crate::core::config::EmbeddingConfig)> {
+    let preset = get_preset(preset).ok_or_else(|| crate::KreuzbergError::Plugin {
+        message: format!("Unknown embedding preset: {}", preset),
+        plugin_name: "embeddings".to_string(),
+    })?;
+
+    let mut chunking = config
+        .chunking
+        .clone()
+        .unwrap_or_else(|| crate::core::config::ChunkingConfig {
+            max_characters: preset.chunk_size,
+            overlap: preset.overlap,
+            ..Default::default()
+        });
+    let mut embedding_config = chunking.embedding.take().unwrap_or_default();
+    embedding_config.model = crate::core::config::EmbeddingModelType::Preset {
+        name: preset.name.to_string(),
+    };
+
+    let config = crate::ExtractionConfig {
+        chunking: Some(chunking),
+        ..config.clone()
+    };
+
+    Ok((config, embedding_config))
+}
+
+#[cfg(feature = "embeddings")]
+fn embed_extracted_chunks(
+    result: crate::types::ExtractionResult,
+    config: &crate::core::config::EmbeddingConfig,
+) -> crate::Result<Vec<EmbeddedChunk>> {
+    if let Some(error) = result.metadata.additional.get("chunking_error") {
+        let error = error.as_str().map_or_else(|| error.to_string(), str::to_string);
+        return Err(crate::KreuzbergError::Other(format!("Chunking failed: {}", error)));
+    }
+
+    let mut chunks = result.chunks.unwrap_or_default();
+    generate_embeddings_for_chunks(&mut chunks, config)?;
+
+    chunks
+        .into_iter()
+        .map(|chunk| {
+            let embedding = chunk.embedding.ok_or_else(|| crate::KreuzbergError::Plugin {
+                message: format!("No embedding generated for chunk {}", chunk.metadata.chunk_index),
+                plugin_name: "embeddings".to_string(),
+            })?;
+
+            Ok(EmbeddedChunk {
+                text: chunk.content,
+                embedding,
+                page: chunk.metadata.first_page,
+                offset: chunk.metadata.byte_start,
+            })
+        })
+        .collect()
+}
+
+/// Compute the cosine similarity between two embedding vectors.
+///
+/// Returns a score in `[-1.0, 1.0]`. When either vector has zero magnitude the
+/// similarity is `0.0` rather than NaN.
+///
+/// # Errors
+///
+/// Returns a validation error if the vectors have different lengths.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> crate::Result<f32> {
+    if a.len() != b.len() {
+        return Err(crate::KreuzbergError::validation(format!(
+            "Embedding dimensions differ: {} vs {}",
+            a.len(),
+            b.len()
+        )));
+    }
+
+    let (dot, norm_a, norm_b) = a
+        .iter()
+        .zip(b)
+        .fold((0.0f32, 0.0f32, 0.0f32), |(dot, norm_a, norm_b), (x, y)| {
+            (dot + x * y, norm_a + x * x, norm_b + y * y)
+        });
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return Ok(0.0);
+    }
+
+    Ok((dot / (norm_a.sqrt() * norm_b.sqrt())).clamp(-1.0, 1.0))
+}
+
+/// Find the `k` corpus vectors most similar to `query` by cosine similarity.
+///
+/// Returns `(index, score)` pairs sorted by descending score. Equal scores keep
+/// their corpus order. Fewer than `k` pairs are returned for smaller corpora.
+///
+/// # Errors
+///
+/// Returns a validation error if any corpus vector differs in length from `query`.
+pub fn top_k_similar(query: &[f32], corpus: &[Vec<f32>], k: usize) -> crate::Result<Vec<(usize, f32)>> {
+    let mut scored = corpus
+        .iter()
+        .enumerate()
+        .map(|(index, vector)| cosine_similarity(query, vector).map(|score| (index, score)))
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scored.truncate(k);
+
+    Ok(scored)
+}
+
+/// Split text into windows of at most `window` characters, overlapping by `overlap`.
+#[cfg(feature = "embeddings")]
+fn split_into_windows(text: &str, window: usize, overlap: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() <= window {
+        return vec![text.to_string()];
+    }
+
+    let step = window.saturating_sub(overlap).max(1);
+    let mut windows = Vec::new();
+    let mut start = 0;
+    loop {
+        let end = (start + window).min(chars.len());
+        windows.push(chars[start..end].iter().collect());
+        if end == chars.len() {
+            break;
+        }
+        start += step;
+    }
+
+    windows
+}
+
+/// Average vectors element-wise.
+#[cfg(feature = "embeddings")]
+fn mean_pool(vectors: &[Vec<f32>]) -> Vec<f32> {
+    let mut pooled = vec![0.0; vectors.first().map_or(0, Vec::len)];
+    for vector in vectors {
+        for (sum, value) in pooled.iter_mut().zip(vector) {
+            *sum += value;
+        }
+    }
+
+    let count = vectors.len().max(1) as f32;
+    pooled.iter_mut().for_each(|sum| *sum /= count);
+    pooled
+}
+
+/// Scale a vector to unit length in place (zero vectors are left unchanged).
+#[cfg(feature = "embeddings")]
+fn l2_normalize(vector: &mut [f32]) {
+    let magnitude: f32 = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if magnitude > 0.0 {
+        vector.iter_mut().for_each(|x| *x /= magnitude);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -591,4 +886,71 @@ mod tests {
     #[cfg(feature = "embeddings")]
     #[test]
     fn test_lock_poisoning_recovery_semantics() {}
+
+    #[cfg(feature = "embeddings")]
+    #[test]
+    fn test_split_into_windows_short_text() {
+        assert_eq!(split_into_windows("short", 10, 2), vec!["short"]);
+    }
+
+    #[cfg(feature = "embeddings")]
+    #[test]
+    fn test_split_into_windows_overlap() {
+        let windows = split_into_windows("abcdefghij", 4, 1);
+        assert_eq!(windows, vec!["abcd", "defg", "ghij"]);
+    }
+
+    #[cfg(feature = "embeddings")]
+    #[test]
+    fn test_mean_pool_preserves_dimensions() {
+        let pooled = mean_pool(&[vec![1.0, 2.0, 3.0], vec![3.0, 4.0, 5.0]]);
+        assert_eq!(pooled, vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_cosine_similarity_identical_vectors() {
+        let score = cosine_similarity(&[0.3, 0.4, 0.5], &[0.3, 0.4, 0.5]).unwrap();
+        assert!((score - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cosine_similarity_orthogonal_vectors() {
+        let score = cosine_similarity(&[1.0, 0.0], &[0.0, 2.0]).unwrap();
+        assert_eq!(score, 0.0);
+    }
+
+    #[test]
+    fn test_cosine_similarity_zero_vector() {
+        let score = cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]).unwrap();
+        assert_eq!(score, 0.0);
+    }
+
+    #[test]
+    fn test_cosine_similarity_length_mismatch() {
+        let result = cosine_similarity(&[1.0, 0.0], &[1.0, 0.0, 0.0]);
+        assert!(matches!(result, Err(crate::KreuzbergError::Validation { .. })));
+    }
+
+    #[test]
+    fn test_top_k_similar_ordering() {
+        let corpus = vec![vec![0.0, 1.0], vec![1.0, 0.1], vec![1.0, 0.0], vec![-1.0, 0.0]];
+
+        let results = top_k_similar(&[1.0, 0.0], &corpus, 3).unwrap();
+
+        let indices: Vec<usize> = results.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, vec![2, 1, 0]);
+        assert!(results.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    }
+
+    #[test]
+    fn test_top_k_similar_k_larger_than_corpus() {
+        let corpus = vec![vec![1.0, 0.0]];
+        assert_eq!(top_k_similar(&[1.0, 0.0], &corpus, 5).unwrap().len(), 1);
+    }
+
+    #[cfg(feature = "embeddings")]
+    #[test]
+    fn test_generate_embeddings_rejects_unknown_preset() {
+        assert!(generate_embeddings("text", "nonexistent").is_err());
+    }
 }
@@ -19,6 +19,7 @@
 //! - `Validation` - Invalid configuration or parameters
 //! - `Ocr` - OCR processing failures
 //! - `MissingDependency` - Missing optional system dependencies
+//! - `Network` - Failures fetching remote documents
 //!
 //! # Example
 //!
@@ -64,6 +65,8 @@ pub type Result<T> = std::result::Result<T, KreuzbergError>;
 /// - `Plugin` - Plugin-specific errors
 /// - `LockPoisoned` - Mutex/RwLock poisoning (should not happen in normal operation)
 /// - `UnsupportedFormat` - Unsupported MIME type or file format
+/// - `InputTooLarge` - Input exceeds `ExtractionConfig::max_input_bytes`
+/// - `Network` - Fetching a remote document failed (connection, HTTP status, redirects)
 /// - `Other` - Catch-all for uncommon errors
 #[derive(Debug, Error)]
 pub enum KreuzbergError {
@@ -124,6 +127,16 @@ pub enum KreuzbergError {
     #[error("Unsupported format: {0}")]
     UnsupportedFormat(String),
 
+    #[error("Input too large: {size} bytes exceeds the limit of {limit} bytes")]
+    InputTooLarge { size: u64, limit: u64 },
+
+    #[error("Network error: {message}")]
+    Network {
+        message: String,
+        #[source]
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+
     #[error("{0}")]
     Other(String),
 }
@@ -207,6 +220,7 @@ impl KreuzbergError {
     error_constructor!(cache, Cache);
     error_constructor!(image_processing, ImageProcessing);
     error_constructor!(serialization, Serialization);
+    error_constructor!(network, Network);
 }
 
 #[cfg(test)]
@@ -305,6 +319,20 @@ mod tests {
         assert!(std::error::Error::source(&err).is_some());
     }
 
+    #[test]
+    fn test_network_error() {
+        let err = KreuzbergError::network("HTTP 404 Not Found");
+        assert_eq!(err.to_string(), "Network error: HTTP 404 Not Found");
+    }
+
+    #[test]
+    fn test_network_error_with_source() {
+        let source = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused");
+        let err = KreuzbergError::network_with_source("fetch failed", source);
+        assert_eq!(err.to_string(), "Network error: fetch failed");
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
     #[test]
     fn test_missing_dependency_error() {
         let err = KreuzbergError::MissingDependency("tesseract not found".to_string());
@@ -326,6 +354,18 @@ mod tests {
         assert_eq!(err.to_string(), "Unsupported format: application/unknown");
     }
 
+    #[test]
+    fn test_input_too_large_error() {
+        let err = KreuzbergError::InputTooLarge {
+            size: 2048,
+            limit: 1024,
+        };
+        assert_eq!(
+            err.to_string(),
+            "Input too large: 2048 bytes exceeds the limit of 1024 bytes"
+        );
+    }
+
     #[test]
     fn test_other_error() {
         let err = KreuzbergError::Other("unexpected error".to_string());
@@ -8,11 +8,11 @@
 //! - Adapted to use kreuzberg's existing `quick-xml` and `zip` versions
 //! - Removed file-path based APIs (we only need bytes/reader)
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::{Cursor, Read, Seek};
 
 use quick_xml::Reader;
-use quick_xml::events::Event;
+use quick_xml::events::{BytesStart, Event};
 
 // --- Types ---
 
@@ -25,6 +25,25 @@ pub struct Document {
     pub footers: Vec<HeaderFooter>,
     pub footnotes: Vec<Note>,
     pub endnotes: Vec<Note>,
+    /// Reviewer comments, populated when [`ExtractOptions::extract_comments`] is set.
+    pub comments: Vec<Comment>,
+    /// Body sections in document order, one per `w:sectPr`
+    pub sections: Vec<Section>,
+    /// Whether even pages have their own headers and footers (`w:evenAndOddHeaders` in settings)
+    pub even_and_odd_headers: bool,
+}
+
+/// A body section, closed by a `w:sectPr` element.
+#[derive(Debug, Clone, Default)]
+pub struct Section {
+    /// Number of body paragraphs up to and including the last paragraph of the section
+    pub paragraph_end: usize,
+    /// Header relationship ids from `w:headerReference`, by header type
+    pub headers: Vec<(HeaderFooterType, String)>,
+    /// Footer relationship ids from `w:footerReference`, by footer type
+    pub footers: Vec<(HeaderFooterType, String)>,
+    /// Whether the first page has its own header and footer (`w:titlePg`)
+    pub title_page: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -41,6 +60,40 @@ pub struct Run {
     pub bold: bool,
     pub italic: bool,
     pub underline: bool,
+    pub note_reference: Option<NoteReference>,
+    /// Tracked change this run belongs to (`w:ins`/`w:del`), if any
+    pub revision: Option<Revision>,
+}
+
+/// A tracked change wrapping one or more runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Revision {
+    pub kind: RevisionKind,
+    /// Revision id from `w:id`
+    pub id: String,
+    /// Author from `w:author`
+    pub author: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevisionKind {
+    /// Text added by the revision (`w:ins`, `w:moveTo`)
+    Insertion,
+    /// Text removed by the revision (`w:del`, `w:moveFrom`)
+    Deletion,
+}
+
+/// How tracked changes are rendered by [`Document::extract_text_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RevisionMode {
+    /// Accept all changes: keep insertions, drop deletions (default)
+    #[default]
+    Final,
+    /// Reject all changes: drop insertions, keep deletions
+    Original,
+    /// Keep both, marked with CriticMarkup: `{++inserted++}{>>author<<}` and
+    /// `{--deleted--}{>>author<<}`
+    Annotated,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -77,6 +130,8 @@ pub struct HeaderFooter {
     pub paragraphs: Vec<Paragraph>,
     pub tables: Vec<Table>,
     pub header_type: HeaderFooterType,
+    /// Relationship id sections use to reference this part, when known
+    pub relationship_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -101,6 +156,94 @@ pub enum NoteType {
     Endnote,
 }
 
+/// A `<w:footnoteReference>` or `<w:endnoteReference>` marker within a run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoteReference {
+    pub note_type: NoteType,
+    pub id: String,
+}
+
+/// A reviewer comment from `word/comments.xml`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Comment {
+    pub id: String,
+    pub author: String,
+    /// ISO 8601 timestamp from `w:date`, if present
+    pub date: Option<String>,
+    /// Comment body, paragraphs joined by newlines
+    pub text: String,
+    /// Document text between the comment's `commentRangeStart` and `commentRangeEnd` markers
+    pub anchor_text: String,
+    /// Id of the comment this one replies to (from `word/commentsExtended.xml`)
+    pub parent_id: Option<String>,
+}
+
+/// Where [`Document::extract_text_with_options`] places header and footer text.
+///
+/// Each header or footer part appears once, even when several sections show it. First-page
+/// parts are included only for sections with a distinct title page, and even-page parts only
+/// when the document enables even and odd headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeaderFooterPlacement {
+    /// Leave headers and footers out (default)
+    #[default]
+    Omit,
+    /// Headers before the paragraphs of the first section showing them, footers after them
+    Inline,
+    /// Collect headers and footers in labeled "Headers" and "Footers" sections after the body
+    Appended,
+}
+
+/// Options controlling how a document is parsed and how
+/// [`Document::extract_text_with_options`] renders text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExtractOptions {
+    /// Insert numbered markers (`[1]` for footnotes, `[E1]` for endnotes) where notes are
+    /// referenced and append the note text in labeled "Footnotes" and "Endnotes" sections.
+    pub inline_notes: bool,
+    /// Parse reviewer comments into [`Document::comments`], resolving their anchored text
+    /// and reply threads. Only honored by [`parse_document_with_options`].
+    pub extract_comments: bool,
+    /// How tracked insertions and deletions appear in the extracted text.
+    pub revision_mode: RevisionMode,
+    /// Whether and where header and footer text appears in the extracted text.
+    pub include_headers_footers: HeaderFooterPlacement,
+    /// Wrap bold runs in `**` and italic runs in `*`. Consecutive runs with the same
+    /// formatting share one pair of delimiters.
+    pub inline_markup: bool,
+}
+
+/// Header and footer parts first shown by a section.
+struct SectionHeadersFooters<'a> {
+    paragraph_end: usize,
+    headers: Vec<&'a HeaderFooter>,
+    footers: Vec<&'a HeaderFooter>,
+}
+
+/// Assigns sequential numbers to notes in the order they are first referenced.
+#[derive(Debug, Default)]
+struct NoteNumbering {
+    footnotes: Vec<String>,
+    endnotes: Vec<String>,
+}
+
+impl NoteNumbering {
+    fn marker(&mut self, reference: &NoteReference) -> String {
+        let (ids, prefix) = match reference.note_type {
+            NoteType::Footnote => (&mut self.footnotes, ""),
+            NoteType::Endnote => (&mut self.endnotes, "E"),
+        };
+        let number = match ids.iter().position(|id| id == &reference.id) {
+            Some(index) => index + 1,
+            None => {
+                ids.push(reference.id.clone());
+                ids.len()
+            }
+        };
+        format!("[{}{}]", prefix, number)
+    }
+}
+
 // --- Impls ---
 
 impl Document {
@@ -109,32 +252,35 @@ impl Document {
     }
 
     pub fn extract_text(&self) -> String {
-        let mut text = String::new();
+        self.extract_text_with_options(&ExtractOptions::default())
+    }
 
-        let mut list_index = 0;
-        for paragraph in &self.paragraphs {
-            if let (Some(_num_id), Some(_level)) = (paragraph.numbering_id, paragraph.numbering_level) {
-                let para_text = paragraph.to_text();
-                if !para_text.is_empty() {
-                    text.push_str(&para_text);
-                    text.push('\n');
-                }
-                list_index += 1;
-                let _ = list_index; // suppress unused warning
-            } else {
-                let para_text = paragraph.to_text();
-                if !para_text.is_empty() {
-                    text.push_str(&para_text);
-                    text.push('\n');
+    pub fn extract_text_with_options(&self, options: &ExtractOptions) -> String {
+        let mut text = String::new();
+        let mut numbering = options.inline_notes.then(NoteNumbering::default);
+
+        let mut paragraph_start = 0;
+        if options.include_headers_footers == HeaderFooterPlacement::Inline {
+            for section in self.header_footer_layout() {
+                let paragraph_end = section.paragraph_end.clamp(paragraph_start, self.paragraphs.len());
+                push_header_footer_text(&mut text, &section.headers);
+                for paragraph in &self.paragraphs[paragraph_start..paragraph_end] {
+                    push_paragraph_line(&mut text, paragraph, numbering.as_mut(), options);
                 }
+                push_header_footer_text(&mut text, &section.footers);
+                paragraph_start = paragraph_end;
             }
         }
 
+        for paragraph in &self.paragraphs[paragraph_start..] {
+            push_paragraph_line(&mut text, paragraph, numbering.as_mut(), options);
+        }
+
         for table in &self.tables {
             for row in &table.rows {
                 for cell in &row.cells {
                     for paragraph in &cell.paragraphs {
-                        let para_text = paragraph.to_text();
+                        let para_text = paragraph.render(numbering.as_mut(), options);
                         if !para_text.is_empty() {
                             text.push_str(&para_text);
                             text.push('\t');
@@ -146,8 +292,149 @@ impl Document {
             text.push('\n');
         }
 
+        if options.include_headers_footers == HeaderFooterPlacement::Appended {
+            let layout = self.header_footer_layout();
+            let headers: Vec<&HeaderFooter> = layout
+                .iter()
+                .flat_map(|section| section.headers.iter().copied())
+                .collect();
+            let footers: Vec<&HeaderFooter> = layout
+                .iter()
+                .flat_map(|section| section.footers.iter().copied())
+                .collect();
+            append_header_footer_section(&mut text, "Headers", &headers);
+            append_header_footer_section(&mut text, "Footers", &footers);
+        }
+
+        if let Some(numbering) = numbering {
+            append_note_section(&mut text, "Footnotes", "", &numbering.footnotes, &self.footnotes);
+            append_note_section(&mut text, "Endnotes", "E", &numbering.endnotes, &self.endnotes);
+        }
+
         text
     }
+
+    /// Headers and footers shown by each section, each part listed only for the first section
+    /// showing it.
+    ///
+    /// Without section references (documents lacking relationships or `w:sectPr`), every part
+    /// is attributed to a single section spanning the whole body.
+    fn header_footer_layout(&self) -> Vec<SectionHeadersFooters<'_>> {
+        let referenced = !self.sections.is_empty()
+            && self
+                .headers
+                .iter()
+                .chain(&self.footers)
+                .all(|part| part.relationship_id.is_some());
+        if !referenced {
+            return vec![SectionHeadersFooters {
+                paragraph_end: self.paragraphs.len(),
+                headers: self.headers.iter().collect(),
+                footers: self.footers.iter().collect(),
+            }];
+        }
+
+        let mut shown = HashSet::new();
+        self.sections
+            .iter()
+            .map(|section| SectionHeadersFooters {
+                paragraph_end: section.paragraph_end,
+                headers: self.shown_parts(&section.headers, &self.headers, section.title_page, &mut shown),
+                footers: self.shown_parts(&section.footers, &self.footers, section.title_page, &mut shown),
+            })
+            .collect()
+    }
+
+    /// Resolve the parts a section displays, in first-page, default, even order, skipping
+    /// parts already in `shown`.
+    fn shown_parts<'a>(
+        &self,
+        references: &[(HeaderFooterType, String)],
+        parts: &'a [HeaderFooter],
+        title_page: bool,
+        shown: &mut HashSet<String>,
+    ) -> Vec<&'a HeaderFooter> {
+        [
+            HeaderFooterType::First,
+            HeaderFooterType::Default,
+            HeaderFooterType::Even,
+        ]
+        .into_iter()
+        .filter(|part_type| match part_type {
+            HeaderFooterType::First => title_page,
+            HeaderFooterType::Even => self.even_and_odd_headers,
+            _ => true,
+        })
+        .filter_map(|part_type| {
+            references
+                .iter()
+                .find(|(reference_type, _)| *reference_type == part_type)
+        })
+        .filter(|(_, id)| shown.insert(id.clone()))
+        .filter_map(|(_, id)| parts.iter().find(|part| part.relationship_id.as_ref() == Some(id)))
+        .collect()
+    }
+}
+
+/// Append a rendered body paragraph followed by a newline, skipping empty paragraphs.
+fn push_paragraph_line(
+    text: &mut String,
+    paragraph: &Paragraph,
+    numbering: Option<&mut NoteNumbering>,
+    options: &ExtractOptions,
+) {
+    let para_text = paragraph.render(numbering, options);
+    if !para_text.is_empty() {
+        text.push_str(&para_text);
+        text.push('\n');
+    }
+}
+
+/// Append the text of header or footer parts, skipping parts without text.
+fn push_header_footer_text(text: &mut String, parts: &[&HeaderFooter]) {
+    for part in parts {
+        let part_text = part.extract_text();
+        if !part_text.trim().is_empty() {
+            text.push_str(&part_text);
+        }
+    }
+}
+
+/// Append a labeled section with the text of header or footer parts.
+fn append_header_footer_section(text: &mut String, label: &str, parts: &[&HeaderFooter]) {
+    if parts.iter().all(|part| part.extract_text().trim().is_empty()) {
+        return;
+    }
+
+    text.push('\n');
+    text.push_str(label);
+    text.push('\n');
+    push_header_footer_text(text, parts);
+}
+
+/// Append a labeled section listing the referenced notes in marker order.
+fn append_note_section(text: &mut String, label: &str, prefix: &str, referenced_ids: &[String], notes: &[Note]) {
+    if referenced_ids.is_empty() {
+        return;
+    }
+
+    text.push('\n');
+    text.push_str(label);
+    text.push('\n');
+
+    for (index, id) in referenced_ids.iter().enumerate() {
+        let Some(note) = notes.iter().find(|note| &note.id == id) else {
+            continue;
+        };
+        let note_text = note
+            .paragraphs
+            .iter()
+            .map(Paragraph::to_text)
+            .filter(|para_text| !para_text.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        text.push_str(&format!("[{}{}] {}\n", prefix, index + 1, note_text.trim()));
+    }
 }
 
 impl Paragraph {
@@ -161,11 +448,67 @@ impl Paragraph {
     /// (e.g. `<w:t>Hello </w:t><w:t>World</w:t>`), so runs are joined
     /// directly without adding extra separators. The parser must use
     /// `trim_text(false)` to preserve this whitespace.
+    ///
+    /// Tracked changes are accepted: deleted runs are skipped.
     pub fn to_text(&self) -> String {
+        self.render(None, &ExtractOptions::default())
+    }
+
+    /// Like [`Paragraph::to_text`], but renders note reference markers when numbering is
+    /// active and tracked changes according to `revision_mode`.
+    fn render(&self, mut numbering: Option<&mut NoteNumbering>, options: &ExtractOptions) -> String {
+        let revision_mode = options.revision_mode;
         let mut text = String::new();
+        let mut open_revision: Option<&Revision> = None;
+        let mut emphasis = EmphasisGroup::default();
+
         for run in &self.runs {
-            text.push_str(&run.text);
+            let kind = run.revision.as_ref().map(|revision| revision.kind);
+            let visible = !matches!(
+                (revision_mode, kind),
+                (RevisionMode::Final, Some(RevisionKind::Deletion))
+                    | (RevisionMode::Original, Some(RevisionKind::Insertion))
+            );
+            if !visible {
+                continue;
+            }
+
+            if revision_mode == RevisionMode::Annotated && open_revision != run.revision.as_ref() {
+                emphasis.flush(&mut text);
+                if let Some(revision) = open_revision {
+                    close_revision(&mut text, revision);
+                }
+                if let Some(revision) = run.revision.as_ref() {
+                    text.push_str(match revision.kind {
+                        RevisionKind::Insertion => "{++",
+                        RevisionKind::Deletion => "{--",
+                    });
+                }
+                open_revision = run.revision.as_ref();
+            }
+
+            // Whitespace-only runs join the current group whatever their formatting
+            if options.inline_markup
+                && !run.text.trim().is_empty()
+                && (run.bold, run.italic) != (emphasis.bold, emphasis.italic)
+            {
+                emphasis.flush(&mut text);
+                emphasis.bold = run.bold;
+                emphasis.italic = run.italic;
+            }
+
+            emphasis.text.push_str(&run.text);
+            if let (Some(reference), Some(numbering)) = (run.note_reference.as_ref(), numbering.as_deref_mut()) {
+                emphasis.flush(&mut text);
+                text.push_str(&numbering.marker(reference));
+            }
         }
+
+        emphasis.flush(&mut text);
+        if let Some(revision) = open_revision {
+            close_revision(&mut text, revision);
+        }
+
         text
     }
 
@@ -174,6 +517,56 @@ impl Paragraph {
     }
 }
 
+/// Text of consecutive runs sharing bold/italic formatting, awaiting emphasis delimiters.
+#[derive(Debug, Default)]
+struct EmphasisGroup {
+    text: String,
+    bold: bool,
+    italic: bool,
+}
+
+impl EmphasisGroup {
+    fn delimiter(&self) -> &'static str {
+        match (self.bold, self.italic) {
+            (true, true) => "***",
+            (true, false) => "**",
+            (false, true) => "*",
+            (false, false) => "",
+        }
+    }
+
+    /// Move the grouped text into `out`, keeping surrounding whitespace outside the
+    /// delimiters so the emphasis stays valid markdown.
+    fn flush(&mut self, out: &mut String) {
+        let delimiter = self.delimiter();
+        let core = self.text.trim();
+        if delimiter.is_empty() || core.is_empty() {
+            out.push_str(&self.text);
+        } else {
+            let start = self.text.len() - self.text.trim_start().len();
+            out.push_str(&self.text[..start]);
+            out.push_str(delimiter);
+            out.push_str(core);
+            out.push_str(delimiter);
+            out.push_str(&self.text[start + core.len()..]);
+        }
+        self.text.clear();
+    }
+}
+
+/// Close an annotated revision span, attributing it to its author when known.
+fn close_revision(text: &mut String, revision: &Revision) {
+    text.push_str(match revision.kind {
+        RevisionKind::Insertion => "++}",
+        RevisionKind::Deletion => "--}",
+    });
+    if let Some(author) = &revision.author {
+        text.push_str("{>>");
+        text.push_str(author);
+        text.push_str("<<}");
+    }
+}
+
 impl Run {
     pub fn new(text: String) -> Self {
         Self {
@@ -232,11 +625,12 @@ impl<R: Read + Seek> DocxParser<R> {
         Ok(Self { archive })
     }
 
-    fn parse(mut self) -> Result<Document, DocxParseError> {
+    fn parse(mut self, options: &ExtractOptions) -> Result<Document, DocxParseError> {
         let mut document = Document::new();
+        let mut comment_anchors = options.extract_comments.then(HashMap::new);
 
         let document_xml = self.read_file("word/document.xml")?;
-        self.parse_document_xml(&document_xml, &mut document)?;
+        self.parse_document_xml(&document_xml, &mut document, comment_anchors.as_mut())?;
 
         if let Ok(numbering_xml) = self.read_file("word/numbering.xml") {
             let numbering_defs = self.parse_numbering(&numbering_xml)?;
@@ -245,6 +639,10 @@ impl<R: Read + Seek> DocxParser<R> {
 
         self.parse_headers_footers(&mut document)?;
 
+        if let Ok(settings_xml) = self.read_file("word/settings.xml") {
+            document.even_and_odd_headers = parse_even_and_odd_headers(&settings_xml)?;
+        }
+
         if let Ok(footnotes_xml) = self.read_file("word/footnotes.xml") {
             self.parse_notes(&footnotes_xml, &mut document.footnotes, NoteType::Footnote)?;
         }
@@ -253,6 +651,19 @@ impl<R: Read + Seek> DocxParser<R> {
             self.parse_notes(&endnotes_xml, &mut document.endnotes, NoteType::Endnote)?;
         }
 
+        if let Some(mut anchors) = comment_anchors
+            && let Ok(comments_xml) = self.read_file("word/comments.xml")
+        {
+            let para_ids = self.parse_comments(&comments_xml, &mut document.comments)?;
+            for comment in &mut document.comments {
+                comment.anchor_text = anchors.remove(&comment.id).unwrap_or_default();
+            }
+
+            if let Ok(extended_xml) = self.read_file("word/commentsExtended.xml") {
+                self.parse_comments_extended(&extended_xml, &para_ids, &mut document.comments)?;
+            }
+        }
+
         Ok(document)
     }
 
@@ -267,7 +678,12 @@ impl<R: Read + Seek> DocxParser<R> {
         Ok(contents)
     }
 
-    fn parse_document_xml(&self, xml: &str, document: &mut Document) -> Result<(), DocxParseError> {
+    fn parse_document_xml(
+        &self,
+        xml: &str,
+        document: &mut Document,
+        mut comment_anchors: Option<&mut HashMap<String, String>>,
+    ) -> Result<(), DocxParseError> {
         let mut reader = Reader::from_str(xml);
         reader.config_mut().trim_text(false);
 
@@ -279,6 +695,10 @@ impl<R: Read + Seek> DocxParser<R> {
         let mut current_cell: Option<TableCell> = None;
         let mut in_text = false;
         let mut in_table = false;
+        let mut open_comments: Vec<String> = Vec::new();
+        let mut current_revision: Option<Revision> = None;
+        let mut current_section: Option<Section> = None;
+        let mut in_section_change = false;
 
         loop {
             match reader.read_event_into(&mut buf) {
@@ -299,11 +719,25 @@ impl<R: Read + Seek> DocxParser<R> {
                         }
                     }
                     b"w:r" => {
-                        current_run = Some(Run::default());
+                        current_run = Some(Run {
+                            revision: current_revision.clone(),
+                            ..Default::default()
+                        });
                     }
-                    b"w:t" => {
+                    b"w:t" | b"w:delText" => {
                         in_text = true;
                     }
+                    b"w:ins" | b"w:moveTo" | b"w:del" | b"w:moveFrom" => {
+                        let kind = match e.name().as_ref() {
+                            b"w:ins" | b"w:moveTo" => RevisionKind::Insertion,
+                            _ => RevisionKind::Deletion,
+                        };
+                        current_revision = Some(Revision {
+                            kind,
+                            id: attribute_value(e, b"w:id").unwrap_or_default(),
+                            author: attribute_value(e, b"w:author"),
+                        });
+                    }
                     b"w:tbl" => {
                         in_table = true;
                         current_table = Some(Table::new());
@@ -314,6 +748,12 @@ impl<R: Read + Seek> DocxParser<R> {
                     b"w:tc" => {
                         current_cell = Some(TableCell::default());
                     }
+                    b"w:sectPr" if !in_section_change => {
+                        current_section = Some(Section::default());
+                    }
+                    b"w:sectPrChange" => {
+                        in_section_change = true;
+                    }
                     b"w:b" => {
                         if let Some(ref mut run) = current_run {
                             run.bold = true;
@@ -331,18 +771,88 @@ impl<R: Read + Seek> DocxParser<R> {
                     }
                     _ => {}
                 },
+                Ok(Event::Empty(ref e)) => {
+                    match e.name().as_ref() {
+                        b"w:commentRangeStart" if comment_anchors.is_some() => {
+                            if let Some(id) = attribute_value(e, b"w:id") {
+                                open_comments.push(id);
+                            }
+                        }
+                        b"w:commentRangeEnd" => {
+                            if let Some(id) = attribute_value(e, b"w:id") {
+                                open_comments.retain(|open| open != &id);
+                            }
+                        }
+                        b"w:headerReference" | b"w:footerReference" if !in_section_change => {
+                            if let (Some(section), Some(id)) = (current_section.as_mut(), attribute_value(e, b"r:id")) {
+                                let part_type = header_footer_type(attribute_value(e, b"w:type").as_deref());
+                                if e.name().as_ref() == b"w:headerReference" {
+                                    section.headers.push((part_type, id));
+                                } else {
+                                    section.footers.push((part_type, id));
+                                }
+                            }
+                        }
+                        b"w:b" | b"w:i" | b"w:u" => {
+                            if let Some(ref mut run) = current_run {
+                                let value = attribute_value(e, b"w:val");
+                                match e.name().as_ref() {
+                                    b"w:b" => run.bold = is_on(value.as_deref()),
+                                    b"w:i" => run.italic = is_on(value.as_deref()),
+                                    // `w:u` carries the underline style, where "none" turns it off
+                                    _ => run.underline = value.as_deref() != Some("none"),
+                                }
+                            }
+                        }
+                        b"w:titlePg" if !in_section_change => {
+                            if let Some(section) = current_section.as_mut() {
+                                section.title_page = is_on(attribute_value(e, b"w:val").as_deref());
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    let note_type = match e.name().as_ref() {
+                        b"w:footnoteReference" => Some(NoteType::Footnote),
+                        b"w:endnoteReference" => Some(NoteType::Endnote),
+                        _ => None,
+                    };
+                    if let (Some(note_type), Some(run)) = (note_type, current_run.as_mut()) {
+                        for attr in e.attributes().flatten() {
+                            if attr.key.as_ref() == b"w:id" {
+                                run.note_reference = Some(NoteReference {
+                                    note_type: note_type.clone(),
+                                    id: String::from_utf8_lossy(&attr.value).to_string(),
+                                });
+                            }
+                        }
+                    }
+                }
                 Ok(Event::Text(e)) => {
                     if in_text {
                         if let Some(ref mut run) = current_run {
                             let text = e.decode()?.into_owned();
+                            let deleted = current_revision
+                                .as_ref()
+                                .is_some_and(|revision| revision.kind == RevisionKind::Deletion);
+                            if let Some(anchors) = comment_anchors.as_deref_mut()
+                                && !deleted
+                            {
+                                for id in &open_comments {
+                                    anchors.entry(id.clone()).or_default().push_str(&text);
+                                }
+                            }
                             run.text.push_str(&text);
                         }
                     }
                 }
                 Ok(Event::End(ref e)) => match e.name().as_ref() {
-                    b"w:t" => {
+                    b"w:t" | b"w:delText" => {
                         in_text = false;
                     }
+                    b"w:ins" | b"w:moveTo" | b"w:del" | b"w:moveFrom" => {
+                        current_revision = None;
+                    }
                     b"w:r" => {
                         if let Some(run) = current_run.take() {
                             if in_table {
@@ -386,6 +896,17 @@ impl<R: Read + Seek> DocxParser<R> {
                             document.tables.push(table);
                         }
                     }
+                    b"w:sectPrChange" => {
+                        in_section_change = false;
+                    }
+                    b"w:sectPr" if !in_section_change => {
+                        if let Some(mut section) = current_section.take() {
+                            // A paragraph-level sectPr sits in the pPr of the section's last paragraph
+                            section.paragraph_end =
+                                document.paragraphs.len() + usize::from(current_paragraph.is_some());
+                            document.sections.push(section);
+                        }
+                    }
                     _ => {}
                 },
                 Ok(Event::Eof) => break,
@@ -454,6 +975,63 @@ impl<R: Read + Seek> DocxParser<R> {
     }
 
     fn parse_headers_footers(&mut self, document: &mut Document) -> Result<(), DocxParseError> {
+        let relationships = match self.read_file("word/_rels/document.xml.rels") {
+            Ok(rels_xml) => parse_header_footer_relationships(&rels_xml)?,
+            Err(_) => Vec::new(),
+        };
+
+        if relationships.is_empty() {
+            return self.parse_numbered_headers_footers(document);
+        }
+
+        for (id, is_header, target) in relationships {
+            let path = match target.strip_prefix('/') {
+                Some(absolute) => absolute.to_string(),
+                None => format!("word/{}", target),
+            };
+            let Ok(xml) = self.read_file(&path) else {
+                continue;
+            };
+
+            let mut part = HeaderFooter {
+                relationship_id: Some(id),
+                ..Default::default()
+            };
+            self.parse_header_footer_content(&xml, &mut part)?;
+            if is_header {
+                document.headers.push(part);
+            } else {
+                document.footers.push(part);
+            }
+        }
+
+        for section in &document.sections {
+            for (part_type, id) in &section.headers {
+                if let Some(header) = document
+                    .headers
+                    .iter_mut()
+                    .find(|h| h.relationship_id.as_ref() == Some(id))
+                {
+                    header.header_type = part_type.clone();
+                }
+            }
+            for (part_type, id) in &section.footers {
+                if let Some(footer) = document
+                    .footers
+                    .iter_mut()
+                    .find(|f| f.relationship_id.as_ref() == Some(id))
+                {
+                    footer.header_type = part_type.clone();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fallback for packages without document relationships: read `header1.xml` to
+    /// `header3.xml` and the matching footers, with unknown types.
+    fn parse_numbered_headers_footers(&mut self, document: &mut Document) -> Result<(), DocxParseError> {
         for i in 1..=3 {
             let header_path = format!("word/header{}.xml", i);
             if let Ok(header_xml) = self.read_file(&header_path) {
@@ -595,6 +1173,177 @@ impl<R: Read + Seek> DocxParser<R> {
 
         Ok(())
     }
+
+    /// Parse `word/comments.xml`, returning a map from each comment's last paragraph id
+    /// (`w14:paraId`) to the comment id, which `commentsExtended.xml` uses to link replies.
+    fn parse_comments(
+        &self,
+        xml: &str,
+        comments: &mut Vec<Comment>,
+    ) -> Result<HashMap<String, String>, DocxParseError> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(false);
+
+        let mut buf = Vec::new();
+        let mut para_ids = HashMap::new();
+        let mut current_comment: Option<Comment> = None;
+        let mut current_paragraph: Option<String> = None;
+        let mut last_para_id: Option<String> = None;
+        let mut in_text = false;
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) => match e.name().as_ref() {
+                    b"w:comment" => {
+                        current_comment = Some(Comment {
+                            id: attribute_value(e, b"w:id").unwrap_or_default(),
+                            author: attribute_value(e, b"w:author").unwrap_or_default(),
+                            date: attribute_value(e, b"w:date"),
+                            ..Default::default()
+                        });
+                        last_para_id = None;
+                    }
+                    b"w:p" => {
+                        current_paragraph = Some(String::new());
+                        last_para_id = attribute_value(e, b"w14:paraId");
+                    }
+                    b"w:t" => in_text = true,
+                    _ => {}
+                },
+                Ok(Event::Text(e)) => {
+                    if in_text && let Some(ref mut para) = current_paragraph {
+                        para.push_str(&e.decode()?);
+                    }
+                }
+                Ok(Event::End(ref e)) => match e.name().as_ref() {
+                    b"w:t" => in_text = false,
+                    b"w:p" => {
+                        if let (Some(para), Some(comment)) = (current_paragraph.take(), current_comment.as_mut()) {
+                            if !comment.text.is_empty() {
+                                comment.text.push('\n');
+                            }
+                            comment.text.push_str(para.trim());
+                        }
+                    }
+                    b"w:comment" => {
+                        if let Some(comment) = current_comment.take() {
+                            if let Some(para_id) = last_para_id.take() {
+                                para_ids.insert(para_id, comment.id.clone());
+                            }
+                            comments.push(comment);
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(Event::Eof) => break,
+                Err(e) => return Err(e.into()),
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        Ok(para_ids)
+    }
+
+    /// Link reply comments to their parents using `word/commentsExtended.xml`.
+    fn parse_comments_extended(
+        &self,
+        xml: &str,
+        para_ids: &HashMap<String, String>,
+        comments: &mut [Comment],
+    ) -> Result<(), DocxParseError> {
+        let mut reader = Reader::from_str(xml);
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Empty(ref e) | Event::Start(ref e)) if e.name().as_ref() == b"w15:commentEx" => {
+                    let comment_id = attribute_value(e, b"w15:paraId").and_then(|id| para_ids.get(&id));
+                    let parent_id = attribute_value(e, b"w15:paraIdParent").and_then(|id| para_ids.get(&id));
+                    if let (Some(comment_id), Some(parent_id)) = (comment_id, parent_id)
+                        && let Some(comment) = comments.iter_mut().find(|comment| &comment.id == comment_id)
+                    {
+                        comment.parent_id = Some(parent_id.clone());
+                    }
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => return Err(e.into()),
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        Ok(())
+    }
+}
+
+/// Header and footer relationships from `word/_rels/document.xml.rels`, as
+/// `(id, is_header, target)`.
+fn parse_header_footer_relationships(xml: &str) -> Result<Vec<(String, bool, String)>, DocxParseError> {
+    let mut reader = Reader::from_str(xml);
+    let mut buf = Vec::new();
+    let mut relationships = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Relationship" => {
+                let rel_type = attribute_value(e, b"Type").unwrap_or_default();
+                let is_header = rel_type.ends_with("/header");
+                if (is_header || rel_type.ends_with("/footer"))
+                    && let (Some(id), Some(target)) = (attribute_value(e, b"Id"), attribute_value(e, b"Target"))
+                {
+                    relationships.push((id, is_header, target));
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(e.into()),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(relationships)
+}
+
+/// Whether `word/settings.xml` enables distinct even-page headers and footers.
+fn parse_even_and_odd_headers(xml: &str) -> Result<bool, DocxParseError> {
+    let mut reader = Reader::from_str(xml);
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e)) if e.name().as_ref() == b"w:evenAndOddHeaders" => {
+                return Ok(is_on(attribute_value(e, b"w:val").as_deref()));
+            }
+            Ok(Event::Eof) => return Ok(false),
+            Err(e) => return Err(e.into()),
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+/// Map a `w:type` attribute of a header or footer reference to its type.
+fn header_footer_type(value: Option<&str>) -> HeaderFooterType {
+    match value {
+        Some("first") => HeaderFooterType::First,
+        Some("even") => HeaderFooterType::Even,
+        _ => HeaderFooterType::Default,
+    }
+}
+
+/// Whether an OOXML on/off property is on; a missing `w:val` means on.
+fn is_on(value: Option<&str>) -> bool {
+    !matches!(value, Some("0" | "false" | "off"))
+}
+
+/// Read an attribute value by its qualified name.
+fn attribute_value(element: &BytesStart, key: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key)
+        .map(|attr| String::from_utf8_lossy(&attr.value).to_string())
 }
 
 // --- Error ---
@@ -625,11 +1374,29 @@ impl From<quick_xml::encoding::EncodingError> for DocxParseError {
 
 /// Parse a DOCX document from bytes and return the structured document.
 pub fn parse_document(bytes: &[u8]) -> crate::error::Result<Document> {
-    let cursor = Cursor::new(bytes);
-    let parser = DocxParser::new(cursor)
+    parse_document_with_options(bytes, &ExtractOptions::default())
+}
+
+/// Parse a DOCX document from bytes, honoring parse-time [`ExtractOptions`] such as
+/// [`ExtractOptions::extract_comments`].
+pub fn parse_document_with_options(bytes: &[u8], options: &ExtractOptions) -> crate::error::Result<Document> {
+    parse_document_from_reader(Cursor::new(bytes), options)
+}
+
+/// Parse a DOCX document from a seekable reader, such as a file or a ranged object-store reader.
+///
+/// Only the ZIP central directory and the XML parts the parser needs (`word/document.xml`,
+/// numbering, headers, footers, notes, comments, settings and relationships) are read and
+/// decompressed. Embedded media under `word/media/` is never read, so peak memory is bounded by
+/// the size of those XML parts plus the parsed [`Document`], not by the size of the archive.
+pub fn parse_document_from_reader<R: Read + Seek>(
+    reader: R,
+    options: &ExtractOptions,
+) -> crate::error::Result<Document> {
+    let parser = DocxParser::new(reader)
         .map_err(|e| crate::error::KreuzbergError::parsing(format!("DOCX parsing failed: {}", e)))?;
     parser
-        .parse()
+        .parse(options)
         .map_err(|e| crate::error::KreuzbergError::parsing(format!("DOCX parsing failed: {}", e)))
 }
 
@@ -639,6 +1406,20 @@ pub fn extract_text_from_bytes(bytes: &[u8]) -> crate::error::Result<String> {
     Ok(doc.extract_text())
 }
 
+/// Extract text from DOCX bytes using the given [`ExtractOptions`].
+pub fn extract_text_from_bytes_with_options(bytes: &[u8], options: &ExtractOptions) -> crate::error::Result<String> {
+    let doc = parse_document_with_options(bytes, options)?;
+    Ok(doc.extract_text_with_options(options))
+}
+
+/// Extract text from a seekable DOCX reader without buffering the archive.
+///
+/// See [`parse_document_from_reader`] for what is read from the archive.
+pub fn extract_text_from_reader<R: Read + Seek>(reader: R, options: &ExtractOptions) -> crate::error::Result<String> {
+    let doc = parse_document_from_reader(reader, options)?;
+    Ok(doc.extract_text_with_options(options))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -683,4 +1464,431 @@ mod tests {
         para.add_run(Run::new("fox".to_string()));
         assert_eq!(para.to_text(), "The quick fox");
     }
+
+    fn build_docx_with_notes() -> Vec<u8> {
+        use std::io::Write;
+
+        let document_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p><w:r><w:t>First claim.</w:t></w:r><w:r><w:rPr><w:rStyle w:val="FootnoteReference"/></w:rPr><w:footnoteReference w:id="2"/></w:r></w:p>
+    <w:p><w:r><w:t>Second claim.</w:t></w:r><w:r><w:rPr><w:rStyle w:val="EndnoteReference"/></w:rPr><w:endnoteReference w:id="3"/></w:r></w:p>
+  </w:body>
+</w:document>"#;
+        let footnotes_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:footnotes xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:footnote w:type="separator" w:id="-1"><w:p><w:r><w:separator/></w:r></w:p></w:footnote>
+  <w:footnote w:type="continuationSeparator" w:id="0"><w:p><w:r><w:continuationSeparator/></w:r></w:p></w:footnote>
+  <w:footnote w:id="2"><w:p><w:r><w:footnoteRef/></w:r><w:r><w:t xml:space="preserve"> Footnote source.</w:t></w:r></w:p></w:footnote>
+</w:footnotes>"#;
+        let endnotes_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:endnotes xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:endnote w:type="separator" w:id="-1"><w:p><w:r><w:separator/></w:r></w:p></w:endnote>
+  <w:endnote w:id="3"><w:p><w:r><w:endnoteRef/></w:r><w:r><w:t xml:space="preserve"> Endnote source.</w:t></w:r></w:p></w:endnote>
+</w:endnotes>"#;
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        for (name, content) in [
+            ("word/document.xml", document_xml),
+            ("word/footnotes.xml", footnotes_xml),
+            ("word/endnotes.xml", endnotes_xml),
+        ] {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_extract_text_inline_notes_disabled_unchanged() {
+        let bytes = build_docx_with_notes();
+        let text = extract_text_from_bytes_with_options(&bytes, &ExtractOptions::default()).unwrap();
+        assert_eq!(text, "First claim.\nSecond claim.\n");
+        assert_eq!(text, extract_text_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_extract_text_inline_notes_footnote_and_endnote() {
+        let bytes = build_docx_with_notes();
+        let options = ExtractOptions {
+            inline_notes: true,
+            ..Default::default()
+        };
+        let text = extract_text_from_bytes_with_options(&bytes, &options).unwrap();
+        assert_eq!(
+            text,
+            "First claim.[1]\nSecond claim.[E1]\n\nFootnotes\n[1] Footnote source.\n\nEndnotes\n[E1] Endnote source.\n"
+        );
+    }
+
+    fn build_docx_with_comments() -> Vec<u8> {
+        use std::io::Write;
+
+        let document_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p><w:r><w:t xml:space="preserve">The </w:t></w:r><w:commentRangeStart w:id="0"/><w:commentRangeStart w:id="1"/><w:r><w:t>indemnity clause</w:t></w:r><w:commentRangeEnd w:id="0"/><w:commentRangeEnd w:id="1"/><w:r><w:commentReference w:id="0"/></w:r><w:r><w:t xml:space="preserve"> applies.</w:t></w:r></w:p>
+  </w:body>
+</w:document>"#;
+        let comments_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:comments xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml">
+  <w:comment w:id="0" w:author="Alice Reviewer" w:date="2024-03-01T10:00:00Z" w:initials="AR"><w:p w14:paraId="1A2B3C01"><w:r><w:annotationRef/></w:r><w:r><w:t>Please narrow this clause.</w:t></w:r></w:p></w:comment>
+  <w:comment w:id="1" w:author="Bob Counsel" w:date="2024-03-02T09:30:00Z" w:initials="BC"><w:p w14:paraId="1A2B3C02"><w:r><w:annotationRef/></w:r><w:r><w:t>Agreed, revised.</w:t></w:r></w:p></w:comment>
+</w:comments>"#;
+        let comments_extended_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w15:commentsEx xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml">
+  <w15:commentEx w15:paraId="1A2B3C01" w15:done="0"/>
+  <w15:commentEx w15:paraId="1A2B3C02" w15:paraIdParent="1A2B3C01" w15:done="0"/>
+</w15:commentsEx>"#;
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        for (name, content) in [
+            ("word/document.xml", document_xml),
+            ("word/comments.xml", comments_xml),
+            ("word/commentsExtended.xml", comments_extended_xml),
+        ] {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_parse_document_comments_with_reply() {
+        let bytes = build_docx_with_comments();
+        let options = ExtractOptions {
+            extract_comments: true,
+            ..Default::default()
+        };
+        let doc = parse_document_with_options(&bytes, &options).unwrap();
+
+        assert_eq!(doc.comments.len(), 2);
+
+        let comment = &doc.comments[0];
+        assert_eq!(comment.id, "0");
+        assert_eq!(comment.author, "Alice Reviewer");
+        assert_eq!(comment.date.as_deref(), Some("2024-03-01T10:00:00Z"));
+        assert_eq!(comment.text, "Please narrow this clause.");
+        assert_eq!(comment.anchor_text, "indemnity clause");
+        assert_eq!(comment.parent_id, None);
+
+        let reply = &doc.comments[1];
+        assert_eq!(reply.author, "Bob Counsel");
+        assert_eq!(reply.text, "Agreed, revised.");
+        assert_eq!(reply.anchor_text, "indemnity clause");
+        assert_eq!(reply.parent_id.as_deref(), Some("0"));
+
+        assert_eq!(doc.extract_text(), "The indemnity clause applies.\n");
+    }
+
+    #[test]
+    fn test_parse_document_comments_disabled_by_default() {
+        let doc = parse_document(&build_docx_with_comments()).unwrap();
+        assert!(doc.comments.is_empty());
+    }
+
+    fn build_docx_with_revisions() -> Vec<u8> {
+        use std::io::Write;
+
+        let document_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p><w:r><w:t xml:space="preserve">Payment is due within </w:t></w:r><w:del w:id="1" w:author="Alice" w:date="2024-03-01T10:00:00Z"><w:r><w:delText>30</w:delText></w:r></w:del><w:ins w:id="2" w:author="Bob" w:date="2024-03-02T10:00:00Z"><w:r><w:t>45</w:t></w:r></w:ins><w:r><w:t xml:space="preserve"> days.</w:t></w:r></w:p>
+  </w:body>
+</w:document>"#;
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        zip.start_file("word/document.xml", options).unwrap();
+        zip.write_all(document_xml.as_bytes()).unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn extract_with_revision_mode(bytes: &[u8], revision_mode: RevisionMode) -> String {
+        let options = ExtractOptions {
+            revision_mode,
+            ..Default::default()
+        };
+        extract_text_from_bytes_with_options(bytes, &options).unwrap()
+    }
+
+    #[test]
+    fn test_revision_mode_final_accepts_changes() {
+        let bytes = build_docx_with_revisions();
+        assert_eq!(
+            extract_with_revision_mode(&bytes, RevisionMode::Final),
+            "Payment is due within 45 days.\n"
+        );
+        assert_eq!(
+            extract_text_from_bytes(&bytes).unwrap(),
+            "Payment is due within 45 days.\n"
+        );
+    }
+
+    #[test]
+    fn test_revision_mode_original_rejects_changes() {
+        let bytes = build_docx_with_revisions();
+        assert_eq!(
+            extract_with_revision_mode(&bytes, RevisionMode::Original),
+            "Payment is due within 30 days.\n"
+        );
+    }
+
+    #[test]
+    fn test_revision_mode_annotated_marks_changes() {
+        let bytes = build_docx_with_revisions();
+        assert_eq!(
+            extract_with_revision_mode(&bytes, RevisionMode::Annotated),
+            "Payment is due within {--30--}{>>Alice<<}{++45++}{>>Bob<<} days.\n"
+        );
+    }
+
+    /// Two sections: the first has a distinct title page and shares its default header with
+    /// the second, which adds an even-page header and its own footer.
+    fn build_docx_with_headers(even_and_odd_headers: bool) -> Vec<u8> {
+        use std::io::Write;
+
+        let document_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <w:body>
+    <w:p><w:r><w:t>Introduction</w:t></w:r></w:p>
+    <w:p><w:pPr><w:sectPr><w:headerReference w:type="default" r:id="rId1"/><w:headerReference w:type="first" r:id="rId2"/><w:footerReference w:type="default" r:id="rId3"/><w:titlePg/></w:sectPr></w:pPr><w:r><w:t>Overview.</w:t></w:r></w:p>
+    <w:p><w:r><w:t>Appendix</w:t></w:r></w:p>
+    <w:sectPr><w:headerReference w:type="default" r:id="rId1"/><w:headerReference w:type="even" r:id="rId4"/><w:footerReference w:type="default" r:id="rId5"/></w:sectPr>
+  </w:body>
+</w:document>"#;
+        let rels_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/header" Target="header1.xml"/>
+  <Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/header" Target="header2.xml"/>
+  <Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/footer" Target="footer1.xml"/>
+  <Relationship Id="rId4" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/header" Target="header3.xml"/>
+  <Relationship Id="rId5" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/footer" Target="footer2.xml"/>
+  <Relationship Id="rId6" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/settings" Target="settings.xml"/>
+</Relationships>"#;
+        let part = |root: &str, text: &str| {
+            format!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:{root} xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:p><w:r><w:t>{text}</w:t></w:r></w:p></w:{root}>"#
+            )
+        };
+        let settings_xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">{}</w:settings>"#,
+            if even_and_odd_headers {
+                "<w:evenAndOddHeaders/>"
+            } else {
+                ""
+            }
+        );
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        for (name, content) in [
+            ("word/document.xml", document_xml.to_string()),
+            ("word/_rels/document.xml.rels", rels_xml.to_string()),
+            ("word/header1.xml", part("hdr", "Company Confidential")),
+            ("word/header2.xml", part("hdr", "Cover Page Header")),
+            ("word/header3.xml", part("hdr", "Even Page Header")),
+            ("word/footer1.xml", part("ftr", "Page Footer")),
+            ("word/footer2.xml", part("ftr", "Appendix Footer")),
+            ("word/settings.xml", settings_xml),
+        ] {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn extract_with_header_placement(bytes: &[u8], include_headers_footers: HeaderFooterPlacement) -> String {
+        let options = ExtractOptions {
+            include_headers_footers,
+            ..Default::default()
+        };
+        extract_text_from_bytes_with_options(bytes, &options).unwrap()
+    }
+
+    #[test]
+    fn test_parse_document_header_footer_types() {
+        let document = parse_document(&build_docx_with_headers(false)).unwrap();
+
+        let header_types: Vec<(String, HeaderFooterType)> = document
+            .headers
+            .iter()
+            .map(|header| (header.extract_text(), header.header_type.clone()))
+            .collect();
+        assert_eq!(
+            header_types,
+            vec![
+                ("Company Confidential\n".to_string(), HeaderFooterType::Default),
+                ("Cover Page Header\n".to_string(), HeaderFooterType::First),
+                ("Even Page Header\n".to_string(), HeaderFooterType::Even),
+            ]
+        );
+        assert_eq!(document.footers.len(), 2);
+        assert_eq!(document.sections.len(), 2);
+        assert_eq!(document.sections[0].paragraph_end, 2);
+        assert!(document.sections[0].title_page);
+        assert_eq!(document.sections[1].paragraph_end, 3);
+        assert!(!document.sections[1].title_page);
+        assert!(!document.even_and_odd_headers);
+    }
+
+    #[test]
+    fn test_header_footer_placement_omit_is_default() {
+        let bytes = build_docx_with_headers(false);
+        assert_eq!(
+            extract_with_header_placement(&bytes, HeaderFooterPlacement::Omit),
+            "Introduction\nOverview.\nAppendix\n"
+        );
+        assert_eq!(
+            extract_text_from_bytes(&bytes).unwrap(),
+            "Introduction\nOverview.\nAppendix\n"
+        );
+    }
+
+    #[test]
+    fn test_header_footer_placement_inline_at_section_boundaries() {
+        let bytes = build_docx_with_headers(false);
+        assert_eq!(
+            extract_with_header_placement(&bytes, HeaderFooterPlacement::Inline),
+            "Cover Page Header\nCompany Confidential\nIntroduction\nOverview.\nPage Footer\nAppendix\nAppendix Footer\n"
+        );
+
+        let bytes = build_docx_with_headers(true);
+        assert_eq!(
+            extract_with_header_placement(&bytes, HeaderFooterPlacement::Inline),
+            "Cover Page Header\nCompany Confidential\nIntroduction\nOverview.\nPage Footer\nEven Page Header\nAppendix\nAppendix Footer\n"
+        );
+    }
+
+    #[test]
+    fn test_header_footer_placement_appended() {
+        let bytes = build_docx_with_headers(false);
+        assert_eq!(
+            extract_with_header_placement(&bytes, HeaderFooterPlacement::Appended),
+            "Introduction\nOverview.\nAppendix\n\nHeaders\nCover Page Header\nCompany Confidential\n\nFooters\nPage Footer\nAppendix Footer\n"
+        );
+    }
+
+    fn build_docx_with_formatting() -> Vec<u8> {
+        use std::io::Write;
+
+        let document_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p><w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">Bold </w:t></w:r><w:r><w:rPr><w:b/></w:rPr><w:t>words</w:t></w:r><w:r><w:t xml:space="preserve"> then </w:t></w:r><w:r><w:rPr><w:i/></w:rPr><w:t>italic</w:t></w:r><w:r><w:rPr><w:i/></w:rPr><w:t xml:space="preserve"> text</w:t></w:r><w:r><w:t xml:space="preserve"> and </w:t></w:r><w:r><w:rPr><w:b/><w:i/></w:rPr><w:t>both</w:t></w:r><w:r><w:rPr><w:b w:val="0"/></w:rPr><w:t>.</w:t></w:r></w:p>
+    <w:p><w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">Note: </w:t></w:r><w:r><w:t>read carefully</w:t></w:r></w:p>
+  </w:body>
+</w:document>"#;
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        zip.start_file("word/document.xml", options).unwrap();
+        zip.write_all(document_xml.as_bytes()).unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_parse_document_run_formatting() {
+        let document = parse_document(&build_docx_with_formatting()).unwrap();
+        let flags: Vec<(bool, bool)> = document.paragraphs[0]
+            .runs
+            .iter()
+            .map(|run| (run.bold, run.italic))
+            .collect();
+        assert_eq!(
+            flags,
+            vec![
+                (true, false),
+                (true, false),
+                (false, false),
+                (false, true),
+                (false, true),
+                (false, false),
+                (true, true),
+                (false, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inline_markup_disabled_is_plain_text() {
+        let bytes = build_docx_with_formatting();
+        assert_eq!(
+            extract_text_from_bytes(&bytes).unwrap(),
+            "Bold words then italic text and both.\nNote: read carefully\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_markup_groups_consecutive_runs() {
+        let bytes = build_docx_with_formatting();
+        let options = ExtractOptions {
+            inline_markup: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            extract_text_from_bytes_with_options(&bytes, &options).unwrap(),
+            "**Bold words** then *italic text* and ***both***.\n**Note:** read carefully\n"
+        );
+    }
+
+    /// Reader that counts the bytes read through it.
+    struct CountingReader<R> {
+        inner: R,
+        bytes_read: std::rc::Rc<std::cell::Cell<usize>>,
+    }
+
+    impl<R: Read> Read for CountingReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.inner.read(buf)?;
+            self.bytes_read.set(self.bytes_read.get() + n);
+            Ok(n)
+        }
+    }
+
+    impl<R: Seek> Seek for CountingReader<R> {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn test_extract_text_from_reader_skips_embedded_media() {
+        use std::io::Write;
+
+        const IMAGE_SIZE: usize = 4 * 1024 * 1024;
+        let document_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p><w:r><w:t>Site photos follow.</w:t></w:r></w:p>
+  </w:body>
+</w:document>"#;
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        zip.start_file("word/media/image1.png", options).unwrap();
+        zip.write_all(&vec![0x89; IMAGE_SIZE]).unwrap();
+        zip.start_file("word/document.xml", options).unwrap();
+        zip.write_all(document_xml.as_bytes()).unwrap();
+        let bytes = zip.finish().unwrap().into_inner();
+
+        let bytes_read = std::rc::Rc::new(std::cell::Cell::new(0));
+        let reader = CountingReader {
+            inner: Cursor::new(bytes),
+            bytes_read: bytes_read.clone(),
+        };
+
+        let text = extract_text_from_reader(reader, &ExtractOptions::default()).unwrap();
+        assert_eq!(text, "Site photos follow.\n");
+        assert!(
+            bytes_read.get() < IMAGE_SIZE / 4,
+            "read {} bytes from a {} byte image archive",
+            bytes_read.get(),
+            IMAGE_SIZE
+        );
+    }
 }
@@ -151,6 +151,7 @@ fn process_xlsx_workbook<RS: Read + Seek>(
 ) -> Result<ExcelWorkbook> {
     let sheet_names = workbook.sheet_names();
     let mut sheets = Vec::with_capacity(sheet_names.len());
+    let mut skipped_sheets = Vec::new();
 
     for name in &sheet_names {
         // Use worksheet_cells_reader to stream cells and detect pathological bounding boxes
@@ -159,12 +160,17 @@ fn process_xlsx_workbook<RS: Read + Seek>(
             Err(e) => {
                 // Log but don't fail - continue with other sheets
                 tracing::warn!("Failed to process sheet '{}': {}", name, e);
+                skipped_sheets.push(name.clone());
             }
         }
     }
 
     let metadata = extract_metadata(&workbook, &sheet_names, office_metadata);
-    Ok(ExcelWorkbook { sheets, metadata })
+    Ok(ExcelWorkbook {
+        sheets,
+        metadata,
+        skipped_sheets,
+    })
 }
 
 /// Process a single XLSX sheet safely by pre-checking the bounding box.
@@ -335,16 +341,22 @@ where
     let sheet_names = workbook.sheet_names();
 
     let mut sheets = Vec::with_capacity(sheet_names.len());
+    let mut skipped_sheets = Vec::new();
 
     for name in &sheet_names {
-        if let Ok(range) = workbook.worksheet_range(name) {
-            sheets.push(process_sheet(name, &range));
+        match workbook.worksheet_range(name) {
+            Ok(range) => sheets.push(process_sheet(name, &range)),
+            Err(_) => skipped_sheets.push(name.clone()),
         }
     }
 
     let metadata = extract_metadata(&workbook, &sheet_names, office_metadata);
 
-    Ok(ExcelWorkbook { sheets, metadata })
+    Ok(ExcelWorkbook {
+        sheets,
+        metadata,
+        skipped_sheets,
+    })
 }
 
 #[inline]
@@ -413,7 +425,20 @@ fn generate_markdown_and_cells(sheet_name: &str, range: &Range<Data>, capacity:
         }
     }
 
-    let rows: Vec<_> = range.rows().collect();
+    let mut rows: Vec<_> = range.rows().collect();
+
+    // Trim trailing rows and columns that hold no values, e.g. formatted but empty cells
+    let used_rows = rows
+        .iter()
+        .rposition(|row| row.iter().any(|cell| !is_blank_cell(cell)))
+        .map_or(0, |last| last + 1);
+    rows.truncate(used_rows);
+    let used_cols = rows
+        .iter()
+        .filter_map(|row| row.iter().rposition(|cell| !is_blank_cell(cell)))
+        .max()
+        .map_or(0, |last| last + 1);
+
     if rows.is_empty() {
         let result_capacity = 50 + sheet_name.len();
         let mut result = String::with_capacity(result_capacity);
@@ -421,8 +446,8 @@ fn generate_markdown_and_cells(sheet_name: &str, range: &Range<Data>, capacity:
         return (result, Vec::new());
     }
 
-    let header = &rows[0];
-    let header_len = header.len();
+    let header = &rows[0][..used_cols];
+    let header_len = used_cols;
     let row_count = rows.len();
 
     let table_capacity = capacity::estimate_table_markdown_capacity(row_count, header_len);
@@ -522,7 +547,12 @@ fn format_cell_to_string(data: &Data) -> String {
         }
         Data::DateTime(dt) => {
             if let Some(datetime) = dt.as_datetime() {
-                format!("{}", datetime.format("%Y-%m-%d %H:%M:%S"))
+                // Whole serial numbers carry no time of day, so render them as plain dates
+                if dt.is_datetime() && dt.as_f64().fract() == 0.0 {
+                    format!("{}", datetime.format("%Y-%m-%d"))
+                } else {
+                    format!("{}", datetime.format("%Y-%m-%d %H:%M:%S"))
+                }
             } else {
                 format!("{:?}", dt)
             }
@@ -533,6 +563,15 @@ fn format_cell_to_string(data: &Data) -> String {
     }
 }
 
+#[inline]
+fn is_blank_cell(data: &Data) -> bool {
+    match data {
+        Data::Empty => true,
+        Data::String(s) => s.trim().is_empty(),
+        _ => false,
+    }
+}
+
 #[inline]
 fn escape_markdown_into(buffer: &mut String, s: &str) {
     for ch in s.chars() {
@@ -745,6 +784,16 @@ mod tests {
         assert!(!result.is_empty());
     }
 
+    #[test]
+    fn test_format_cell_value_date_serial() {
+        use calamine::{ExcelDateTime, ExcelDateTimeType};
+        let date = Data::DateTime(ExcelDateTime::new(45306.0, ExcelDateTimeType::DateTime, false));
+        assert_eq!(format_cell_to_string(&date), "2024-01-15");
+
+        let datetime = Data::DateTime(ExcelDateTime::new(45306.5, ExcelDateTimeType::DateTime, false));
+        assert_eq!(format_cell_to_string(&datetime), "2024-01-15 12:00:00");
+    }
+
     #[test]
     fn test_format_cell_value_error() {
         use calamine::CellErrorType;
@@ -865,7 +914,23 @@ mod tests {
 
         assert!(markdown.contains("X"));
         assert!(markdown.contains("Z"));
-        assert_eq!(cells.len(), 3);
+        assert_eq!(cells.len(), 2, "trailing empty row should be trimmed");
+    }
+
+    #[test]
+    fn test_generate_markdown_and_cells_trims_trailing_blanks() {
+        let mut range: Range<Data> = Range::new((0, 0), (3, 3));
+        range.set_value((0, 0), Data::String("Item".to_owned()));
+        range.set_value((0, 1), Data::String("Qty".to_owned()));
+        range.set_value((0, 3), Data::String("  ".to_owned()));
+        range.set_value((1, 0), Data::String("Bolt".to_owned()));
+        range.set_value((1, 1), Data::Float(4.0));
+        range.set_value((3, 2), Data::String(String::new()));
+
+        let (markdown, cells) = generate_markdown_and_cells("Parts", &range, 200);
+
+        assert_eq!(cells, vec![vec!["Item", "Qty"], vec!["Bolt", "4.0"]]);
+        assert!(markdown.ends_with("| Bolt | 4.0 |\n"), "Markdown was: {}", markdown);
     }
 
     #[test]
@@ -5,6 +5,39 @@
 
 use crate::extraction::capacity;
 
+/// How cell content is escaped when rendering a markdown table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CellEscaping {
+    /// Escape pipes only; line breaks are kept as they are
+    #[default]
+    PipesOnly,
+    /// Escape pipes, trim each line and join the lines of a cell with `<br>`
+    LineBreaks,
+    /// Escape pipes, trim each line and join the lines of a cell with spaces
+    Spaces,
+}
+
+/// Output format of a rendered table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TableFormat {
+    /// GitHub-Flavored Markdown table with a `|------|` separator after the header row
+    #[default]
+    Gfm,
+    /// Plain grid of ` | `-separated cells, one row per line, without a separator row
+    Simple,
+    /// HTML `<table>` with the first row in `<thead>`
+    Html,
+}
+
+/// Options for [`cells_to_markdown_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TableMarkdownOptions {
+    /// Output format of the table
+    pub format: TableFormat,
+    /// How cell content is escaped
+    pub escaping: CellEscaping,
+}
+
 /// Converts a 2D vector of cell strings into a GitHub-Flavored Markdown table.
 ///
 /// # Behavior
@@ -12,7 +45,7 @@ use crate::extraction::capacity;
 /// - The first row is treated as the header row
 /// - A separator row is inserted after the header
 /// - Pipe characters (`|`) in cell content are automatically escaped with backslash
-/// - Irregular tables (rows with varying column counts) are padded with empty cells to match the header
+/// - Irregular tables (rows with varying column counts) are padded with empty cells to the widest row
 /// - Returns an empty string for empty input
 ///
 /// # Arguments
@@ -38,53 +71,147 @@ use crate::extraction::capacity;
 /// assert!(markdown.contains("|------|------|"));
 /// ```
 pub fn cells_to_markdown(cells: &[Vec<String>]) -> String {
-    if cells.is_empty() {
-        return String::new();
-    }
+    cells_to_markdown_with(cells, &TableMarkdownOptions::default())
+}
 
-    let num_cols = cells.first().map(|r| r.len()).unwrap_or(0);
+/// Converts table cells into a GitHub-Flavored Markdown table using the given options.
+///
+/// Behaves like [`cells_to_markdown`], except that the table is rendered in `options.format`
+/// and cell content is escaped according to `options.escaping`. With
+/// [`CellEscaping::LineBreaks`] or [`CellEscaping::Spaces`], cells containing line breaks
+/// stay on a single table row. HTML output escapes `&`, `<`, `>` and `"` instead of pipes.
+///
+/// # Examples
+///
+/// ```
+/// # use kreuzberg::extraction::{CellEscaping, TableMarkdownOptions, cells_to_markdown_with};
+/// let cells = vec![
+///     vec!["Item".to_string()],
+///     vec!["first line\nsecond line".to_string()],
+/// ];
+/// let options = TableMarkdownOptions {
+///     escaping: CellEscaping::LineBreaks,
+///     ..Default::default()
+/// };
+///
+/// let markdown = cells_to_markdown_with(&cells, &options);
+/// assert!(markdown.contains("| first line<br>second line |"));
+/// ```
+pub fn cells_to_markdown_with(cells: &[Vec<String>], options: &TableMarkdownOptions) -> String {
+    let num_cols = cells.iter().map(Vec::len).max().unwrap_or(0);
     if num_cols == 0 {
         return String::new();
     }
 
+    match options.format {
+        TableFormat::Gfm => gfm_table(cells, num_cols, options),
+        TableFormat::Simple => simple_table(cells, num_cols, options),
+        TableFormat::Html => html_table(cells, num_cols, options),
+    }
+}
+
+fn gfm_table(cells: &[Vec<String>], num_cols: usize, options: &TableMarkdownOptions) -> String {
     let estimated_capacity = capacity::estimate_table_markdown_capacity(cells.len(), num_cols);
     let mut markdown = String::with_capacity(estimated_capacity);
 
-    if let Some(header) = cells.first() {
+    for (row_idx, row) in cells.iter().enumerate() {
         markdown.push('|');
-        for cell in header {
+        for cell in row {
             markdown.push(' ');
-            let escaped = cell.replace('|', "\\|");
-            markdown.push_str(&escaped);
+            markdown.push_str(&escape_cell(cell, options));
+            markdown.push_str(" |");
+        }
+        for _ in row.len()..num_cols {
             markdown.push_str(" |");
         }
         markdown.push('\n');
 
-        markdown.push('|');
-        for _ in 0..num_cols {
-            markdown.push_str("------|");
+        if row_idx == 0 {
+            markdown.push('|');
+            for _ in 0..num_cols {
+                markdown.push_str("------|");
+            }
+            markdown.push('\n');
         }
+    }
+
+    markdown
+}
+
+fn simple_table(cells: &[Vec<String>], num_cols: usize, options: &TableMarkdownOptions) -> String {
+    let estimated_capacity = capacity::estimate_table_markdown_capacity(cells.len(), num_cols);
+    let mut markdown = String::with_capacity(estimated_capacity);
+
+    for row in cells {
+        let escaped: Vec<String> = padded_row(row, num_cols)
+            .map(|cell| escape_cell(cell, options))
+            .collect();
+        markdown.push_str(escaped.join(" | ").trim_end());
         markdown.push('\n');
     }
 
-    for row in cells.iter().skip(1) {
-        markdown.push('|');
-        for (idx, cell) in row.iter().enumerate() {
-            if idx >= num_cols {
-                break;
-            }
-            markdown.push(' ');
-            let escaped = cell.replace('|', "\\|");
-            markdown.push_str(&escaped);
-            markdown.push_str(" |");
+    markdown
+}
+
+fn html_table(cells: &[Vec<String>], num_cols: usize, options: &TableMarkdownOptions) -> String {
+    let estimated_capacity = capacity::estimate_table_markdown_capacity(cells.len(), num_cols);
+    let mut html = String::with_capacity(estimated_capacity * 2);
+
+    html.push_str("<table>\n");
+    for (row_idx, row) in cells.iter().enumerate() {
+        let tag = if row_idx == 0 { "th" } else { "td" };
+        match row_idx {
+            0 => html.push_str("<thead>\n"),
+            1 => html.push_str("<tbody>\n"),
+            _ => {}
         }
-        for _ in row.len()..num_cols {
-            markdown.push_str(" |");
+
+        html.push_str("<tr>");
+        for cell in padded_row(row, num_cols) {
+            html.push_str(&format!("<{tag}>{}</{tag}>", escape_cell(cell, options)));
         }
-        markdown.push('\n');
+        html.push_str("</tr>\n");
+
+        if row_idx == 0 {
+            html.push_str("</thead>\n");
+        }
+    }
+    if cells.len() > 1 {
+        html.push_str("</tbody>\n");
     }
+    html.push_str("</table>\n");
 
-    markdown
+    html
+}
+
+/// Cells of a row, padded with empty cells up to `num_cols`.
+fn padded_row(row: &[String], num_cols: usize) -> impl Iterator<Item = &str> {
+    row.iter()
+        .map(String::as_str)
+        .chain(std::iter::repeat_n("", num_cols.saturating_sub(row.len())))
+}
+
+fn escape_cell(cell: &str, options: &TableMarkdownOptions) -> String {
+    let escaped = match options.format {
+        TableFormat::Html => cell
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;"),
+        TableFormat::Gfm | TableFormat::Simple => cell.replace('|', "\\|"),
+    };
+    let separator = match options.escaping {
+        CellEscaping::PipesOnly => return escaped,
+        CellEscaping::LineBreaks => "<br>",
+        CellEscaping::Spaces => " ",
+    };
+
+    escaped
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator)
 }
 
 #[cfg(test)]
@@ -201,6 +328,135 @@ mod tests {
         assert!(markdown.contains("~~Strike~~"));
     }
 
+    /// Cells of a markdown table row, split on unescaped pipes.
+    fn row_cells(line: &str) -> Vec<String> {
+        let mut cells = vec![String::new()];
+        let mut chars = line.trim().trim_start_matches('|').chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' if chars.peek() == Some(&'|') => {
+                    cells.last_mut().unwrap().push_str("\\|");
+                    chars.next();
+                }
+                '|' => cells.push(String::new()),
+                _ => cells.last_mut().unwrap().push(c),
+            }
+        }
+        cells.pop();
+        cells.into_iter().map(|cell| cell.trim().to_string()).collect()
+    }
+
+    #[test]
+    fn test_markdown_with_escapes_pipe_as_single_cell() {
+        let cells = vec![
+            vec!["Operator".to_string(), "Meaning".to_string()],
+            vec!["a | b".to_string(), "  either  ".to_string()],
+        ];
+        let options = TableMarkdownOptions {
+            escaping: CellEscaping::LineBreaks,
+            ..Default::default()
+        };
+
+        let markdown = cells_to_markdown_with(&cells, &options);
+        let lines: Vec<&str> = markdown.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(row_cells(lines[2]), vec!["a \\| b", "either"]);
+    }
+
+    #[test]
+    fn test_markdown_with_keeps_multiline_cell_on_one_row() {
+        let cells = vec![
+            vec!["Step".to_string(), "Notes".to_string()],
+            vec!["1".to_string(), "mix well\r\n\nthen bake ".to_string()],
+        ];
+
+        let line_breaks = cells_to_markdown_with(
+            &cells,
+            &TableMarkdownOptions {
+                escaping: CellEscaping::LineBreaks,
+                ..Default::default()
+            },
+        );
+        let lines: Vec<&str> = line_breaks.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(row_cells(lines[2]), vec!["1", "mix well<br>then bake"]);
+
+        let spaces = cells_to_markdown_with(
+            &cells,
+            &TableMarkdownOptions {
+                escaping: CellEscaping::Spaces,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            row_cells(spaces.lines().nth(2).unwrap()),
+            vec!["1", "mix well then bake"]
+        );
+
+        // The default keeps line breaks, splitting the row
+        assert_eq!(cells_to_markdown(&cells).lines().count(), 5);
+    }
+
+    fn two_by_two() -> Vec<Vec<String>> {
+        vec![
+            vec!["Name".to_string(), "Age".to_string()],
+            vec!["Alice".to_string(), "30".to_string()],
+        ]
+    }
+
+    fn render(cells: &[Vec<String>], format: TableFormat) -> String {
+        cells_to_markdown_with(
+            cells,
+            &TableMarkdownOptions {
+                format,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_table_format_gfm() {
+        let markdown = render(&two_by_two(), TableFormat::Gfm);
+
+        assert_eq!(markdown, "| Name | Age |\n|------|------|\n| Alice | 30 |\n");
+        assert_eq!(markdown, cells_to_markdown(&two_by_two()));
+    }
+
+    #[test]
+    fn test_table_format_simple() {
+        let markdown = render(&two_by_two(), TableFormat::Simple);
+
+        assert_eq!(markdown, "Name | Age\nAlice | 30\n");
+    }
+
+    #[test]
+    fn test_table_format_html() {
+        let markdown = render(&two_by_two(), TableFormat::Html);
+
+        assert_eq!(
+            markdown,
+            "<table>\n<thead>\n<tr><th>Name</th><th>Age</th></tr>\n</thead>\n\
+             <tbody>\n<tr><td>Alice</td><td>30</td></tr>\n</tbody>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn test_table_formats_pad_ragged_rows_to_widest_row() {
+        let cells = vec![
+            vec!["H1".to_string()],
+            vec!["R1C1".to_string(), "R1C2".to_string(), "a < b".to_string()],
+        ];
+
+        assert_eq!(
+            render(&cells, TableFormat::Gfm),
+            "| H1 | | |\n|------|------|------|\n| R1C1 | R1C2 | a < b |\n"
+        );
+        assert_eq!(render(&cells, TableFormat::Simple), "H1 |  |\nR1C1 | R1C2 | a < b\n");
+        assert!(render(&cells, TableFormat::Html).contains("<tr><th>H1</th><th></th><th></th></tr>"));
+        assert!(render(&cells, TableFormat::Html).contains("<td>a &lt; b</td>"));
+    }
+
     #[test]
     fn test_markdown_unicode_content() {
         let cells = vec![
@@ -42,7 +42,6 @@ pub mod table;
 #[cfg(feature = "xml")]
 pub mod xml;
 
-#[cfg(any(feature = "office", feature = "html", feature = "xml"))]
 pub mod markdown;
 
 pub use structured::{JsonExtractionConfig, StructuredDataResult, parse_json, parse_toml, parse_yaml};
@@ -88,8 +87,7 @@ pub use table::table_from_arrow_to_markdown;
 #[cfg(feature = "xml")]
 pub use xml::parse_xml;
 
-#[cfg(any(feature = "office", feature = "html", feature = "xml"))]
-pub use markdown::cells_to_markdown;
+pub use markdown::{CellEscaping, TableFormat, TableMarkdownOptions, cells_to_markdown, cells_to_markdown_with};
 
 pub use capacity::{
     estimate_content_capacity, estimate_html_markdown_capacity, estimate_presentation_capacity,
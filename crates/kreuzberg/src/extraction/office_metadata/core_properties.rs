@@ -44,6 +44,15 @@ pub struct CoreProperties {
     pub last_printed: Option<String>,
 }
 
+impl CoreProperties {
+    /// Keywords split into individual entries
+    ///
+    /// Returns an empty vector when the keywords element is absent or empty.
+    pub fn keyword_list(&self) -> Vec<String> {
+        self.keywords.as_deref().map(super::split_keywords).unwrap_or_default()
+    }
+}
+
 /// Extract core properties from an Office Open XML document
 ///
 /// Parses `docProps/core.xml` from the ZIP archive and extracts Dublin Core metadata.
@@ -182,6 +191,7 @@ mod tests {
         assert_eq!(props.subject, Some("Testing".to_string()));
         assert_eq!(props.creator, Some("John Doe".to_string()));
         assert_eq!(props.keywords, Some("test, metadata".to_string()));
+        assert_eq!(props.keyword_list(), vec!["test", "metadata"]);
         assert_eq!(props.description, Some("A test document".to_string()));
         assert_eq!(props.last_modified_by, Some("Jane Doe".to_string()));
         assert_eq!(props.revision, Some("5".to_string()));
@@ -206,6 +216,7 @@ mod tests {
         assert_eq!(props.creator, Some("Alice".to_string()));
         assert_eq!(props.title, None);
         assert_eq!(props.keywords, None);
+        assert!(props.keyword_list().is_empty());
     }
 
     #[test]
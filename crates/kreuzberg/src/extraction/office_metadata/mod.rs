@@ -59,6 +59,19 @@ pub(crate) fn parse_xml_text(node: Node, name: &str) -> Option<String> {
         .map(String::from)
 }
 
+/// Split a keywords property into individual keywords
+///
+/// Office applications separate keywords with commas or semicolons depending on
+/// locale and version; both are accepted. Empty entries are dropped.
+pub fn split_keywords(keywords: &str) -> Vec<String> {
+    keywords
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
 /// Parse integer content from an XML element by tag name
 ///
 /// Returns the parsed integer if the element exists and contains valid integer text.
@@ -88,6 +101,16 @@ pub(crate) fn parse_xml_bool(node: Node, name: &str) -> Option<bool> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_split_keywords() {
+        assert_eq!(
+            split_keywords("invoice; finance, 2024 ;;"),
+            vec!["invoice", "finance", "2024"]
+        );
+        assert!(split_keywords("").is_empty());
+        assert!(split_keywords(" , ; ").is_empty());
+    }
+
     #[test]
     fn test_parse_xml_text() {
         let xml = r#"<root><title>Test Document</title></root>"#;
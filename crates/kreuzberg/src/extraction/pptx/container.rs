@@ -80,7 +80,7 @@ impl PptxContainer {
             }
         }
 
-        slide_paths.sort();
+        super::parser::sort_slide_paths(&mut slide_paths);
         Ok(slide_paths)
     }
 
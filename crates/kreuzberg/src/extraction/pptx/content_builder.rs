@@ -71,10 +71,10 @@ impl ContentBuilder {
     }
 
     pub(super) fn add_slide_header(&mut self, slide_number: u32) {
-        self.content.reserve(50);
-        self.content.push_str("\n\n<!-- Slide number: ");
+        self.content.reserve(20);
+        self.content.push_str("\n\n## Slide ");
         self.content.push_str(&slide_number.to_string());
-        self.content.push_str(" -->\n");
+        self.content.push('\n');
     }
 
     pub(super) fn add_text(&mut self, text: &str) {
@@ -85,7 +85,7 @@ impl ContentBuilder {
 
     pub(super) fn add_title(&mut self, title: &str) {
         if !title.trim().is_empty() {
-            self.content.push_str("# ");
+            self.content.push_str("### ");
             self.content.push_str(title.trim());
             self.content.push('\n');
         }
@@ -114,14 +114,16 @@ pub(super) struct Slide {
 #[derive(Debug, Clone)]
 pub(super) struct ParserConfig {
     pub(super) extract_images: bool,
-    pub(super) include_slide_comment: bool,
+    pub(super) include_slide_headings: bool,
+    pub(super) include_notes: bool,
 }
 
 impl Default for ParserConfig {
     fn default() -> Self {
         Self {
             extract_images: true,
-            include_slide_comment: false,
+            include_slide_headings: true,
+            include_notes: true,
         }
     }
 }
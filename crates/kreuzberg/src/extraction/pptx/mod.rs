@@ -29,7 +29,7 @@
 //! use kreuzberg::extraction::pptx::extract_pptx_from_path;
 //!
 //! # fn example() -> kreuzberg::Result<()> {
-//! let result = extract_pptx_from_path("presentation.pptx", true, None)?;
+//! let result = extract_pptx_from_path("presentation.pptx", true, true, None)?;
 //!
 //! println!("Slide count: {}", result.slide_count);
 //! println!("Image count: {}", result.image_count);
@@ -62,6 +62,7 @@ use metadata::{extract_all_notes, extract_metadata};
 ///
 /// * `path` - Path to the PPTX file
 /// * `extract_images` - Whether to extract embedded images
+/// * `include_notes` - Whether to append speaker notes to each slide
 /// * `page_config` - Optional page configuration for boundary tracking
 ///
 /// # Returns
@@ -70,10 +71,12 @@ use metadata::{extract_all_notes, extract_metadata};
 pub fn extract_pptx_from_path(
     path: &str,
     extract_images: bool,
+    include_notes: bool,
     page_config: Option<&crate::core::config::PageConfig>,
 ) -> Result<PptxExtractionResult> {
     let config = ParserConfig {
         extract_images,
+        include_notes,
         ..Default::default()
     };
 
@@ -81,7 +84,11 @@ pub fn extract_pptx_from_path(
 
     let metadata = extract_metadata(&mut container.archive);
 
-    let notes = extract_all_notes(&mut container)?;
+    let notes = if config.include_notes {
+        extract_all_notes(&mut container)?
+    } else {
+        Default::default()
+    };
 
     let mut iterator = SlideIterator::new(container);
     let slide_count = iterator.slide_count();
@@ -176,6 +183,7 @@ pub fn extract_pptx_from_path(
 ///
 /// * `data` - Raw PPTX file bytes
 /// * `extract_images` - Whether to extract embedded images
+/// * `include_notes` - Whether to append speaker notes to each slide
 /// * `page_config` - Optional page configuration for boundary tracking
 ///
 /// # Returns
@@ -184,6 +192,7 @@ pub fn extract_pptx_from_path(
 pub fn extract_pptx_from_bytes(
     data: &[u8],
     extract_images: bool,
+    include_notes: bool,
     page_config: Option<&crate::core::config::PageConfig>,
 ) -> Result<PptxExtractionResult> {
     use std::sync::atomic::{AtomicU64, Ordering};
@@ -199,6 +208,7 @@ pub fn extract_pptx_from_bytes(
             crate::KreuzbergError::validation("Invalid temp path - contains invalid UTF-8".to_string())
         })?,
         extract_images,
+        include_notes,
         page_config,
     );
 
@@ -230,7 +240,7 @@ impl elements::Slide {
     fn to_markdown(&self, config: &ParserConfig) -> String {
         let mut builder = ContentBuilder::new();
 
-        if config.include_slide_comment {
+        if config.include_slide_headings {
             builder.add_slide_header(self.slide_number);
         }
 
@@ -408,7 +418,7 @@ mod tests {
     #[test]
     fn test_extract_pptx_from_bytes_single_slide() {
         let pptx_bytes = create_test_pptx_bytes(vec!["Hello World"]);
-        let result = extract_pptx_from_bytes(&pptx_bytes, false, None).unwrap();
+        let result = extract_pptx_from_bytes(&pptx_bytes, false, true, None).unwrap();
 
         assert_eq!(result.slide_count, 1);
         assert!(
@@ -423,7 +433,7 @@ mod tests {
     #[test]
     fn test_extract_pptx_from_bytes_multiple_slides() {
         let pptx_bytes = create_test_pptx_bytes(vec!["Slide 1", "Slide 2", "Slide 3"]);
-        let result = extract_pptx_from_bytes(&pptx_bytes, false, None).unwrap();
+        let result = extract_pptx_from_bytes(&pptx_bytes, false, true, None).unwrap();
 
         assert_eq!(result.slide_count, 3);
         assert!(result.content.contains("Slide 1"));
@@ -434,7 +444,7 @@ mod tests {
     #[test]
     fn test_extract_pptx_metadata() {
         let pptx_bytes = create_test_pptx_bytes(vec!["Content"]);
-        let result = extract_pptx_from_bytes(&pptx_bytes, false, None).unwrap();
+        let result = extract_pptx_from_bytes(&pptx_bytes, false, true, None).unwrap();
 
         // Metadata should be populated (slide_count should be 1 for the test content)
         assert_eq!(result.metadata.slide_count, 1);
@@ -443,7 +453,7 @@ mod tests {
     #[test]
     fn test_extract_pptx_empty_slides() {
         let pptx_bytes = create_test_pptx_bytes(vec!["", "", ""]);
-        let result = extract_pptx_from_bytes(&pptx_bytes, false, None).unwrap();
+        let result = extract_pptx_from_bytes(&pptx_bytes, false, true, None).unwrap();
 
         assert_eq!(result.slide_count, 3);
     }
@@ -453,7 +463,7 @@ mod tests {
         use crate::error::KreuzbergError;
 
         let invalid_bytes = b"not a valid pptx file";
-        let result = extract_pptx_from_bytes(invalid_bytes, false, None);
+        let result = extract_pptx_from_bytes(invalid_bytes, false, true, None);
 
         assert!(result.is_err());
         if let Err(KreuzbergError::Parsing { message: msg, .. }) = result {
@@ -466,11 +476,40 @@ mod tests {
     #[test]
     fn test_extract_pptx_from_bytes_empty_data() {
         let empty_bytes: &[u8] = &[];
-        let result = extract_pptx_from_bytes(empty_bytes, false, None);
+        let result = extract_pptx_from_bytes(empty_bytes, false, true, None);
 
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_extract_pptx_slide_headings() {
+        let pptx_bytes = create_test_pptx_bytes(vec!["First", "Second"]);
+        let result = extract_pptx_from_bytes(&pptx_bytes, false, true, None).unwrap();
+
+        let first = result.content.find("## Slide 1").unwrap();
+        let second = result.content.find("## Slide 2").unwrap();
+        assert!(first < second, "Content was: {}", result.content);
+    }
+
+    #[test]
+    fn test_sort_slide_paths_numeric() {
+        let mut paths: Vec<String> = ["slide10.xml", "slide2.xml", "slide9.xml", "slide1.xml"]
+            .iter()
+            .map(|name| format!("ppt/slides/{}", name))
+            .collect();
+        parser::sort_slide_paths(&mut paths);
+
+        assert_eq!(
+            paths,
+            vec![
+                "ppt/slides/slide1.xml",
+                "ppt/slides/slide2.xml",
+                "ppt/slides/slide9.xml",
+                "ppt/slides/slide10.xml",
+            ]
+        );
+    }
+
     #[test]
     fn test_detect_image_format_jpeg() {
         let jpeg_header = vec![0xFF, 0xD8, 0xFF, 0xE0];
@@ -388,7 +388,19 @@ pub(super) fn parse_presentation_rels(rels_data: &[u8]) -> Result<Vec<String>> {
     // PowerPoint doesn't guarantee relationship order in the rels file.
     // GitHub Issue #329: Without sorting, slides can be processed in wrong order,
     // causing images to have incorrect page numbers.
-    slide_paths.sort();
+    sort_slide_paths(&mut slide_paths);
 
     Ok(slide_paths)
 }
+
+/// Sort slide part paths by their slide number, so `slide10.xml` follows `slide9.xml`.
+///
+/// Paths without a numeric `slideN.xml` file name sort after numbered slides.
+pub(super) fn sort_slide_paths(slide_paths: &mut [String]) {
+    slide_paths.sort_by_cached_key(|path| (slide_number_from_path(path).unwrap_or(u32::MAX), path.clone()));
+}
+
+fn slide_number_from_path(path: &str) -> Option<u32> {
+    let file_name = path.rsplit('/').next()?;
+    file_name.strip_prefix("slide")?.strip_suffix(".xml")?.parse().ok()
+}
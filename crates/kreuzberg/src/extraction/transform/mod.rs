@@ -249,6 +249,8 @@ mod tests {
             created_by: None,
             modified_by: None,
             pages: None,
+            outline: None,
+            document_kind: None,
             format: None,
             image_preprocessing: None,
             json_schema: None,
@@ -272,6 +274,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: Some(vec![
                 PageContent {
@@ -359,6 +365,7 @@ mod tests {
             ],
             markdown: "| Header1 | Header2 |\n| Cell1 | Cell2 |".to_string(),
             page_number: 1,
+            caption: None,
         };
 
         let image = ExtractedImage {
@@ -383,6 +390,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: Some(vec![PageContent {
                 page_number: 1,
@@ -431,6 +442,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -463,6 +478,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -79,7 +79,11 @@ fn build_archive_result(
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     }
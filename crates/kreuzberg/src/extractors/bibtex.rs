@@ -6,7 +6,7 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata};
+use crate::types::{ExtractionResult, ExtractionWarning, ExtractionWarningKind, Metadata};
 use ahash::AHashMap;
 use async_trait::async_trait;
 use std::borrow::Cow;
@@ -83,6 +83,7 @@ impl DocumentExtractor for BibtexExtractor {
         let mut years_set = HashSet::new();
         let mut entry_types_map: AHashMap<String, i32> = AHashMap::new();
         let mut formatted_entries = String::new();
+        let mut warnings = Vec::new();
 
         match Bibliography::parse(&bibtex_str) {
             Ok(bib) => {
@@ -124,10 +125,14 @@ impl DocumentExtractor for BibtexExtractor {
                     entries_vec.push(key);
                 }
             }
-            Err(_err) => {
+            Err(err) => {
                 #[cfg(feature = "otel")]
-                tracing::warn!("BibTeX parsing failed, returning raw content: {}", _err);
+                tracing::warn!("BibTeX parsing failed, returning raw content: {}", err);
                 formatted_entries = bibtex_str.to_string();
+                warnings.push(ExtractionWarning::new(
+                    ExtractionWarningKind::PartialContent,
+                    format!("BibTeX parsing failed, returning raw content: {}", err),
+                ));
             }
         }
 
@@ -174,6 +179,10 @@ impl DocumentExtractor for BibtexExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings,
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
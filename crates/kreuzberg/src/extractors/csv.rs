@@ -0,0 +1,358 @@
+//! CSV and TSV extractor.
+//!
+//! Parses delimited text into a [`Table`] and renders it as a markdown table for the
+//! content field. Quoted fields (including embedded delimiters, doubled quotes, and
+//! newlines) follow RFC 4180. The delimiter is detected from the data unless
+//! `ExtractionConfig::csv_delimiter` forces one.
+
+use super::SyncExtractor;
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::extraction::cells_to_markdown;
+use crate::plugins::{DocumentExtractor, Plugin};
+use crate::text::encoding::decode_text;
+use crate::types::{ExtractionResult, Metadata, Table};
+use async_trait::async_trait;
+use std::borrow::Cow;
+
+/// Delimiters considered during detection, in order of preference on ties.
+const CANDIDATE_DELIMITERS: [char; 4] = [',', '\t', ';', '|'];
+/// Number of records sampled when detecting the delimiter.
+const DETECTION_SAMPLE_RECORDS: usize = 20;
+
+/// CSV/TSV extractor.
+///
+/// Produces one table per file. The first row is used as the table header when it
+/// looks like one (non-empty, non-numeric, distinct values); otherwise the markdown
+/// gets generated `Column N` headers and every row is kept as data.
+pub struct CsvExtractor;
+
+impl CsvExtractor {
+    /// Create a new CSV extractor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CsvExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for CsvExtractor {
+    fn name(&self) -> &str {
+        "csv-extractor"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn description(&self) -> &str {
+        "Extracts CSV and TSV files into tables"
+    }
+
+    fn author(&self) -> &str {
+        "Kreuzberg Team"
+    }
+}
+
+impl SyncExtractor for CsvExtractor {
+    fn extract_sync(&self, content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+        let decoded = decode_text(content, config.encoding_override.as_deref());
+        let text = decoded.text.trim_start_matches('\u{FEFF}');
+
+        let delimiter = config.csv_delimiter.unwrap_or_else(|| {
+            if mime_type == "text/tab-separated-values" {
+                '\t'
+            } else {
+                detect_delimiter(text)
+            }
+        });
+
+        let mut rows: Vec<Vec<String>> = parse_records(text, delimiter)
+            .into_iter()
+            .filter(|row| row.iter().any(|cell| !cell.trim().is_empty()))
+            .collect();
+        let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(column_count, String::new());
+        }
+
+        let has_header = has_header_row(&rows);
+        let (content, tables) = if rows.is_empty() {
+            (String::new(), vec![])
+        } else {
+            // Markdown table cells cannot span lines, so embedded newlines become spaces there.
+            let mut markdown_rows: Vec<Vec<String>> = rows
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|cell| cell.split_whitespace().collect::<Vec<_>>().join(" "))
+                        .collect()
+                })
+                .collect();
+            if !has_header {
+                markdown_rows.insert(0, (1..=column_count).map(|i| format!("Column {}", i)).collect());
+            }
+            let markdown = cells_to_markdown(&markdown_rows);
+            let table = Table {
+                cells: rows.clone(),
+                markdown: markdown.clone(),
+                page_number: 1,
+                caption: None,
+            };
+            (markdown.trim_end().to_string(), vec![table])
+        };
+
+        let mut metadata = Metadata::default();
+        let data_rows = rows.len() - usize::from(has_header);
+        for (key, value) in [
+            ("delimiter", serde_json::json!(delimiter.to_string())),
+            ("row_count", serde_json::json!(data_rows)),
+            ("column_count", serde_json::json!(column_count)),
+            ("has_header", serde_json::json!(has_header)),
+        ] {
+            metadata.additional.insert(Cow::Borrowed(key), value);
+        }
+
+        Ok(ExtractionResult {
+            content,
+            mime_type: mime_type.to_string().into(),
+            metadata,
+            pages: None,
+            tables,
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            links: vec![],
+            warnings: decoded.warnings(),
+            partial: false,
+            elements: None,
+            layout: None,
+            djot_content: None,
+        })
+    }
+}
+
+#[async_trait]
+impl DocumentExtractor for CsvExtractor {
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, content, config),
+        fields(
+            extractor.name = self.name(),
+            content.size_bytes = content.len(),
+        )
+    ))]
+    async fn extract_bytes(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        self.extract_sync(content, mime_type, config)
+    }
+
+    fn supported_mime_types(&self) -> &[&str] {
+        &["text/csv", "text/tab-separated-values"]
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+
+    fn as_sync_extractor(&self) -> Option<&dyn crate::extractors::SyncExtractor> {
+        Some(self)
+    }
+}
+
+/// Split delimited text into records of fields.
+///
+/// Fields wrapped in double quotes may contain the delimiter, newlines, and doubled
+/// quotes (`""`). Records end at `\n`, `\r\n`, or `\r` outside quotes.
+fn parse_records(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.next_if_eq(&'"').is_some() {
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            '\r' | '\n' => {
+                if c == '\r' {
+                    chars.next_if_eq(&'\n');
+                }
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+}
+
+/// Pick the candidate delimiter that splits the leading records most consistently.
+///
+/// A candidate scores one point per sampled record whose field count matches the most
+/// common count, provided that count is above one. Falls back to a comma.
+fn detect_delimiter(text: &str) -> char {
+    let mut best = (',', 0usize);
+
+    for delimiter in CANDIDATE_DELIMITERS {
+        let counts: Vec<usize> = parse_records(text, delimiter)
+            .iter()
+            .filter(|record| record.iter().any(|field| !field.is_empty()))
+            .take(DETECTION_SAMPLE_RECORDS)
+            .map(Vec::len)
+            .collect();
+
+        let Some(mode) = counts
+            .iter()
+            .copied()
+            .max_by_key(|&count| (counts.iter().filter(|&&c| c == count).count(), count))
+        else {
+            continue;
+        };
+        if mode < 2 {
+            continue;
+        }
+
+        let score = counts.iter().filter(|&&count| count == mode).count();
+        if score > best.1 {
+            best = (delimiter, score);
+        }
+    }
+
+    best.0
+}
+
+/// Whether the first row looks like a header: every cell filled, none numeric, all distinct.
+fn has_header_row(rows: &[Vec<String>]) -> bool {
+    let Some(first) = rows.first() else {
+        return false;
+    };
+
+    let mut seen = std::collections::HashSet::new();
+    first.iter().all(|cell| {
+        let cell = cell.trim();
+        !cell.is_empty() && cell.parse::<f64>().is_err() && seen.insert(cell)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn extract(content: &[u8], mime_type: &str, config: &ExtractionConfig) -> ExtractionResult {
+        CsvExtractor::new()
+            .extract_bytes(content, mime_type, config)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_quoted_comma_fields() {
+        let content = b"\xEF\xBB\xBFName,Note\r\n\"Smith, John\",\"said \"\"hi\"\"\nthen left\"\r\nDoe,plain\r\n";
+        let result = extract(content, "text/csv", &ExtractionConfig::default()).await;
+
+        assert_eq!(result.tables.len(), 1);
+        assert_eq!(
+            result.tables[0].cells,
+            vec![
+                vec!["Name".to_string(), "Note".to_string()],
+                vec!["Smith, John".to_string(), "said \"hi\"\nthen left".to_string()],
+                vec!["Doe".to_string(), "plain".to_string()],
+            ]
+        );
+        assert!(result.content.starts_with("| Name | Note |"));
+        assert!(result.content.contains("| Smith, John | said \"hi\" then left |"));
+        assert_eq!(
+            result.metadata.additional.get("has_header"),
+            Some(&serde_json::json!(true))
+        );
+        assert_eq!(result.metadata.additional.get("row_count"), Some(&serde_json::json!(2)));
+    }
+
+    #[tokio::test]
+    async fn test_semicolon_delimited() {
+        let content = b"Name;Price\nWidget;1,50\nGadget;2,75\n";
+        let result = extract(content, "text/csv", &ExtractionConfig::default()).await;
+
+        assert_eq!(
+            result.metadata.additional.get("delimiter"),
+            Some(&serde_json::json!(";"))
+        );
+        assert_eq!(
+            result.tables[0].cells[1],
+            vec!["Widget".to_string(), "1,50".to_string()]
+        );
+        assert!(result.content.contains("| Gadget | 2,75 |"));
+    }
+
+    #[tokio::test]
+    async fn test_forced_delimiter_and_missing_header() {
+        let config = ExtractionConfig {
+            csv_delimiter: Some('|'),
+            ..Default::default()
+        };
+        let result = extract(b"1|a,b\n2|c,d", "text/csv", &config).await;
+
+        assert_eq!(result.tables[0].cells[0], vec!["1".to_string(), "a,b".to_string()]);
+        assert!(result.content.starts_with("| Column 1 | Column 2 |"));
+        assert_eq!(
+            result.metadata.additional.get("has_header"),
+            Some(&serde_json::json!(false))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tsv_uses_tab() {
+        let result = extract(
+            b"a,b\tc\n1,2\t3",
+            "text/tab-separated-values",
+            &ExtractionConfig::default(),
+        )
+        .await;
+
+        assert_eq!(result.tables[0].cells[0], vec!["a,b".to_string(), "c".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_empty_input() {
+        let result = extract(b"", "text/csv", &ExtractionConfig::default()).await;
+
+        assert!(result.content.is_empty());
+        assert!(result.tables.is_empty());
+    }
+}
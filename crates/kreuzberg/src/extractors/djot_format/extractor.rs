@@ -103,6 +103,7 @@ impl DocumentExtractor for DjotExtractor {
 
         // Extract complete djot content with all features
         let djot_content = extract_complete_djot_content(&events, metadata.clone(), tables.clone());
+        let links = djot_content.extracted_links();
 
         Ok(ExtractionResult {
             content: extracted_text,
@@ -112,7 +113,11 @@ impl DocumentExtractor for DjotExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links,
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: Some(djot_content),
             elements: None,
         })
@@ -130,6 +135,7 @@ impl DocumentExtractor for DjotExtractor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::LinkKind;
 
     #[test]
     fn test_djot_extractor_creation() {
@@ -169,5 +175,119 @@ mod tests {
         assert!(result.content.contains("This is a paragraph"));
         assert!(result.content.contains("bold"));
         assert!(result.content.contains("italic"));
+        assert!(result.links.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_extract_djot_links_and_images() {
+        let content = b"See [the docs](https://example.com/docs) for details.\n\n![A diagram](diagram.png)";
+        let extractor = DjotExtractor::new();
+        let config = ExtractionConfig::default();
+
+        let result = extractor.extract_bytes(content, "text/djot", &config).await.unwrap();
+
+        let link = result
+            .links
+            .iter()
+            .find(|link| link.kind == LinkKind::Hyperlink)
+            .expect("hyperlink should be extracted");
+        assert_eq!(link.url, "https://example.com/docs");
+        assert_eq!(link.text, "the docs");
+
+        let image = result
+            .links
+            .iter()
+            .find(|link| link.kind == LinkKind::Image)
+            .expect("image reference should be extracted");
+        assert_eq!(image.url, "diagram.png");
+        assert_eq!(image.text, "A diagram");
+    }
+
+    #[tokio::test]
+    async fn test_extract_djot_reference_link_resolved() {
+        let content = b"Read [the docs][docs] first.\n\n[docs]: https://example.com/docs\n";
+        let extractor = DjotExtractor::new();
+        let config = ExtractionConfig::default();
+
+        let result = extractor.extract_bytes(content, "text/djot", &config).await.unwrap();
+        let djot_content = result.djot_content.expect("djot content should be present");
+
+        assert_eq!(djot_content.links.len(), 1);
+        assert_eq!(djot_content.links[0].url, "https://example.com/docs");
+        assert_eq!(djot_content.links[0].text, "the docs");
+
+        let link_element = djot_content
+            .blocks
+            .iter()
+            .flat_map(|block| block.inline_content.iter())
+            .find(|element| element.element_type == crate::types::InlineType::Link)
+            .expect("link inline element should be present");
+        let meta = link_element.metadata.as_ref().unwrap();
+        assert_eq!(meta.get("href").map(String::as_str), Some("https://example.com/docs"));
+        assert!(!meta.contains_key("unresolved"));
+    }
+
+    #[tokio::test]
+    async fn test_extract_djot_reference_link_unresolved() {
+        let content = b"Read [the docs][missing] first.";
+        let extractor = DjotExtractor::new();
+        let config = ExtractionConfig::default();
+
+        let result = extractor.extract_bytes(content, "text/djot", &config).await.unwrap();
+        let djot_content = result.djot_content.expect("djot content should be present");
+
+        assert_eq!(djot_content.links.len(), 1);
+        assert_eq!(djot_content.links[0].url, "missing");
+
+        let link_element = djot_content
+            .blocks
+            .iter()
+            .flat_map(|block| block.inline_content.iter())
+            .find(|element| element.element_type == crate::types::InlineType::Link)
+            .expect("link inline element should be present");
+        let meta = link_element.metadata.as_ref().unwrap();
+        assert_eq!(meta.get("href").map(String::as_str), Some("missing"));
+        assert_eq!(meta.get("unresolved").map(String::as_str), Some("true"));
+    }
+
+    #[tokio::test]
+    async fn test_extract_djot_inline_math_delimiters() {
+        let content = b"Energy is $`E = mc^2` here.";
+        let extractor = DjotExtractor::new();
+        let config = ExtractionConfig::default();
+
+        let result = extractor.extract_bytes(content, "text/djot", &config).await.unwrap();
+        assert!(result.content.contains("$E = mc^2$"));
+
+        let markup = crate::extractors::djot_format::djot_content_to_djot(result.djot_content.as_ref().unwrap());
+        assert!(markup.contains("$E = mc^2$"));
+        assert!(!markup.contains("$$"));
+    }
+
+    #[tokio::test]
+    async fn test_extract_djot_display_math_delimiters() {
+        let content = b"$$`\\int_0^1 x \\, dx`";
+        let extractor = DjotExtractor::new();
+        let config = ExtractionConfig::default();
+
+        let result = extractor.extract_bytes(content, "text/djot", &config).await.unwrap();
+        assert!(result.content.contains("$$\\int_0^1 x \\, dx$$"));
+
+        let markup = crate::extractors::djot_format::djot_content_to_djot(result.djot_content.as_ref().unwrap());
+        assert!(markup.contains("$$\\int_0^1 x \\, dx$$"));
+    }
+
+    #[tokio::test]
+    async fn test_extract_djot_table_caption() {
+        let content = b"| Quarter | Revenue |\n|---------|---------|\n| Q1      | 100     |\n\n^ Quarterly results\n";
+        let extractor = DjotExtractor::new();
+        let config = ExtractionConfig::default();
+
+        let result = extractor.extract_bytes(content, "text/djot", &config).await.unwrap();
+
+        assert_eq!(result.tables.len(), 1);
+        assert_eq!(result.tables[0].caption.as_deref(), Some("Quarterly results"));
+        assert!(result.content.contains("Revenue"));
+        assert!(!result.content.contains("Quarterly results"));
     }
 }
@@ -14,6 +14,8 @@ use super::text_extraction::extract_text_from_events;
 use crate::extractors::djot_format::attributes::parse_jotdown_attributes;
 use crate::types::{Attributes, DjotContent, DjotImage, DjotLink, FormattedBlock};
 use jotdown::{Container, Event};
+use std::collections::HashMap;
+
 /// Extract complete djot content with 100% feature extraction.
 ///
 /// Processes ALL djot events to build a rich DjotContent structure including:
@@ -35,6 +37,7 @@ pub fn extract_complete_djot_content(
     tables: Vec<crate::types::Table>,
 ) -> DjotContent {
     let plain_text = extract_text_from_events(events);
+    let definitions = collect_link_definitions(events);
 
     let mut blocks = Vec::new();
     let mut images = Vec::new();
@@ -55,6 +58,7 @@ pub fn extract_complete_djot_content(
                     &mut images,
                     &mut links,
                     &mut footnotes,
+                    &definitions,
                 );
             }
             Event::End(container) => {
@@ -65,10 +69,13 @@ pub fn extract_complete_djot_content(
                     &mut images,
                     &mut links,
                     &mut footnotes,
+                    &definitions,
                 );
             }
             Event::Str(s) => {
-                if state.in_code_block || state.in_raw_block {
+                if state.in_caption {
+                    state.caption_text.push_str(s);
+                } else if state.in_code_block || state.in_raw_block {
                     state.code_content.push_str(s);
                 } else if state.in_math {
                     state.math_content.push_str(s);
@@ -166,7 +173,58 @@ pub fn extract_complete_djot_content(
     }
 }
 
+/// Collect link definitions (`[label]: url`) keyed by label.
+///
+/// Runs as a first pass so reference-style links can be resolved regardless of
+/// where their definition appears in the document.
+fn collect_link_definitions(events: &[Event]) -> HashMap<String, String> {
+    let mut definitions = HashMap::new();
+    let mut current: Option<(String, String)> = None;
+
+    for event in events {
+        match event {
+            Event::Start(Container::LinkDefinition { label }, _) => {
+                current = Some((label.to_string(), String::new()));
+            }
+            Event::Str(s) => {
+                if let Some((_, url)) = current.as_mut() {
+                    url.push_str(s);
+                }
+            }
+            Event::End(Container::LinkDefinition { .. }) => {
+                if let Some((label, url)) = current.take() {
+                    definitions.insert(label, url.trim().to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    definitions
+}
+
+/// Handle end of a caption.
+///
+/// Table captions are already stored on the `Table` by `extract_tables_from_events`;
+/// any other caption belongs to the nearest preceding image.
+fn handle_caption_end(state: &mut ExtractionState, images: &mut [DjotImage]) {
+    state.in_caption = false;
+    let caption = std::mem::take(&mut state.caption_text);
+    let caption = caption.trim();
+
+    if state.in_table || caption.is_empty() {
+        return;
+    }
+
+    if let Some(image) = images.last_mut()
+        && image.caption.is_none()
+    {
+        image.caption = Some(caption.to_string());
+    }
+}
+
 /// Handle start of a container event.
+#[allow(clippy::too_many_arguments)]
 fn handle_start_event(
     state: &mut ExtractionState,
     container: &Container,
@@ -175,6 +233,7 @@ fn handle_start_event(
     images: &mut Vec<DjotImage>,
     links: &mut Vec<DjotLink>,
     footnotes: &mut Vec<crate::types::Footnote>,
+    definitions: &HashMap<String, String>,
 ) {
     // Parse attributes from jotdown's Attributes type
     let parsed_attrs = if attrs.is_empty() {
@@ -189,17 +248,25 @@ fn handle_start_event(
     }
 
     // Try inline handlers
-    if handle_inline_start(state, container, parsed_attrs, images, links) {
+    if handle_inline_start(state, container, parsed_attrs, images, links, definitions) {
         return;
     }
 
     // Handle remaining containers (tables, link definitions, etc.)
     match container {
-        Container::Table | Container::TableRow { .. } | Container::TableCell { .. } | Container::Caption => {
+        Container::Table => {
+            // Tables are extracted separately
+            state.in_table = true;
+        }
+        Container::Caption => {
+            state.in_caption = true;
+            state.caption_text.clear();
+        }
+        Container::TableRow { .. } | Container::TableCell { .. } => {
             // Tables are extracted separately
         }
         Container::LinkDefinition { .. } => {
-            // Link definitions are resolved by jotdown, not needed in output
+            // Link definitions are collected up front by `collect_link_definitions`
         }
         _ => {}
     }
@@ -213,6 +280,7 @@ fn handle_end_event(
     images: &mut [DjotImage],
     links: &mut [DjotLink],
     footnotes: &mut [crate::types::Footnote],
+    definitions: &HashMap<String, String>,
 ) {
     // Check if it's a block container
     if handle_block_end(state, container) {
@@ -228,8 +296,8 @@ fn handle_end_event(
         Container::Math { display } => {
             handle_math_end(state, *display);
         }
-        Container::Link(url, _) => {
-            handle_link_end(state, url, links);
+        Container::Link(url, link_type) => {
+            handle_link_end(state, url, link_type, definitions, links);
         }
         Container::Image(src, _) => {
             handle_image_end(state, src, images);
@@ -244,7 +312,14 @@ fn handle_end_event(
 
     // Handle remaining containers (tables, link definitions, etc.)
     match container {
-        Container::Table | Container::TableRow { .. } | Container::TableCell { .. } | Container::Caption => {
+        Container::Table => {
+            // Tables are handled separately
+            state.in_table = false;
+        }
+        Container::Caption => {
+            handle_caption_end(state, images);
+        }
+        Container::TableRow { .. } | Container::TableCell { .. } => {
             // Tables are handled separately
         }
         Container::LinkDefinition { .. } => {
@@ -2,7 +2,29 @@
 
 use super::state::ExtractionState;
 use crate::types::{DjotImage, DjotLink, InlineElement, InlineType};
-use jotdown::Container;
+use jotdown::{Container, LinkType, SpanLinkType};
+use std::collections::HashMap;
+
+/// Resolve a link target against the document's link definitions.
+///
+/// Returns the URL to emit and whether the link is a reference without a matching
+/// definition. Unresolved references keep their raw reference text as the URL.
+pub(super) fn resolve_link_url(
+    url: &str,
+    link_type: &LinkType,
+    definitions: &HashMap<String, String>,
+) -> (String, bool) {
+    match link_type {
+        LinkType::Span(SpanLinkType::Reference | SpanLinkType::Unresolved) => match definitions.get(url) {
+            Some(resolved) => (resolved.clone(), false),
+            None => (
+                url.to_string(),
+                matches!(link_type, LinkType::Span(SpanLinkType::Unresolved)),
+            ),
+        },
+        _ => (url.to_string(), false),
+    }
+}
 
 /// Handle start of inline elements.
 pub(super) fn handle_inline_start(
@@ -11,6 +33,7 @@ pub(super) fn handle_inline_start(
     parsed_attrs: Option<crate::types::Attributes>,
     images: &mut Vec<DjotImage>,
     links: &mut Vec<DjotLink>,
+    definitions: &HashMap<String, String>,
 ) -> bool {
     match container {
         Container::Math { display } => {
@@ -71,10 +94,11 @@ pub(super) fn handle_inline_start(
             state.flush_text();
             true
         }
-        Container::Link(url, _link_type) => {
+        Container::Link(url, link_type) => {
             state.inline_type_stack.push(InlineType::Link);
+            let (url, _) = resolve_link_url(url, link_type, definitions);
             links.push(DjotLink {
-                url: url.to_string(),
+                url,
                 text: String::new(),
                 title: None,
                 attributes: parsed_attrs,
@@ -90,6 +114,7 @@ pub(super) fn handle_inline_start(
                 alt: String::new(),
                 title: None,
                 attributes: parsed_attrs,
+                caption: None,
             });
             state.current_image_index = Some(images.len() - 1);
             state.flush_text();
@@ -158,7 +183,13 @@ pub(super) fn finalize_inline_element(state: &mut ExtractionState, container: &C
 }
 
 /// Handle end of link element.
-pub(super) fn handle_link_end(state: &mut ExtractionState, url: &str, links: &mut [DjotLink]) {
+pub(super) fn handle_link_end(
+    state: &mut ExtractionState,
+    url: &str,
+    link_type: &LinkType,
+    definitions: &HashMap<String, String>,
+    links: &mut [DjotLink],
+) {
     if let Some(idx) = state.current_link_index.take() {
         let text = std::mem::take(&mut state.current_text);
         if let Some(link) = links.get_mut(idx) {
@@ -166,8 +197,12 @@ pub(super) fn handle_link_end(state: &mut ExtractionState, url: &str, links: &mu
         }
         state.inline_type_stack.pop();
 
-        let mut meta: std::collections::HashMap<String, String> = std::collections::HashMap::new();
-        meta.insert("href".to_string(), url.to_string());
+        let (href, unresolved) = resolve_link_url(url, link_type, definitions);
+        let mut meta: HashMap<String, String> = HashMap::new();
+        meta.insert("href".to_string(), href);
+        if unresolved {
+            meta.insert("unresolved".to_string(), "true".to_string());
+        }
 
         state.current_inline_elements.push(InlineElement {
             element_type: InlineType::Link,
@@ -20,6 +20,9 @@ pub(super) struct ExtractionState {
     pub in_raw_block: bool,
     pub raw_format: Option<String>,
     pub current_inline_elements: Vec<InlineElement>,
+    pub in_table: bool,
+    pub in_caption: bool,
+    pub caption_text: String, // Accumulator for captions
 }
 
 impl ExtractionState {
@@ -40,6 +43,9 @@ impl ExtractionState {
             in_raw_block: false,
             raw_format: None,
             current_inline_elements: Vec::new(),
+            in_table: false,
+            in_caption: false,
+            caption_text: String::new(),
         }
     }
 
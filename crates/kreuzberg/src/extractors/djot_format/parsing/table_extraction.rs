@@ -9,12 +9,16 @@ use jotdown::{Container, Event};
 ///
 /// Parses table events and extracts table data as a Vec<Vec<String>>,
 /// converting each table to markdown representation for storage.
+/// Table captions (`^ caption`) are stored on the table they belong to.
 pub fn extract_tables_from_events(events: &[Event]) -> Vec<Table> {
     let mut tables = Vec::new();
     let mut current_table: Option<(Vec<Vec<String>>, usize)> = None;
     let mut current_row: Vec<String> = Vec::new();
     let mut current_cell = String::new();
     let mut in_table_cell = false;
+    let mut current_caption: Option<String> = None;
+    let mut caption_text = String::new();
+    let mut in_caption = false;
     let mut table_index = 0;
 
     for event in events {
@@ -32,6 +36,20 @@ pub fn extract_tables_from_events(events: &[Event]) -> Vec<Table> {
             Event::Str(s) if in_table_cell => {
                 current_cell.push_str(s.as_ref());
             }
+            Event::Start(Container::Caption, _) => {
+                caption_text = String::new();
+                in_caption = true;
+            }
+            Event::Str(s) if in_caption => {
+                caption_text.push_str(s.as_ref());
+            }
+            Event::End(Container::Caption) => {
+                in_caption = false;
+                let caption = caption_text.trim();
+                if current_table.is_some() && !caption.is_empty() {
+                    current_caption = Some(caption.to_string());
+                }
+            }
             Event::End(Container::TableCell { .. }) => {
                 if in_table_cell {
                     current_row.push(current_cell.trim().to_string());
@@ -48,6 +66,7 @@ pub fn extract_tables_from_events(events: &[Event]) -> Vec<Table> {
                 current_row = Vec::new();
             }
             Event::End(Container::Table) => {
+                let caption = current_caption.take();
                 if let Some((cells, idx)) = current_table.take()
                     && !cells.is_empty()
                 {
@@ -56,6 +75,7 @@ pub fn extract_tables_from_events(events: &[Event]) -> Vec<Table> {
                         cells,
                         markdown,
                         page_number: idx + 1,
+                        caption,
                     });
                     table_index += 1;
                 }
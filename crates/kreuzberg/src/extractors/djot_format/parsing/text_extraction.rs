@@ -2,7 +2,7 @@
 //!
 //! Handles parsing of jotdown events into plain text.
 
-use jotdown::Event;
+use jotdown::{Container, Event};
 
 /// Extract plain text from Djot events.
 ///
@@ -11,11 +11,22 @@ use jotdown::Event;
 /// - Line breaks (soft, hard, blank)
 /// - Smart punctuation (quotes, dashes, ellipsis)
 /// - Special symbols and footnote references
+/// - Math as LaTeX, delimited by `$...$` (inline) or `$$...$$` (display)
+///
+/// Captions are skipped; they are stored on the table or image they describe.
 pub fn extract_text_from_events(events: &[Event]) -> String {
     let mut text = String::new();
+    let mut in_caption = false;
 
     for event in events {
         match event {
+            Event::Start(Container::Caption, _) => {
+                in_caption = true;
+            }
+            Event::End(Container::Caption) => {
+                in_caption = false;
+            }
+            _ if in_caption => {}
             Event::Str(s) => {
                 text.push_str(s.as_ref());
             }
@@ -53,9 +64,17 @@ pub fn extract_text_from_events(events: &[Event]) -> String {
             Event::ThematicBreak(_) => {
                 text.push_str("\n---\n");
             }
+            Event::Start(Container::Math { display }, _) | Event::End(Container::Math { display }) => {
+                text.push_str(math_delimiter(*display));
+            }
             Event::Start(_, _) | Event::End(_) | Event::Escape | Event::Attributes(_) => {}
         }
     }
 
     text
 }
+
+/// LaTeX delimiter for inline (`$`) or display (`$$`) math.
+fn math_delimiter(display: bool) -> &'static str {
+    if display { "$$" } else { "$" }
+}
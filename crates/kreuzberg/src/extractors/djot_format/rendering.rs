@@ -345,9 +345,15 @@ pub fn render_inline_content(output: &mut String, elements: &[InlineElement]) {
                 }
             }
             InlineType::Math => {
-                output.push('$');
+                let display = elem
+                    .metadata
+                    .as_ref()
+                    .and_then(|m| m.get("display"))
+                    .is_some_and(|d| d == "true");
+                let delimiter = if display { "$$" } else { "$" };
+                output.push_str(delimiter);
                 output.push_str(&elem.content);
-                output.push('$');
+                output.push_str(delimiter);
             }
             InlineType::RawInline => {
                 // Raw inline uses `content`{=format}
@@ -242,6 +242,7 @@ fn parse_docbook_single_pass(content: &str) -> Result<DocBookParseResult> {
                                 cells: current_table.clone(),
                                 markdown,
                                 page_number: table_index + 1,
+                                caption: None,
                             });
                             table_index += 1;
                             current_table.clear();
@@ -404,7 +405,11 @@ impl DocumentExtractor for DocbookExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         })
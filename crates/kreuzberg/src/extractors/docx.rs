@@ -94,6 +94,7 @@ fn convert_docx_table_to_table(docx_table: &crate::extraction::docx::parser::Tab
         cells,
         markdown,
         page_number: table_index + 1,
+        caption: None,
     }
 }
 
@@ -175,6 +176,10 @@ impl DocumentExtractor for DocxExtractor {
         let mut parsed_keywords: Option<Vec<String>> = None;
 
         if let Ok(core) = office_metadata::extract_core_properties(&mut archive) {
+            let keywords = core.keyword_list();
+            if !keywords.is_empty() {
+                parsed_keywords = Some(keywords);
+            }
             if let Some(title) = core.title {
                 metadata_map.insert(Cow::Borrowed("title"), serde_json::Value::String(title));
             }
@@ -188,16 +193,6 @@ impl DocumentExtractor for DocxExtractor {
             if let Some(subject) = core.subject {
                 metadata_map.insert(Cow::Borrowed("subject"), serde_json::Value::String(subject));
             }
-            if let Some(keywords) = core.keywords {
-                // Parse comma-separated keywords into Vec<String>
-                parsed_keywords = Some(
-                    keywords
-                        .split(',')
-                        .map(|s| s.trim().to_string())
-                        .filter(|s| !s.is_empty())
-                        .collect(),
-                );
-            }
             if let Some(description) = core.description {
                 metadata_map.insert(Cow::Borrowed("description"), serde_json::Value::String(description));
             }
@@ -309,6 +304,10 @@ impl DocumentExtractor for DocxExtractor {
             detected_languages: None,
             chunks: None,
             images: Some(vec![]),
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -135,6 +135,10 @@ impl DocumentExtractor for EpubExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
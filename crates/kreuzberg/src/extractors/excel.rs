@@ -3,7 +3,7 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExcelMetadata, ExtractionResult, Metadata, Table};
+use crate::types::{ExcelMetadata, ExtractionResult, ExtractionWarning, ExtractionWarningKind, Metadata, Table};
 use ahash::AHashMap;
 use async_trait::async_trait;
 use std::borrow::Cow;
@@ -25,6 +25,20 @@ impl ExcelExtractor {
         Self
     }
 
+    /// Report sheets that could not be read as partial-content warnings.
+    fn skipped_sheet_warnings(workbook: &crate::types::ExcelWorkbook) -> Vec<ExtractionWarning> {
+        workbook
+            .skipped_sheets
+            .iter()
+            .map(|name| {
+                ExtractionWarning::new(
+                    ExtractionWarningKind::PartialContent,
+                    format!("Sheet '{}' could not be read and was skipped", name),
+                )
+            })
+            .collect()
+    }
+
     /// Convert Excel workbook sheets to Table structs.
     ///
     /// Each sheet becomes a table with the first row as headers,
@@ -46,6 +60,7 @@ impl ExcelExtractor {
                     cells: cells.clone(),
                     markdown: sheet.markdown.clone(),
                     page_number: sheet_index + 1,
+                    caption: Some(sheet.name.clone()),
                 });
             }
         }
@@ -115,6 +130,7 @@ impl DocumentExtractor for ExcelExtractor {
 
         let markdown = crate::extraction::excel::excel_to_markdown(&workbook);
         let tables = Self::sheets_to_tables(&workbook);
+        let warnings = Self::skipped_sheet_warnings(&workbook);
 
         let sheet_names: Vec<String> = workbook.sheets.iter().map(|s| s.name.clone()).collect();
         let excel_metadata = ExcelMetadata {
@@ -142,6 +158,10 @@ impl DocumentExtractor for ExcelExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings,
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -161,6 +181,7 @@ impl DocumentExtractor for ExcelExtractor {
         let workbook = crate::extraction::excel::read_excel_file(path_str)?;
         let markdown = crate::extraction::excel::excel_to_markdown(&workbook);
         let tables = Self::sheets_to_tables(&workbook);
+        let warnings = Self::skipped_sheet_warnings(&workbook);
 
         let sheet_names: Vec<String> = workbook.sheets.iter().map(|s| s.name.clone()).collect();
         let excel_metadata = ExcelMetadata {
@@ -188,6 +209,10 @@ impl DocumentExtractor for ExcelExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings,
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -260,12 +285,14 @@ mod tests {
         let workbook = crate::types::ExcelWorkbook {
             sheets: vec![sheet],
             metadata: HashMap::new(),
+            skipped_sheets: vec![],
         };
 
         let tables = ExcelExtractor::sheets_to_tables(&workbook);
 
         assert_eq!(tables.len(), 1);
         assert_eq!(tables[0].page_number, 1);
+        assert_eq!(tables[0].caption.as_deref(), Some("TestSheet"));
         assert_eq!(tables[0].cells.len(), 3);
         assert_eq!(tables[0].cells[0], vec!["Name", "Age", "City"]);
         assert_eq!(tables[0].cells[1], vec!["Alice", "30", "NYC"]);
@@ -289,6 +316,7 @@ mod tests {
         let workbook = crate::types::ExcelWorkbook {
             sheets: vec![sheet],
             metadata: HashMap::new(),
+            skipped_sheets: vec![],
         };
 
         let tables = ExcelExtractor::sheets_to_tables(&workbook);
@@ -339,6 +367,7 @@ mod tests {
         let workbook = crate::types::ExcelWorkbook {
             sheets: vec![sheet1, sheet2],
             metadata: HashMap::new(),
+            skipped_sheets: vec![],
         };
 
         let tables = ExcelExtractor::sheets_to_tables(&workbook);
@@ -376,6 +405,7 @@ mod tests {
         let workbook = crate::types::ExcelWorkbook {
             sheets: vec![sheet],
             metadata: HashMap::new(),
+            skipped_sheets: vec![],
         };
 
         let tables = ExcelExtractor::sheets_to_tables(&workbook);
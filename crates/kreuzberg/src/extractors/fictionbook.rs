@@ -440,6 +440,10 @@ impl DocumentExtractor for FictionBookExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
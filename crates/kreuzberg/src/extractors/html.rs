@@ -4,7 +4,7 @@ use crate::Result;
 use crate::core::config::{ExtractionConfig, OutputFormat};
 use crate::extractors::SyncExtractor;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::text::utf8_validation;
+use crate::text::encoding::decode_html;
 use crate::types::{ExtractionResult, Metadata, Table};
 use async_trait::async_trait;
 #[cfg(feature = "tokio-runtime")]
@@ -63,6 +63,7 @@ fn parse_markdown_tables(markdown: &str) -> Vec<Table> {
                 cells,
                 markdown: markdown_table,
                 page_number: table_index + 1,
+                caption: None,
             });
             table_index += 1;
             i = end_idx;
@@ -198,12 +199,10 @@ impl Plugin for HtmlExtractor {
 
 impl SyncExtractor for HtmlExtractor {
     fn extract_sync(&self, content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
-        let html = utf8_validation::from_utf8(content)
-            .map(|s| s.to_string())
-            .unwrap_or_else(|_| String::from_utf8_lossy(content).to_string());
+        let decoded = decode_html(content, config.encoding_override.as_deref());
 
         let (content_text, html_metadata) = crate::extraction::html::convert_html_to_markdown_with_metadata(
-            &html,
+            &decoded.text,
             config.html_options.clone(),
             Some(config.output_format),
         )?;
@@ -217,18 +216,24 @@ impl SyncExtractor for HtmlExtractor {
             _ => mime_type, // Preserve original mime_type for other formats
         };
 
+        let metadata = Metadata {
+            format: html_metadata.map(|m| crate::types::FormatMetadata::Html(Box::new(m))),
+            ..Default::default()
+        };
+
         Ok(ExtractionResult {
             content: content_text,
             mime_type: result_mime_type.to_string().into(),
-            metadata: Metadata {
-                format: html_metadata.map(|m| crate::types::FormatMetadata::Html(Box::new(m))),
-                ..Default::default()
-            },
+            metadata,
             pages: None,
             tables,
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: decoded.warnings(),
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -427,6 +432,26 @@ mod tests {
         assert_eq!(table.cells[2], vec!["Bob", "25"]);
     }
 
+    #[cfg(feature = "quality")]
+    #[tokio::test]
+    async fn test_html_extractor_uses_meta_charset() {
+        let html = b"<html><head><meta charset=\"windows-1252\"></head>\
+                     <body><p>\x93Caf\xE9\x94 menu</p></body></html>";
+
+        let extractor = HtmlExtractor::new();
+        let config = ExtractionConfig::default();
+        let result = extractor.extract_bytes(html, "text/html", &config).await.unwrap();
+
+        assert!(result.content.contains("\u{201C}Caf\u{E9}\u{201D} menu"));
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|warning| warning.kind == crate::types::ExtractionWarningKind::Encoding
+                    && warning.message.contains("windows-1252"))
+        );
+    }
+
     #[tokio::test]
     async fn test_html_extractor_with_djot_output() {
         let html = r#"
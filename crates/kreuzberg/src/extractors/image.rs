@@ -45,8 +45,16 @@ impl ImageExtractor {
         };
 
         // Thread output_format from ExtractionConfig to OcrConfig
-        let mut ocr_config_with_format = ocr_config.clone();
+        let mut ocr_config_with_format = if config.detect_image_tables {
+            ocr_config.with_table_detection()
+        } else {
+            ocr_config.clone()
+        };
         ocr_config_with_format.output_format = Some(config.output_format);
+        if ocr_config_with_format.is_auto_language() {
+            let language = backend.resolve_language(content, &ocr_config_with_format).await?;
+            ocr_config_with_format = ocr_config_with_format.with_language(&language);
+        }
 
         let ocr_result = backend.process_image(content, &ocr_config_with_format).await?;
 
@@ -151,6 +159,10 @@ impl DocumentExtractor for ImageExtractor {
                     detected_languages: None,
                     chunks: None,
                     images: None,
+                    links: vec![],
+                    warnings: vec![],
+                    partial: false,
+                    layout: None,
                     djot_content: None,
                 });
             }
@@ -171,6 +183,10 @@ impl DocumentExtractor for ImageExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -343,6 +343,10 @@ impl DocumentExtractor for JupyterExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -101,6 +101,10 @@ impl DocumentExtractor for LatexExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -136,6 +136,7 @@ impl MarkdownExtractor {
                             cells,
                             markdown,
                             page_number: idx + 1,
+                            caption: None,
                         });
                         table_index += 1;
                     }
@@ -231,6 +232,10 @@ impl DocumentExtractor for MarkdownExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -61,6 +61,7 @@ pub trait SyncExtractor {
     fn extract_sync(&self, content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult>;
 }
 
+pub mod csv;
 pub mod structured;
 pub mod text;
 
@@ -139,6 +140,7 @@ pub mod xml;
 #[cfg(feature = "xml")]
 pub mod docbook;
 
+pub use csv::CsvExtractor;
 pub use structured::StructuredExtractor;
 pub use text::{MarkdownExtractor, PlainTextExtractor};
 
@@ -271,6 +273,7 @@ pub fn register_default_extractors() -> Result<()> {
         .map_err(|e| crate::KreuzbergError::Other(format!("Document extractor registry lock poisoned: {}", e)))?;
 
     registry.register(Arc::new(PlainTextExtractor::new()))?;
+    registry.register(Arc::new(CsvExtractor::new()))?;
     registry.register(Arc::new(MarkdownExtractor::new()))?;
     registry.register(Arc::new(StructuredExtractor::new()))?;
 
@@ -348,8 +351,9 @@ mod tests {
         let extractor_names = reg.list();
 
         #[allow(unused_mut)]
-        let mut expected_count = 4; // plain-text, markdown, structured, djot
+        let mut expected_count = 5; // plain-text, csv, markdown, structured, djot
         assert!(extractor_names.contains(&"plain-text-extractor".to_string()));
+        assert!(extractor_names.contains(&"csv-extractor".to_string()));
         assert!(extractor_names.contains(&"markdown-extractor".to_string()));
         assert!(extractor_names.contains(&"structured-extractor".to_string()));
         assert!(extractor_names.contains(&"djot-extractor".to_string()));
@@ -404,6 +404,7 @@ fn parse_odt_table(table_node: roxmltree::Node, table_index: usize) -> Option<Ta
         cells,
         markdown,
         page_number: table_index + 1,
+        caption: None,
     })
 }
 
@@ -584,6 +585,10 @@ impl DocumentExtractor for OdtExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -183,6 +183,7 @@ impl OrgModeExtractor {
                             cells: current_table.clone(),
                             markdown,
                             page_number: 1,
+                            caption: None,
                         });
                         current_table.clear();
                     }
@@ -196,6 +197,7 @@ impl OrgModeExtractor {
                     cells: current_table,
                     markdown,
                     page_number: 1,
+                    caption: None,
                 });
             }
         }
@@ -306,6 +308,10 @@ impl DocumentExtractor for OrgModeExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -4,7 +4,7 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
-use crate::types::{PageBoundary, PageContent};
+use crate::types::{PageBoundary, PageContent, PositionedText};
 
 #[cfg(feature = "pdf")]
 use crate::types::Table;
@@ -18,6 +18,7 @@ pub(crate) type PdfExtractionPhaseResult = (
     Vec<Table>,
     Option<Vec<PageContent>>,
     Option<Vec<PageBoundary>>,
+    Option<Vec<PositionedText>>,
 );
 
 /// Extract text, metadata, and tables from a PDF document using a single shared instance.
@@ -43,17 +44,120 @@ pub(crate) type PdfExtractionPhaseResult = (
 /// - Extracted tables (if OCR feature enabled)
 /// - Per-page content (if page extraction configured)
 /// - Page boundaries for per-page OCR evaluation
+/// - Word positions (if `include_layout` is enabled)
+///
+/// When `config.max_pages` is set, pages past the limit are removed from `document`
+/// before any extraction phase runs. With `config.allow_partial`, the first unreadable
+/// page and every page after it are removed as well.
 #[cfg(feature = "pdf")]
 pub(crate) fn extract_all_from_document(
-    document: &PdfDocument,
+    document: &mut PdfDocument,
     config: &ExtractionConfig,
 ) -> Result<PdfExtractionPhaseResult> {
-    let (native_text, boundaries, page_contents, pdf_metadata) =
-        crate::pdf::text::extract_text_and_metadata_from_pdf_document(document, Some(config))?;
+    let truncated_from = truncate_to_max_pages(document, config.max_pages)?;
+    let stopped_at = if config.allow_partial {
+        truncate_at_unreadable_page(document)?
+    } else {
+        None
+    };
+    let document = &*document;
+
+    // Merging OCR text for some pages into the native text needs the page boundaries,
+    // which are otherwise only tracked when page extraction is configured.
+    let track_pages_for_ocr = config.pages.is_none() && selects_ocr_pages(config);
+
+    let (native_text, boundaries, page_contents, mut pdf_metadata) = if track_pages_for_ocr {
+        let page_config = crate::core::config::PageConfig::default();
+        let (native_text, boundaries, page_contents) =
+            crate::pdf::text::extract_text_from_pdf_document(document, Some(&page_config), Some(config))?;
+        let pdf_metadata = crate::pdf::metadata::extract_metadata_from_document_impl(document, None)?;
+        (native_text, boundaries, page_contents, pdf_metadata)
+    } else {
+        crate::pdf::text::extract_text_and_metadata_from_pdf_document(document, Some(config))?
+    };
+
+    pdf_metadata.truncated_from = truncated_from;
+    pdf_metadata.stopped_at = stopped_at;
 
     let tables = extract_tables_from_document(document, &pdf_metadata)?;
 
-    Ok((pdf_metadata, native_text, tables, page_contents, boundaries))
+    let layout = if config.include_layout {
+        Some(crate::pdf::layout::extract_layout_from_document(document)?)
+    } else {
+        None
+    };
+
+    Ok((pdf_metadata, native_text, tables, page_contents, boundaries, layout))
+}
+
+/// Delete the pages past `max_pages` from a loaded document.
+///
+/// Returns the original page count when pages were removed.
+#[cfg(feature = "pdf")]
+fn truncate_to_max_pages(document: &mut PdfDocument, max_pages: Option<usize>) -> Result<Option<usize>> {
+    let page_count = document.pages().len();
+    let Some(limit) = max_pages.and_then(|max_pages| PdfPageIndex::try_from(max_pages).ok()) else {
+        return Ok(None);
+    };
+    if page_count <= limit {
+        return Ok(None);
+    }
+
+    delete_pages_from(document, limit)
+        .map_err(|e| crate::pdf::error::PdfError::ExtractionFailed(format!("Failed to apply max_pages: {}", e)))?;
+
+    Ok(Some(page_count as usize))
+}
+
+/// Delete the page at index `start` and every page after it.
+///
+/// `PdfPage::delete` needs a loaded page, and pages being dropped may not load at all,
+/// so this deletes by index through the deprecated `delete_page_range`.
+#[cfg(feature = "pdf")]
+#[allow(deprecated)]
+fn delete_pages_from(document: &mut PdfDocument, start: PdfPageIndex) -> std::result::Result<(), PdfiumError> {
+    let page_count = document.pages().len();
+    document.pages_mut().delete_page_range(start..page_count)
+}
+
+/// Delete the first page that cannot be read and every page after it.
+///
+/// Returns the 1-based number of that page and the reason it could not be read. A
+/// document whose first page is unreadable has nothing to salvage and fails instead.
+#[cfg(feature = "pdf")]
+fn truncate_at_unreadable_page(document: &mut PdfDocument) -> Result<Option<(usize, String)>> {
+    use crate::pdf::error::{PdfError, format_pdfium_error};
+
+    let page_count = document.pages().len();
+    let unreadable = (0..page_count).find_map(|index| match document.pages().get(index) {
+        Ok(page) => page
+            .text()
+            .err()
+            .map(|e| (index, format!("its text could not be read: {}", format_pdfium_error(e)))),
+        Err(e) => Some((index, format!("it could not be loaded: {}", format_pdfium_error(e)))),
+    });
+
+    let Some((index, reason)) = unreadable else {
+        return Ok(None);
+    };
+    if index == 0 {
+        return Err(PdfError::ExtractionFailed(format!("Page 1 is unreadable: {}", reason)).into());
+    }
+
+    delete_pages_from(document, index)
+        .map_err(|e| PdfError::ExtractionFailed(format!("Failed to drop unreadable pages: {}", e)))?;
+
+    Ok(Some((index as usize + 1, reason)))
+}
+
+/// Whether OCR is limited to a subset of pages rather than the whole document.
+#[cfg(feature = "pdf")]
+fn selects_ocr_pages(config: &ExtractionConfig) -> bool {
+    config
+        .ocr
+        .as_ref()
+        .and_then(|ocr| ocr.ocr_pages.as_ref())
+        .is_some_and(|selection| *selection != crate::core::config::OcrPageSelection::All)
 }
 
 /// Extract tables from PDF document using native text positions.
@@ -91,6 +195,7 @@ fn extract_tables_from_document(
                 cells: table_cells,
                 markdown,
                 page_number: page_index + 1,
+                caption: None,
             });
         }
     }
@@ -12,7 +12,7 @@ use bytes::Bytes;
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata};
+use crate::types::{DocumentKind, ExtractionResult, ExtractionWarning, ExtractionWarningKind, Metadata};
 use async_trait::async_trait;
 #[cfg(feature = "tokio-runtime")]
 use std::path::Path;
@@ -78,7 +78,7 @@ impl DocumentExtractor for PdfExtractor {
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         #[cfg(feature = "pdf")]
-        let (pdf_metadata, native_text, tables, page_contents, _boundaries) = {
+        let (mut pdf_metadata, native_text, tables, page_contents, _boundaries, layout) = {
             #[cfg(target_arch = "wasm32")]
             {
                 let pdfium = crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")
@@ -96,7 +96,7 @@ impl DocumentExtractor for PdfExtractor {
                         }
                     })?;
 
-                let document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
+                let mut document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
                     let err_msg = crate::pdf::error::format_pdfium_error(e);
                     if err_msg.contains("password") || err_msg.contains("Password") {
                         PdfError::PasswordRequired
@@ -105,7 +105,7 @@ impl DocumentExtractor for PdfExtractor {
                     }
                 })?;
 
-                extract_all_from_document(&document, config)?
+                extract_all_from_document(&mut document, config)?
             }
             #[cfg(all(not(target_arch = "wasm32"), feature = "tokio-runtime"))]
             {
@@ -119,7 +119,7 @@ impl DocumentExtractor for PdfExtractor {
                         let pdfium =
                             crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")?;
 
-                        let document = pdfium.load_pdf_from_byte_slice(&content_owned, None).map_err(|e| {
+                        let mut document = pdfium.load_pdf_from_byte_slice(&content_owned, None).map_err(|e| {
                             let err_msg = crate::pdf::error::format_pdfium_error(e);
                             if err_msg.contains("password") || err_msg.contains("Password") {
                                 PdfError::PasswordRequired
@@ -128,8 +128,8 @@ impl DocumentExtractor for PdfExtractor {
                             }
                         })?;
 
-                        let (pdf_metadata, native_text, tables, page_contents, _boundaries) =
-                            extract_all_from_document(&document, &config_owned)?;
+                        let (pdf_metadata, native_text, tables, page_contents, _boundaries, layout) =
+                            extract_all_from_document(&mut document, &config_owned)?;
 
                         if let Some(page_cfg) = config_owned.pages.as_ref()
                             && page_cfg.extract_pages
@@ -148,6 +148,7 @@ impl DocumentExtractor for PdfExtractor {
                             tables,
                             page_contents,
                             _boundaries,
+                            layout,
                         ))
                     })
                     .await
@@ -156,7 +157,7 @@ impl DocumentExtractor for PdfExtractor {
                     let pdfium =
                         crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")?;
 
-                    let document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
+                    let mut document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
                         let err_msg = crate::pdf::error::format_pdfium_error(e);
                         if err_msg.contains("password") || err_msg.contains("Password") {
                             PdfError::PasswordRequired
@@ -165,7 +166,7 @@ impl DocumentExtractor for PdfExtractor {
                         }
                     })?;
 
-                    extract_all_from_document(&document, config)?
+                    extract_all_from_document(&mut document, config)?
                 }
             }
             #[cfg(all(not(target_arch = "wasm32"), not(feature = "tokio-runtime")))]
@@ -173,7 +174,7 @@ impl DocumentExtractor for PdfExtractor {
                 let pdfium =
                     crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")?;
 
-                let document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
+                let mut document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
                     let err_msg = crate::pdf::error::format_pdfium_error(e);
                     if err_msg.contains("password") || err_msg.contains("Password") {
                         PdfError::PasswordRequired
@@ -182,18 +183,22 @@ impl DocumentExtractor for PdfExtractor {
                     }
                 })?;
 
-                extract_all_from_document(&document, config)?
+                extract_all_from_document(&mut document, config)?
             }
         };
 
         #[cfg(feature = "ocr")]
-        let text = if config.force_ocr {
-            if config.ocr.is_some() {
-                extract_with_ocr(content, config).await?
-            } else {
-                native_text
-            }
-        } else if config.ocr.is_some() {
+        let ocr_pages = ocr::select_ocr_pages(config, &native_text, _boundaries.as_deref());
+
+        #[cfg(feature = "ocr")]
+        let run_ocr = if config.ocr.is_none() {
+            false
+        } else if let Some(pages) = &ocr_pages {
+            // A page selection decides on its own which pages need OCR
+            !pages.is_empty()
+        } else if config.force_ocr {
+            true
+        } else {
             let decision = ocr::evaluate_per_page_ocr(
                 &native_text,
                 _boundaries.as_deref(),
@@ -214,17 +219,142 @@ impl DocumentExtractor for PdfExtractor {
                 );
             }
 
-            if decision.fallback {
-                extract_with_ocr(content, config).await?
+            decision.fallback
+        };
+
+        #[cfg(feature = "ocr")]
+        let (text, ocr_tables, ocr_page_texts) = match ocr_pages {
+            _ if !run_ocr => (native_text, Vec::new(), None),
+            None => {
+                // OCR renders from the original bytes, so pages dropped by max_pages or
+                // allow_partial are skipped here
+                let pages_dropped = pdf_metadata.truncated_from.is_some() || pdf_metadata.stopped_at.is_some();
+                let kept_pages: Option<Vec<usize>> =
+                    pages_dropped.then(|| (0..pdf_metadata.pdf_specific.page_count.unwrap_or_default()).collect());
+                let (page_texts, ocr_tables) = extract_with_ocr(content, config, kept_pages.as_deref()).await?;
+                let text = page_texts.join("\n\n");
+                (text, ocr_tables, Some(page_texts.into_iter().enumerate().collect()))
+            }
+            Some(pages) => {
+                let (page_texts, ocr_tables) = extract_with_ocr(content, config, Some(&pages)).await?;
+                let page_texts: Vec<(usize, String)> = pages.into_iter().zip(page_texts).collect();
+                let (text, boundaries) =
+                    ocr::merge_ocr_pages(&native_text, _boundaries.as_deref().unwrap_or_default(), &page_texts);
+                if let Some(page_structure) = pdf_metadata.page_structure.as_mut() {
+                    page_structure.boundaries = Some(boundaries);
+                }
+                (text, ocr_tables, Some(page_texts))
+            }
+        };
+
+        #[cfg(not(feature = "ocr"))]
+        let (text, ocr_tables, ocr_page_texts) = (native_text, Vec::new(), None::<Vec<(usize, String)>>);
+
+        // Page reports start from the native text layer; OCR replaces the text of the pages it ran on
+        #[cfg(feature = "pdf")]
+        if let Some(page_texts) = &ocr_page_texts {
+            for (page_index, page_text) in page_texts {
+                if let Some(report) = pdf_metadata.pdf_specific.page_reports.get_mut(*page_index) {
+                    report.char_count = crate::pdf::classification::count_text_chars(page_text);
+                    report.ocr_used = true;
+                }
+            }
+        }
+
+        let tables: Vec<crate::types::Table> = tables.into_iter().chain(ocr_tables).collect();
+
+        // Pages OCR'd as a whole already include the text of their images
+        #[cfg(feature = "ocr")]
+        let text = if config.ocr_embedded_images {
+            let ocr_pages: std::collections::HashSet<usize> = ocr_page_texts
+                .iter()
+                .flatten()
+                .map(|(page_index, _)| page_index + 1)
+                .collect();
+            let blocks = ocr::ocr_embedded_images(content, config, |page_number| {
+                config.max_pages.is_none_or(|max_pages| page_number <= max_pages)
+                    && pdf_metadata
+                        .stopped_at
+                        .as_ref()
+                        .is_none_or(|(stopped_page, _)| page_number < *stopped_page)
+                    && !ocr_pages.contains(&page_number)
+            })
+            .await?;
+
+            if blocks.is_empty() {
+                text
+            } else if text.trim().is_empty() {
+                blocks.join("\n\n")
             } else {
-                native_text
+                format!("{}\n\n{}", text.trim_end(), blocks.join("\n\n"))
             }
         } else {
-            native_text
+            text
         };
 
-        #[cfg(not(feature = "ocr"))]
-        let text = native_text;
+        // Appended after the OCR decision so form values never count towards native text coverage
+        #[cfg(feature = "pdf")]
+        let text = match pdf_metadata.pdf_specific.form_fields.as_ref() {
+            Some(fields) if config.extract_form_fields => {
+                let section = crate::pdf::forms::format_form_fields(fields);
+                if text.trim().is_empty() {
+                    section
+                } else {
+                    format!("{}\n\n{}", text.trim_end(), section)
+                }
+            }
+            _ => text,
+        };
+
+        let mut warnings: Vec<ExtractionWarning> = pdf_metadata
+            .missing_fonts
+            .iter()
+            .map(|font| {
+                ExtractionWarning::new(
+                    ExtractionWarningKind::MissingFont,
+                    format!("Font '{}' is not embedded; a substitute font was used", font),
+                )
+            })
+            .collect();
+
+        let ocr_enabled = cfg!(feature = "ocr") && config.ocr.is_some();
+        if !ocr_enabled {
+            let message = match pdf_metadata.document_kind {
+                DocumentKind::Scanned => Some("Document appears to be scanned and has no extractable text"),
+                DocumentKind::Mixed => Some("Some pages appear to be scanned and have no extractable text"),
+                DocumentKind::Digital => None,
+            };
+            if let Some(message) = message {
+                warnings.push(ExtractionWarning::new(
+                    ExtractionWarningKind::OcrRecommended,
+                    format!("{}; enable OCR to extract it", message),
+                ));
+            }
+        }
+
+        if let Some(original_page_count) = pdf_metadata.truncated_from {
+            warnings.push(ExtractionWarning::new(
+                ExtractionWarningKind::Truncated,
+                format!(
+                    "Only the first {} of {} pages were extracted (max_pages)",
+                    pdf_metadata.pdf_specific.page_count.unwrap_or_default(),
+                    original_page_count
+                ),
+            ));
+        }
+
+        let partial = pdf_metadata.stopped_at.is_some();
+        if let Some((page_number, reason)) = &pdf_metadata.stopped_at {
+            warnings.push(ExtractionWarning::new(
+                ExtractionWarningKind::PartialContent,
+                format!(
+                    "Extraction stopped at page {} because {}; only the first {} pages were extracted (allow_partial)",
+                    page_number,
+                    reason,
+                    page_number - 1
+                ),
+            ));
+        }
 
         #[cfg(feature = "pdf")]
         if let Some(ref page_cfg) = config.pages
@@ -237,6 +367,10 @@ impl DocumentExtractor for PdfExtractor {
                     "Page markers were configured but none found in extracted content. \
                      This may indicate very short documents or incomplete extraction."
                 );
+                warnings.push(ExtractionWarning::new(
+                    ExtractionWarningKind::MissingPageMarkers,
+                    "Page markers were configured but none found in extracted content",
+                ));
             }
         }
 
@@ -246,6 +380,13 @@ impl DocumentExtractor for PdfExtractor {
                 Ok(pdf_images) => Some(
                     pdf_images
                         .into_iter()
+                        .filter(|img| config.max_pages.is_none_or(|max_pages| img.page_number <= max_pages))
+                        .filter(|img| {
+                            pdf_metadata
+                                .stopped_at
+                                .as_ref()
+                                .is_none_or(|(page_number, _)| img.page_number < *page_number)
+                        })
                         .enumerate()
                         .map(|(idx, img)| {
                             let format = img
@@ -301,6 +442,10 @@ impl DocumentExtractor for PdfExtractor {
                 #[cfg(feature = "pdf")]
                 pages: pdf_metadata.page_structure.clone(),
                 #[cfg(feature = "pdf")]
+                outline: (!pdf_metadata.outline.is_empty()).then(|| pdf_metadata.outline.clone()),
+                #[cfg(feature = "pdf")]
+                document_kind: Some(pdf_metadata.document_kind),
+                #[cfg(feature = "pdf")]
                 format: Some(crate::types::FormatMetadata::Pdf(pdf_metadata.pdf_specific)),
                 ..Default::default()
             },
@@ -309,6 +454,10 @@ impl DocumentExtractor for PdfExtractor {
             detected_languages: None,
             chunks: None,
             images,
+            links: vec![],
+            warnings,
+            partial,
+            layout,
             djot_content: None,
             elements: None,
         })
@@ -166,21 +166,108 @@ pub fn evaluate_per_page_ocr(
     document_decision
 }
 
+/// Resolve `OcrConfig::ocr_pages` to the 0-indexed pages to OCR.
+///
+/// Returns `None` when every page should be OCR'd, either because the selection is
+/// [`OcrPageSelection::All`] (or unset) or because page boundaries are unavailable.
+/// With [`OcrPageSelection::ImageOnly`], a page is selected when its native text fails
+/// the same quality checks used for the document-level OCR fallback.
+///
+/// [`OcrPageSelection::All`]: crate::core::config::OcrPageSelection::All
+/// [`OcrPageSelection::ImageOnly`]: crate::core::config::OcrPageSelection::ImageOnly
+#[cfg(feature = "ocr")]
+pub(crate) fn select_ocr_pages(
+    config: &ExtractionConfig,
+    native_text: &str,
+    boundaries: Option<&[crate::types::PageBoundary]>,
+) -> Option<Vec<usize>> {
+    use crate::core::config::OcrPageSelection;
+
+    let selection = config.ocr.as_ref()?.ocr_pages.as_ref()?;
+    let boundaries = boundaries.filter(|b| !b.is_empty())?;
+
+    match selection {
+        OcrPageSelection::All => None,
+        OcrPageSelection::ImageOnly => Some(
+            boundaries
+                .iter()
+                .enumerate()
+                .filter(|(_, boundary)| {
+                    native_text
+                        .get(boundary.byte_start..boundary.byte_end)
+                        .is_none_or(|page_text| evaluate_native_text_for_ocr(page_text, Some(1)).fallback)
+                })
+                .map(|(page_index, _)| page_index)
+                .collect(),
+        ),
+        OcrPageSelection::Explicit(pages) => {
+            let mut indices: Vec<usize> = pages
+                .iter()
+                .filter(|&&page| page >= 1 && page <= boundaries.len())
+                .map(|page| page - 1)
+                .collect();
+            indices.sort_unstable();
+            indices.dedup();
+            Some(indices)
+        }
+    }
+}
+
+/// Replace the text of OCR'd pages in `native_text`.
+///
+/// `ocr_pages` pairs 0-indexed pages with their OCR text. Separators and page markers
+/// between pages are kept. Returns the merged text and the page boundaries within it.
+#[cfg(feature = "ocr")]
+pub(crate) fn merge_ocr_pages(
+    native_text: &str,
+    boundaries: &[crate::types::PageBoundary],
+    ocr_pages: &[(usize, String)],
+) -> (String, Vec<crate::types::PageBoundary>) {
+    let mut merged = String::with_capacity(native_text.len());
+    let mut merged_boundaries = Vec::with_capacity(boundaries.len());
+    let mut cursor = 0usize;
+
+    for (page_index, boundary) in boundaries.iter().enumerate() {
+        merged.push_str(&native_text[cursor..boundary.byte_start]);
+        let byte_start = merged.len();
+        match ocr_pages.iter().find(|(index, _)| *index == page_index) {
+            Some((_, ocr_text)) => merged.push_str(ocr_text),
+            None => merged.push_str(&native_text[boundary.byte_start..boundary.byte_end]),
+        }
+        merged_boundaries.push(crate::types::PageBoundary {
+            byte_start,
+            byte_end: merged.len(),
+            page_number: boundary.page_number,
+        });
+        cursor = boundary.byte_end;
+    }
+    merged.push_str(&native_text[cursor..]);
+
+    (merged, merged_boundaries)
+}
+
 /// Extract text from PDF using OCR.
 ///
-/// Renders all pages to images and processes them with OCR backend.
+/// Renders pages to images and processes them with OCR backend.
 ///
 /// # Arguments
 ///
 /// * `content` - Raw PDF bytes
 /// * `config` - Extraction configuration including OCR settings
+/// * `pages` - 0-indexed pages to OCR, or `None` for every page
 ///
 /// # Returns
 ///
-/// Concatenated text from all pages, separated by double newlines
+/// The OCR text of each processed page, in the order given (page order for all pages),
+/// and the tables reconstructed from OCR word layout when `config.detect_image_tables`
+/// is set
 #[cfg(feature = "ocr")]
-pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig) -> crate::Result<String> {
-    use crate::pdf::rendering::{PageRenderOptions, PdfRenderer};
+pub(crate) async fn extract_with_ocr(
+    content: &[u8],
+    config: &ExtractionConfig,
+    pages: Option<&[usize]>,
+) -> crate::Result<(Vec<String>, Vec<crate::types::Table>)> {
+    use crate::pdf::rendering::PdfRenderer;
     use crate::plugins::registry::get_ocr_backend_registry;
     use image::ImageEncoder;
     use image::codecs::png::PngEncoder;
@@ -190,6 +277,11 @@ pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig)
         message: "OCR config required for force_ocr".to_string(),
         source: None,
     })?;
+    let mut ocr_config = if config.detect_image_tables {
+        ocr_config.with_table_detection()
+    } else {
+        ocr_config.clone()
+    };
 
     let backend = {
         let registry = get_ocr_backend_registry();
@@ -200,24 +292,36 @@ pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig)
         registry.get(&ocr_config.backend)?
     };
 
+    ocr_config.validate()?;
+
     let images = {
-        let render_options = PageRenderOptions::default();
+        let render_options = ocr_render_options(&ocr_config);
         let renderer = PdfRenderer::new().map_err(|e| crate::KreuzbergError::Parsing {
             message: format!("Failed to initialize PDF renderer: {}", e),
             source: None,
         })?;
 
-        renderer
-            .render_all_pages(content, &render_options)
-            .map_err(|e| crate::KreuzbergError::Parsing {
-                message: format!("Failed to render PDF pages: {}", e),
-                source: None,
-            })?
+        let rendered = match pages {
+            Some(pages) => pages
+                .iter()
+                .map(|&page_index| renderer.render_page_to_image(content, page_index, &render_options))
+                .collect(),
+            None => renderer.render_all_pages(content, &render_options),
+        };
+        rendered.map_err(|e| crate::KreuzbergError::Parsing {
+            message: format!("Failed to render PDF pages: {}", e),
+            source: None,
+        })?
+    };
+    let page_indices: Vec<usize> = match pages {
+        Some(pages) => pages.to_vec(),
+        None => (0..images.len()).collect(),
     };
 
     let mut page_texts = Vec::with_capacity(images.len());
+    let mut tables = Vec::new();
 
-    for image in images {
+    for (page_index, image) in page_indices.into_iter().zip(images) {
         let rgb_image = image.to_rgb8();
         let (width, height) = rgb_image.dimensions();
 
@@ -232,10 +336,181 @@ pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig)
 
         let image_data = image_bytes.into_inner();
 
-        let ocr_result = backend.process_image(&image_data, ocr_config).await?;
+        // Detect the language on the first page only, so every page uses the same packs
+        if ocr_config.is_auto_language() {
+            let language = backend.resolve_language(&image_data, &ocr_config).await?;
+            ocr_config = ocr_config.with_language(&language);
+        }
+
+        let ocr_result = backend.process_image(&image_data, &ocr_config).await?;
+
+        if config.detect_image_tables {
+            tables.extend(ocr_result.tables.into_iter().map(|table| crate::types::Table {
+                page_number: page_index + 1,
+                ..table
+            }));
+        }
 
         page_texts.push(ocr_result.content);
     }
 
-    Ok(page_texts.join("\n\n"))
+    Ok((page_texts, tables))
+}
+
+/// Embedded images narrower or shorter than this many pixels are not OCR'd; they are
+/// icons, bullets, or rules rather than text.
+#[cfg(feature = "ocr")]
+const MIN_EMBEDDED_IMAGE_SIDE: u32 = 32;
+
+/// OCR the images embedded in a PDF and format their text as captioned blocks.
+///
+/// Each image XObject is recognized once, on the first page that uses it, so images
+/// reused across pages (logos, repeated figures) do not repeat their text. Images on
+/// pages rejected by `keep_page` (1-indexed page number), images too small to hold
+/// text, and images in encodings that cannot be decoded are skipped.
+///
+/// Uses `config.ocr` when set and the default OCR configuration otherwise.
+///
+/// # Returns
+///
+/// One `[Image text, page N]` block per image with recognized text, in page order
+#[cfg(feature = "ocr")]
+pub(crate) async fn ocr_embedded_images(
+    content: &[u8],
+    config: &ExtractionConfig,
+    keep_page: impl Fn(usize) -> bool,
+) -> crate::Result<Vec<String>> {
+    use crate::pdf::images::PdfImageExtractor;
+    use crate::plugins::registry::get_ocr_backend_registry;
+    use image::ImageEncoder;
+    use image::codecs::png::PngEncoder;
+    use std::collections::HashSet;
+    use std::io::Cursor;
+
+    // Image listing is best effort, as for image extraction
+    let Ok(extractor) = PdfImageExtractor::new(content) else {
+        return Ok(Vec::new());
+    };
+    let Ok(images) = extractor.extract_images() else {
+        return Ok(Vec::new());
+    };
+
+    let mut ocr_config = config.ocr.clone().unwrap_or_default();
+    ocr_config.validate()?;
+
+    let backend = {
+        let registry = get_ocr_backend_registry();
+        let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
+            message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
+            plugin_name: "ocr-registry".to_string(),
+        })?;
+        registry.get(&ocr_config.backend)?
+    };
+
+    let mut seen = HashSet::new();
+    let mut blocks = Vec::new();
+
+    for image in images.iter().filter(|image| keep_page(image.page_number)) {
+        if !seen.insert(image.object_id) {
+            continue;
+        }
+        let Some(decoded) = extractor.decode_image(image) else {
+            continue;
+        };
+        let rgb_image = decoded.to_rgb8();
+        let (width, height) = rgb_image.dimensions();
+        if width < MIN_EMBEDDED_IMAGE_SIDE || height < MIN_EMBEDDED_IMAGE_SIDE {
+            continue;
+        }
+
+        let mut image_bytes = Cursor::new(Vec::new());
+        PngEncoder::new(&mut image_bytes)
+            .write_image(&rgb_image, width, height, image::ColorType::Rgb8.into())
+            .map_err(|e| crate::KreuzbergError::Parsing {
+                message: format!("Failed to encode image: {}", e),
+                source: None,
+            })?;
+        let image_data = image_bytes.into_inner();
+
+        if ocr_config.is_auto_language() {
+            let language = backend.resolve_language(&image_data, &ocr_config).await?;
+            ocr_config = ocr_config.with_language(&language);
+        }
+
+        let ocr_result = backend.process_image(&image_data, &ocr_config).await?;
+        let text = ocr_result.content.trim();
+        if !text.is_empty() {
+            blocks.push(format!("[Image text, page {}]\n{}", image.page_number, text));
+        }
+    }
+
+    Ok(blocks)
+}
+
+/// Page render options that rasterize at the OCR config's DPI.
+///
+/// Oversized pages are still scaled down to fit the maximum image dimension, but the
+/// requested DPI is not clamped to the default 72-600 range.
+#[cfg(feature = "ocr")]
+pub(crate) fn ocr_render_options(
+    ocr_config: &crate::core::config::OcrConfig,
+) -> crate::pdf::rendering::PageRenderOptions {
+    let defaults = crate::pdf::rendering::PageRenderOptions::default();
+    let dpi = i32::try_from(ocr_config.render_dpi()).unwrap_or(i32::MAX);
+
+    crate::pdf::rendering::PageRenderOptions {
+        target_dpi: dpi,
+        min_dpi: defaults.min_dpi.min(dpi),
+        max_dpi: defaults.max_dpi.max(dpi),
+        ..defaults
+    }
+}
+
+#[cfg(all(test, feature = "ocr"))]
+mod tests {
+    use super::*;
+    use crate::core::config::OcrConfig;
+    use crate::pdf::rendering::PdfRenderer;
+
+    #[test]
+    fn test_ocr_render_options_default_dpi() {
+        let options = ocr_render_options(&OcrConfig::default());
+        assert_eq!(options.target_dpi, 300);
+    }
+
+    #[test]
+    fn test_ocr_render_options_do_not_clamp_configured_dpi() {
+        let options = ocr_render_options(&OcrConfig {
+            dpi: Some(1200),
+            ..Default::default()
+        });
+        assert_eq!(options.target_dpi, 1200);
+        assert!(options.max_dpi >= 1200);
+
+        let options = ocr_render_options(&OcrConfig {
+            dpi: Some(50),
+            ..Default::default()
+        });
+        assert!(options.min_dpi <= 50);
+    }
+
+    #[test]
+    fn test_ocr_render_options_rasterize_at_configured_dpi() {
+        let path =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test_documents/pdf/bookmarked_outline.pdf");
+        let Ok(pdf_bytes) = std::fs::read(&path) else {
+            return;
+        };
+
+        let renderer = PdfRenderer::new().unwrap();
+        for (dpi, expected_width, expected_height) in [(50, 425, 550), (150, 1275, 1650)] {
+            let options = ocr_render_options(&OcrConfig {
+                dpi: Some(dpi),
+                ..Default::default()
+            });
+            let image = renderer.render_page_to_image(&pdf_bytes, 0, &options).unwrap();
+
+            assert_eq!((image.width(), image.height()), (expected_width, expected_height));
+        }
+    }
 }
@@ -74,6 +74,10 @@ impl PptxExtractor {
                         detected_languages: None,
                         chunks: None,
                         images: None,
+                        links: vec![],
+                        warnings: vec![],
+                        partial: false,
+                        layout: None,
                         djot_content: None,
                         pages: None,
                         elements: None,
@@ -124,6 +128,7 @@ impl DocumentExtractor for PptxExtractor {
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let extract_images = config.images.as_ref().is_some_and(|img| img.extract_images);
+        let include_notes = config.include_speaker_notes;
 
         let pages_config = config.pages.clone();
         let pptx_result = if crate::core::batch_mode::is_batch_mode() {
@@ -131,12 +136,22 @@ impl DocumentExtractor for PptxExtractor {
             let span = tracing::Span::current();
             tokio::task::spawn_blocking(move || {
                 let _guard = span.entered();
-                crate::extraction::pptx::extract_pptx_from_bytes(&content_owned, extract_images, pages_config.as_ref())
+                crate::extraction::pptx::extract_pptx_from_bytes(
+                    &content_owned,
+                    extract_images,
+                    include_notes,
+                    pages_config.as_ref(),
+                )
             })
             .await
             .map_err(|e| crate::error::KreuzbergError::parsing(format!("PPTX extraction task failed: {}", e)))??
         } else {
-            crate::extraction::pptx::extract_pptx_from_bytes(content, extract_images, config.pages.as_ref())?
+            crate::extraction::pptx::extract_pptx_from_bytes(
+                content,
+                extract_images,
+                include_notes,
+                config.pages.as_ref(),
+            )?
         };
 
         let mut additional: AHashMap<Cow<'static, str>, serde_json::Value> = AHashMap::new();
@@ -183,6 +198,10 @@ impl DocumentExtractor for PptxExtractor {
             detected_languages: None,
             chunks: None,
             images,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -201,8 +220,12 @@ impl DocumentExtractor for PptxExtractor {
 
         let extract_images = config.images.as_ref().is_some_and(|img| img.extract_images);
 
-        let pptx_result =
-            crate::extraction::pptx::extract_pptx_from_path(path_str, extract_images, config.pages.as_ref())?;
+        let pptx_result = crate::extraction::pptx::extract_pptx_from_path(
+            path_str,
+            extract_images,
+            config.include_speaker_notes,
+            config.pages.as_ref(),
+        )?;
 
         let mut additional: AHashMap<Cow<'static, str>, serde_json::Value> = AHashMap::new();
         additional.insert(Cow::Borrowed("slide_count"), serde_json::json!(pptx_result.slide_count));
@@ -248,6 +271,10 @@ impl DocumentExtractor for PptxExtractor {
             detected_languages: None,
             chunks: None,
             images,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -347,6 +347,7 @@ impl RstExtractor {
             cells,
             markdown,
             page_number: 1,
+            caption: None,
         })
     }
 
@@ -455,6 +456,10 @@ impl DocumentExtractor for RstExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -22,3 +22,28 @@ pub fn normalize_whitespace(s: &str) -> String {
 
     result.trim().to_string()
 }
+
+/// Normalize whitespace line by line, keeping paragraph breaks.
+///
+/// Each line is normalized with [`normalize_whitespace`]; runs of blank lines collapse
+/// into a single blank line (a `\n\n` paragraph break), and leading and trailing blank
+/// lines are removed.
+pub fn normalize_paragraphs(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut pending_break = false;
+
+    for line in s.split('\n') {
+        let line = normalize_whitespace(line);
+        if line.is_empty() {
+            pending_break = true;
+            continue;
+        }
+        if !result.is_empty() {
+            result.push_str(if pending_break { "\n\n" } else { "\n" });
+        }
+        result.push_str(&line);
+        pending_break = false;
+    }
+
+    result
+}
@@ -36,7 +36,14 @@ pub fn extract_image_metadata(chars: &mut std::iter::Peekable<std::str::Chars>)
                     "dibitmap" => image_type = Some("bmp"),
                     "picwgoal" => width_goal = value,
                     "pichgoal" => height_goal = value,
-                    "bin" => break,
+                    "bin" => {
+                        // Raw bytes may contain braces; skip them so the group still closes correctly.
+                        for _ in 0..value.unwrap_or(0).max(0) {
+                            if chars.next().is_none() {
+                                break;
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
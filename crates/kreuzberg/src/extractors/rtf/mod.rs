@@ -5,9 +5,10 @@
 //! This native Rust extractor provides text extraction from RTF documents with:
 //! - Character encoding support (Windows-1252 for 0x80-0x9F range)
 //! - Common RTF control words (paragraph breaks, tabs, bullets, quotes, dashes)
-//! - Unicode escape sequences
+//! - Unicode escape sequences (`\uN` with `\ucN` fallback skipping)
 //! - Image metadata extraction
-//! - Whitespace normalization
+//! - Skipping of font/color tables, document info, embedded objects, and binary data
+//! - Whitespace normalization that keeps `\par` paragraph breaks
 
 mod encoding;
 mod formatting;
@@ -105,6 +106,10 @@ impl DocumentExtractor for RtfExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -139,4 +144,26 @@ mod tests {
         let (extracted, _) = extract_text_from_rtf(rtf_content);
         assert!(extracted.contains("Hello") || extracted.contains("World"));
     }
+
+    #[test]
+    fn test_unicode_escapes_and_paragraphs() {
+        let rtf_content = r#"{\rtf1\ansi\uc1{\fonttbl{\f0\fswiss Helvetica;}}{\colortbl;\red255\green0\blue0;}
+{\info{\title Report}{\author Jane Roe}}
+\f0 Caf\u233? na\u239\'3fve\par
+Second {\uc2 \u8364??}paragraph{\*\generator Writer;}\par}"#;
+
+        let (extracted, _) = extract_text_from_rtf(rtf_content);
+        assert_eq!(extracted, "Café naïve\n\nSecond € paragraph");
+
+        let metadata = extract_rtf_metadata(rtf_content, &extracted);
+        assert_eq!(metadata.get("title").and_then(|v| v.as_str()), Some("Report"));
+        assert_eq!(metadata.get("created_by").and_then(|v| v.as_str()), Some("Jane Roe"));
+    }
+
+    #[test]
+    fn test_binary_and_object_groups_are_skipped() {
+        let rtf_content = "{\\rtf1 Before {\\object\\objemb{\\*\\objdata 0102}{\\result Embedded}} \\bin3 {}} After}";
+        let (extracted, _) = extract_text_from_rtf(rtf_content);
+        assert_eq!(extracted, "Before After");
+    }
 }
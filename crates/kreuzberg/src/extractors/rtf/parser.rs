@@ -1,24 +1,54 @@
 //! Core RTF parsing logic.
 
 use crate::extractors::rtf::encoding::{decode_windows_1252, parse_hex_byte, parse_rtf_control_word};
-use crate::extractors::rtf::formatting::normalize_whitespace;
+use crate::extractors::rtf::formatting::normalize_paragraphs;
 use crate::extractors::rtf::images::extract_image_metadata;
 use crate::extractors::rtf::tables::TableState;
 use crate::types::Table;
 
+/// Destination groups whose contents are never document text.
+///
+/// Font, color, and style tables, document info, list definitions, revision and
+/// theme data, and embedded objects. Groups starting with `\*` (ignorable
+/// destinations) are skipped as well, except `\*\shppict`, which holds pictures.
+const SKIPPED_DESTINATIONS: &[&str] = &[
+    "fonttbl",
+    "colortbl",
+    "stylesheet",
+    "info",
+    "listtable",
+    "listoverridetable",
+    "revtbl",
+    "rsidtbl",
+    "filetbl",
+    "generator",
+    "xmlnstbl",
+    "themedata",
+    "colorschememapping",
+    "datastore",
+    "latentstyles",
+    "pgdsctbl",
+    "fldinst",
+    "object",
+    "nonshppict",
+];
+
 /// Extract text and image metadata from RTF document.
 ///
 /// This function extracts plain text from an RTF document by:
 /// 1. Tokenizing control sequences and text
-/// 2. Converting encoded characters to Unicode
-/// 3. Extracting text while skipping formatting groups
+/// 2. Converting encoded characters to Unicode (skipping `\uN` fallback characters)
+/// 3. Extracting text while skipping destination groups (font/color tables, info, objects)
+///    and `\binN` binary data
 /// 4. Detecting and extracting image metadata (\pict sections)
-/// 5. Normalizing whitespace
+/// 5. Normalizing whitespace, keeping `\par` paragraph breaks
 pub fn extract_text_from_rtf(content: &str) -> (String, Vec<Table>) {
     let mut result = String::new();
     let mut chars = content.chars().peekable();
     let mut tables: Vec<Table> = Vec::new();
     let mut table_state: Option<TableState> = None;
+    // Number of fallback characters following a `\uN` escape, scoped per group (`\ucN`).
+    let mut unicode_skip: Vec<usize> = vec![1];
 
     let ensure_table = |table_state: &mut Option<TableState>| {
         if table_state.is_none() {
@@ -70,6 +100,33 @@ pub fn extract_text_from_rtf(content: &str) -> (String, Vec<Table>) {
                                     break;
                                 }
                             }
+                            if num_str.is_empty() {
+                                let (control_word, value) = parse_rtf_control_word(&mut chars);
+                                let control_word = format!("u{}", control_word);
+                                if chars.peek() == Some(&' ') {
+                                    chars.next();
+                                }
+                                if control_word == "uc" {
+                                    if let Some(count) = unicode_skip.last_mut() {
+                                        *count = value.unwrap_or(1).max(0) as usize;
+                                    }
+                                } else {
+                                    handle_control_word(
+                                        &control_word,
+                                        &mut chars,
+                                        &mut result,
+                                        &mut table_state,
+                                        &mut tables,
+                                        &ensure_table,
+                                        &finalize_table,
+                                    );
+                                }
+                                continue;
+                            }
+                            if chars.peek() == Some(&' ') {
+                                chars.next();
+                            }
+                            skip_unicode_fallback(&mut chars, unicode_skip.last().copied().unwrap_or(1));
                             if let Ok(code_num) = num_str.parse::<i32>() {
                                 let code_u = if code_num < 0 {
                                     (code_num + 65536) as u32
@@ -86,8 +143,44 @@ pub fn extract_text_from_rtf(content: &str) -> (String, Vec<Table>) {
                                 }
                             }
                         }
+                        '\n' | '\r' => {
+                            chars.next();
+                            handle_control_word(
+                                "par",
+                                &mut chars,
+                                &mut result,
+                                &mut table_state,
+                                &mut tables,
+                                &ensure_table,
+                                &finalize_table,
+                            );
+                        }
+                        c if !c.is_ascii_alphabetic() => {
+                            chars.next();
+                            let symbol = match c {
+                                '~' => Some('\u{00A0}'),
+                                '_' => Some('\u{2011}'),
+                                _ => None,
+                            };
+                            if let Some(symbol) = symbol {
+                                result.push(symbol);
+                                if let Some(state) = table_state.as_mut()
+                                    && state.in_row
+                                {
+                                    state.current_cell.push(symbol);
+                                }
+                            }
+                        }
                         _ => {
-                            let (control_word, _) = parse_rtf_control_word(&mut chars);
+                            let (control_word, value) = parse_rtf_control_word(&mut chars);
+                            if control_word == "bin" {
+                                skip_binary_data(&mut chars, value.unwrap_or(0));
+                                continue;
+                            }
+                            // A space after a control word is its delimiter, not text.
+                            if chars.peek() == Some(&' ') {
+                                chars.next();
+                            }
                             handle_control_word(
                                 &control_word,
                                 &mut chars,
@@ -101,7 +194,21 @@ pub fn extract_text_from_rtf(content: &str) -> (String, Vec<Table>) {
                     }
                 }
             }
-            '{' | '}' => {
+            '{' => {
+                if is_skipped_destination(&chars) {
+                    skip_group(&mut chars);
+                    continue;
+                }
+                let inherited = unicode_skip.last().copied().unwrap_or(1);
+                unicode_skip.push(inherited);
+                if !result.is_empty() && !result.ends_with(' ') {
+                    result.push(' ');
+                }
+            }
+            '}' => {
+                if unicode_skip.len() > 1 {
+                    unicode_skip.pop();
+                }
                 if !result.is_empty() && !result.ends_with(' ') {
                     result.push(' ');
                 }
@@ -138,7 +245,92 @@ pub fn extract_text_from_rtf(content: &str) -> (String, Vec<Table>) {
         finalize_table(&mut table_state, &mut tables);
     }
 
-    (normalize_whitespace(&result), tables)
+    (normalize_paragraphs(&result), tables)
+}
+
+/// Check whether the group just opened (after its `{`) is a destination to skip.
+fn is_skipped_destination(chars: &std::iter::Peekable<std::str::Chars>) -> bool {
+    let mut lookahead = chars.clone();
+    while lookahead.next_if(|c| c.is_whitespace()).is_some() {}
+    if lookahead.next() != Some('\\') {
+        return false;
+    }
+
+    if lookahead.next_if_eq(&'*').is_some() {
+        while lookahead.next_if(|c| c.is_whitespace()).is_some() {}
+        if lookahead.next() != Some('\\') {
+            return true;
+        }
+        let (control_word, _) = parse_rtf_control_word(&mut lookahead);
+        return control_word != "shppict";
+    }
+
+    let (control_word, _) = parse_rtf_control_word(&mut lookahead);
+    SKIPPED_DESTINATIONS.contains(&control_word.as_str())
+}
+
+/// Consume the rest of a group whose `{` has already been read, including nested groups.
+fn skip_group(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    let mut depth = 1usize;
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                if chars.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+                    let (control_word, value) = parse_rtf_control_word(chars);
+                    if control_word == "bin" {
+                        skip_binary_data(chars, value.unwrap_or(0));
+                    }
+                } else {
+                    chars.next();
+                }
+            }
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Skip the raw bytes of a `\binN` control word.
+fn skip_binary_data(chars: &mut std::iter::Peekable<std::str::Chars>, len: i32) {
+    if chars.peek() == Some(&' ') {
+        chars.next();
+    }
+    for _ in 0..len.max(0) {
+        if chars.next().is_none() {
+            break;
+        }
+    }
+}
+
+/// Skip the ANSI fallback that follows a `\uN` escape for readers without Unicode support.
+///
+/// Each `\'hh` escape counts as one character. A group boundary or another control
+/// word ends the fallback early.
+fn skip_unicode_fallback(chars: &mut std::iter::Peekable<std::str::Chars>, count: usize) {
+    for _ in 0..count {
+        match chars.peek() {
+            Some('{') | Some('}') | None => return,
+            Some('\\') => {
+                let mut lookahead = chars.clone();
+                lookahead.next();
+                if lookahead.peek() != Some(&'\'') {
+                    return;
+                }
+                for _ in 0..4 {
+                    chars.next();
+                }
+            }
+            Some(_) => {
+                chars.next();
+            }
+        }
+    }
 }
 
 /// Handle an RTF control word during parsing.
@@ -179,6 +371,18 @@ fn handle_control_word(
             }
         }
         "par" => {
+            // Paragraphs inside a table cell stay part of the cell.
+            if let Some(state) = table_state.as_mut()
+                && state.in_row
+            {
+                if !result.ends_with(' ') {
+                    result.push(' ');
+                }
+                if !state.current_cell.ends_with(' ') {
+                    state.current_cell.push(' ');
+                }
+                return;
+            }
             if table_state.is_some() {
                 finalize_table(table_state, tables);
             }
@@ -187,6 +391,9 @@ fn handle_control_word(
                 result.push('\n');
             }
         }
+        "line" if !result.is_empty() && !result.ends_with('\n') => {
+            result.push('\n');
+        }
         "tab" => {
             result.push('\t');
             if let Some(state) = table_state.as_mut()
@@ -221,7 +428,7 @@ fn handle_control_word(
             if let Some(state) = table_state.as_mut() {
                 state.start_row();
             }
-            if !result.is_empty() && !result.ends_with('\n') {
+            if !result.is_empty() && !result.trim_end_matches(' ').ends_with('\n') {
                 result.push('\n');
             }
             if !result.ends_with('|') {
@@ -72,6 +72,7 @@ impl TableState {
             cells: self.rows,
             markdown,
             page_number: 1,
+            caption: None,
         })
     }
 }
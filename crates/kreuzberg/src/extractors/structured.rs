@@ -91,7 +91,11 @@ impl DocumentExtractor for StructuredExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             elements: None,
+            layout: None,
             djot_content: None,
         })
     }
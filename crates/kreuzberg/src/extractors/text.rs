@@ -4,6 +4,7 @@ use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::text::parse_text;
 use crate::plugins::{DocumentExtractor, Plugin};
+use crate::text::encoding::decode_text;
 use crate::types::ExtractionResult;
 use async_trait::async_trait;
 
@@ -54,7 +55,7 @@ impl Plugin for PlainTextExtractor {
 #[async_trait]
 impl DocumentExtractor for PlainTextExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -64,40 +65,46 @@ impl DocumentExtractor for PlainTextExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let text = String::from_utf8_lossy(content).into_owned();
-        let text = text.trim_end_matches('\n').trim_end_matches('\r').to_string();
+        let decoded = decode_text(content, config.encoding_override.as_deref());
+        let text = decoded.text.trim_end_matches('\n').trim_end_matches('\r').to_string();
         let line_count = text.lines().count();
         let word_count = text.split_whitespace().count();
         let character_count = text.len();
 
+        let metadata = crate::types::Metadata {
+            format: Some(crate::types::FormatMetadata::Text(crate::types::TextMetadata {
+                line_count,
+                word_count,
+                character_count,
+                headers: None,
+                links: None,
+                code_blocks: None,
+            })),
+            ..Default::default()
+        };
+
         Ok(ExtractionResult {
             content: text,
             mime_type: mime_type.to_string().into(),
-            metadata: crate::types::Metadata {
-                format: Some(crate::types::FormatMetadata::Text(crate::types::TextMetadata {
-                    line_count,
-                    word_count,
-                    character_count,
-                    headers: None,
-                    links: None,
-                    code_blocks: None,
-                })),
-                ..Default::default()
-            },
+            metadata,
             pages: None,
             tables: vec![],
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: decoded.warnings(),
+            partial: false,
             elements: None,
+            layout: None,
             djot_content: None,
         })
     }
 
     fn supported_mime_types(&self) -> &[&str] {
-        &["text/plain", "text/csv", "text/tab-separated-values"]
+        &["text/plain"]
     }
 
     fn priority(&self) -> i32 {
@@ -186,7 +193,11 @@ impl DocumentExtractor for MarkdownExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             elements: None,
+            layout: None,
             djot_content: None,
         })
     }
@@ -203,6 +214,7 @@ impl DocumentExtractor for MarkdownExtractor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ExtractionWarningKind;
 
     #[tokio::test]
     async fn test_plain_text_extractor() {
@@ -223,6 +235,47 @@ mod tests {
         assert_eq!(text_meta.word_count, 6);
     }
 
+    #[cfg(feature = "quality")]
+    #[tokio::test]
+    async fn test_plain_text_extractor_decodes_windows_1252() {
+        let extractor = PlainTextExtractor::new();
+        let content = b"\x93Quoted\x94 text written on an older system. It\x92s still readable \x96 mostly.";
+        let config = ExtractionConfig::default();
+
+        let result = extractor.extract_bytes(content, "text/plain", &config).await.unwrap();
+
+        assert!(result.content.starts_with("\u{201C}Quoted\u{201D}"));
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|warning| warning.kind == ExtractionWarningKind::Encoding
+                    && warning.message.contains("windows-1252"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plain_text_extractor_respects_encoding_override() {
+        let extractor = PlainTextExtractor::new();
+        let config = ExtractionConfig {
+            encoding_override: Some("utf-8".to_string()),
+            ..Default::default()
+        };
+
+        let result = extractor
+            .extract_bytes(b"valid \xFF", "text/plain", &config)
+            .await
+            .unwrap();
+
+        assert!(result.content.contains('\u{FFFD}'));
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|warning| warning.kind == ExtractionWarningKind::Encoding)
+        );
+    }
+
     #[tokio::test]
     async fn test_markdown_extractor() {
         let extractor = MarkdownExtractor::new();
@@ -251,10 +304,7 @@ mod tests {
         let extractor = PlainTextExtractor::new();
         assert_eq!(extractor.name(), "plain-text-extractor");
         assert_eq!(extractor.version(), env!("CARGO_PKG_VERSION"));
-        assert_eq!(
-            extractor.supported_mime_types(),
-            &["text/plain", "text/csv", "text/tab-separated-values"]
-        );
+        assert_eq!(extractor.supported_mime_types(), &["text/plain"]);
         assert_eq!(extractor.priority(), 50);
     }
 
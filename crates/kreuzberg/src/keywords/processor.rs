@@ -114,8 +114,12 @@ machine learning that uses neural networks with multiple layers.
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -145,8 +149,12 @@ machine learning that uses neural networks with multiple layers.
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -172,8 +180,12 @@ machine learning that uses neural networks with multiple layers.
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -199,8 +211,12 @@ machine learning that uses neural networks with multiple layers.
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -237,8 +253,12 @@ machine learning that uses neural networks with multiple layers.
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -264,8 +284,12 @@ machine learning that uses neural networks with multiple layers.
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -277,8 +301,12 @@ machine learning that uses neural networks with multiple layers.
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -5,12 +5,56 @@
 use crate::Result;
 use crate::core::config::LanguageDetectionConfig;
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use whatlang::{Lang, detect};
 
 pub mod processor;
 pub use processor::LanguageDetector;
 
+/// Minimum number of non-whitespace characters required by [`detect_language`].
+///
+/// Shorter inputs don't contain enough trigrams for a meaningful guess.
+const MIN_DETECTION_CHARS: usize = 10;
+
+/// Most likely language of a piece of text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageGuess {
+    /// ISO 639-3 language code (e.g. "eng", "deu")
+    pub code: String,
+    /// Detection confidence (0.0-1.0)
+    pub confidence: f32,
+}
+
+/// Detect the most likely language of arbitrary text.
+///
+/// Uses whatlang's trigram model. Unlike [`detect_languages`], this takes no
+/// configuration and always returns the best guess together with its confidence,
+/// leaving any thresholding to the caller.
+///
+/// Returns `None` when the text is too short (fewer than 10 non-whitespace
+/// characters) or no language could be detected.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::language_detection::detect_language;
+///
+/// let guess = detect_language("The quick brown fox jumps over the lazy dog.").unwrap();
+/// assert_eq!(guess.code, "eng");
+/// assert!(detect_language("abc").is_none());
+/// ```
+pub fn detect_language(text: &str) -> Option<LanguageGuess> {
+    if text.chars().filter(|c| !c.is_whitespace()).count() < MIN_DETECTION_CHARS {
+        return None;
+    }
+
+    detect(text).map(|info| LanguageGuess {
+        code: lang_to_iso639_3(info.lang()),
+        confidence: info.confidence() as f32,
+    })
+}
+
 /// Detect languages in text using whatlang.
 ///
 /// Returns a list of detected language codes (ISO 639-3 format).
@@ -305,6 +349,34 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_detect_language_english() {
+        let guess = detect_language("The weather today is sunny and warm, so we are going to the beach.").unwrap();
+        assert_eq!(guess.code, "eng");
+        assert!(guess.confidence > 0.5);
+    }
+
+    #[test]
+    fn test_detect_language_german() {
+        let guess =
+            detect_language("Das Wetter ist heute sonnig und warm, deshalb gehen wir zusammen an den Strand.").unwrap();
+        assert_eq!(guess.code, "deu");
+        assert!(guess.confidence > 0.5);
+    }
+
+    #[test]
+    fn test_detect_language_french() {
+        let guess =
+            detect_language("Il fait beau et chaud aujourd'hui, alors nous allons ensemble à la plage.").unwrap();
+        assert_eq!(guess.code, "fra");
+        assert!(guess.confidence > 0.5);
+    }
+
+    #[test]
+    fn test_detect_language_too_short() {
+        assert!(detect_language("abc").is_none());
+    }
+
     #[test]
     fn test_lang_to_iso639_3() {
         assert_eq!(lang_to_iso639_3(Lang::Eng), "eng");
@@ -80,35 +80,47 @@ pub use types::*;
 
 #[cfg(feature = "tokio-runtime")]
 pub use core::extractor::{batch_extract_bytes, batch_extract_file};
-pub use core::extractor::{extract_bytes, extract_file};
+pub use core::extractor::{extract_bytes, extract_bytes_with_mime, extract_file, extract_file_with_prepared};
 
-pub use core::extractor::{batch_extract_bytes_sync, extract_bytes_sync};
+pub use core::extractor::{batch_extract_bytes_sync, extract_bytes_sync, extract_bytes_with_mime_sync};
 
 #[cfg(feature = "tokio-runtime")]
 pub use core::extractor::{batch_extract_file_sync, extract_file_sync};
 
+#[cfg(feature = "http")]
+pub use core::extractor::extract_url;
+
 pub use core::config::{
-    ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig, ImageExtractionConfig,
-    LanguageDetectionConfig, OcrConfig, OutputFormat, PageConfig, PostProcessorConfig, TokenReductionConfig,
+    BinarizationMethod, ChunkerType, ChunkingConfig, ConfigError, EmbeddingConfig, EmbeddingModelType,
+    ExtractionConfig, ImageExtractionConfig, LanguageDetectionConfig, OcrConfig, OcrPageSelection, OutputFormat,
+    PageConfig, PostProcessorConfig, PreparedConfig, TokenReductionConfig,
 };
 
 #[cfg(feature = "api")]
 pub use core::server_config::ServerConfig;
 
 #[cfg(feature = "pdf")]
-pub use core::config::{HierarchyConfig, PdfConfig};
+pub use core::config::{ColumnMode, HierarchyConfig, PdfConfig};
 
 pub use core::mime::{
-    DOCX_MIME_TYPE, EXCEL_MIME_TYPE, HTML_MIME_TYPE, JSON_MIME_TYPE, MARKDOWN_MIME_TYPE, PDF_MIME_TYPE,
-    PLAIN_TEXT_MIME_TYPE, POWER_POINT_MIME_TYPE, XML_MIME_TYPE, detect_mime_type, detect_mime_type_from_bytes,
-    detect_or_validate, get_extensions_for_mime, validate_mime_type,
+    DOCX_MIME_TYPE, EXCEL_MIME_TYPE, HTML_MIME_TYPE, JSON_MIME_TYPE, MARKDOWN_MIME_TYPE, MimeCandidate, MimeDetection,
+    MimePolicy, MimeSource, PDF_MIME_TYPE, PLAIN_TEXT_MIME_TYPE, POWER_POINT_MIME_TYPE, XML_MIME_TYPE, detect_mime,
+    detect_mime_candidates, detect_mime_type, detect_mime_type_from_bytes, detect_or_validate, get_extensions_for_mime,
+    validate_mime_type,
 };
 
 pub use core::formats::{KNOWN_FORMATS, is_valid_format_field};
 
+pub use core::version::{VERSION, VersionMismatch, check_version_compatibility};
+
+pub use utils::{CacheStats, ClearedCaches, cache_stats, clear_all_caches};
+
 pub use plugins::registry::{
     get_document_extractor_registry, get_ocr_backend_registry, get_post_processor_registry, get_validator_registry,
 };
 
 #[cfg(feature = "embeddings")]
-pub use embeddings::{EMBEDDING_PRESETS, EmbeddingPreset, get_preset, list_presets};
+pub use embeddings::{
+    EMBEDDING_PRESETS, EmbeddedChunk, EmbeddingPreset, cosine_similarity, extract_and_embed,
+    extract_bytes_and_embed_sync, generate_embeddings, get_preset, list_presets, top_k_similar,
+};
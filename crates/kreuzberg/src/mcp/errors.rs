@@ -11,6 +11,7 @@ use std::fmt::Write;
 /// This function ensures different error types are properly differentiated in MCP responses:
 /// - `Validation` errors → `INVALID_PARAMS` (-32602)
 /// - `UnsupportedFormat` errors → `INVALID_PARAMS` (-32602)
+/// - `InputTooLarge` errors → `INVALID_PARAMS` (-32602)
 /// - `Parsing` errors → `PARSE_ERROR` (-32700)
 /// - `Io` errors → `INTERNAL_ERROR` (-32603) with context preserved
 /// - All other errors → `INTERNAL_ERROR` (-32603)
@@ -31,6 +32,11 @@ pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
             McpError::invalid_params(format!("Unsupported format: {}", mime_type), None)
         }
 
+        KreuzbergError::InputTooLarge { size, limit } => McpError::invalid_params(
+            format!("Input too large: {} bytes exceeds the limit of {} bytes", size, limit),
+            None,
+        ),
+
         KreuzbergError::MissingDependency(dep) => McpError::invalid_params(
             format!(
                 "Missing required dependency: {}. Please install it to use this feature.",
@@ -82,6 +88,14 @@ pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
             McpError::internal_error(error_message, None)
         }
 
+        KreuzbergError::Network { message, source } => {
+            let mut error_message = format!("Network error: {}", message);
+            if let Some(src) = source {
+                let _ = write!(error_message, " (caused by: {})", src);
+            }
+            McpError::internal_error(error_message, None)
+        }
+
         KreuzbergError::Plugin { message, plugin_name } => {
             McpError::internal_error(format!("Plugin '{}' error: {}", plugin_name, message), None)
         }
@@ -297,8 +297,12 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -322,13 +326,18 @@ mod tests {
                     vec!["A".to_string(), "B".to_string()],
                 ],
                 page_number: 1,
+                caption: None,
                 markdown: "| Col1 | Col2 |\n|------|------|\n| A    | B    |".to_string(),
             }],
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -361,8 +370,12 @@ mod tests {
                 },
             }]),
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -383,8 +396,12 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         };
 
@@ -651,6 +651,12 @@ mod tests {
                 language: "spa".to_string(),
                 tesseract_config: None,
                 output_format: None,
+                dpi: None,
+                psm: None,
+                oem: None,
+                binarization: None,
+                ocr_pages: None,
+                language_fallback: None,
             }),
             ..Default::default()
         };
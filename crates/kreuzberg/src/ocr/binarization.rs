@@ -0,0 +1,212 @@
+//! Image binarization applied before OCR.
+//!
+//! Tesseract thresholds every image with a single global value. That works for clean
+//! scans but fails when lighting varies across the page: ink on a bright region can be
+//! lighter than paper in a shaded one. Binarizing up front with a method suited to the
+//! input avoids this.
+
+use std::borrow::Cow;
+use std::io::Cursor;
+
+use image::{GrayImage, ImageFormat, Luma};
+
+use crate::core::config::BinarizationMethod;
+use crate::ocr::error::OcrError;
+
+/// Fraction below the neighbourhood mean at which adaptive thresholding marks a pixel as ink.
+const ADAPTIVE_THRESHOLD_OFFSET: f64 = 0.15;
+/// Adaptive thresholding window side as a fraction of the longer image side.
+const ADAPTIVE_WINDOW_DIVISOR: u32 = 16;
+/// Minimum adaptive thresholding window side, in pixels.
+const MIN_ADAPTIVE_WINDOW: u32 = 15;
+
+const INK: Luma<u8> = Luma([0]);
+const PAPER: Luma<u8> = Luma([255]);
+
+/// Binarize a grayscale image with the given method.
+///
+/// Returns black (0) ink on white (255) paper. [`BinarizationMethod::None`] returns
+/// the image unchanged.
+pub fn binarize(image: &GrayImage, method: BinarizationMethod) -> GrayImage {
+    match method {
+        BinarizationMethod::None => image.clone(),
+        BinarizationMethod::Otsu => {
+            let threshold = otsu_threshold(image);
+            GrayImage::from_fn(image.width(), image.height(), |x, y| {
+                if image.get_pixel(x, y)[0] <= threshold {
+                    INK
+                } else {
+                    PAPER
+                }
+            })
+        }
+        BinarizationMethod::Adaptive => adaptive_threshold(image),
+    }
+}
+
+/// Decode encoded image bytes, binarize them, and re-encode as PNG.
+///
+/// [`BinarizationMethod::None`] borrows the input without decoding it.
+pub(crate) fn binarize_image_bytes(image_bytes: &[u8], method: BinarizationMethod) -> Result<Cow<'_, [u8]>, OcrError> {
+    if method == BinarizationMethod::None {
+        return Ok(Cow::Borrowed(image_bytes));
+    }
+
+    let image = image::load_from_memory(image_bytes)
+        .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode image: {}", e)))?;
+    let binarized = binarize(&image.to_luma8(), method);
+
+    let mut encoded = Cursor::new(Vec::new());
+    binarized
+        .write_to(&mut encoded, ImageFormat::Png)
+        .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to encode binarized image: {}", e)))?;
+
+    Ok(Cow::Owned(encoded.into_inner()))
+}
+
+/// Pick the global threshold that maximizes between-class variance (Otsu's method).
+fn otsu_threshold(image: &GrayImage) -> u8 {
+    let mut histogram = [0u64; 256];
+    for pixel in image.pixels() {
+        histogram[pixel[0] as usize] += 1;
+    }
+
+    let total: u64 = histogram.iter().sum();
+    let weighted_total: f64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(value, &count)| value as f64 * count as f64)
+        .sum();
+
+    let mut best_threshold = 0u8;
+    let mut best_variance = 0.0;
+    let mut background_weight = 0u64;
+    let mut background_sum = 0.0;
+
+    for (value, &count) in histogram.iter().enumerate() {
+        background_weight += count;
+        if background_weight == 0 {
+            continue;
+        }
+        let foreground_weight = total - background_weight;
+        if foreground_weight == 0 {
+            break;
+        }
+
+        background_sum += value as f64 * count as f64;
+        let background_mean = background_sum / background_weight as f64;
+        let foreground_mean = (weighted_total - background_sum) / foreground_weight as f64;
+        let variance =
+            background_weight as f64 * foreground_weight as f64 * (background_mean - foreground_mean).powi(2);
+
+        if variance > best_variance {
+            best_variance = variance;
+            best_threshold = value as u8;
+        }
+    }
+
+    best_threshold
+}
+
+/// Threshold each pixel against the mean of its neighbourhood (Bradley-Roth).
+///
+/// Window sums come from an integral image, so the cost is independent of window size.
+fn adaptive_threshold(image: &GrayImage) -> GrayImage {
+    let (width, height) = image.dimensions();
+    let half_window = ((width.max(height) / ADAPTIVE_WINDOW_DIVISOR).max(MIN_ADAPTIVE_WINDOW)) / 2;
+
+    let stride = width as usize + 1;
+    let mut integral = vec![0u64; stride * (height as usize + 1)];
+    for y in 0..height as usize {
+        let mut row_sum = 0u64;
+        for x in 0..width as usize {
+            row_sum += u64::from(image.get_pixel(x as u32, y as u32)[0]);
+            integral[(y + 1) * stride + x + 1] = integral[y * stride + x + 1] + row_sum;
+        }
+    }
+
+    GrayImage::from_fn(width, height, |x, y| {
+        let x0 = x.saturating_sub(half_window) as usize;
+        let y0 = y.saturating_sub(half_window) as usize;
+        let x1 = (x + half_window + 1).min(width) as usize;
+        let y1 = (y + half_window + 1).min(height) as usize;
+
+        let sum = (integral[y1 * stride + x1] + integral[y0 * stride + x0])
+            - (integral[y0 * stride + x1] + integral[y1 * stride + x0]);
+        let mean = sum as f64 / ((x1 - x0) * (y1 - y0)) as f64;
+
+        if f64::from(image.get_pixel(x, y)[0]) < mean * (1.0 - ADAPTIVE_THRESHOLD_OFFSET) {
+            INK
+        } else {
+            PAPER
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Dark squares on a background whose brightness ramps from left to right.
+    fn unevenly_lit_image() -> GrayImage {
+        GrayImage::from_fn(200, 40, |x, y| {
+            let lighting = 40.0 + 190.0 * f64::from(x) / 199.0;
+            let is_ink = (15..25).contains(&y) && (x / 10) % 2 == 0;
+            Luma([(if is_ink { lighting * 0.6 } else { lighting }) as u8])
+        })
+    }
+
+    fn ink_mask(image: &GrayImage) -> Vec<bool> {
+        image.pixels().map(|pixel| pixel[0] == 0).collect()
+    }
+
+    #[test]
+    fn test_none_leaves_image_unchanged() {
+        let image = unevenly_lit_image();
+        assert_eq!(binarize(&image, BinarizationMethod::None), image);
+    }
+
+    #[test]
+    fn test_otsu_separates_bimodal_image() {
+        let image = GrayImage::from_fn(10, 10, |x, _| if x < 3 { Luma([30]) } else { Luma([220]) });
+        let binarized = binarize(&image, BinarizationMethod::Otsu);
+
+        assert_eq!(binarized.get_pixel(0, 0)[0], 0);
+        assert_eq!(binarized.get_pixel(9, 9)[0], 255);
+    }
+
+    #[test]
+    fn test_adaptive_handles_uneven_lighting() {
+        let image = unevenly_lit_image();
+        let expected: Vec<bool> = image
+            .enumerate_pixels()
+            .map(|(x, y, _)| (15..25).contains(&y) && (x / 10) % 2 == 0)
+            .collect();
+
+        let adaptive_errors = ink_mask(&binarize(&image, BinarizationMethod::Adaptive))
+            .iter()
+            .zip(&expected)
+            .filter(|(actual, expected)| actual != expected)
+            .count();
+        let otsu_errors = ink_mask(&binarize(&image, BinarizationMethod::Otsu))
+            .iter()
+            .zip(&expected)
+            .filter(|(actual, expected)| actual != expected)
+            .count();
+
+        assert!(
+            adaptive_errors * 10 < expected.len(),
+            "adaptive misclassified {adaptive_errors} pixels"
+        );
+        assert!(adaptive_errors < otsu_errors);
+    }
+
+    #[test]
+    fn test_binarize_image_bytes_none_borrows_input() {
+        let bytes = b"not decoded";
+        assert!(matches!(
+            binarize_image_bytes(bytes, BinarizationMethod::None),
+            Ok(Cow::Borrowed(_))
+        ));
+    }
+}
@@ -38,6 +38,7 @@
 //! kreuzberg = { version = "4.0", features = ["ocr"] }
 //! ```
 mod backends;
+pub mod binarization;
 pub mod cache;
 pub mod error;
 pub mod hocr;
@@ -49,12 +50,13 @@ pub mod types;
 pub mod utils;
 pub mod validation;
 
+pub use binarization::binarize;
 pub use cache::{OcrCache, OcrCacheStats};
 pub use error::OcrError;
 pub use hocr::convert_hocr_to_markdown;
 pub use language_registry::LanguageRegistry;
 pub use processor::OcrProcessor;
-pub use table::{HocrWord, extract_words_from_tsv, reconstruct_table, table_to_markdown};
+pub use table::{HocrWord, extract_words_from_tsv, reconstruct_table, reconstruct_tables, table_to_markdown};
 pub use tesseract_backend::TesseractBackend;
 pub use types::{BatchItemResult, ExtractionResult, PSMMode, Table, TesseractConfig};
 pub use utils::compute_hash;
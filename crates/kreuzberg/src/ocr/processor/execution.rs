@@ -11,7 +11,7 @@ use crate::core::config::ExtractionConfig;
 use crate::ocr::cache::OcrCache;
 use crate::ocr::error::OcrError;
 use crate::ocr::hocr::convert_hocr_to_markdown;
-use crate::ocr::table::{extract_words_from_tsv, reconstruct_table, table_to_markdown};
+use crate::ocr::table::{extract_words_from_tsv, reconstruct_tables, table_to_markdown};
 use crate::ocr::types::{BatchItemResult, TesseractConfig};
 use crate::types::{OcrExtractionResult, OcrTable};
 use kreuzberg_tesseract::{TessPageSegMode, TesseractAPI};
@@ -265,30 +265,28 @@ pub(super) fn perform_ocr(
 
         let words = extract_words_from_tsv(&tsv_data, config.table_min_confidence)?;
 
-        if !words.is_empty() {
-            let table = reconstruct_table(&words, config.table_column_threshold, config.table_row_threshold_ratio);
-            if !table.is_empty() {
-                metadata.insert("table_count".to_string(), serde_json::Value::String("1".to_string()));
-                metadata.insert(
-                    "tables_detected".to_string(),
-                    serde_json::Value::String("1".to_string()),
-                );
-                metadata.insert(
-                    "table_rows".to_string(),
-                    serde_json::Value::String(table.len().to_string()),
-                );
-                metadata.insert(
-                    "table_cols".to_string(),
-                    serde_json::Value::String(table[0].len().to_string()),
-                );
-
-                let markdown_table = table_to_markdown(&table);
-                tables.push(OcrTable {
-                    cells: table,
-                    markdown: markdown_table,
-                    page_number: 0,
-                });
-            }
+        let detected = reconstruct_tables(&words, config.table_column_threshold, config.table_row_threshold_ratio);
+        if let Some(first) = detected.first() {
+            let count = detected.len().to_string();
+            metadata.insert("table_count".to_string(), serde_json::Value::String(count.clone()));
+            metadata.insert("tables_detected".to_string(), serde_json::Value::String(count));
+            metadata.insert(
+                "table_rows".to_string(),
+                serde_json::Value::String(first.len().to_string()),
+            );
+            metadata.insert(
+                "table_cols".to_string(),
+                serde_json::Value::String(first[0].len().to_string()),
+            );
+        }
+
+        for table in detected {
+            let markdown_table = table_to_markdown(&table);
+            tables.push(OcrTable {
+                cells: table,
+                markdown: markdown_table,
+                page_number: 0,
+            });
         }
     }
 
@@ -348,6 +346,61 @@ fn resolve_config_language(config: &TesseractConfig) -> Result<Option<TesseractC
     }
 }
 
+/// Pick the installed Tesseract language pack matching the text of an image.
+///
+/// Runs a first OCR pass with `fallback`, detects the language of the recognized text
+/// and returns the matching language pack. Returns `fallback` when the text is too short
+/// to detect, or when the detected language has no installed pack (logged as a warning).
+pub(super) fn detect_image_language(image_bytes: &[u8], fallback: &str) -> Result<String, OcrError> {
+    let first_pass = TesseractConfig {
+        language: fallback.to_string(),
+        use_cache: false,
+        ..TesseractConfig::default()
+    };
+    let text = perform_ocr(image_bytes, &first_pass, None)?.content;
+
+    let Some(language) = detect_text_language(&text) else {
+        return Ok(fallback.to_string());
+    };
+    let language = tesseract_language_code(&language);
+
+    let installed = resolve_all_installed_languages(&resolve_tessdata_path()).unwrap_or_default();
+    if installed
+        .split('+')
+        .any(|installed_language| installed_language == language)
+    {
+        Ok(language.to_string())
+    } else {
+        tracing::warn!(
+            "Detected OCR language '{}' but its Tesseract language pack is not installed; using '{}'",
+            language,
+            fallback
+        );
+        Ok(fallback.to_string())
+    }
+}
+
+#[cfg(feature = "language-detection")]
+fn detect_text_language(text: &str) -> Option<String> {
+    crate::language_detection::detect_language(text).map(|guess| guess.code)
+}
+
+#[cfg(not(feature = "language-detection"))]
+fn detect_text_language(_text: &str) -> Option<String> {
+    None
+}
+
+/// Map an ISO 639-3 code to the name of its Tesseract language pack.
+fn tesseract_language_code(iso639_3: &str) -> &str {
+    match iso639_3 {
+        "cmn" => "chi_sim",
+        "nob" => "nor",
+        "pes" => "fas",
+        "tgl" => "fil",
+        code => code,
+    }
+}
+
 /// Process an image and return OCR results, using cache if enabled.
 ///
 /// Resolves the `"all"` / `"*"` language wildcard, then delegates to
@@ -515,6 +568,13 @@ mod tests {
         assert!(!is_all_languages(""));
     }
 
+    #[test]
+    fn test_tesseract_language_code() {
+        assert_eq!(tesseract_language_code("deu"), "deu");
+        assert_eq!(tesseract_language_code("cmn"), "chi_sim");
+        assert_eq!(tesseract_language_code("pes"), "fas");
+    }
+
     #[test]
     fn test_resolve_config_language_passthrough() {
         let config = TesseractConfig {
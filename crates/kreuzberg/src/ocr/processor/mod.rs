@@ -57,6 +57,14 @@ impl OcrProcessor {
         execution::process_image_with_cache(image_bytes, config, &self.cache, Some(output_format))
     }
 
+    /// Detect the language of an image and return the matching installed language pack.
+    ///
+    /// Runs a first OCR pass with `fallback` and detects the language of its text. Returns
+    /// `fallback` when no installed language pack matches the detected language.
+    pub fn detect_language(&self, image_bytes: &[u8], fallback: &str) -> Result<String, OcrError> {
+        execution::detect_image_language(image_bytes, fallback)
+    }
+
     pub fn clear_cache(&self) -> Result<(), OcrError> {
         self.cache.clear()
     }
@@ -0,0 +1,240 @@
+//! Table reconstruction from OCR word bounding boxes.
+//!
+//! Words are grouped into rows by vertical alignment, each row is split into cells
+//! at horizontal gaps wider than the column threshold, and consecutive rows with
+//! several cells are aligned into shared columns. Only runs that form a grid of at
+//! least two rows and two columns become tables, so ordinary single-column text
+//! yields no tables.
+
+use super::HocrWord;
+
+const MIN_TABLE_ROWS: usize = 2;
+const MIN_TABLE_COLUMNS: usize = 2;
+
+struct Cell {
+    left: u32,
+    right: u32,
+    text: String,
+}
+
+/// Reconstruct all grid tables from a page of OCR words.
+///
+/// # Arguments
+///
+/// * `words` - Recognized words with bounding boxes (pixel coordinates)
+/// * `column_gap` - Minimum horizontal gap in pixels that separates two cells in a row
+/// * `row_threshold_ratio` - Maximum vertical center offset, relative to the median
+///   word height, for two words to share a row
+///
+/// # Returns
+///
+/// One `rows × columns` cell grid per detected table, in top-to-bottom order.
+pub fn reconstruct_tables(words: &[HocrWord], column_gap: u32, row_threshold_ratio: f64) -> Vec<Vec<Vec<String>>> {
+    let rows: Vec<Vec<Cell>> = group_rows(words, row_threshold_ratio)
+        .into_iter()
+        .map(|row| split_cells(row, column_gap))
+        .collect();
+
+    let mut tables = Vec::new();
+    let mut run: Vec<Vec<Cell>> = Vec::new();
+
+    for row in rows {
+        if row.len() >= MIN_TABLE_COLUMNS {
+            run.push(row);
+            continue;
+        }
+        if let Some(table) = build_grid(std::mem::take(&mut run)) {
+            tables.push(table);
+        }
+    }
+    if let Some(table) = build_grid(run) {
+        tables.push(table);
+    }
+
+    tables
+}
+
+/// Group words into rows by vertical center, top to bottom.
+fn group_rows(words: &[HocrWord], row_threshold_ratio: f64) -> Vec<Vec<&HocrWord>> {
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let mut heights: Vec<u32> = words.iter().map(|w| w.height).collect();
+    heights.sort_unstable();
+    let median_height = heights[heights.len() / 2] as f64;
+    let tolerance = (median_height * row_threshold_ratio).max(1.0);
+
+    let mut sorted: Vec<&HocrWord> = words.iter().collect();
+    sorted.sort_by(|a, b| a.y_center().total_cmp(&b.y_center()).then(a.left.cmp(&b.left)));
+
+    let mut rows: Vec<Vec<&HocrWord>> = Vec::new();
+    let mut row_center = 0.0;
+
+    for word in sorted {
+        match rows.last_mut() {
+            Some(row) if (word.y_center() - row_center).abs() <= tolerance => {
+                row.push(word);
+                row_center = row.iter().map(|w| w.y_center()).sum::<f64>() / row.len() as f64;
+            }
+            _ => {
+                row_center = word.y_center();
+                rows.push(vec![word]);
+            }
+        }
+    }
+
+    rows
+}
+
+/// Split a row into cells wherever the gap between neighbouring words reaches `column_gap`.
+fn split_cells(mut row: Vec<&HocrWord>, column_gap: u32) -> Vec<Cell> {
+    row.sort_by_key(|w| w.left);
+
+    let mut cells: Vec<Cell> = Vec::new();
+    for word in row {
+        match cells.last_mut() {
+            Some(cell) if word.left.saturating_sub(cell.right) < column_gap => {
+                cell.text.push(' ');
+                cell.text.push_str(&word.text);
+                cell.right = cell.right.max(word.right());
+            }
+            _ => cells.push(Cell {
+                left: word.left,
+                right: word.right(),
+                text: word.text.clone(),
+            }),
+        }
+    }
+
+    cells
+}
+
+/// Align the cells of a run of multi-cell rows into columns.
+///
+/// Columns are the union of overlapping horizontal cell extents across the run.
+/// Returns `None` when the run is too short or collapses to a single column.
+fn build_grid(rows: Vec<Vec<Cell>>) -> Option<Vec<Vec<String>>> {
+    if rows.len() < MIN_TABLE_ROWS {
+        return None;
+    }
+
+    let mut extents: Vec<(u32, u32)> = rows.iter().flatten().map(|c| (c.left, c.right)).collect();
+    extents.sort_unstable();
+
+    let mut columns: Vec<(u32, u32)> = Vec::new();
+    for (left, right) in extents {
+        match columns.last_mut() {
+            Some(column) if left <= column.1 => column.1 = column.1.max(right),
+            _ => columns.push((left, right)),
+        }
+    }
+
+    if columns.len() < MIN_TABLE_COLUMNS {
+        return None;
+    }
+
+    let grid = rows
+        .into_iter()
+        .map(|row| {
+            let mut cells = vec![String::new(); columns.len()];
+            for cell in row {
+                let index = columns
+                    .iter()
+                    .position(|&(left, right)| cell.left >= left && cell.left <= right)
+                    .unwrap_or(columns.len() - 1);
+                if !cells[index].is_empty() {
+                    cells[index].push(' ');
+                }
+                cells[index].push_str(&cell.text);
+            }
+            cells
+        })
+        .collect();
+
+    Some(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, left: u32, top: u32, width: u32) -> HocrWord {
+        HocrWord {
+            text: text.to_string(),
+            left,
+            top,
+            width,
+            height: 20,
+            confidence: 95.0,
+        }
+    }
+
+    #[test]
+    fn test_reconstruct_tables_grid() {
+        let words = vec![
+            word("Name", 10, 10, 60),
+            word("City", 200, 12, 50),
+            word("Age", 400, 10, 40),
+            word("Alice", 10, 50, 60),
+            word("New", 200, 50, 40),
+            word("York", 248, 51, 50),
+            word("30", 400, 50, 30),
+            word("Bob", 10, 90, 45),
+            word("Paris", 200, 90, 60),
+            word("25", 400, 89, 30),
+        ];
+
+        let tables = reconstruct_tables(&words, 50, 0.5);
+
+        assert_eq!(tables.len(), 1);
+        assert_eq!(
+            tables[0],
+            vec![
+                vec!["Name", "City", "Age"],
+                vec!["Alice", "New York", "30"],
+                vec!["Bob", "Paris", "25"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_tables_single_column_text_yields_none() {
+        let words = vec![
+            word("The", 10, 10, 40),
+            word("quick", 58, 10, 60),
+            word("brown", 126, 10, 60),
+            word("fox", 10, 40, 35),
+            word("jumps", 53, 40, 60),
+            word("over", 121, 40, 50),
+            word("the", 10, 70, 35),
+            word("dog", 53, 70, 40),
+        ];
+
+        assert!(reconstruct_tables(&words, 50, 0.5).is_empty());
+    }
+
+    #[test]
+    fn test_reconstruct_tables_ignores_surrounding_prose() {
+        let words = vec![
+            word("Quarterly", 10, 10, 100),
+            word("results", 118, 10, 80),
+            word("Q1", 10, 50, 30),
+            word("100", 300, 50, 40),
+            word("Q2", 10, 90, 30),
+            word("120", 300, 90, 40),
+            word("End", 10, 130, 40),
+            word("of", 58, 130, 20),
+            word("report", 86, 130, 70),
+        ];
+
+        let tables = reconstruct_tables(&words, 50, 0.5);
+
+        assert_eq!(tables, vec![vec![vec!["Q1", "100"], vec!["Q2", "120"]]]);
+    }
+
+    #[test]
+    fn test_reconstruct_tables_empty() {
+        assert!(reconstruct_tables(&[], 50, 0.5).is_empty());
+    }
+}
@@ -1,4 +1,6 @@
+pub mod layout;
 pub mod tsv_parser;
 
 pub use html_to_markdown_rs::hocr::{HocrWord, reconstruct_table, table_to_markdown};
+pub use layout::reconstruct_tables;
 pub use tsv_parser::extract_words_from_tsv;
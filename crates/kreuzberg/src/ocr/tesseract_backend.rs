@@ -4,7 +4,8 @@
 //! trait, bridging the plugin system with the low-level OcrProcessor.
 
 use crate::Result;
-use crate::core::config::OcrConfig;
+use crate::core::config::{BinarizationMethod, OcrConfig};
+use crate::ocr::binarization::binarize_image_bytes;
 use crate::ocr::processor::OcrProcessor;
 use crate::plugins::{OcrBackend, OcrBackendType, Plugin};
 use crate::types::ExtractionResult;
@@ -87,15 +88,23 @@ impl TesseractBackend {
     /// Convert OcrConfig to internal TesseractConfig.
     ///
     /// Uses tesseract_config from OcrConfig if provided, otherwise uses defaults
-    /// with the language from OcrConfig.
+    /// with the language from OcrConfig. The top-level `psm` and `oem` settings
+    /// take precedence over the ones in tesseract_config.
     fn config_to_tesseract(&self, config: &OcrConfig) -> InternalTesseractConfig {
-        match &config.tesseract_config {
+        let mut tess_config = match &config.tesseract_config {
             Some(tess_config) => Self::convert_config(tess_config),
             None => InternalTesseractConfig {
                 language: config.language.clone(),
                 ..Default::default()
             },
+        };
+        if let Some(psm) = config.psm {
+            tess_config.psm = psm;
+        }
+        if let Some(oem) = config.oem {
+            tess_config.oem = oem;
         }
+        tess_config
     }
 
     /// Get cached available languages, lazily querying Tesseract if needed.
@@ -177,16 +186,25 @@ impl Plugin for TesseractBackend {
 #[async_trait]
 impl OcrBackend for TesseractBackend {
     async fn process_image(&self, image_bytes: &[u8], config: &OcrConfig) -> Result<ExtractionResult> {
+        config.validate()?;
+        if config.is_auto_language() {
+            let language = self.resolve_language(image_bytes, config).await?;
+            return self.process_image(image_bytes, &config.with_language(&language)).await;
+        }
         let tess_config = self.config_to_tesseract(config);
         let tess_config_clone = tess_config.clone();
         let output_format = config.output_format;
+        let binarization = config.binarization.unwrap_or_default();
 
         let processor = Arc::clone(&self.processor);
         let image_bytes = image_bytes.to_vec();
 
-        let ocr_result = tokio::task::spawn_blocking(move || match output_format {
-            Some(fmt) => processor.process_image_with_format(&image_bytes, &tess_config_clone, fmt),
-            None => processor.process_image(&image_bytes, &tess_config_clone),
+        let ocr_result = tokio::task::spawn_blocking(move || {
+            let image_bytes = binarize_image_bytes(&image_bytes, binarization)?;
+            match output_format {
+                Some(fmt) => processor.process_image_with_format(&image_bytes, &tess_config_clone, fmt),
+                None => processor.process_image(&image_bytes, &tess_config_clone),
+            }
         })
         .await
         .map_err(|e| crate::KreuzbergError::Plugin {
@@ -240,17 +258,28 @@ impl OcrBackend for TesseractBackend {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number,
+                    caption: None,
                 })
                 .collect(),
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             elements: None,
+            layout: None,
             djot_content: None,
         })
     }
 
     async fn process_file(&self, path: &Path, config: &OcrConfig) -> Result<ExtractionResult> {
+        config.validate()?;
+        if config.binarization.unwrap_or_default() != BinarizationMethod::None || config.is_auto_language() {
+            // Binarization and language detection work on image bytes, so go through the in-memory path.
+            let bytes = crate::core::io::read_file_async(path).await?;
+            return self.process_image(&bytes, config).await;
+        }
         let tess_config = self.config_to_tesseract(config);
         let tess_config_clone = tess_config.clone();
         let output_format = config.output_format;
@@ -314,16 +343,38 @@ impl OcrBackend for TesseractBackend {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number,
+                    caption: None,
                 })
                 .collect(),
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             elements: None,
+            layout: None,
             djot_content: None,
         })
     }
 
+    async fn resolve_language(&self, image_bytes: &[u8], config: &OcrConfig) -> Result<String> {
+        let processor = Arc::clone(&self.processor);
+        let image_bytes = image_bytes.to_vec();
+        let fallback = config.fallback_language().to_string();
+
+        tokio::task::spawn_blocking(move || processor.detect_language(&image_bytes, &fallback))
+            .await
+            .map_err(|e| crate::KreuzbergError::Plugin {
+                message: format!("Tesseract task panicked: {}", e),
+                plugin_name: "tesseract".to_string(),
+            })?
+            .map_err(|e| crate::KreuzbergError::Ocr {
+                message: format!("Tesseract language detection failed: {}", e),
+                source: Some(Box::new(e)),
+            })
+    }
+
     fn supports_language(&self, lang: &str) -> bool {
         self.get_cached_languages().contains(&lang.to_string())
     }
@@ -399,6 +450,12 @@ mod tests {
             language: "deu".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         };
 
         let tess_config = backend.config_to_tesseract(&ocr_config);
@@ -421,6 +478,12 @@ mod tests {
             language: "eng".to_string(),
             tesseract_config: Some(custom_tess_config),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         };
 
         let tess_config = backend.config_to_tesseract(&ocr_config);
@@ -429,6 +492,25 @@ mod tests {
         assert!(tess_config.enable_table_detection);
     }
 
+    #[test]
+    fn test_config_to_tesseract_psm_oem_override_tesseract_config() {
+        let backend = TesseractBackend::new().unwrap();
+        let ocr_config = OcrConfig {
+            tesseract_config: Some(crate::types::TesseractConfig {
+                psm: 6,
+                oem: 1,
+                ..Default::default()
+            }),
+            psm: Some(11),
+            oem: Some(3),
+            ..Default::default()
+        };
+
+        let tess_config = backend.config_to_tesseract(&ocr_config);
+        assert_eq!(tess_config.psm, 11);
+        assert_eq!(tess_config.oem, 3);
+    }
+
     #[test]
     fn test_tesseract_backend_default() {
         let backend = TesseractBackend::default();
@@ -465,6 +547,12 @@ mod tests {
             language: "eng".to_string(),
             tesseract_config: Some(custom_tess_config),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         };
 
         let tess_config = backend.config_to_tesseract(&ocr_config);
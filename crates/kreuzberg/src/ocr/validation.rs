@@ -1,5 +1,6 @@
 use super::error::OcrError;
 use super::utils::MINIMAL_SUPPORTED_TESSERACT_VERSION;
+use crate::core::config::ocr::AUTO_OCR_LANGUAGE;
 use std::collections::HashSet;
 
 lazy_static::lazy_static! {
@@ -137,6 +138,11 @@ pub fn validate_language_code(lang_code: &str) -> Result<(), OcrError> {
         return Ok(());
     }
 
+    // "auto" is resolved to detected languages before Tesseract runs
+    if lower == AUTO_OCR_LANGUAGE {
+        return Ok(());
+    }
+
     for code in lang_code.split('+') {
         if !TESSERACT_SUPPORTED_LANGUAGE_CODES.contains(code) {
             return Err(OcrError::InvalidLanguageCode(format!(
@@ -170,6 +176,12 @@ mod tests {
         assert!(validate_language_code("All").is_ok());
     }
 
+    #[test]
+    fn test_validate_language_code_auto() {
+        assert!(validate_language_code("auto").is_ok());
+        assert!(validate_language_code("AUTO").is_ok());
+    }
+
     #[test]
     fn test_validate_language_code_valid() {
         assert!(validate_language_code("eng").is_ok());
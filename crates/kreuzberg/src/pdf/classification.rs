@@ -0,0 +1,121 @@
+//! Scanned vs digital document classification.
+//!
+//! A page counts as digital when it carries enough extractable text, and as scanned
+//! when it has (almost) no text but images cover most of it. Pages that are neither,
+//! such as blank separator pages, do not affect the result.
+
+use super::metadata::PageReport;
+use crate::types::DocumentKind;
+use pdfium_render::prelude::*;
+
+/// Minimum non-whitespace characters for a page to count as having a text layer.
+const MIN_TEXT_CHARS: usize = 16;
+/// Minimum fraction of the page area covered by images for a textless page to count as scanned.
+const MIN_IMAGE_COVERAGE: f32 = 0.5;
+
+/// Classification of a single page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PageKind {
+    /// The page has extractable text
+    Text,
+    /// The page is covered by images and has no extractable text
+    Image,
+    /// The page has neither text nor significant image coverage
+    Empty,
+}
+
+/// Classify a document as digital, scanned, or mixed from its pages.
+pub fn classify_document(document: &PdfDocument<'_>) -> DocumentKind {
+    analyze_document(document).0
+}
+
+/// Classify a document and report the native text yield of each page in one pass.
+pub(crate) fn analyze_document(document: &PdfDocument<'_>) -> (DocumentKind, Vec<PageReport>) {
+    let (kinds, reports): (Vec<PageKind>, Vec<PageReport>) = document
+        .pages()
+        .iter()
+        .enumerate()
+        .map(|(index, page)| analyze_page(index + 1, &page))
+        .unzip();
+    (combine_page_kinds(kinds), reports)
+}
+
+fn analyze_page(page_number: usize, page: &PdfPage<'_>) -> (PageKind, PageReport) {
+    let char_count = page.text().map(|text| count_text_chars(&text.all())).unwrap_or(0);
+    let image_areas: Vec<f32> = page
+        .objects()
+        .iter()
+        .filter(|object| object.object_type() == PdfPageObjectType::Image)
+        .map(|object| {
+            object
+                .bounds()
+                .map(|bounds| bounds.width().value.abs() * bounds.height().value.abs())
+                .unwrap_or(0.0)
+        })
+        .collect();
+
+    let page_area = page.width().value * page.height().value;
+    let kind = if char_count >= MIN_TEXT_CHARS {
+        PageKind::Text
+    } else if page_area > 0.0 && image_areas.iter().sum::<f32>() / page_area >= MIN_IMAGE_COVERAGE {
+        PageKind::Image
+    } else {
+        PageKind::Empty
+    };
+
+    let report = PageReport {
+        page: page_number,
+        char_count,
+        has_images: !image_areas.is_empty(),
+        ocr_used: false,
+    };
+    (kind, report)
+}
+
+/// Count the characters of page text that carry content, i.e. everything but whitespace.
+pub(crate) fn count_text_chars(text: &str) -> usize {
+    text.chars().filter(|c| !c.is_whitespace()).count()
+}
+
+/// Combine per-page classifications into a document kind.
+///
+/// Documents with no text or image pages at all are reported as digital, since
+/// there is nothing for OCR to recover.
+pub(crate) fn combine_page_kinds(pages: impl IntoIterator<Item = PageKind>) -> DocumentKind {
+    let (mut text_pages, mut image_pages) = (0usize, 0usize);
+    for kind in pages {
+        match kind {
+            PageKind::Text => text_pages += 1,
+            PageKind::Image => image_pages += 1,
+            PageKind::Empty => {}
+        }
+    }
+
+    match (text_pages, image_pages) {
+        (_, 0) => DocumentKind::Digital,
+        (0, _) => DocumentKind::Scanned,
+        _ => DocumentKind::Mixed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combine_page_kinds() {
+        use PageKind::*;
+
+        assert_eq!(combine_page_kinds([Text, Text, Empty]), DocumentKind::Digital);
+        assert_eq!(combine_page_kinds([Image, Empty, Image]), DocumentKind::Scanned);
+        assert_eq!(combine_page_kinds([Text, Image]), DocumentKind::Mixed);
+        assert_eq!(combine_page_kinds([Empty]), DocumentKind::Digital);
+        assert_eq!(combine_page_kinds([]), DocumentKind::Digital);
+    }
+
+    #[test]
+    fn test_count_text_chars_ignores_whitespace() {
+        assert_eq!(count_text_chars(" \n\t "), 0);
+        assert_eq!(count_text_chars("Page 1\n\u{a0}text"), 9);
+    }
+}
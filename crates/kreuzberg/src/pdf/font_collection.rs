@@ -0,0 +1,300 @@
+//! TrueType/OpenType font collection (`.ttc`/`.otc`) parsing.
+//!
+//! A collection file packs several font faces that share table data. Pdfium expects one
+//! standalone font per descriptor, so this module enumerates the faces in a collection,
+//! reads each face's family and subfamily from its `name` table, and rebuilds a standalone
+//! sfnt for each face by copying the tables its directory references.
+
+use super::error::PdfError;
+
+const TTC_TAG: &[u8; 4] = b"ttcf";
+const NAME_TAG: &[u8; 4] = b"name";
+const SFNT_HEADER_LEN: usize = 12;
+const TABLE_RECORD_LEN: usize = 16;
+
+const NAME_ID_FAMILY: u16 = 1;
+const NAME_ID_SUBFAMILY: u16 = 2;
+const NAME_ID_TYPOGRAPHIC_FAMILY: u16 = 16;
+
+const PLATFORM_UNICODE: u16 = 0;
+const PLATFORM_MACINTOSH: u16 = 1;
+const PLATFORM_WINDOWS: u16 = 3;
+const WINDOWS_LANGUAGE_EN_US: u16 = 0x0409;
+
+/// A single face inside a font collection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CollectionFace {
+    /// Byte offset of the face's table directory within the collection file
+    pub offset: u32,
+    /// Family name from the `name` table (typographic family preferred)
+    pub family: String,
+    /// Subfamily name from the `name` table (e.g. "Bold", "W6")
+    pub subfamily: String,
+}
+
+/// Check whether font data starts with a collection header.
+pub(crate) fn is_font_collection(data: &[u8]) -> bool {
+    data.get(..4) == Some(TTC_TAG.as_slice())
+}
+
+/// Enumerate the faces in a font collection.
+///
+/// Faces without a readable family name are skipped.
+///
+/// # Errors
+///
+/// Returns an error if the data is not a collection or its header is truncated.
+pub(crate) fn enumerate_collection_faces(data: &[u8]) -> Result<Vec<CollectionFace>, PdfError> {
+    if !is_font_collection(data) {
+        return Err(PdfError::FontLoadingFailed("Not a font collection".to_string()));
+    }
+
+    let num_fonts = read_u32(data, 8).ok_or_else(|| truncated("collection header"))? as usize;
+    let mut faces = Vec::with_capacity(num_fonts.min(64));
+
+    for index in 0..num_fonts {
+        let offset = read_u32(data, 12 + index * 4).ok_or_else(|| truncated("collection offsets"))?;
+        let Some((family, subfamily)) = read_face_names(data, offset as usize) else {
+            continue;
+        };
+        faces.push(CollectionFace {
+            offset,
+            family,
+            subfamily,
+        });
+    }
+
+    Ok(faces)
+}
+
+/// Build a standalone sfnt font for the face whose table directory starts at `offset`.
+///
+/// Table data is copied unchanged and realigned to four-byte boundaries; only the
+/// table offsets in the directory are rewritten.
+///
+/// # Errors
+///
+/// Returns an error if the table directory or any table lies outside the data.
+pub(crate) fn extract_collection_face(data: &[u8], offset: u32) -> Result<Vec<u8>, PdfError> {
+    let offset = offset as usize;
+    let num_tables = read_u16(data, offset + 4).ok_or_else(|| truncated("table directory"))? as usize;
+    let directory_len = SFNT_HEADER_LEN + num_tables * TABLE_RECORD_LEN;
+    let directory = data
+        .get(offset..offset + directory_len)
+        .ok_or_else(|| truncated("table directory"))?;
+
+    let mut font = directory.to_vec();
+    for table in 0..num_tables {
+        let record = SFNT_HEADER_LEN + table * TABLE_RECORD_LEN;
+        let table_offset = read_u32(directory, record + 8).ok_or_else(|| truncated("table record"))? as usize;
+        let table_len = read_u32(directory, record + 12).ok_or_else(|| truncated("table record"))? as usize;
+        let table_data = data
+            .get(table_offset..table_offset + table_len)
+            .ok_or_else(|| truncated("table data"))?;
+
+        let new_offset = font.len() as u32;
+        font[record + 8..record + 12].copy_from_slice(&new_offset.to_be_bytes());
+        font.extend_from_slice(table_data);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+
+    Ok(font)
+}
+
+/// Read the family and subfamily names of the face at `offset`.
+fn read_face_names(data: &[u8], offset: usize) -> Option<(String, String)> {
+    let name_table = find_table(data, offset, NAME_TAG)?;
+
+    let family = read_name(data, name_table, NAME_ID_TYPOGRAPHIC_FAMILY)
+        .or_else(|| read_name(data, name_table, NAME_ID_FAMILY))?;
+    let subfamily = read_name(data, name_table, NAME_ID_SUBFAMILY).unwrap_or_default();
+
+    Some((family, subfamily))
+}
+
+/// Find a table by tag in the directory at `offset`, returning its absolute offset.
+fn find_table(data: &[u8], offset: usize, tag: &[u8; 4]) -> Option<usize> {
+    let num_tables = read_u16(data, offset + 4)? as usize;
+    (0..num_tables)
+        .map(|table| offset + SFNT_HEADER_LEN + table * TABLE_RECORD_LEN)
+        .find(|&record| data.get(record..record + 4) == Some(tag.as_slice()))
+        .and_then(|record| read_u32(data, record + 8))
+        .map(|table_offset| table_offset as usize)
+}
+
+/// Read a name string from the `name` table at `table`, preferring English Windows records.
+fn read_name(data: &[u8], table: usize, name_id: u16) -> Option<String> {
+    let count = read_u16(data, table + 2)? as usize;
+    let storage = table + read_u16(data, table + 4)? as usize;
+
+    let mut best: Option<(u8, String)> = None;
+    for index in 0..count {
+        let record = table + 6 + index * 12;
+        if read_u16(data, record + 6)? != name_id {
+            continue;
+        }
+
+        let platform = read_u16(data, record)?;
+        let language = read_u16(data, record + 4)?;
+        let rank = match (platform, language) {
+            (PLATFORM_WINDOWS, WINDOWS_LANGUAGE_EN_US) => 0,
+            (PLATFORM_WINDOWS, _) => 1,
+            (PLATFORM_UNICODE, _) => 2,
+            (PLATFORM_MACINTOSH, 0) => 3,
+            _ => continue,
+        };
+        if best.as_ref().is_some_and(|(best_rank, _)| *best_rank <= rank) {
+            continue;
+        }
+
+        let length = read_u16(data, record + 8)? as usize;
+        let start = storage + read_u16(data, record + 10)? as usize;
+        let Some(bytes) = data.get(start..start + length) else {
+            continue;
+        };
+
+        let name = if platform == PLATFORM_MACINTOSH {
+            bytes.iter().map(|&b| b as char).collect()
+        } else {
+            let units: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        };
+
+        let name = name.trim().to_string();
+        if !name.is_empty() {
+            best = Some((rank, name));
+        }
+    }
+
+    best.map(|(_, name)| name)
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn truncated(what: &str) -> PdfError {
+    PdfError::FontLoadingFailed(format!("Truncated font collection: invalid {}", what))
+}
+
+/// Build a minimal font collection for tests.
+///
+/// Each face has a table directory with a single `name` table holding Windows
+/// English family (ID 1) and subfamily (ID 2) records.
+#[cfg(test)]
+pub(crate) fn build_test_collection(faces: &[(&str, &str)]) -> Vec<u8> {
+    fn utf16(s: &str) -> Vec<u8> {
+        s.encode_utf16().flat_map(u16::to_be_bytes).collect()
+    }
+
+    fn name_table(family: &str, subfamily: &str) -> Vec<u8> {
+        let family = utf16(family);
+        let subfamily = utf16(subfamily);
+        let mut table = Vec::new();
+        table.extend_from_slice(&0u16.to_be_bytes());
+        table.extend_from_slice(&2u16.to_be_bytes());
+        table.extend_from_slice(&(6u16 + 2 * 12).to_be_bytes());
+        for (name_id, len, offset) in [
+            (NAME_ID_FAMILY, family.len(), 0),
+            (NAME_ID_SUBFAMILY, subfamily.len(), family.len()),
+        ] {
+            for value in [
+                PLATFORM_WINDOWS,
+                1,
+                WINDOWS_LANGUAGE_EN_US,
+                name_id,
+                len as u16,
+                offset as u16,
+            ] {
+                table.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+        table.extend_from_slice(&family);
+        table.extend_from_slice(&subfamily);
+        table
+    }
+
+    let header_len = 12 + faces.len() * 4;
+    let directory_len = SFNT_HEADER_LEN + TABLE_RECORD_LEN;
+
+    let mut data = Vec::new();
+    data.extend_from_slice(TTC_TAG);
+    data.extend_from_slice(&[0, 1, 0, 0]);
+    data.extend_from_slice(&(faces.len() as u32).to_be_bytes());
+
+    let mut directories = Vec::new();
+    let mut tables = Vec::new();
+    let tables_start = header_len + faces.len() * directory_len;
+
+    for (index, (family, subfamily)) in faces.iter().enumerate() {
+        data.extend_from_slice(&((header_len + index * directory_len) as u32).to_be_bytes());
+
+        let table = name_table(family, subfamily);
+        let table_offset = tables_start + tables.len();
+        directories.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+        directories.extend_from_slice(&1u16.to_be_bytes());
+        directories.extend_from_slice(&[0; 6]);
+        directories.extend_from_slice(NAME_TAG);
+        directories.extend_from_slice(&0u32.to_be_bytes());
+        directories.extend_from_slice(&(table_offset as u32).to_be_bytes());
+        directories.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        tables.extend_from_slice(&table);
+        tables.resize(tables.len().next_multiple_of(4), 0);
+    }
+
+    data.extend_from_slice(&directories);
+    data.extend_from_slice(&tables);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enumerate_collection_faces() {
+        let data = build_test_collection(&[("Noto Sans CJK JP", "Regular"), ("Noto Serif CJK JP", "Bold")]);
+
+        let faces = enumerate_collection_faces(&data).unwrap();
+
+        assert_eq!(faces.len(), 2);
+        assert_eq!(faces[0].family, "Noto Sans CJK JP");
+        assert_eq!(faces[0].subfamily, "Regular");
+        assert_eq!(faces[1].family, "Noto Serif CJK JP");
+        assert_eq!(faces[1].subfamily, "Bold");
+    }
+
+    #[test]
+    fn test_extract_collection_face_is_standalone() {
+        let data = build_test_collection(&[("First", "Regular"), ("Second", "Italic")]);
+        let faces = enumerate_collection_faces(&data).unwrap();
+
+        let face = extract_collection_face(&data, faces[1].offset).unwrap();
+
+        assert!(!is_font_collection(&face));
+        assert_eq!(
+            read_face_names(&face, 0),
+            Some(("Second".to_string(), "Italic".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_enumerate_rejects_non_collection() {
+        assert!(enumerate_collection_faces(&[0, 1, 0, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_enumerate_truncated_header() {
+        let mut data = build_test_collection(&[("Only", "Regular")]);
+        data.truncate(10);
+        assert!(enumerate_collection_faces(&data).is_err());
+    }
+}
@@ -18,6 +18,11 @@
 //! - **Linux**: `/usr/share/fonts`, `/usr/local/share/fonts`
 //! - **Windows**: `C:\Windows\Fonts`
 //!
+//! Font collections (`.ttc`/`.otc`) are enumerated face by face and each face is registered
+//! under the family name from its `name` table. Pdfium needs a standalone font per
+//! descriptor, so each face's font data is rebuilt from the collection bytes when the
+//! collection is loaded.
+//!
 //! # Example
 //!
 //! ```rust,no_run
@@ -35,6 +40,7 @@
 //! ```
 
 use super::error::PdfError;
+use super::font_collection::{enumerate_collection_faces, extract_collection_face, is_font_collection};
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -42,9 +48,9 @@ use std::sync::Arc;
 use std::sync::RwLock;
 
 #[cfg(feature = "pdf")]
-use pdfium_render::prelude::FontDescriptor;
+use pdfium_render::prelude::{FontDescriptor, PdfDocument, PdfPageObjectsCommon};
 
-/// Global font cache: maps font paths to loaded bytes.
+/// Global font cache: maps font identifiers to loaded fonts.
 ///
 /// Uses `Arc<[u8]>` for zero-copy sharing when passing fonts to multiple Pdfium instances.
 /// Protected by `RwLock` for concurrent read access during PDF processing.
@@ -57,12 +63,22 @@ static FONT_CACHE: Lazy<RwLock<FontCacheState>> = Lazy::new(|| {
 
 /// Internal state for the font cache.
 struct FontCacheState {
-    /// Map from font path (relative identifier) to loaded font bytes
-    fonts: HashMap<String, Arc<[u8]>>,
+    /// Map from font identifier (file name, plus `#index` for collection faces) to cached font
+    fonts: HashMap<String, CachedFont>,
     /// Whether the cache has been initialized
     initialized: bool,
 }
 
+/// A single cached font face.
+struct CachedFont {
+    /// Family name reported to Pdfium
+    family: String,
+    /// Style hint used to derive weight and slant (subfamily name or file name)
+    style: String,
+    /// Standalone font data for the face
+    data: Arc<[u8]>,
+}
+
 /// Platform-specific font directory paths.
 #[cfg(target_os = "macos")]
 fn system_font_directories() -> Vec<PathBuf> {
@@ -94,7 +110,7 @@ fn system_font_directories() -> Vec<PathBuf> {
 ///
 /// # Arguments
 ///
-/// * `path` - Path to the font file (.ttf or .otf)
+/// * `path` - Path to the font file (.ttf, .otf, .ttc or .otc)
 ///
 /// # Returns
 ///
@@ -105,15 +121,81 @@ fn load_font_file(path: &Path) -> Result<Arc<[u8]>, PdfError> {
         .map_err(|e| PdfError::FontLoadingFailed(format!("Failed to read font file '{}': {}", path.display(), e)))
 }
 
+/// Register a loaded font file in a font map.
+///
+/// Plain font files are keyed by file name and use the file stem as family. Font
+/// collections register one entry per face, keyed as `filename#index`, using the family
+/// and subfamily from each face's `name` table. Faces that cannot be extracted are skipped.
+fn register_font_file(fonts: &mut HashMap<String, CachedFont>, filename: &str, data: Arc<[u8]>) {
+    if !is_font_collection(&data) {
+        fonts.insert(
+            filename.to_string(),
+            CachedFont {
+                family: filename.split('.').next().unwrap_or("Unknown").to_string(),
+                style: filename.to_string(),
+                data,
+            },
+        );
+        return;
+    }
+
+    let faces = match enumerate_collection_faces(&data) {
+        Ok(faces) => faces,
+        Err(e) => {
+            tracing::debug!("Failed to read font collection '{}': {}", filename, e);
+            return;
+        }
+    };
+
+    for (index, face) in faces.into_iter().enumerate() {
+        let face_data = match extract_collection_face(&data, face.offset) {
+            Ok(face_data) => face_data,
+            Err(e) => {
+                tracing::debug!(
+                    "Failed to extract face {} of font collection '{}': {}",
+                    index,
+                    filename,
+                    e
+                );
+                continue;
+            }
+        };
+        fonts.insert(
+            format!("{}#{}", filename, index),
+            CachedFont {
+                family: face.family,
+                style: face.subfamily,
+                data: Arc::from(face_data.into_boxed_slice()),
+            },
+        );
+    }
+}
+
+/// Build a Pdfium font descriptor for a cached font.
+fn font_descriptor(font: &CachedFont) -> FontDescriptor {
+    let style = font.style.to_lowercase();
+    let is_italic = style.contains("italic") || style.contains("oblique");
+    let is_bold = style.contains("bold");
+    let weight = if is_bold { 700 } else { 400 };
+
+    FontDescriptor {
+        family: font.family.clone(),
+        weight,
+        is_italic,
+        charset: 0,
+        data: font.data.clone(),
+    }
+}
+
 /// Discover and load all system fonts.
 ///
-/// Scans platform-specific font directories and loads all .ttf and .otf files.
+/// Scans platform-specific font directories and loads all .ttf, .otf, .ttc and .otc files.
 /// Font files larger than 50MB are skipped to prevent memory issues.
 ///
 /// # Returns
 ///
-/// A HashMap mapping font identifiers (relative paths) to loaded font bytes.
-fn discover_system_fonts() -> Result<HashMap<String, Arc<[u8]>>, PdfError> {
+/// A HashMap mapping font identifiers to cached fonts.
+fn discover_system_fonts() -> Result<HashMap<String, CachedFont>, PdfError> {
     let mut fonts = HashMap::new();
     const MAX_FONT_SIZE: u64 = 50 * 1024 * 1024;
 
@@ -129,7 +211,7 @@ fn discover_system_fonts() -> Result<HashMap<String, Arc<[u8]>>, PdfError> {
 
                     if let Some(ext) = path.extension() {
                         let ext_str = ext.to_string_lossy().to_lowercase();
-                        if ext_str != "ttf" && ext_str != "otf" {
+                        if !matches!(ext_str.as_str(), "ttf" | "otf" | "ttc" | "otc") {
                             continue;
                         }
 
@@ -149,8 +231,7 @@ fn discover_system_fonts() -> Result<HashMap<String, Arc<[u8]>>, PdfError> {
                         match load_font_file(&path) {
                             Ok(font_data) => {
                                 if let Some(filename) = path.file_name() {
-                                    let key = filename.to_string_lossy().to_string();
-                                    fonts.insert(key, font_data);
+                                    register_font_file(&mut fonts, &filename.to_string_lossy(), font_data);
                                 }
                             }
                             Err(_e) => {
@@ -232,25 +313,7 @@ pub fn get_font_descriptors() -> Result<Vec<FontDescriptor>, PdfError> {
         .read()
         .map_err(|e| PdfError::FontLoadingFailed(format!("Font cache lock poisoned: {}", e)))?;
 
-    let descriptors = cache
-        .fonts
-        .iter()
-        .map(|(filename, data)| {
-            let is_italic = filename.to_lowercase().contains("italic");
-            let is_bold = filename.to_lowercase().contains("bold");
-            let weight = if is_bold { 700 } else { 400 };
-
-            let family = filename.split('.').next().unwrap_or("Unknown").to_string();
-
-            FontDescriptor {
-                family,
-                weight,
-                is_italic,
-                charset: 0,
-                data: data.clone(),
-            }
-        })
-        .collect();
+    let descriptors = cache.fonts.values().map(font_descriptor).collect();
 
     Ok(descriptors)
 }
@@ -266,23 +329,69 @@ pub fn cached_font_count() -> usize {
     FONT_CACHE.read().map(|cache| cache.fonts.len()).unwrap_or(0)
 }
 
-/// Clear the font cache (for testing purposes).
+/// Clear the font cache.
 ///
-/// # Panics
-///
-/// Panics if the cache lock is poisoned, which should only happen in test scenarios
-/// with deliberate panic injection.
-#[cfg(test)]
+/// Drops all cached font data; the next call to [`initialize_font_cache`] rediscovers
+/// system fonts. Used by tests and by benchmarks that need to start from a cold cache.
+/// Fonts already handed to Pdfium stay loaded, since the Pdfium bindings are a
+/// process-wide singleton that cannot be reset.
 pub fn clear_font_cache() {
-    let mut cache = FONT_CACHE.write().expect("Failed to acquire write lock");
+    let mut cache = FONT_CACHE.write().unwrap_or_else(|poisoned| poisoned.into_inner());
     cache.fonts.clear();
     cache.initialized = false;
 }
 
+/// Base names of the standard 14 PDF fonts, which viewers must provide without embedding.
+const STANDARD_FONT_FAMILIES: [&str; 5] = ["Courier", "Helvetica", "Times", "Symbol", "ZapfDingbats"];
+
+/// Whether `name` is one of the standard 14 PDF fonts (e.g. `Helvetica-Bold`, `Times-Roman`).
+fn is_standard_font(name: &str) -> bool {
+    STANDARD_FONT_FAMILIES.iter().any(|family| {
+        name.strip_prefix(family)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+    })
+}
+
+/// Find fonts used by text on any page that are neither embedded nor standard fonts.
+///
+/// Pdfium substitutes such fonts from the system font cache, so the extracted text may
+/// still be correct, but glyph metrics (and therefore layout-derived output such as
+/// heading levels) can differ from the original. Returns sorted, de-duplicated names.
+#[cfg(feature = "pdf")]
+pub fn find_missing_fonts(document: &PdfDocument<'_>) -> Vec<String> {
+    let mut missing = std::collections::BTreeSet::new();
+
+    for page in document.pages().iter() {
+        for object in page.objects().iter() {
+            let Some(text_object) = object.as_text_object() else {
+                continue;
+            };
+            let font = text_object.font();
+            let name = font.name();
+
+            if !name.is_empty() && !font.is_embedded().unwrap_or(true) && !is_standard_font(&name) {
+                missing.insert(name);
+            }
+        }
+    }
+
+    missing.into_iter().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_standard_font() {
+        assert!(is_standard_font("Helvetica"));
+        assert!(is_standard_font("Helvetica-BoldOblique"));
+        assert!(is_standard_font("Times-Roman"));
+        assert!(is_standard_font("ZapfDingbats"));
+        assert!(!is_standard_font("TimesNewRoman"));
+        assert!(!is_standard_font("Garamond"));
+    }
+
     #[test]
     fn test_initialize_font_cache() {
         clear_font_cache();
@@ -337,6 +446,48 @@ mod tests {
         assert!(result.is_err(), "Loading nonexistent file should fail with error");
     }
 
+    #[test]
+    fn test_register_font_collection_registers_each_family() {
+        let collection = crate::pdf::font_collection::build_test_collection(&[
+            ("Noto Sans CJK JP", "Regular"),
+            ("Noto Sans CJK KR", "Bold"),
+            ("Noto Serif CJK SC", "Italic"),
+        ]);
+        let mut fonts = HashMap::new();
+
+        register_font_file(&mut fonts, "NotoCJK.ttc", Arc::from(collection.into_boxed_slice()));
+
+        let mut families: Vec<&str> = fonts.values().map(|font| font.family.as_str()).collect();
+        families.sort_unstable();
+        families.dedup();
+        assert!(families.len() > 1, "Collection should register several families");
+        assert_eq!(families, ["Noto Sans CJK JP", "Noto Sans CJK KR", "Noto Serif CJK SC"]);
+
+        let bold = font_descriptor(&fonts["NotoCJK.ttc#1"]);
+        assert_eq!(bold.family, "Noto Sans CJK KR");
+        assert_eq!(bold.weight, 700);
+        assert!(!is_font_collection(&bold.data));
+
+        let italic = font_descriptor(&fonts["NotoCJK.ttc#2"]);
+        assert!(italic.is_italic);
+    }
+
+    #[test]
+    fn test_register_plain_font_file_uses_file_stem() {
+        let mut fonts = HashMap::new();
+
+        register_font_file(
+            &mut fonts,
+            "DejaVuSans-BoldItalic.ttf",
+            Arc::from(vec![0u8, 1, 0, 0].into_boxed_slice()),
+        );
+
+        let descriptor = font_descriptor(&fonts["DejaVuSans-BoldItalic.ttf"]);
+        assert_eq!(descriptor.family, "DejaVuSans-BoldItalic");
+        assert_eq!(descriptor.weight, 700);
+        assert!(descriptor.is_italic);
+    }
+
     #[test]
     fn test_font_descriptors_attributes() {
         clear_font_cache();
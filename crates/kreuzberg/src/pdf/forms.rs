@@ -0,0 +1,120 @@
+//! PDF form field (AcroForm) value extraction.
+//!
+//! Filled forms store their values in field dictionaries rather than in the page
+//! content stream, so text extraction never sees them. This module reads the values
+//! of text fields, checkboxes, radio groups and choice fields through pdfium.
+//! Signature fields and push buttons carry no user-entered value and are skipped.
+
+use pdfium_render::prelude::*;
+use std::collections::BTreeMap;
+
+/// Value reported for a checked checkbox.
+const CHECKED: &str = "on";
+/// Value reported for an unchecked checkbox or a radio group with no selection.
+const UNCHECKED: &str = "off";
+
+/// Extract form field values from a PDF document, keyed by fully qualified field name.
+///
+/// - Text, combo box and list box fields map to their current value (empty fields are omitted)
+/// - Checkboxes map to `"on"` or `"off"`
+/// - Radio groups map to the export value of the selected button, or `"off"` when none is selected
+///
+/// Returns an empty map when the document has no interactive form.
+pub fn extract_form_fields(document: &PdfDocument) -> BTreeMap<String, String> {
+    let mut fields = BTreeMap::new();
+
+    if document.form().is_none() {
+        return fields;
+    }
+
+    for page in document.pages().iter() {
+        for annotation in page.annotations().iter() {
+            let Some(field) = annotation.as_form_field() else {
+                continue;
+            };
+            let Some(name) = field.name().filter(|name| !name.is_empty()) else {
+                continue;
+            };
+
+            match field {
+                PdfFormField::Text(text) => {
+                    if let Some(value) = text.value().filter(|value| !value.is_empty()) {
+                        fields.insert(name, value);
+                    }
+                }
+                PdfFormField::ComboBox(combo) => {
+                    if let Some(value) = combo.value().filter(|value| !value.is_empty()) {
+                        fields.insert(name, value);
+                    }
+                }
+                PdfFormField::ListBox(list) => {
+                    if let Some(value) = list.value().filter(|value| !value.is_empty()) {
+                        fields.insert(name, value);
+                    }
+                }
+                PdfFormField::Checkbox(checkbox) => {
+                    let value = if checkbox.is_checked().unwrap_or(false) {
+                        CHECKED
+                    } else {
+                        UNCHECKED
+                    };
+                    fields.insert(name, value.to_string());
+                }
+                PdfFormField::RadioButton(radio) => {
+                    // Each button of a group is a separate widget sharing the group name;
+                    // only the checked one determines the value.
+                    if radio.is_checked().unwrap_or(false) {
+                        let value = radio.group_value().filter(|value| !value.is_empty());
+                        fields.insert(name, value.unwrap_or_else(|| CHECKED.to_string()));
+                    } else {
+                        fields.entry(name).or_insert_with(|| UNCHECKED.to_string());
+                    }
+                }
+                PdfFormField::Signature(_) | PdfFormField::PushButton(_) | PdfFormField::Unknown(_) => {}
+            }
+        }
+    }
+
+    fields
+}
+
+/// Render form field values as a labeled text section of `name: value` lines.
+///
+/// Returns an empty string when there are no fields.
+pub fn format_form_fields(fields: &BTreeMap<String, String>) -> String {
+    if fields.is_empty() {
+        return String::new();
+    }
+
+    let mut section = String::from("Form Fields\n");
+    for (name, value) in fields {
+        section.push_str(name);
+        section.push_str(": ");
+        section.push_str(value);
+        section.push('\n');
+    }
+    section
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_form_fields() {
+        let fields = BTreeMap::from([
+            ("agree_terms".to_string(), "on".to_string()),
+            ("full_name".to_string(), "Jane Doe".to_string()),
+        ]);
+
+        assert_eq!(
+            format_form_fields(&fields),
+            "Form Fields\nagree_terms: on\nfull_name: Jane Doe\n"
+        );
+    }
+
+    #[test]
+    fn test_format_empty_form_fields() {
+        assert_eq!(format_form_fields(&BTreeMap::new()), "");
+    }
+}
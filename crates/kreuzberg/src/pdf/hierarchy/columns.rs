@@ -0,0 +1,281 @@
+//! Column detection for multi-column PDF layouts.
+//!
+//! Text blocks are projected onto the x-axis; vertical gaps that no block crosses
+//! are gutters between columns. Blocks spanning most of the page (titles, full-width
+//! figure captions) are ignored during detection so they do not bridge the gutters.
+
+use super::extraction::TextBlock;
+
+/// Blocks wider than this fraction of the text extent span several columns and are
+/// ignored when looking for gutters.
+const SPANNING_BLOCK_RATIO: f32 = 0.5;
+
+/// A detected text column, as a horizontal extent in PDF units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Column {
+    /// Left edge of the column
+    pub left: f32,
+    /// Right edge of the column
+    pub right: f32,
+}
+
+impl Column {
+    /// Width of the column in PDF units.
+    pub fn width(&self) -> f32 {
+        self.right - self.left
+    }
+}
+
+/// Detect text columns from block positions.
+///
+/// Column candidates narrower than `min_column_width` are merged into a neighbour,
+/// and while more than `max_columns` remain, the narrowest column is merged into the
+/// neighbour across the narrower gutter. A `max_columns` of 0 or 1 always yields a
+/// single column.
+///
+/// Returns columns ordered left to right, or an empty vector when there are no blocks.
+pub fn detect_columns(blocks: &[TextBlock], max_columns: usize, min_column_width: f32) -> Vec<Column> {
+    let Some(extent) = text_extent(blocks) else {
+        return Vec::new();
+    };
+
+    if max_columns <= 1 {
+        return vec![extent];
+    }
+
+    let mut spans: Vec<Column> = blocks
+        .iter()
+        .filter(|block| block.bbox.width() <= extent.width() * SPANNING_BLOCK_RATIO)
+        .map(|block| Column {
+            left: block.bbox.left,
+            right: block.bbox.right,
+        })
+        .collect();
+    spans.sort_by(|a, b| a.left.total_cmp(&b.left));
+
+    let mut columns: Vec<Column> = Vec::new();
+    for span in spans {
+        match columns.last_mut() {
+            Some(last) if span.left <= last.right => last.right = last.right.max(span.right),
+            _ => columns.push(span),
+        }
+    }
+
+    if columns.is_empty() {
+        return vec![extent];
+    }
+
+    // Spanning blocks were skipped above, so stretch the outer columns to the full extent
+    columns[0].left = extent.left;
+    if let Some(last) = columns.last_mut() {
+        last.right = extent.right;
+    }
+
+    while columns.len() > 1 {
+        let narrowest = (0..columns.len())
+            .min_by(|&a, &b| columns[a].width().total_cmp(&columns[b].width()))
+            .expect("columns is not empty");
+
+        if columns.len() <= max_columns && columns[narrowest].width() >= min_column_width {
+            break;
+        }
+
+        merge_into_closest_neighbour(&mut columns, narrowest);
+    }
+
+    columns
+}
+
+/// Split the text extent of the blocks into `count` columns of equal width.
+///
+/// Used instead of [`detect_columns`] when the column layout of a corpus is known.
+/// A `count` of 0 or 1 yields a single column. Returns an empty vector when there
+/// are no blocks.
+pub fn split_columns_evenly(blocks: &[TextBlock], count: usize) -> Vec<Column> {
+    let Some(extent) = text_extent(blocks) else {
+        return Vec::new();
+    };
+
+    let count = count.max(1);
+    let width = extent.width() / count as f32;
+    (0..count)
+        .map(|idx| Column {
+            left: extent.left + width * idx as f32,
+            right: if idx + 1 == count {
+                extent.right
+            } else {
+                extent.left + width * (idx + 1) as f32
+            },
+        })
+        .collect()
+}
+
+/// Horizontal extent covered by all blocks.
+fn text_extent(blocks: &[TextBlock]) -> Option<Column> {
+    blocks
+        .iter()
+        .map(|block| Column {
+            left: block.bbox.left,
+            right: block.bbox.right,
+        })
+        .reduce(|a, b| Column {
+            left: a.left.min(b.left),
+            right: a.right.max(b.right),
+        })
+}
+
+/// Merge `columns[idx]` into the adjacent column across the narrower gutter.
+fn merge_into_closest_neighbour(columns: &mut Vec<Column>, idx: usize) {
+    let gap_before = idx.checked_sub(1).map(|prev| columns[idx].left - columns[prev].right);
+    let gap_after = columns.get(idx + 1).map(|next| next.left - columns[idx].right);
+
+    let target = match (gap_before, gap_after) {
+        (Some(before), Some(after)) if after < before => idx + 1,
+        (Some(_), _) => idx - 1,
+        (None, _) => idx + 1,
+    };
+
+    let removed = columns.remove(idx);
+    let target = if target > idx { target - 1 } else { target };
+    columns[target].left = columns[target].left.min(removed.left);
+    columns[target].right = columns[target].right.max(removed.right);
+}
+
+/// Order blocks for reading: column by column, top to bottom within each column.
+///
+/// Each block belongs to the column its left edge falls in, so blocks spanning several
+/// columns are read together with the first of them.
+pub fn order_blocks_by_columns(mut blocks: Vec<TextBlock>, columns: &[Column]) -> Vec<TextBlock> {
+    if columns.len() <= 1 {
+        return blocks;
+    }
+
+    let column_of = |block: &TextBlock| {
+        columns
+            .iter()
+            .position(|column| block.bbox.left <= column.right)
+            .unwrap_or(columns.len() - 1)
+    };
+
+    blocks.sort_by(|a, b| {
+        column_of(a)
+            .cmp(&column_of(b))
+            .then_with(|| a.bbox.top.total_cmp(&b.bbox.top))
+            .then_with(|| a.bbox.left.total_cmp(&b.bbox.left))
+    });
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pdf::hierarchy::BoundingBox;
+
+    fn block(text: &str, left: f32, right: f32, top: f32) -> TextBlock {
+        TextBlock {
+            text: text.to_string(),
+            bbox: BoundingBox {
+                left,
+                top,
+                right,
+                bottom: top + 12.0,
+            },
+            font_size: 12.0,
+        }
+    }
+
+    /// Four 100pt columns separated by gutters of 20, 40 and 30 points.
+    fn four_column_page() -> Vec<TextBlock> {
+        let lefts = [0.0, 120.0, 260.0, 390.0];
+        lefts
+            .iter()
+            .enumerate()
+            .flat_map(|(col, &left)| {
+                (0..3).map(move |row| block(&format!("c{}r{}", col, row), left, left + 100.0, row as f32 * 20.0))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_all_columns_within_limit() {
+        let columns = detect_columns(&four_column_page(), 4, 50.0);
+        assert_eq!(columns.len(), 4);
+    }
+
+    #[test]
+    fn test_max_columns_merges_down_to_limit() {
+        let columns = detect_columns(&four_column_page(), 2, 50.0);
+        assert_eq!(columns.len(), 2);
+        assert!(columns.iter().all(|column| column.width() > 0.0));
+        assert_eq!(columns[0].left, 0.0);
+        assert_eq!(columns[1].right, 490.0);
+    }
+
+    #[test]
+    fn test_max_columns_of_one_is_single_column() {
+        let columns = detect_columns(&four_column_page(), 1, 50.0);
+        assert_eq!(
+            columns,
+            vec![Column {
+                left: 0.0,
+                right: 490.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_slivers_are_merged() {
+        let mut blocks = four_column_page();
+        blocks.push(block("sliver", 600.0, 610.0, 0.0));
+
+        let columns = detect_columns(&blocks, 5, 50.0);
+        assert_eq!(columns.len(), 4);
+        assert!(columns.iter().all(|column| column.width() >= 50.0));
+    }
+
+    #[test]
+    fn test_spanning_title_does_not_bridge_gutters() {
+        let mut blocks = four_column_page();
+        blocks.push(block("title", 0.0, 490.0, -30.0));
+
+        assert_eq!(detect_columns(&blocks, 4, 50.0).len(), 4);
+    }
+
+    #[test]
+    fn test_split_columns_evenly() {
+        let columns = split_columns_evenly(&four_column_page(), 2);
+        assert_eq!(
+            columns,
+            vec![
+                Column {
+                    left: 0.0,
+                    right: 245.0
+                },
+                Column {
+                    left: 245.0,
+                    right: 490.0
+                },
+            ]
+        );
+
+        assert_eq!(split_columns_evenly(&four_column_page(), 0).len(), 1);
+        assert!(split_columns_evenly(&[], 2).is_empty());
+    }
+
+    #[test]
+    fn test_blocks_ordered_column_by_column() {
+        let blocks = vec![
+            block("right-top", 200.0, 300.0, 0.0),
+            block("left-top", 0.0, 100.0, 0.0),
+            block("right-bottom", 200.0, 300.0, 20.0),
+            block("left-bottom", 0.0, 100.0, 20.0),
+        ];
+        let columns = detect_columns(&blocks, 3, 50.0);
+
+        let ordered: Vec<String> = order_blocks_by_columns(blocks, &columns)
+            .into_iter()
+            .map(|block| block.text)
+            .collect();
+        assert_eq!(ordered, vec!["left-top", "left-bottom", "right-top", "right-bottom"]);
+    }
+}
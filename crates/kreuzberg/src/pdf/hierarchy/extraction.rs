@@ -6,6 +6,7 @@
 
 use super::bounding_box::BoundingBox;
 use super::clustering::FontSizeCluster;
+use super::markup::render_chars_with_markup;
 use crate::core::config::ExtractionConfig;
 use crate::pdf::error::{PdfError, Result};
 use pdfium_render::prelude::*;
@@ -338,6 +339,19 @@ pub fn extract_chars_with_fonts(page: &PdfPage) -> Result<Vec<CharData>> {
 /// 4. Merge characters into blocks based on proximity thresholds
 /// 5. Return sorted blocks by position (top to bottom, left to right)
 pub fn merge_chars_into_blocks(chars: Vec<CharData>) -> Vec<TextBlock> {
+    merge_chars_into_blocks_with(chars, |block| block.iter().map(|c| c.text.as_str()).collect())
+}
+
+/// Merge characters into text blocks whose text is rendered as markdown.
+///
+/// Blocks are formed as in [`merge_chars_into_blocks`]. Raised and lowered characters
+/// attached to a word are emitted as `^superscript^` and `~subscript~`, and the words
+/// in `struck_words` (see [`find_struck_words`](super::find_struck_words)) as `~~struck~~`.
+pub fn merge_chars_into_markdown_blocks(chars: Vec<CharData>, struck_words: &[BoundingBox]) -> Vec<TextBlock> {
+    merge_chars_into_blocks_with(chars, |block| render_chars_with_markup(block, struck_words))
+}
+
+fn merge_chars_into_blocks_with(chars: Vec<CharData>, render: impl Fn(&[CharData]) -> String) -> Vec<TextBlock> {
     if chars.is_empty() {
         return Vec::new();
     }
@@ -437,7 +451,7 @@ pub fn merge_chars_into_blocks(chars: Vec<CharData>) -> Vec<TextBlock> {
     blocks
         .into_iter()
         .map(|block| {
-            let text = block.iter().map(|c| c.text.clone()).collect::<String>();
+            let text = render(&block);
 
             // Calculate bounding box and average font size in a single fold operation
             let (min_x, min_y, max_x, max_y, total_font_size) = block.iter().fold(
@@ -560,4 +574,62 @@ mod tests {
         assert_eq!(cloned.text, char_data.text);
         assert_eq!(cloned.font_size, char_data.font_size);
     }
+
+    #[test]
+    fn test_merge_chars_emits_superscript_markup() {
+        let chars = vec![
+            CharData {
+                text: "x".to_string(),
+                x: 100.0,
+                y: 50.0,
+                font_size: 12.0,
+                width: 6.0,
+                height: 12.0,
+            },
+            CharData {
+                text: "2".to_string(),
+                x: 106.0,
+                y: 54.0,
+                font_size: 8.0,
+                width: 4.0,
+                height: 8.0,
+            },
+        ];
+
+        let blocks = merge_chars_into_markdown_blocks(chars.clone(), &[]);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].text, "x^2^");
+
+        let blocks = merge_chars_into_blocks(chars);
+        assert_eq!(blocks[0].text, "x2");
+    }
+
+    #[test]
+    fn test_merge_chars_emits_strikethrough_markup() {
+        let chars: Vec<CharData> = "old new"
+            .chars()
+            .enumerate()
+            .map(|(i, ch)| CharData {
+                text: ch.to_string(),
+                x: 100.0 + i as f32 * 6.0,
+                y: 50.0,
+                font_size: 12.0,
+                width: 6.0,
+                height: 12.0,
+            })
+            .collect();
+        let strike_line = crate::pdf::hierarchy::HorizontalSegment {
+            left: 99.0,
+            right: 119.0,
+            y: 56.0,
+        };
+
+        let struck_words = crate::pdf::hierarchy::find_struck_words(&chars, &[strike_line]);
+        let blocks = merge_chars_into_markdown_blocks(chars.clone(), &struck_words);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].text, "~~old~~ new");
+
+        let blocks = merge_chars_into_blocks(chars);
+        assert_eq!(blocks[0].text, "old new");
+    }
 }
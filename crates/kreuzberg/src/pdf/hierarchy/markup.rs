@@ -0,0 +1,114 @@
+//! Inline markdown rendering for PDF text.
+//!
+//! Combines the per-character styling recovered from the page (baseline shifts and
+//! strike lines) into markdown: `^superscript^`, `~subscript~` and `~~struck~~`.
+
+use super::bounding_box::BoundingBox;
+use super::extraction::CharData;
+use super::script::{ScriptPosition, detect_script_positions};
+use super::strikethrough::is_in_struck_word;
+
+/// Concatenate character text, wrapping styled runs in inline markdown.
+///
+/// Superscript runs are wrapped in `^`, subscript runs in `~` and runs inside
+/// `struck_words` in `~~`. Script markup is nested inside strikethrough markup.
+pub fn render_chars_with_markup(chars: &[CharData], struck_words: &[BoundingBox]) -> String {
+    let positions = detect_script_positions(chars);
+    let mut text = String::with_capacity(chars.len() + 4);
+    let mut script = ScriptPosition::Normal;
+    let mut struck = false;
+
+    for (c, &position) in chars.iter().zip(&positions) {
+        let is_struck = is_in_struck_word(c, struck_words);
+        if is_struck != struck {
+            text.extend(script.delimiter());
+            text.push_str("~~");
+            script = ScriptPosition::Normal;
+            struck = is_struck;
+        }
+        if position != script {
+            text.extend(script.delimiter());
+            text.extend(position.delimiter());
+            script = position;
+        }
+        text.push_str(&c.text);
+    }
+    text.extend(script.delimiter());
+    if struck {
+        text.push_str("~~");
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn char_at(text: &str, x: f32, y: f32, font_size: f32) -> CharData {
+        CharData {
+            text: text.to_string(),
+            x,
+            y,
+            font_size,
+            width: font_size * 0.5,
+            height: font_size,
+        }
+    }
+
+    #[test]
+    fn test_superscript_markup() {
+        let chars = vec![
+            char_at("x", 10.0, 100.0, 12.0),
+            char_at("2", 16.0, 104.0, 8.0),
+            char_at(" ", 20.0, 100.0, 12.0),
+            char_at("y", 26.0, 100.0, 12.0),
+        ];
+
+        assert_eq!(render_chars_with_markup(&chars, &[]), "x^2^ y");
+    }
+
+    #[test]
+    fn test_subscript_markup() {
+        let chars = vec![
+            char_at("H", 10.0, 100.0, 12.0),
+            char_at("2", 18.0, 97.0, 8.0),
+            char_at("O", 22.0, 100.0, 12.0),
+        ];
+
+        assert_eq!(render_chars_with_markup(&chars, &[]), "H~2~O");
+    }
+
+    #[test]
+    fn test_strikethrough_markup() {
+        let chars = vec![
+            char_at("a", 10.0, 100.0, 12.0),
+            char_at(" ", 16.0, 100.0, 12.0),
+            char_at("b", 22.0, 100.0, 12.0),
+            char_at("c", 28.0, 100.0, 12.0),
+            char_at(" ", 34.0, 100.0, 12.0),
+            char_at("d", 40.0, 100.0, 12.0),
+        ];
+        let struck_words = [BoundingBox {
+            left: 22.0,
+            top: 88.0,
+            right: 34.0,
+            bottom: 100.0,
+        }];
+
+        assert_eq!(render_chars_with_markup(&chars, &struck_words), "a ~~bc~~ d");
+    }
+
+    #[test]
+    fn test_script_nested_in_strikethrough() {
+        let chars = vec![char_at("x", 10.0, 100.0, 12.0), char_at("2", 16.0, 104.0, 8.0)];
+        let struck_words = [BoundingBox {
+            left: 10.0,
+            top: 88.0,
+            right: 20.0,
+            bottom: 104.0,
+        }];
+
+        assert_eq!(render_chars_with_markup(&chars, &struck_words), "~~x^2^~~");
+    }
+}
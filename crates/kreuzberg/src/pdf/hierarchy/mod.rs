@@ -7,12 +7,23 @@
 
 mod bounding_box;
 mod clustering;
+mod columns;
 mod extraction;
+mod markup;
+pub(crate) mod prepared;
+mod script;
+mod strikethrough;
 
 // Re-export all public types and functions for backward compatibility
 pub use bounding_box::BoundingBox;
 pub use clustering::{FontSizeCluster, cluster_font_sizes};
+pub use columns::{Column, detect_columns, order_blocks_by_columns};
 pub use extraction::{
     CharData, HierarchyBlock, HierarchyLevel, KMeansResult, TextBlock, assign_hierarchy_levels,
-    assign_hierarchy_levels_from_clusters, extract_chars_with_fonts, merge_chars_into_blocks, should_trigger_ocr,
+    assign_hierarchy_levels_from_clusters, extract_chars_with_fonts, merge_chars_into_blocks,
+    merge_chars_into_markdown_blocks, should_trigger_ocr,
 };
+pub use markup::render_chars_with_markup;
+pub use prepared::{PreparedDocument, render_from_prepared};
+pub use script::{ScriptPosition, detect_script_positions};
+pub use strikethrough::{HorizontalSegment, extract_horizontal_segments, find_struck_words};
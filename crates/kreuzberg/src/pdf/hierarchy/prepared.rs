@@ -0,0 +1,314 @@
+//! Reusable per-page character data for repeated hierarchy extraction.
+//!
+//! Extracting characters with font metrics is the expensive part of hierarchy
+//! extraction. A [`PreparedDocument`] does it once, so tools that sweep
+//! [`HierarchyConfig`] options can re-run block merging and font size clustering
+//! without reading the PDF again.
+
+use super::clustering::cluster_font_sizes;
+use super::columns::{detect_columns, order_blocks_by_columns, split_columns_evenly};
+use super::extraction::{
+    CharData, HierarchyLevel, KMeansResult, TextBlock, assign_hierarchy_levels, extract_chars_with_fonts,
+    merge_chars_into_blocks,
+};
+use crate::core::config::{ColumnMode, HierarchyConfig};
+use crate::pdf::error::Result;
+use crate::pdf::outline::assign_hierarchy_levels_from_outline;
+use crate::types::{HierarchicalBlock, OutlineEntry, PageHierarchy};
+use pdfium_render::prelude::*;
+
+/// Characters and outline of a PDF document, extracted once for repeated rendering.
+///
+/// The cache is immutable after construction and can be shared across threads.
+#[derive(Debug, Clone)]
+pub struct PreparedDocument {
+    pages: Vec<Vec<CharData>>,
+    outline: Vec<OutlineEntry>,
+}
+
+impl PreparedDocument {
+    /// Extract the characters of every page and the outline of a loaded document.
+    pub fn from_document(document: &PdfDocument<'_>) -> Result<Self> {
+        let pages = document
+            .pages()
+            .iter()
+            .map(|page| extract_chars_with_fonts(&page))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            pages,
+            outline: crate::pdf::outline::extract_outline(document),
+        })
+    }
+
+    /// Build a prepared document from characters that were already extracted, one entry per page.
+    pub fn from_pages(pages: Vec<Vec<CharData>>, outline: Vec<OutlineEntry>) -> Self {
+        Self { pages, outline }
+    }
+
+    /// Number of pages in the document.
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Characters of a page (0-indexed), in content stream order.
+    pub fn page_chars(&self, page_index: usize) -> Option<&[CharData]> {
+        self.pages.get(page_index).map(Vec::as_slice)
+    }
+
+    /// The document outline, empty when the document has none.
+    pub fn outline(&self) -> &[OutlineEntry] {
+        &self.outline
+    }
+}
+
+/// Compute the hierarchy of every page of a prepared document.
+///
+/// Returns one entry per page. Pages without text yield `None`, as does every page
+/// when `config.enabled` is false. With `config.use_outline`, heading levels come from
+/// the document outline when it has one.
+pub fn render_from_prepared(
+    prepared: &PreparedDocument,
+    config: &HierarchyConfig,
+) -> Result<Vec<Option<PageHierarchy>>> {
+    let outline: &[OutlineEntry] = if config.use_outline { &prepared.outline } else { &[] };
+
+    prepared
+        .pages
+        .iter()
+        .enumerate()
+        .map(|(page_index, chars)| page_hierarchy_from_chars(chars.clone(), page_index + 1, config, outline))
+        .collect()
+}
+
+/// Build the hierarchy of one page from its characters, with plain block text.
+///
+/// Uses font size clustering to identify heading levels (H1-H6), or the outline
+/// entries of the page when `outline` is not empty.
+pub(crate) fn page_hierarchy_from_chars(
+    char_data: Vec<CharData>,
+    page_number: usize,
+    config: &HierarchyConfig,
+    outline: &[OutlineEntry],
+) -> Result<Option<PageHierarchy>> {
+    if !config.enabled || char_data.is_empty() {
+        return Ok(None);
+    }
+
+    // Merge characters into text blocks
+    page_hierarchy_from_blocks(merge_chars_into_blocks(char_data), page_number, config, outline)
+}
+
+/// Build the hierarchy of one page from text blocks that were already merged.
+pub(crate) fn page_hierarchy_from_blocks(
+    text_blocks: Vec<TextBlock>,
+    page_number: usize,
+    config: &HierarchyConfig,
+    outline: &[OutlineEntry],
+) -> Result<Option<PageHierarchy>> {
+    if !config.enabled || text_blocks.is_empty() {
+        return Ok(None);
+    }
+
+    // Read multi-column layouts column by column
+    let columns = match config.column_mode {
+        ColumnMode::Auto => detect_columns(&text_blocks, config.max_columns, config.min_column_width),
+        ColumnMode::Single => Vec::new(),
+        ColumnMode::Fixed(count) => split_columns_evenly(&text_blocks, count),
+    };
+    let text_blocks = order_blocks_by_columns(text_blocks, &columns);
+
+    let hierarchy_blocks = if outline.is_empty() {
+        // Cluster by font sizes
+        let k_clusters = config.k_clusters.min(text_blocks.len());
+        let clusters = cluster_font_sizes(&text_blocks, k_clusters)?;
+
+        if clusters.is_empty() {
+            return Ok(None);
+        }
+
+        // Assign hierarchy levels using KMeans-based clustering
+        let kmeans_result = KMeansResult {
+            labels: text_blocks
+                .iter()
+                .map(|block| {
+                    // Find which cluster this block belongs to
+                    let mut min_dist = f32::INFINITY;
+                    let mut best_cluster = 0u32;
+                    for (idx, cluster) in clusters.iter().enumerate() {
+                        let dist = (block.font_size - cluster.centroid).abs();
+                        if dist < min_dist {
+                            min_dist = dist;
+                            best_cluster = idx as u32;
+                        }
+                    }
+                    best_cluster
+                })
+                .collect(),
+        };
+
+        assign_hierarchy_levels(&text_blocks, &kmeans_result)
+    } else {
+        assign_hierarchy_levels_from_outline(&text_blocks, outline, page_number)
+    };
+
+    // Convert to output format
+    let blocks: Vec<HierarchicalBlock> = hierarchy_blocks
+        .into_iter()
+        .map(|hb| HierarchicalBlock {
+            text: hb.text,
+            font_size: hb.font_size,
+            level: match hb.hierarchy_level {
+                HierarchyLevel::H1 => "h1".to_string(),
+                HierarchyLevel::H2 => "h2".to_string(),
+                HierarchyLevel::H3 => "h3".to_string(),
+                HierarchyLevel::H4 => "h4".to_string(),
+                HierarchyLevel::H5 => "h5".to_string(),
+                HierarchyLevel::H6 => "h6".to_string(),
+                HierarchyLevel::Body => "body".to_string(),
+            },
+            bbox: if config.include_bbox {
+                Some((hb.bbox.left, hb.bbox.top, hb.bbox.right, hb.bbox.bottom))
+            } else {
+                None
+            },
+        })
+        .collect();
+
+    let block_count = blocks.len();
+
+    Ok(Some(PageHierarchy { block_count, blocks }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_line(text: &str, y: f32, font_size: f32) -> Vec<CharData> {
+        let width = font_size / 2.0;
+        text.chars()
+            .enumerate()
+            .map(|(i, ch)| CharData {
+                text: ch.to_string(),
+                x: 72.0 + i as f32 * width,
+                y,
+                font_size,
+                width,
+                height: font_size,
+            })
+            .collect()
+    }
+
+    fn prepared_page() -> PreparedDocument {
+        let mut chars = text_line("Report", 80.0, 24.0);
+        chars.extend(text_line("Revenue", 300.0, 12.0));
+        PreparedDocument::from_pages(vec![chars, Vec::new()], Vec::new())
+    }
+
+    fn levels(pages: &[Option<PageHierarchy>]) -> Vec<(String, String)> {
+        pages[0]
+            .as_ref()
+            .expect("first page should have a hierarchy")
+            .blocks
+            .iter()
+            .map(|block| (block.text.clone(), block.level.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_prepared_document_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<PreparedDocument>();
+    }
+
+    #[test]
+    fn test_render_from_prepared_depends_only_on_config() {
+        let prepared = prepared_page();
+        let two_levels = HierarchyConfig {
+            k_clusters: 2,
+            ..Default::default()
+        };
+        let one_level = HierarchyConfig {
+            k_clusters: 1,
+            include_bbox: false,
+            ..Default::default()
+        };
+
+        let first = render_from_prepared(&prepared, &two_levels).unwrap();
+        let flattened = render_from_prepared(&prepared, &one_level).unwrap();
+        let second = render_from_prepared(&prepared, &two_levels).unwrap();
+
+        assert_eq!(first.len(), 2);
+        assert!(first[1].is_none(), "empty pages have no hierarchy");
+        assert_eq!(
+            levels(&first),
+            vec![
+                ("Report".to_string(), "h1".to_string()),
+                ("Revenue".to_string(), "h2".to_string()),
+            ]
+        );
+        assert_eq!(levels(&second), levels(&first));
+        assert!(levels(&flattened).iter().all(|(_, level)| level == "h1"));
+
+        let first_blocks = &first[0].as_ref().unwrap().blocks;
+        let flattened_blocks = &flattened[0].as_ref().unwrap().blocks;
+        assert!(first_blocks.iter().all(|block| block.bbox.is_some()));
+        assert!(flattened_blocks.iter().all(|block| block.bbox.is_none()));
+    }
+
+    /// Two columns of three rows; every row of the left column lines up with one on the right.
+    fn two_column_page() -> Vec<CharData> {
+        let mut chars = Vec::new();
+        for (row, y) in [100.0, 150.0, 200.0].into_iter().enumerate() {
+            chars.extend(text_line(&format!("left {}", row), y, 24.0));
+            chars.extend(
+                text_line(&format!("right {}", row), y, 24.0)
+                    .into_iter()
+                    .map(|c| CharData { x: c.x + 240.0, ..c }),
+            );
+        }
+        chars
+    }
+
+    fn block_texts(column_mode: ColumnMode) -> Vec<String> {
+        let config = HierarchyConfig {
+            column_mode,
+            ..Default::default()
+        };
+        page_hierarchy_from_chars(two_column_page(), 1, &config, &[])
+            .unwrap()
+            .expect("page has text")
+            .blocks
+            .into_iter()
+            .map(|block| block.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_single_column_mode_reads_rows_across_columns() {
+        assert_eq!(
+            block_texts(ColumnMode::Single),
+            vec!["left 0", "right 0", "left 1", "right 1", "left 2", "right 2"]
+        );
+    }
+
+    #[test]
+    fn test_fixed_column_mode_matches_detection() {
+        let auto = block_texts(ColumnMode::Auto);
+        assert_eq!(
+            auto,
+            vec!["left 0", "left 1", "left 2", "right 0", "right 1", "right 2"]
+        );
+        assert_eq!(block_texts(ColumnMode::Fixed(2)), auto);
+    }
+
+    #[test]
+    fn test_render_from_prepared_disabled() {
+        let config = HierarchyConfig {
+            enabled: false,
+            ..Default::default()
+        };
+        let pages = render_from_prepared(&prepared_page(), &config).unwrap();
+        assert!(pages.iter().all(Option::is_none));
+    }
+}
@@ -0,0 +1,210 @@
+//! Superscript and subscript detection for PDF text.
+//!
+//! PDFs have no notion of superscript or subscript text; footnote markers and
+//! chemical formulas are simply glyphs drawn above or below the line baseline.
+//! This module recovers them from character positions; see [`super::markup`] for
+//! how they are rendered.
+
+use super::extraction::CharData;
+
+/// Minimum baseline offset, as a fraction of the line font size, for a character to
+/// count as raised or lowered. Loose bounds of a smaller glyph on the same baseline
+/// differ by well under this, so smaller inline text is not misclassified.
+const SCRIPT_BASELINE_SHIFT_RATIO: f32 = 0.12;
+/// Maximum baseline offset, as a fraction of the font size, for two characters to
+/// share a line.
+const LINE_BASELINE_TOLERANCE_RATIO: f32 = 0.6;
+
+/// Vertical position of a character relative to its line baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptPosition {
+    /// On the line baseline
+    Normal,
+    /// Raised above the line baseline
+    Superscript,
+    /// Lowered below the line baseline
+    Subscript,
+}
+
+impl ScriptPosition {
+    pub(super) fn delimiter(self) -> Option<char> {
+        match self {
+            ScriptPosition::Normal => None,
+            ScriptPosition::Superscript => Some('^'),
+            ScriptPosition::Subscript => Some('~'),
+        }
+    }
+}
+
+/// A text line identified by the baseline of its largest characters.
+struct Line {
+    baseline: f32,
+    font_size: f32,
+}
+
+/// Classify each character as normal, superscript or subscript.
+///
+/// Characters are grouped into lines by baseline proximity, and each line takes its
+/// baseline from its largest characters. A character is only considered a script when
+/// its baseline is shifted, never because of its size alone, and only when the run it
+/// belongs to is attached to a word on the line (e.g. `x²` or `H₂O`, not a standalone
+/// small number).
+pub fn detect_script_positions(chars: &[CharData]) -> Vec<ScriptPosition> {
+    let mut lines: Vec<Line> = Vec::new();
+    let mut line_of: Vec<Option<usize>> = Vec::with_capacity(chars.len());
+
+    for c in chars {
+        if c.text.trim().is_empty() {
+            line_of.push(None);
+            continue;
+        }
+
+        let existing = lines.iter().position(|line| {
+            (c.y - line.baseline).abs() <= LINE_BASELINE_TOLERANCE_RATIO * line.font_size.max(c.font_size)
+        });
+        match existing {
+            Some(idx) => {
+                let line = &mut lines[idx];
+                if c.font_size > line.font_size {
+                    line.baseline = c.y;
+                    line.font_size = c.font_size;
+                }
+                line_of.push(Some(idx));
+            }
+            None => {
+                lines.push(Line {
+                    baseline: c.y,
+                    font_size: c.font_size,
+                });
+                line_of.push(Some(lines.len() - 1));
+            }
+        }
+    }
+
+    let mut positions: Vec<ScriptPosition> = chars
+        .iter()
+        .zip(&line_of)
+        .map(|(c, line_idx)| {
+            let Some(line) = line_idx.map(|idx| &lines[idx]) else {
+                return ScriptPosition::Normal;
+            };
+            // PDF user space grows upwards, so a raised baseline has a larger y
+            let shift = (c.y - line.baseline) / line.font_size;
+            if shift >= SCRIPT_BASELINE_SHIFT_RATIO {
+                ScriptPosition::Superscript
+            } else if shift <= -SCRIPT_BASELINE_SHIFT_RATIO {
+                ScriptPosition::Subscript
+            } else {
+                ScriptPosition::Normal
+            }
+        })
+        .collect();
+
+    // Only keep runs attached to a word on either side
+    let is_word_char = |idx: usize, positions: &[ScriptPosition]| {
+        positions[idx] == ScriptPosition::Normal && !chars[idx].text.trim().is_empty()
+    };
+    let mut start = 0;
+    while start < positions.len() {
+        let position = positions[start];
+        let mut end = start + 1;
+        while end < positions.len() && positions[end] == position {
+            end += 1;
+        }
+
+        if position != ScriptPosition::Normal {
+            let attached_before = start > 0 && is_word_char(start - 1, &positions);
+            let attached_after = end < positions.len() && is_word_char(end, &positions);
+            if !attached_before && !attached_after {
+                positions[start..end].fill(ScriptPosition::Normal);
+            }
+        }
+
+        start = end;
+    }
+
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn char_at(text: &str, x: f32, y: f32, font_size: f32) -> CharData {
+        CharData {
+            text: text.to_string(),
+            x,
+            y,
+            font_size,
+            width: font_size * 0.5,
+            height: font_size,
+        }
+    }
+
+    #[test]
+    fn test_raised_and_lowered_baselines() {
+        let chars = vec![
+            char_at("x", 10.0, 100.0, 12.0),
+            char_at("2", 16.0, 104.0, 8.0),
+            char_at("y", 22.0, 100.0, 12.0),
+            char_at("3", 28.0, 97.0, 8.0),
+        ];
+
+        assert_eq!(
+            detect_script_positions(&chars),
+            vec![
+                ScriptPosition::Normal,
+                ScriptPosition::Superscript,
+                ScriptPosition::Normal,
+                ScriptPosition::Subscript,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_smaller_text_on_baseline_is_not_script() {
+        let chars = vec![
+            char_at("A", 10.0, 100.0, 12.0),
+            char_at("b", 18.0, 100.5, 8.0),
+            char_at("c", 22.0, 100.5, 8.0),
+        ];
+
+        assert!(
+            detect_script_positions(&chars)
+                .iter()
+                .all(|&p| p == ScriptPosition::Normal)
+        );
+    }
+
+    #[test]
+    fn test_smaller_caption_line_is_not_script() {
+        let chars = vec![
+            char_at("B", 10.0, 100.0, 12.0),
+            char_at("o", 18.0, 100.0, 12.0),
+            char_at("d", 26.0, 100.0, 12.0),
+            char_at("y", 34.0, 100.0, 12.0),
+            char_at("C", 10.0, 80.0, 8.0),
+            char_at("a", 15.0, 80.0, 8.0),
+            char_at("p", 20.0, 80.0, 8.0),
+        ];
+
+        assert!(
+            detect_script_positions(&chars)
+                .iter()
+                .all(|&p| p == ScriptPosition::Normal)
+        );
+    }
+
+    #[test]
+    fn test_detached_raised_run_is_not_script() {
+        let chars = vec![
+            char_at("a", 10.0, 100.0, 12.0),
+            char_at(" ", 16.0, 100.0, 12.0),
+            char_at("1", 22.0, 104.0, 8.0),
+            char_at(" ", 26.0, 100.0, 12.0),
+            char_at("b", 32.0, 100.0, 12.0),
+        ];
+
+        assert_eq!(detect_script_positions(&chars)[2], ScriptPosition::Normal);
+    }
+}
@@ -0,0 +1,202 @@
+//! Strikethrough detection for PDF text.
+//!
+//! Struck-through text (common in redlined documents) is drawn as ordinary glyphs
+//! with a separate horizontal line across them. This module collects thin horizontal
+//! path objects from a page and finds the words they cross out.
+
+use super::bounding_box::BoundingBox;
+use super::extraction::CharData;
+use pdfium_render::prelude::*;
+
+/// Maximum thickness in PDF units for a path object to count as a line segment.
+const MAX_LINE_THICKNESS: f32 = 2.0;
+/// Minimum fraction of a word's width a line must cover to strike it out.
+/// Keeps short decorations and partial table borders from striking words.
+const MIN_WORD_COVERAGE: f32 = 0.8;
+/// Vertical band, as fractions of the word height measured from its bottom, in which
+/// a strike line must lie. Underlines sit below and overlines or borders above it.
+const STRIKE_BAND: (f32, f32) = (0.3, 0.7);
+
+/// A horizontal line segment drawn on a page, in PDF units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HorizontalSegment {
+    /// Left end of the segment
+    pub left: f32,
+    /// Right end of the segment
+    pub right: f32,
+    /// Vertical center of the segment
+    pub y: f32,
+}
+
+/// Collect thin horizontal path objects (lines and hairline rectangles) from a page.
+pub fn extract_horizontal_segments(page: &PdfPage) -> Vec<HorizontalSegment> {
+    page.objects()
+        .iter()
+        .filter(|object| object.object_type() == PdfPageObjectType::Path)
+        .filter_map(|object| object.bounds().ok())
+        .filter_map(|bounds| {
+            let (left, right) = (bounds.left().value, bounds.right().value);
+            let (bottom, top) = (bounds.bottom().value, bounds.top().value);
+            let thickness = (top - bottom).abs();
+            let length = right - left;
+
+            (thickness <= MAX_LINE_THICKNESS && length > thickness).then_some(HorizontalSegment {
+                left,
+                right,
+                y: (top + bottom) / 2.0,
+            })
+        })
+        .collect()
+}
+
+/// Find the words crossed out by one of `segments`.
+///
+/// Words are runs of non-whitespace characters on the same baseline. A word is struck
+/// when a segment passes through the middle of its height and covers at least
+/// [`MIN_WORD_COVERAGE`] of its width. Each struck word is returned as the union of its
+/// character boxes, in the coordinates used by
+/// [`merge_chars_into_blocks`](super::merge_chars_into_blocks).
+pub fn find_struck_words(chars: &[CharData], segments: &[HorizontalSegment]) -> Vec<BoundingBox> {
+    let mut struck_words = Vec::new();
+    if segments.is_empty() {
+        return struck_words;
+    }
+
+    let mut start = 0;
+    while start < chars.len() {
+        if chars[start].text.trim().is_empty() {
+            start += 1;
+            continue;
+        }
+
+        let mut end = start + 1;
+        while end < chars.len()
+            && !chars[end].text.trim().is_empty()
+            && (chars[end].y - chars[start].y).abs() <= chars[start].font_size * 0.5
+        {
+            end += 1;
+        }
+
+        let word = &chars[start..end];
+        if is_word_struck(word, segments) {
+            struck_words.push(BoundingBox {
+                left: word.iter().map(|c| c.x).fold(f32::INFINITY, f32::min),
+                top: word.iter().map(|c| c.y - c.height).fold(f32::INFINITY, f32::min),
+                right: word.iter().map(|c| c.x + c.width).fold(f32::NEG_INFINITY, f32::max),
+                bottom: word.iter().map(|c| c.y).fold(f32::NEG_INFINITY, f32::max),
+            });
+        }
+
+        start = end;
+    }
+
+    struck_words
+}
+
+/// Whether the center of a character's box lies in one of `struck_words`.
+pub(super) fn is_in_struck_word(c: &CharData, struck_words: &[BoundingBox]) -> bool {
+    let (center_x, center_y) = (c.x + c.width / 2.0, c.y - c.height / 2.0);
+    struck_words
+        .iter()
+        .any(|word| (word.left..=word.right).contains(&center_x) && (word.top..=word.bottom).contains(&center_y))
+}
+
+fn is_word_struck(word: &[CharData], segments: &[HorizontalSegment]) -> bool {
+    let left = word.iter().map(|c| c.x).fold(f32::INFINITY, f32::min);
+    let right = word.iter().map(|c| c.x + c.width).fold(f32::NEG_INFINITY, f32::max);
+    let bottom = word.iter().map(|c| c.y).fold(f32::INFINITY, f32::min);
+    let top = word.iter().map(|c| c.y + c.height).fold(f32::NEG_INFINITY, f32::max);
+
+    let width = right - left;
+    let height = top - bottom;
+    if width <= 0.0 || height <= 0.0 {
+        return false;
+    }
+
+    let band_low = bottom + height * STRIKE_BAND.0;
+    let band_high = bottom + height * STRIKE_BAND.1;
+
+    segments.iter().any(|segment| {
+        let overlap = segment.right.min(right) - segment.left.max(left);
+        (band_low..=band_high).contains(&segment.y) && overlap >= width * MIN_WORD_COVERAGE
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, x: f32) -> Vec<CharData> {
+        text.chars()
+            .enumerate()
+            .map(|(i, ch)| CharData {
+                text: ch.to_string(),
+                x: x + i as f32 * 6.0,
+                y: 100.0,
+                font_size: 12.0,
+                width: 6.0,
+                height: 12.0,
+            })
+            .collect()
+    }
+
+    fn line(text: &str) -> Vec<CharData> {
+        let mut chars = Vec::new();
+        let mut x = 0.0;
+        for (i, w) in text.split(' ').enumerate() {
+            if i > 0 {
+                chars.extend(word(" ", x));
+                x += 6.0;
+            }
+            chars.extend(word(w, x));
+            x += w.len() as f32 * 6.0;
+        }
+        chars
+    }
+
+    fn struck_text(chars: &[CharData], segments: &[HorizontalSegment]) -> String {
+        let struck_words = find_struck_words(chars, segments);
+        chars
+            .iter()
+            .filter(|c| is_in_struck_word(c, &struck_words))
+            .map(|c| c.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_line_through_word_center_strikes_word() {
+        // "keep drop keep": "drop" spans x 30..54
+        let chars = line("keep drop keep");
+        let segments = [HorizontalSegment {
+            left: 29.0,
+            right: 55.0,
+            y: 106.0,
+        }];
+
+        assert_eq!(struck_text(&chars, &segments), "drop");
+    }
+
+    #[test]
+    fn test_underline_does_not_strike() {
+        let chars = line("keep drop keep");
+        let segments = [HorizontalSegment {
+            left: 29.0,
+            right: 55.0,
+            y: 100.5,
+        }];
+
+        assert_eq!(struck_text(&chars, &segments), "");
+    }
+
+    #[test]
+    fn test_short_line_does_not_strike() {
+        let chars = line("keep drop keep");
+        let segments = [HorizontalSegment {
+            left: 30.0,
+            right: 38.0,
+            y: 106.0,
+        }];
+
+        assert_eq!(struck_text(&chars, &segments), "");
+    }
+}
@@ -1,4 +1,5 @@
 use super::error::{PdfError, Result};
+use image::{DynamicImage, GrayImage, ImageFormat, RgbImage};
 use lopdf::Document;
 use serde::{Deserialize, Serialize};
 
@@ -6,6 +7,8 @@ use serde::{Deserialize, Serialize};
 pub struct PdfImage {
     pub page_number: usize,
     pub image_index: usize,
+    /// Object number and generation of the image XObject; an image reused on several pages keeps its id
+    pub object_id: (u32, u16),
     pub width: i64,
     pub height: i64,
     pub color_space: Option<String>,
@@ -55,6 +58,7 @@ impl PdfImageExtractor {
                 all_images.push(PdfImage {
                     page_number: *page_num as usize,
                     image_index: img_index + 1,
+                    object_id: img.id,
                     width: img.width,
                     height: img.height,
                     color_space: img.color_space.clone(),
@@ -86,6 +90,7 @@ impl PdfImageExtractor {
             page_images.push(PdfImage {
                 page_number: page_number as usize,
                 image_index: img_index + 1,
+                object_id: img.id,
                 width: img.width,
                 height: img.height,
                 color_space: img.color_space.clone(),
@@ -102,6 +107,42 @@ impl PdfImageExtractor {
         let images = self.extract_images()?;
         Ok(images.len())
     }
+
+    /// Decode an image extracted from this document into pixels.
+    ///
+    /// Supports JPEG images and 8-bit gray or RGB samples (optionally Flate or LZW
+    /// compressed). Returns `None` for other encodings, such as JBIG2, CCITT, JPEG 2000,
+    /// indexed or CMYK color.
+    pub fn decode_image(&self, image: &PdfImage) -> Option<DynamicImage> {
+        let stream = self.document.get_object(image.object_id).ok()?.as_stream().ok()?;
+
+        if image.filters.iter().any(|filter| filter == "DCTDecode") {
+            if image.filters.len() != 1 {
+                return None;
+            }
+            return image::load_from_memory_with_format(&stream.content, ImageFormat::Jpeg).ok();
+        }
+
+        let mut samples = if image.filters.is_empty() {
+            stream.content.clone()
+        } else {
+            stream.decompressed_content().ok()?
+        };
+        let width = u32::try_from(image.width).ok()?;
+        let height = u32::try_from(image.height).ok()?;
+        let channels = match (image.color_space.as_deref(), image.bits_per_component) {
+            (Some("DeviceGray"), Some(8)) => 1,
+            (Some("DeviceRGB"), Some(8)) => 3,
+            _ => return None,
+        };
+        samples.truncate((width as usize).checked_mul(height as usize)?.checked_mul(channels)?);
+
+        if channels == 1 {
+            GrayImage::from_raw(width, height, samples).map(DynamicImage::ImageLuma8)
+        } else {
+            RgbImage::from_raw(width, height, samples).map(DynamicImage::ImageRgb8)
+        }
+    }
 }
 
 pub fn extract_images_from_pdf(pdf_bytes: &[u8]) -> Result<Vec<PdfImage>> {
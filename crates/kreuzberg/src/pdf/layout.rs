@@ -0,0 +1,124 @@
+//! Word-level text positions for layout-aware consumers.
+//!
+//! Groups the characters reported by pdfium into words and records each word's
+//! bounding box in page coordinates (points, origin at the bottom-left corner). Used
+//! when `ExtractionConfig::include_layout` is enabled.
+
+use super::error::Result;
+use super::hierarchy::{CharData, extract_chars_with_fonts};
+use crate::types::{BoundingBox, PositionedText};
+use pdfium_render::prelude::*;
+
+/// Horizontal gap between characters, as a fraction of the font size, that starts a new word
+/// even when the PDF contains no explicit space character.
+const WORD_GAP_RATIO: f32 = 0.5;
+
+/// Extract every word of the document along with its page number and bounding box.
+pub(crate) fn extract_layout_from_document(document: &PdfDocument<'_>) -> Result<Vec<PositionedText>> {
+    let mut layout = Vec::new();
+    for (page_index, page) in document.pages().iter().enumerate() {
+        let chars = extract_chars_with_fonts(&page)?;
+        layout.extend(group_words(&chars, page_index + 1));
+    }
+    Ok(layout)
+}
+
+/// Group characters into words.
+///
+/// Words are runs of non-whitespace characters on the same baseline, split where the
+/// gap to the previous character exceeds [`WORD_GAP_RATIO`] of the font size. Words
+/// without a measurable area are dropped.
+fn group_words(chars: &[CharData], page_number: usize) -> Vec<PositionedText> {
+    let mut words = Vec::new();
+
+    let mut start = 0;
+    while start < chars.len() {
+        if chars[start].text.trim().is_empty() {
+            start += 1;
+            continue;
+        }
+
+        let mut end = start + 1;
+        while end < chars.len() && continues_word(&chars[end - 1], &chars[end]) {
+            end += 1;
+        }
+
+        if let Some(word) = positioned_word(&chars[start..end], page_number) {
+            words.push(word);
+        }
+        start = end;
+    }
+
+    words
+}
+
+fn continues_word(previous: &CharData, next: &CharData) -> bool {
+    let gap = next.x - (previous.x + previous.width);
+    !next.text.trim().is_empty()
+        && (next.y - previous.y).abs() <= previous.font_size * 0.5
+        && gap <= previous.font_size * WORD_GAP_RATIO
+        && next.x + next.width > previous.x
+}
+
+fn positioned_word(word: &[CharData], page_number: usize) -> Option<PositionedText> {
+    let x0 = word.iter().map(|c| c.x).fold(f32::INFINITY, f32::min);
+    let x1 = word.iter().map(|c| c.x + c.width).fold(f32::NEG_INFINITY, f32::max);
+    let y0 = word.iter().map(|c| c.y).fold(f32::INFINITY, f32::min);
+    let y1 = word.iter().map(|c| c.y + c.height).fold(f32::NEG_INFINITY, f32::max);
+    if !(x1 > x0 && y1 > y0) {
+        return None;
+    }
+
+    Some(PositionedText {
+        text: word.iter().map(|c| c.text.as_str()).collect(),
+        page_number,
+        bbox: BoundingBox {
+            x0: x0 as f64,
+            y0: y0 as f64,
+            x1: x1 as f64,
+            y1: y1 as f64,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars_at(text: &str, x: f32, y: f32) -> Vec<CharData> {
+        text.chars()
+            .enumerate()
+            .map(|(i, ch)| CharData {
+                text: ch.to_string(),
+                x: x + i as f32 * 6.0,
+                y,
+                font_size: 12.0,
+                width: 6.0,
+                height: 12.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_groups_words_on_spaces_gaps_and_lines() {
+        let mut chars = chars_at("Hello world", 72.0, 700.0);
+        chars.extend(chars_at("Gap", 72.0 + 11.0 * 6.0 + 20.0, 700.0));
+        chars.extend(chars_at("\n", 0.0, 0.0));
+        chars.extend(chars_at("Next", 72.0, 680.0));
+
+        let words = group_words(&chars, 2);
+        let texts: Vec<&str> = words.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(texts, vec!["Hello", "world", "Gap", "Next"]);
+        assert!(words.iter().all(|w| w.page_number == 2));
+
+        let hello = &words[0].bbox;
+        assert_eq!((hello.x0, hello.x1, hello.y0, hello.y1), (72.0, 102.0, 700.0, 712.0));
+    }
+
+    #[test]
+    fn test_drops_words_without_area() {
+        let mut chars = chars_at("a", 10.0, 10.0);
+        chars[0].width = 0.0;
+        assert!(group_words(&chars, 1).is_empty());
+    }
+}
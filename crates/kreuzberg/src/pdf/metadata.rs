@@ -1,8 +1,9 @@
 use super::bindings::bind_pdfium;
 use super::error::{PdfError, Result};
-use crate::types::{PageBoundary, PageInfo, PageStructure, PageUnitType};
+use crate::types::{DocumentKind, OutlineEntry, PageBoundary, PageInfo, PageStructure, PageUnitType};
 use pdfium_render::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// PDF-specific metadata.
 ///
@@ -35,6 +36,34 @@ pub struct PdfMetadata {
     /// Total number of pages in the PDF document
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_count: Option<usize>,
+
+    /// Filled form field values keyed by field name (checkboxes are "on"/"off")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_fields: Option<BTreeMap<String, String>>,
+
+    /// Text yield of each page, in page order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub page_reports: Vec<PageReport>,
+}
+
+/// Text yield of a single PDF page.
+///
+/// A `char_count` of zero marks a page that contributed no text, such as a blank page
+/// or a scanned page that was not (or could not be) OCRed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct PageReport {
+    /// Page number (1-indexed)
+    pub page: usize,
+
+    /// Non-whitespace characters of text extracted from the page
+    pub char_count: usize,
+
+    /// Whether the page contains image objects
+    pub has_images: bool,
+
+    /// Whether the page text was produced by OCR
+    pub ocr_used: bool,
 }
 
 /// Complete PDF extraction metadata including common and PDF-specific fields.
@@ -78,6 +107,26 @@ pub struct PdfExtractionMetadata {
     /// Page structure with boundaries and optional per-page metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_structure: Option<PageStructure>,
+
+    /// Document outline (bookmarks), empty when the document has none
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outline: Vec<OutlineEntry>,
+
+    /// Fonts used by the document that are neither embedded nor standard fonts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_fonts: Vec<String>,
+
+    /// Whether the document's pages carry extractable text or are scanned images
+    pub document_kind: DocumentKind,
+
+    /// Page count of the original document when pages past `max_pages` were dropped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub truncated_from: Option<usize>,
+
+    /// First unreadable page (1-based) and why it could not be read, when `allow_partial`
+    /// dropped it and every page after it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopped_at: Option<(usize, String)>,
 }
 
 /// Extract PDF-specific metadata from raw bytes.
@@ -154,7 +203,9 @@ pub(crate) fn extract_metadata_from_document_impl(
     document: &PdfDocument<'_>,
     page_boundaries: Option<&[PageBoundary]>,
 ) -> Result<PdfExtractionMetadata> {
-    let pdf_specific = extract_pdf_specific_metadata(document)?;
+    let mut pdf_specific = extract_pdf_specific_metadata(document)?;
+    let (document_kind, page_reports) = super::classification::analyze_document(document);
+    pdf_specific.page_reports = page_reports;
 
     let common = extract_common_metadata_from_document(document)?;
 
@@ -174,6 +225,11 @@ pub(crate) fn extract_metadata_from_document_impl(
         created_by: common.created_by,
         pdf_specific,
         page_structure,
+        outline: super::outline::extract_outline(document),
+        missing_fonts: super::fonts::find_missing_fonts(document),
+        document_kind,
+        truncated_from: None,
+        stopped_at: None,
     })
 }
 
@@ -208,6 +264,9 @@ fn extract_pdf_specific_metadata(document: &PdfDocument<'_>) -> Result<PdfMetada
     // Always capture page count
     metadata.page_count = Some(document.pages().len() as usize);
 
+    let form_fields = super::forms::extract_form_fields(document);
+    metadata.form_fields = (!form_fields.is_empty()).then_some(form_fields);
+
     Ok(metadata)
 }
 
@@ -40,18 +40,30 @@ pub(crate) mod bindings;
 #[cfg(all(feature = "pdf", feature = "bundled-pdfium"))]
 pub mod bundled;
 #[cfg(feature = "pdf")]
+pub mod classification;
+#[cfg(feature = "pdf")]
 pub mod error;
 #[cfg(feature = "pdf")]
+pub(crate) mod font_collection;
+#[cfg(feature = "pdf")]
 pub mod fonts;
 #[cfg(feature = "pdf")]
+pub mod forms;
+#[cfg(feature = "pdf")]
 pub mod hierarchy;
 #[cfg(feature = "pdf")]
 pub mod images;
 #[cfg(feature = "pdf")]
+pub(crate) mod layout;
+#[cfg(feature = "pdf")]
 pub mod metadata;
 #[cfg(feature = "pdf")]
+pub mod outline;
+#[cfg(feature = "pdf")]
 pub mod rendering;
 #[cfg(feature = "pdf")]
+pub(crate) mod running_headers;
+#[cfg(feature = "pdf")]
 pub mod table;
 #[cfg(feature = "pdf")]
 pub mod text;
@@ -61,9 +73,13 @@ pub use crate::core::config::HierarchyConfig;
 #[cfg(all(feature = "pdf", feature = "bundled-pdfium"))]
 pub use bundled::extract_bundled_pdfium;
 #[cfg(feature = "pdf")]
+pub use classification::classify_document;
+#[cfg(feature = "pdf")]
 pub use error::PdfError;
 #[cfg(feature = "pdf")]
-pub use fonts::{cached_font_count, get_font_descriptors, initialize_font_cache};
+pub use fonts::{cached_font_count, clear_font_cache, get_font_descriptors, initialize_font_cache};
+#[cfg(feature = "pdf")]
+pub use forms::extract_form_fields;
 #[cfg(feature = "pdf")]
 pub use hierarchy::{
     BoundingBox, CharData, FontSizeCluster, HierarchyLevel, TextBlock, assign_hierarchy_levels,
@@ -72,10 +88,12 @@ pub use hierarchy::{
 #[cfg(feature = "pdf")]
 pub use images::{PdfImage, PdfImageExtractor, extract_images_from_pdf};
 #[cfg(feature = "pdf")]
-pub use metadata::extract_metadata;
+pub use metadata::{PageReport, extract_metadata};
+#[cfg(feature = "pdf")]
+pub use outline::{assign_hierarchy_levels_from_outline, extract_outline};
 #[cfg(feature = "pdf")]
 pub use rendering::{PageRenderOptions, render_page_to_image};
 #[cfg(feature = "pdf")]
 pub use table::extract_words_from_page;
 #[cfg(feature = "pdf")]
-pub use text::extract_text_from_pdf;
+pub use text::{extract_text_from_pdf, pdf_page_count, pdf_page_count_from_path};
@@ -0,0 +1,180 @@
+//! PDF outline (bookmark) extraction.
+//!
+//! Bookmarks are authored alongside the document and encode its section structure
+//! directly, which makes them a more reliable source of heading levels than font
+//! size clustering when they are present.
+
+use crate::pdf::hierarchy::{HierarchyBlock, HierarchyLevel, TextBlock};
+use crate::types::OutlineEntry;
+use pdfium_render::prelude::*;
+
+/// Maximum outline nesting depth that is followed.
+const MAX_OUTLINE_DEPTH: usize = 64;
+/// Maximum number of outline items visited in a document.
+///
+/// Malformed outlines can link siblings into a cycle; this bounds the traversal.
+const MAX_OUTLINE_ENTRIES: usize = 10_000;
+
+/// Extract the document outline in reading order (depth-first).
+///
+/// Top-level entries have level 1. Entries without a title are skipped, but their
+/// children are still read. Returns an empty vector when the document has no outline.
+pub fn extract_outline(document: &PdfDocument<'_>) -> Vec<OutlineEntry> {
+    let mut entries = Vec::new();
+
+    if let Some(first) = document.bookmarks().root() {
+        collect_siblings(first, 1, &mut entries, &mut 0);
+    }
+
+    entries
+}
+
+fn collect_siblings(first: PdfBookmark<'_>, level: usize, entries: &mut Vec<OutlineEntry>, visited: &mut usize) {
+    let mut current = Some(first);
+
+    while let Some(bookmark) = current {
+        if *visited >= MAX_OUTLINE_ENTRIES {
+            return;
+        }
+        *visited += 1;
+
+        if let Some(title) = bookmark.title().map(|title| title.trim().to_string())
+            && !title.is_empty()
+        {
+            let page = bookmark
+                .destination()
+                .and_then(|destination| destination.page_index().ok())
+                .map(|index| index as usize + 1);
+            entries.push(OutlineEntry { title, level, page });
+        }
+
+        if level < MAX_OUTLINE_DEPTH
+            && let Some(child) = bookmark.first_child()
+        {
+            collect_siblings(child, level + 1, entries, visited);
+        }
+
+        current = bookmark.next_sibling();
+    }
+}
+
+/// Assign hierarchy levels to a page's text blocks from the document outline.
+///
+/// A block whose text matches the title of an outline entry targeting `page_number`
+/// takes the entry's nesting level (levels deeper than 6 map to H6); every other block
+/// is body text. Titles are compared case-insensitively with collapsed whitespace.
+pub fn assign_hierarchy_levels_from_outline(
+    blocks: &[TextBlock],
+    outline: &[OutlineEntry],
+    page_number: usize,
+) -> Vec<HierarchyBlock> {
+    let titles: Vec<(String, usize)> = outline
+        .iter()
+        .filter(|entry| entry.page.is_none_or(|page| page == page_number))
+        .map(|entry| (normalize_title(&entry.title), entry.level))
+        .collect();
+
+    blocks
+        .iter()
+        .map(|block| {
+            let normalized = normalize_title(&block.text);
+            let level = titles
+                .iter()
+                .find(|(title, _)| !normalized.is_empty() && *title == normalized)
+                .map(|&(_, level)| level);
+
+            HierarchyBlock {
+                text: block.text.clone(),
+                bbox: block.bbox,
+                font_size: block.font_size,
+                hierarchy_level: match level {
+                    None => HierarchyLevel::Body,
+                    Some(1) => HierarchyLevel::H1,
+                    Some(2) => HierarchyLevel::H2,
+                    Some(3) => HierarchyLevel::H3,
+                    Some(4) => HierarchyLevel::H4,
+                    Some(5) => HierarchyLevel::H5,
+                    Some(_) => HierarchyLevel::H6,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Lowercase and collapse whitespace so titles match the text drawn on the page.
+fn normalize_title(text: &str) -> String {
+    text.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pdf::hierarchy::BoundingBox;
+
+    fn entry(title: &str, level: usize, page: Option<usize>) -> OutlineEntry {
+        OutlineEntry {
+            title: title.to_string(),
+            level,
+            page,
+        }
+    }
+
+    fn block(text: &str) -> TextBlock {
+        TextBlock {
+            text: text.to_string(),
+            bbox: BoundingBox {
+                left: 0.0,
+                top: 0.0,
+                right: 100.0,
+                bottom: 12.0,
+            },
+            font_size: 12.0,
+        }
+    }
+
+    fn levels(blocks: &[TextBlock], outline: &[OutlineEntry], page_number: usize) -> Vec<HierarchyLevel> {
+        assign_hierarchy_levels_from_outline(blocks, outline, page_number)
+            .into_iter()
+            .map(|block| block.hierarchy_level)
+            .collect()
+    }
+
+    #[test]
+    fn test_outline_titles_on_page_become_headings() {
+        let outline = vec![
+            entry("1. Introduction", 1, Some(1)),
+            entry("1.1  Background", 2, Some(1)),
+        ];
+        let blocks = vec![
+            block("  1. Introduction\n"),
+            block("1.1 background"),
+            block("Body text"),
+        ];
+
+        assert_eq!(
+            levels(&blocks, &outline, 1),
+            vec![HierarchyLevel::H1, HierarchyLevel::H2, HierarchyLevel::Body]
+        );
+        assert_eq!(
+            levels(&blocks, &outline, 2),
+            vec![HierarchyLevel::Body, HierarchyLevel::Body, HierarchyLevel::Body]
+        );
+    }
+
+    #[test]
+    fn test_outline_entry_without_page_matches_any_page() {
+        let outline = vec![entry("Appendix", 1, None)];
+
+        assert_eq!(levels(&[block("Appendix")], &outline, 7), vec![HierarchyLevel::H1]);
+    }
+
+    #[test]
+    fn test_deep_outline_levels_clamp_to_h6() {
+        let outline = vec![entry("Deep", 9, Some(1))];
+
+        assert_eq!(levels(&[block("Deep")], &outline, 1), vec![HierarchyLevel::H6]);
+    }
+}
@@ -0,0 +1,247 @@
+//! Running header and footer detection.
+//!
+//! Page numbers, document titles, and similar lines repeated at the top or bottom of
+//! every page interrupt the body text and end up inside chunks. A line is treated as a
+//! running header or footer when text that is identical apart from its digits sits
+//! at the same vertical position on more than half of the pages. Only the first and
+//! last few lines of a page are considered, and documents shorter than
+//! [`MIN_REPEAT_PAGES`] pages are left alone.
+
+use pdfium_render::prelude::*;
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Number of lines at the top and at the bottom of a page that may be a header or footer.
+const EDGE_LINES: usize = 3;
+/// Minimum number of pages a line must repeat on before it is stripped.
+const MIN_REPEAT_PAGES: usize = 3;
+/// Maximum vertical distance (in points) between repetitions of the same line.
+const Y_TOLERANCE: f32 = 4.0;
+
+/// A candidate line near the top or bottom edge of a page.
+#[derive(Debug, Clone)]
+pub(crate) struct EdgeLine {
+    /// Index of the line in the page text split on `\n`
+    pub(crate) index: usize,
+    /// Line text, lowercased, with whitespace collapsed and digit runs replaced by `#`
+    pub(crate) key: String,
+    /// Baseline position of the line in page coordinates
+    pub(crate) y: f32,
+}
+
+/// Running header and footer lines found in a document, ready to strip from page text.
+#[derive(Debug, Default)]
+pub(crate) struct RunningHeaderFilter {
+    lines_to_strip: Vec<BTreeSet<usize>>,
+}
+
+impl RunningHeaderFilter {
+    /// Scan all pages of `document` for running headers and footers.
+    pub(crate) fn detect(document: &PdfDocument<'_>) -> Self {
+        let pages: Vec<Vec<EdgeLine>> = document.pages().iter().map(|page| page_edge_lines(&page)).collect();
+        Self {
+            lines_to_strip: find_running_lines(&pages),
+        }
+    }
+
+    /// Remove the detected header and footer lines from the text of a page (0-indexed).
+    pub(crate) fn strip<'a>(&self, page_index: usize, page_text: &'a str) -> Cow<'a, str> {
+        match self.lines_to_strip.get(page_index) {
+            Some(lines) if !lines.is_empty() => Cow::Owned(strip_lines(page_text, lines)),
+            _ => Cow::Borrowed(page_text),
+        }
+    }
+}
+
+/// Collect the edge lines of a page along with their vertical positions.
+///
+/// Line positions come from the page characters, which include the line breaks pdfium
+/// generates for the page text. Pages where the two disagree yield no candidates.
+fn page_edge_lines(page: &PdfPage<'_>) -> Vec<EdgeLine> {
+    let Ok(text) = page.text() else {
+        return Vec::new();
+    };
+
+    let mut line_positions: Vec<Option<f32>> = Vec::new();
+    let mut current: Option<f32> = None;
+    for pdf_char in text.chars().iter() {
+        match pdf_char.unicode_char() {
+            Some('\n') => line_positions.push(current.take()),
+            Some(c) if !c.is_whitespace() => {
+                if let Ok(bounds) = pdf_char.loose_bounds() {
+                    let y = bounds.bottom().value;
+                    current = Some(current.map_or(y, |line_y| line_y.min(y)));
+                }
+            }
+            _ => {}
+        }
+    }
+    line_positions.push(current);
+
+    let page_text = text.all();
+    let lines: Vec<&str> = page_text.split('\n').collect();
+    if lines.len() != line_positions.len() {
+        return Vec::new();
+    }
+
+    edge_line_indices(&lines)
+        .into_iter()
+        .filter_map(|index| {
+            Some(EdgeLine {
+                index,
+                key: line_key(lines[index]),
+                y: line_positions[index]?,
+            })
+        })
+        .collect()
+}
+
+/// Indices of the first and last [`EDGE_LINES`] non-blank lines.
+fn edge_line_indices(lines: &[&str]) -> BTreeSet<usize> {
+    let non_blank: Vec<usize> = (0..lines.len()).filter(|&i| !lines[i].trim().is_empty()).collect();
+    let tail_start = non_blank.len().saturating_sub(EDGE_LINES);
+    non_blank
+        .iter()
+        .take(EDGE_LINES)
+        .chain(&non_blank[tail_start..])
+        .copied()
+        .collect()
+}
+
+/// Normalize a line for comparison across pages, so `Page 3 of 10` matches `Page 4 of 10`.
+fn line_key(line: &str) -> String {
+    let mut key = String::with_capacity(line.len());
+    for word in line.split_whitespace() {
+        if !key.is_empty() {
+            key.push(' ');
+        }
+        let mut previous_digit = false;
+        for c in word.chars().flat_map(char::to_lowercase) {
+            let is_digit = c.is_ascii_digit();
+            if !is_digit {
+                key.push(c);
+            } else if !previous_digit {
+                key.push('#');
+            }
+            previous_digit = is_digit;
+        }
+    }
+    key
+}
+
+/// Decide which edge lines of each page are running headers or footers.
+///
+/// Returns, for each page, the indices of the lines to strip.
+pub(crate) fn find_running_lines(pages: &[Vec<EdgeLine>]) -> Vec<BTreeSet<usize>> {
+    let mut lines_to_strip = vec![BTreeSet::new(); pages.len()];
+    if pages.len() < MIN_REPEAT_PAGES {
+        return lines_to_strip;
+    }
+
+    let mut occurrences: HashMap<&str, Vec<(usize, &EdgeLine)>> = HashMap::new();
+    for (page_index, lines) in pages.iter().enumerate() {
+        for line in lines.iter().filter(|line| !line.key.is_empty()) {
+            occurrences.entry(&line.key).or_default().push((page_index, line));
+        }
+    }
+
+    for candidates in occurrences.values() {
+        let Some(band) = candidates
+            .iter()
+            .map(|(_, anchor)| {
+                candidates
+                    .iter()
+                    .filter(|(_, line)| (line.y - anchor.y).abs() <= Y_TOLERANCE)
+                    .collect::<Vec<_>>()
+            })
+            .max_by_key(|band| distinct_pages(band))
+        else {
+            continue;
+        };
+
+        let page_hits = distinct_pages(&band);
+        if page_hits >= MIN_REPEAT_PAGES && page_hits * 2 > pages.len() {
+            for (page_index, line) in band {
+                lines_to_strip[*page_index].insert(line.index);
+            }
+        }
+    }
+
+    lines_to_strip
+}
+
+fn distinct_pages(band: &[&(usize, &EdgeLine)]) -> usize {
+    band.iter()
+        .map(|(page_index, _)| page_index)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Drop the given lines from `text`, along with line breaks left dangling at either end.
+fn strip_lines(text: &str, lines: &BTreeSet<usize>) -> String {
+    let kept: String = text
+        .split_inclusive('\n')
+        .enumerate()
+        .filter(|(index, _)| !lines.contains(index))
+        .map(|(_, line)| line)
+        .collect();
+    kept.trim_matches(['\r', '\n']).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge_lines(lines: &[(&str, f32)]) -> Vec<EdgeLine> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, &(text, y))| EdgeLine {
+                index,
+                key: line_key(text),
+                y,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_line_key_ignores_page_numbers() {
+        assert_eq!(line_key("  Page 3 of  10 "), "page # of #");
+        assert_eq!(line_key("Page 12 of 10"), line_key("page 4 of 10"));
+    }
+
+    #[test]
+    fn test_footer_repeated_on_every_page_is_stripped() {
+        let pages: Vec<Vec<EdgeLine>> = (1..=3)
+            .map(|page| {
+                let footer = format!("Annual Report - Page {}", page);
+                edge_lines(&[("Body text", 700.0 - page as f32 * 20.0), (&footer, 36.0 + page as f32)])
+            })
+            .collect();
+
+        let lines_to_strip = find_running_lines(&pages);
+        assert!(lines_to_strip.iter().all(|lines| lines == &BTreeSet::from([1])));
+
+        let filter = RunningHeaderFilter { lines_to_strip };
+        assert_eq!(filter.strip(0, "Body text\r\nAnnual Report - Page 1"), "Body text");
+    }
+
+    #[test]
+    fn test_short_documents_and_moving_lines_are_kept() {
+        let two_pages = vec![edge_lines(&[("Title", 750.0)]), edge_lines(&[("Title", 750.0)])];
+        assert!(find_running_lines(&two_pages).iter().all(BTreeSet::is_empty));
+
+        let drifting: Vec<Vec<EdgeLine>> = (0..4)
+            .map(|page| edge_lines(&[("Summary", 700.0 - page as f32 * 100.0)]))
+            .collect();
+        assert!(find_running_lines(&drifting).iter().all(BTreeSet::is_empty));
+
+        let minority: Vec<Vec<EdgeLine>> = (0..8)
+            .map(|page| match page {
+                0..3 => edge_lines(&[("Draft", 20.0)]),
+                _ => edge_lines(&[("Body", page as f32 * 100.0)]),
+            })
+            .collect();
+        assert!(find_running_lines(&minority).iter().all(BTreeSet::is_empty));
+    }
+}
@@ -177,10 +177,24 @@ fn should_start_new_word(current_word_chars: &[CharInfo], new_char: &CharInfo) -
         return true;
     }
 
-    let horizontal_gap = new_char.x - (last_char.x + last_char.width);
+    // Right-to-left scripts advance leftwards, so measure from the new character's right edge
+    let horizontal_gap = if is_rtl_char(last_char.text) && is_rtl_char(new_char.text) && new_char.x < last_char.x {
+        last_char.x - (new_char.x + new_char.width)
+    } else {
+        new_char.x - (last_char.x + last_char.width)
+    };
     horizontal_gap > WORD_SPACING_THRESHOLD
 }
 
+/// Check whether a character belongs to a right-to-left script.
+///
+/// Covers Hebrew, Arabic, Syriac, Thaana, and NKo, plus the Hebrew and Arabic
+/// presentation forms.
+#[cfg(feature = "ocr")]
+fn is_rtl_char(c: char) -> bool {
+    matches!(c, '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFC}')
+}
+
 /// Convert a group of characters into a HocrWord.
 ///
 /// Calculates bounding box and confidence for the word.
@@ -298,6 +312,44 @@ mod tests {
         assert!(should_start_new_word(&chars, &far_char));
     }
 
+    #[test]
+    fn test_should_start_new_word_rtl_spacing() {
+        let chars = vec![CharInfo {
+            text: '\u{05E9}',
+            x: 200.0,
+            y: 50.0,
+            width: 10.0,
+            height: 12.0,
+        }];
+
+        let close_char = CharInfo {
+            text: '\u{05DC}',
+            x: 189.0,
+            y: 50.0,
+            width: 10.0,
+            height: 12.0,
+        };
+        assert!(!should_start_new_word(&chars, &close_char));
+
+        let far_char = CharInfo {
+            text: '\u{05DD}',
+            x: 170.0,
+            y: 50.0,
+            width: 10.0,
+            height: 12.0,
+        };
+        assert!(should_start_new_word(&chars, &far_char));
+    }
+
+    #[test]
+    fn test_is_rtl_char() {
+        assert!(is_rtl_char('\u{05D0}'));
+        assert!(is_rtl_char('\u{0627}'));
+        assert!(!is_rtl_char('A'));
+        assert!(!is_rtl_char('7'));
+        assert!(!is_rtl_char('\u{FEFF}'));
+    }
+
     #[test]
     fn test_should_start_new_word_different_line() {
         let chars = vec![CharInfo {
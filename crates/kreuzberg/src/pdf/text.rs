@@ -4,6 +4,7 @@
 
 use super::bindings::{PdfiumHandle, bind_pdfium};
 use super::error::{PdfError, Result};
+use super::running_headers::RunningHeaderFilter;
 use crate::core::config::PageConfig;
 use crate::pdf::metadata::PdfExtractionMetadata;
 use crate::types::{PageBoundary, PageContent};
@@ -89,6 +90,24 @@ pub fn extract_text_from_pdf(pdf_bytes: &[u8]) -> Result<String> {
     extractor.extract_text(pdf_bytes)
 }
 
+/// Count the pages of a PDF without extracting its content.
+///
+/// Only the document structure is loaded, which makes this far cheaper than a full
+/// extraction when all that is needed is the page count. Encrypted documents fail with
+/// [`PdfError::PasswordRequired`]; input that is not a PDF fails with [`PdfError::InvalidPdf`].
+pub fn pdf_page_count(pdf_bytes: &[u8]) -> Result<usize> {
+    let extractor = PdfTextExtractor::new()?;
+    extractor.get_page_count(pdf_bytes)
+}
+
+/// Count the pages of a PDF file without extracting its content.
+///
+/// See [`pdf_page_count`]. I/O errors are returned unchanged.
+pub fn pdf_page_count_from_path(path: impl AsRef<std::path::Path>) -> crate::Result<usize> {
+    let pdf_bytes = std::fs::read(path)?;
+    Ok(pdf_page_count(&pdf_bytes)?)
+}
+
 pub fn extract_text_from_pdf_with_password(pdf_bytes: &[u8], password: &str) -> Result<String> {
     let extractor = PdfTextExtractor::new()?;
     extractor.extract_text_with_password(pdf_bytes, Some(password))
@@ -170,18 +189,25 @@ pub fn extract_text_and_metadata_from_pdf_document(
 ///
 /// When page_config is None, uses fast path with minimal overhead.
 /// When page_config is Some, tracks byte offsets using .len() for O(1) performance (UTF-8 valid boundaries).
+///
+/// When `strip_running_headers` is enabled, an extra pass over the pages detects running
+/// headers and footers first, and they are left out of the page text.
 pub fn extract_text_from_pdf_document(
     document: &PdfDocument<'_>,
     page_config: Option<&PageConfig>,
     extraction_config: Option<&crate::core::config::ExtractionConfig>,
 ) -> Result<PdfTextExtractionResult> {
+    let running_headers = extraction_config
+        .is_some_and(|cfg| cfg.strip_running_headers)
+        .then(|| RunningHeaderFilter::detect(document));
+
     if page_config.is_none() {
-        return extract_text_lazy_fast_path(document);
+        return extract_text_lazy_fast_path(document, running_headers.as_ref());
     }
 
     let config = page_config.unwrap();
 
-    extract_text_lazy_with_tracking(document, config, extraction_config)
+    extract_text_lazy_with_tracking(document, config, extraction_config, running_headers.as_ref())
 }
 
 /// Fast path for text extraction without page tracking.
@@ -197,7 +223,10 @@ pub fn extract_text_from_pdf_document(
 /// and extrapolating for the full document. This reduces String reallocation
 /// calls from O(n) to O(log n) while maintaining low peak memory usage.
 /// For large documents, this can reduce allocation overhead by 40-50%.
-fn extract_text_lazy_fast_path(document: &PdfDocument<'_>) -> Result<PdfTextExtractionResult> {
+fn extract_text_lazy_fast_path(
+    document: &PdfDocument<'_>,
+    running_headers: Option<&RunningHeaderFilter>,
+) -> Result<PdfTextExtractionResult> {
     let page_count = document.pages().len() as usize;
     let mut content = String::new();
     let mut total_sample_size = 0usize;
@@ -209,6 +238,7 @@ fn extract_text_lazy_fast_path(document: &PdfDocument<'_>) -> Result<PdfTextExtr
             .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
 
         let page_text = text.all();
+        let page_text = strip_running_headers(running_headers, page_idx, &page_text);
         let page_size = page_text.len();
 
         if page_idx > 0 {
@@ -251,6 +281,7 @@ fn extract_text_lazy_with_tracking(
     document: &PdfDocument<'_>,
     config: &PageConfig,
     extraction_config: Option<&crate::core::config::ExtractionConfig>,
+    running_headers: Option<&RunningHeaderFilter>,
 ) -> Result<PdfTextExtractionResult> {
     let mut content = String::new();
     let page_count = document.pages().len() as usize;
@@ -273,6 +304,16 @@ fn extract_text_lazy_with_tracking(
         .and_then(|pdf_cfg| pdf_cfg.hierarchy.as_ref())
         .cloned();
 
+    // Inline markup (superscripts, strikethrough) only belongs in markdown block text
+    let markdown =
+        extraction_config.is_some_and(|cfg| cfg.output_format == crate::core::config::OutputFormat::Markdown);
+
+    let outline = if should_extract_hierarchy && hierarchy_config.as_ref().is_some_and(|h_cfg| h_cfg.use_outline) {
+        crate::pdf::outline::extract_outline(document)
+    } else {
+        Vec::new()
+    };
+
     let mut total_sample_size = 0usize;
     let mut sample_count = 0;
 
@@ -283,7 +324,8 @@ fn extract_text_lazy_with_tracking(
             .text()
             .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
 
-        let page_text_ref = text.all();
+        let page_text = text.all();
+        let page_text_ref = strip_running_headers(running_headers, page_idx, &page_text);
         let page_size = page_text_ref.len();
 
         if page_idx < 5 {
@@ -313,14 +355,14 @@ fn extract_text_lazy_with_tracking(
         if let Some(ref mut pages) = page_contents {
             // Extract hierarchy if enabled
             let hierarchy = if should_extract_hierarchy {
-                extract_page_hierarchy(&page, hierarchy_config.as_ref())?
+                extract_page_hierarchy(&page, page_number, hierarchy_config.as_ref(), &outline, markdown)?
             } else {
                 None
             };
 
             pages.push(PageContent {
                 page_number,
-                content: page_text_ref.to_owned(),
+                content: page_text_ref.into_owned(),
                 tables: Vec::new(),
                 images: Vec::new(),
                 hierarchy,
@@ -338,28 +380,42 @@ fn extract_text_lazy_with_tracking(
     Ok((content, Some(boundaries), page_contents))
 }
 
+/// Page text with running headers and footers removed, when a filter is given.
+fn strip_running_headers<'a>(
+    running_headers: Option<&RunningHeaderFilter>,
+    page_idx: usize,
+    page_text: &'a str,
+) -> std::borrow::Cow<'a, str> {
+    match running_headers {
+        Some(filter) => filter.strip(page_idx, page_text),
+        None => std::borrow::Cow::Borrowed(page_text),
+    }
+}
+
 /// Extract text hierarchy from a single PDF page.
 ///
 /// Uses font size clustering to identify heading levels (H1-H6) and assigns
-/// hierarchy levels to text blocks based on their font sizes.
+/// hierarchy levels to text blocks based on their font sizes. When a document
+/// outline is given, heading levels are taken from it instead.
 ///
 /// # Arguments
 ///
 /// * `page` - The PDF page to extract hierarchy from
+/// * `page_number` - 1-indexed number of the page, used to match outline entries
 /// * `hierarchy_config` - Configuration for hierarchy extraction
+/// * `outline` - Document outline, or empty to use font size clustering
+/// * `markdown` - Render superscripts, subscripts and struck words as markdown in block text
 ///
 /// # Returns
 ///
 /// Optional PageHierarchy containing hierarchical blocks with heading levels
 fn extract_page_hierarchy(
     page: &pdfium_render::prelude::PdfPage,
+    page_number: usize,
     hierarchy_config: Option<&crate::core::config::HierarchyConfig>,
+    outline: &[crate::types::OutlineEntry],
+    markdown: bool,
 ) -> Result<Option<crate::types::PageHierarchy>> {
-    use crate::pdf::hierarchy::{
-        HierarchyLevel, assign_hierarchy_levels, cluster_font_sizes, extract_chars_with_fonts, merge_chars_into_blocks,
-    };
-    use crate::types::HierarchicalBlock;
-
     // Check if config is present and hierarchy is enabled
     let config = match hierarchy_config {
         Some(cfg) if cfg.enabled => cfg,
@@ -367,75 +423,17 @@ fn extract_page_hierarchy(
     };
 
     // Extract characters with font information
-    let char_data = extract_chars_with_fonts(page)?;
-
-    if char_data.is_empty() {
-        return Ok(None);
-    }
-
-    // Merge characters into text blocks
-    let text_blocks = merge_chars_into_blocks(char_data);
+    let char_data = crate::pdf::hierarchy::extract_chars_with_fonts(page)?;
 
-    if text_blocks.is_empty() {
-        return Ok(None);
+    if !markdown {
+        return crate::pdf::hierarchy::prepared::page_hierarchy_from_chars(char_data, page_number, config, outline);
     }
 
-    // Cluster by font sizes
-    let k_clusters = config.k_clusters.min(text_blocks.len());
-    let clusters = cluster_font_sizes(&text_blocks, k_clusters)?;
-
-    if clusters.is_empty() {
-        return Ok(None);
-    }
-
-    // Assign hierarchy levels using KMeans-based clustering
-    let kmeans_result = crate::pdf::hierarchy::KMeansResult {
-        labels: text_blocks
-            .iter()
-            .map(|block| {
-                // Find which cluster this block belongs to
-                let mut min_dist = f32::INFINITY;
-                let mut best_cluster = 0u32;
-                for (idx, cluster) in clusters.iter().enumerate() {
-                    let dist = (block.font_size - cluster.centroid).abs();
-                    if dist < min_dist {
-                        min_dist = dist;
-                        best_cluster = idx as u32;
-                    }
-                }
-                best_cluster
-            })
-            .collect(),
-    };
-
-    let hierarchy_blocks = assign_hierarchy_levels(&text_blocks, &kmeans_result);
-
-    // Convert to output format
-    let blocks: Vec<HierarchicalBlock> = hierarchy_blocks
-        .into_iter()
-        .map(|hb| HierarchicalBlock {
-            text: hb.text,
-            font_size: hb.font_size,
-            level: match hb.hierarchy_level {
-                HierarchyLevel::H1 => "h1".to_string(),
-                HierarchyLevel::H2 => "h2".to_string(),
-                HierarchyLevel::H3 => "h3".to_string(),
-                HierarchyLevel::H4 => "h4".to_string(),
-                HierarchyLevel::H5 => "h5".to_string(),
-                HierarchyLevel::H6 => "h6".to_string(),
-                HierarchyLevel::Body => "body".to_string(),
-            },
-            bbox: if config.include_bbox {
-                Some((hb.bbox.left, hb.bbox.top, hb.bbox.right, hb.bbox.bottom))
-            } else {
-                None
-            },
-        })
-        .collect();
-
-    let block_count = blocks.len();
-
-    Ok(Some(crate::types::PageHierarchy { block_count, blocks }))
+    // Strike lines are separate path objects, only worth collecting for markdown
+    let segments = crate::pdf::hierarchy::extract_horizontal_segments(page);
+    let struck_words = crate::pdf::hierarchy::find_struck_words(&char_data, &segments);
+    let text_blocks = crate::pdf::hierarchy::merge_chars_into_markdown_blocks(char_data, &struck_words);
+    crate::pdf::hierarchy::prepared::page_hierarchy_from_blocks(text_blocks, page_number, config, outline)
 }
 
 #[cfg(test)]
@@ -463,6 +461,12 @@ mod tests {
         assert!(matches!(result.unwrap_err(), PdfError::InvalidPdf(_)));
     }
 
+    #[test]
+    fn test_pdf_page_count_invalid_pdf() {
+        let result = pdf_page_count(b"not a pdf");
+        assert!(matches!(result, Err(PdfError::InvalidPdf(_))));
+    }
+
     #[test]
     fn test_password_required_detection() {
         let extractor = PdfTextExtractor::new().unwrap();
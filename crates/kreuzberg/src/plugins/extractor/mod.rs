@@ -60,6 +60,10 @@ mod tests {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
+                layout: None,
                 djot_content: None,
                 pages: None,
                 elements: None,
@@ -235,6 +239,10 @@ mod tests {
                     detected_languages: None,
                     chunks: None,
                     images: None,
+                    links: vec![],
+                    warnings: vec![],
+                    partial: false,
+                    layout: None,
                     djot_content: None,
                     pages: None,
                     elements: None,
@@ -52,13 +52,7 @@ use std::sync::Arc;
 ///             content: String::from_utf8_lossy(content).to_string(),
 ///             mime_type: mime_type.to_string().into(),
 ///             metadata: Metadata::default(),
-///             tables: vec![],
-///             detected_languages: None,
-///             chunks: None,
-///             images: None,
-///             djot_content: None,
-///             pages: None,
-///             elements: None,
+///             ..Default::default()
 ///         })
 ///     }
 ///
@@ -230,6 +224,10 @@ mod tests {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
+                layout: None,
                 djot_content: None,
                 pages: None,
                 elements: None,
@@ -369,6 +367,10 @@ mod tests {
                     detected_languages: None,
                     chunks: None,
                     images: None,
+                    links: vec![],
+                    warnings: vec![],
+                    partial: false,
+                    layout: None,
                     djot_content: None,
                     pages: None,
                     elements: None,
@@ -417,6 +419,10 @@ mod tests {
                     detected_languages: None,
                     chunks: None,
                     images: None,
+                    links: vec![],
+                    warnings: vec![],
+                    partial: false,
+                    layout: None,
                     djot_content: None,
                     pages: None,
                     elements: None,
@@ -59,13 +59,7 @@ use crate::KreuzbergError;
 ///             content: "Premium extracted content".to_string(),
 ///             mime_type: mime_type.to_string(),
 ///             metadata: Metadata::default(),
-///             tables: vec![],
-///             detected_languages: None,
-///             chunks: None,
-///             images: None,
-///             djot_content: None,
-///             pages: None,
-///             elements: None,
+///             ..Default::default()
 ///         })
 ///     }
 ///
@@ -140,13 +134,7 @@ pub trait DocumentExtractor: Plugin {
     ///         content: text,
     ///         mime_type: mime_type.to_string(),
     ///         metadata,
-    ///         tables: vec![],
-    ///         detected_languages: None,
-    ///         chunks: None,
-    ///         images: None,
-    ///         djot_content: None,
-    ///         pages: None,
-    ///         elements: None,
+    ///         ..Default::default()
     ///     })
     /// }
     /// # }
@@ -213,13 +201,7 @@ pub trait DocumentExtractor: Plugin {
     ///         content,
     ///         mime_type: mime_type.to_string(),
     ///         metadata: Metadata::default(),
-    ///         tables: vec![],
-    ///         detected_languages: None,
-    ///         chunks: None,
-    ///         images: None,
-    ///         djot_content: None,
-    ///         pages: None,
-    ///         elements: None,
+    ///         ..Default::default()
     ///     })
     /// }
     /// # }
@@ -43,13 +43,7 @@
 //! #             content: String::new(),
 //! #             mime_type: String::new(),
 //! #             metadata: Metadata::default(),
-//! #             tables: vec![],
-//! #             detected_languages: None,
-//! #             chunks: None,
-//! #             images: None,
-//! #             pages: None,
-//! #             djot_content: None,
-//! #             elements: None,
+//! #             ..Default::default()
 //! #         })
 //! #     }
 //! #     async fn extract_file(&self, _: &std::path::Path, _: &str, _: &kreuzberg::ExtractionConfig)
@@ -58,13 +52,7 @@
 //! #             content: String::new(),
 //! #             mime_type: String::new(),
 //! #             metadata: Metadata::default(),
-//! #             tables: vec![],
-//! #             detected_languages: None,
-//! #             chunks: None,
-//! #             images: None,
-//! #             pages: None,
-//! #             djot_content: None,
-//! #             elements: None,
+//! #             ..Default::default()
 //! #         })
 //! #     }
 //! #     fn supported_mime_types(&self) -> &[&str] { &[] }
@@ -122,13 +110,7 @@
 //!             content: extracted_text,
 //!             mime_type: "application/json".to_string(),
 //!             metadata,
-//!             tables: vec![],
-//!             detected_languages: None,
-//!             chunks: None,
-//!             images: None,
-//!             djot_content: None,
-//!             pages: None,
-//!             elements: None,
+//!             ..Default::default()
 //!         })
 //!     }
 //!
@@ -214,9 +196,15 @@ pub use extractor::{DocumentExtractor, clear_extractors, list_extractors, regist
 pub use ocr::{
     OcrBackend, OcrBackendType, clear_ocr_backends, list_ocr_backends, register_ocr_backend, unregister_ocr_backend,
 };
-pub use processor::{PostProcessor, ProcessingStage, list_post_processors};
+pub use processor::{
+    PostProcessor, ProcessingStage, clear_post_processors, list_post_processors, register_post_processor,
+    unregister_post_processor,
+};
 pub use traits::Plugin;
-pub use validator::{Validator, clear_validators, list_validators, register_validator, unregister_validator};
+pub use validator::{
+    MinTextLengthValidator, ValidationOutcome, Validator, clear_validators, list_validators, min_text_length_validator,
+    register_validator, unregister_validator,
+};
 
 // Re-export registry items for backward compatibility
 pub use registry::{
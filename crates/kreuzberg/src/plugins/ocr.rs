@@ -63,13 +63,7 @@ pub enum OcrBackendType {
 ///             content: "Extracted text".to_string(),
 ///             mime_type: Cow::Borrowed("text/plain"),
 ///             metadata: Metadata::default(),
-///             tables: vec![],
-///             detected_languages: None,
-///             chunks: None,
-///             images: None,
-///             djot_content: None,
-///             pages: None,
-///             elements: None,
+///             ..Default::default()
 ///         })
 ///     }
 ///
@@ -144,13 +138,7 @@ pub trait OcrBackend: Plugin {
     ///         content: text,
     ///         mime_type: Cow::Borrowed("text/plain"),
     ///         metadata: Metadata::default(),
-    ///         tables: vec![],
-    ///         detected_languages: None,
-    ///         chunks: None,
-    ///         images: None,
-    ///         djot_content: None,
-    ///         pages: None,
-    ///         elements: None,
+    ///         ..Default::default()
     ///     })
     /// }
     /// # }
@@ -264,6 +252,17 @@ pub trait OcrBackend: Plugin {
         vec![]
     }
 
+    /// Optional: Resolve the `"auto"` language to the language(s) to OCR a document with.
+    ///
+    /// Called once per document with the image of its first page when
+    /// [`OcrConfig::is_auto_language`] is true; every page is then processed with the
+    /// returned language. Defaults to [`OcrConfig::fallback_language`]. Override if your
+    /// backend can detect the language of an image.
+    async fn resolve_language(&self, image_bytes: &[u8], config: &OcrConfig) -> Result<String> {
+        let _ = image_bytes;
+        Ok(config.fallback_language().to_string())
+    }
+
     /// Optional: Check if the backend supports table detection.
     ///
     /// Defaults to `false`. Override if your backend can detect and extract tables.
@@ -317,13 +316,7 @@ pub trait OcrBackend: Plugin {
 ///             content: "text".to_string(),
 ///             mime_type: Cow::Borrowed("text/plain"),
 ///             metadata: Metadata::default(),
-///             tables: vec![],
-///             detected_languages: None,
-///             chunks: None,
-///             images: None,
-///             djot_content: None,
-///             pages: None,
-///             elements: None,
+///             ..Default::default()
 ///         })
 ///     }
 ///     fn supports_language(&self, _: &str) -> bool { true }
@@ -485,6 +478,10 @@ mod tests {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
+                layout: None,
                 djot_content: None,
                 pages: None,
                 elements: None,
@@ -515,6 +512,12 @@ mod tests {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         };
 
         let result = backend.process_image(b"fake image data", &config).await.unwrap();
@@ -603,6 +606,12 @@ mod tests {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         };
 
         let result = backend.process_file(path, &config).await.unwrap();
@@ -641,6 +650,12 @@ mod tests {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         };
 
         let result = backend.process_image(b"", &config).await;
@@ -1,6 +1,111 @@
 //! Post-processor registry management.
 //!
-//! This module provides functions for querying the global post-processor registry.
+//! This module provides functions for registering, unregistering, and querying
+//! post-processors in the global registry.
+
+use super::PostProcessor;
+use std::sync::Arc;
+
+/// Register a post-processor with the global registry.
+///
+/// Post-processors run after extraction, grouped by [`ProcessingStage`](super::ProcessingStage).
+/// Within a stage, higher priorities run first and processors with equal priority run
+/// in registration order. Registering a processor under a name that is already taken
+/// replaces the previous processor.
+///
+/// # Arguments
+///
+/// * `processor` - The post-processor to register
+/// * `priority` - Execution priority within the processor's stage (higher = runs first)
+///
+/// # Returns
+///
+/// - `Ok(())` if registration succeeded
+/// - `Err(...)` if validation failed or initialization failed
+///
+/// # Errors
+///
+/// - `KreuzbergError::Validation` - Invalid post-processor name (empty or contains whitespace)
+/// - Any error from the post-processor's `initialize()` method
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::{Plugin, PostProcessor, ProcessingStage, register_post_processor};
+/// use kreuzberg::{Result, ExtractionResult, ExtractionConfig};
+/// use async_trait::async_trait;
+/// use std::sync::Arc;
+///
+/// struct UppercaseProcessor;
+///
+/// impl Plugin for UppercaseProcessor {
+///     fn name(&self) -> &str { "uppercase" }
+///     fn version(&self) -> String { "1.0.0".to_string() }
+///     fn initialize(&self) -> Result<()> { Ok(()) }
+///     fn shutdown(&self) -> Result<()> { Ok(()) }
+/// }
+///
+/// #[async_trait]
+/// impl PostProcessor for UppercaseProcessor {
+///     async fn process(&self, result: &mut ExtractionResult, _: &ExtractionConfig) -> Result<()> {
+///         result.content = result.content.to_uppercase();
+///         Ok(())
+///     }
+///
+///     fn processing_stage(&self) -> ProcessingStage {
+///         ProcessingStage::Late
+///     }
+/// }
+///
+/// # tokio_test::block_on(async {
+/// register_post_processor(Arc::new(UppercaseProcessor), 0)?;
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// # });
+/// ```
+pub fn register_post_processor(processor: Arc<dyn PostProcessor>, priority: i32) -> crate::Result<()> {
+    use crate::plugins::registry::get_post_processor_registry;
+
+    let registry = get_post_processor_registry();
+    let mut registry = registry
+        .write()
+        .expect("~keep Failed to acquire write lock on post-processor registry"); // ~keep
+
+    registry.register(processor, priority)
+}
+
+/// Unregister a post-processor by name.
+///
+/// Removes the post-processor from the global registry and calls its `shutdown()` method.
+///
+/// # Arguments
+///
+/// * `name` - Name of the post-processor to unregister
+///
+/// # Returns
+///
+/// - `Ok(())` if the post-processor was unregistered or didn't exist
+/// - `Err(...)` if the shutdown method failed
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::unregister_post_processor;
+///
+/// # tokio_test::block_on(async {
+/// unregister_post_processor("uppercase")?;
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// # });
+/// ```
+pub fn unregister_post_processor(name: &str) -> crate::Result<()> {
+    use crate::plugins::registry::get_post_processor_registry;
+
+    let registry = get_post_processor_registry();
+    let mut registry = registry
+        .write()
+        .expect("~keep Failed to acquire write lock on post-processor registry"); // ~keep
+
+    registry.remove(name)
+}
 
 /// List all registered post-processor names.
 ///
@@ -35,3 +140,154 @@ pub fn list_post_processors() -> crate::Result<Vec<String>> {
 
     Ok(registry.list())
 }
+
+/// Clear all post-processors from the global registry.
+///
+/// Removes all post-processors and calls their `shutdown()` methods.
+///
+/// # Returns
+///
+/// - `Ok(())` if all post-processors were cleared successfully
+/// - `Err(...)` if any shutdown method failed
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::clear_post_processors;
+///
+/// # tokio_test::block_on(async {
+/// clear_post_processors()?;
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// # });
+/// ```
+pub fn clear_post_processors() -> crate::Result<()> {
+    use crate::plugins::registry::get_post_processor_registry;
+
+    let registry = get_post_processor_registry();
+    let mut registry = registry
+        .write()
+        .expect("~keep Failed to acquire write lock on post-processor registry"); // ~keep
+
+    registry.shutdown_all()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Result;
+    use crate::core::config::ExtractionConfig;
+    use crate::plugins::registry::get_post_processor_registry;
+    use crate::plugins::{Plugin, ProcessingStage};
+    use crate::types::ExtractionResult;
+    use async_trait::async_trait;
+    use std::borrow::Cow;
+
+    /// Rewrites the content; `suffix` distinguishes instances registered under the same name.
+    struct RewriteProcessor {
+        name: &'static str,
+        uppercase: bool,
+        suffix: &'static str,
+    }
+
+    impl Plugin for RewriteProcessor {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl PostProcessor for RewriteProcessor {
+        async fn process(&self, result: &mut ExtractionResult, _config: &ExtractionConfig) -> Result<()> {
+            if self.uppercase {
+                result.content = result.content.to_uppercase();
+            }
+            result.content.push_str(self.suffix);
+            Ok(())
+        }
+
+        fn processing_stage(&self) -> ProcessingStage {
+            ProcessingStage::Late
+        }
+    }
+
+    fn rewrite(name: &'static str, uppercase: bool, suffix: &'static str) -> Arc<dyn PostProcessor> {
+        Arc::new(RewriteProcessor {
+            name,
+            uppercase,
+            suffix,
+        })
+    }
+
+    async fn run_late_stage(content: &str, names: &[&str]) -> String {
+        let processors = get_post_processor_registry()
+            .read()
+            .unwrap()
+            .get_for_stage(ProcessingStage::Late);
+
+        let mut result = ExtractionResult {
+            content: content.to_string(),
+            mime_type: Cow::Borrowed("text/plain"),
+            metadata: crate::types::Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
+            djot_content: None,
+            pages: None,
+            elements: None,
+        };
+        let config = ExtractionConfig::default();
+        for processor in processors.iter().filter(|p| names.contains(&p.name())) {
+            processor.process(&mut result, &config).await.unwrap();
+        }
+        result.content
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_register_post_processor_runs_in_registration_order() {
+        register_post_processor(rewrite("test-uppercase", true, ""), 0).unwrap();
+        register_post_processor(rewrite("test-suffix", false, " done"), 0).unwrap();
+
+        let names = ["test-uppercase", "test-suffix"];
+        assert_eq!(run_late_stage("hello world", &names).await, "HELLO WORLD done");
+        assert!(list_post_processors().unwrap().contains(&"test-uppercase".to_string()));
+
+        unregister_post_processor("test-uppercase").unwrap();
+        unregister_post_processor("test-suffix").unwrap();
+        assert_eq!(run_late_stage("hello world", &names).await, "hello world");
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_register_post_processor_replaces_same_name() {
+        register_post_processor(rewrite("test-replaced", true, " first"), 0).unwrap();
+        register_post_processor(rewrite("test-replaced", true, " second"), 0).unwrap();
+
+        assert_eq!(run_late_stage("text", &["test-replaced"]).await, "TEXT second");
+
+        unregister_post_processor("test-replaced").unwrap();
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_unregister_nonexistent_post_processor() {
+        assert!(unregister_post_processor("nonexistent-processor-xyz").is_ok());
+    }
+}
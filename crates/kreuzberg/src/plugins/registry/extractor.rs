@@ -259,6 +259,10 @@ mod tests {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
+                layout: None,
                 djot_content: None,
                 pages: None,
                 elements: None,
@@ -501,6 +505,10 @@ mod tests {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
+                layout: None,
                 djot_content: None,
                 pages: None,
                 elements: None,
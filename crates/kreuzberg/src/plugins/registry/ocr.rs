@@ -224,6 +224,10 @@ mod tests {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
+                layout: None,
                 djot_content: None,
                 pages: None,
                 elements: None,
@@ -351,6 +355,10 @@ mod tests {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
+                layout: None,
                 djot_content: None,
                 pages: None,
                 elements: None,
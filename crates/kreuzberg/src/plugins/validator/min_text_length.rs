@@ -0,0 +1,143 @@
+//! Built-in validator that flags suspiciously short extractions.
+
+use super::r#trait::{ValidationOutcome, Validator};
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::plugins::Plugin;
+use crate::types::ExtractionResult;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Validator that warns when the extracted text is shorter than a threshold.
+///
+/// Very little text from a document that should have some usually means a scan
+/// without OCR or a failed extraction. The check never fails the extraction; it
+/// attaches a validation warning to the result instead.
+///
+/// Length is counted in characters of the content with surrounding whitespace trimmed.
+#[derive(Debug, Clone, Copy)]
+pub struct MinTextLengthValidator {
+    min_length: usize,
+}
+
+impl MinTextLengthValidator {
+    /// Registration name of the validator.
+    pub const NAME: &'static str = "min-text-length";
+
+    /// Create a validator that warns below `min_length` characters.
+    pub fn new(min_length: usize) -> Self {
+        Self { min_length }
+    }
+
+    /// The configured minimum length in characters.
+    pub fn min_length(&self) -> usize {
+        self.min_length
+    }
+}
+
+impl Plugin for MinTextLengthValidator {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Validator for MinTextLengthValidator {
+    async fn validate(&self, _result: &ExtractionResult, _config: &ExtractionConfig) -> Result<()> {
+        Ok(())
+    }
+
+    async fn validate_with_outcome(
+        &self,
+        result: &ExtractionResult,
+        _config: &ExtractionConfig,
+    ) -> Result<ValidationOutcome> {
+        let length = result.content.trim().chars().count();
+        if length >= self.min_length {
+            return Ok(ValidationOutcome::pass());
+        }
+
+        Ok(ValidationOutcome::pass_with_warnings(vec![format!(
+            "Extracted text is shorter than expected ({} < {} characters); the document may be scanned or extraction may have failed",
+            length, self.min_length
+        )]))
+    }
+}
+
+/// Create a [`MinTextLengthValidator`] ready for [`super::register_validator`].
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::{min_text_length_validator, register_validator};
+///
+/// register_validator(min_text_length_validator(50))?;
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// ```
+pub fn min_text_length_validator(min_length: usize) -> Arc<dyn Validator> {
+    Arc::new(MinTextLengthValidator::new(min_length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::registry::ValidatorRegistry;
+    use crate::types::Metadata;
+    use std::borrow::Cow;
+
+    fn result_with_content(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: Cow::Borrowed("text/plain"),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
+            djot_content: None,
+            pages: None,
+            elements: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_min_text_length_validator_registered() {
+        let mut registry = ValidatorRegistry::new();
+        registry.register(min_text_length_validator(20)).unwrap();
+        assert_eq!(registry.list(), vec![MinTextLengthValidator::NAME.to_string()]);
+
+        let validator = &registry.get_all()[0];
+        let config = ExtractionConfig::default();
+
+        let short = validator
+            .validate_with_outcome(&result_with_content("  too short \n"), &config)
+            .await
+            .unwrap();
+        assert!(short.passed);
+        assert_eq!(short.warnings.len(), 1);
+        assert!(short.warnings[0].contains("(9 < 20 characters)"));
+
+        let long = validator
+            .validate_with_outcome(&result_with_content("long enough to pass the check"), &config)
+            .await
+            .unwrap();
+        assert_eq!(long, ValidationOutcome::pass());
+    }
+}
@@ -2,11 +2,14 @@
 //!
 //! This module provides the trait and registry for implementing custom validators.
 
+mod min_text_length;
 mod registry;
 mod r#trait;
 
+pub use min_text_length::{MinTextLengthValidator, min_text_length_validator};
+
 // Re-export trait for backward compatibility
-pub use r#trait::Validator;
+pub use r#trait::{ValidationOutcome, Validator};
 
 // Re-export registry functions for backward compatibility
 pub use registry::{clear_validators, list_validators, register_validator, unregister_validator};
@@ -68,6 +71,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -89,6 +96,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -112,6 +123,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -122,6 +137,60 @@ mod tests {
         assert!(validator.should_validate(&result, &config));
     }
 
+    #[tokio::test]
+    async fn test_validator_outcome_default_has_no_warnings() {
+        let validator = MockValidator { should_fail: false };
+
+        let result = ExtractionResult {
+            content: "test content".to_string(),
+            mime_type: Cow::Borrowed("text/plain"),
+            metadata: crate::types::Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
+            djot_content: None,
+            pages: None,
+            elements: None,
+        };
+
+        let config = ExtractionConfig::default();
+        let outcome = validator.validate_with_outcome(&result, &config).await.unwrap();
+
+        assert_eq!(outcome, ValidationOutcome::pass());
+    }
+
+    #[tokio::test]
+    async fn test_validator_outcome_default_propagates_error() {
+        let validator = MockValidator { should_fail: true };
+
+        let result = ExtractionResult {
+            content: "test content".to_string(),
+            mime_type: Cow::Borrowed("text/plain"),
+            metadata: crate::types::Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
+            djot_content: None,
+            pages: None,
+            elements: None,
+        };
+
+        let config = ExtractionConfig::default();
+        let outcome = validator.validate_with_outcome(&result, &config).await;
+
+        assert!(matches!(outcome, Err(KreuzbergError::Validation { .. })));
+    }
+
     #[test]
     fn test_validator_priority_default() {
         let validator = MockValidator { should_fail: false };
@@ -150,6 +219,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -200,6 +273,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -213,6 +290,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -299,6 +380,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -334,6 +419,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             elements: None,
         };
@@ -352,6 +441,7 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |".to_string(),
             page_number: 0,
+            caption: None,
         };
 
         let result = ExtractionResult {
@@ -362,6 +452,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -393,6 +487,10 @@ mod tests {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
+                layout: None,
                 djot_content: None,
                 pages: None,
                 elements: None,
@@ -414,6 +512,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
             djot_content: None,
             pages: None,
             elements: None,
@@ -8,6 +8,43 @@ use crate::plugins::Plugin;
 use crate::types::ExtractionResult;
 use async_trait::async_trait;
 
+/// Outcome of running a validator that may annotate the result instead of failing it.
+///
+/// A validator that passes can still attach warnings (e.g. "low text density, likely
+/// scanned"), which are appended to `ExtractionResult::warnings` with kind
+/// [`ExtractionWarningKind::Validation`](crate::types::ExtractionWarningKind::Validation). A failed outcome aborts the extraction just like an
+/// error returned from [`Validator::validate`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationOutcome {
+    /// Whether the result passed validation.
+    pub passed: bool,
+    /// Non-fatal warnings to attach to the result.
+    pub warnings: Vec<String>,
+}
+
+impl ValidationOutcome {
+    /// A passing outcome with no warnings.
+    pub fn pass() -> Self {
+        Self {
+            passed: true,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// A passing outcome carrying the given warnings.
+    pub fn pass_with_warnings(warnings: Vec<String>) -> Self {
+        Self { passed: true, warnings }
+    }
+
+    /// A failing outcome; the warnings are used as the failure reason.
+    pub fn fail(warnings: Vec<String>) -> Self {
+        Self {
+            passed: false,
+            warnings,
+        }
+    }
+}
+
 /// Trait for validator plugins.
 ///
 /// Validators check extraction results for quality, completeness, or correctness.
@@ -27,7 +64,8 @@ use async_trait::async_trait;
 /// Validator errors are **fatal** - they cause the extraction to fail and bubble up
 /// to the caller. Use validators for hard requirements that must be met.
 ///
-/// For non-fatal checks, use post-processors instead.
+/// For non-fatal checks, override [`Validator::validate_with_outcome`] and return
+/// [`ValidationOutcome::pass_with_warnings`] to annotate the result without failing it.
 ///
 /// # Thread Safety
 ///
@@ -197,6 +235,27 @@ pub trait Validator: Plugin {
     /// ```
     async fn validate(&self, result: &ExtractionResult, config: &ExtractionConfig) -> Result<()>;
 
+    /// Validate an extraction result and report non-fatal warnings.
+    ///
+    /// The default implementation delegates to [`Validator::validate`] and reports a
+    /// passing outcome without warnings, so existing validators keep working unchanged.
+    /// Override this to annotate results instead of failing them.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(outcome)` with `passed = true` - validation passed; `warnings` are appended
+    ///   to the result's warnings
+    /// - `Ok(outcome)` with `passed = false` - validation failed (extraction will fail)
+    /// - `Err(...)` - validation failed (extraction will fail)
+    async fn validate_with_outcome(
+        &self,
+        result: &ExtractionResult,
+        config: &ExtractionConfig,
+    ) -> Result<ValidationOutcome> {
+        self.validate(result, config).await?;
+        Ok(ValidationOutcome::pass())
+    }
+
     /// Optional: Check if this validator should run for a given result.
     ///
     /// Allows conditional validation based on MIME type, metadata, or content.
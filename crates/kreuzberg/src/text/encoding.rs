@@ -0,0 +1,308 @@
+//! Character encoding detection for plain-text and HTML input.
+//!
+//! Raw bytes are decoded to UTF-8 using the first source that applies:
+//!
+//! 1. An explicit encoding override from the extraction config
+//! 2. A byte order mark
+//! 3. An HTML `<meta charset>` declaration (HTML input only)
+//! 4. Strict UTF-8 validation
+//! 5. Statistical detection via `chardetng` (requires the `quality` feature)
+//!
+//! If none of these produce a clean decode, the input is decoded as UTF-8 with
+//! replacement characters and a warning is reported alongside the text.
+//! Extractors surface decoding issues as [`ExtractionWarningKind::Encoding`] warnings.
+//!
+//! Without the `quality` feature only UTF-8 is supported: an override naming another
+//! encoding is ignored and reported as a warning.
+
+use std::borrow::Cow;
+
+use once_cell::sync::Lazy;
+use regex::bytes::Regex;
+
+use crate::text::utf8_validation;
+use crate::types::{ExtractionWarning, ExtractionWarningKind};
+
+/// Number of leading bytes scanned for an HTML `<meta charset>` declaration.
+const META_PRESCAN_BYTES: usize = 1024;
+
+const UTF_8_NAME: &str = "UTF-8";
+
+static META_CHARSET: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)<meta\b[^>]*?charset\s*=\s*["']?\s*([a-z0-9_.:\-]+)"#)
+        .expect("Meta charset regex pattern is valid and should compile")
+});
+
+/// Text decoded from raw bytes, with the encoding that was used.
+#[derive(Debug, Clone)]
+pub struct DecodedText<'a> {
+    /// Decoded UTF-8 text (borrowed when the input was already valid UTF-8)
+    pub text: Cow<'a, str>,
+    /// Canonical name of the encoding used to decode the input
+    pub encoding: &'static str,
+    /// Set when invalid sequences had to be replaced during decoding
+    pub warning: Option<String>,
+}
+
+impl DecodedText<'_> {
+    /// Extraction warnings describing how the input was decoded.
+    ///
+    /// Reports the source encoding when it differs from UTF-8, followed by any decoding warning.
+    pub fn warnings(&self) -> Vec<ExtractionWarning> {
+        let mut warnings = Vec::new();
+        if self.encoding != UTF_8_NAME {
+            warnings.push(ExtractionWarning::new(
+                ExtractionWarningKind::Encoding,
+                format!("Input was decoded from {}", self.encoding),
+            ));
+        }
+        if let Some(warning) = &self.warning {
+            warnings.push(ExtractionWarning::new(ExtractionWarningKind::Encoding, warning.clone()));
+        }
+        warnings
+    }
+}
+
+/// Decode plain-text bytes to UTF-8.
+///
+/// # Arguments
+///
+/// * `bytes` - Raw file content
+/// * `encoding_override` - Encoding label (e.g. `"windows-1252"`) that bypasses detection
+pub fn decode_text<'a>(bytes: &'a [u8], encoding_override: Option<&str>) -> DecodedText<'a> {
+    decode(bytes, encoding_override, None)
+}
+
+/// Decode HTML bytes to UTF-8.
+///
+/// Behaves like [`decode_text`], except that a `<meta charset>` declaration in the
+/// document head takes precedence over UTF-8 validation and statistical detection.
+pub fn decode_html<'a>(bytes: &'a [u8], encoding_override: Option<&str>) -> DecodedText<'a> {
+    decode(bytes, encoding_override, sniff_meta_charset(bytes))
+}
+
+/// Find the charset label declared by an HTML `<meta>` tag near the start of the document.
+fn sniff_meta_charset(bytes: &[u8]) -> Option<&str> {
+    let head = &bytes[..bytes.len().min(META_PRESCAN_BYTES)];
+    let label = META_CHARSET.captures(head)?.get(1)?;
+    std::str::from_utf8(label.as_bytes()).ok()
+}
+
+#[cfg(feature = "quality")]
+fn decode<'a>(bytes: &'a [u8], encoding_override: Option<&str>, declared: Option<&str>) -> DecodedText<'a> {
+    use encoding_rs::Encoding;
+
+    if let Some(encoding) = encoding_override.and_then(|label| Encoding::for_label(label.trim().as_bytes())) {
+        return decode_with(bytes, encoding);
+    }
+
+    if let Some((encoding, bom_len)) = Encoding::for_bom(bytes) {
+        return decode_with(&bytes[bom_len..], encoding);
+    }
+
+    // UTF-16 declarations in <meta> are meaningless for byte-oriented input (HTML spec)
+    if let Some(encoding) = declared
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .filter(|encoding| *encoding != encoding_rs::UTF_16LE && *encoding != encoding_rs::UTF_16BE)
+    {
+        return decode_with(bytes, encoding);
+    }
+
+    if let Ok(text) = utf8_validation::from_utf8(bytes) {
+        return DecodedText {
+            text: Cow::Borrowed(text),
+            encoding: UTF_8_NAME,
+            warning: None,
+        };
+    }
+
+    let mut detector = chardetng::EncodingDetector::new();
+    detector.feed(bytes, true);
+    let encoding = detector.guess(None, false);
+    let (text, had_errors) = encoding.decode_without_bom_handling(bytes);
+    if !had_errors {
+        return DecodedText {
+            text,
+            encoding: encoding.name(),
+            warning: None,
+        };
+    }
+
+    decode_lossy(bytes)
+}
+
+#[cfg(not(feature = "quality"))]
+fn decode<'a>(bytes: &'a [u8], encoding_override: Option<&str>, _declared: Option<&str>) -> DecodedText<'a> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    let mut decoded = match utf8_validation::from_utf8(bytes) {
+        Ok(text) => DecodedText {
+            text: Cow::Borrowed(text),
+            encoding: UTF_8_NAME,
+            warning: None,
+        },
+        Err(_) => decode_lossy(bytes),
+    };
+
+    if let Some(label) = encoding_override.filter(|label| !is_utf8_label(label)) {
+        decoded.warning = Some(format!(
+            "Encoding override '{}' was ignored because decoding non-UTF-8 input requires the `quality` feature",
+            label.trim()
+        ));
+    }
+
+    decoded
+}
+
+/// Whether `label` is one of the WHATWG labels for UTF-8.
+#[cfg(not(feature = "quality"))]
+pub(crate) fn is_utf8_label(label: &str) -> bool {
+    const UTF_8_LABELS: [&str; 6] = [
+        "utf-8",
+        "utf8",
+        "unicode-1-1-utf-8",
+        "unicode11utf8",
+        "unicode20utf8",
+        "x-unicode20utf8",
+    ];
+    UTF_8_LABELS
+        .iter()
+        .any(|utf8_label| label.trim().eq_ignore_ascii_case(utf8_label))
+}
+
+#[cfg(feature = "quality")]
+fn decode_with<'a>(bytes: &'a [u8], encoding: &'static encoding_rs::Encoding) -> DecodedText<'a> {
+    let (text, had_errors) = encoding.decode_without_bom_handling(bytes);
+    DecodedText {
+        text,
+        encoding: encoding.name(),
+        warning: had_errors.then(|| {
+            format!(
+                "Input is not valid {}; invalid byte sequences were replaced",
+                encoding.name()
+            )
+        }),
+    }
+}
+
+fn decode_lossy(bytes: &[u8]) -> DecodedText<'_> {
+    DecodedText {
+        text: String::from_utf8_lossy(bytes),
+        encoding: UTF_8_NAME,
+        warning: Some("Could not detect input encoding; decoded as UTF-8 with replacement characters".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_text_utf8_is_borrowed() {
+        let decoded = decode_text("Grüße".as_bytes(), None);
+
+        assert_eq!(decoded.text, "Grüße");
+        assert_eq!(decoded.encoding, "UTF-8");
+        assert!(matches!(decoded.text, Cow::Borrowed(_)));
+        assert!(decoded.warning.is_none());
+    }
+
+    #[test]
+    fn test_decode_text_strips_utf8_bom() {
+        let decoded = decode_text(b"\xEF\xBB\xBFHello", None);
+
+        assert_eq!(decoded.text, "Hello");
+        assert_eq!(decoded.encoding, "UTF-8");
+    }
+
+    #[test]
+    fn test_sniff_meta_charset() {
+        assert_eq!(
+            sniff_meta_charset(b"<html><head><meta charset=\"windows-1251\"></head>"),
+            Some("windows-1251")
+        );
+        assert_eq!(
+            sniff_meta_charset(b"<META http-equiv=\"Content-Type\" content=\"text/html; charset=Shift_JIS\">"),
+            Some("Shift_JIS")
+        );
+        assert_eq!(sniff_meta_charset(b"<html><head><title>x</title></head>"), None);
+    }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn test_decode_text_windows_1252_smart_quotes() {
+        let bytes = b"\x93Smart quotes\x94 are common in documents saved by older word processors. \
+                      It\x92s a \x91classic\x92 problem \x96 and it costs \x8020 to fix.";
+
+        let decoded = decode_text(bytes, None);
+
+        assert_eq!(decoded.encoding, "windows-1252");
+        assert!(decoded.text.starts_with("\u{201C}Smart quotes\u{201D}"));
+        assert!(
+            decoded
+                .text
+                .contains("It\u{2019}s a \u{2018}classic\u{2019} problem \u{2013}")
+        );
+        assert!(decoded.text.contains("\u{20AC}20"));
+        assert!(decoded.warning.is_none());
+    }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn test_decode_text_shift_jis() {
+        let source = "日本語のテキストファイルです。文字コードはシフトJISで保存されています。\
+                      これは自動検出のテストです。";
+        let (bytes, _, had_errors) = encoding_rs::SHIFT_JIS.encode(source);
+        assert!(!had_errors);
+
+        let decoded = decode_text(&bytes, None);
+
+        assert_eq!(decoded.encoding, "Shift_JIS");
+        assert_eq!(decoded.text, source);
+        assert!(decoded.warning.is_none());
+    }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn test_decode_text_respects_override() {
+        let decoded = decode_text(b"caf\xE9", Some("iso-8859-1"));
+
+        assert_eq!(decoded.text, "café");
+        assert_eq!(decoded.encoding, "windows-1252");
+    }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn test_decode_html_meta_charset_takes_precedence() {
+        // 0xA4 is the euro sign in ISO-8859-15 but the currency sign in windows-1252
+        let html = b"<html><head><meta charset=\"iso-8859-15\"></head><body>Price: 5\xA4</body></html>";
+
+        let decoded = decode_html(html, None);
+
+        assert_eq!(decoded.encoding, "ISO-8859-15");
+        assert!(decoded.text.contains("Price: 5\u{20AC}"));
+    }
+
+    #[cfg(not(feature = "quality"))]
+    #[test]
+    fn test_decode_text_reports_ignored_override_without_quality() {
+        let decoded = decode_text(b"caf\xC3\xA9", Some("windows-1252"));
+        assert_eq!(decoded.text, "caf\u{e9}");
+        assert!(decoded.warning.is_some_and(|warning| warning.contains("windows-1252")));
+
+        let decoded = decode_text(b"caf\xC3\xA9", Some("UTF-8"));
+        assert!(decoded.warning.is_none());
+    }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn test_decode_override_with_invalid_bytes_records_warning() {
+        let decoded = decode_text(b"ok \xFF\xFE", Some("utf-8"));
+
+        assert!(decoded.text.contains('\u{FFFD}'));
+        assert!(decoded.warning.is_some());
+
+        let warnings = decoded.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, ExtractionWarningKind::Encoding);
+    }
+}
@@ -1,4 +1,10 @@
+pub mod encoding;
+pub mod redaction;
 pub mod utf8_validation;
+pub mod whitespace;
+
+pub use redaction::{EMAIL_PATTERN, REDACTION_COUNTS_KEY, RedactionScope, RegexRedactor, SSN_PATTERN};
+pub use whitespace::normalize_text;
 
 #[cfg(feature = "quality")]
 pub mod quality;
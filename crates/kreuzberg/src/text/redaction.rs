@@ -0,0 +1,351 @@
+//! Regex-based redaction post-processor.
+//!
+//! [`RegexRedactor`] masks every match of a set of named patterns (emails, social
+//! security numbers, or custom expressions) in an extraction result and records how
+//! many matches of each pattern were replaced. It is not registered by default; build
+//! one and pass it to [`register_post_processor`](crate::plugins::register_post_processor).
+
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::types::{Metadata, Table};
+use crate::{ExtractionConfig, ExtractionResult, KreuzbergError, Result};
+use async_trait::async_trait;
+use regex::{NoExpand, Regex};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Pattern matching email addresses.
+pub const EMAIL_PATTERN: &str = r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}";
+
+/// Pattern matching US social security numbers written as `123-45-6789`.
+pub const SSN_PATTERN: &str = r"\b\d{3}-\d{2}-\d{4}\b";
+
+/// Metadata key holding the number of replaced matches per pattern name.
+pub const REDACTION_COUNTS_KEY: &str = "redaction_counts";
+
+/// Parts of an extraction result that a [`RegexRedactor`] rewrites.
+///
+/// Everything is redacted by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RedactionScope {
+    /// Main content and per-page content
+    pub content: bool,
+    /// Table cells and their rendered markdown
+    pub tables: bool,
+    /// Text metadata fields (title, authors, keywords, ...) and string values of additional metadata
+    pub metadata: bool,
+}
+
+impl Default for RedactionScope {
+    fn default() -> Self {
+        Self {
+            content: true,
+            tables: true,
+            metadata: true,
+        }
+    }
+}
+
+/// Post-processor that replaces matches of named regex patterns with a replacement token.
+///
+/// This processor:
+/// - Runs in the Early processing stage, before chunking and keyword extraction
+/// - Redacts the parts of the result selected by its [`RedactionScope`]
+/// - Stores per-pattern match counts in `metadata.additional["redaction_counts"]`
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::register_post_processor;
+/// use kreuzberg::text::{EMAIL_PATTERN, RegexRedactor};
+/// use std::sync::Arc;
+///
+/// let redactor = RegexRedactor::new([("email", EMAIL_PATTERN), ("ticket", r"TICKET-\d+")], "[REDACTED]")?;
+/// register_post_processor(Arc::new(redactor), 0)?;
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegexRedactor {
+    patterns: Vec<(String, Regex)>,
+    replacement: String,
+    scope: RedactionScope,
+}
+
+impl RegexRedactor {
+    /// Create a redactor from `(name, pattern)` pairs.
+    ///
+    /// Patterns are applied in the given order. Returns a validation error when a
+    /// pattern does not compile.
+    pub fn new<N, P>(patterns: impl IntoIterator<Item = (N, P)>, replacement: impl Into<String>) -> Result<Self>
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+    {
+        let patterns = patterns
+            .into_iter()
+            .map(|(name, pattern)| {
+                let name = name.into();
+                Regex::new(pattern.as_ref())
+                    .map(|regex| (name.clone(), regex))
+                    .map_err(|e| KreuzbergError::validation(format!("Invalid redaction pattern '{}': {}", name, e)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            patterns,
+            replacement: replacement.into(),
+            scope: RedactionScope::default(),
+        })
+    }
+
+    /// Create a redactor for email addresses (`"email"`) and social security numbers (`"ssn"`).
+    pub fn pii(replacement: impl Into<String>) -> Self {
+        Self::new([("email", EMAIL_PATTERN), ("ssn", SSN_PATTERN)], replacement)
+            .expect("Built-in redaction patterns are valid and should compile")
+    }
+
+    /// Restrict which parts of the result are redacted.
+    pub fn with_scope(mut self, scope: RedactionScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// The parts of the result this redactor rewrites.
+    pub fn scope(&self) -> RedactionScope {
+        self.scope
+    }
+
+    /// Redact `text` in place, adding the number of replaced matches to `counts`.
+    fn redact(&self, text: &mut String, counts: &mut BTreeMap<String, usize>) {
+        for (name, regex) in &self.patterns {
+            let matches = regex.find_iter(text).count();
+            if matches > 0 {
+                *text = regex.replace_all(text, NoExpand(&self.replacement)).into_owned();
+                *counts.entry(name.clone()).or_default() += matches;
+            }
+        }
+    }
+
+    /// Redact text that duplicates counted text, such as per-page content.
+    fn redact_uncounted(&self, text: &mut String) {
+        self.redact(text, &mut BTreeMap::new());
+    }
+
+    fn redact_table(&self, table: &mut Table, counts: &mut BTreeMap<String, usize>) {
+        for cell in table.cells.iter_mut().flatten() {
+            self.redact(cell, counts);
+        }
+        self.redact_uncounted(&mut table.markdown);
+    }
+
+    fn redact_metadata(&self, metadata: &mut Metadata, counts: &mut BTreeMap<String, usize>) {
+        let fields = [
+            metadata.title.as_mut(),
+            metadata.subject.as_mut(),
+            metadata.created_by.as_mut(),
+            metadata.modified_by.as_mut(),
+        ];
+        for field in fields.into_iter().flatten() {
+            self.redact(field, counts);
+        }
+        for value in [metadata.authors.as_mut(), metadata.keywords.as_mut()]
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            self.redact(value, counts);
+        }
+        for value in metadata.additional.values_mut() {
+            self.redact_json(value, counts);
+        }
+    }
+
+    fn redact_json(&self, value: &mut serde_json::Value, counts: &mut BTreeMap<String, usize>) {
+        match value {
+            serde_json::Value::String(text) => self.redact(text, counts),
+            serde_json::Value::Array(items) => items.iter_mut().for_each(|item| self.redact_json(item, counts)),
+            serde_json::Value::Object(map) => map.values_mut().for_each(|item| self.redact_json(item, counts)),
+            _ => {}
+        }
+    }
+}
+
+impl Plugin for RegexRedactor {
+    fn name(&self) -> &str {
+        "regex-redaction"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for RegexRedactor {
+    async fn process(&self, result: &mut ExtractionResult, _config: &ExtractionConfig) -> Result<()> {
+        let mut counts: BTreeMap<String, usize> = self.patterns.iter().map(|(name, _)| (name.clone(), 0)).collect();
+
+        if self.scope.content {
+            self.redact(&mut result.content, &mut counts);
+            for page in result.pages.iter_mut().flatten() {
+                self.redact_uncounted(&mut page.content);
+            }
+        }
+
+        if self.scope.tables {
+            for table in &mut result.tables {
+                self.redact_table(table, &mut counts);
+            }
+            for page in result.pages.iter_mut().flatten() {
+                for table in &mut page.tables {
+                    self.redact_table(Arc::make_mut(table), &mut BTreeMap::new());
+                }
+            }
+        }
+
+        if self.scope.metadata {
+            self.redact_metadata(&mut result.metadata, &mut counts);
+        }
+
+        result
+            .metadata
+            .additional
+            .insert(Cow::Borrowed(REDACTION_COUNTS_KEY), serde_json::json!(counts));
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Early
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        let text_length = result.content.len();
+        (text_length / 102400).max(1) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result_with_table(content: &str, cells: Vec<Vec<&str>>) -> ExtractionResult {
+        let cells: Vec<Vec<String>> = cells
+            .into_iter()
+            .map(|row| row.into_iter().map(str::to_string).collect())
+            .collect();
+        let markdown = cells
+            .iter()
+            .map(|row| format!("| {} |", row.join(" | ")))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: Cow::Borrowed("text/plain"),
+            metadata: Metadata::default(),
+            tables: vec![Table {
+                cells,
+                markdown,
+                page_number: 1,
+                caption: None,
+            }],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            layout: None,
+            djot_content: None,
+            pages: None,
+            elements: None,
+        }
+    }
+
+    fn count(result: &ExtractionResult, name: &str) -> u64 {
+        result.metadata.additional.get(REDACTION_COUNTS_KEY).unwrap()[name]
+            .as_u64()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_redacts_email_in_content_and_table_cell() {
+        let redactor = RegexRedactor::pii("[REDACTED]");
+        let mut result = result_with_table(
+            "Contact jane.doe@example.com for details.",
+            vec![vec!["Name", "Email"], vec!["John", "john@example.org"]],
+        );
+
+        redactor
+            .process(&mut result, &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.content, "Contact [REDACTED] for details.");
+        assert_eq!(result.tables[0].cells[1][1], "[REDACTED]");
+        assert!(!result.tables[0].markdown.contains("john@example.org"));
+        assert_eq!(count(&result, "email"), 2);
+        assert_eq!(count(&result, "ssn"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_scope_excludes_tables() {
+        let redactor = RegexRedactor::pii("***").with_scope(RedactionScope {
+            tables: false,
+            ..Default::default()
+        });
+        let mut result = result_with_table("SSN 123-45-6789", vec![vec!["john@example.org"]]);
+
+        redactor
+            .process(&mut result, &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.content, "SSN ***");
+        assert_eq!(result.tables[0].cells[0][0], "john@example.org");
+        assert_eq!(count(&result, "ssn"), 1);
+        assert_eq!(count(&result, "email"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_redacts_metadata_fields() {
+        let redactor = RegexRedactor::new([("ticket", r"TICKET-\d+")], "$0-hidden").unwrap();
+        let mut result = result_with_table("", vec![]);
+        result.metadata.title = Some("Notes for TICKET-42".to_string());
+        result.metadata.authors = Some(vec!["TICKET-7".to_string()]);
+        result
+            .metadata
+            .additional
+            .insert(Cow::Borrowed("source"), serde_json::json!(["TICKET-1", 3]));
+
+        redactor
+            .process(&mut result, &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.metadata.title.as_deref(), Some("Notes for $0-hidden"));
+        assert_eq!(result.metadata.authors, Some(vec!["$0-hidden".to_string()]));
+        assert_eq!(
+            result.metadata.additional.get("source").unwrap(),
+            &serde_json::json!(["$0-hidden", 3])
+        );
+        assert_eq!(count(&result, "ticket"), 3);
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_rejected() {
+        let err = RegexRedactor::new([("broken", "(unclosed")], "x").unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+        assert!(err.to_string().contains("broken"));
+    }
+}
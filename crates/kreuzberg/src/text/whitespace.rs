@@ -0,0 +1,248 @@
+//! Whitespace normalization for extracted text.
+//!
+//! PDF and OCR output frequently contains non-breaking spaces, soft hyphens, words
+//! hyphenated across line breaks, and mixed line endings. [`normalize_text`] cleans
+//! these up while keeping the document structure intact: paragraph breaks survive,
+//! line indentation is kept, and fenced code blocks are left untouched.
+
+use crate::types::PageBoundary;
+
+/// Soft hyphen (U+00AD), an invisible hyphenation hint.
+const SOFT_HYPHEN: char = '\u{AD}';
+
+/// Normalize whitespace in extracted text.
+///
+/// - Converts `\r\n` and `\r` line endings to `\n`
+/// - Replaces non-breaking and other Unicode spaces with a regular space
+/// - Removes soft hyphens (U+00AD)
+/// - Collapses runs of spaces and tabs inside a line to a single space, keeping leading indentation
+/// - Strips trailing whitespace from each line
+/// - Joins words hyphenated across a line break (`word-\nbreak` → `wordbreak`)
+/// - Collapses three or more consecutive newlines into one paragraph break (`\n\n`)
+///
+/// Lines inside fenced code blocks (```` ``` ```` or `~~~`) are kept verbatim.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::text::normalize_text;
+///
+/// let text = "Hyphen-\nated\u{a0}\u{a0}text\r\n\r\n\r\nNext paragraph";
+/// assert_eq!(normalize_text(text), "Hyphenated text\n\nNext paragraph");
+/// ```
+pub fn normalize_text(text: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut in_code_block = false;
+    let mut blank_run = 0usize;
+    let mut previous_is_prose = false;
+
+    for raw_line in text.split("\r\n").flat_map(|segment| segment.split(['\r', '\n'])) {
+        if in_code_block {
+            in_code_block = !is_code_fence(raw_line);
+            lines.push(raw_line.to_string());
+            continue;
+        }
+
+        let line = normalize_line(raw_line);
+        if line.is_empty() {
+            blank_run += 1;
+            previous_is_prose = false;
+            continue;
+        }
+
+        if blank_run == 0
+            && previous_is_prose
+            && let Some(previous) = lines.last_mut()
+            && let Some(stem_len) = hyphenated_stem_len(previous)
+            && line.trim_start().starts_with(char::is_lowercase)
+        {
+            previous.truncate(stem_len);
+            previous.push_str(line.trim_start());
+            continue;
+        }
+
+        push_blank_lines(&mut lines, blank_run, false);
+        blank_run = 0;
+
+        if is_code_fence(raw_line) {
+            in_code_block = true;
+            lines.push(raw_line.to_string());
+            previous_is_prose = false;
+        } else {
+            lines.push(line);
+            previous_is_prose = true;
+        }
+    }
+    push_blank_lines(&mut lines, blank_run, true);
+
+    lines.join("\n")
+}
+
+/// Normalize `content` and remap the page boundaries that index into it.
+///
+/// Each page range (and any separator text between pages) is normalized on its own,
+/// so the returned boundaries point at the same pages in the normalized text. Returns
+/// `None` when the boundaries are not ordered, non-overlapping ranges on character
+/// boundaries of `content`.
+pub(crate) fn normalize_text_with_boundaries(
+    content: &str,
+    boundaries: &[PageBoundary],
+) -> Option<(String, Vec<PageBoundary>)> {
+    let mut normalized = String::with_capacity(content.len());
+    let mut remapped = Vec::with_capacity(boundaries.len());
+    let mut cursor = 0usize;
+
+    for boundary in boundaries {
+        if boundary.byte_start < cursor || boundary.byte_end < boundary.byte_start {
+            return None;
+        }
+        normalized.push_str(&normalize_text(content.get(cursor..boundary.byte_start)?));
+
+        let byte_start = normalized.len();
+        normalized.push_str(&normalize_text(content.get(boundary.byte_start..boundary.byte_end)?));
+        remapped.push(PageBoundary {
+            byte_start,
+            byte_end: normalized.len(),
+            page_number: boundary.page_number,
+        });
+        cursor = boundary.byte_end;
+    }
+    normalized.push_str(&normalize_text(content.get(cursor..)?));
+
+    Some((normalized, remapped))
+}
+
+fn is_code_fence(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+/// Treat Unicode space separators (NBSP, narrow NBSP, em space, ...) like a regular space.
+fn is_horizontal_space(c: char) -> bool {
+    c == ' ' || c == '\t' || (c.is_whitespace() && !matches!(c, '\n' | '\r' | '\u{b}' | '\u{c}'))
+}
+
+fn normalize_line(line: &str) -> String {
+    let mut normalized = String::with_capacity(line.len());
+    let mut chars = line.chars().filter(|&c| c != SOFT_HYPHEN).peekable();
+
+    while let Some(&c) = chars.peek() {
+        if !is_horizontal_space(c) {
+            break;
+        }
+        normalized.push(if c == '\t' { '\t' } else { ' ' });
+        chars.next();
+    }
+
+    let mut pending_space = false;
+    for c in chars {
+        if is_horizontal_space(c) {
+            pending_space = true;
+            continue;
+        }
+        if pending_space {
+            normalized.push(' ');
+            pending_space = false;
+        }
+        normalized.push(c);
+    }
+
+    if normalized.trim().is_empty() {
+        normalized.clear();
+    }
+    normalized
+}
+
+/// Emit a run of blank lines, keeping at most one paragraph break.
+///
+/// Between lines a single empty line already forms `\n\n`; at the start or end of the
+/// text it takes two to produce the same break.
+fn push_blank_lines(lines: &mut Vec<String>, blank_run: usize, at_end: bool) {
+    let max = if at_end || lines.is_empty() { 2 } else { 1 };
+    lines.extend(std::iter::repeat_n(String::new(), blank_run.min(max)));
+}
+
+/// Length of the line without its trailing hyphen, if it ends in a word broken across lines.
+fn hyphenated_stem_len(line: &str) -> Option<usize> {
+    let stem = line.strip_suffix('-')?;
+    stem.chars().next_back().filter(|c| c.is_alphabetic())?;
+    Some(stem.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_joins_soft_hyphenated_line_breaks() {
+        assert_eq!(normalize_text("a long word-\nbreak here"), "a long wordbreak here");
+        assert_eq!(normalize_text("trans-\n  formation"), "transformation");
+        assert_eq!(normalize_text("invis\u{ad}ible"), "invisible");
+    }
+
+    #[test]
+    fn test_keeps_hyphens_that_are_not_line_breaks() {
+        assert_eq!(normalize_text("well-known fact"), "well-known fact");
+        assert_eq!(normalize_text("ends with a dash -\nnext"), "ends with a dash -\nnext");
+        assert_eq!(normalize_text("Covid-\n19"), "Covid-\n19");
+        assert_eq!(normalize_text("paragraph-\n\ncontinues"), "paragraph-\n\ncontinues");
+    }
+
+    #[test]
+    fn test_collapses_nbsp_and_repeated_spaces() {
+        assert_eq!(
+            normalize_text("one\u{a0}\u{a0}two   three\u{202f}four\t\tfive  "),
+            "one two three four five"
+        );
+    }
+
+    #[test]
+    fn test_preserves_paragraph_structure() {
+        let text = "First\u{a0}paragraph line one\r\nline two\r\n\r\nSecond paragraph\n\n\n\n\nThird";
+        assert_eq!(
+            normalize_text(text),
+            "First paragraph line one\nline two\n\nSecond paragraph\n\nThird"
+        );
+    }
+
+    #[test]
+    fn test_preserves_indentation_and_code_blocks() {
+        let text = "Example:\n\n```\nfn main() {\n    let  x =  1;\n\n\n}\n```\n    indented  line";
+        assert_eq!(
+            normalize_text(text),
+            "Example:\n\n```\nfn main() {\n    let  x =  1;\n\n\n}\n```\n    indented line"
+        );
+    }
+
+    #[test]
+    fn test_remaps_page_boundaries() {
+        let content = "Page\u{a0}\u{a0}one\n\n\n\nPage two-\nthree";
+        let split = content.find("Page two").unwrap();
+        let boundaries = vec![
+            PageBoundary {
+                byte_start: 0,
+                byte_end: split,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: split,
+                byte_end: content.len(),
+                page_number: 2,
+            },
+        ];
+
+        let (normalized, remapped) = normalize_text_with_boundaries(content, &boundaries).unwrap();
+        assert_eq!(normalized, "Page one\n\nPage twothree");
+        assert_eq!(
+            &normalized[remapped[0].byte_start..remapped[0].byte_end],
+            "Page one\n\n"
+        );
+        assert_eq!(
+            &normalized[remapped[1].byte_start..remapped[1].byte_end],
+            "Page twothree"
+        );
+
+        let overlapping = vec![boundaries[1].clone(), boundaries[0].clone()];
+        assert!(normalize_text_with_boundaries(content, &overlapping).is_none());
+    }
+}
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 
 // Import Metadata and Table from parent module
 use super::Table;
+use super::extraction::{ExtractedLink, LinkKind};
 use super::metadata::Metadata;
 
 /// Comprehensive Djot document structure with semantic preservation.
@@ -50,6 +51,28 @@ pub struct DjotContent {
     pub attributes: Vec<(String, Attributes)>,
 }
 
+impl DjotContent {
+    /// Collect links and image references as format-independent [`ExtractedLink`]s.
+    ///
+    /// Hyperlinks come first, followed by images, each in document order.
+    pub fn extracted_links(&self) -> Vec<ExtractedLink> {
+        let links = self.links.iter().map(|link| ExtractedLink {
+            url: link.url.clone(),
+            text: link.text.clone(),
+            title: link.title.clone(),
+            kind: LinkKind::Hyperlink,
+        });
+        let images = self.images.iter().map(|image| ExtractedLink {
+            url: image.src.clone(),
+            text: image.alt.clone(),
+            title: image.title.clone(),
+            kind: LinkKind::Image,
+        });
+
+        links.chain(images).collect()
+    }
+}
+
 /// Block-level element in a Djot document.
 ///
 /// Represents structural elements like headings, paragraphs, lists, code blocks, etc.
@@ -187,6 +210,10 @@ pub struct DjotImage {
     /// Element attributes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<Attributes>,
+
+    /// Figure caption associated with the image
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub caption: Option<String>,
 }
 
 /// Link element in Djot.
@@ -13,7 +13,7 @@ use super::tables::Table;
 /// General extraction result used by the core extraction API.
 ///
 /// This is the main result type returned by all extraction functions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
 #[cfg_attr(feature = "api", schema(no_recursion))]
 pub struct ExtractionResult {
@@ -41,6 +41,29 @@ pub struct ExtractionResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub images: Option<Vec<ExtractedImage>>,
 
+    /// Hyperlinks and image references found in the document.
+    ///
+    /// Populated by formats that carry link markup (currently Djot). Image references
+    /// point at the image source; decoded image data is reported in `images` instead.
+    /// Empty for formats that do not extract links.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub links: Vec<ExtractedLink>,
+
+    /// Non-fatal issues encountered during extraction.
+    ///
+    /// Extraction can succeed while still losing fidelity, e.g. when a PDF references
+    /// fonts that are not embedded or a sheet fails to parse. Each warning carries a
+    /// machine-readable kind alongside a human-readable message.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub warnings: Vec<ExtractionWarning>,
+
+    /// Whether extraction stopped early and the result holds only part of the document.
+    ///
+    /// Set when `ExtractionConfig::allow_partial` is enabled and the document is damaged
+    /// past some point; a `PartialContent` warning describes where extraction stopped.
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub partial: bool,
+
     /// Per-page content when page extraction is enabled.
     ///
     /// When page extraction is configured, the document is split into per-page content
@@ -56,6 +79,14 @@ pub struct ExtractionResult {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub elements: Option<Vec<Element>>,
 
+    /// Positioned words when layout extraction is enabled.
+    ///
+    /// When `include_layout` is set in `ExtractionConfig`, this field contains every
+    /// word of the document text with its page number and bounding box. Currently
+    /// populated for PDF documents only.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub layout: Option<Vec<PositionedText>>,
+
     /// Rich Djot content structure (when extracting Djot documents).
     ///
     /// When extracting Djot documents with structured extraction enabled,
@@ -189,6 +220,79 @@ pub struct ExtractedImage {
     pub ocr_result: Option<Box<ExtractionResult>>,
 }
 
+/// Kind of reference an [`ExtractedLink`] points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum LinkKind {
+    /// Hyperlink to another resource
+    Hyperlink,
+    /// Embedded image reference (the image data itself is not loaded)
+    Image,
+}
+
+/// A hyperlink or image reference extracted from a document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct ExtractedLink {
+    /// Target URL or path
+    pub url: String,
+
+    /// Link text, or alternative text for images
+    pub text: String,
+
+    /// Optional title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Whether this is a hyperlink or an image reference
+    pub kind: LinkKind,
+}
+
+/// Category of an [`ExtractionWarning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractionWarningKind {
+    /// A font used by the document is neither embedded nor a standard font
+    MissingFont,
+    /// Part of the document could not be parsed and was skipped or returned raw
+    PartialContent,
+    /// Page markers were requested but could not be inserted
+    MissingPageMarkers,
+    /// Pages contain no extractable text and OCR is disabled
+    OcrRecommended,
+    /// OCR was configured but its backend is unavailable, so it was skipped (`ExtractionConfig::ocr_optional`)
+    OcrUnavailable,
+    /// Pages beyond `ExtractionConfig::max_pages` were not extracted
+    Truncated,
+    /// A post-processing validator reported a non-fatal issue
+    Validation,
+    /// The input encoding was guessed, decoded lossily, or a requested override was not applied
+    Encoding,
+}
+
+/// A non-fatal issue encountered while extracting a document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct ExtractionWarning {
+    /// Machine-readable category of the warning
+    pub kind: ExtractionWarningKind,
+
+    /// Human-readable description
+    pub message: String,
+}
+
+impl ExtractionWarning {
+    /// Create a warning of the given kind.
+    pub fn new(kind: ExtractionWarningKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
 // ============================================================================
 // Element-based Output Format Types (Unstructured-compatible)
 // ============================================================================
@@ -287,6 +391,20 @@ pub struct BoundingBox {
     pub y1: f64,
 }
 
+/// A word of document text together with its position on the page.
+///
+/// Coordinates are in PDF points with the origin at the bottom-left corner of the page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct PositionedText {
+    /// The word text
+    pub text: String,
+    /// Page number (1-indexed)
+    pub page_number: usize,
+    /// Bounding box of the word
+    pub bbox: BoundingBox,
+}
+
 /// Metadata for a semantic element.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
@@ -19,6 +19,9 @@ pub struct ExcelWorkbook {
     pub sheets: Vec<ExcelSheet>,
     /// Workbook-level metadata (author, creation date, etc.)
     pub metadata: HashMap<String, String>,
+    /// Names of sheets that could not be read and are missing from `sheets`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_sheets: Vec<String>,
 }
 
 /// Single Excel worksheet.
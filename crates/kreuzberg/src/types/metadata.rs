@@ -112,6 +112,14 @@ pub struct Metadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pages: Option<PageStructure>,
 
+    /// Document outline (bookmarks / table of contents) in reading order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outline: Option<Vec<OutlineEntry>>,
+
+    /// Whether the document's text is digital, scanned (image-only), or a mix of both
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_kind: Option<DocumentKind>,
+
     /// Format-specific metadata (discriminated union)
     ///
     /// Contains detailed metadata specific to the document format.
@@ -154,6 +162,34 @@ pub struct Metadata {
     pub additional: AHashMap<Cow<'static, str>, serde_json::Value>,
 }
 
+/// An entry of a document outline (bookmark / table of contents entry).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct OutlineEntry {
+    /// Entry title as shown in the outline
+    pub title: String,
+
+    /// Nesting level, starting at 1 for top-level entries
+    pub level: usize,
+
+    /// Target page number (1-indexed), if the entry points to a page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+}
+
+/// How a document's text is stored.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum DocumentKind {
+    /// Text is embedded in the document and can be extracted directly
+    Digital,
+    /// Pages are images with no extractable text; OCR is needed to read them
+    Scanned,
+    /// Some pages have extractable text and others are images only
+    Mixed,
+}
+
 /// Excel/spreadsheet metadata.
 ///
 /// Contains information about sheets in Excel, LibreOffice Calc, and other
@@ -24,6 +24,54 @@ mod tests {
     use std::borrow::Cow;
     use std::sync::Arc;
 
+    fn ragged_table() -> Table {
+        Table {
+            cells: vec![
+                vec!["Name".to_string(), "Qty".to_string(), "Note".to_string()],
+                vec!["Apple".to_string(), "3".to_string()],
+                vec!["Pear".to_string()],
+            ],
+            markdown: String::new(),
+            page_number: 2,
+            caption: Some("Fruit".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_table_ragged_row_and_column_access() {
+        let table = ragged_table();
+
+        assert_eq!(table.dimensions(), (3, 3));
+        assert_eq!(table.row(1), Some(&["Apple".to_string(), "3".to_string()][..]));
+        assert_eq!(table.row(3), None);
+        assert_eq!(table.column(0), Some(vec!["Name", "Apple", "Pear"]));
+        assert_eq!(table.column(2), Some(vec!["Note", "", ""]));
+        assert_eq!(table.column(3), None);
+        assert_eq!(Table { cells: vec![], ..table }.dimensions(), (0, 0));
+    }
+
+    #[test]
+    fn test_table_transpose_round_trip() {
+        let table = ragged_table();
+
+        let transposed = table.transpose();
+        assert_eq!(transposed.dimensions(), (3, 3));
+        assert_eq!(transposed.cells[0], vec!["Name", "Apple", "Pear"]);
+        assert_eq!(transposed.cells[2], vec!["Note", "", ""]);
+        assert_eq!(transposed.page_number, 2);
+        assert_eq!(transposed.caption.as_deref(), Some("Fruit"));
+
+        let round_trip = transposed.transpose();
+        assert_eq!(
+            round_trip.cells,
+            vec![
+                vec!["Name", "Qty", "Note"],
+                vec!["Apple", "3", ""],
+                vec!["Pear", "", ""],
+            ]
+        );
+    }
+
     #[test]
     fn test_metadata_serialization_with_format() {
         let mut metadata = Metadata {
@@ -64,6 +112,7 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |\n|---|---|\n".to_string(),
             page_number: 1,
+            caption: None,
         };
 
         let json = serde_json::to_value(&table).unwrap();
@@ -82,6 +131,7 @@ mod tests {
             ],
             markdown: "| X | Y |\n|---|---|\n| 1 | 2 |\n".to_string(),
             page_number: 5,
+            caption: None,
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -98,6 +148,7 @@ mod tests {
             cells: vec![vec!["shared".to_string()]],
             markdown: "| shared |".to_string(),
             page_number: 1,
+            caption: None,
         });
 
         let tables_before = [Arc::clone(&shared_table), Arc::clone(&shared_table)].to_vec();
@@ -113,11 +164,13 @@ mod tests {
                 cells: vec![vec!["A".to_string()]],
                 markdown: "| A |".to_string(),
                 page_number: 1,
+                caption: None,
             },
             Table {
                 cells: vec![vec!["B".to_string()]],
                 markdown: "| B |".to_string(),
                 page_number: 2,
+                caption: None,
             },
         ];
 
@@ -140,11 +193,13 @@ mod tests {
                     cells: vec![vec!["Table1".to_string()]],
                     markdown: "| Table1 |".to_string(),
                     page_number: 3,
+                    caption: None,
                 }),
                 Arc::new(Table {
                     cells: vec![vec!["Table2".to_string()]],
                     markdown: "| Table2 |".to_string(),
                     page_number: 3,
+                    caption: None,
                 }),
             ],
             images: Vec::new(),
@@ -215,6 +270,7 @@ mod tests {
             cells: vec![vec!["shared across pages".to_string()]],
             markdown: "| shared across pages |".to_string(),
             page_number: 0,
+            caption: None,
         });
 
         let page1 = PageContent {
@@ -268,12 +324,14 @@ mod tests {
             cells: vec![vec!["A".to_string()]],
             markdown: "| A |".to_string(),
             page_number: 1,
+            caption: None,
         };
 
         let table2 = Table {
             cells: vec![vec!["B".to_string()]],
             markdown: "| B |".to_string(),
             page_number: 2,
+            caption: None,
         };
 
         let json = serde_json::to_string(&vec![table1, table2]).unwrap();
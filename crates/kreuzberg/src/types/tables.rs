@@ -15,6 +15,9 @@ pub struct Table {
     pub markdown: String,
     /// Page number where the table was found (1-indexed)
     pub page_number: usize,
+    /// Table caption, when the source format provides one
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub caption: Option<String>,
 }
 
 /// Individual table cell with content and optional styling.
@@ -36,6 +39,83 @@ pub struct TableCell {
     pub is_header: bool,
 }
 
+impl Table {
+    /// Number of rows and columns, as `(rows, columns)`.
+    ///
+    /// The column count is the length of the widest row.
+    pub fn dimensions(&self) -> (usize, usize) {
+        let columns = self.cells.iter().map(Vec::len).max().unwrap_or(0);
+        (self.cells.len(), columns)
+    }
+
+    /// Cells of row `index` (0-indexed), or `None` when the table has fewer rows.
+    ///
+    /// Rows are returned as stored, so a ragged row can be shorter than the table width.
+    pub fn row(&self, index: usize) -> Option<&[String]> {
+        self.cells.get(index).map(Vec::as_slice)
+    }
+
+    /// Cells of column `index` (0-indexed), one per row, or `None` when no row is that wide.
+    ///
+    /// Rows too short to reach the column contribute an empty string.
+    pub fn column(&self, index: usize) -> Option<Vec<&str>> {
+        if index >= self.dimensions().1 {
+            return None;
+        }
+
+        Some(
+            self.cells
+                .iter()
+                .map(|row| row.get(index).map_or("", String::as_str))
+                .collect(),
+        )
+    }
+
+    /// Swap rows and columns.
+    ///
+    /// Ragged rows are padded with empty cells to the widest row first, so transposing
+    /// twice yields the padded table. The `markdown` of the result is empty because it
+    /// described the original layout; page number and caption are kept.
+    pub fn transpose(&self) -> Table {
+        let (rows, columns) = self.dimensions();
+        let cells = (0..columns)
+            .map(|column| {
+                (0..rows)
+                    .map(|row| self.cells[row].get(column).cloned().unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+
+        Table {
+            cells,
+            markdown: String::new(),
+            page_number: self.page_number,
+            caption: self.caption.clone(),
+        }
+    }
+
+    /// Render the table cells in the given format, with pipes escaped.
+    ///
+    /// Ragged rows are padded with empty cells to the widest row.
+    #[cfg(any(feature = "office", feature = "html", feature = "xml"))]
+    pub fn to_markdown(&self, format: crate::extraction::TableFormat) -> String {
+        self.to_markdown_with(&crate::extraction::TableMarkdownOptions {
+            format,
+            ..Default::default()
+        })
+    }
+
+    /// Render the table cells as a GitHub-Flavored Markdown table using the given options.
+    ///
+    /// The `markdown` field holds the rendering chosen by the extractor; this renders the
+    /// cells again, e.g. with [`CellEscaping::LineBreaks`](crate::extraction::CellEscaping)
+    /// so multi-line cells don't break the table.
+    #[cfg(any(feature = "office", feature = "html", feature = "xml"))]
+    pub fn to_markdown_with(&self, options: &crate::extraction::TableMarkdownOptions) -> String {
+        crate::extraction::cells_to_markdown_with(&self.cells, options)
+    }
+}
+
 fn default_span() -> usize {
     1
 }
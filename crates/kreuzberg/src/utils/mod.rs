@@ -28,4 +28,7 @@ pub use pool::{
 
 pub use pool_sizing::{PoolSizeHint, estimate_pool_size};
 
-pub use string_pool::{InternedString, intern_language_code, intern_mime_type};
+pub use string_pool::{
+    CacheStats, ClearedCaches, InternedString, cache_stats, clear_all_caches, clear_string_pools, intern_language_code,
+    intern_mime_type, interned_string_count,
+};
@@ -130,6 +130,36 @@ impl StringBufferPool {
         }
     }
 
+    /// Acquire a string buffer with at least `hint` bytes of capacity.
+    ///
+    /// Prefers the smallest pooled buffer whose bucket can satisfy the hint, growing it
+    /// once if needed, and otherwise allocates a new buffer of the requested size. Use
+    /// this when the output size is roughly known up front to avoid repeated
+    /// reallocation while the buffer fills.
+    pub fn acquire_with_capacity(self: Arc<Self>, hint: usize) -> PooledString {
+        #[cfg(feature = "pool-metrics")]
+        self.acquire_count.fetch_add(1, Ordering::Relaxed);
+
+        let min_bucket = self.find_bucket(hint);
+        for &bucket in [1024, 4096, 16384, 65536]
+            .iter()
+            .filter(|&&bucket| bucket >= min_bucket)
+        {
+            if let Some(mut buffer) = self.try_acquire_from_bucket(bucket) {
+                #[cfg(feature = "pool-metrics")]
+                self.reuse_count.fetch_add(1, Ordering::Relaxed);
+                // Buffers in the hint's own bucket may still be slightly too small
+                buffer.reserve(hint);
+                return PooledString { buffer, pool: self };
+            }
+        }
+
+        PooledString {
+            buffer: String::with_capacity(hint.max(self.config.initial_capacity)),
+            pool: self,
+        }
+    }
+
     /// Return a buffer to the pool for reuse.
     pub fn release(&self, mut buffer: String) {
         if buffer.capacity() > self.config.max_capacity_before_discard {
@@ -150,12 +180,26 @@ impl StringBufferPool {
         }
     }
 
+    /// Drop all pooled buffers.
+    ///
+    /// Buffers currently checked out are returned to the (now empty) pool when dropped.
+    pub fn clear(&self) {
+        self.pool.clear();
+    }
+
     /// Get the current pool size across all buckets.
-    #[allow(dead_code)]
     pub fn size(&self) -> usize {
         self.pool.iter().map(|entry| entry.value().len()).sum()
     }
 
+    /// Get the total capacity in bytes of all idle buffers held by the pool.
+    pub fn pooled_bytes(&self) -> usize {
+        self.pool
+            .iter()
+            .map(|entry| entry.value().iter().map(String::capacity).sum::<usize>())
+            .sum()
+    }
+
     /// Get buffer reuse metrics (only available with `pool-metrics` feature).
     #[cfg(feature = "pool-metrics")]
     pub fn metrics(&self) -> StringBufferPoolMetrics {
@@ -259,6 +303,22 @@ pub fn acquire_string_buffer() -> PooledString {
     Arc::clone(&*STRING_BUFFER_POOL).acquire()
 }
 
+/// Acquire a string buffer with at least `hint` bytes of capacity from the global pool.
+///
+/// See [`StringBufferPool::acquire_with_capacity`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut buffer = acquire_string_buffer_with_capacity(page_count * 2048);
+/// for page in pages {
+///     buffer.push_str(page);
+/// }
+/// ```
+pub fn acquire_string_buffer_with_capacity(hint: usize) -> PooledString {
+    Arc::clone(&*STRING_BUFFER_POOL).acquire_with_capacity(hint)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -304,6 +364,56 @@ mod tests {
         assert!(buffer2.capacity() >= 4096);
     }
 
+    /// Number of times the buffer grows while a 40-page synthetic document is appended.
+    fn count_reallocations(mut buffer: PooledString) -> usize {
+        let page = "x".repeat(1000);
+        let mut reallocations = 0;
+        let mut capacity = buffer.capacity();
+        for _ in 0..40 {
+            buffer.push_str(&page);
+            if buffer.capacity() != capacity {
+                reallocations += 1;
+                capacity = buffer.capacity();
+            }
+        }
+        reallocations
+    }
+
+    #[test]
+    fn test_acquire_with_capacity_avoids_reallocation() {
+        let pool = Arc::new(StringBufferPool::new(PoolConfig::default()));
+
+        let without_hint = count_reallocations(pool.clone().acquire());
+        let with_hint = count_reallocations(pool.clone().acquire_with_capacity(40 * 1000));
+
+        assert!(without_hint > 0);
+        assert_eq!(with_hint, 0);
+    }
+
+    #[test]
+    fn test_acquire_with_capacity_prefers_large_pooled_buffer() {
+        let pool = Arc::new(StringBufferPool::new(PoolConfig::default()));
+        let small = pool.clone().acquire();
+        let mut large = pool.clone().acquire();
+        large.reserve(30_000);
+        drop(small);
+        drop(large);
+        assert_eq!(pool.size(), 2);
+
+        let buffer = pool.clone().acquire_with_capacity(20_000);
+        assert!(buffer.capacity() >= 20_000);
+        assert_eq!(pool.size(), 1, "the large pooled buffer should be reused");
+    }
+
+    #[test]
+    fn test_acquire_with_capacity_grows_small_pooled_buffer() {
+        let pool = Arc::new(StringBufferPool::new(PoolConfig::default()));
+        drop(pool.clone().acquire());
+
+        let buffer = pool.clone().acquire_with_capacity(3000);
+        assert!(buffer.capacity() >= 3000);
+    }
+
     #[test]
     fn test_pooled_string_deref() {
         let mut buffer = acquire_string_buffer();
@@ -50,6 +50,19 @@ impl LanguageStringPool {
             .compare_exchange(false, true, Ordering::Release, Ordering::Relaxed);
     }
 
+    /// Remove all interned strings, including pre-interned ones.
+    ///
+    /// Pre-interned language codes are restored on the next `get_or_intern()` call.
+    pub(super) fn clear(&self) {
+        self.pool.clear();
+        self.initialized.store(false, Ordering::Release);
+    }
+
+    /// Number of strings currently interned.
+    pub(super) fn len(&self) -> usize {
+        self.pool.len()
+    }
+
     /// Get or intern a language code string.
     /// Ensures pre-interned language codes are initialized on first call.
     pub(super) fn get_or_intern(&self, lang_code: &str) -> Arc<String> {
@@ -130,6 +130,19 @@ impl MimeStringPool {
             .compare_exchange(false, true, Ordering::Release, Ordering::Relaxed);
     }
 
+    /// Remove all interned strings, including pre-interned ones.
+    ///
+    /// Pre-interned MIME types are restored on the next `get_or_intern()` call.
+    pub(super) fn clear(&self) {
+        self.pool.clear();
+        self.initialized.store(false, Ordering::Release);
+    }
+
+    /// Number of strings currently interned.
+    pub(super) fn len(&self) -> usize {
+        self.pool.len()
+    }
+
     /// Get or intern a MIME type string.
     /// Ensures pre-interned MIME types are initialized on first call.
     pub(super) fn get_or_intern(&self, mime_type: &str) -> Arc<String> {
@@ -186,6 +199,19 @@ mod tests {
         assert!(Arc::ptr_eq(&mime1.0, &mime2.0));
     }
 
+    #[test]
+    fn test_clear_restores_preinterned_types() {
+        let pool = MimeStringPool::new();
+        pool.get_or_intern("application/x-custom");
+        assert!(pool.len() > 1);
+
+        pool.clear();
+        assert_eq!(pool.len(), 0);
+
+        assert_eq!(pool.get_or_intern("application/pdf").as_str(), "application/pdf");
+        assert!(pool.len() > 1, "pre-interned types should be restored after clear");
+    }
+
     #[test]
     fn test_preinterned_mime_types() {
         let pdf = intern_mime_type("application/pdf");
@@ -32,10 +32,108 @@ mod language_pool;
 mod mime_pool;
 
 // Re-export public types and functions
-pub use buffer_pool::{PoolConfig, PooledString, STRING_BUFFER_POOL, StringBufferPool, acquire_string_buffer};
+pub use buffer_pool::{
+    PoolConfig, PooledString, STRING_BUFFER_POOL, StringBufferPool, acquire_string_buffer,
+    acquire_string_buffer_with_capacity,
+};
 pub use interned::InternedString;
 pub use language_pool::intern_language_code;
 pub use mime_pool::intern_mime_type;
 
 #[cfg(feature = "pool-metrics")]
 pub use buffer_pool::StringBufferPoolMetrics;
+
+/// Number of entries released by [`clear_all_caches`], per cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ClearedCaches {
+    /// Strings removed from the MIME type intern pool
+    pub mime_types: usize,
+    /// Strings removed from the language code intern pool
+    pub language_codes: usize,
+    /// Idle buffers dropped from the string buffer pool
+    pub string_buffers: usize,
+}
+
+impl ClearedCaches {
+    /// Total number of entries released across all caches.
+    pub fn total(&self) -> usize {
+        self.mime_types + self.language_codes + self.string_buffers
+    }
+}
+
+/// Snapshot of the process-level string caches, returned by [`cache_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CacheStats {
+    /// Strings held by the MIME type intern pool
+    pub mime_entries: usize,
+    /// Strings held by the language code intern pool
+    pub language_entries: usize,
+    /// Total capacity in bytes of idle buffers in the string buffer pool
+    pub buffer_pool_bytes: usize,
+    /// Buffer acquisitions served from the pool
+    #[cfg(feature = "pool-metrics")]
+    pub buffer_pool_hits: usize,
+    /// Buffer acquisitions that had to allocate a new buffer
+    #[cfg(feature = "pool-metrics")]
+    pub buffer_pool_misses: usize,
+}
+
+/// Report the current size of the process-level string caches.
+///
+/// Useful for monitoring memory held by long-running processes and for deciding
+/// when to call [`clear_all_caches`]. Buffer pool hit and miss counters are only
+/// tracked with the `pool-metrics` feature and are cumulative since process start.
+pub fn cache_stats() -> CacheStats {
+    #[cfg(feature = "pool-metrics")]
+    let metrics = STRING_BUFFER_POOL.metrics();
+
+    CacheStats {
+        mime_entries: mime_pool::MIME_POOL.len(),
+        language_entries: language_pool::LANGUAGE_POOL.len(),
+        buffer_pool_bytes: STRING_BUFFER_POOL.pooled_bytes(),
+        #[cfg(feature = "pool-metrics")]
+        buffer_pool_hits: metrics.total_reuses,
+        #[cfg(feature = "pool-metrics")]
+        buffer_pool_misses: metrics.total_acquires.saturating_sub(metrics.total_reuses),
+    }
+}
+
+/// Reset all process-level caches and report how many entries were released.
+///
+/// Clears the MIME type and language code intern pools and drops idle buffers from
+/// the string buffer pool. Long-running servers can call this between distinct
+/// workloads to reclaim memory, and benchmarks can use it to start from a cold state.
+///
+/// Strings and buffers already handed out stay valid; they are simply no longer
+/// shared with strings interned afterwards. Clearing during concurrent extraction is
+/// safe but defeats pooling until the caches warm up again.
+///
+/// The PDF font cache is intentionally not cleared: its entries load font data
+/// lazily through a `OnceLock` that may already be registered with the process-wide
+/// Pdfium instance. Use `kreuzberg::pdf::clear_font_cache` explicitly when a cold font
+/// cache is required.
+pub fn clear_all_caches() -> ClearedCaches {
+    let cleared = ClearedCaches {
+        mime_types: mime_pool::MIME_POOL.len(),
+        language_codes: language_pool::LANGUAGE_POOL.len(),
+        string_buffers: STRING_BUFFER_POOL.size(),
+    };
+
+    mime_pool::MIME_POOL.clear();
+    language_pool::LANGUAGE_POOL.clear();
+    STRING_BUFFER_POOL.clear();
+
+    cleared
+}
+
+/// Clear the MIME type and language code intern pools and drop pooled string buffers.
+///
+/// Equivalent to [`clear_all_caches`] without the summary.
+pub fn clear_string_pools() {
+    clear_all_caches();
+}
+
+/// Number of strings currently held by the MIME type and language code intern pools.
+pub fn interned_string_count() -> usize {
+    mime_pool::MIME_POOL.len() + language_pool::LANGUAGE_POOL.len()
+}
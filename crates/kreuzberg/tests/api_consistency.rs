@@ -251,6 +251,21 @@ fn test_extraction_config_no_unknown_fields_in_default() {
         "max_concurrent_extractions",
         "result_format",
         "output_format",
+        "detect_image_tables",
+        "encoding_override",
+        "force_single_threaded",
+        "max_input_bytes",
+        "detect_language",
+        "extract_form_fields",
+        "normalize_whitespace",
+        "csv_delimiter",
+        "strip_running_headers",
+        "include_layout",
+        "include_speaker_notes",
+        "max_pages",
+        "allow_partial",
+        "ocr_embedded_images",
+        "ocr_optional",
     ];
 
     for key in obj.keys() {
@@ -279,6 +294,12 @@ fn test_extraction_config_needs_image_processing() {
         language: "eng".to_string(),
         tesseract_config: None,
         output_format: None,
+        dpi: None,
+        psm: None,
+        oem: None,
+        binarization: None,
+        ocr_pages: None,
+        language_fallback: None,
     });
     assert!(
         config.needs_image_processing(),
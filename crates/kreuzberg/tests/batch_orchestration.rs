@@ -260,6 +260,12 @@ fn test_ocr_multipage_efficiency() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         use_cache: true,
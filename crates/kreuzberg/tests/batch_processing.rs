@@ -304,7 +304,91 @@ async fn test_batch_extract_large_batch() {
     }
 }
 
-/// Test sync variant with bytes.
+/// Test that single-threaded batch extraction matches the parallel path.
+#[tokio::test]
+async fn test_batch_extract_bytes_force_single_threaded_matches_parallel() {
+    let contents: Vec<(Vec<u8>, String)> = vec![
+        (b"first document".to_vec(), "text/plain".to_string()),
+        (b"# Second document".to_vec(), "text/markdown".to_string()),
+        (b"third document".to_vec(), "application/x-unknown-format".to_string()),
+        (b"fourth document".to_vec(), "text/plain".to_string()),
+    ];
+
+    let parallel_config = ExtractionConfig::default();
+    let sequential_config = ExtractionConfig {
+        force_single_threaded: true,
+        ..Default::default()
+    };
+
+    let parallel = batch_extract_bytes(contents.clone(), &parallel_config)
+        .await
+        .expect("Parallel batch should succeed");
+    let sequential = batch_extract_bytes(contents, &sequential_config)
+        .await
+        .expect("Single-threaded batch should succeed");
+
+    assert_eq!(sequential.len(), parallel.len());
+    for (seq, par) in sequential.iter().zip(&parallel) {
+        assert_eq!(seq.content, par.content);
+        assert_eq!(seq.mime_type, par.mime_type);
+        assert_eq!(seq.metadata.error.is_some(), par.metadata.error.is_some());
+    }
+    assert!(
+        sequential[2].metadata.error.is_some(),
+        "Unsupported format should yield an error result"
+    );
+}
+
+/// Inputs whose larger items come first, so later items tend to finish earlier.
+fn out_of_order_contents() -> Vec<(Vec<u8>, String)> {
+    (0..32)
+        .map(|i| {
+            let text = format!("document {} ", i).repeat((32 - i) * 500);
+            (text.into_bytes(), "text/plain".to_string())
+        })
+        .collect()
+}
+
+/// Test that results map positionally to inputs even when items finish out of order.
+#[tokio::test]
+async fn test_batch_extract_bytes_preserves_input_order() {
+    let contents = out_of_order_contents();
+    let config = ExtractionConfig {
+        max_concurrent_extractions: Some(8),
+        ..Default::default()
+    };
+
+    let results = batch_extract_bytes(contents.clone(), &config)
+        .await
+        .expect("Batch should succeed");
+
+    assert_eq!(results.len(), contents.len());
+    let order_preserved = results
+        .iter()
+        .enumerate()
+        .all(|(i, result)| result.content.starts_with(&format!("document {} ", i)));
+    assert!(order_preserved, "results[i] must correspond to input i");
+}
+
+/// Test that the sync variant also maps results positionally to inputs.
+#[test]
+fn test_batch_extract_bytes_sync_preserves_input_order() {
+    let contents = out_of_order_contents();
+    let config = ExtractionConfig {
+        max_concurrent_extractions: Some(8),
+        ..Default::default()
+    };
+
+    let results = batch_extract_bytes_sync(contents.clone(), &config).expect("Sync batch should succeed");
+
+    assert_eq!(results.len(), contents.len());
+    let order_preserved = results
+        .iter()
+        .enumerate()
+        .all(|(i, result)| result.content.starts_with(&format!("document {} ", i)));
+    assert!(order_preserved, "sync results must follow input order");
+}
+
 #[test]
 fn test_batch_extract_bytes_sync_variant() {
     let config = ExtractionConfig::default();
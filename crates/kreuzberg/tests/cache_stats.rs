@@ -0,0 +1,44 @@
+//! Tests for `cache_stats`.
+//!
+//! The caches are process-wide, so this lives in its own test binary to keep
+//! concurrently running tests from changing the counts between snapshots.
+
+use kreuzberg::utils::string_pool::acquire_string_buffer;
+use kreuzberg::utils::{intern_language_code, intern_mime_type};
+use kreuzberg::{cache_stats, clear_all_caches};
+
+#[test]
+fn test_cache_stats_track_interning_and_buffers() {
+    clear_all_caches();
+    let empty = cache_stats();
+    assert_eq!(empty.mime_entries, 0);
+    assert_eq!(empty.language_entries, 0);
+    assert_eq!(empty.buffer_pool_bytes, 0);
+
+    let _mime = intern_mime_type("application/x-kreuzberg-stats-test");
+    let _language = intern_language_code("x-kreuzberg-stats");
+    let after_intern = cache_stats();
+    assert!(after_intern.mime_entries > empty.mime_entries);
+    assert!(after_intern.language_entries > empty.language_entries);
+
+    drop(acquire_string_buffer());
+    let after_buffer = cache_stats();
+    assert!(
+        after_buffer.buffer_pool_bytes > 0,
+        "the released buffer should be pooled"
+    );
+
+    #[cfg(feature = "pool-metrics")]
+    {
+        drop(acquire_string_buffer());
+        let after_reuse = cache_stats();
+        assert_eq!(after_reuse.buffer_pool_hits, after_buffer.buffer_pool_hits + 1);
+        assert_eq!(after_reuse.buffer_pool_misses, after_buffer.buffer_pool_misses);
+    }
+
+    clear_all_caches();
+    let cleared = cache_stats();
+    assert_eq!(cleared.mime_entries, 0);
+    assert_eq!(cleared.language_entries, 0);
+    assert_eq!(cleared.buffer_pool_bytes, 0);
+}
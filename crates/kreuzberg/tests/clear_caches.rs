@@ -0,0 +1,41 @@
+//! Tests for `clear_all_caches`.
+//!
+//! The caches are process-wide, so this lives in its own test binary to keep
+//! concurrently running tests from interning strings between the count and the clear.
+
+use kreuzberg::utils::string_pool::{STRING_BUFFER_POOL, acquire_string_buffer};
+use kreuzberg::utils::{intern_language_code, intern_mime_type, interned_string_count};
+use kreuzberg::{ClearedCaches, clear_all_caches};
+
+#[test]
+fn test_clear_all_caches_reports_freed_counts() {
+    clear_all_caches();
+
+    let pdf = intern_mime_type("application/pdf");
+    let custom_mime = intern_mime_type("application/x-kreuzberg-clear-test");
+    let custom_language = intern_language_code("x-kreuzberg");
+    drop(acquire_string_buffer());
+
+    let interned_count = interned_string_count();
+    let buffer_count = STRING_BUFFER_POOL.size();
+    assert!(
+        interned_count > 2,
+        "pre-interned codes plus the custom strings should be pooled"
+    );
+    assert_eq!(buffer_count, 1, "the released buffer should return to the pool");
+
+    let cleared = clear_all_caches();
+    assert_eq!(cleared.mime_types + cleared.language_codes, interned_count);
+    assert_eq!(cleared.string_buffers, buffer_count);
+    assert_eq!(cleared.total(), interned_count + buffer_count);
+
+    assert_eq!(interned_string_count(), 0);
+    assert_eq!(STRING_BUFFER_POOL.size(), 0);
+    assert_eq!(clear_all_caches(), ClearedCaches::default());
+
+    // Handed-out strings stay valid but are no longer shared with new interns
+    assert_eq!(pdf.as_str(), "application/pdf");
+    assert_eq!(custom_language.as_str(), "x-kreuzberg");
+    let reinterned = intern_mime_type("application/x-kreuzberg-clear-test");
+    assert_ne!(custom_mime.as_str().as_ptr(), reinterned.as_str().as_ptr());
+}
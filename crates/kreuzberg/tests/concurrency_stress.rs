@@ -197,6 +197,12 @@ async fn test_concurrent_ocr_processing() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -267,6 +273,12 @@ fn test_concurrent_ocr_cache_stress() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -385,8 +397,12 @@ async fn test_concurrent_pipeline_processing() {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
                 pages: None,
                 elements: None,
+                layout: None,
                 djot_content: None,
             };
 
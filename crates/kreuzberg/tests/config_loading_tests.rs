@@ -18,7 +18,6 @@ fn test_from_file_toml_succeeds() {
 
     let toml_content = r#"
 [ocr]
-enabled = true
 backend = "tesseract"
 
 [chunking]
@@ -48,7 +47,6 @@ fn test_from_file_yaml_succeeds() {
 
     let yaml_content = r#"
 ocr:
-  enabled: true
   backend: tesseract
 chunking:
   max_characters: 1000
@@ -78,7 +76,6 @@ fn test_from_file_json_succeeds() {
     let json_content = r#"
 {
   "ocr": {
-    "enabled": true,
     "backend": "tesseract"
   },
   "chunking": {
@@ -110,7 +107,7 @@ fn test_from_file_yml_extension_succeeds() {
 
     let yml_content = r#"
 ocr:
-  enabled: true
+  backend: tesseract
 "#;
 
     fs::write(&config_path, yml_content).expect("Operation failed");
@@ -227,7 +224,7 @@ fn test_discover_finds_config_in_current_dir() {
 
     let toml_content = r#"
 [ocr]
-enabled = true
+backend = "tesseract"
 "#;
 
     fs::write(&config_path, toml_content).expect("Operation failed");
@@ -257,7 +254,7 @@ fn test_discover_finds_config_in_parent_dir() {
 
     let toml_content = r#"
 [ocr]
-enabled = true
+backend = "tesseract"
 "#;
 
     fs::write(&config_path, toml_content).expect("Operation failed");
@@ -306,8 +303,12 @@ fn test_discover_returns_none_when_not_found() {
 fn test_discover_file_name_preference() {
     let temp_dir = TempDir::new().expect("Operation failed");
 
-    fs::write(temp_dir.path().join("kreuzberg.toml"), "[ocr]\nenabled = true").expect("Operation failed");
-    fs::write(temp_dir.path().join(".kreuzberg.toml"), "[ocr]\nenabled = false").expect("Operation failed");
+    fs::write(temp_dir.path().join("kreuzberg.toml"), "[ocr]\nbackend = \"tesseract\"").expect("Operation failed");
+    fs::write(
+        temp_dir.path().join(".kreuzberg.toml"),
+        "[ocr]\nbackend = \"paddleocr\"",
+    )
+    .expect("Operation failed");
 
     let original_dir = std::env::current_dir().expect("Operation failed");
     if std::env::set_current_dir(temp_dir.path()).is_err() {
@@ -332,7 +333,7 @@ fn test_discover_with_nested_directories() {
 
     let toml_content = r#"
 [ocr]
-enabled = true
+backend = "tesseract"
 "#;
 
     fs::write(&config_path, toml_content).expect("Operation failed");
@@ -368,7 +369,6 @@ fn test_from_file_comprehensive_config() {
 
     let toml_content = r#"
 [ocr]
-enabled = true
 backend = "tesseract"
 
 [chunking]
@@ -379,7 +379,7 @@ max_overlap = 200
 enabled = true
 
 [images]
-enabled = true
+extract_images = true
 
 [pdf_options]
 extract_images = true
@@ -423,3 +423,96 @@ max_overlap = -100
         assert!(chunking.max_characters > 0, "max_characters should be positive");
     }
 }
+
+/// Test unknown keys are rejected in every supported format.
+#[test]
+fn test_from_file_rejects_unknown_keys() {
+    let temp_dir = TempDir::new().expect("Operation failed");
+
+    let files = [
+        (
+            "unknown.toml",
+            "use_cache = false\n\n[ocr]\nbackend = \"tesseract\"\nlanguagee = \"deu\"\n",
+        ),
+        (
+            "unknown.yaml",
+            "use_cache: false\nocr:\n  backend: tesseract\n  languagee: deu\n",
+        ),
+        (
+            "unknown.json",
+            r#"{"use_cache": false, "ocr": {"backend": "tesseract", "languagee": "deu"}}"#,
+        ),
+    ];
+
+    for (name, content) in files {
+        let config_path = temp_dir.path().join(name);
+        fs::write(&config_path, content).expect("Operation failed");
+
+        match ExtractionConfig::from_file(&config_path) {
+            Err(KreuzbergError::Validation { message, .. }) => {
+                assert!(
+                    message.contains("ocr.languagee"),
+                    "{}: error should name the unknown key: {}",
+                    name,
+                    message
+                );
+            }
+            other => panic!("{}: expected unknown key error, got {:?}", name, other),
+        }
+    }
+}
+
+/// Test a `[server]` section may share the file with the extraction settings.
+#[test]
+fn test_from_file_allows_server_section() {
+    let temp_dir = TempDir::new().expect("Operation failed");
+    let config_path = temp_dir.path().join("kreuzberg.toml");
+
+    let toml_content = r#"
+use_cache = false
+
+[server]
+host = "0.0.0.0"
+port = 9000
+"#;
+
+    fs::write(&config_path, toml_content).expect("Operation failed");
+
+    let config = ExtractionConfig::from_file(&config_path).expect("Operation failed");
+    assert!(!config.use_cache);
+}
+
+/// Test configs are validated after parsing.
+#[test]
+fn test_from_file_validates_values() {
+    let temp_dir = TempDir::new().expect("Operation failed");
+    let config_path = temp_dir.path().join("config.json");
+
+    fs::write(&config_path, r#"{"max_concurrent_extractions": 0}"#).expect("Operation failed");
+
+    match ExtractionConfig::from_file(&config_path) {
+        Err(KreuzbergError::Validation { message, .. }) => {
+            assert!(
+                message.contains("max_concurrent_extractions"),
+                "error should name the invalid field: {}",
+                message
+            );
+        }
+        other => panic!("expected validation error, got {:?}", other),
+    }
+}
+
+/// Test discover_from() searches upward from the given directory.
+#[test]
+fn test_discover_from_start_dir() {
+    let temp_dir = TempDir::new().expect("Operation failed");
+    fs::write(temp_dir.path().join("kreuzberg.toml"), "use_cache = false").expect("Operation failed");
+
+    let nested = temp_dir.path().join("a").join("b");
+    fs::create_dir_all(&nested).expect("Operation failed");
+
+    let config = ExtractionConfig::discover_from(&nested)
+        .expect("Operation failed")
+        .expect("Should find config in ancestor directory");
+    assert!(!config.use_cache);
+}
@@ -460,6 +460,12 @@ async fn test_extraction_with_ocr_config() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
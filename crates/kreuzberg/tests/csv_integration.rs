@@ -32,7 +32,7 @@ async fn test_csv_basic_extraction() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Name"), "Should contain 'Name' header");
     assert!(extraction.content.contains("Age"), "Should contain 'Age' header");
@@ -70,7 +70,7 @@ async fn test_csv_with_headers() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Product"), "Should contain Product header");
     assert!(extraction.content.contains("Price"), "Should contain Price header");
@@ -119,13 +119,14 @@ async fn test_csv_custom_delimiter() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(!extraction.content.is_empty(), "Content should be extracted");
 
     assert!(extraction.content.contains("Alice"), "Should contain Alice");
     assert!(extraction.content.contains("30"), "Should contain age");
     assert!(extraction.content.contains("NYC"), "Should contain city");
+    assert_eq!(extraction.tables[0].cells[1], vec!["Alice", "30", "NYC"]);
 }
 
 /// Test TSV (Tab-Separated Values) file.
@@ -152,7 +153,7 @@ async fn test_tsv_file() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Name"), "Should contain Name header");
     assert!(extraction.content.contains("Age"), "Should contain Age header");
@@ -187,7 +188,10 @@ async fn test_csv_quoted_fields() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
+
+    assert_eq!(extraction.tables[0].cells[1][0], "Smith, John");
+    assert_eq!(extraction.tables[0].cells[2][1], "Product B, standard");
 
     assert!(extraction.content.contains("Smith"), "Should contain Smith");
     assert!(extraction.content.contains("John"), "Should contain John");
@@ -223,7 +227,7 @@ async fn test_csv_special_characters() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(!extraction.content.is_empty(), "Special characters should be handled");
 
@@ -261,7 +265,7 @@ async fn test_csv_large_file() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(!extraction.content.is_empty(), "Large CSV should be processed");
 
@@ -331,7 +335,7 @@ async fn test_csv_headers_only() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(
         extraction.content.contains("Name") || !extraction.content.is_empty(),
@@ -362,7 +366,7 @@ async fn test_csv_blank_lines() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Alice") || extraction.content.contains("Bob"));
 }
@@ -390,7 +394,7 @@ async fn test_csv_numeric_data() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Price"), "Should contain Price header");
     assert!(
@@ -0,0 +1,51 @@
+//! Integration tests for scanned vs digital PDF classification.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+use kreuzberg::types::{DocumentKind, ExtractionWarningKind};
+
+const TEXT_PDF: &str = "pdf/bookmarked_outline.pdf";
+const IMAGE_ONLY_PDF: &str = "pdf/non_searchable.pdf";
+
+#[test]
+fn test_text_pdf_is_digital() {
+    if skip_if_missing(TEXT_PDF) {
+        return;
+    }
+
+    let result = extract_file_sync(get_test_file_path(TEXT_PDF), None, &ExtractionConfig::default())
+        .expect("PDF should extract");
+
+    assert_eq!(result.metadata.document_kind, Some(DocumentKind::Digital));
+    assert!(
+        !result
+            .warnings
+            .iter()
+            .any(|warning| warning.kind == ExtractionWarningKind::OcrRecommended)
+    );
+}
+
+#[test]
+fn test_image_only_pdf_is_scanned() {
+    if skip_if_missing(IMAGE_ONLY_PDF) {
+        return;
+    }
+
+    let result = extract_file_sync(get_test_file_path(IMAGE_ONLY_PDF), None, &ExtractionConfig::default())
+        .expect("PDF should extract");
+
+    assert_eq!(result.metadata.document_kind, Some(DocumentKind::Scanned));
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|warning| warning.kind == ExtractionWarningKind::OcrRecommended),
+        "warnings: {:?}",
+        result.warnings
+    );
+}
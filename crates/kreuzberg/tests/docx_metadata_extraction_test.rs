@@ -251,3 +251,61 @@ async fn test_docx_keywords_extraction() {
     println!("✅ DOCX keywords extraction test passed!");
     println!("   Extracted keywords: {:?}", keywords);
 }
+
+#[tokio::test]
+async fn test_docx_keywords_fixture() {
+    let workspace_root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Operation failed")
+        .parent()
+        .expect("Operation failed");
+    let test_file = workspace_root.join("test_documents/docx/keywords.docx");
+
+    if !test_file.exists() {
+        println!("Skipping test: Test file not found at {:?}", test_file);
+        return;
+    }
+
+    let result = extract_file(&test_file, None, &ExtractionConfig::default())
+        .await
+        .expect("Should extract DOCX successfully");
+
+    // core.xml mixes separators: "invoice; finance, quarterly report"
+    assert_eq!(
+        result.metadata.keywords,
+        Some(vec![
+            "invoice".to_string(),
+            "finance".to_string(),
+            "quarterly report".to_string()
+        ]),
+        "Keywords should be split on both semicolons and commas"
+    );
+
+    let json = serde_json::to_value(&result.metadata).expect("Metadata should serialize");
+    assert_eq!(
+        json["keywords"],
+        serde_json::json!(["invoice", "finance", "quarterly report"])
+    );
+}
+
+#[tokio::test]
+async fn test_docx_empty_keywords_element() {
+    let workspace_root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Operation failed")
+        .parent()
+        .expect("Operation failed");
+    // word_sample.docx has an empty <cp:keywords/> element
+    let test_file = workspace_root.join("test_documents/docx/word_sample.docx");
+
+    if !test_file.exists() {
+        println!("Skipping test: Test file not found at {:?}", test_file);
+        return;
+    }
+
+    let result = extract_file(&test_file, None, &ExtractionConfig::default())
+        .await
+        .expect("Should extract DOCX successfully");
+
+    assert_eq!(result.metadata.keywords, None);
+}
@@ -0,0 +1,164 @@
+//! Integration tests for environment variable overrides of ExtractionConfig.
+//!
+//! Tests verify the precedence order: Env > File > Default
+
+use kreuzberg::KreuzbergError;
+use kreuzberg::core::config::ExtractionConfig;
+use std::fs;
+use tempfile::tempdir;
+
+const ENV_KEYS: &[&str] = &[
+    "KREUZBERG_OCR_LANGUAGE",
+    "KREUZBERG_OCR_BACKEND",
+    "KREUZBERG_CHUNKING_MAX_CHARS",
+    "KREUZBERG_CHUNKING_MAX_OVERLAP",
+    "KREUZBERG_CACHE_ENABLED",
+    "KREUZBERG_TOKEN_REDUCTION_MODE",
+    "KREUZBERG_MAX_CONCURRENT",
+    "KREUZBERG_OUTPUT_FORMAT",
+];
+
+// Helper function to clear the override variables, returning their original values
+#[allow(unsafe_code)]
+fn clear_env() -> Vec<(&'static str, Option<String>)> {
+    ENV_KEYS
+        .iter()
+        .map(|&key| {
+            let original = std::env::var(key).ok();
+            unsafe {
+                std::env::remove_var(key);
+            }
+            (key, original)
+        })
+        .collect()
+}
+
+// Helper function to set environment variables
+#[allow(unsafe_code)]
+fn set_env(key: &str, value: &str) {
+    unsafe {
+        std::env::set_var(key, value);
+    }
+}
+
+// Helper function to restore environment variables
+#[allow(unsafe_code)]
+fn restore_env(saved: Vec<(&'static str, Option<String>)>) {
+    unsafe {
+        for (key, value) in saved {
+            if let Some(v) = value {
+                std::env::set_var(key, v);
+            } else {
+                std::env::remove_var(key);
+            }
+        }
+    }
+}
+
+#[test]
+#[serial_test::serial]
+fn test_env_overrides_file_values() {
+    let saved = clear_env();
+
+    let dir = tempdir().expect("Operation failed");
+    let config_path = dir.path().join("kreuzberg.toml");
+    fs::write(
+        &config_path,
+        r#"
+use_cache = true
+max_concurrent_extractions = 2
+
+[ocr]
+backend = "tesseract"
+language = "eng"
+
+[chunking]
+max_chars = 2000
+max_overlap = 300
+"#,
+    )
+    .expect("Operation failed");
+
+    set_env("KREUZBERG_OCR_LANGUAGE", "deu");
+    set_env("KREUZBERG_MAX_CONCURRENT", "8");
+    set_env("KREUZBERG_CACHE_ENABLED", "false");
+
+    let mut config = ExtractionConfig::from_file(&config_path).expect("Operation failed");
+    let result = config.apply_env_overrides();
+
+    restore_env(saved);
+    result.expect("Overrides should apply");
+
+    let ocr = config.ocr.expect("Operation failed");
+    assert_eq!(ocr.language, "deu", "env should override file language");
+    assert_eq!(
+        ocr.backend, "tesseract",
+        "file value should remain without env override"
+    );
+    assert_eq!(config.max_concurrent_extractions, Some(8));
+    assert!(!config.use_cache);
+
+    let chunking = config.chunking.expect("Operation failed");
+    assert_eq!(chunking.max_characters, 2000);
+    assert_eq!(chunking.overlap, 300);
+}
+
+#[test]
+#[serial_test::serial]
+fn test_env_overrides_defaults_without_file() {
+    let saved = clear_env();
+
+    set_env("KREUZBERG_OCR_BACKEND", "paddleocr");
+    set_env("KREUZBERG_OUTPUT_FORMAT", "markdown");
+
+    let mut config = ExtractionConfig::default();
+    let result = config.apply_env_overrides();
+
+    restore_env(saved);
+    result.expect("Overrides should apply");
+
+    assert_eq!(config.ocr.expect("Operation failed").backend, "paddleocr");
+    assert_eq!(config.output_format.to_string(), "markdown");
+}
+
+#[test]
+#[serial_test::serial]
+fn test_env_override_accepts_auto_ocr_language() {
+    let saved = clear_env();
+
+    set_env("KREUZBERG_OCR_LANGUAGE", "auto");
+
+    let mut config = ExtractionConfig::default();
+    let result = config.apply_env_overrides();
+
+    restore_env(saved);
+    result.expect("auto should be accepted as an OCR language");
+
+    assert_eq!(config.ocr.expect("Operation failed").language, "auto");
+}
+
+#[test]
+#[serial_test::serial]
+fn test_env_override_with_bad_value_errors() {
+    for bad_value in ["eight", "0", "-1"] {
+        let saved = clear_env();
+        set_env("KREUZBERG_MAX_CONCURRENT", bad_value);
+
+        let mut config = ExtractionConfig::default();
+        let result = config.apply_env_overrides();
+
+        restore_env(saved);
+
+        match result {
+            Err(KreuzbergError::Validation { message, .. }) => {
+                assert!(
+                    message.contains("KREUZBERG_MAX_CONCURRENT"),
+                    "error should name the variable: {}",
+                    message
+                );
+            }
+            other => panic!("expected validation error for {:?}, got {:?}", bad_value, other),
+        }
+        assert_eq!(config.max_concurrent_extractions, None);
+    }
+}
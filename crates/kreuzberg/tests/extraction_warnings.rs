@@ -0,0 +1,54 @@
+//! Integration tests for structured extraction warnings.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+use kreuzberg::types::ExtractionWarningKind;
+
+const NON_EMBEDDED_FONT_PDF: &str = "pdf/non_embedded_font.pdf";
+
+#[test]
+fn test_missing_font_warning() {
+    if skip_if_missing(NON_EMBEDDED_FONT_PDF) {
+        return;
+    }
+
+    let result = extract_file_sync(
+        get_test_file_path(NON_EMBEDDED_FONT_PDF),
+        None,
+        &ExtractionConfig::default(),
+    )
+    .expect("PDF with a non-embedded font should still extract");
+
+    let missing_fonts: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|warning| warning.kind == ExtractionWarningKind::MissingFont)
+        .collect();
+    assert_eq!(missing_fonts.len(), 1, "warnings: {:?}", result.warnings);
+    assert!(missing_fonts[0].message.contains("not embedded"));
+    assert!(
+        !missing_fonts[0].message.contains("Helvetica"),
+        "standard fonts should not be reported"
+    );
+}
+
+#[test]
+fn test_standard_fonts_produce_no_warnings() {
+    if skip_if_missing("pdf/bookmarked_outline.pdf") {
+        return;
+    }
+
+    let result = extract_file_sync(
+        get_test_file_path("pdf/bookmarked_outline.pdf"),
+        None,
+        &ExtractionConfig::default(),
+    )
+    .expect("PDF should extract");
+
+    assert!(result.warnings.is_empty(), "warnings: {:?}", result.warnings);
+}
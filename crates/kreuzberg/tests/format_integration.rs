@@ -101,6 +101,12 @@ async fn test_ocr_simple_english_image_async() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -144,6 +150,12 @@ async fn test_ocr_image_without_text_async() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
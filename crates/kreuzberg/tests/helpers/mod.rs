@@ -116,6 +116,12 @@ pub fn test_config_with_ocr() -> kreuzberg::core::config::ExtractionConfig {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
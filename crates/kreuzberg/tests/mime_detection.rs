@@ -459,3 +459,76 @@ async fn test_pandoc_mime_validation() {
         assert_eq!(result.expect("Operation failed"), mime_type);
     }
 }
+
+/// Test legacy Office files are identified from their OLE compound file streams.
+#[test]
+fn test_mime_detection_from_ole_content() {
+    use kreuzberg::core::mime::detect_mime_type_from_bytes;
+
+    let test_cases = [
+        ("doc/unit_test_lists.doc", "application/msword"),
+        ("xls/test_excel.xls", "application/vnd.ms-excel"),
+        ("ppt/simple.ppt", "application/vnd.ms-powerpoint"),
+    ];
+
+    for (relative_path, expected_mime) in test_cases {
+        if helpers::skip_if_missing(relative_path) {
+            continue;
+        }
+
+        let content = std::fs::read(helpers::get_test_file_path(relative_path)).expect("Operation failed");
+        let detected = detect_mime_type_from_bytes(&content).expect("Operation failed");
+        assert_eq!(detected, expected_mime, "MIME type mismatch for {}", relative_path);
+    }
+}
+
+/// Test Office Open XML files are identified from `[Content_Types].xml`, not as generic ZIP.
+#[cfg(any(feature = "office", feature = "archives"))]
+#[test]
+fn test_mime_detection_from_ooxml_content_types() {
+    use kreuzberg::core::mime::detect_mime_type_from_bytes;
+    use std::io::Cursor;
+    use zip::write::{FileOptions, ZipWriter};
+
+    let test_cases = [
+        (
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml",
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        ),
+        (
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml",
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        ),
+        (
+            "application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml",
+            "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        ),
+    ];
+
+    for (main_content_type, expected_mime) in test_cases {
+        // Part names deliberately avoid the usual word/, xl/ and ppt/ paths
+        let content_types = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/content/main.xml" ContentType="{}"/>
+</Types>"#,
+            main_content_type
+        );
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+            zip.start_file("[Content_Types].xml", options)
+                .expect("Operation failed");
+            zip.write_all(content_types.as_bytes()).expect("Operation failed");
+            zip.start_file("content/main.xml", options).expect("Operation failed");
+            zip.write_all(b"<document/>").expect("Operation failed");
+            zip.finish().expect("Operation failed");
+        }
+
+        let detected = detect_mime_type_from_bytes(cursor.get_ref()).expect("Operation failed");
+        assert_eq!(detected, expected_mime, "MIME type mismatch for {}", main_content_type);
+    }
+}
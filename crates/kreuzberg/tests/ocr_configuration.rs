@@ -16,9 +16,9 @@
 mod helpers;
 
 use helpers::*;
-use kreuzberg::core::config::{ExtractionConfig, OcrConfig};
-use kreuzberg::extract_file_sync;
+use kreuzberg::core::config::{BinarizationMethod, ExtractionConfig, OcrConfig};
 use kreuzberg::types::TesseractConfig;
+use kreuzberg::{extract_bytes_sync, extract_file_sync};
 
 #[test]
 fn test_ocr_language_english() {
@@ -33,6 +33,12 @@ fn test_ocr_language_english() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -59,6 +65,12 @@ fn test_ocr_language_german() {
             language: "deu".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -98,6 +110,12 @@ fn test_ocr_language_multiple() {
             language: "eng+kor".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -140,6 +158,12 @@ fn test_ocr_psm_auto() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -169,6 +193,12 @@ fn test_ocr_psm_single_block() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -198,6 +228,12 @@ fn test_ocr_psm_single_line() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -225,6 +261,12 @@ fn test_force_ocr_on_text_pdf() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -256,6 +298,12 @@ fn test_force_ocr_disabled() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -292,6 +340,12 @@ fn test_table_detection_enabled() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -321,6 +375,12 @@ fn test_table_detection_disabled() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -350,6 +410,12 @@ fn test_language_model_ngram_configuration() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -380,6 +446,12 @@ fn test_dictionary_correction_enabled() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -410,6 +482,12 @@ fn test_character_whitelist() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -439,6 +517,12 @@ fn test_ocr_cache_enabled() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -479,6 +563,12 @@ fn test_ocr_cache_disabled() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         use_cache: false,
@@ -514,6 +604,12 @@ fn test_complex_configuration_combination() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -528,3 +624,103 @@ fn test_complex_configuration_combination() {
     assert!(result.chunks.is_none(), "Chunks should be None without chunking config");
     assert!(result.detected_languages.is_none(), "Language detection not enabled");
 }
+
+/// Re-render an image as low-contrast ink under lighting that ramps from dark to bright,
+/// so that ink on the bright side is lighter than paper on the dark side.
+fn unevenly_lit_png(relative_path: &str) -> Vec<u8> {
+    let original = image::open(get_test_file_path(relative_path))
+        .expect("test image should decode")
+        .to_luma8();
+    let (width, height) = original.dimensions();
+
+    let degraded = image::GrayImage::from_fn(width, height, |x, y| {
+        let lighting = 40.0 + 190.0 * f64::from(x) / f64::from(width.max(2) - 1);
+        let ink = f64::from(255 - original.get_pixel(x, y)[0]) / 255.0;
+        image::Luma([(lighting * (1.0 - 0.35 * ink)) as u8])
+    });
+
+    let mut png = std::io::Cursor::new(Vec::new());
+    degraded
+        .write_to(&mut png, image::ImageFormat::Png)
+        .expect("degraded image should encode");
+    png.into_inner()
+}
+
+fn ocr_with_binarization(image_bytes: &[u8], binarization: BinarizationMethod) -> String {
+    let config = ExtractionConfig {
+        ocr: Some(OcrConfig {
+            binarization: Some(binarization),
+            tesseract_config: Some(TesseractConfig {
+                use_cache: false,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        use_cache: false,
+        ..Default::default()
+    };
+
+    extract_bytes_sync(image_bytes, "image/png", &config)
+        .expect("OCR should succeed")
+        .content
+        .to_lowercase()
+}
+
+#[test]
+fn test_ocr_adaptive_binarization_improves_unevenly_lit_image() {
+    if skip_if_missing("images/test_hello_world.png") {
+        return;
+    }
+
+    let image_bytes = unevenly_lit_png("images/test_hello_world.png");
+    let expected_words = ["hello", "world"];
+    let recognized = |content: &str| expected_words.iter().filter(|word| content.contains(*word)).count();
+
+    let plain = ocr_with_binarization(&image_bytes, BinarizationMethod::None);
+    let adaptive = ocr_with_binarization(&image_bytes, BinarizationMethod::Adaptive);
+
+    assert_eq!(
+        recognized(&adaptive),
+        expected_words.len(),
+        "adaptive binarization output: {adaptive:?}"
+    );
+    assert!(
+        recognized(&adaptive) > recognized(&plain),
+        "adaptive: {adaptive:?}, none: {plain:?}"
+    );
+}
+
+#[cfg(all(feature = "pdf", feature = "language-detection"))]
+#[tokio::test]
+async fn test_auto_language_selects_german_for_german_scan() {
+    use kreuzberg::ocr::TesseractBackend;
+    use kreuzberg::pdf::{PageRenderOptions, render_page_to_image};
+    use kreuzberg::plugins::OcrBackend;
+
+    if skip_if_missing("pdf/image_only_german_pdf.pdf") {
+        return;
+    }
+    let backend = TesseractBackend::new().expect("Tesseract backend should initialize");
+    if !backend.supports_language("deu") {
+        tracing::debug!("Skipping auto language test: German language pack not installed");
+        return;
+    }
+
+    let pdf_bytes = std::fs::read(get_test_file_path("pdf/image_only_german_pdf.pdf")).expect("fixture should read");
+    let page = render_page_to_image(&pdf_bytes, 0, &PageRenderOptions::default()).expect("page should render");
+    let mut png = std::io::Cursor::new(Vec::new());
+    page.write_to(&mut png, image::ImageFormat::Png)
+        .expect("page should encode");
+
+    let config = OcrConfig {
+        language: "auto".to_string(),
+        ..Default::default()
+    };
+    assert!(config.is_auto_language());
+
+    let language = backend
+        .resolve_language(png.get_ref(), &config)
+        .await
+        .expect("language detection should succeed");
+    assert_eq!(language, "deu");
+}
@@ -19,7 +19,7 @@ mod helpers;
 
 use helpers::*;
 use kreuzberg::core::config::{ExtractionConfig, OcrConfig};
-use kreuzberg::types::TesseractConfig;
+use kreuzberg::types::{ExtractionWarningKind, TesseractConfig};
 use kreuzberg::{KreuzbergError, extract_bytes_sync, extract_file_sync};
 
 #[test]
@@ -35,6 +35,12 @@ fn test_ocr_invalid_language_code() {
             language: "invalid_lang_99999".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -76,6 +82,12 @@ fn test_ocr_invalid_psm_mode() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -115,6 +127,12 @@ fn test_ocr_invalid_backend_name() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -142,6 +160,64 @@ fn test_ocr_invalid_backend_name() {
     }
 }
 
+/// PDF OCR config with a backend that is never registered, as on a host without Tesseract.
+#[cfg(feature = "pdf")]
+fn missing_backend_config(ocr_optional: bool) -> ExtractionConfig {
+    ExtractionConfig {
+        ocr: Some(OcrConfig {
+            backend: "uninstalled_ocr_backend".to_string(),
+            ..Default::default()
+        }),
+        force_ocr: true,
+        ocr_optional,
+        ..Default::default()
+    }
+}
+
+#[cfg(feature = "pdf")]
+#[test]
+fn test_ocr_missing_backend_fails_without_ocr_optional() {
+    if skip_if_missing("pdf/mixed_scanned_page.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/mixed_scanned_page.pdf");
+    let result = extract_file_sync(&file_path, None, &missing_backend_config(false));
+
+    match result {
+        Err(KreuzbergError::Plugin { plugin_name, .. }) => assert_eq!(plugin_name, "uninstalled_ocr_backend"),
+        other => panic!("expected a missing backend error, got {:?}", other.map(|r| r.content)),
+    }
+}
+
+#[cfg(feature = "pdf")]
+#[test]
+fn test_ocr_optional_returns_digital_text_when_backend_missing() {
+    if skip_if_missing("pdf/mixed_scanned_page.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/mixed_scanned_page.pdf");
+    let result = extract_file_sync(&file_path, None, &missing_backend_config(true))
+        .expect("ocr_optional should extract without the OCR backend");
+
+    assert!(
+        result.content.contains("Quarterly results"),
+        "digital text missing: {}",
+        result.content
+    );
+    let warning = result
+        .warnings
+        .iter()
+        .find(|warning| warning.kind == ExtractionWarningKind::OcrUnavailable)
+        .expect("OCR unavailable warning should be recorded");
+    assert!(
+        warning.message.contains("uninstalled_ocr_backend"),
+        "{}",
+        warning.message
+    );
+}
+
 #[test]
 fn test_ocr_corrupted_image_data() {
     let corrupted_data = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10];
@@ -151,6 +227,12 @@ fn test_ocr_corrupted_image_data() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -182,6 +264,12 @@ fn test_ocr_empty_image() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -213,6 +301,12 @@ fn test_ocr_non_image_data() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -252,6 +346,12 @@ fn test_ocr_extreme_table_threshold() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -289,6 +389,12 @@ fn test_ocr_negative_psm() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -322,6 +428,12 @@ fn test_ocr_empty_whitelist() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -359,6 +471,12 @@ fn test_ocr_conflicting_whitelist_blacklist() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -392,6 +510,12 @@ fn test_ocr_empty_language() {
             language: "".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -425,6 +549,12 @@ fn test_ocr_malformed_multi_language() {
             language: "eng++deu++fra".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -459,6 +589,12 @@ fn test_ocr_cache_disabled_then_enabled() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         use_cache: false,
@@ -480,6 +616,12 @@ fn test_ocr_cache_disabled_then_enabled() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -512,6 +654,12 @@ fn test_ocr_concurrent_same_file() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -582,6 +730,12 @@ fn test_ocr_concurrent_different_files() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -655,6 +809,12 @@ fn test_ocr_with_preprocessing_extreme_dpi() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -701,6 +861,12 @@ fn test_ocr_with_invalid_binarization_method() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: false,
         ..Default::default()
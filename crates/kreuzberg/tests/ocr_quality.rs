@@ -168,6 +168,12 @@ fn test_ocr_quality_simple_text_high_accuracy() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -243,6 +249,12 @@ fn test_ocr_quality_numeric_accuracy() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -309,6 +321,12 @@ fn test_ocr_quality_layout_preservation() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -369,6 +387,12 @@ fn test_ocr_quality_technical_document() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -414,6 +438,12 @@ fn test_ocr_consistency_across_runs() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         use_cache: false,
@@ -480,6 +510,12 @@ fn test_ocr_consistency_with_different_psm() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -494,6 +530,12 @@ fn test_ocr_consistency_with_different_psm() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -565,6 +607,12 @@ fn test_ocr_quality_multi_page_consistency() {
             language: "eng".to_string(),
             tesseract_config: None,
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -617,6 +665,12 @@ fn test_ocr_quality_with_tables() {
                 ..Default::default()
             }),
             output_format: None,
+            dpi: None,
+            psm: None,
+            oem: None,
+            binarization: None,
+            ocr_pages: None,
+            language_fallback: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -0,0 +1,56 @@
+//! Integration tests for OCR of images embedded in digital PDFs with `ocr_embedded_images`.
+
+#![cfg(all(feature = "pdf", feature = "ocr"))]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+
+/// Two pages with a line of native text each, both showing the same scanned newspaper clipping.
+const EMBEDDED_IMAGE_PDF: &str = "pdf/embedded_text_image.pdf";
+
+#[test]
+fn test_embedded_image_text_is_appended_once() {
+    if skip_if_missing(EMBEDDED_IMAGE_PDF) {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        ocr_embedded_images: true,
+        ..test_config_with_ocr()
+    };
+    let result = extract_file_sync(get_test_file_path(EMBEDDED_IMAGE_PDF), None, &config).expect("Operation failed");
+
+    assert!(
+        result.content.contains("first edition") && result.content.contains("second edition"),
+        "native text missing: {}",
+        result.content
+    );
+    assert!(
+        result.content.to_lowercase().contains("nasdaq"),
+        "image text missing: {}",
+        result.content
+    );
+    assert_eq!(
+        result.content.matches("[Image text, page").count(),
+        1,
+        "an image reused on two pages should be recognized once: {}",
+        result.content
+    );
+    assert!(result.content.contains("[Image text, page 1]"));
+}
+
+#[test]
+fn test_embedded_images_are_not_ocrd_by_default() {
+    if skip_if_missing(EMBEDDED_IMAGE_PDF) {
+        return;
+    }
+
+    let result = extract_file_sync(get_test_file_path(EMBEDDED_IMAGE_PDF), None, &test_config_with_ocr())
+        .expect("Operation failed");
+
+    assert!(result.content.contains("first edition"));
+    assert!(!result.content.contains("[Image text"), "{}", result.content);
+}
@@ -0,0 +1,54 @@
+//! Integration tests for PDF form field (AcroForm) value extraction.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+use kreuzberg::types::FormatMetadata;
+use std::collections::BTreeMap;
+
+const FORM_PDF: &str = "pdf/filled_form.pdf";
+
+fn form_fields(config: &ExtractionConfig) -> (String, BTreeMap<String, String>) {
+    let result = extract_file_sync(get_test_file_path(FORM_PDF), None, config).expect("form PDF should extract");
+
+    let Some(FormatMetadata::Pdf(pdf)) = result.metadata.format else {
+        panic!("expected PDF format metadata");
+    };
+    (result.content, pdf.form_fields.expect("form fields should be reported"))
+}
+
+#[test]
+fn test_form_field_values_in_metadata() {
+    if skip_if_missing(FORM_PDF) {
+        return;
+    }
+
+    let (content, fields) = form_fields(&ExtractionConfig::default());
+
+    assert_eq!(fields.get("full_name").map(String::as_str), Some("Jane Doe"));
+    assert_eq!(fields.get("agree_terms").map(String::as_str), Some("on"));
+    assert_eq!(fields.get("plan").map(String::as_str), Some("Gold"));
+    assert!(!fields.contains_key("signature"), "signature fields should be skipped");
+    assert!(!content.contains("Form Fields"), "form section should be opt-in");
+}
+
+#[test]
+fn test_form_field_section_in_content() {
+    if skip_if_missing(FORM_PDF) {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        extract_form_fields: true,
+        ..Default::default()
+    };
+    let (content, _) = form_fields(&config);
+
+    assert!(content.contains("Form Fields"));
+    assert!(content.contains("full_name: Jane Doe"));
+    assert!(content.contains("agree_terms: on"));
+}
@@ -48,6 +48,7 @@ async fn test_full_hierarchy_extraction() {
                 k_clusters: 6,
                 include_bbox: true,
                 ocr_coverage_threshold: None,
+                ..Default::default()
             }),
         }),
         ..Default::default()
@@ -160,6 +161,7 @@ async fn test_hierarchy_disabled() {
                 k_clusters: 6,
                 include_bbox: true,
                 ocr_coverage_threshold: None,
+                ..Default::default()
             }),
         }),
         ..Default::default()
@@ -215,6 +217,7 @@ async fn test_hierarchy_with_explicit_disabled() {
                 k_clusters: 6,
                 include_bbox: true,
                 ocr_coverage_threshold: None,
+                ..Default::default()
             }),
         }),
         ..Default::default()
@@ -271,6 +274,7 @@ async fn test_hierarchy_different_k_clusters() {
                     k_clusters: *k,
                     include_bbox: true,
                     ocr_coverage_threshold: None,
+                    ..Default::default()
                 }),
             }),
             ..Default::default()
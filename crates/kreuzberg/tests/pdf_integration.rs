@@ -11,6 +11,7 @@ mod helpers;
 use helpers::*;
 use kreuzberg::core::config::ExtractionConfig;
 use kreuzberg::extract_file_sync;
+use kreuzberg::pdf::{pdf_page_count, pdf_page_count_from_path};
 
 #[test]
 fn test_pdf_password_protected_fails_gracefully() {
@@ -43,3 +44,25 @@ fn test_pdf_password_protected_fails_gracefully() {
         }
     }
 }
+
+#[test]
+fn test_pdf_page_count_without_extraction() {
+    if skip_if_missing("pdf/multi_page.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/multi_page.pdf");
+    assert_eq!(
+        pdf_page_count_from_path(&file_path).expect("page count should succeed"),
+        5
+    );
+
+    let pdf_bytes = std::fs::read(&file_path).expect("fixture should be readable");
+    assert_eq!(pdf_page_count(&pdf_bytes).expect("page count should succeed"), 5);
+}
+
+#[test]
+fn test_pdf_page_count_missing_file_is_io_error() {
+    let result = pdf_page_count_from_path("/nonexistent/document.pdf");
+    assert!(matches!(result, Err(kreuzberg::KreuzbergError::Io(_))));
+}
@@ -0,0 +1,73 @@
+//! Integration tests for word positions reported with `include_layout`.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+use kreuzberg::types::FormatMetadata;
+
+const RUNNING_FOOTER_PDF: &str = "pdf/running_footer.pdf";
+
+#[test]
+fn test_layout_is_opt_in() {
+    if skip_if_missing(RUNNING_FOOTER_PDF) {
+        return;
+    }
+
+    let result = extract_file_sync(
+        get_test_file_path(RUNNING_FOOTER_PDF),
+        None,
+        &ExtractionConfig::default(),
+    )
+    .expect("PDF should extract");
+
+    assert!(result.layout.is_none());
+}
+
+#[test]
+fn test_words_have_bounding_boxes_within_page() {
+    if skip_if_missing(RUNNING_FOOTER_PDF) {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        include_layout: true,
+        ..Default::default()
+    };
+    let result = extract_file_sync(get_test_file_path(RUNNING_FOOTER_PDF), None, &config).expect("PDF should extract");
+
+    let Some(FormatMetadata::Pdf(pdf)) = result.metadata.format else {
+        panic!("expected PDF format metadata");
+    };
+    let width = pdf.width.expect("page width") as f64;
+    let height = pdf.height.expect("page height") as f64;
+
+    let layout = result.layout.expect("layout should be populated");
+    assert!(!layout.is_empty());
+    for word in &layout {
+        let bbox = &word.bbox;
+        assert!(!word.text.trim().is_empty(), "empty word: {:?}", word);
+        assert!((1..=3).contains(&word.page_number), "bad page: {:?}", word);
+        assert!(
+            0.0 <= bbox.x0 && bbox.x0 < bbox.x1 && bbox.x1 <= width,
+            "x out of page: {:?}",
+            word
+        );
+        assert!(
+            0.0 <= bbox.y0 && bbox.y0 < bbox.y1 && bbox.y1 <= height,
+            "y out of page: {:?}",
+            word
+        );
+    }
+
+    for expected in ["Introduction", "Revenue", "Outlook"] {
+        assert!(
+            layout.iter().any(|word| word.text == expected),
+            "missing word {:?}",
+            expected
+        );
+    }
+}
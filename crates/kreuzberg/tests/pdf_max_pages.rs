@@ -0,0 +1,68 @@
+//! Integration tests for the `max_pages` safety cap on PDF extraction.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+use kreuzberg::types::ExtractionWarningKind;
+
+const MULTI_PAGE_PDF: &str = "pdf/mixed_scanned_page.pdf";
+
+#[test]
+fn test_max_pages_extracts_only_leading_pages() {
+    if skip_if_missing(MULTI_PAGE_PDF) {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        max_pages: Some(1),
+        ..Default::default()
+    };
+    let result = extract_file_sync(get_test_file_path(MULTI_PAGE_PDF), None, &config).expect("Operation failed");
+
+    assert!(
+        result.content.contains("Quarterly results"),
+        "page one text missing: {}",
+        result.content
+    );
+    assert!(
+        !result.content.contains("The appendix describes"),
+        "page three text should be dropped: {}",
+        result.content
+    );
+
+    let warning = result
+        .warnings
+        .iter()
+        .find(|warning| warning.kind == ExtractionWarningKind::Truncated)
+        .expect("truncation warning should be recorded");
+    assert!(
+        warning.message.contains("1 of 3"),
+        "unexpected message: {}",
+        warning.message
+    );
+}
+
+#[test]
+fn test_max_pages_above_page_count_is_a_no_op() {
+    if skip_if_missing(MULTI_PAGE_PDF) {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        max_pages: Some(10),
+        ..Default::default()
+    };
+    let result = extract_file_sync(get_test_file_path(MULTI_PAGE_PDF), None, &config).expect("Operation failed");
+
+    assert!(result.content.contains("The appendix describes"));
+    assert!(
+        result
+            .warnings
+            .iter()
+            .all(|warning| warning.kind != ExtractionWarningKind::Truncated)
+    );
+}
@@ -0,0 +1,148 @@
+//! Integration tests for running OCR on a selection of PDF pages with `ocr_pages`.
+
+#![cfg(all(feature = "pdf", feature = "ocr"))]
+
+mod helpers;
+
+use async_trait::async_trait;
+use helpers::*;
+use kreuzberg::core::config::{ExtractionConfig, OcrConfig, OcrPageSelection};
+use kreuzberg::plugins::registry::get_ocr_backend_registry;
+use kreuzberg::plugins::{OcrBackend, OcrBackendType, Plugin};
+use kreuzberg::types::{ExtractionResult, FormatMetadata, Metadata};
+use kreuzberg::{Result, extract_file_sync};
+use serial_test::serial;
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const MIXED_PDF: &str = "pdf/mixed_scanned_page.pdf";
+const BACKEND_NAME: &str = "page-selection-test-ocr";
+const OCR_TEXT: &str = "SCANNED PAGE TEXT";
+
+struct CountingOcrBackend {
+    call_count: AtomicUsize,
+}
+
+impl Plugin for CountingOcrBackend {
+    fn name(&self) -> &str {
+        BACKEND_NAME
+    }
+
+    fn version(&self) -> String {
+        "1.0.0".to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OcrBackend for CountingOcrBackend {
+    async fn process_image(&self, _image_bytes: &[u8], _config: &OcrConfig) -> Result<ExtractionResult> {
+        self.call_count.fetch_add(1, Ordering::SeqCst);
+        Ok(ExtractionResult {
+            content: OCR_TEXT.to_string(),
+            mime_type: Cow::Borrowed("text/plain"),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
+            pages: None,
+            layout: None,
+            djot_content: None,
+            elements: None,
+        })
+    }
+
+    fn supports_language(&self, _lang: &str) -> bool {
+        true
+    }
+
+    fn backend_type(&self) -> OcrBackendType {
+        OcrBackendType::Custom
+    }
+}
+
+fn extract_with_selection(selection: OcrPageSelection) -> (ExtractionResult, usize) {
+    let registry = get_ocr_backend_registry();
+    let backend = Arc::new(CountingOcrBackend {
+        call_count: AtomicUsize::new(0),
+    });
+    registry
+        .write()
+        .expect("Operation failed")
+        .register(Arc::clone(&backend) as Arc<dyn OcrBackend>)
+        .expect("Operation failed");
+
+    let config = ExtractionConfig {
+        ocr: Some(OcrConfig {
+            backend: BACKEND_NAME.to_string(),
+            ocr_pages: Some(selection),
+            language_fallback: None,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = extract_file_sync(get_test_file_path(MIXED_PDF), None, &config);
+
+    registry
+        .write()
+        .expect("Operation failed")
+        .shutdown_all()
+        .expect("Operation failed");
+
+    (
+        result.expect("PDF should extract"),
+        backend.call_count.load(Ordering::SeqCst),
+    )
+}
+
+#[serial]
+#[test]
+fn test_image_only_runs_ocr_on_scanned_page() {
+    if skip_if_missing(MIXED_PDF) {
+        return;
+    }
+
+    let (result, calls) = extract_with_selection(OcrPageSelection::ImageOnly);
+    assert_eq!(calls, 1, "only the scanned page should be sent to OCR");
+
+    for expected in ["Quarterly results", OCR_TEXT, "The appendix describes"] {
+        assert!(
+            result.content.contains(expected),
+            "missing {:?}: {}",
+            expected,
+            result.content
+        );
+    }
+
+    let Some(FormatMetadata::Pdf(pdf)) = result.metadata.format else {
+        panic!("expected PDF format metadata");
+    };
+    let ocr_used: Vec<bool> = pdf.page_reports.iter().map(|report| report.ocr_used).collect();
+    assert_eq!(ocr_used, vec![false, true, false]);
+    assert!(pdf.page_reports[1].has_images);
+}
+
+#[serial]
+#[test]
+fn test_explicit_pages_are_ocrd_regardless_of_text() {
+    if skip_if_missing(MIXED_PDF) {
+        return;
+    }
+
+    let (result, calls) = extract_with_selection(OcrPageSelection::Explicit(vec![1, 3, 7]));
+    assert_eq!(calls, 2, "out-of-range pages should be ignored");
+    assert_eq!(result.content.matches(OCR_TEXT).count(), 2);
+    assert!(!result.content.contains("Quarterly results"));
+}
@@ -287,6 +287,7 @@ fn test_ocr_custom_threshold() {
                 k_clusters: 6,
                 include_bbox: true,
                 ocr_coverage_threshold: Some(0.25),
+                ..Default::default()
             }),
         }),
         ..Default::default()
@@ -0,0 +1,96 @@
+//! Integration tests for PDF outline (bookmark) extraction.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::{ExtractionConfig, HierarchyConfig, PageConfig, PdfConfig};
+use kreuzberg::extract_file_sync;
+use kreuzberg::types::OutlineEntry;
+
+const OUTLINE_PDF: &str = "pdf/bookmarked_outline.pdf";
+
+fn entry(title: &str, level: usize, page: usize) -> OutlineEntry {
+    OutlineEntry {
+        title: title.to_string(),
+        level,
+        page: Some(page),
+    }
+}
+
+#[test]
+fn test_outline_in_metadata() {
+    if skip_if_missing(OUTLINE_PDF) {
+        return;
+    }
+
+    let result = extract_file_sync(get_test_file_path(OUTLINE_PDF), None, &ExtractionConfig::default())
+        .expect("bookmarked PDF should extract");
+
+    assert_eq!(
+        result.metadata.outline,
+        Some(vec![
+            entry("Introduction", 1, 1),
+            entry("Background", 2, 1),
+            entry("Scope", 2, 2),
+            entry("Conclusion", 1, 3),
+        ])
+    );
+}
+
+#[test]
+fn test_pdf_without_outline_has_no_outline_metadata() {
+    if skip_if_missing("pdf/filled_form.pdf") {
+        return;
+    }
+
+    let result = extract_file_sync(
+        get_test_file_path("pdf/filled_form.pdf"),
+        None,
+        &ExtractionConfig::default(),
+    )
+    .expect("PDF should extract");
+
+    assert!(result.metadata.outline.is_none());
+}
+
+#[test]
+fn test_hierarchy_levels_from_outline() {
+    if skip_if_missing(OUTLINE_PDF) {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        pages: Some(PageConfig {
+            extract_pages: true,
+            ..Default::default()
+        }),
+        pdf_options: Some(PdfConfig {
+            extract_images: false,
+            passwords: None,
+            extract_metadata: true,
+            hierarchy: Some(HierarchyConfig {
+                use_outline: true,
+                ..Default::default()
+            }),
+        }),
+        ..Default::default()
+    };
+
+    let result =
+        extract_file_sync(get_test_file_path(OUTLINE_PDF), None, &config).expect("bookmarked PDF should extract");
+    let pages = result.pages.expect("pages should be extracted");
+    let hierarchy = pages[0].hierarchy.as_ref().expect("hierarchy should be extracted");
+
+    let level_of = |title: &str| {
+        hierarchy
+            .blocks
+            .iter()
+            .find(|block| block.text.trim() == title)
+            .map(|block| block.level.as_str())
+    };
+    assert_eq!(level_of("Introduction"), Some("h1"));
+    assert_eq!(level_of("Background"), Some("h2"));
+    assert_eq!(level_of("Some introductory body text."), Some("body"));
+}
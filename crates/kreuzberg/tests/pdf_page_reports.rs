@@ -0,0 +1,36 @@
+//! Integration tests for per-page text reports in PDF metadata.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+use kreuzberg::types::FormatMetadata;
+
+const BLANK_PAGE_PDF: &str = "pdf/blank_middle_page.pdf";
+
+#[test]
+fn test_empty_page_has_zero_char_count() {
+    if skip_if_missing(BLANK_PAGE_PDF) {
+        return;
+    }
+
+    let result = extract_file_sync(get_test_file_path(BLANK_PAGE_PDF), None, &ExtractionConfig::default())
+        .expect("PDF should extract");
+
+    let Some(FormatMetadata::Pdf(pdf)) = result.metadata.format else {
+        panic!("expected PDF format metadata");
+    };
+    let reports = pdf.page_reports;
+
+    assert_eq!(
+        reports.iter().map(|report| report.page).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert!(reports[0].char_count > 0, "page 1 should have text: {:?}", reports[0]);
+    assert_eq!(reports[1].char_count, 0, "page 2 should be empty: {:?}", reports[1]);
+    assert!(reports[2].char_count > 0, "page 3 should have text: {:?}", reports[2]);
+    assert!(reports.iter().all(|report| !report.has_images && !report.ocr_used));
+}
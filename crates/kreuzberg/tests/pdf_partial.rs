@@ -0,0 +1,71 @@
+//! Integration tests for `allow_partial` extraction of damaged PDFs.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+use kreuzberg::types::ExtractionWarningKind;
+
+/// Three pages; the objects of page three are zeroed out, as after an interrupted write.
+const DAMAGED_PDF: &str = "pdf/damaged_after_two_pages.pdf";
+
+#[test]
+fn test_allow_partial_returns_pages_before_damage() {
+    if skip_if_missing(DAMAGED_PDF) {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        allow_partial: true,
+        ..Default::default()
+    };
+    let result = extract_file_sync(get_test_file_path(DAMAGED_PDF), None, &config).expect("Operation failed");
+
+    assert!(result.partial, "result should be marked partial");
+    assert!(
+        result.content.contains("Page one introduces") && result.content.contains("Page two continues"),
+        "readable pages missing: {}",
+        result.content
+    );
+    assert!(
+        !result.content.contains("Page three"),
+        "damaged page leaked: {}",
+        result.content
+    );
+
+    let warning = result
+        .warnings
+        .iter()
+        .find(|warning| warning.kind == ExtractionWarningKind::PartialContent)
+        .expect("partial content warning should be recorded");
+    assert!(
+        warning.message.contains("stopped at page 3"),
+        "unexpected message: {}",
+        warning.message
+    );
+}
+
+#[test]
+fn test_intact_pdf_is_not_partial() {
+    let path = "pdf/mixed_scanned_page.pdf";
+    if skip_if_missing(path) {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        allow_partial: true,
+        ..Default::default()
+    };
+    let result = extract_file_sync(get_test_file_path(path), None, &config).expect("Operation failed");
+
+    assert!(!result.partial);
+    assert!(
+        result
+            .warnings
+            .iter()
+            .all(|warning| warning.kind != ExtractionWarningKind::PartialContent)
+    );
+}
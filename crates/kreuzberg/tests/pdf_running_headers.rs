@@ -0,0 +1,42 @@
+//! Integration tests for stripping running headers and footers from PDF text.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+
+const RUNNING_FOOTER_PDF: &str = "pdf/running_footer.pdf";
+
+fn extract_content(config: &ExtractionConfig) -> String {
+    extract_file_sync(get_test_file_path(RUNNING_FOOTER_PDF), None, config)
+        .expect("PDF should extract")
+        .content
+}
+
+#[test]
+fn test_shared_footer_is_stripped() {
+    if skip_if_missing(RUNNING_FOOTER_PDF) {
+        return;
+    }
+
+    let default_content = extract_content(&ExtractionConfig::default());
+    assert_eq!(default_content.matches("Quarterly Report - Page").count(), 3);
+
+    let config = ExtractionConfig {
+        strip_running_headers: true,
+        ..Default::default()
+    };
+    let content = extract_content(&config);
+
+    assert!(
+        !content.contains("Quarterly Report"),
+        "footer should be stripped: {}",
+        content
+    );
+    for body in ["Introduction", "Revenue grew in every region.", "Outlook"] {
+        assert!(content.contains(body), "missing body text {:?}: {}", body, content);
+    }
+}
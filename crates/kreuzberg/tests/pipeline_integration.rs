@@ -141,7 +141,11 @@ async fn test_pipeline_empty_no_processors() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -188,7 +192,11 @@ async fn test_pipeline_single_processor_per_stage() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -235,7 +243,11 @@ async fn test_pipeline_multiple_processors_per_stage() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -273,7 +285,11 @@ async fn test_pipeline_all_stages_enabled() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -309,7 +325,11 @@ async fn test_pipeline_postprocessing_disabled() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -360,7 +380,11 @@ async fn test_pipeline_early_stage_runs_first() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -402,7 +426,11 @@ async fn test_pipeline_middle_stage_runs_second() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -440,7 +468,11 @@ async fn test_pipeline_late_stage_runs_last() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -478,7 +510,11 @@ async fn test_pipeline_within_stage_priority_order() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -548,7 +584,11 @@ async fn test_pipeline_cross_stage_data_flow() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -608,7 +648,11 @@ async fn test_pipeline_early_stage_error_recorded() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -652,7 +696,11 @@ async fn test_pipeline_middle_stage_error_propagation() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -726,7 +774,11 @@ async fn test_pipeline_late_stage_error_doesnt_affect_earlier_stages() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -816,7 +868,11 @@ async fn test_pipeline_processor_error_doesnt_stop_other_processors() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -896,7 +952,11 @@ async fn test_pipeline_multiple_processor_errors() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -940,7 +1000,11 @@ async fn test_pipeline_error_context_preservation() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -1018,7 +1082,11 @@ async fn test_pipeline_metadata_added_in_early_visible_in_middle() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -1092,7 +1160,11 @@ async fn test_pipeline_content_modified_in_middle_visible_in_late() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -1163,7 +1235,11 @@ async fn test_pipeline_multiple_processors_modifying_same_metadata() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -1253,7 +1329,11 @@ async fn test_pipeline_processors_reading_previous_output() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -1320,7 +1400,11 @@ async fn test_pipeline_large_content_modification() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -1358,7 +1442,11 @@ async fn test_pipeline_enabled_processors_whitelist() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -1407,7 +1495,11 @@ async fn test_pipeline_disabled_processors_blacklist() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -1456,7 +1548,11 @@ async fn test_pipeline_no_filtering_runs_all() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -1496,7 +1592,11 @@ async fn test_pipeline_empty_whitelist_runs_none() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
+        layout: None,
         djot_content: None,
         elements: None,
     };
@@ -63,7 +63,11 @@ impl OcrBackend for MockOcrBackend {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -162,7 +166,11 @@ impl OcrBackend for ValidatingOcrBackend {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -225,7 +233,11 @@ impl OcrBackend for MetadataOcrBackend {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
+            layout: None,
             djot_content: None,
             elements: None,
         })
@@ -312,6 +324,12 @@ fn test_ocr_backend_used_for_image_extraction() {
         language: "eng".to_string(),
         tesseract_config: None,
         output_format: None,
+        dpi: None,
+        psm: None,
+        oem: None,
+        binarization: None,
+        ocr_pages: None,
+        language_fallback: None,
     };
 
     let config = ExtractionConfig {
@@ -373,6 +391,12 @@ fn test_ocr_backend_receives_correct_parameters() {
         language: "deu".to_string(),
         tesseract_config: None,
         output_format: None,
+        dpi: None,
+        psm: None,
+        oem: None,
+        binarization: None,
+        ocr_pages: None,
+        language_fallback: None,
     };
 
     let config = ExtractionConfig {
@@ -422,6 +446,12 @@ fn test_ocr_backend_returns_correct_format() {
         language: "eng".to_string(),
         tesseract_config: None,
         output_format: None,
+        dpi: None,
+        psm: None,
+        oem: None,
+        binarization: None,
+        ocr_pages: None,
+        language_fallback: None,
     };
 
     let config = ExtractionConfig {
@@ -473,6 +503,12 @@ fn test_ocr_backend_error_handling() {
         language: "eng".to_string(),
         tesseract_config: None,
         output_format: None,
+        dpi: None,
+        psm: None,
+        oem: None,
+        binarization: None,
+        ocr_pages: None,
+        language_fallback: None,
     };
 
     let config = ExtractionConfig {
@@ -524,6 +560,12 @@ fn test_ocr_backend_validation_error() {
         language: "eng".to_string(),
         tesseract_config: None,
         output_format: None,
+        dpi: None,
+        psm: None,
+        oem: None,
+        binarization: None,
+        ocr_pages: None,
+        language_fallback: None,
     };
 
     let config = ExtractionConfig {
@@ -589,6 +631,12 @@ fn test_switching_between_ocr_backends() {
         language: "eng".to_string(),
         tesseract_config: None,
         output_format: None,
+        dpi: None,
+        psm: None,
+        oem: None,
+        binarization: None,
+        ocr_pages: None,
+        language_fallback: None,
     };
 
     let config1 = ExtractionConfig {
@@ -613,6 +661,12 @@ fn test_switching_between_ocr_backends() {
         language: "eng".to_string(),
         tesseract_config: None,
         output_format: None,
+        dpi: None,
+        psm: None,
+        oem: None,
+        binarization: None,
+        ocr_pages: None,
+        language_fallback: None,
     };
 
     let config2 = ExtractionConfig {
@@ -59,8 +59,12 @@ impl DocumentExtractor for FailingExtractor {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
                 pages: None,
                 elements: None,
+                layout: None,
                 djot_content: None,
             })
         }
@@ -306,8 +310,12 @@ fn test_extractor_priority_ordering_complex() {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                links: vec![],
+                warnings: vec![],
+                partial: false,
                 pages: None,
                 elements: None,
+                layout: None,
                 djot_content: None,
             })
         }
@@ -468,8 +476,12 @@ async fn test_processor_execution_order_within_stage() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
         elements: None,
+        layout: None,
         djot_content: None,
     };
 
@@ -505,8 +517,12 @@ async fn test_processor_error_propagation() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
         elements: None,
+        layout: None,
         djot_content: None,
     };
 
@@ -679,8 +695,12 @@ async fn test_validator_content_validation() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
         elements: None,
+        layout: None,
         djot_content: None,
     };
 
@@ -695,8 +715,12 @@ async fn test_validator_content_validation() {
         detected_languages: None,
         chunks: None,
         images: None,
+        links: vec![],
+        warnings: vec![],
+        partial: false,
         pages: None,
         elements: None,
+        layout: None,
         djot_content: None,
     };
 
@@ -0,0 +1,62 @@
+//! Integration tests for PPTX slide sections and speaker notes.
+
+#![cfg(feature = "office")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+
+const TWO_SLIDE_PPTX: &str = "pptx/two_slides_with_table.pptx";
+
+#[test]
+fn test_pptx_slides_are_emitted_as_sections_in_order() {
+    if skip_if_missing(TWO_SLIDE_PPTX) {
+        return;
+    }
+
+    let result = extract_file_sync(get_test_file_path(TWO_SLIDE_PPTX), None, &ExtractionConfig::default())
+        .expect("Operation failed");
+
+    let positions: Vec<usize> = ["## Slide 1", "Quarterly Review", "## Slide 2", "Revenue by Region"]
+        .iter()
+        .map(|needle| {
+            result
+                .content
+                .find(needle)
+                .unwrap_or_else(|| panic!("missing {:?}: {}", needle, result.content))
+        })
+        .collect();
+    assert!(
+        positions.windows(2).all(|pair| pair[0] < pair[1]),
+        "slides out of order: {}",
+        result.content
+    );
+
+    assert!(
+        result
+            .content
+            .contains("<tr><th>Region</th><th>Revenue</th></tr><tr><td>North</td><td>120</td></tr>"),
+        "table content missing: {}",
+        result.content
+    );
+    assert!(result.content.contains("<td>South</td><td>95</td>"));
+    assert!(result.content.contains("Mention the southern pipeline"));
+}
+
+#[test]
+fn test_pptx_speaker_notes_can_be_disabled() {
+    if skip_if_missing(TWO_SLIDE_PPTX) {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        include_speaker_notes: false,
+        ..Default::default()
+    };
+    let result = extract_file_sync(get_test_file_path(TWO_SLIDE_PPTX), None, &config).expect("Operation failed");
+
+    assert!(result.content.contains("## Slide 2"));
+    assert!(!result.content.contains("Mention the southern pipeline"));
+}
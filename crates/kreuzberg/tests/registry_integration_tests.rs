@@ -126,8 +126,12 @@ impl DocumentExtractor for MockExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            links: vec![],
+            warnings: vec![],
+            partial: false,
             pages: None,
             elements: None,
+            layout: None,
             djot_content: None,
         })
     }
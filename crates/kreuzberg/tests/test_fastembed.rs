@@ -629,3 +629,67 @@ async fn test_generate_embeddings_chunking_integration() {
         chunking_result.chunks.len()
     );
 }
+
+#[cfg(feature = "embeddings")]
+#[tokio::test]
+async fn test_generate_embeddings_matches_preset_dimensions() {
+    use kreuzberg::{generate_embeddings, get_preset};
+
+    let preset = get_preset("fast").expect("fast preset exists");
+    let long_text = "Kreuzberg pools window embeddings for long inputs. ".repeat(40);
+
+    for text in ["A short sentence to embed.", long_text.as_str()] {
+        let embedding = generate_embeddings(text, preset.name).expect("Embedding generation failed");
+        assert_eq!(embedding.len(), preset.dimensions, "Embedding has wrong dimensions");
+
+        let magnitude: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!(
+            (magnitude - 1.0).abs() < 0.001,
+            "Embedding not normalized (magnitude={})",
+            magnitude
+        );
+    }
+}
+
+#[cfg(feature = "embeddings")]
+#[tokio::test]
+async fn test_extract_and_embed_attaches_embedding_per_chunk() {
+    use kreuzberg::{ChunkingConfig, ExtractionConfig, extract_and_embed, get_preset};
+    use std::io::Write;
+
+    let mut file = tempfile::Builder::new()
+        .suffix(".txt")
+        .tempfile()
+        .expect("Failed to create temp file");
+    let text = "Kreuzberg chunks extracted text before embedding it. ".repeat(20);
+    file.write_all(text.as_bytes()).expect("Failed to write temp file");
+
+    let config = ExtractionConfig {
+        chunking: Some(ChunkingConfig {
+            max_characters: 200,
+            overlap: 20,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let preset = get_preset("fast").expect("fast preset exists");
+
+    let chunks = extract_and_embed(file.path(), &config, preset.name)
+        .await
+        .expect("extract_and_embed failed");
+
+    assert!(chunks.len() > 1, "Expected the configured chunk size to split the text");
+    for (i, chunk) in chunks.iter().enumerate() {
+        assert!(!chunk.text.is_empty(), "Chunk {} is empty", i);
+        assert_eq!(
+            chunk.embedding.len(),
+            preset.dimensions,
+            "Chunk {} has wrong dimensions",
+            i
+        );
+    }
+    assert!(chunks.windows(2).all(|pair| pair[0].offset < pair[1].offset));
+
+    let unknown = extract_and_embed(file.path(), &config, "nonexistent").await;
+    assert!(unknown.is_err(), "Unknown presets should be rejected");
+}
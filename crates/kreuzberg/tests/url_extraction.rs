@@ -0,0 +1,140 @@
+//! Integration tests for `extract_url` against a local mock HTTP server.
+
+#![cfg(feature = "http")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::{KreuzbergError, extract_url};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+
+/// Start a mock HTTP server on an ephemeral port and return its base URL.
+///
+/// Routes:
+/// - `/memo.pdf` - 200 with `pdf/fake_memo.pdf`
+/// - `/redirect` - 302 to `/memo.pdf`
+/// - `/loop` - 302 to itself
+/// - anything else - 404
+fn start_mock_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+    let addr = listener.local_addr().unwrap();
+    let pdf = std::fs::read(get_test_file_path("pdf/fake_memo.pdf")).unwrap_or_default();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).is_err() {
+                continue;
+            }
+            let path = request_line.split_whitespace().nth(1).unwrap_or("/").to_string();
+
+            let mut header = String::new();
+            while reader.read_line(&mut header).is_ok_and(|n| n > 2) {
+                header.clear();
+            }
+
+            let (status, headers, body): (&str, String, &[u8]) = match path.as_str() {
+                "/memo.pdf" => (
+                    "200 OK",
+                    "Content-Type: application/pdf\r\n".to_string(),
+                    pdf.as_slice(),
+                ),
+                "/redirect" => ("302 Found", "Location: /memo.pdf\r\n".to_string(), b"".as_slice()),
+                "/loop" => ("302 Found", "Location: /loop\r\n".to_string(), b"".as_slice()),
+                _ => (
+                    "404 Not Found",
+                    "Content-Type: text/plain\r\n".to_string(),
+                    b"not found".as_slice(),
+                ),
+            };
+
+            let response = format!(
+                "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                headers,
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes());
+            let _ = stream.write_all(body);
+        }
+    });
+
+    format!("http://{}", addr)
+}
+
+#[cfg(feature = "pdf")]
+#[tokio::test]
+async fn test_extract_url_pdf() {
+    if skip_if_missing("pdf/fake_memo.pdf") {
+        return;
+    }
+
+    let base = start_mock_server();
+    let result = extract_url(&format!("{}/memo.pdf", base), &ExtractionConfig::default())
+        .await
+        .expect("PDF served over HTTP should extract");
+
+    assert_mime_type(&result, "application/pdf");
+    assert_non_empty_content(&result);
+}
+
+#[cfg(feature = "pdf")]
+#[tokio::test]
+async fn test_extract_url_follows_redirect() {
+    if skip_if_missing("pdf/fake_memo.pdf") {
+        return;
+    }
+
+    let base = start_mock_server();
+    let result = extract_url(&format!("{}/redirect", base), &ExtractionConfig::default())
+        .await
+        .expect("redirect should be followed");
+
+    assert_mime_type(&result, "application/pdf");
+}
+
+#[tokio::test]
+async fn test_extract_url_not_found_is_network_error() {
+    let base = start_mock_server();
+    let err = extract_url(&format!("{}/missing.pdf", base), &ExtractionConfig::default())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, KreuzbergError::Network { .. }), "got: {:?}", err);
+    assert!(err.to_string().contains("404"));
+}
+
+#[tokio::test]
+async fn test_extract_url_redirect_loop_is_network_error() {
+    let base = start_mock_server();
+    let err = extract_url(&format!("{}/loop", base), &ExtractionConfig::default())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, KreuzbergError::Network { .. }), "got: {:?}", err);
+}
+
+#[tokio::test]
+async fn test_extract_url_enforces_max_input_bytes() {
+    if skip_if_missing("pdf/fake_memo.pdf") {
+        return;
+    }
+
+    let base = start_mock_server();
+    let config = ExtractionConfig {
+        max_input_bytes: Some(1024),
+        ..Default::default()
+    };
+    let err = extract_url(&format!("{}/memo.pdf", base), &config).await.unwrap_err();
+
+    assert!(
+        matches!(err, KreuzbergError::InputTooLarge { limit: 1024, .. }),
+        "got: {:?}",
+        err
+    );
+}
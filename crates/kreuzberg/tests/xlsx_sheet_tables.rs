@@ -0,0 +1,43 @@
+//! Integration tests for per-sheet tables produced by the Excel extractor.
+
+#![cfg(feature = "excel")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+
+const TWO_SHEET_XLSX: &str = "xlsx/two_sheets_typed.xlsx";
+
+#[test]
+fn test_xlsx_emits_one_table_per_sheet() {
+    if skip_if_missing(TWO_SHEET_XLSX) {
+        return;
+    }
+
+    let result = extract_file_sync(get_test_file_path(TWO_SHEET_XLSX), None, &ExtractionConfig::default())
+        .expect("Operation failed");
+
+    let captions: Vec<Option<&str>> = result.tables.iter().map(|table| table.caption.as_deref()).collect();
+    assert_eq!(captions, vec![Some("Inventory"), Some("Totals")]);
+    assert_eq!(
+        result.tables.iter().map(|table| table.page_number).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    // Numbers are rendered with a decimal part, text is kept verbatim, formulas use their
+    // cached values, date cells are formatted, and the empty styled row and column are trimmed.
+    assert_eq!(
+        result.tables[0].cells,
+        vec![
+            vec!["Item", "Quantity", "Code", "Restocked"],
+            vec!["Widget", "42.0", "42", "2024-01-15"],
+            vec!["Gadget", "84.0", "0017", "2024-01-16"],
+        ]
+    );
+    assert_eq!(
+        result.tables[1].cells,
+        vec![vec!["Metric", "Value"], vec!["Total quantity", "126.0"]]
+    );
+}
@@ -144,11 +144,9 @@ impl DocumentExtractor for CSVExtractor {
 
         Ok(ExtractionResult {
             content: markdown,
-            mime_type: "text/csv".to_string(),
+            mime_type: "text/csv".into(),
             metadata,
-            tables: vec![],
-            detected_languages: None,
-            chunks: None,
+            ..Default::default()
         })
     }
 
@@ -205,11 +203,9 @@ impl DocumentExtractor for BinaryFormatExtractor {
 
         Ok(ExtractionResult {
             content,
-            mime_type: "application/x-custom-binary".to_string(),
+            mime_type: "application/x-custom-binary".into(),
             metadata: Metadata::default(),
-            tables: vec![],
-            detected_languages: None,
-            chunks: None,
+            ..Default::default()
         })
     }
 
@@ -67,4 +67,9 @@ defmodule Kreuzberg.Native do
   # Config discovery operations
   def config_discover, do: :erlang.nif_error(:nif_not_loaded)
   def config_from_file(_file_path), do: :erlang.nif_error(:nif_not_loaded)
+
+  # Version operations
+  def core_version, do: :erlang.nif_error(:nif_not_loaded)
+  def binding_version, do: :erlang.nif_error(:nif_not_loaded)
+  def check_compatibility, do: :erlang.nif_error(:nif_not_loaded)
 end
@@ -13,7 +13,7 @@
 //! - `config` - Configuration parsing and validation
 //! - `extraction` - Single document extraction NIFs
 //! - `batch` - Batch extraction NIFs
-//! - `utilities` - Validation, MIME detection, cache, and config NIFs
+//! - `utilities` - Validation, MIME detection, cache, config, and version NIFs
 
 mod atoms;
 pub mod batch;
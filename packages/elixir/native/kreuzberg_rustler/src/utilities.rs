@@ -1,7 +1,7 @@
 //! Utility NIFs
 //!
 //! This module provides utility Native Implemented Functions (NIFs) for validation,
-//! MIME type detection, cache management, and configuration operations.
+//! MIME type detection, cache management, configuration, and version operations.
 
 use crate::atoms;
 use rustler::types::map::map_new;
@@ -382,3 +382,34 @@ pub fn config_from_file<'a>(env: Env<'a>, file_path: String) -> NifResult<Term<'
         Err(e) => Ok((atoms::error(), format!("Failed to load config from file: {}", e)).encode(env)),
     }
 }
+
+// =============================================================================
+// VERSION FUNCTIONS
+// =============================================================================
+
+/// Version of the core kreuzberg library compiled into the NIF.
+#[rustler::nif]
+pub fn core_version() -> String {
+    kreuzberg::VERSION.to_string()
+}
+
+/// Version of the NIF binding crate.
+#[rustler::nif]
+pub fn binding_version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+/// Check that the NIF binding and core library versions are compatible.
+///
+/// Versions are compatible when their semver major versions match.
+///
+/// # Returns
+/// * `:ok` - If the versions are compatible
+/// * `{:error, reason}` - If they are not, with a message naming both versions
+#[rustler::nif]
+pub fn check_compatibility<'a>(env: Env<'a>) -> NifResult<Term<'a>> {
+    match kreuzberg::check_version_compatibility(env!("CARGO_PKG_VERSION")) {
+        Ok(()) => Ok(atoms::ok().encode(env)),
+        Err(e) => Ok((atoms::error(), e.to_string()).encode(env)),
+    }
+}
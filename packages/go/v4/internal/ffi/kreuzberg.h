@@ -2127,6 +2127,27 @@ char *kreuzberg_list_post_processors(void);
  */
 bool kreuzberg_register_validator(const char *name, ValidatorCallback callback, int32_t priority);
 
+/**
+ * Register the built-in minimum text length validator.
+ *
+ * Adds a `validation` warning to the result's warnings when the
+ * extracted text has fewer than `min_length` characters. Extraction never fails.
+ *
+ * # Safety
+ *
+ * - Returns true on success, false on error (check kreuzberg_last_error)
+ * - Replaces a previously registered `min-text-length` validator
+ *
+ * # Example (C)
+ *
+ * ```c
+ * if (!kreuzberg_register_min_text_length_validator(50)) {
+ *     printf("Failed to register: %s\n", kreuzberg_last_error());
+ * }
+ * ```
+ */
+bool kreuzberg_register_min_text_length_validator(uintptr_t min_length);
+
 /**
  * Unregister a Validator by name.
  *
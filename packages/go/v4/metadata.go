@@ -13,6 +13,8 @@ var metadataCoreKeys = map[string]struct{}{
 	"created_by":          {},
 	"modified_by":         {},
 	"pages":               {},
+	"outline":             {},
+	"document_kind":       {},
 	"format_type":         {},
 	"image_preprocessing": {},
 	"json_schema":         {},
@@ -23,7 +25,7 @@ var formatFieldSets = map[FormatType][]string{
 	FormatPDF: {
 		"title", "subject", "authors", "keywords", "created_at", "modified_at",
 		"created_by", "producer", "page_count", "pdf_version", "is_encrypted",
-		"width", "height", "summary",
+		"width", "height", "summary", "form_fields", "page_reports",
 	},
 	FormatExcel:   {"sheet_count", "sheet_names"},
 	FormatEmail:   {"from_email", "from_name", "to_emails", "cc_emails", "bcc_emails", "message_id", "attachments"},
@@ -88,6 +90,20 @@ func (m *Metadata) UnmarshalJSON(data []byte) error {
 		}
 	}
 
+	if value, ok := raw["outline"]; ok {
+		var outline []OutlineEntry
+		if err := json.Unmarshal(value, &outline); err == nil {
+			m.Outline = outline
+		}
+	}
+
+	if value, ok := raw["document_kind"]; ok {
+		var kind DocumentKind
+		if err := json.Unmarshal(value, &kind); err == nil {
+			m.DocumentKind = &kind
+		}
+	}
+
 	if value, ok := raw["image_preprocessing"]; ok {
 		var meta ImagePreprocessingMetadata
 		if err := json.Unmarshal(value, &meta); err == nil {
@@ -171,6 +187,12 @@ func (m Metadata) MarshalJSON() ([]byte, error) {
 	if m.Pages != nil {
 		out["pages"] = m.Pages
 	}
+	if len(m.Outline) > 0 {
+		out["outline"] = m.Outline
+	}
+	if m.DocumentKind != nil {
+		out["document_kind"] = *m.DocumentKind
+	}
 	if m.ImagePreprocessing != nil {
 		out["image_preprocessing"] = m.ImagePreprocessing
 	}
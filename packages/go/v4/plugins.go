@@ -107,6 +107,15 @@ func RegisterValidator(name string, priority int32, callback C.ValidatorCallback
 	return nil
 }
 
+// RegisterMinTextLengthValidator registers the built-in validator that adds a warning to
+// the result metadata when the extracted text has fewer than minLength characters.
+func RegisterMinTextLengthValidator(minLength uint) error {
+	if ok := C.kreuzberg_register_min_text_length_validator(C.uintptr_t(minLength)); !bool(ok) {
+		return lastError()
+	}
+	return nil
+}
+
 // UnregisterValidator deregisters a validator by name.
 func UnregisterValidator(name string) error {
 	if name == "" {
@@ -68,6 +68,8 @@ type Metadata struct {
 	CreatedBy          *string                     `json:"created_by,omitempty"`
 	ModifiedBy         *string                     `json:"modified_by,omitempty"`
 	Pages              *PageStructure              `json:"pages,omitempty"`
+	Outline            []OutlineEntry              `json:"outline,omitempty"`
+	DocumentKind       *DocumentKind               `json:"document_kind,omitempty"`
 	Format             FormatMetadata              `json:"-"`
 	ImagePreprocessing *ImagePreprocessingMetadata `json:"image_preprocessing,omitempty"`
 	JSONSchema         json.RawMessage             `json:"json_schema,omitempty"`
@@ -164,20 +166,30 @@ func (m Metadata) OcrMetadata() (*OcrMetadata, bool) {
 
 // PdfMetadata contains metadata extracted from PDF documents.
 type PdfMetadata struct {
-	Title       *string  `json:"title,omitempty"`
-	Subject     *string  `json:"subject,omitempty"`
-	Authors     []string `json:"authors,omitempty"`
-	Keywords    []string `json:"keywords,omitempty"`
-	CreatedAt   *string  `json:"created_at,omitempty"`
-	ModifiedAt  *string  `json:"modified_at,omitempty"`
-	CreatedBy   *string  `json:"created_by,omitempty"`
-	Producer    *string  `json:"producer,omitempty"`
-	PageCount   *int     `json:"page_count,omitempty"`
-	PDFVersion  *string  `json:"pdf_version,omitempty"`
-	IsEncrypted *bool    `json:"is_encrypted,omitempty"`
-	Width       *int64   `json:"width,omitempty"`
-	Height      *int64   `json:"height,omitempty"`
-	Summary     *string  `json:"summary,omitempty"`
+	Title       *string           `json:"title,omitempty"`
+	Subject     *string           `json:"subject,omitempty"`
+	Authors     []string          `json:"authors,omitempty"`
+	Keywords    []string          `json:"keywords,omitempty"`
+	CreatedAt   *string           `json:"created_at,omitempty"`
+	ModifiedAt  *string           `json:"modified_at,omitempty"`
+	CreatedBy   *string           `json:"created_by,omitempty"`
+	Producer    *string           `json:"producer,omitempty"`
+	PageCount   *int              `json:"page_count,omitempty"`
+	PDFVersion  *string           `json:"pdf_version,omitempty"`
+	IsEncrypted *bool             `json:"is_encrypted,omitempty"`
+	Width       *int64            `json:"width,omitempty"`
+	Height      *int64            `json:"height,omitempty"`
+	Summary     *string           `json:"summary,omitempty"`
+	FormFields  map[string]string `json:"form_fields,omitempty"`
+	PageReports []PdfPageReport   `json:"page_reports,omitempty"`
+}
+
+// PdfPageReport describes the text yield of a single PDF page.
+type PdfPageReport struct {
+	Page      int  `json:"page"`
+	CharCount int  `json:"char_count"`
+	HasImages bool `json:"has_images"`
+	OcrUsed   bool `json:"ocr_used"`
 }
 
 // ExcelMetadata lists sheets inside spreadsheet documents.
@@ -397,6 +409,22 @@ type PageStructure struct {
 	Pages      []PageInfo     `json:"pages,omitempty"`
 }
 
+// OutlineEntry is a document outline (bookmark) entry.
+type OutlineEntry struct {
+	Title string `json:"title"`
+	Level int    `json:"level"`
+	Page  *int   `json:"page,omitempty"`
+}
+
+// DocumentKind describes whether a document's text is digital or scanned.
+type DocumentKind string
+
+const (
+	DocumentKindDigital DocumentKind = "digital"
+	DocumentKindScanned DocumentKind = "scanned"
+	DocumentKindMixed   DocumentKind = "mixed"
+)
+
 // HierarchicalBlock represents a text block with hierarchy level assignment.
 type HierarchicalBlock struct {
 	Text     string      `json:"text"`
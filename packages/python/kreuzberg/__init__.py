@@ -35,7 +35,7 @@ import hashlib
 import json
 import threading
 from importlib.metadata import version
-from typing import TYPE_CHECKING, Any
+from typing import TYPE_CHECKING, Any, Literal
 
 # ~keep: This must be imported FIRST before any Rust bindings
 # ~keep: It sets up dynamic library paths for bundled native libraries (pdfium, etc.)
@@ -73,6 +73,7 @@ from kreuzberg._internal_bindings import (
     config_get_field,
     config_merge,
     config_to_json,
+    detect_language,
     detect_mime_type_from_bytes,
     get_embedding_preset,
     get_extensions_for_mime,
@@ -86,6 +87,7 @@ from kreuzberg._internal_bindings import (
     list_ocr_backends,
     list_post_processors,
     list_validators,
+    register_min_text_length_validator,
     unregister_document_extractor,
     unregister_ocr_backend,
     unregister_post_processor,
@@ -117,6 +119,9 @@ from kreuzberg._internal_bindings import (
 from kreuzberg._internal_bindings import (
     classify_error as _classify_error_impl,
 )
+from kreuzberg._internal_bindings import (
+    detect_mime as _detect_mime_impl,
+)
 from kreuzberg._internal_bindings import (
     detect_mime_type_from_path as _detect_mime_type_from_path_impl,
 )
@@ -263,6 +268,8 @@ __all__ = [
     "config_merge",
     "config_to_json",
     "deprecated",
+    "detect_language",
+    "detect_mime",
     "detect_mime_type",
     "detect_mime_type_from_path",
     "discover_extraction_config",
@@ -286,6 +293,7 @@ __all__ = [
     "list_post_processors",
     "list_validators",
     "load_extraction_config_from_file",
+    "register_min_text_length_validator",
     "register_ocr_backend",
     "register_post_processor",
     "register_validator",
@@ -482,7 +490,7 @@ def batch_extract_files_sync(
         paddleocr_kwargs: PaddleOCR initialization options
 
     Returns:
-        List of ExtractionResults (one per file)
+        List of ExtractionResults (one per file, in the same order as ``paths``)
     """
     if config is None:
         config = ExtractionConfig()
@@ -510,7 +518,7 @@ def batch_extract_bytes_sync(
         paddleocr_kwargs: PaddleOCR initialization options
 
     Returns:
-        List of ExtractionResults (one per data item)
+        List of ExtractionResults (one per data item, in the same order as ``data_list``)
     """
     if config is None:
         config = ExtractionConfig()
@@ -592,7 +600,7 @@ async def batch_extract_files(
         paddleocr_kwargs: PaddleOCR initialization options
 
     Returns:
-        List of ExtractionResults (one per file)
+        List of ExtractionResults (one per file, in the same order as ``paths``)
     """
     if config is None:
         config = ExtractionConfig()
@@ -620,7 +628,7 @@ async def batch_extract_bytes(
         paddleocr_kwargs: PaddleOCR initialization options
 
     Returns:
-        List of ExtractionResults (one per data item)
+        List of ExtractionResults (one per data item, in the same order as ``data_list``)
     """
     if config is None:
         config = ExtractionConfig()
@@ -671,6 +679,37 @@ def detect_mime_type_from_path(path: str | Path) -> str:
     return _detect_mime_type_from_path_impl(str(path))
 
 
+def detect_mime(
+    path: str | Path,
+    data: bytes | bytearray,
+    policy: Literal["prefer_content", "prefer_extension", "strict"] = "prefer_content",
+) -> tuple[str, bool]:
+    """Detect MIME type from both a file path and its content.
+
+    The extension-based and content-based MIME types are compared. Text content is
+    considered compatible with any text-based extension (e.g. Markdown, CSV). When the
+    two disagree, ``policy`` decides which one wins.
+
+    Args:
+        path: File path or name whose extension is checked (need not exist)
+        data: File content as bytes or bytearray
+        policy: "prefer_content" (default), "prefer_extension", or "strict" to raise on mismatch
+
+    Returns:
+        Tuple of the chosen MIME type and whether content and extension disagreed
+
+    Raises:
+        ValueError: If the policy is invalid
+        RuntimeError: If the sources disagree under "strict", or no MIME type can be determined
+
+    Example:
+        >>> from kreuzberg import detect_mime
+        >>> mime_type, mismatch = detect_mime("upload.txt", b"%PDF-1.4\\n")
+        >>> assert mime_type == "application/pdf" and mismatch
+    """
+    return _detect_mime_impl(str(path), bytes(data), policy)
+
+
 def discover_extraction_config() -> ExtractionConfig | None:
     """Discover extraction configuration from the environment.
 
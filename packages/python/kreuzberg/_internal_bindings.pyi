@@ -50,6 +50,7 @@ __all__ = [
     "KeywordAlgorithm",
     "KeywordConfig",
     "LanguageDetectionConfig",
+    "LanguageGuess",
     "LinkMetadata",
     "Metadata",
     "MissingDependencyError",
@@ -90,6 +91,8 @@ __all__ = [
     "config_get_field",
     "config_merge",
     "config_to_json",
+    "detect_language",
+    "detect_mime",
     "detect_mime_type_from_bytes",
     "detect_mime_type_from_path",
     "error_code_name",
@@ -139,6 +142,10 @@ class MissingDependencyError(Exception): ...
 
 def init_async_runtime() -> None: ...
 
+class LanguageGuess(TypedDict):
+    code: str
+    confidence: float
+
 class ErrorDetails(TypedDict):
     message: str
     error_code: int
@@ -239,6 +246,20 @@ class ExtractionConfig:
         force_ocr (bool): Force OCR processing even for searchable PDFs that contain
             extractable text. Useful for ensuring consistent formatting. Default: False
 
+        detect_image_tables (bool): Reconstruct tables from OCR word layout on scanned
+            pages and images. Heuristic and slower. Default: False
+
+        ocr_embedded_images (bool): OCR images embedded in PDF pages and append their
+            text to the content as captioned blocks. Images reused on several pages are
+            recognized once. Costly. Default: False
+
+        ocr_optional (bool): Extract without OCR instead of raising when the OCR
+            backend is unavailable. The embedded text is returned with an
+            "ocr_unavailable" warning. Default: False
+
+        encoding_override (str | None): Encoding label for plain-text and HTML input
+            (e.g. "windows-1252"). None = detect automatically. Default: None
+
         chunking (ChunkingConfig | None): Text chunking configuration for dividing
             content into manageable chunks. None = chunking disabled. Default: None
 
@@ -270,6 +291,46 @@ class ExtractionConfig:
         max_concurrent_extractions (int | None): Maximum concurrent extractions
             in batch operations. None = num_cpus * 2. Default: None
 
+        force_single_threaded (bool): Process batch extractions sequentially instead
+            of in parallel. Default: False
+
+        max_input_bytes (int | None): Maximum input size in bytes. Larger files and
+            byte inputs raise ValidationError before being read. None = unlimited. Default: None
+
+        max_pages (int | None): Maximum number of pages to extract. Later pages are dropped
+            and a "truncated" warning is added to the result. Currently enforced for PDFs.
+            None = all pages. Default: None
+
+        allow_partial (bool): Return the pages read before a damaged page instead of
+            raising. The result has partial=True and a "partial_content" warning.
+            Currently supported for PDFs. Default: False
+
+        detect_language (bool): Detect the primary content language and store it in
+            metadata["language"] when the document does not declare one. Default: False
+
+        extract_form_fields (bool): Append filled PDF form field values to the content
+            as a labeled section. Values are always reported in metadata["form_fields"].
+            Default: False
+
+        normalize_whitespace (bool): Collapse repeated and non-breaking spaces, join words
+            hyphenated across line breaks, and normalize line endings in the content.
+            Paragraph breaks, indentation, and fenced code blocks are preserved. Default: False
+
+        csv_delimiter (str | None): Single-character field delimiter for CSV and TSV input.
+            Detected from the data when None (TSV defaults to tab). Default: None
+
+        strip_running_headers (bool): Remove running headers and footers (page numbers,
+            repeated titles) from PDF text. Lines repeated at the same position on more than
+            half of the pages are stripped; documents under three pages are unchanged.
+            Default: False
+
+        include_layout (bool): Report every word with its page number and bounding box
+            (PDF points, bottom-left origin) in the result's ``layout``. Currently populated
+            for PDFs only. Default: False
+
+        include_speaker_notes (bool): Append speaker notes from ``ppt/notesSlides`` to
+            their slide in presentation output. Default: True
+
         html_options (HtmlConversionOptions | None): HTML conversion options for
             converting documents to markdown. Default: None
 
@@ -301,6 +362,10 @@ class ExtractionConfig:
     enable_quality_processing: bool
     ocr: OcrConfig | None
     force_ocr: bool
+    detect_image_tables: bool
+    ocr_embedded_images: bool
+    ocr_optional: bool
+    encoding_override: str | None
     chunking: ChunkingConfig | None
     images: ImageExtractionConfig | None
     pdf_options: PdfConfig | None
@@ -309,6 +374,17 @@ class ExtractionConfig:
     keywords: KeywordConfig | None
     postprocessor: PostProcessorConfig | None
     max_concurrent_extractions: int | None
+    force_single_threaded: bool
+    max_input_bytes: int | None
+    max_pages: int | None
+    allow_partial: bool
+    detect_language: bool
+    extract_form_fields: bool
+    normalize_whitespace: bool
+    csv_delimiter: str | None
+    strip_running_headers: bool
+    include_layout: bool
+    include_speaker_notes: bool
     html_options: HtmlConversionOptions | None
     pages: PageConfig | None
     result_format: str
@@ -333,6 +409,21 @@ class ExtractionConfig:
         pages: PageConfig | None = None,
         result_format: str | None = None,
         output_format: str | None = None,
+        detect_image_tables: bool | None = None,
+        ocr_embedded_images: bool | None = None,
+        ocr_optional: bool | None = None,
+        encoding_override: str | None = None,
+        force_single_threaded: bool | None = None,
+        max_input_bytes: int | None = None,
+        max_pages: int | None = None,
+        allow_partial: bool | None = None,
+        detect_language: bool | None = None,
+        extract_form_fields: bool | None = None,
+        normalize_whitespace: bool | None = None,
+        csv_delimiter: str | None = None,
+        strip_running_headers: bool | None = None,
+        include_layout: bool | None = None,
+        include_speaker_notes: bool | None = None,
     ) -> None: ...
     @staticmethod
     def from_file(path: str | Path) -> ExtractionConfig: ...
@@ -693,6 +784,15 @@ class HierarchyConfig:
         ocr_coverage_threshold (float | None): Optional threshold for OCR coverage
             before enabling hierarchy detection. Default: None
 
+        max_columns (int): Maximum number of text columns detected per page. The
+            narrowest columns are merged when more are found. Default: 3
+
+        min_column_width (float): Minimum column width in PDF points; narrower column
+            candidates are merged into a neighbour. Default: 50.0
+
+        use_outline (bool): Take heading levels from the PDF outline (bookmarks) when
+            the document has one, instead of font size clustering. Default: False
+
     Example:
         Basic hierarchy detection:
             >>> from kreuzberg import ExtractionConfig, HierarchyConfig
@@ -709,6 +809,9 @@ class HierarchyConfig:
     k_clusters: int
     include_bbox: bool
     ocr_coverage_threshold: float | None
+    max_columns: int
+    min_column_width: float
+    use_outline: bool
 
     def __init__(
         self,
@@ -717,6 +820,9 @@ class HierarchyConfig:
         k_clusters: int | None = None,
         include_bbox: bool | None = None,
         ocr_coverage_threshold: float | None = None,
+        max_columns: int | None = None,
+        min_column_width: float | None = None,
+        use_outline: bool | None = None,
     ) -> None: ...
 
 class PageConfig:
@@ -1165,6 +1271,12 @@ class TesseractConfig:
         thresholding_method: bool | None = None,
     ) -> None: ...
 
+class PdfPageReport(TypedDict):
+    page: int
+    char_count: int
+    has_images: bool
+    ocr_used: bool
+
 class PdfMetadata(TypedDict, total=False):
     pdf_version: str
     producer: str
@@ -1172,6 +1284,8 @@ class PdfMetadata(TypedDict, total=False):
     width: int
     height: int
     page_count: int
+    form_fields: dict[str, str]
+    page_reports: list[PdfPageReport]
 
 class ExcelMetadata(TypedDict, total=False):
     sheet_count: int
@@ -1305,6 +1419,11 @@ class PageStructure(TypedDict, total=False):
     boundaries: list[PageBoundary] | None
     pages: list[PageInfo] | None
 
+class OutlineEntry(TypedDict, total=False):
+    title: str
+    level: int
+    page: int | None
+
 class Metadata(TypedDict, total=False):
     # Common fields (set directly on all extractions)
     title: str
@@ -1317,6 +1436,8 @@ class Metadata(TypedDict, total=False):
     created_by: str
     modified_by: str
     pages: PageStructure
+    outline: list[OutlineEntry]
+    document_kind: Literal["digital", "scanned", "mixed"]
 
     # Format discriminator (from serde tag)
     format_type: Literal["pdf", "excel", "email", "pptx", "archive", "image", "xml", "text", "html", "ocr"]
@@ -1328,6 +1449,8 @@ class Metadata(TypedDict, total=False):
     width: int | None
     height: int | None
     page_count: int | None
+    form_fields: dict[str, str] | None
+    page_reports: list[PdfPageReport] | None
 
     # Excel-specific (flattened from ExcelMetadata)
     sheet_count: int
@@ -1521,6 +1644,7 @@ class ExtractionResult:
     djot_content: DjotContent | None
     output_format: str | None
     result_format: str | None
+    partial: bool
     def get_page_count(self) -> int: ...
     def get_chunk_count(self) -> int: ...
     def get_detected_language(self) -> str | None: ...
@@ -1536,6 +1660,7 @@ class ExtractedTable:
     cells: list[list[str]]
     markdown: str
     page_number: int
+    caption: str | None
 
 @overload
 def extract_file_sync(
@@ -1594,6 +1719,7 @@ def register_post_processor(processor: PostProcessorProtocol) -> None: ...
 def clear_post_processors() -> None: ...
 def unregister_post_processor(name: str) -> None: ...
 def register_validator(validator: ValidatorProtocol) -> None: ...
+def register_min_text_length_validator(min_length: int) -> None: ...
 def clear_validators() -> None: ...
 def unregister_validator(name: str) -> None: ...
 def list_embedding_presets() -> list[str]: ...
@@ -1602,6 +1728,7 @@ def clear_document_extractors() -> None: ...
 def clear_ocr_backends() -> None: ...
 def detect_mime_type_from_bytes(data: bytes) -> str: ...
 def detect_mime_type_from_path(path: str | Path) -> str: ...
+def detect_mime(path: str, data: bytes, policy: str = "prefer_content") -> tuple[str, bool]: ...
 def validate_mime_type(mime_type: str) -> str: ...
 def get_extensions_for_mime(mime_type: str) -> list[str]: ...
 def list_document_extractors() -> list[str]: ...
@@ -1615,6 +1742,7 @@ def get_last_panic_context() -> str | None: ...
 def validate_binarization_method(method: str) -> bool: ...
 def validate_ocr_backend(backend: str) -> bool: ...
 def validate_language_code(code: str) -> bool: ...
+def detect_language(text: str) -> LanguageGuess | None: ...
 def validate_token_reduction_level(level: str) -> bool: ...
 def validate_tesseract_psm(psm: int) -> bool: ...
 def validate_tesseract_oem(oem: int) -> bool: ...
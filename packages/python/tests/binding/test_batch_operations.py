@@ -11,12 +11,16 @@ Tests cover:
 
 from __future__ import annotations
 
+import asyncio
 from typing import TYPE_CHECKING
 
 import pytest
 
 from kreuzberg import (
     ExtractionConfig,
+    batch_extract_bytes,
+    batch_extract_bytes_sync,
+    batch_extract_files_sync,
     extract_bytes_sync,
     extract_file_sync,
 )
@@ -389,3 +393,53 @@ class TestBatchContentPreservation:
         assert len(results) == 4
         for result in results:
             assert result is not None
+
+
+class TestBatchOrdering:
+    """Test that batch results map positionally to their inputs."""
+
+    @staticmethod
+    def _documents(count: int) -> list[bytes]:
+        # Larger documents come first so later ones tend to finish earlier.
+        return [(f"document {i} " * ((count - i) * 500)).encode() for i in range(count)]
+
+    @staticmethod
+    def _order_preserved(contents: list[str]) -> bool:
+        return all(content.startswith(f"document {i} ") for i, content in enumerate(contents))
+
+    def test_batch_bytes_sync_preserves_input_order(self) -> None:
+        """results[i] corresponds to data_list[i] for the sync bytes batch."""
+        config = ExtractionConfig(max_concurrent_extractions=8)
+        documents = self._documents(32)
+
+        results = batch_extract_bytes_sync(documents, ["text/plain"] * len(documents), config)
+
+        assert len(results) == len(documents)
+        order_preserved = self._order_preserved([result.content for result in results])
+        assert order_preserved
+
+    def test_batch_bytes_async_preserves_input_order(self) -> None:
+        """results[i] corresponds to data_list[i] for the async bytes batch."""
+        config = ExtractionConfig(max_concurrent_extractions=8)
+        documents = self._documents(32)
+
+        results = asyncio.run(batch_extract_bytes(documents, ["text/plain"] * len(documents), config))
+
+        assert len(results) == len(documents)
+        order_preserved = self._order_preserved([result.content for result in results])
+        assert order_preserved
+
+    def test_batch_files_sync_preserves_input_order(self, tmp_path: Path) -> None:
+        """results[i] corresponds to paths[i] for the sync file batch."""
+        config = ExtractionConfig(max_concurrent_extractions=8)
+        paths: list[str | Path] = []
+        for i, document in enumerate(self._documents(16)):
+            path = tmp_path / f"document_{i}.txt"
+            path.write_bytes(document)
+            paths.append(path)
+
+        results = batch_extract_files_sync(paths, config)
+
+        assert len(results) == len(paths)
+        order_preserved = self._order_preserved([result.content for result in results])
+        assert order_preserved
@@ -45,6 +45,12 @@ pub fn parse_ocr_config(ruby: &Ruby, hash: RHash) -> Result<OcrConfig, Error> {
         language,
         tesseract_config: None,
         output_format: None,
+        dpi: None,
+        psm: None,
+        oem: None,
+        binarization: None,
+        ocr_pages: None,
+        language_fallback: None,
     };
 
     if let Some(val) = get_kw(ruby, hash, "tesseract_config")
@@ -163,11 +169,35 @@ pub fn parse_hierarchy_config(ruby: &Ruby, hash: RHash) -> Result<HierarchyConfi
         None
     };
 
+    let defaults = HierarchyConfig::default();
+
+    let max_columns = if let Some(val) = get_kw(ruby, hash, "max_columns") {
+        usize::try_convert(val)?
+    } else {
+        defaults.max_columns
+    };
+
+    let min_column_width = if let Some(val) = get_kw(ruby, hash, "min_column_width") {
+        f64::try_convert(val)? as f32
+    } else {
+        defaults.min_column_width
+    };
+
+    let use_outline = if let Some(val) = get_kw(ruby, hash, "use_outline") {
+        bool::try_convert(val)?
+    } else {
+        defaults.use_outline
+    };
+
     let config = HierarchyConfig {
         enabled,
         k_clusters,
         include_bbox,
         ocr_coverage_threshold,
+        max_columns,
+        min_column_width,
+        column_mode: defaults.column_mode,
+        use_outline,
     };
 
     Ok(config)
@@ -869,6 +899,24 @@ pub fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extra
             config.force_ocr = bool::try_convert(val)?;
         }
 
+        if let Some(val) = get_kw(ruby, hash, "detect_image_tables") {
+            config.detect_image_tables = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "ocr_embedded_images") {
+            config.ocr_embedded_images = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "ocr_optional") {
+            config.ocr_optional = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "encoding_override")
+            && !val.is_nil()
+        {
+            config.encoding_override = Some(String::try_convert(val)?);
+        }
+
         if let Some(val) = get_kw(ruby, hash, "ocr")
             && !val.is_nil()
         {
@@ -944,6 +992,66 @@ pub fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extra
             config.max_concurrent_extractions = Some(value);
         }
 
+        if let Some(val) = get_kw(ruby, hash, "force_single_threaded") {
+            config.force_single_threaded = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "max_input_bytes")
+            && !val.is_nil()
+        {
+            config.max_input_bytes = Some(u64::try_convert(val)?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "max_pages")
+            && !val.is_nil()
+        {
+            config.max_pages = Some(usize::try_convert(val)?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "allow_partial") {
+            config.allow_partial = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "detect_language") {
+            config.detect_language = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "extract_form_fields") {
+            config.extract_form_fields = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "normalize_whitespace") {
+            config.normalize_whitespace = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "strip_running_headers") {
+            config.strip_running_headers = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "include_layout") {
+            config.include_layout = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "include_speaker_notes") {
+            config.include_speaker_notes = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "csv_delimiter")
+            && !val.is_nil()
+        {
+            let delimiter_str = String::try_convert(val)?;
+            let mut chars = delimiter_str.chars();
+            config.csv_delimiter = match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => {
+                    return Err(runtime_error(format!(
+                        "Invalid csv_delimiter: '{}'. Expected a single character",
+                        delimiter_str
+                    )));
+                }
+            };
+        }
+
         if let Some(val) = get_kw(ruby, hash, "result_format") {
             let format_str = String::try_convert(val)?;
             config.result_format = match format_str.as_str() {
@@ -30,6 +30,8 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
     let mime_value = ruby.str_new(result.mime_type.as_ref()).into_value_with(ruby);
     set_hash_entry(ruby, &hash, "mime_type", mime_value)?;
 
+    set_hash_entry(ruby, &hash, "partial", result.partial.into_value_with(ruby))?;
+
     // Set metadata both as JSON string and parsed hash
     let metadata_json = serde_json::to_string(&result.metadata)
         .map_err(|e| runtime_error(format!("Failed to serialize metadata: {}", e)))?;
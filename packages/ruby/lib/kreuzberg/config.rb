@@ -222,18 +222,25 @@ module Kreuzberg
     #   hierarchy = Hierarchy.new(enabled: true, k_clusters: 6, include_bbox: true)
     #
     class Hierarchy
-      attr_reader :enabled, :k_clusters, :include_bbox, :ocr_coverage_threshold
+      attr_reader :enabled, :k_clusters, :include_bbox, :ocr_coverage_threshold, :max_columns, :min_column_width,
+                  :use_outline
 
       def initialize(
         enabled: true,
         k_clusters: 6,
         include_bbox: true,
-        ocr_coverage_threshold: nil
+        ocr_coverage_threshold: nil,
+        max_columns: 3,
+        min_column_width: 50.0,
+        use_outline: false
       )
         @enabled = enabled ? true : false
         @k_clusters = k_clusters&.to_i || 6
         @include_bbox = include_bbox ? true : false
         @ocr_coverage_threshold = ocr_coverage_threshold&.to_f
+        @max_columns = max_columns&.to_i || 3
+        @min_column_width = min_column_width&.to_f || 50.0
+        @use_outline = use_outline ? true : false
       end
 
       def to_h
@@ -241,7 +248,10 @@ module Kreuzberg
           enabled: @enabled,
           k_clusters: @k_clusters,
           include_bbox: @include_bbox,
-          ocr_coverage_threshold: @ocr_coverage_threshold
+          ocr_coverage_threshold: @ocr_coverage_threshold,
+          max_columns: @max_columns,
+          min_column_width: @min_column_width,
+          use_outline: @use_outline
         }.compact
       end
 
@@ -11,7 +11,7 @@ module Kreuzberg
   # rubocop:disable Metrics/ClassLength
   class Result
     attr_reader :content, :mime_type, :metadata, :metadata_json, :tables,
-                :detected_languages, :chunks, :images, :pages, :elements, :djot_content
+                :detected_languages, :chunks, :images, :pages, :elements, :djot_content, :partial
 
     # @!attribute [r] cells
     #   @return [Array<Array<String>>] Table cells (2D array)
@@ -228,6 +228,7 @@ module Kreuzberg
       @pages = parse_pages(get_value(hash, 'pages'))
       @elements = parse_elements(get_value(hash, 'elements'))
       @djot_content = parse_djot_content(get_value(hash, 'djot_content'))
+      @partial = get_value(hash, 'partial', false) == true
     end
     # rubocop:enable Metrics/AbcSize
 
@@ -246,7 +247,8 @@ module Kreuzberg
         images: serialize_images,
         pages: serialize_pages,
         elements: serialize_elements,
-        djot_content: @djot_content&.to_h
+        djot_content: @djot_content&.to_h,
+        partial: @partial
       }
     end
 
@@ -245,6 +245,33 @@ RSpec.describe Kreuzberg do
     end
   end
 
+  describe 'batch result ordering' do
+    # Larger documents come first so later ones tend to finish earlier.
+    let(:data) { Array.new(32) { |i| "document #{i} " * ((32 - i) * 500) } }
+    let(:mime_types) { ['text/plain'] * 32 }
+    let(:config) { Kreuzberg::Config::Extraction.new(max_concurrent_extractions: 8) }
+
+    def order_preserved?(results)
+      results.each_with_index.all? { |result, idx| result.content.start_with?("document #{idx} ") }
+    end
+
+    it 'maps sync byte results positionally to inputs' do
+      results = described_class.batch_extract_bytes_sync(data_array: data, mime_types: mime_types, config: config)
+
+      expect(results.length).to eq(data.length)
+      order_preserved = order_preserved?(results)
+      expect(order_preserved).to be(true)
+    end
+
+    it 'maps async byte results positionally to inputs' do
+      results = described_class.batch_extract_bytes(data_array: data, mime_types: mime_types, config: config)
+
+      expect(results.length).to eq(data.length)
+      order_preserved = order_preserved?(results)
+      expect(order_preserved).to be(true)
+    end
+  end
+
   describe 'batch performance characteristics' do
     it 'processes batch operations efficiently' do
       paths = []
@@ -13,6 +13,7 @@ export interface Table {
 	cells: string[][];
 	markdown: string;
 	pageNumber: number;
+	caption?: string | null;
 }
 
 export interface ChunkMetadata {
@@ -201,6 +202,8 @@ export interface DjotImage {
 	title?: string | null;
 	/** Image attributes */
 	attributes?: Attributes | null;
+	/** Figure caption */
+	caption?: string | null;
 }
 
 /**
@@ -24,6 +24,17 @@ pub trait FrameworkAdapter: Send + Sync {
     /// * `file_type` - File extension without dot (e.g., "pdf", "docx")
     fn supports_format(&self, file_type: &str) -> bool;
 
+    /// Check if this adapter can process a specific document
+    ///
+    /// Called in addition to [`FrameworkAdapter::supports_format`] for adapters that only
+    /// handle a subset of fixtures (e.g., those with a remote URL). Default is true.
+    ///
+    /// # Arguments
+    /// * `document_path` - Resolved path of the fixture document
+    fn supports_document(&self, _document_path: &Path) -> bool {
+        true
+    }
+
     /// Extract content from a document
     ///
     /// # Arguments
@@ -80,6 +91,15 @@ pub trait FrameworkAdapter: Send + Sync {
         Ok(())
     }
 
+    /// Reset process-level caches warmed by previous extractions
+    ///
+    /// Called before each framework run when `BenchmarkConfig::reset_caches_between_frameworks`
+    /// is set. Subprocess-based adapters already run in their own processes, so the default
+    /// implementation does nothing.
+    async fn reset_caches(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// Warm up the framework by performing a test extraction
     ///
     /// This is called once before benchmarking to get the framework into a warm state.
@@ -7,6 +7,7 @@ pub mod node;
 pub mod python;
 pub mod ruby;
 pub mod subprocess;
+pub mod url;
 
 pub use external::{
     create_docling_adapter, create_markitdown_adapter, create_mineru_adapter, create_pandoc_adapter,
@@ -24,6 +25,7 @@ pub use node::NodeAdapter;
 pub use python::PythonAdapter;
 pub use ruby::RubyAdapter;
 pub use subprocess::SubprocessAdapter;
+pub use url::UrlAdapter;
 
 /// Returns the OCR flag string based on the provided boolean
 pub(crate) fn ocr_flag(ocr_enabled: bool) -> String {
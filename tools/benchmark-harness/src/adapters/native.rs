@@ -22,7 +22,7 @@ use std::time::{Duration, Instant};
 /// - `OcrStatus::Used` if OCR metadata is present, or if this is an image with OCR enabled
 /// - `OcrStatus::NotUsed` if format metadata is present and OCR was not involved
 /// - `OcrStatus::Unknown` if no format metadata is available
-fn determine_ocr_status(result: &ExtractionResult, config: &ExtractionConfig) -> OcrStatus {
+pub(crate) fn determine_ocr_status(result: &ExtractionResult, config: &ExtractionConfig) -> OcrStatus {
     match &result.metadata.format {
         Some(FormatMetadata::Ocr(_)) => OcrStatus::Used,
         Some(FormatMetadata::Image(_)) => {
@@ -130,10 +130,10 @@ impl FrameworkAdapter for NativeAdapter {
         // Start extraction timing (same as total for native - no subprocess overhead)
         let extraction_start = Instant::now();
 
-        let extraction_result = tokio::time::timeout(timeout, extract_file(file_path, None, &self.config))
-            .await
-            .map_err(|_| Error::Timeout(format!("Extraction exceeded {:?}", timeout)))?
-            .map_err(|e| Error::Benchmark(format!("Extraction failed: {}", e)));
+        let extraction_result = match tokio::time::timeout(timeout, extract_file(file_path, None, &self.config)).await {
+            Ok(result) => result.map_err(|e| Error::Benchmark(format!("Extraction failed: {}", e))),
+            Err(_) => Err(Error::Timeout(format!("Extraction exceeded {:?}", timeout))),
+        };
 
         let extraction_duration = extraction_start.elapsed();
         let duration = start.elapsed();
@@ -163,6 +163,7 @@ impl FrameworkAdapter for NativeAdapter {
                 success: false,
                 error_message: Some(e.to_string()),
                 error_kind: ErrorKind::HarnessError,
+                timed_out: matches!(e, Error::Timeout(_)),
                 duration,
                 extraction_duration: Some(extraction_duration),
                 subprocess_overhead: Some(Duration::ZERO), // No subprocess for native Rust
@@ -209,6 +210,7 @@ impl FrameworkAdapter for NativeAdapter {
             success: true,
             error_message: None,
             error_kind: ErrorKind::None,
+            timed_out: false,
             duration,
             extraction_duration: Some(extraction_duration),
             subprocess_overhead: Some(Duration::ZERO), // No subprocess for native Rust
@@ -249,10 +251,10 @@ impl FrameworkAdapter for NativeAdapter {
 
         let paths: Vec<PathBuf> = file_paths.iter().map(|p| p.to_path_buf()).collect();
 
-        let batch_result = tokio::time::timeout(timeout, batch_extract_file(paths.clone(), &self.config))
-            .await
-            .map_err(|_| Error::Timeout(format!("Batch extraction exceeded {:?}", timeout)))?
-            .map_err(|e| Error::Benchmark(format!("Batch extraction failed: {}", e)));
+        let batch_result = match tokio::time::timeout(timeout, batch_extract_file(paths.clone(), &self.config)).await {
+            Ok(result) => result.map_err(|e| Error::Benchmark(format!("Batch extraction failed: {}", e))),
+            Err(_) => Err(Error::Timeout(format!("Batch extraction exceeded {:?}", timeout))),
+        };
 
         let total_duration = start.elapsed();
 
@@ -283,6 +285,7 @@ impl FrameworkAdapter for NativeAdapter {
                         success: false,
                         error_message: Some(e.to_string()),
                         error_kind: ErrorKind::HarnessError,
+                        timed_out: matches!(e, Error::Timeout(_)),
                         duration: avg_duration_per_file,
                         extraction_duration: Some(avg_duration_per_file), // For native, extraction = total
                         subprocess_overhead: Some(Duration::ZERO),        // No subprocess for native Rust
@@ -370,6 +373,7 @@ impl FrameworkAdapter for NativeAdapter {
                     } else {
                         ErrorKind::HarnessError
                     },
+                    timed_out: false,
                     duration: extraction_duration,
                     extraction_duration: Some(extraction_duration),
                     subprocess_overhead: Some(Duration::ZERO), // No subprocess for native Rust
@@ -412,6 +416,16 @@ impl FrameworkAdapter for NativeAdapter {
     async fn teardown(&self) -> Result<()> {
         Ok(())
     }
+
+    /// Clear the string interning pools and the PDF font cache
+    ///
+    /// The Pdfium bindings and the extractor registry are initialized once per process
+    /// (`OnceLock`/`Lazy`) and cannot be reset, so their cost only shows up in cold start.
+    async fn reset_caches(&self) -> Result<()> {
+        kreuzberg::utils::clear_string_pools();
+        kreuzberg::pdf::clear_font_cache();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -498,6 +498,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                         success: false,
                         error_message: Some(e.to_string()),
                         error_kind: ErrorKind::HarnessError,
+                        timed_out: matches!(e, Error::Timeout(_)),
                         duration: actual_duration,
                         extraction_duration: None,
                         subprocess_overhead: None,
@@ -553,6 +554,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                         success: false,
                         error_message: Some(e.to_string()),
                         error_kind: ErrorKind::HarnessError,
+                        timed_out: matches!(e, Error::Timeout(_)),
                         duration: actual_duration,
                         extraction_duration: None,
                         subprocess_overhead: None,
@@ -621,6 +623,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                     success: false,
                     error_message: Some(e.to_string()),
                     error_kind,
+                    timed_out: matches!(e, Error::Timeout(_)),
                     duration,
                     extraction_duration: None,
                     subprocess_overhead: None,
@@ -720,6 +723,7 @@ impl FrameworkAdapter for SubprocessAdapter {
             success: true,
             error_message: None,
             error_kind: ErrorKind::None,
+            timed_out: false,
             duration,
             extraction_duration,
             subprocess_overhead,
@@ -816,6 +820,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                             success: false,
                             error_message: Some(e.to_string()),
                             error_kind: ErrorKind::HarnessError,
+                            timed_out: matches!(e, Error::Timeout(_)),
                             duration: avg_duration_per_file,
                             extraction_duration: None,
                             subprocess_overhead: None,
@@ -946,6 +951,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                     success: true,
                     error_message: None,
                     error_kind: ErrorKind::None,
+                    timed_out: false,
                     duration: avg_duration_per_file,
                     extraction_duration,
                     subprocess_overhead,
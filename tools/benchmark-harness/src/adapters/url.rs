@@ -0,0 +1,177 @@
+//! Kreuzberg URL adapter
+//!
+//! This adapter benchmarks remote extraction through `kreuzberg::extract_url`, covering
+//! the download as well as the extraction. It only handles fixtures that declare a `url`;
+//! the local `document` path identifies the fixture and provides the file type.
+
+use crate::adapter::FrameworkAdapter;
+use crate::adapters::native::determine_ocr_status;
+use crate::fixture::FixtureManager;
+use crate::monitoring::ResourceMonitor;
+use crate::types::{BenchmarkResult, ErrorKind, FrameworkCapabilities, OcrStatus, PerformanceMetrics};
+use crate::{Error, Result};
+use async_trait::async_trait;
+use kreuzberg::{ExtractionConfig, KreuzbergError, extract_url};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Native Rust adapter that fetches fixture documents from their URLs
+pub struct UrlAdapter {
+    config: ExtractionConfig,
+    urls: HashMap<PathBuf, String>,
+}
+
+impl UrlAdapter {
+    /// Create a URL adapter for the given document path to URL mapping
+    ///
+    /// NOTE: Cache is explicitly disabled for accurate benchmarking
+    pub fn new(urls: HashMap<PathBuf, String>) -> Self {
+        let config = ExtractionConfig {
+            use_cache: false,
+            ..Default::default()
+        };
+        Self { config, urls }
+    }
+
+    /// Create a URL adapter for every fixture that declares a `url`
+    pub fn from_fixtures(fixtures: &FixtureManager, config: ExtractionConfig) -> Self {
+        Self {
+            config,
+            urls: fixtures.document_urls(),
+        }
+    }
+}
+
+#[async_trait]
+impl FrameworkAdapter for UrlAdapter {
+    fn name(&self) -> &str {
+        "kreuzberg-rust-url"
+    }
+
+    fn supports_format(&self, _file_type: &str) -> bool {
+        true
+    }
+
+    fn supports_document(&self, document_path: &Path) -> bool {
+        self.urls.contains_key(document_path)
+    }
+
+    async fn extract(&self, file_path: &Path, timeout: Duration) -> Result<BenchmarkResult> {
+        let url = self
+            .urls
+            .get(file_path)
+            .ok_or_else(|| Error::Benchmark(format!("No URL configured for {}", file_path.display())))?;
+
+        // The download size is unknown up front, so sample at the highest resolution
+        let sampling_ms = crate::monitoring::adaptive_sampling_interval_ms(0);
+        let monitor = ResourceMonitor::new();
+        monitor.start(Duration::from_millis(sampling_ms)).await;
+
+        let start = Instant::now();
+        let extraction_result = tokio::time::timeout(timeout, extract_url(url, &self.config)).await;
+        let duration = start.elapsed();
+
+        let samples = monitor.stop().await;
+        let snapshots = monitor.get_snapshots().await;
+        let resource_stats = ResourceMonitor::calculate_stats(&samples, &snapshots);
+
+        let (extraction_result, error_message, error_kind, timed_out) = match extraction_result {
+            Ok(Ok(result)) => (Some(result), None, ErrorKind::None, false),
+            // Fetch failures are infrastructure problems, not the extractor's fault
+            Ok(Err(e @ KreuzbergError::Network { .. })) => (None, Some(e.to_string()), ErrorKind::HarnessError, false),
+            Ok(Err(e)) => (None, Some(e.to_string()), ErrorKind::FrameworkError, false),
+            Err(_) => (
+                None,
+                Some(format!("Extraction exceeded {:?}", timeout)),
+                ErrorKind::HarnessError,
+                true,
+            ),
+        };
+
+        // The download size is not reported back, so throughput uses the extracted text length
+        let file_size = extraction_result.as_ref().map_or(0, |r| r.content.len() as u64);
+        let throughput = if duration.as_secs_f64() > 0.0 {
+            file_size as f64 / duration.as_secs_f64()
+        } else {
+            0.0
+        };
+
+        Ok(BenchmarkResult {
+            framework: self.name().to_string(),
+            file_path: file_path.to_path_buf(),
+            file_size,
+            success: extraction_result.is_some(),
+            error_message,
+            error_kind,
+            timed_out,
+            duration,
+            extraction_duration: None,
+            subprocess_overhead: Some(Duration::ZERO),
+            metrics: PerformanceMetrics {
+                peak_memory_bytes: resource_stats.peak_memory_bytes,
+                avg_cpu_percent: resource_stats.avg_cpu_percent,
+                throughput_bytes_per_sec: throughput,
+                p50_memory_bytes: resource_stats.p50_memory_bytes,
+                p95_memory_bytes: resource_stats.p95_memory_bytes,
+                p99_memory_bytes: resource_stats.p99_memory_bytes,
+            },
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            file_extension: file_path
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("unknown")
+                .to_lowercase(),
+            framework_capabilities: FrameworkCapabilities::default(),
+            pdf_metadata: None,
+            ocr_status: extraction_result
+                .as_ref()
+                .map_or(OcrStatus::Unknown, |r| determine_ocr_status(r, &self.config)),
+            extracted_text: extraction_result.map(|r| r.content),
+        })
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_adapter_only_supports_documents_with_urls() {
+        let adapter = UrlAdapter::new(HashMap::from([(
+            PathBuf::from("fixtures/remote.pdf"),
+            "https://example.com/remote.pdf".to_string(),
+        )]));
+
+        assert_eq!(adapter.name(), "kreuzberg-rust-url");
+        assert!(adapter.supports_document(Path::new("fixtures/remote.pdf")));
+        assert!(!adapter.supports_document(Path::new("fixtures/local.pdf")));
+    }
+
+    #[tokio::test]
+    async fn test_url_adapter_reports_fetch_failure() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let adapter = UrlAdapter::new(HashMap::from([(
+            PathBuf::from("remote.pdf"),
+            format!("http://{}/remote.pdf", addr),
+        )]));
+
+        let result = adapter
+            .extract(Path::new("remote.pdf"), Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert_eq!(result.error_kind, ErrorKind::HarnessError);
+        assert!(!result.timed_out);
+    }
+}
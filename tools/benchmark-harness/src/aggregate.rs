@@ -7,6 +7,7 @@
 //!
 //! Calculates percentile-based statistics for better understanding of performance distributions.
 
+use crate::environment::EnvironmentInfo;
 use crate::types::{BenchmarkResult, DiskSizeInfo};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -39,6 +40,35 @@ pub struct ComparisonData {
     pub quality_ranking: Vec<RankedFramework>,
     /// Performance deltas relative to the fastest framework
     pub deltas_vs_baseline: HashMap<String, DeltaMetrics>,
+    /// Duration against quality score, one point per framework with quality data
+    #[serde(default)]
+    pub speed_vs_quality: SpeedQualityScatter,
+}
+
+/// Speed vs quality scatter data for frameworks with ground truth scores
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpeedQualityScatter {
+    /// One point per framework:mode, fastest first
+    pub points: Vec<SpeedQualityPoint>,
+    /// Median duration across points in ms (quadrant boundary)
+    pub median_duration_ms: f64,
+    /// Median quality score across points (quadrant boundary)
+    pub median_quality_score: f64,
+    /// Framework:mode keys left out because they have no quality data
+    pub excluded: Vec<String>,
+}
+
+/// A framework entry in the speed vs quality scatter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeedQualityPoint {
+    /// Framework:mode key (e.g., "kreuzberg-rust:single")
+    pub framework_mode: String,
+    /// Median duration in ms, averaged across file types
+    pub duration_ms: f64,
+    /// Median quality score (0.0 to 1.0), averaged across file types
+    pub quality_score: f64,
+    /// At or below the median duration and at or above the median quality
+    pub fast_and_accurate: bool,
 }
 
 /// A framework entry in a ranking
@@ -82,6 +112,9 @@ pub struct ConsolidationMetadata {
     pub file_type_count: usize,
     /// Timestamp of consolidation
     pub timestamp: String,
+    /// Environments of the consolidated runs, one per distinct machine
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub environments: Vec<EnvironmentInfo>,
 }
 
 /// Aggregated results for a specific framework and mode combination
@@ -187,12 +220,14 @@ pub fn aggregate_new_format(results: &[BenchmarkResult]) -> NewConsolidatedResul
                 memory_ranking: Vec::new(),
                 quality_ranking: Vec::new(),
                 deltas_vs_baseline: HashMap::new(),
+                speed_vs_quality: SpeedQualityScatter::default(),
             },
             metadata: ConsolidationMetadata {
                 total_results: 0,
                 framework_count: 0,
                 file_type_count: 0,
                 timestamp: chrono::Utc::now().to_rfc3339(),
+                environments: Vec::new(),
             },
         };
     }
@@ -263,6 +298,7 @@ pub fn aggregate_new_format(results: &[BenchmarkResult]) -> NewConsolidatedResul
         framework_count: aggregated_by_framework_mode.len(),
         file_type_count: file_types.len(),
         timestamp: chrono::Utc::now().to_rfc3339(),
+        environments: Vec::new(),
     };
 
     let comparison = build_comparison(&aggregated_by_framework_mode);
@@ -638,6 +674,47 @@ fn build_comparison(by_framework_mode: &HashMap<String, FrameworkModeAggregation
         memory_ranking,
         quality_ranking,
         deltas_vs_baseline,
+        speed_vs_quality: build_speed_quality_scatter(&metrics),
+    }
+}
+
+/// Build speed vs quality scatter data from per-framework median metrics
+///
+/// Frameworks without quality scores are listed in `excluded` instead of plotted.
+fn build_speed_quality_scatter(metrics: &[(String, f64, f64, f64, f64)]) -> SpeedQualityScatter {
+    let mut excluded = Vec::new();
+    let mut points = Vec::new();
+    for (key, duration, _, _, quality) in metrics {
+        if duration.is_finite() && quality.is_finite() {
+            points.push((key.clone(), *duration, *quality));
+        } else {
+            excluded.push(key.clone());
+        }
+    }
+    points.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+    excluded.sort();
+
+    let median = |values: Vec<f64>| {
+        let mut sorted = values;
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        calculate_percentile_value(&sorted, 0.50)
+    };
+    let median_duration_ms = median(points.iter().map(|p| p.1).collect());
+    let median_quality_score = median(points.iter().map(|p| p.2).collect());
+
+    SpeedQualityScatter {
+        points: points
+            .into_iter()
+            .map(|(framework_mode, duration_ms, quality_score)| SpeedQualityPoint {
+                framework_mode,
+                duration_ms,
+                quality_score,
+                fast_and_accurate: duration_ms <= median_duration_ms && quality_score >= median_quality_score,
+            })
+            .collect(),
+        median_duration_ms,
+        median_quality_score,
+        excluded,
     }
 }
 
@@ -679,7 +756,7 @@ fn calculate_percentile_value(sorted_values: &[f64], percentile: f64) -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{ErrorKind, FrameworkCapabilities, OcrStatus, PerformanceMetrics};
+    use crate::types::{ErrorKind, FrameworkCapabilities, OcrStatus, PerformanceMetrics, QualityMetrics};
     use std::path::PathBuf;
     use std::time::Duration;
 
@@ -698,6 +775,7 @@ mod tests {
             success: true,
             error_message: None,
             error_kind: ErrorKind::None,
+            timed_out: false,
             duration: Duration::from_millis(duration_ms),
             extraction_duration: None,
             subprocess_overhead: None,
@@ -834,6 +912,7 @@ mod tests {
             success: true,
             error_message: None,
             error_kind: ErrorKind::None,
+            timed_out: false,
             duration: Duration::from_millis(100),
             extraction_duration: None,
             subprocess_overhead: None,
@@ -876,6 +955,7 @@ mod tests {
                 success: true,
                 error_message: None,
                 error_kind: ErrorKind::None,
+                timed_out: false,
                 duration: Duration::from_millis(100),
                 extraction_duration: None,
                 subprocess_overhead: None,
@@ -904,6 +984,7 @@ mod tests {
                 success: false, // Failed result
                 error_message: Some("Test error".to_string()),
                 error_kind: ErrorKind::HarnessError,
+                timed_out: false,
                 duration: Duration::from_secs(0),
                 extraction_duration: None,
                 subprocess_overhead: None,
@@ -1189,6 +1270,7 @@ mod tests {
             success: false,
             error_message: Some("Error".to_string()),
             error_kind: ErrorKind::HarnessError,
+            timed_out: false,
             duration: Duration::from_millis(0),
             extraction_duration: None,
             subprocess_overhead: None,
@@ -1219,4 +1301,117 @@ mod tests {
         assert!(percentiles.extraction_duration.is_none());
         assert_eq!(percentiles.success_rate_percent, 50.0);
     }
+
+    #[test]
+    fn test_quality_aggregation_and_ranking() {
+        let with_quality = |framework: &str, duration_ms: u64, f1_text: f64, quality_score: f64| {
+            let mut result = create_test_result(
+                framework,
+                "pdf",
+                OcrStatus::NotUsed,
+                duration_ms,
+                1_000_000.0,
+                10_000_000,
+            );
+            result.quality = Some(QualityMetrics {
+                f1_score_text: f1_text,
+                f1_score_numeric: 1.0,
+                f1_score_layout: 0.0,
+                quality_score,
+            });
+            result
+        };
+        let results = vec![
+            with_quality("fast-but-wrong", 10, 0.2, 0.3),
+            with_quality("fast-but-wrong", 12, 0.4, 0.5),
+            with_quality("fast-but-wrong", 14, f64::NAN, f64::NAN),
+            with_quality("slow-but-right", 100, 0.9, 0.95),
+            create_test_result(
+                "no-ground-truth",
+                "pdf",
+                OcrStatus::NotUsed,
+                50,
+                1_000_000.0,
+                10_000_000,
+            ),
+        ];
+
+        let aggregated = aggregate_new_format(&results);
+
+        let quality = aggregated.by_framework_mode["fast-but-wrong:single"].by_file_type["pdf"]
+            .no_ocr
+            .as_ref()
+            .unwrap()
+            .quality
+            .as_ref()
+            .expect("quality percentiles should be present");
+        // NaN scores are skipped, so the median is over the two valid samples
+        assert!((quality.f1_text_p50 - 0.3).abs() < 1e-9);
+        assert!((quality.quality_score_p50 - 0.4).abs() < 1e-9);
+
+        let no_quality = aggregated.by_framework_mode["no-ground-truth:single"].by_file_type["pdf"]
+            .no_ocr
+            .as_ref()
+            .unwrap();
+        assert!(no_quality.quality.is_none());
+
+        // Fastest framework ranks last on quality; frameworks without quality data are excluded
+        let ranking: Vec<&str> = aggregated
+            .comparison
+            .quality_ranking
+            .iter()
+            .map(|r| r.framework_mode.as_str())
+            .collect();
+        assert_eq!(ranking, vec!["slow-but-right:single", "fast-but-wrong:single"]);
+        assert_eq!(
+            aggregated.comparison.performance_ranking[0].framework_mode,
+            "fast-but-wrong:single"
+        );
+    }
+
+    #[test]
+    fn test_speed_quality_scatter() {
+        let with_quality = |framework: &str, duration_ms: u64, quality_score: f64| {
+            let mut result = create_test_result(
+                framework,
+                "pdf",
+                OcrStatus::NotUsed,
+                duration_ms,
+                1_000_000.0,
+                10_000_000,
+            );
+            result.quality = Some(QualityMetrics {
+                f1_score_text: quality_score,
+                f1_score_numeric: 1.0,
+                f1_score_layout: 0.0,
+                quality_score,
+            });
+            result
+        };
+        let results = vec![
+            with_quality("fast-and-right", 10, 0.9),
+            with_quality("fast-but-wrong", 20, 0.3),
+            with_quality("slow-but-right", 100, 0.95),
+            create_test_result("no-ground-truth", "pdf", OcrStatus::NotUsed, 5, 1_000_000.0, 10_000_000),
+        ];
+
+        let scatter = aggregate_new_format(&results).comparison.speed_vs_quality;
+
+        let points: Vec<(&str, bool)> = scatter
+            .points
+            .iter()
+            .map(|p| (p.framework_mode.as_str(), p.fast_and_accurate))
+            .collect();
+        assert_eq!(
+            points,
+            vec![
+                ("fast-and-right:single", true),
+                ("fast-but-wrong:single", false),
+                ("slow-but-right:single", false),
+            ]
+        );
+        assert_eq!(scatter.excluded, vec!["no-ground-truth:single"]);
+        assert!((scatter.median_duration_ms - 20.0).abs() < 1e-9);
+        assert!((scatter.median_quality_score - 0.9).abs() < 1e-9);
+    }
 }
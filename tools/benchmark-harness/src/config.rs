@@ -182,6 +182,12 @@ pub struct BenchmarkConfig {
     /// Timeout for each extraction
     pub timeout: Duration,
 
+    /// Per-extension timeout overrides, keyed by lowercase extension without dot
+    ///
+    /// Fixtures whose extension has no entry fall back to [`BenchmarkConfig::timeout`].
+    #[serde(default)]
+    pub timeout_by_extension: HashMap<String, Duration>,
+
     /// Maximum number of concurrent extractions
     pub max_concurrent: usize,
 
@@ -209,6 +215,29 @@ pub struct BenchmarkConfig {
     /// Whether OCR is enabled for this benchmark run.
     /// When false, fixtures that require OCR (images, scanned PDFs) are excluded.
     pub ocr_enabled: bool,
+
+    /// Reset process-level caches before each framework run
+    ///
+    /// Prevents later frameworks from benefiting from state warmed by earlier ones.
+    /// Only in-process adapters hold such state: the native adapter clears its string
+    /// interning pools and font cache. Caches initialized once per process (Pdfium
+    /// bindings, extractor registry) cannot be reset.
+    #[serde(default)]
+    pub reset_caches_between_frameworks: bool,
+
+    /// Append each result to this JSON Lines file as soon as it completes
+    ///
+    /// Allows monitoring a run while it is in progress and consolidating the results
+    /// of a run that was interrupted before `results.json` was written.
+    #[serde(default)]
+    pub stream_results_path: Option<PathBuf>,
+
+    /// Resume an interrupted run from the results in `stream_results_path`
+    ///
+    /// Framework/document pairs with a successful streamed result are skipped and their
+    /// result is reused. Failed entries are dropped from the stream and run again.
+    #[serde(default)]
+    pub resume: bool,
 }
 
 impl Default for BenchmarkConfig {
@@ -217,6 +246,7 @@ impl Default for BenchmarkConfig {
             max_file_size: None,
             file_types: None,
             timeout: Duration::from_secs(1800),
+            timeout_by_extension: HashMap::new(),
             max_concurrent: num_cpus::get(),
             output_dir: PathBuf::from("results"),
             measure_quality: false,
@@ -226,6 +256,9 @@ impl Default for BenchmarkConfig {
             benchmark_iterations: 3,
             profiling: ProfilingConfig::default(),
             ocr_enabled: false,
+            reset_caches_between_frameworks: false,
+            stream_results_path: None,
+            resume: false,
         }
     }
 }
@@ -255,6 +288,7 @@ impl BenchmarkConfig {
             max_file_size: None,
             file_types: None,
             timeout,
+            timeout_by_extension: HashMap::new(),
             max_concurrent,
             output_dir,
             measure_quality: false,
@@ -264,6 +298,9 @@ impl BenchmarkConfig {
             benchmark_iterations,
             profiling: ProfilingConfig::default(),
             ocr_enabled: false,
+            reset_caches_between_frameworks: false,
+            stream_results_path: None,
+            resume: false,
         };
         config.validate()?;
         Ok(config)
@@ -279,6 +316,13 @@ impl BenchmarkConfig {
             return Err(crate::Error::Config("Timeout must be > 0".to_string()));
         }
 
+        if let Some((ext, _)) = self.timeout_by_extension.iter().find(|(_, t)| t.is_zero()) {
+            return Err(crate::Error::Config(format!(
+                "Timeout for extension '{}' must be > 0",
+                ext
+            )));
+        }
+
         if self.max_concurrent == 0 {
             return Err(crate::Error::Config("max_concurrent must be > 0".to_string()));
         }
@@ -297,10 +341,68 @@ impl BenchmarkConfig {
             ));
         }
 
+        if self.resume && self.stream_results_path.is_none() {
+            return Err(crate::Error::Config("resume requires stream_results_path".to_string()));
+        }
+
         self.profiling.validate()?;
 
         Ok(())
     }
+
+    /// Resolve the extraction timeout for a fixture file
+    ///
+    /// Uses the override for the file's extension when one is configured,
+    /// otherwise the global [`BenchmarkConfig::timeout`].
+    pub fn timeout_for(&self, file_path: &Path) -> Duration {
+        file_path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| self.timeout_by_extension.get(&ext.to_lowercase()))
+            .copied()
+            .unwrap_or(self.timeout)
+    }
+}
+
+/// Parse per-extension timeout overrides from `ext=seconds` entries
+///
+/// Extensions are lowercased and may be given with or without a leading dot.
+///
+/// # Errors
+///
+/// Returns [`crate::Error::Config`] if an entry is malformed or its timeout is not a positive integer
+pub fn parse_timeout_overrides<S: AsRef<str>>(entries: &[S]) -> Result<HashMap<String, Duration>> {
+    let mut overrides = HashMap::new();
+    for entry in entries {
+        let entry = entry.as_ref().trim();
+        let (ext, secs) = entry.split_once('=').ok_or_else(|| {
+            Error::Config(format!(
+                "Invalid timeout override '{}': expected format ext=seconds",
+                entry
+            ))
+        })?;
+        let ext = ext.trim().trim_start_matches('.').to_lowercase();
+        if ext.is_empty() {
+            return Err(Error::Config(format!(
+                "Invalid timeout override '{}': extension must not be empty",
+                entry
+            )));
+        }
+        let secs: u64 = secs.trim().parse().map_err(|_| {
+            Error::Config(format!(
+                "Invalid timeout override '{}': seconds must be a positive integer",
+                entry
+            ))
+        })?;
+        if secs == 0 {
+            return Err(Error::Config(format!(
+                "Invalid timeout override '{}': seconds must be > 0",
+                entry
+            )));
+        }
+        overrides.insert(ext, Duration::from_secs(secs));
+    }
+    Ok(overrides)
 }
 
 /// Load framework disk sizes from JSON configuration file
@@ -4,7 +4,8 @@ use crate::stats::percentile_r7;
 use crate::types::{BenchmarkResult, QualityMetrics};
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
 use std::fs;
 use std::path::Path;
 
@@ -121,29 +122,66 @@ pub struct QualityReliability {
     pub quality_degradation_concerns: Vec<String>,
 }
 
+/// Frameworks that attempted the same work, compared only against each other
+///
+/// Comparing a framework that skips OCR against one that runs it on scanned documents
+/// says little about either, so capability buckets keep rankings, deltas and quality
+/// analysis within frameworks that share the same capabilities.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapabilityBucket {
+    /// Bucket identifier: `"ocr"` or `"no-ocr"`
+    pub key: String,
+    /// Frameworks in this bucket, sorted by name
+    pub frameworks: Vec<String>,
+    pub comparison: CrossFrameworkComparison,
+    pub quality: QualityAnalysis,
+}
+
 /// Consolidated results from multiple runs
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsolidatedResults {
     pub by_framework: HashMap<String, FrameworkAggregation>,
     pub comparison: CrossFrameworkComparison,
     pub quality: QualityAnalysis,
+    /// Per-capability comparisons (only when `ConsolidationOptions::bucket_by_capability` is set)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capability_buckets: Vec<CapabilityBucket>,
     pub run_count: usize,
     pub total_files: usize,
     pub framework_count: usize,
 }
 
+/// Options controlling run consolidation
+#[derive(Debug, Clone, Default)]
+pub struct ConsolidationOptions {
+    /// Also compare frameworks within capability buckets (see [`CapabilityBucket`])
+    pub bucket_by_capability: bool,
+}
+
 /// Load benchmark results from results.json files in a directory
+///
+/// Directories with a streamed results.jsonl but no results.json (an interrupted run)
+/// are loaded from the stream instead.
 pub fn load_run_results(dir: &Path) -> Result<Vec<BenchmarkResult>> {
     let mut results = Vec::new();
     for entry in fs::read_dir(dir).map_err(Error::Io)? {
         let entry = entry.map_err(Error::Io)?;
         let path = entry.path();
 
-        if path.is_file() && path.file_name().is_some_and(|n| n == "results.json") {
+        let is_results_json = path.file_name().is_some_and(|n| n == "results.json");
+        // Streamed results duplicate results.json, so only use them for runs that never finished
+        let is_unfinished_stream =
+            path.file_name().is_some_and(|n| n == "results.jsonl") && !dir.join("results.json").is_file();
+
+        if path.is_file() && (is_results_json || is_unfinished_stream) {
             eprintln!("Loading results from {}", path.display());
-            let json_content = fs::read_to_string(&path).map_err(Error::Io)?;
-            let mut run_results: Vec<BenchmarkResult> = serde_json::from_str(&json_content)
-                .map_err(|e| Error::Benchmark(format!("Failed to parse {}: {}", path.display(), e)))?;
+            let mut run_results: Vec<BenchmarkResult> = if is_results_json {
+                let json_content = fs::read_to_string(&path).map_err(Error::Io)?;
+                serde_json::from_str(&json_content)
+                    .map_err(|e| Error::Benchmark(format!("Failed to parse {}: {}", path.display(), e)))?
+            } else {
+                crate::output::read_jsonl(&path)?
+            };
 
             // Infer benchmark mode from the parent directory name.
             // The runner outputs to `benchmark-results/{FRAMEWORK}-{MODE}/results.json`
@@ -180,6 +218,14 @@ pub fn load_run_results(dir: &Path) -> Result<Vec<BenchmarkResult>> {
 
 /// Main consolidation orchestrator
 pub fn consolidate_runs(runs: Vec<Vec<BenchmarkResult>>) -> Result<ConsolidatedResults> {
+    consolidate_runs_with_options(runs, &ConsolidationOptions::default())
+}
+
+/// Consolidate runs with explicit [`ConsolidationOptions`]
+pub fn consolidate_runs_with_options(
+    runs: Vec<Vec<BenchmarkResult>>,
+    options: &ConsolidationOptions,
+) -> Result<ConsolidatedResults> {
     if runs.is_empty() {
         return Err(Error::Benchmark("No runs provided".to_string()));
     }
@@ -203,6 +249,13 @@ pub fn consolidate_runs(runs: Vec<Vec<BenchmarkResult>>) -> Result<ConsolidatedR
     eprintln!("Analyzing quality metrics");
     let quality = analyze_quality(&all_results_owned);
 
+    let capability_buckets = if options.bucket_by_capability {
+        eprintln!("Comparing frameworks within capability buckets");
+        bucket_by_capability(&all_results_owned)
+    } else {
+        Vec::new()
+    };
+
     let run_count = runs.len();
     let framework_count = by_framework.len();
 
@@ -210,6 +263,7 @@ pub fn consolidate_runs(runs: Vec<Vec<BenchmarkResult>>) -> Result<ConsolidatedR
         by_framework,
         comparison,
         quality,
+        capability_buckets,
         run_count,
         total_files,
         framework_count,
@@ -569,6 +623,79 @@ pub fn compare_frameworks(results: &[BenchmarkResult]) -> CrossFrameworkComparis
     }
 }
 
+/// Group results into capability buckets and compare frameworks within each bucket
+///
+/// A framework belongs to the `"ocr"` bucket if any of its results reports OCR support.
+/// Buckets are returned sorted by key.
+pub fn bucket_by_capability(results: &[BenchmarkResult]) -> Vec<CapabilityBucket> {
+    let mut ocr_support: HashMap<&str, bool> = HashMap::new();
+    for result in results {
+        *ocr_support.entry(result.framework.as_str()).or_default() |= result.framework_capabilities.ocr_support;
+    }
+
+    let mut by_bucket: BTreeMap<&str, Vec<BenchmarkResult>> = BTreeMap::new();
+    for result in results {
+        let key = if ocr_support[result.framework.as_str()] {
+            "ocr"
+        } else {
+            "no-ocr"
+        };
+        by_bucket.entry(key).or_default().push(result.clone());
+    }
+
+    by_bucket
+        .into_iter()
+        .map(|(key, bucket_results)| {
+            let mut frameworks: Vec<String> = bucket_results.iter().map(|r| r.framework.clone()).collect();
+            frameworks.sort();
+            frameworks.dedup();
+
+            CapabilityBucket {
+                key: key.to_string(),
+                frameworks,
+                comparison: compare_frameworks(&bucket_results),
+                quality: analyze_quality(&bucket_results),
+            }
+        })
+        .collect()
+}
+
+/// Render one duration/quality summary table per capability bucket
+pub fn format_capability_summary(buckets: &[CapabilityBucket]) -> String {
+    let mut summary = String::new();
+
+    for bucket in buckets {
+        let _ = writeln!(
+            summary,
+            "Capability bucket: {} ({} frameworks)",
+            bucket.key,
+            bucket.frameworks.len()
+        );
+        let _ = writeln!(
+            summary,
+            "  {:<4} {:<30} {:>18} {:>11} {:>13}",
+            "Rank", "Framework", "Mean duration (ms)", "vs fastest", "Mean quality"
+        );
+
+        for ranking in &bucket.comparison.performance_ranking {
+            let quality = bucket
+                .quality
+                .by_framework
+                .get(&ranking.framework)
+                .map(|q| format!("{:.3}", q.mean_quality_score))
+                .unwrap_or_else(|| "-".to_string());
+            let _ = writeln!(
+                summary,
+                "  {:<4} {:<30} {:>18.2} {:>10.2}x {:>13}",
+                ranking.rank, ranking.framework, ranking.value, ranking.relative_performance, quality
+            );
+        }
+        summary.push('\n');
+    }
+
+    summary
+}
+
 /// Analyze quality metrics across frameworks
 pub fn analyze_quality(results: &[BenchmarkResult]) -> QualityAnalysis {
     let mut by_framework: HashMap<String, Vec<&BenchmarkResult>> = HashMap::new();
@@ -821,6 +948,7 @@ mod tests {
             } else {
                 ErrorKind::HarnessError
             },
+            timed_out: false,
             duration: Duration::from_millis(duration_ms),
             extraction_duration: None,
             subprocess_overhead: None,
@@ -1042,6 +1170,79 @@ mod tests {
         assert!(std_dev > 0.0);
     }
 
+    #[test]
+    fn test_load_run_results_reads_unfinished_streams() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+
+        let finished = temp_dir.path().join("kreuzberg-rust-single-file");
+        let finished_results = vec![create_test_result("kreuzberg-rust", "a.pdf", true, 100)];
+        crate::output::write_json(&finished_results, &finished.join("results.json")).unwrap();
+        crate::output::write_jsonl(&finished_results, &finished.join("results.jsonl")).unwrap();
+
+        let interrupted = temp_dir.path().join("docling-single-file");
+        let interrupted_results = vec![
+            create_test_result("docling", "a.pdf", true, 900),
+            create_test_result("docling", "b.pdf", true, 1200),
+        ];
+        crate::output::write_jsonl(&interrupted_results, &interrupted.join("results.jsonl")).unwrap();
+
+        let loaded = load_run_results(temp_dir.path()).unwrap();
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(loaded.iter().filter(|r| r.framework == "kreuzberg-rust").count(), 1);
+        assert_eq!(loaded.iter().filter(|r| r.framework == "docling").count(), 2);
+    }
+
+    #[test]
+    fn test_bucket_by_capability_separates_frameworks() {
+        let with_ocr = |framework: &str, file: &str, duration_ms: u64| {
+            let mut result = create_test_result(framework, file, true, duration_ms);
+            result.framework_capabilities.ocr_support = true;
+            result
+        };
+        let results = vec![
+            with_ocr("ocr-framework", "scan1.pdf", 900),
+            with_ocr("ocr-framework", "scan2.pdf", 1100),
+            create_test_result("text-framework", "scan1.pdf", true, 50),
+            create_test_result("text-framework", "scan2.pdf", true, 50),
+        ];
+
+        let buckets = bucket_by_capability(&results);
+
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].key, "no-ocr");
+        assert_eq!(buckets[0].frameworks, vec!["text-framework"]);
+        assert_eq!(buckets[1].key, "ocr");
+        assert_eq!(buckets[1].frameworks, vec!["ocr-framework"]);
+        for bucket in &buckets {
+            assert_eq!(bucket.comparison.performance_ranking.len(), 1);
+            assert!(bucket.comparison.deltas_vs_baseline.is_empty());
+        }
+
+        let summary = format_capability_summary(&buckets);
+        assert!(summary.contains("Capability bucket: ocr (1 frameworks)"));
+        assert!(summary.contains("Capability bucket: no-ocr (1 frameworks)"));
+    }
+
+    #[test]
+    fn test_consolidate_runs_buckets_only_when_requested() {
+        let mut ocr_result = create_test_result("ocr-framework", "scan.pdf", true, 900);
+        ocr_result.framework_capabilities.ocr_support = true;
+        let runs = vec![vec![
+            ocr_result,
+            create_test_result("text-framework", "scan.pdf", true, 50),
+        ]];
+
+        let plain = consolidate_runs(runs.clone()).unwrap();
+        assert!(plain.capability_buckets.is_empty());
+
+        let options = ConsolidationOptions {
+            bucket_by_capability: true,
+        };
+        let bucketed = consolidate_runs_with_options(runs, &options).unwrap();
+        assert_eq!(bucketed.capability_buckets.len(), 2);
+        assert_eq!(bucketed.comparison.performance_ranking.len(), 2);
+    }
+
     #[test]
     fn test_compare_frameworks() {
         let results = vec![
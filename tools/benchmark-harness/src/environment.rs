@@ -0,0 +1,225 @@
+//! Machine environment capture
+//!
+//! Benchmark numbers are only comparable between runs on equivalent machines, so each
+//! run records the environment it ran in alongside its results.
+
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
+
+/// File name of the environment record written next to `results.json`
+pub const ENVIRONMENT_FILE: &str = "environment.json";
+
+/// Machine and build environment of a benchmark run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvironmentInfo {
+    /// Operating system (e.g. "linux", "macos")
+    pub os: String,
+    /// CPU architecture (e.g. "x86_64", "aarch64")
+    pub arch: String,
+    /// Number of logical CPUs
+    pub num_cpus: usize,
+    /// CPU brand string, if the platform reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_model: Option<String>,
+    /// Total physical memory in bytes
+    pub total_memory_bytes: u64,
+    /// Output of `rustc --version`, if rustc is on the PATH
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rustc_version: Option<String>,
+    /// Kreuzberg version the harness was built against
+    pub kreuzberg_version: String,
+    /// When the environment was captured (RFC 3339)
+    pub captured_at: String,
+}
+
+impl EnvironmentInfo {
+    /// Capture the environment of the current process
+    pub fn capture() -> Self {
+        let system = System::new_with_specifics(
+            RefreshKind::nothing()
+                .with_cpu(CpuRefreshKind::nothing())
+                .with_memory(MemoryRefreshKind::nothing().with_ram()),
+        );
+        let cpu_model = system
+            .cpus()
+            .first()
+            .map(|cpu| cpu.brand().trim().to_string())
+            .filter(|brand| !brand.is_empty());
+
+        let rustc_version = Command::new("rustc")
+            .arg("--version")
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+            .filter(|version| !version.is_empty());
+
+        Self {
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            num_cpus: num_cpus::get(),
+            cpu_model,
+            total_memory_bytes: system.total_memory(),
+            rustc_version,
+            kreuzberg_version: env!("CARGO_PKG_VERSION").to_string(),
+            captured_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Describe the differences that make results from `self` and `other` not directly comparable
+    ///
+    /// Capture time and toolchain version are ignored. An empty result means the
+    /// environments are equivalent.
+    pub fn differences(&self, other: &EnvironmentInfo) -> Vec<String> {
+        let mut differences = Vec::new();
+        let mut compare = |field: &str, a: String, b: String| {
+            if a != b {
+                differences.push(format!("{}: {} vs {}", field, a, b));
+            }
+        };
+
+        compare("os", self.os.clone(), other.os.clone());
+        compare("arch", self.arch.clone(), other.arch.clone());
+        compare("num_cpus", self.num_cpus.to_string(), other.num_cpus.to_string());
+        compare(
+            "cpu_model",
+            self.cpu_model.clone().unwrap_or_else(|| "unknown".to_string()),
+            other.cpu_model.clone().unwrap_or_else(|| "unknown".to_string()),
+        );
+        compare(
+            "total_memory_bytes",
+            self.total_memory_bytes.to_string(),
+            other.total_memory_bytes.to_string(),
+        );
+        compare(
+            "kreuzberg_version",
+            self.kreuzberg_version.clone(),
+            other.kreuzberg_version.clone(),
+        );
+
+        differences
+    }
+}
+
+/// Write the environment record into a run's output directory
+pub fn write_environment(environment: &EnvironmentInfo, output_dir: &Path) -> Result<()> {
+    fs::create_dir_all(output_dir).map_err(Error::Io)?;
+
+    let json = serde_json::to_string_pretty(environment)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize environment: {}", e)))?;
+    fs::write(output_dir.join(ENVIRONMENT_FILE), json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+/// Load the environment record of a run directory, if it has one
+pub fn load_environment(run_dir: &Path) -> Result<Option<EnvironmentInfo>> {
+    let path = run_dir.join(ENVIRONMENT_FILE);
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let json = fs::read_to_string(&path).map_err(Error::Io)?;
+    serde_json::from_str(&json)
+        .map(Some)
+        .map_err(|e| Error::Benchmark(format!("Failed to parse {}: {}", path.display(), e)))
+}
+
+/// Load the environment records of a results directory and all of its run subdirectories
+pub fn load_environments(dir: &Path) -> Result<Vec<EnvironmentInfo>> {
+    let mut environments: Vec<EnvironmentInfo> = load_environment(dir)?.into_iter().collect();
+
+    let mut subdirs: Vec<_> = fs::read_dir(dir)
+        .map_err(Error::Io)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_dir())
+        .collect();
+    subdirs.sort();
+
+    for subdir in subdirs {
+        environments.extend(load_environments(&subdir)?);
+    }
+
+    Ok(environments)
+}
+
+/// Drop environments equivalent (see [`EnvironmentInfo::differences`]) to an earlier one
+pub fn distinct_environments(environments: Vec<EnvironmentInfo>) -> Vec<EnvironmentInfo> {
+    let mut distinct: Vec<EnvironmentInfo> = Vec::new();
+    for environment in environments {
+        if distinct.iter().all(|seen| !seen.differences(&environment).is_empty()) {
+            distinct.push(environment);
+        }
+    }
+    distinct
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_capture_is_populated() {
+        let environment = EnvironmentInfo::capture();
+
+        assert!(!environment.os.is_empty());
+        assert!(!environment.arch.is_empty());
+        assert!(environment.num_cpus > 0);
+        assert!(!environment.kreuzberg_version.is_empty());
+        assert!(environment.differences(&environment).is_empty());
+    }
+
+    #[test]
+    fn test_environment_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(load_environment(temp_dir.path()).unwrap().is_none());
+
+        let environment = EnvironmentInfo::capture();
+        write_environment(&environment, temp_dir.path()).unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(temp_dir.path().join(ENVIRONMENT_FILE)).unwrap()).unwrap();
+        for field in ["os", "arch", "num_cpus", "kreuzberg_version"] {
+            assert!(json.get(field).is_some(), "missing {}", field);
+        }
+
+        assert_eq!(load_environment(temp_dir.path()).unwrap(), Some(environment));
+    }
+
+    #[test]
+    fn test_differences() {
+        let baseline = EnvironmentInfo::capture();
+        let current = EnvironmentInfo {
+            num_cpus: baseline.num_cpus + 4,
+            captured_at: "2000-01-01T00:00:00Z".to_string(),
+            rustc_version: None,
+            ..baseline.clone()
+        };
+
+        let differences = baseline.differences(&current);
+        assert_eq!(differences.len(), 1);
+        assert!(differences[0].starts_with("num_cpus:"));
+    }
+
+    #[test]
+    fn test_load_environments_from_run_subdirectories() {
+        let temp_dir = TempDir::new().unwrap();
+        let baseline = EnvironmentInfo::capture();
+        let other_machine = EnvironmentInfo {
+            arch: "riscv64".to_string(),
+            ..baseline.clone()
+        };
+        write_environment(&baseline, &temp_dir.path().join("kreuzberg-rust-single-file")).unwrap();
+        write_environment(&baseline, &temp_dir.path().join("kreuzberg-rust-batch")).unwrap();
+        write_environment(&other_machine, &temp_dir.path().join("pandoc-single-file")).unwrap();
+
+        let environments = load_environments(temp_dir.path()).unwrap();
+        assert_eq!(environments.len(), 3);
+        assert_eq!(distinct_environments(environments), vec![baseline, other_machine]);
+    }
+}
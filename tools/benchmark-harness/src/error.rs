@@ -29,6 +29,14 @@ pub enum Error {
     #[error("Test document not found: {0}")]
     DocumentNotFound(PathBuf),
 
+    /// Test document does not match the checksum recorded in its fixture
+    #[error("Checksum mismatch for {path}: expected {expected}, found {actual}")]
+    ChecksumMismatch {
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+
     /// Framework extraction error
     #[error("Framework '{framework}' failed on {file}: {message}")]
     ExtractionFailed {
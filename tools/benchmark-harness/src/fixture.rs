@@ -7,6 +7,7 @@
 //! ```json
 //! {
 //!   "document": "path/to/document.pdf",
+//!   "url": "https://example.com/document.pdf",  // Optional: remote copy for URL adapters
 //!   "file_type": "pdf",
 //!   "file_size": 1024000,
 //!   "expected_frameworks": ["kreuzberg", "docling"],
@@ -19,12 +20,14 @@
 //!   "ground_truth": {
 //!     "text_file": "path/to/ground_truth.txt",
 //!     "source": "pdf_text_layer"
-//!   }
+//!   },
+//!   "checksum": "9f86d081..."  // Optional: SHA-256 of the document, hex encoded
 //! }
 //! ```
 
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
@@ -52,6 +55,20 @@ pub struct Fixture {
     /// Ground truth for quality assessment (optional)
     #[serde(default)]
     pub ground_truth: Option<GroundTruth>,
+
+    /// Remote location of the document (optional)
+    ///
+    /// URL-based adapters fetch the document from here instead of reading `document`,
+    /// which is still used for the file type, ground truth and reporting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// SHA-256 checksum of the document, hex encoded (optional)
+    ///
+    /// Recorded with [`FixtureManager::write_checksums`] and checked with
+    /// [`FixtureManager::verify_checksums`] so results stay comparable across runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
 }
 
 /// Ground truth data for quality assessment
@@ -79,6 +96,7 @@ impl Fixture {
     /// Performs comprehensive validation including:
     /// - Path validation (relative paths only)
     /// - File type validation (non-empty)
+    /// - URL validation (http or https scheme)
     /// - Ground truth validation:
     ///   - Relative path requirement
     ///   - Valid source type
@@ -98,6 +116,15 @@ impl Fixture {
             });
         }
 
+        if let Some(url) = &self.url
+            && !(url.starts_with("http://") || url.starts_with("https://"))
+        {
+            return Err(Error::InvalidFixture {
+                path: fixture_path.to_path_buf(),
+                reason: format!("url must use http or https: {}", url),
+            });
+        }
+
         if let Some(gt) = &self.ground_truth {
             if gt.text_file.is_absolute() {
                 return Err(Error::InvalidFixture {
@@ -154,6 +181,32 @@ impl Fixture {
         self.ground_truth.as_ref().map(|gt| fixture_dir.join(&gt.text_file))
     }
 
+    /// Check the document against the recorded checksum, if any
+    ///
+    /// Returns `Ok(false)` when the fixture has no checksum to verify.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ChecksumMismatch`] if the document changed since the checksum
+    /// was recorded, or an I/O error if it cannot be read.
+    pub fn verify_checksum(&self, fixture_dir: &Path) -> Result<bool> {
+        let Some(expected) = &self.checksum else {
+            return Ok(false);
+        };
+
+        let document = self.resolve_document_path(fixture_dir);
+        let actual = document_checksum(&document)?;
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(Error::ChecksumMismatch {
+                path: document,
+                expected: expected.clone(),
+                actual,
+            });
+        }
+
+        Ok(true)
+    }
+
     /// Determine if this fixture requires OCR based on file type and metadata
     pub fn requires_ocr(&self) -> bool {
         // Check if explicitly marked in metadata
@@ -169,18 +222,149 @@ impl Fixture {
     }
 }
 
+/// Compute the hex-encoded SHA-256 checksum of a document
+pub fn document_checksum(path: &Path) -> Result<String> {
+    if !path.exists() {
+        return Err(Error::DocumentNotFound(path.to_path_buf()));
+    }
+
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Include/exclude glob patterns applied to fixture document paths
+///
+/// Patterns support `*` (any run of characters within a path component), `**` (any
+/// number of components) and `?` (a single character). A pattern matches a document if
+/// it matches the whole path or any trailing run of its components, so `*.pdf` matches
+/// every PDF and `pdf/*_broken.*` matches broken documents in any `pdf` directory.
+#[derive(Debug, Clone, Default)]
+pub struct FixtureFilter {
+    /// Only load fixtures whose document matches one of these patterns (empty = all)
+    pub include: Vec<String>,
+    /// Skip fixtures whose document matches any of these patterns
+    pub exclude: Vec<String>,
+}
+
+impl FixtureFilter {
+    /// Create a filter from include and exclude patterns, ignoring empty patterns
+    pub fn new(include: Vec<String>, exclude: Vec<String>) -> Self {
+        let clean = |patterns: Vec<String>| {
+            patterns
+                .into_iter()
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect()
+        };
+        Self {
+            include: clean(include),
+            exclude: clean(exclude),
+        }
+    }
+
+    /// Whether this filter lets every fixture through
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Check whether a document path passes the filter
+    pub fn matches(&self, document: &Path) -> bool {
+        let path = document.to_string_lossy().replace('\\', "/");
+        let matches_any = |patterns: &[String]| patterns.iter().any(|pattern| path_matches(pattern, &path));
+
+        (self.include.is_empty() || matches_any(&self.include)) && !matches_any(&self.exclude)
+    }
+}
+
+/// Match a glob pattern against a path or any of its trailing component runs
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let path: Vec<char> = path.chars().collect();
+
+    (0..path.len())
+        .filter(|&i| i == 0 || path[i - 1] == '/')
+        .any(|i| glob_match(&pattern, &path[i..]))
+}
+
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        ['*', '*', '/', rest @ ..] => {
+            (0..=text.len()).any(|i| (i == 0 || text[i - 1] == '/') && glob_match(rest, &text[i..]))
+        }
+        ['*', '*', rest @ ..] => (0..=text.len()).any(|i| glob_match(rest, &text[i..])),
+        ['*', rest @ ..] => (0..=text.len())
+            .take_while(|&i| i == 0 || text[i - 1] != '/')
+            .any(|i| glob_match(rest, &text[i..])),
+        ['?', rest @ ..] => matches!(text, [c, ..] if *c != '/') && glob_match(rest, &text[1..]),
+        [c, rest @ ..] => text.first() == Some(c) && glob_match(rest, &text[1..]),
+    }
+}
+
+/// A fixture file that could not be loaded
+#[derive(Debug, Clone)]
+pub struct FixtureLoadError {
+    /// Path to the fixture JSON file
+    pub path: PathBuf,
+    /// Why loading failed
+    pub message: String,
+}
+
+impl std::fmt::Display for FixtureLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+/// Recursively collect fixture JSON files under `dir`
+fn collect_fixture_paths(dir: &Path, paths: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_fixture_paths(&path, paths)?;
+        } else if path.extension().and_then(|s| s.to_str()) == Some("json") {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
 /// Manages loading and accessing fixtures
 pub struct FixtureManager {
     fixtures: Vec<(PathBuf, Fixture)>,
+    filter: FixtureFilter,
 }
 
 impl FixtureManager {
     /// Create a new empty fixture manager
     pub fn new() -> Self {
-        Self { fixtures: Vec::new() }
+        Self {
+            fixtures: Vec::new(),
+            filter: FixtureFilter::default(),
+        }
+    }
+
+    /// Create a fixture manager that only loads fixtures passing `filter`
+    pub fn with_filter(filter: FixtureFilter) -> Self {
+        Self {
+            fixtures: Vec::new(),
+            filter,
+        }
+    }
+
+    /// Set the include/exclude filter applied to fixtures loaded from now on
+    pub fn set_filter(&mut self, filter: FixtureFilter) {
+        self.filter = filter;
     }
 
     /// Load a single fixture file
+    ///
+    /// Fixtures whose resolved document path doesn't pass the manager's [`FixtureFilter`]
+    /// are skipped.
     pub fn load_fixture(&mut self, path: impl AsRef<Path>) -> Result<()> {
         let path = path.as_ref();
 
@@ -189,6 +373,12 @@ impl FixtureManager {
         }
 
         let fixture = Fixture::from_file(path)?;
+
+        let fixture_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        if !self.filter.matches(&fixture.resolve_document_path(fixture_dir)) {
+            return Ok(());
+        }
+
         self.fixtures.push((path.to_path_buf(), fixture));
 
         Ok(())
@@ -221,12 +411,34 @@ impl FixtureManager {
     ///
     /// If the `PROFILING_FIXTURES` environment variable is set, only fixtures matching
     /// the specified names (comma-separated) will be loaded. Otherwise, all fixtures are loaded.
+    /// Fixtures that fail to load are skipped with a warning; use
+    /// [`FixtureManager::load_fixtures_from_dir_with_errors`] to inspect them.
     pub fn load_fixtures_from_dir(&mut self, dir: impl AsRef<Path>) -> Result<()> {
-        self.load_fixtures_from_dir_internal(dir, true)
+        let (loaded, failed) = self.load_fixtures_from_dir_with_errors(dir)?;
+
+        if !failed.is_empty() {
+            eprintln!(
+                "Warning: {} of {} fixtures failed to load:",
+                failed.len(),
+                loaded + failed.len()
+            );
+            for error in failed {
+                eprintln!("  - {}", error);
+            }
+        }
+
+        Ok(())
     }
 
-    /// Internal method for loading fixtures from a directory (with filter control)
-    fn load_fixtures_from_dir_internal(&mut self, dir: impl AsRef<Path>, apply_filter: bool) -> Result<()> {
+    /// Load all fixtures from a directory (recursively), collecting the ones that fail
+    ///
+    /// Behaves like [`FixtureManager::load_fixtures_from_dir`] but returns the number of
+    /// fixtures loaded together with every fixture that could not be loaded, instead of
+    /// printing warnings. Only a missing or unreadable directory is an error.
+    pub fn load_fixtures_from_dir_with_errors(
+        &mut self,
+        dir: impl AsRef<Path>,
+    ) -> Result<(usize, Vec<FixtureLoadError>)> {
         let dir = dir.as_ref();
 
         if !dir.exists() {
@@ -234,109 +446,68 @@ impl FixtureManager {
         }
 
         let mut all_fixtures: Vec<PathBuf> = Vec::new();
-
-        for entry in std::fs::read_dir(dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            if path.is_dir() {
-                let mut temp_manager = FixtureManager::new();
-                temp_manager.load_fixtures_from_dir_internal(&path, false)?;
-                for (fixture_path, _) in temp_manager.fixtures {
-                    all_fixtures.push(fixture_path);
-                }
-            } else if path.extension().and_then(|s| s.to_str()) == Some("json") {
-                all_fixtures.push(path);
-            }
-        }
+        collect_fixture_paths(dir, &mut all_fixtures)?;
 
         let total_fixtures = all_fixtures.len();
-        let mut failed_fixtures: Vec<(PathBuf, String)> = Vec::new();
-
-        if apply_filter {
-            if let Some(profiling_set) = Self::get_profiling_fixtures() {
-                let mut loaded_count = 0;
-                let mut fixture_names = Vec::new();
-
-                for fixture_path in &all_fixtures {
-                    if let Some(stem) = fixture_path.file_stem().and_then(|s| s.to_str())
-                        && profiling_set.contains(stem)
-                    {
-                        match self.load_fixture(fixture_path) {
-                            Ok(()) => {
-                                loaded_count += 1;
-                                fixture_names.push(stem.to_string());
-                            }
-                            Err(e) => {
-                                failed_fixtures.push((fixture_path.clone(), e.to_string()));
-                            }
-                        }
-                    }
-                }
-
-                if loaded_count > 0 {
+        let loaded_before = self.fixtures.len();
+        let mut failed_fixtures: Vec<FixtureLoadError> = Vec::new();
+
+        let selected: Vec<PathBuf> = match Self::get_profiling_fixtures() {
+            Some(profiling_set) => {
+                let selected: Vec<PathBuf> = all_fixtures
+                    .iter()
+                    .filter(|path| {
+                        path.file_stem()
+                            .and_then(|s| s.to_str())
+                            .is_some_and(|stem| profiling_set.contains(stem))
+                    })
+                    .cloned()
+                    .collect();
+
+                if selected.is_empty() {
+                    eprintln!(
+                        "Warning: PROFILING_FIXTURES set but no matching fixtures found. \
+                        Loading all {} fixtures.",
+                        total_fixtures
+                    );
+                    all_fixtures
+                } else {
+                    let mut fixture_names: Vec<String> = selected
+                        .iter()
+                        .filter_map(|path| path.file_stem().and_then(|s| s.to_str()).map(str::to_string))
+                        .collect();
                     fixture_names.sort();
                     eprintln!(
                         "Profiling mode: Using {} of {} fixtures: {}",
-                        loaded_count,
+                        selected.len(),
                         total_fixtures,
                         fixture_names.join(", ")
                     );
-                } else {
-                    eprintln!(
-                        "Warning: PROFILING_FIXTURES set but no matching fixtures found. \
-                        Loading all {} fixtures.",
-                        total_fixtures
-                    );
-                    for fixture_path in all_fixtures {
-                        match self.load_fixture(&fixture_path) {
-                            Ok(()) => {
-                                // Successfully loaded
-                            }
-                            Err(e) => {
-                                failed_fixtures.push((fixture_path.clone(), e.to_string()));
-                            }
-                        }
-                    }
-                }
-            } else {
-                for fixture_path in all_fixtures {
-                    match self.load_fixture(&fixture_path) {
-                        Ok(()) => {
-                            // Successfully loaded
-                        }
-                        Err(e) => {
-                            failed_fixtures.push((fixture_path.clone(), e.to_string()));
-                        }
-                    }
+                    selected
                 }
             }
-        } else {
-            for fixture_path in all_fixtures {
-                match self.load_fixture(&fixture_path) {
-                    Ok(()) => {
-                        // Successfully loaded
-                    }
-                    Err(e) => {
-                        failed_fixtures.push((fixture_path.clone(), e.to_string()));
-                    }
-                }
+            None => all_fixtures,
+        };
+
+        for fixture_path in selected {
+            if let Err(e) = self.load_fixture(&fixture_path) {
+                failed_fixtures.push(FixtureLoadError {
+                    path: fixture_path,
+                    message: e.to_string(),
+                });
             }
         }
 
-        // Report failed fixtures if any occurred
-        if !failed_fixtures.is_empty() {
+        let loaded = self.fixtures.len() - loaded_before;
+
+        if !self.filter.is_empty() {
             eprintln!(
-                "Warning: {} of {} fixtures failed to load:",
-                failed_fixtures.len(),
-                total_fixtures
+                "Fixture filter: {} of {} fixtures match the include/exclude patterns",
+                loaded, total_fixtures
             );
-            for (path, error) in failed_fixtures {
-                eprintln!("  - {}: {}", path.display(), error);
-            }
         }
 
-        Ok(())
+        Ok((loaded, failed_fixtures))
     }
 
     /// Get all loaded fixtures
@@ -354,6 +525,58 @@ impl FixtureManager {
         self.fixtures.is_empty()
     }
 
+    /// Map resolved document paths to the remote URLs of fixtures that declare one
+    pub fn document_urls(&self) -> HashMap<PathBuf, String> {
+        self.fixtures
+            .iter()
+            .filter_map(|(fixture_path, fixture)| {
+                let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+                fixture
+                    .url
+                    .clone()
+                    .map(|url| (fixture.resolve_document_path(fixture_dir), url))
+            })
+            .collect()
+    }
+
+    /// Verify every loaded fixture that records a checksum against its document
+    ///
+    /// Returns the number of fixtures verified; fixtures without a checksum are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ChecksumMismatch`] for the first document that changed.
+    pub fn verify_checksums(&self) -> Result<usize> {
+        let mut verified = 0;
+        for (fixture_path, fixture) in &self.fixtures {
+            let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+            if fixture.verify_checksum(fixture_dir)? {
+                verified += 1;
+            }
+        }
+        Ok(verified)
+    }
+
+    /// Record the current checksum of every loaded fixture's document
+    ///
+    /// Rewrites each fixture file whose checksum is missing or outdated and returns
+    /// how many were updated.
+    pub fn write_checksums(&mut self) -> Result<usize> {
+        let mut updated = 0;
+        for (fixture_path, fixture) in &mut self.fixtures {
+            let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+            let checksum = document_checksum(&fixture.resolve_document_path(fixture_dir))?;
+            if fixture.checksum.as_deref() == Some(checksum.as_str()) {
+                continue;
+            }
+
+            fixture.checksum = Some(checksum);
+            std::fs::write(&*fixture_path, serde_json::to_string_pretty(fixture)?)?;
+            updated += 1;
+        }
+        Ok(updated)
+    }
+
     /// Filter fixtures by file type
     pub fn filter_by_type(&self, file_types: &[String]) -> Vec<(PathBuf, Fixture)> {
         self.fixtures
@@ -387,11 +610,31 @@ mod tests {
             expected_frameworks: vec!["kreuzberg".to_string()],
             metadata: HashMap::new(),
             ground_truth: None,
+            url: None,
+            checksum: None,
         };
 
         assert!(fixture.validate(Path::new("fixture.json")).is_ok());
     }
 
+    #[test]
+    fn test_fixture_url_validation() {
+        let mut fixture = Fixture {
+            document: PathBuf::from("remote.pdf"),
+            file_type: "pdf".to_string(),
+            file_size: 1024,
+            expected_frameworks: vec![],
+            metadata: HashMap::new(),
+            ground_truth: None,
+            url: Some("https://example.com/remote.pdf".to_string()),
+            checksum: None,
+        };
+        assert!(fixture.validate(Path::new("fixture.json")).is_ok());
+
+        fixture.url = Some("ftp://example.com/remote.pdf".to_string());
+        assert!(fixture.validate(Path::new("fixture.json")).is_err());
+    }
+
     #[test]
     fn test_absolute_path_rejected() {
         #[cfg(windows)]
@@ -406,6 +649,8 @@ mod tests {
             expected_frameworks: vec![],
             metadata: HashMap::new(),
             ground_truth: None,
+            url: None,
+            checksum: None,
         };
 
         assert!(fixture.validate(Path::new("fixture.json")).is_err());
@@ -423,6 +668,8 @@ mod tests {
             expected_frameworks: vec![],
             metadata: HashMap::new(),
             ground_truth: None,
+            url: None,
+            checksum: None,
         };
 
         std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
@@ -447,6 +694,8 @@ mod tests {
                 expected_frameworks: vec![],
                 metadata: HashMap::new(),
                 ground_truth: None,
+                url: None,
+                checksum: None,
             };
             std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
         }
@@ -491,6 +740,8 @@ mod tests {
                 expected_frameworks: vec![],
                 metadata: HashMap::new(),
                 ground_truth: None,
+                url: None,
+                checksum: None,
             };
             std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
         }
@@ -520,6 +771,8 @@ mod tests {
                 expected_frameworks: vec![],
                 metadata: HashMap::new(),
                 ground_truth: None,
+                url: None,
+                checksum: None,
             };
             std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
         }
@@ -553,6 +806,8 @@ mod tests {
                 expected_frameworks: vec![],
                 metadata: HashMap::new(),
                 ground_truth: None,
+                url: None,
+                checksum: None,
             };
             std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
         }
@@ -591,6 +846,8 @@ mod tests {
                 expected_frameworks: vec![],
                 metadata: HashMap::new(),
                 ground_truth: None,
+                url: None,
+                checksum: None,
             };
 
             assert!(
@@ -613,6 +870,8 @@ mod tests {
                 expected_frameworks: vec![],
                 metadata: HashMap::new(),
                 ground_truth: None,
+                url: None,
+                checksum: None,
             };
 
             assert!(
@@ -635,6 +894,8 @@ mod tests {
             expected_frameworks: vec![],
             metadata,
             ground_truth: None,
+            url: None,
+            checksum: None,
         };
 
         // PDF normally doesn't require OCR, but metadata overrides this
@@ -653,6 +914,8 @@ mod tests {
             expected_frameworks: vec![],
             metadata,
             ground_truth: None,
+            url: None,
+            checksum: None,
         };
 
         // PNG normally requires OCR, but metadata overrides this
@@ -668,6 +931,8 @@ mod tests {
             expected_frameworks: vec![],
             metadata: HashMap::new(),
             ground_truth: None,
+            url: None,
+            checksum: None,
         };
 
         assert!(fixture.requires_ocr());
@@ -688,6 +953,8 @@ mod tests {
                 text_file: PathBuf::from("nonexistent_ground_truth.txt"),
                 source: "manual".to_string(),
             }),
+            url: None,
+            checksum: None,
         };
 
         std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
@@ -722,6 +989,8 @@ mod tests {
                 text_file: PathBuf::from("ground_truth.txt"),
                 source: "manual".to_string(),
             }),
+            url: None,
+            checksum: None,
         };
 
         std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
@@ -745,6 +1014,8 @@ mod tests {
             expected_frameworks: vec![],
             metadata: HashMap::new(),
             ground_truth: None,
+            url: None,
+            checksum: None,
         };
         std::fs::write(&valid_fixture_path, serde_json::to_string(&valid_fixture).unwrap()).unwrap();
 
@@ -760,6 +1031,8 @@ mod tests {
                 text_file: PathBuf::from("nonexistent.txt"),
                 source: "manual".to_string(),
             }),
+            url: None,
+            checksum: None,
         };
         std::fs::write(&invalid_fixture_path, serde_json::to_string(&invalid_fixture).unwrap()).unwrap();
 
@@ -775,4 +1048,139 @@ mod tests {
         // Should have loaded only the valid fixture
         assert_eq!(manager.len(), 1);
     }
+
+    #[test]
+    fn test_load_fixtures_from_dir_with_errors_reports_malformed_fixture() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        unsafe {
+            std::env::remove_var("PROFILING_FIXTURES");
+        }
+        let temp_dir = TempDir::new().unwrap();
+
+        let good = Fixture {
+            document: PathBuf::from("good.pdf"),
+            file_type: "pdf".to_string(),
+            file_size: 1024,
+            expected_frameworks: vec![],
+            metadata: HashMap::new(),
+            ground_truth: None,
+            url: None,
+            checksum: None,
+        };
+        std::fs::write(temp_dir.path().join("good.json"), serde_json::to_string(&good).unwrap()).unwrap();
+
+        let nested = temp_dir.path().join("nested");
+        std::fs::create_dir(&nested).unwrap();
+        let bad_path = nested.join("bad.json");
+        std::fs::write(&bad_path, r#"{"document": "bad.pdf", "file_type": "#).unwrap();
+
+        let mut manager = FixtureManager::new();
+        let (loaded, failed) = manager.load_fixtures_from_dir_with_errors(temp_dir.path()).unwrap();
+
+        assert_eq!(loaded, 1);
+        assert_eq!(manager.fixtures()[0].1.document, PathBuf::from("good.pdf"));
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].path, bad_path);
+        assert!(!failed[0].message.is_empty());
+    }
+
+    #[test]
+    fn test_fixture_filter_patterns() {
+        let filter = FixtureFilter::new(vec!["*.pdf".to_string()], vec!["*_broken.*".to_string()]);
+        assert!(filter.matches(Path::new("/data/pdf/report.pdf")));
+        assert!(!filter.matches(Path::new("/data/pdf/report_broken.pdf")));
+        assert!(!filter.matches(Path::new("/data/docx/report.docx")));
+
+        let filter = FixtureFilter::new(vec!["pdf/**/*.pdf".to_string(), "".to_string()], vec![]);
+        assert_eq!(filter.include.len(), 1);
+        assert!(filter.matches(Path::new("/data/pdf/report.pdf")));
+        assert!(filter.matches(Path::new("/data/pdf/scanned/report.pdf")));
+        assert!(!filter.matches(Path::new("/data/images/report.pdf")));
+
+        let filter = FixtureFilter::new(vec!["report_?.txt".to_string()], vec![]);
+        assert!(filter.matches(Path::new("report_1.txt")));
+        assert!(!filter.matches(Path::new("report_10.txt")));
+
+        assert!(FixtureFilter::default().matches(Path::new("anything.bin")));
+    }
+
+    #[test]
+    fn test_load_fixtures_from_dir_with_include_filter() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        unsafe {
+            std::env::remove_var("PROFILING_FIXTURES");
+        }
+        let temp_dir = TempDir::new().unwrap();
+
+        for (name, file_type) in [("report", "pdf"), ("scan", "pdf"), ("letter", "docx"), ("notes", "txt")] {
+            let fixture = Fixture {
+                document: PathBuf::from(format!("{}.{}", name, file_type)),
+                file_type: file_type.to_string(),
+                file_size: 1024,
+                expected_frameworks: vec![],
+                metadata: HashMap::new(),
+                ground_truth: None,
+                url: None,
+                checksum: None,
+            };
+            let fixture_path = temp_dir.path().join(format!("{}_{}.json", name, file_type));
+            std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
+        }
+
+        let mut manager = FixtureManager::with_filter(FixtureFilter::new(vec!["*.pdf".to_string()], vec![]));
+        manager.load_fixtures_from_dir(temp_dir.path()).unwrap();
+
+        assert_eq!(manager.len(), 2);
+        assert!(manager.fixtures().iter().all(|(_, fixture)| fixture.file_type == "pdf"));
+    }
+
+    #[test]
+    fn test_checksum_verification_detects_tampering() {
+        let temp_dir = TempDir::new().unwrap();
+
+        for name in ["untouched", "tampered"] {
+            std::fs::write(temp_dir.path().join(format!("{}.txt", name)), "original contents").unwrap();
+            let fixture = Fixture {
+                document: PathBuf::from(format!("{}.txt", name)),
+                file_type: "txt".to_string(),
+                file_size: 17,
+                expected_frameworks: vec![],
+                metadata: HashMap::new(),
+                ground_truth: None,
+                url: None,
+                checksum: None,
+            };
+            let fixture_path = temp_dir.path().join(format!("{}.json", name));
+            std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
+        }
+
+        let load = |name: &str| {
+            let mut manager = FixtureManager::new();
+            manager
+                .load_fixture(temp_dir.path().join(format!("{}.json", name)))
+                .unwrap();
+            manager
+        };
+
+        let mut manager = FixtureManager::new();
+        for name in ["untouched", "tampered"] {
+            manager
+                .load_fixture(temp_dir.path().join(format!("{}.json", name)))
+                .unwrap();
+        }
+        assert_eq!(manager.verify_checksums().unwrap(), 0);
+        assert_eq!(manager.write_checksums().unwrap(), 2);
+        assert_eq!(manager.write_checksums().unwrap(), 0);
+
+        std::fs::write(temp_dir.path().join("tampered.txt"), "modified contents").unwrap();
+
+        assert_eq!(load("untouched").verify_checksums().unwrap(), 1);
+        match load("tampered").verify_checksums() {
+            Err(Error::ChecksumMismatch { path, expected, actual }) => {
+                assert_eq!(path, temp_dir.path().join("tampered.txt"));
+                assert_ne!(expected, actual);
+            }
+            other => panic!("expected checksum mismatch, got {:?}", other),
+        }
+    }
 }
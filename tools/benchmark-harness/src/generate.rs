@@ -219,6 +219,8 @@ fn process_document(
         expected_frameworks: get_expected_frameworks(file_type),
         metadata: build_metadata(doc_path, config),
         ground_truth,
+        url: None,
+        checksum: None,
     };
 
     // Write fixture
@@ -9,6 +9,7 @@ pub mod adapters;
 pub mod aggregate;
 pub mod config;
 pub mod consolidate;
+pub mod environment;
 pub mod error;
 pub mod fixture;
 pub mod generate;
@@ -25,24 +26,26 @@ pub mod stats;
 pub mod types;
 
 pub use adapter::FrameworkAdapter;
-pub use adapters::{NativeAdapter, NodeAdapter, PythonAdapter, RubyAdapter};
+pub use adapters::{NativeAdapter, NodeAdapter, PythonAdapter, RubyAdapter, UrlAdapter};
 pub use aggregate::{
     ComparisonData, ConsolidationMetadata, DeltaMetrics, DurationPercentiles, FileTypeAggregation,
     FrameworkModeAggregation, NewConsolidatedResults, Percentiles, PerformancePercentiles, QualityPercentiles,
-    RankedFramework, aggregate_new_format,
+    RankedFramework, SpeedQualityPoint, SpeedQualityScatter, aggregate_new_format,
 };
-pub use config::{BenchmarkConfig, BenchmarkMode, ProfilingConfig, load_framework_sizes};
+pub use config::{BenchmarkConfig, BenchmarkMode, ProfilingConfig, load_framework_sizes, parse_timeout_overrides};
 pub use consolidate::{
-    ConsolidatedResults, CrossFrameworkComparison, FrameworkAggregation, FrameworkQuality, QualityAnalysis,
-    aggregate_by_framework, analyze_quality, compare_frameworks, consolidate_runs, load_run_results,
+    CapabilityBucket, ConsolidatedResults, ConsolidationOptions, CrossFrameworkComparison, FrameworkAggregation,
+    FrameworkQuality, QualityAnalysis, aggregate_by_framework, analyze_quality, bucket_by_capability,
+    compare_frameworks, consolidate_runs, consolidate_runs_with_options, format_capability_summary, load_run_results,
     write_consolidated_json,
 };
+pub use environment::{EnvironmentInfo, distinct_environments, load_environment, load_environments, write_environment};
 pub use error::{Error, Result};
-pub use fixture::{Fixture, FixtureManager};
+pub use fixture::{Fixture, FixtureFilter, FixtureLoadError, FixtureManager};
 pub use monitoring::{ResourceMonitor, ResourceSample, ResourceStats};
-pub use output::{write_by_extension_analysis, write_json};
+pub use output::{JsonlWriter, read_jsonl, write_by_extension_analysis, write_json, write_jsonl};
 pub use pool_metrics::{FilePoolMetrics, PoolMetricsReport};
-pub use profile_report::{Hotspot, MemorySnapshot, ProfileReport};
+pub use profile_report::{Hotspot, MemorySnapshot, ProfileReport, ReportTheme, generate_flamegraph_index};
 pub use quality::compute_quality;
 pub use registry::AdapterRegistry;
 pub use runner::BenchmarkRunner;
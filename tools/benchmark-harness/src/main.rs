@@ -56,6 +56,14 @@ enum Commands {
         /// Directory or file pattern to search for fixtures
         #[arg(short, long)]
         fixtures: PathBuf,
+
+        /// Fail if a document no longer matches the checksum recorded in its fixture
+        #[arg(long, default_value = "false")]
+        verify_checksums: bool,
+
+        /// Record the current SHA-256 checksum of every document in its fixture file
+        #[arg(long, default_value = "false", conflicts_with = "verify_checksums")]
+        write_checksums: bool,
     },
 
     /// Run benchmarks
@@ -80,6 +88,10 @@ enum Commands {
         #[arg(short = 't', long)]
         timeout: Option<u64>,
 
+        /// Per-extension timeouts in seconds, overriding --timeout (e.g. pdf=300,txt=10)
+        #[arg(long, value_delimiter = ',')]
+        timeout_by_extension: Vec<String>,
+
         /// Benchmark mode: single-file (sequential) or batch (concurrent)
         #[arg(short = 'm', long, value_enum, default_value = "batch")]
         mode: CliMode,
@@ -99,6 +111,34 @@ enum Commands {
         /// Enable quality assessment
         #[arg(long, default_value = "false")]
         measure_quality: bool,
+
+        /// Capture a CPU flamegraph per framework and fixture, then write an index to <output>/flamegraphs
+        #[arg(long, default_value = "false")]
+        flamegraph: bool,
+
+        /// Reset in-process caches (string pools, font cache) before each framework run
+        #[arg(long, default_value = "false")]
+        reset_caches: bool,
+
+        /// Stream each result to <output>/results.jsonl as soon as it completes
+        #[arg(long, default_value = "false")]
+        stream_results: bool,
+
+        /// Resume an interrupted run from <output>/results.jsonl, skipping pairs that already succeeded (implies --stream-results)
+        #[arg(long, default_value = "false")]
+        resume: bool,
+
+        /// Only run fixtures whose document path matches one of these globs (e.g. '*.pdf')
+        #[arg(long, value_delimiter = ',')]
+        include: Vec<String>,
+
+        /// Skip fixtures whose document path matches any of these globs (e.g. '*_broken.*')
+        #[arg(long, value_delimiter = ',')]
+        exclude: Vec<String>,
+
+        /// Fail before benchmarking if a document no longer matches the checksum recorded in its fixture
+        #[arg(long, default_value = "false")]
+        verify_checksums: bool,
     },
 
     /// Consolidate multiple benchmark runs
@@ -114,6 +154,11 @@ enum Commands {
         /// Baseline framework for delta calculations (not used but provided for compatibility)
         #[arg(long, default_value = "kreuzberg-rust")]
         baseline: String,
+
+        /// Also compare frameworks only against others with the same capabilities (e.g. OCR),
+        /// writing capability_buckets.json and printing one summary table per bucket
+        #[arg(long, default_value = "false")]
+        bucket_by_capability: bool,
     },
 
     /// Generate fixture JSON files from vendored test documents
@@ -135,6 +180,17 @@ enum Commands {
         overwrite: bool,
     },
 
+    /// Generate an HTML index of flamegraph SVGs
+    GenerateFlamegraphIndex {
+        /// Directory containing flamegraph SVGs (searched recursively)
+        #[arg(long)]
+        flamegraphs: PathBuf,
+
+        /// Output HTML file
+        #[arg(long)]
+        output: PathBuf,
+    },
+
     /// Measure framework installation sizes
     MeasureFrameworkSizes {
         /// Output JSON file for framework sizes
@@ -170,16 +226,49 @@ async fn main() -> Result<()> {
             Ok(())
         }
 
-        Commands::Validate { fixtures } => {
+        Commands::Validate {
+            fixtures,
+            verify_checksums,
+            write_checksums,
+        } => {
             let mut manager = FixtureManager::new();
 
             if fixtures.is_dir() {
-                manager.load_fixtures_from_dir(&fixtures)?;
+                let (loaded, failed) = manager.load_fixtures_from_dir_with_errors(&fixtures)?;
+
+                if !failed.is_empty() {
+                    eprintln!(
+                        "✗ {} of {} fixture(s) are invalid:",
+                        failed.len(),
+                        loaded + failed.len()
+                    );
+                    for error in &failed {
+                        eprintln!("  - {}", error);
+                    }
+                    return Err(benchmark_harness::Error::Benchmark(format!(
+                        "{} invalid fixture(s)",
+                        failed.len()
+                    )));
+                }
             } else {
                 manager.load_fixture(&fixtures)?;
             }
 
             println!("✓ All {} fixture(s) are valid", manager.len());
+
+            if write_checksums {
+                let updated = manager.write_checksums()?;
+                println!("✓ Wrote checksums to {} fixture(s)", updated);
+            } else if verify_checksums {
+                let verified = manager.verify_checksums()?;
+                println!(
+                    "✓ {} of {} fixture(s) match their checksums ({} without a checksum)",
+                    verified,
+                    manager.len(),
+                    manager.len() - verified
+                );
+            }
+
             Ok(())
         }
 
@@ -189,13 +278,23 @@ async fn main() -> Result<()> {
             output,
             max_concurrent,
             timeout,
+            timeout_by_extension,
             mode,
             warmup,
             iterations,
             ocr,
             measure_quality,
+            flamegraph,
+            reset_caches,
+            stream_results,
+            resume,
+            include,
+            exclude,
+            verify_checksums,
         } => {
-            use benchmark_harness::{AdapterRegistry, BenchmarkRunner, NativeAdapter};
+            use benchmark_harness::{
+                AdapterRegistry, BenchmarkRunner, FixtureFilter, NativeAdapter, ProfilingConfig, UrlAdapter,
+            };
             use kreuzberg::{ExtractionConfig, OcrConfig};
             use std::sync::Arc;
 
@@ -209,20 +308,45 @@ async fn main() -> Result<()> {
                 }
             }
 
+            let fixture_filter = FixtureFilter::new(include, exclude);
+
+            let profile = flamegraph || std::env::var("ENABLE_PROFILING").unwrap_or_default() == "true";
+            let profile = if profile && !benchmark_harness::profiling::is_supported() {
+                eprintln!(
+                    "Warning: CPU profiling requires the 'profiling' feature on a non-Windows platform; skipping flamegraphs"
+                );
+                false
+            } else {
+                profile
+            };
+
             let config = BenchmarkConfig {
                 output_dir: output.clone(),
                 max_concurrent: max_concurrent.unwrap_or_else(num_cpus::get),
                 timeout: std::time::Duration::from_secs(timeout.unwrap_or(1800)),
+                timeout_by_extension: benchmark_harness::parse_timeout_overrides(&timeout_by_extension)?,
                 benchmark_mode: mode.into(),
                 warmup_iterations: warmup,
                 benchmark_iterations: iterations,
                 measure_quality,
                 ocr_enabled: ocr,
+                reset_caches_between_frameworks: reset_caches,
+                stream_results_path: (stream_results || resume).then(|| output.join("results.jsonl")),
+                resume,
+                profiling: ProfilingConfig {
+                    enabled: profile,
+                    flamegraph_enabled: profile,
+                    ..Default::default()
+                },
                 ..Default::default()
             };
 
             config.validate()?;
 
+            // Written up front so interrupted (streamed) runs still record their environment
+            let environment = benchmark_harness::EnvironmentInfo::capture();
+            benchmark_harness::write_environment(&environment, &output)?;
+
             let mut extraction_config = if ocr {
                 ExtractionConfig {
                     ocr: Some(OcrConfig {
@@ -230,6 +354,12 @@ async fn main() -> Result<()> {
                         language: "eng".to_string(),
                         tesseract_config: None,
                         output_format: None,
+                        dpi: None,
+                        psm: None,
+                        oem: None,
+                        binarization: None,
+                        ocr_pages: None,
+                        language_fallback: None,
                     }),
                     ..Default::default()
                 }
@@ -266,6 +396,21 @@ async fn main() -> Result<()> {
 
             // Always register native adapter if requested or no specific frameworks specified
             let mut kreuzberg_count = 0;
+
+            // The URL adapter is opt-in so that default runs never touch the network
+            if frameworks.iter().any(|f| f == "kreuzberg-rust-url") {
+                let mut url_fixtures = FixtureManager::with_filter(fixture_filter.clone());
+                if fixtures.is_dir() {
+                    url_fixtures.load_fixtures_from_dir(&fixtures)?;
+                } else {
+                    url_fixtures.load_fixture(&fixtures)?;
+                }
+                let adapter = UrlAdapter::from_fixtures(&url_fixtures, extraction_config.clone());
+                registry.register(Arc::new(adapter))?;
+                eprintln!("[adapter] ✓ kreuzberg-rust-url (registered)");
+                kreuzberg_count += 1;
+            }
+
             if should_init("kreuzberg-rust") {
                 registry.register(Arc::new(NativeAdapter::with_config(extraction_config)))?;
                 eprintln!("[adapter] ✓ kreuzberg-rust (registered)");
@@ -321,11 +466,24 @@ async fn main() -> Result<()> {
             );
 
             let mut runner = BenchmarkRunner::new(config, registry);
+            runner.set_fixture_filter(fixture_filter);
             runner.load_fixtures(&fixtures)?;
 
             println!("Loaded {} fixture(s)", runner.fixture_count());
+            if verify_checksums {
+                let verified = runner.verify_fixture_checksums()?;
+                println!("Verified {} fixture checksum(s)", verified);
+            }
             println!("Frameworks: {:?}", frameworks);
             println!("Configuration: {:?}", runner.config());
+            println!(
+                "Environment: {} {} ({} CPUs, {}), kreuzberg {}",
+                environment.os,
+                environment.arch,
+                environment.num_cpus,
+                environment.cpu_model.as_deref().unwrap_or("unknown CPU"),
+                environment.kreuzberg_version
+            );
 
             if runner.fixture_count() == 0 {
                 println!("No fixtures to benchmark");
@@ -364,12 +522,20 @@ async fn main() -> Result<()> {
             write_by_extension_analysis(&results, &by_ext_file)?;
             println!("Per-extension analysis written to: {}", by_ext_file.display());
 
+            if profile {
+                let flamegraph_dir = output.join(benchmark_harness::profile_report::FLAMEGRAPH_DIR);
+                let index_file = flamegraph_dir.join("index.html");
+                benchmark_harness::generate_flamegraph_index(&flamegraph_dir, &index_file)?;
+                println!("Flamegraph index written to: {}", index_file.display());
+            }
+
             Ok(())
         }
         Commands::Consolidate {
             inputs,
             output,
             baseline: _baseline,
+            bucket_by_capability,
         } => {
             use benchmark_harness::load_run_results;
 
@@ -382,6 +548,7 @@ async fn main() -> Result<()> {
             println!("Loading benchmark results from {} directory(ies)...", inputs.len());
 
             let mut all_results = Vec::new();
+            let mut environments = Vec::new();
             for input in &inputs {
                 if !input.is_dir() {
                     return Err(benchmark_harness::Error::Benchmark(format!(
@@ -393,10 +560,22 @@ async fn main() -> Result<()> {
                 let run_results = load_run_results(input)?;
                 println!("    Loaded {} results", run_results.len());
                 all_results.extend(run_results);
+                environments.extend(benchmark_harness::load_environments(input)?);
+            }
+
+            let environments = benchmark_harness::distinct_environments(environments);
+            if let [baseline, others @ ..] = environments.as_slice() {
+                for other in others {
+                    eprintln!(
+                        "Warning: results come from different environments ({}); comparisons across them may be misleading",
+                        baseline.differences(other).join(", ")
+                    );
+                }
             }
 
             println!("\nAggregating {} results...", all_results.len());
-            let aggregated = benchmark_harness::aggregate_new_format(&all_results);
+            let mut aggregated = benchmark_harness::aggregate_new_format(&all_results);
+            aggregated.metadata.environments = environments;
             println!(
                 "  Aggregated {} frameworks across {} file types",
                 aggregated.by_framework_mode.len(),
@@ -418,6 +597,15 @@ async fn main() -> Result<()> {
                 let _ = key; // used as map key
             }
 
+            let scatter = &aggregated.comparison.speed_vs_quality;
+            if !scatter.excluded.is_empty() {
+                eprintln!(
+                    "\nNote: {} framework(s) have no quality data and are left out of the speed vs quality scatter: {}",
+                    scatter.excluded.len(),
+                    scatter.excluded.join(", ")
+                );
+            }
+
             std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
 
             // Single unified output file
@@ -427,6 +615,18 @@ async fn main() -> Result<()> {
             std::fs::write(&output_file, json).map_err(benchmark_harness::Error::Io)?;
             println!("\nResults written to: {}", output_file.display());
 
+            if bucket_by_capability {
+                let buckets = benchmark_harness::bucket_by_capability(&all_results);
+                eprintln!("\n{}", benchmark_harness::format_capability_summary(&buckets));
+
+                let buckets_file = output.join("capability_buckets.json");
+                let json = serde_json::to_string_pretty(&buckets).map_err(|e| {
+                    benchmark_harness::Error::Benchmark(format!("Failed to serialize capability buckets: {}", e))
+                })?;
+                std::fs::write(&buckets_file, json).map_err(benchmark_harness::Error::Io)?;
+                println!("Capability buckets written to: {}", buckets_file.display());
+            }
+
             Ok(())
         }
 
@@ -472,6 +672,12 @@ async fn main() -> Result<()> {
             Ok(())
         }
 
+        Commands::GenerateFlamegraphIndex { flamegraphs, output } => {
+            benchmark_harness::generate_flamegraph_index(&flamegraphs, &output)?;
+            println!("Flamegraph index written to: {}", output.display());
+            Ok(())
+        }
+
         Commands::MeasureFrameworkSizes { output } => {
             use benchmark_harness::{measure_framework_sizes, save_framework_sizes};
 
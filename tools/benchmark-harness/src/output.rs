@@ -1,7 +1,7 @@
 //! Output writers for benchmark results
 //!
 //! This module provides functionality for persisting benchmark results to disk
-//! in JSON format.
+//! in JSON format, or as JSON Lines (one result per line) for streaming.
 
 use crate::stats::percentile_r7;
 use crate::types::{BenchmarkResult, ErrorKind};
@@ -9,6 +9,7 @@ use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
+use std::io::{BufRead, BufReader, BufWriter, Write};
 use std::path::Path;
 
 /// Validate a benchmark result for invalid states
@@ -66,6 +67,90 @@ pub fn write_json(results: &[BenchmarkResult], output_path: &Path) -> Result<()>
     Ok(())
 }
 
+/// Streaming JSON Lines writer for benchmark results
+///
+/// Each result is written as one JSON object per line and flushed immediately, so the
+/// file can be tailed while a run is in progress and read back if the run is interrupted.
+pub struct JsonlWriter {
+    writer: BufWriter<fs::File>,
+}
+
+impl JsonlWriter {
+    /// Create (or truncate) a JSON Lines file, creating parent directories as needed
+    pub fn create(output_path: &Path) -> Result<Self> {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent).map_err(Error::Io)?;
+        }
+
+        let file = fs::File::create(output_path).map_err(Error::Io)?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    /// Validate and append a single result
+    pub fn write(&mut self, result: &BenchmarkResult) -> Result<()> {
+        validate_result(result)?;
+
+        serde_json::to_writer(&mut self.writer, result)
+            .map_err(|e| Error::Benchmark(format!("Failed to serialize result: {}", e)))?;
+        self.writer.write_all(b"\n").map_err(Error::Io)?;
+        self.writer.flush().map_err(Error::Io)?;
+
+        Ok(())
+    }
+}
+
+/// Write benchmark results to a JSON Lines file as they are produced
+///
+/// # Arguments
+/// * `results` - Results to write, consumed one at a time
+/// * `output_path` - Path to output `.jsonl` file
+pub fn write_jsonl<'a>(results: impl IntoIterator<Item = &'a BenchmarkResult>, output_path: &Path) -> Result<()> {
+    let mut writer = JsonlWriter::create(output_path)?;
+    for result in results {
+        writer.write(result)?;
+    }
+    Ok(())
+}
+
+/// Read benchmark results from a JSON Lines file
+///
+/// Blank lines are ignored. A malformed final line is skipped with a warning, since it is
+/// most likely a partial write from an interrupted run; malformed lines elsewhere are errors.
+pub fn read_jsonl(path: &Path) -> Result<Vec<BenchmarkResult>> {
+    let file = fs::File::open(path).map_err(Error::Io)?;
+    let lines: Vec<String> = BufReader::new(file)
+        .lines()
+        .collect::<std::io::Result<_>>()
+        .map_err(Error::Io)?;
+    let last_line = lines.iter().rposition(|line| !line.trim().is_empty());
+
+    let mut results = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match serde_json::from_str(line) {
+            Ok(result) => results.push(result),
+            Err(e) if Some(index) == last_line => {
+                eprintln!("Warning: Skipping incomplete last line of {}: {}", path.display(), e);
+            }
+            Err(e) => {
+                return Err(Error::Benchmark(format!(
+                    "Failed to parse {} line {}: {}",
+                    path.display(),
+                    index + 1,
+                    e
+                )));
+            }
+        }
+    }
+
+    Ok(results)
+}
+
 /// Per-framework statistics for a specific file extension
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FrameworkExtensionStats {
@@ -333,6 +418,7 @@ mod tests {
             } else {
                 ErrorKind::HarnessError
             },
+            timed_out: false,
             duration: Duration::from_millis(duration_ms),
             extraction_duration: extraction_duration_ms.map(Duration::from_millis),
             subprocess_overhead: extraction_duration_ms.map(|ed| Duration::from_millis(duration_ms.saturating_sub(ed))),
@@ -368,6 +454,7 @@ mod tests {
             success: true,
             error_message: None,
             error_kind: ErrorKind::None,
+            timed_out: false,
             duration: Duration::from_secs(1),
             extraction_duration: None,
             subprocess_overhead: None,
@@ -413,6 +500,43 @@ mod tests {
         assert!(output_path.parent().unwrap().exists());
     }
 
+    #[test]
+    fn test_jsonl_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("run/results.jsonl");
+
+        let results = vec![
+            create_benchmark_result("kreuzberg", true, 100, Some(80), 1_000_000.0, 10_000_000),
+            create_benchmark_result("docling", false, 250, None, 0.0, 50_000_000),
+            create_benchmark_result("pandoc", true, 40, Some(35), 2_500_000.0, 5_000_000),
+        ];
+
+        write_jsonl(&results, &output_path).unwrap();
+
+        let contents = fs::read_to_string(&output_path).unwrap();
+        assert_eq!(contents.lines().count(), results.len());
+
+        let parsed = read_jsonl(&output_path).unwrap();
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&results).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_read_jsonl_skips_truncated_last_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("results.jsonl");
+
+        let result = create_benchmark_result("kreuzberg", true, 100, None, 1_000_000.0, 10_000_000);
+        let line = serde_json::to_string(&result).unwrap();
+        fs::write(&output_path, format!("{}\n{}", line, &line[..line.len() / 2])).unwrap();
+        assert_eq!(read_jsonl(&output_path).unwrap().len(), 1);
+
+        fs::write(&output_path, format!("{{\n{}\n", line)).unwrap();
+        assert!(read_jsonl(&output_path).is_err());
+    }
+
     // ============================================================================
     // Tests for extraction_duration statistics in calculate_framework_stats
     // ============================================================================
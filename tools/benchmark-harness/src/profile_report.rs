@@ -22,11 +22,23 @@
 //!
 //! Reports are generated as self-contained HTML documents with inline CSS, requiring
 //! no external dependencies. The HTML is viewable in any modern web browser.
+//! [`ReportTheme`] selects the palette: the default colors, a color-blind-safe
+//! Okabe-Ito palette, or high-contrast grayscale for printing.
+//!
+//! # Flamegraph Index
+//!
+//! [`generate_flamegraph_index`] builds a gallery page linking every flamegraph SVG
+//! written during a run, grouped by framework.
 
 #[cfg(feature = "profiling")]
 use crate::profiling::ProfilingResult;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+/// Directory (relative to the run output directory) holding flamegraphs
+pub const FLAMEGRAPH_DIR: &str = "flamegraphs";
+
 /// Comprehensive profiling report with hotspot analysis
 ///
 /// Contains aggregated profiling metrics, top functions, and analysis recommendations
@@ -47,6 +59,18 @@ pub struct ProfileReport {
     pub recommendations: Vec<String>,
 }
 
+/// Color theme for the HTML report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReportTheme {
+    /// Blue and purple gradients
+    #[default]
+    Default,
+    /// Okabe-Ito palette, distinguishable with common forms of color blindness
+    Colorblind,
+    /// High-contrast grayscale with striped bars, for printing
+    Print,
+}
+
 /// Individual function hotspot identified in the profile
 ///
 /// Represents a function that consumed significant CPU samples during profiling.
@@ -259,6 +283,14 @@ impl ProfileReport {
     ///
     /// HTML string with the formatted report
     pub fn generate_html(&self) -> String {
+        self.generate_html_with_theme(ReportTheme::Default)
+    }
+
+    /// Generate an HTML report using the given color theme
+    ///
+    /// Same content as [`generate_html`](Self::generate_html), with the theme's
+    /// palette applied on top of the base styles.
+    pub fn generate_html_with_theme(&self, theme: ReportTheme) -> String {
         let hotspots_html = self.render_hotspots_table();
         let recommendations_html = self.render_recommendations();
         let memory_html = if self.memory_trajectory.is_empty() {
@@ -267,7 +299,7 @@ impl ProfileReport {
             self.render_memory_chart()
         };
 
-        let css = Self::css_styles();
+        let css = format!("{}{}", Self::css_styles(), Self::theme_styles(theme));
         let duration_ms = self.duration.as_millis();
 
         format!(
@@ -760,6 +792,226 @@ impl ProfileReport {
         }
         "#
     }
+
+    /// Inline CSS overriding the report colors for a theme
+    ///
+    /// Empty for the default theme, whose colors are part of the base styles.
+    fn theme_styles(theme: ReportTheme) -> &'static str {
+        match theme {
+            ReportTheme::Default => "",
+            ReportTheme::Colorblind => {
+                r#"
+        .report-header {
+            background: #0072B2;
+        }
+
+        h2,
+        .stat-value,
+        .hotspots-table th,
+        .rank {
+            color: #0072B2;
+        }
+
+        .sample-count,
+        .percentage-text {
+            color: #D55E00;
+        }
+
+        .bar {
+            background: #E69F00;
+        }
+
+        .recommendations-list li {
+            border-left-color: #009E73;
+        }
+
+        .recommendations-list li:before {
+            color: #009E73;
+        }
+        "#
+            }
+            ReportTheme::Print => {
+                r#"
+        body {
+            background: white;
+            color: #000;
+        }
+
+        .container {
+            box-shadow: none;
+        }
+
+        .report-header {
+            background: white;
+            color: #000;
+            border-bottom: 3px solid #000;
+        }
+
+        h2,
+        .stat-value,
+        .hotspots-table th,
+        .rank,
+        .sample-count,
+        .percentage-text,
+        .recommendations-list li:before {
+            color: #000;
+        }
+
+        .summary-stats,
+        .hotspots-table thead,
+        .recommendations-section,
+        .memory-section,
+        .report-footer {
+            background: white;
+        }
+
+        .bar {
+            background: repeating-linear-gradient(45deg, #000 0, #000 3px, #fff 3px, #fff 6px);
+            border: 1px solid #000;
+            -webkit-print-color-adjust: exact;
+            print-color-adjust: exact;
+        }
+
+        .recommendations-list li {
+            border-left-color: #000;
+        }
+        "#
+            }
+        }
+    }
+}
+
+/// Generate an HTML index of all flamegraphs below `flamegraph_dir`
+///
+/// Flamegraphs are expected at `<framework>/<mode>/<fixture>.svg`, as written by the
+/// benchmark runner. Each SVG is embedded in a section for its framework, linked
+/// alongside its `<fixture>_report.html` profile report when one exists. Links are
+/// relative to the index file, so the output can be moved or published as a whole.
+///
+/// # Arguments
+///
+/// * `flamegraph_dir` - Directory searched recursively for `.svg` files
+/// * `output_file` - Path of the HTML index to write
+///
+/// # Errors
+///
+/// Returns an error if the flamegraph directory cannot be read or the index cannot be written
+pub fn generate_flamegraph_index(flamegraph_dir: &Path, output_file: &Path) -> crate::Result<()> {
+    let mut svgs = Vec::new();
+    if flamegraph_dir.is_dir() {
+        collect_svgs(flamegraph_dir, &mut svgs)?;
+    }
+    svgs.sort();
+
+    let index_dir = output_file
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    std::fs::create_dir_all(index_dir)?;
+    let relative_href = |path: &Path| -> String {
+        let absolute = |p: &Path| std::path::absolute(p).unwrap_or_else(|_| p.to_path_buf());
+        let relative = pathdiff::diff_paths(absolute(path), absolute(index_dir)).unwrap_or_else(|| path.to_path_buf());
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join("/")
+    };
+
+    let mut by_framework: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    for svg in svgs {
+        let framework = svg
+            .strip_prefix(flamegraph_dir)
+            .ok()
+            .filter(|relative| relative.components().count() > 1)
+            .and_then(|relative| relative.components().next())
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .unwrap_or_else(|| "other".to_string());
+        by_framework.entry(framework).or_default().push(svg);
+    }
+
+    let mut sections = String::new();
+    for (framework, entries) in &by_framework {
+        sections.push_str(&format!(
+            "        <section>\n            <h2>{}</h2>\n",
+            html_escape(framework)
+        ));
+        for svg in entries {
+            let label = svg
+                .strip_prefix(flamegraph_dir.join(framework))
+                .unwrap_or(svg)
+                .with_extension("")
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            let report_path = svg.with_file_name(format!(
+                "{}_report.html",
+                svg.file_stem().map(|s| s.to_string_lossy()).unwrap_or_default()
+            ));
+            let report_link = if report_path.exists() {
+                format!(
+                    r#" &middot; <a href="{}">report</a>"#,
+                    html_escape(&relative_href(&report_path))
+                )
+            } else {
+                String::new()
+            };
+
+            sections.push_str(&format!(
+                r#"            <figure>
+                <figcaption><a href="{href}">{label}</a>{report_link}</figcaption>
+                <object type="image/svg+xml" data="{href}"></object>
+            </figure>
+"#,
+                href = html_escape(&relative_href(svg)),
+                label = html_escape(&label),
+                report_link = report_link,
+            ));
+        }
+        sections.push_str("        </section>\n");
+    }
+
+    if sections.is_empty() {
+        sections.push_str("        <p>No flamegraphs were produced for this run.</p>\n");
+    }
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Flamegraphs</title>
+    <style>
+        body {{ font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; margin: 20px; color: #333; }}
+        section {{ margin-bottom: 40px; }}
+        figure {{ margin: 0 0 24px 0; }}
+        figcaption {{ font-family: monospace; margin-bottom: 8px; }}
+        object {{ width: 100%; border: 1px solid #ddd; }}
+    </style>
+</head>
+<body>
+    <h1>Flamegraphs</h1>
+{sections}</body>
+</html>
+"#
+    );
+
+    std::fs::write(output_file, html)?;
+    Ok(())
+}
+
+/// Recursively collect `.svg` files below `dir`
+fn collect_svgs(dir: &Path, svgs: &mut Vec<PathBuf>) -> crate::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_svgs(&path, svgs)?;
+        } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("svg")) {
+            svgs.push(path);
+        }
+    }
+    Ok(())
 }
 
 /// Escape HTML special characters
@@ -960,4 +1212,71 @@ mod tests {
         assert!(css.contains("border-radius"));
         assert!(css.contains("font-family"));
     }
+
+    #[test]
+    fn test_generate_html_applies_theme_palette() {
+        let report = ProfileReport::default();
+
+        let default_html = report.generate_html();
+        assert!(default_html.contains("#667eea"));
+        assert!(!default_html.contains("#0072B2"));
+
+        let colorblind_html = report.generate_html_with_theme(ReportTheme::Colorblind);
+        for color in ["#0072B2", "#D55E00", "#E69F00", "#009E73"] {
+            assert!(colorblind_html.contains(color), "missing Okabe-Ito color {}", color);
+        }
+
+        let print_html = report.generate_html_with_theme(ReportTheme::Print);
+        assert!(print_html.contains("repeating-linear-gradient"));
+        assert!(!print_html.contains("#0072B2"));
+        assert_eq!(report.generate_html_with_theme(ReportTheme::Default), default_html);
+    }
+
+    #[test]
+    fn test_generate_flamegraph_index_references_each_svg() -> crate::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let flamegraph_dir = temp_dir.path().join(FLAMEGRAPH_DIR);
+        let svgs = [
+            "kreuzberg-rust/batch/sample.svg",
+            "kreuzberg-rust/single-file/sample.svg",
+            "docling/batch/report.svg",
+        ];
+        for svg in svgs {
+            let path = flamegraph_dir.join(svg);
+            std::fs::create_dir_all(path.parent().unwrap())?;
+            std::fs::write(&path, "<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>")?;
+        }
+        std::fs::write(
+            flamegraph_dir.join("kreuzberg-rust/batch/sample_report.html"),
+            "<html></html>",
+        )?;
+
+        let index_path = flamegraph_dir.join("index.html");
+        generate_flamegraph_index(&flamegraph_dir, &index_path)?;
+        let index = std::fs::read_to_string(&index_path)?;
+
+        for svg in svgs {
+            assert!(
+                index.contains(&format!(r#"data="{}""#, svg)),
+                "index should embed {}",
+                svg
+            );
+        }
+        assert!(index.contains(r#"href="kreuzberg-rust/batch/sample_report.html""#));
+        assert!(!index.contains("single-file/sample_report.html"));
+        assert!(index.find("<h2>docling</h2>") < index.find("<h2>kreuzberg-rust</h2>"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_flamegraph_index_empty_run() -> crate::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+
+        let index_path = temp_dir.path().join("flamegraphs.html");
+        generate_flamegraph_index(&temp_dir.path().join(FLAMEGRAPH_DIR), &index_path)?;
+        let index = std::fs::read_to_string(index_path)?;
+
+        assert!(index.contains("No flamegraphs were produced"));
+        Ok(())
+    }
 }
@@ -41,6 +41,14 @@ use std::path::Path;
 #[cfg(all(feature = "profiling", not(target_os = "windows")))]
 use std::time::Duration;
 
+/// Whether CPU profiling is available in this build
+///
+/// Returns `false` when the `profiling` feature is disabled or on Windows, where
+/// all profiling types fall back to no-op implementations.
+pub const fn is_supported() -> bool {
+    cfg!(all(feature = "profiling", not(target_os = "windows")))
+}
+
 /// CPU profiler with RAII semantics
 ///
 /// Automatically stops profiling when dropped. Captures CPU samples at the specified
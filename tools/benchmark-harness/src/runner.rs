@@ -5,12 +5,13 @@
 
 use crate::adapter::FrameworkAdapter;
 use crate::config::{BenchmarkConfig, BenchmarkMode};
-use crate::fixture::FixtureManager;
+use crate::fixture::{FixtureFilter, FixtureManager};
+use crate::output::JsonlWriter;
 use crate::registry::AdapterRegistry;
 use crate::stats::percentile_r7;
 use crate::types::{BenchmarkResult, DiskSizeInfo, DurationStatistics, IterationResult, PerformanceMetrics};
 use crate::{Error, Result};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
@@ -126,15 +127,6 @@ fn calculate_statistics(iterations: &[IterationResult]) -> DurationStatistics {
     }
 }
 
-/// Check if profiling is enabled via environment variable
-///
-/// # Returns
-/// `true` if `ENABLE_PROFILING=true` is set, `false` otherwise
-#[cfg(feature = "profiling")]
-fn should_profile() -> bool {
-    std::env::var("ENABLE_PROFILING").unwrap_or_default() == "true"
-}
-
 /// Aggregate performance metrics from iterations (average)
 fn aggregate_metrics(iterations: &[IterationResult]) -> PerformanceMetrics {
     if iterations.is_empty() {
@@ -223,6 +215,11 @@ impl BenchmarkRunner {
         }
     }
 
+    /// Restrict fixtures loaded from now on to those passing `filter`
+    pub fn set_fixture_filter(&mut self, filter: FixtureFilter) {
+        self.fixtures.set_filter(filter);
+    }
+
     /// Load fixtures from a directory or file
     pub fn load_fixtures(&mut self, path: &PathBuf) -> Result<()> {
         if path.is_dir() {
@@ -233,6 +230,13 @@ impl BenchmarkRunner {
         Ok(())
     }
 
+    /// Verify loaded fixtures against their recorded checksums
+    ///
+    /// Returns the number of fixtures verified. See [`FixtureManager::verify_checksums`].
+    pub fn verify_fixture_checksums(&self) -> Result<usize> {
+        self.fixtures.verify_checksums()
+    }
+
     /// Filter fixtures by file type (to be implemented when needed)
     ///
     /// For now, filtering is done during execution based on adapter support
@@ -279,10 +283,11 @@ impl BenchmarkRunner {
         cold_start_duration: Option<Duration>,
     ) -> Result<BenchmarkResult> {
         let mut all_results = Vec::new();
+        let timeout = config.timeout_for(file_path);
 
         let estimated_task_duration_ms = if config.profiling.enabled {
             let warmup_start = std::time::Instant::now();
-            let warmup_result = adapter.extract(file_path, config.timeout).await?;
+            let warmup_result = adapter.extract(file_path, timeout).await?;
             let _warmup_duration = warmup_start.elapsed();
             warmup_result.duration.as_millis() as u64
         } else {
@@ -294,7 +299,7 @@ impl BenchmarkRunner {
             crate::config::ProfilingConfig::calculate_optimal_frequency(estimated_task_duration_ms);
 
         #[cfg(feature = "profiling")]
-        let profiler = if should_profile() && config.profiling.enabled {
+        let profiler = if config.profiling.enabled {
             match ProfileGuard::new(sampling_frequency) {
                 Ok(g) => {
                     eprintln!(
@@ -314,7 +319,7 @@ impl BenchmarkRunner {
 
         let warmup_start = if config.profiling.enabled { 1 } else { 0 };
         for _iteration in warmup_start..config.warmup_iterations {
-            let result = adapter.extract(file_path, config.timeout).await?;
+            let result = adapter.extract(file_path, timeout).await?;
             drop(result);
         }
 
@@ -326,7 +331,7 @@ impl BenchmarkRunner {
 
         for _iteration in 0..config.benchmark_iterations {
             for _amp in 0..amplification_factor {
-                let result = adapter.extract(file_path, config.timeout).await?;
+                let result = adapter.extract(file_path, timeout).await?;
                 all_results.push(result);
             }
         }
@@ -347,11 +352,13 @@ impl BenchmarkRunner {
                 "unknown_file"
             });
 
-            let flamegraph_path = format!("flamegraphs/{}/{}/{}.svg", framework_name, mode_name, fixture_stem);
-            let report_path = format!(
-                "flamegraphs/{}/{}/{}_report.html",
-                framework_name, mode_name, fixture_stem
-            );
+            let profile_dir = config
+                .output_dir
+                .join(crate::profile_report::FLAMEGRAPH_DIR)
+                .join(framework_name)
+                .join(mode_name);
+            let flamegraph_path = profile_dir.join(format!("{}.svg", fixture_stem));
+            let report_path = profile_dir.join(format!("{}_report.html", fixture_stem));
 
             match profiler.finish() {
                 Ok(result) => {
@@ -368,24 +375,22 @@ impl BenchmarkRunner {
                     }
 
                     if config.profiling.flamegraph_enabled {
-                        let path = Path::new(&flamegraph_path);
-                        if let Err(e) = result.generate_flamegraph(path) {
+                        if let Err(e) = result.generate_flamegraph(&flamegraph_path) {
                             eprintln!("Warning: Failed to generate flamegraph: {}", e);
                         }
 
                         let profile_report = ProfileReport::from_profiling_result(&result, framework_name);
                         let html_report = profile_report.generate_html();
 
-                        let report_file_path = Path::new(&report_path);
-                        if let Some(parent) = report_file_path.parent()
+                        if let Some(parent) = report_path.parent()
                             && !parent.as_os_str().is_empty()
                         {
                             if let Err(e) = std::fs::create_dir_all(parent) {
                                 eprintln!("Warning: Failed to create report directory: {}", e);
-                            } else if let Err(e) = std::fs::write(report_file_path, html_report) {
+                            } else if let Err(e) = std::fs::write(&report_path, html_report) {
                                 eprintln!("Warning: Failed to write HTML report: {}", e);
                             } else {
-                                eprintln!("Profile report written to: {}", report_path);
+                                eprintln!("Profile report written to: {}", report_path.display());
                             }
                         }
                     }
@@ -448,6 +453,7 @@ impl BenchmarkRunner {
             success: true,
             error_message: None,
             error_kind: first_result.error_kind,
+            timed_out: all_results.iter().any(|r| r.timed_out),
             duration: statistics.mean,
             extraction_duration: avg_extraction_duration,
             subprocess_overhead,
@@ -483,9 +489,16 @@ impl BenchmarkRunner {
         let total_iterations = config.warmup_iterations + config.benchmark_iterations;
         let mut all_batch_results = Vec::new();
 
+        // A batch shares one deadline, so it must accommodate its slowest file type
+        let timeout = file_paths
+            .iter()
+            .map(|path| config.timeout_for(path))
+            .max()
+            .unwrap_or(config.timeout);
+
         for iteration in 0..total_iterations {
             let refs: Vec<&std::path::Path> = file_paths.iter().map(|p| p.as_path()).collect();
-            let batch_results = adapter.extract_batch(&refs, config.timeout).await?;
+            let batch_results = adapter.extract_batch(&refs, timeout).await?;
 
             if iteration >= config.warmup_iterations {
                 all_batch_results.push(batch_results);
@@ -563,6 +576,7 @@ impl BenchmarkRunner {
                 success: true,
                 error_message: None,
                 error_kind: first_result.error_kind,
+                timed_out: file_iterations.iter().any(|r| r.timed_out),
                 duration: statistics.mean,
                 extraction_duration: avg_extraction_duration,
                 subprocess_overhead,
@@ -645,12 +659,15 @@ impl BenchmarkRunner {
             let warmup_file = fixture.resolve_document_path(fixture_dir);
 
             for adapter in &frameworks {
-                if !adapter.supports_format(&fixture.file_type) {
+                if !adapter.supports_format(&fixture.file_type) || !adapter.supports_document(&warmup_file) {
                     continue;
                 }
 
                 println!("Warming up {} with {}...", adapter.name(), warmup_file.display());
-                match adapter.warmup(&warmup_file, self.config.timeout).await {
+                match adapter
+                    .warmup(&warmup_file, self.config.timeout_for(&warmup_file))
+                    .await
+                {
                     Ok(cold_start) => {
                         println!("  Cold start: {:?}", cold_start);
                         self.cold_start_durations.insert(adapter.name().to_string(), cold_start);
@@ -662,7 +679,41 @@ impl BenchmarkRunner {
             }
         }
 
-        let mut results = Vec::new();
+        // Build mapping from document path -> ground truth text for quality scoring
+        let mut ground_truth_map: HashMap<PathBuf, String> = HashMap::new();
+        if self.config.measure_quality {
+            for (fixture_path, fixture) in self.fixtures.fixtures() {
+                let fixture_dir = fixture_path.parent().unwrap_or_else(|| std::path::Path::new("."));
+                if let Some(gt_path) = fixture.resolve_ground_truth_path(fixture_dir)
+                    && gt_path.exists()
+                    && let Ok(gt_text) = std::fs::read_to_string(&gt_path)
+                {
+                    let doc_path = fixture.resolve_document_path(fixture_dir);
+                    ground_truth_map.insert(doc_path, gt_text);
+                }
+            }
+        }
+
+        let completed_results = self.load_completed_results()?;
+        let completed: HashSet<(String, PathBuf)> = completed_results
+            .iter()
+            .map(|result| (result.framework.clone(), result.file_path.clone()))
+            .collect();
+
+        let mut stream = match &self.config.stream_results_path {
+            Some(path) => {
+                println!("Streaming results to {}", path.display());
+                let mut writer = JsonlWriter::create(path)?;
+                // Rewriting only the completed results drops failed entries, which are run again below
+                for result in &completed_results {
+                    writer.write(result)?;
+                }
+                Some(writer)
+            }
+            None => None,
+        };
+
+        let mut results = completed_results;
 
         let use_batch = matches!(self.config.benchmark_mode, BenchmarkMode::Batch);
 
@@ -683,6 +734,11 @@ impl BenchmarkRunner {
 
                     let fixture_dir = fixture_path.parent().unwrap_or_else(|| std::path::Path::new("."));
                     let document_path = fixture.resolve_document_path(fixture_dir);
+                    if !adapter.supports_document(&document_path)
+                        || completed.contains(&(adapter.name().to_string(), document_path.clone()))
+                    {
+                        continue;
+                    }
 
                     adapter_files
                         .entry(adapter.name().to_string())
@@ -701,6 +757,8 @@ impl BenchmarkRunner {
                         continue;
                     }
 
+                    self.reset_caches_if_configured(adapter).await;
+
                     if adapter.supports_batch() {
                         let adapter = Arc::clone(adapter);
                         let file_paths = file_paths.clone();
@@ -708,12 +766,11 @@ impl BenchmarkRunner {
                         let cold_start = self.cold_start_durations.get(adapter_name).copied();
 
                         match Self::run_batch_iterations_static(file_paths, adapter, &config, cold_start).await {
-                            Ok(mut batch_results) => {
-                                // Enrich each result with framework size information
-                                for result in &mut batch_results {
-                                    self.enrich_with_framework_size(result);
+                            Ok(batch_results) => {
+                                for mut result in batch_results {
+                                    self.complete_result(&mut result, &ground_truth_map, stream.as_mut())?;
+                                    results.push(result);
                                 }
-                                results.extend(batch_results);
                             }
                             Err(e) => {
                                 eprintln!("Batch benchmark task failed for {}: {}", adapter_name, e);
@@ -728,7 +785,7 @@ impl BenchmarkRunner {
 
                             match Self::run_iterations_static(&file_path, adapter, &config, cold_start).await {
                                 Ok(mut result) => {
-                                    self.enrich_with_framework_size(&mut result);
+                                    self.complete_result(&mut result, &ground_truth_map, stream.as_mut())?;
                                     results.push(result);
                                 }
                                 Err(e) => {
@@ -754,6 +811,11 @@ impl BenchmarkRunner {
 
                     let fixture_dir = fixture_path.parent().unwrap_or_else(|| std::path::Path::new("."));
                     let document_path = fixture.resolve_document_path(fixture_dir);
+                    if !adapter.supports_document(&document_path)
+                        || completed.contains(&(adapter.name().to_string(), document_path.clone()))
+                    {
+                        continue;
+                    }
 
                     task_queue.push((document_path, adapter.name().to_string(), Arc::clone(adapter)));
                 }
@@ -761,11 +823,17 @@ impl BenchmarkRunner {
 
             let config = self.config.clone();
 
+            let mut previous_framework: Option<String> = None;
             for (file_path, framework_name, adapter) in task_queue {
+                if previous_framework.as_deref() != Some(framework_name.as_str()) {
+                    self.reset_caches_if_configured(&adapter).await;
+                    previous_framework = Some(framework_name.clone());
+                }
+
                 let cold_start = self.cold_start_durations.get(&framework_name).copied();
                 match Self::run_iterations_static(&file_path, adapter, &config, cold_start).await {
                     Ok(mut result) => {
-                        self.enrich_with_framework_size(&mut result);
+                        self.complete_result(&mut result, &ground_truth_map, stream.as_mut())?;
                         results.push(result);
                     }
                     Err(e) => {
@@ -775,30 +843,6 @@ impl BenchmarkRunner {
             }
         }
 
-        // Apply quality scoring if enabled
-        if self.config.measure_quality {
-            // Build mapping from document path -> ground truth text
-            let mut ground_truth_map: HashMap<PathBuf, String> = HashMap::new();
-            for (fixture_path, fixture) in self.fixtures.fixtures() {
-                let fixture_dir = fixture_path.parent().unwrap_or_else(|| std::path::Path::new("."));
-                if let Some(gt_path) = fixture.resolve_ground_truth_path(fixture_dir)
-                    && gt_path.exists()
-                    && let Ok(gt_text) = std::fs::read_to_string(&gt_path)
-                {
-                    let doc_path = fixture.resolve_document_path(fixture_dir);
-                    ground_truth_map.insert(doc_path, gt_text);
-                }
-            }
-
-            for result in &mut results {
-                if let Some(ref extracted) = result.extracted_text
-                    && let Some(gt_text) = ground_truth_map.get(&result.file_path)
-                {
-                    result.quality = Some(crate::quality::compute_quality(extracted, gt_text));
-                }
-            }
-        }
-
         for adapter in &frameworks {
             adapter.teardown().await?;
         }
@@ -806,6 +850,69 @@ impl BenchmarkRunner {
         Ok(results)
     }
 
+    /// Load the successful results of an interrupted run when resuming
+    ///
+    /// Returns no results unless `resume` is set and the stream file exists. Failed
+    /// entries are left out so their framework/document pairs are run again.
+    fn load_completed_results(&self) -> Result<Vec<BenchmarkResult>> {
+        let Some(path) = self.config.stream_results_path.as_ref().filter(|_| self.config.resume) else {
+            return Ok(Vec::new());
+        };
+        if !path.is_file() {
+            return Ok(Vec::new());
+        }
+
+        let previous = crate::output::read_jsonl(path)?;
+        let previous_count = previous.len();
+        let mut seen = HashSet::new();
+        let completed: Vec<BenchmarkResult> = previous
+            .into_iter()
+            .filter(|result| result.success && seen.insert((result.framework.clone(), result.file_path.clone())))
+            .collect();
+
+        println!(
+            "Resuming from {}: {} completed, {} to retry",
+            path.display(),
+            completed.len(),
+            previous_count - completed.len()
+        );
+
+        Ok(completed)
+    }
+
+    /// Enrich a finished result, score its quality and stream it if configured
+    fn complete_result(
+        &self,
+        result: &mut BenchmarkResult,
+        ground_truth: &HashMap<PathBuf, String>,
+        stream: Option<&mut JsonlWriter>,
+    ) -> Result<()> {
+        self.enrich_with_framework_size(result);
+
+        if let Some(ref extracted) = result.extracted_text
+            && let Some(gt_text) = ground_truth.get(&result.file_path)
+        {
+            result.quality = Some(crate::quality::compute_quality(extracted, gt_text));
+        }
+
+        if let Some(stream) = stream {
+            stream.write(result)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reset an adapter's process-level caches when `reset_caches_between_frameworks` is set
+    async fn reset_caches_if_configured(&self, adapter: &Arc<dyn FrameworkAdapter>) {
+        if !self.config.reset_caches_between_frameworks {
+            return;
+        }
+
+        if let Err(e) = adapter.reset_caches().await {
+            eprintln!("Warning: Failed to reset caches for {}: {}", adapter.name(), e);
+        }
+    }
+
     /// Get reference to benchmark configuration
     pub fn config(&self) -> &BenchmarkConfig {
         &self.config
@@ -827,6 +934,35 @@ mod tests {
         assert_eq!(runner.fixture_count(), 0);
     }
 
+    #[tokio::test]
+    async fn test_reset_caches_between_frameworks() {
+        let adapter: Arc<dyn FrameworkAdapter> = Arc::new(NativeAdapter::new());
+        let mime_type = "application/x-benchmark-reset-test";
+        let before = kreuzberg::utils::intern_mime_type(mime_type);
+
+        let runner = BenchmarkRunner::new(BenchmarkConfig::default(), AdapterRegistry::new());
+        runner.reset_caches_if_configured(&adapter).await;
+        let unchanged = kreuzberg::utils::intern_mime_type(mime_type);
+        assert_eq!(
+            before.as_str().as_ptr(),
+            unchanged.as_str().as_ptr(),
+            "caches must be kept when the flag is not set"
+        );
+
+        let config = BenchmarkConfig {
+            reset_caches_between_frameworks: true,
+            ..Default::default()
+        };
+        let runner = BenchmarkRunner::new(config, AdapterRegistry::new());
+        runner.reset_caches_if_configured(&adapter).await;
+        let after = kreuzberg::utils::intern_mime_type(mime_type);
+        assert_ne!(
+            before.as_str().as_ptr(),
+            after.as_str().as_ptr(),
+            "interned strings must not survive a cache reset"
+        );
+    }
+
     #[tokio::test]
     async fn test_run_with_no_frameworks() {
         let config = BenchmarkConfig::default();
@@ -850,6 +986,212 @@ mod tests {
         assert_eq!(results.len(), 0);
     }
 
+    /// Adapter that sleeps longer for files named `slow.*`, honouring the timeout it is given
+    struct SleepAdapter {
+        timeouts: std::sync::Mutex<Vec<(PathBuf, Duration)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl FrameworkAdapter for SleepAdapter {
+        fn name(&self) -> &str {
+            "sleep"
+        }
+
+        fn supports_format(&self, _file_type: &str) -> bool {
+            true
+        }
+
+        async fn extract(&self, file_path: &Path, timeout: Duration) -> Result<BenchmarkResult> {
+            use crate::types::{FrameworkCapabilities, OcrStatus};
+
+            self.timeouts.lock().unwrap().push((file_path.to_path_buf(), timeout));
+            let delay = if file_path.file_stem().is_some_and(|stem| stem == "slow") {
+                Duration::from_millis(300)
+            } else {
+                Duration::from_millis(10)
+            };
+            let timed_out = tokio::time::timeout(timeout, tokio::time::sleep(delay)).await.is_err();
+
+            Ok(BenchmarkResult {
+                framework: self.name().to_string(),
+                file_path: file_path.to_path_buf(),
+                file_size: 0,
+                success: !timed_out,
+                error_message: timed_out.then(|| format!("Extraction exceeded {:?}", timeout)),
+                error_kind: if timed_out {
+                    ErrorKind::HarnessError
+                } else {
+                    ErrorKind::None
+                },
+                timed_out,
+                duration: delay.min(timeout),
+                extraction_duration: None,
+                subprocess_overhead: None,
+                metrics: PerformanceMetrics::default(),
+                quality: None,
+                iterations: vec![],
+                statistics: None,
+                cold_start_duration: None,
+                file_extension: file_path
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .unwrap_or("unknown")
+                    .to_lowercase(),
+                framework_capabilities: FrameworkCapabilities::default(),
+                pdf_metadata: None,
+                ocr_status: OcrStatus::Unknown,
+                extracted_text: None,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_per_extension_timeout_overrides() {
+        let config = BenchmarkConfig {
+            timeout: Duration::from_secs(60),
+            timeout_by_extension: HashMap::from([
+                ("pdf".to_string(), Duration::from_secs(5)),
+                ("txt".to_string(), Duration::from_millis(100)),
+            ]),
+            warmup_iterations: 0,
+            benchmark_iterations: 1,
+            ..Default::default()
+        };
+        config.validate().unwrap();
+
+        let sleep_adapter = Arc::new(SleepAdapter {
+            timeouts: std::sync::Mutex::new(Vec::new()),
+        });
+        let adapter: Arc<dyn FrameworkAdapter> = sleep_adapter.clone();
+
+        let fast_txt = BenchmarkRunner::run_iterations_static(Path::new("fast.txt"), adapter.clone(), &config, None)
+            .await
+            .unwrap();
+        assert!(fast_txt.success);
+        assert!(!fast_txt.timed_out);
+
+        let slow_pdf = BenchmarkRunner::run_iterations_static(Path::new("slow.PDF"), adapter.clone(), &config, None)
+            .await
+            .unwrap();
+        assert!(slow_pdf.success, "slow PDF should fit within its 5s override");
+        assert!(!slow_pdf.timed_out);
+
+        let slow_txt = BenchmarkRunner::run_iterations_static(Path::new("slow.txt"), adapter.clone(), &config, None)
+            .await
+            .unwrap();
+        assert!(!slow_txt.success);
+        assert!(slow_txt.timed_out, "slow text file should exceed its 100ms override");
+
+        let other = BenchmarkRunner::run_iterations_static(Path::new("fast.docx"), adapter, &config, None)
+            .await
+            .unwrap();
+        assert!(other.success);
+
+        let timeouts = sleep_adapter.timeouts.lock().unwrap().clone();
+        assert_eq!(
+            timeouts,
+            vec![
+                (PathBuf::from("fast.txt"), Duration::from_millis(100)),
+                (PathBuf::from("slow.PDF"), Duration::from_secs(5)),
+                (PathBuf::from("slow.txt"), Duration::from_millis(100)),
+                (PathBuf::from("fast.docx"), Duration::from_secs(60)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resume_skips_completed_pairs() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let stream_path = temp_dir.path().join("results.jsonl");
+        let documents: Vec<PathBuf> = ["a", "b", "c"]
+            .iter()
+            .map(|name| {
+                let document = temp_dir.path().join(format!("{}.txt", name));
+                std::fs::write(&document, "text").unwrap();
+                std::fs::write(
+                    temp_dir.path().join(format!("{}.json", name)),
+                    format!(r#"{{"document": "{}.txt", "file_type": "txt", "file_size": 4}}"#, name),
+                )
+                .unwrap();
+                document
+            })
+            .collect();
+
+        let sleep_adapter = Arc::new(SleepAdapter {
+            timeouts: std::sync::Mutex::new(Vec::new()),
+        });
+
+        // One pair completed and one failed before the previous run was interrupted
+        let completed = sleep_adapter
+            .extract(&documents[0], Duration::from_secs(1))
+            .await
+            .unwrap();
+        let failed = BenchmarkResult {
+            success: false,
+            error_message: Some("crashed".to_string()),
+            error_kind: ErrorKind::HarnessError,
+            ..sleep_adapter
+                .extract(&documents[1], Duration::from_secs(1))
+                .await
+                .unwrap()
+        };
+        crate::output::write_jsonl([&completed, &failed], &stream_path).unwrap();
+        sleep_adapter.timeouts.lock().unwrap().clear();
+
+        let config = BenchmarkConfig {
+            max_concurrent: 1,
+            benchmark_mode: BenchmarkMode::SingleFile,
+            warmup_iterations: 0,
+            benchmark_iterations: 1,
+            stream_results_path: Some(stream_path.clone()),
+            resume: true,
+            ..Default::default()
+        };
+        config.validate().unwrap();
+
+        let mut registry = AdapterRegistry::new();
+        registry.register(sleep_adapter.clone()).unwrap();
+        let mut runner = BenchmarkRunner::new(config, registry);
+        for name in ["a", "b", "c"] {
+            runner
+                .load_fixtures(&temp_dir.path().join(format!("{}.json", name)))
+                .unwrap();
+        }
+
+        let results = runner.run(&[]).await.unwrap();
+
+        // The first extraction is the adapter warmup, which always runs
+        let extracted: Vec<PathBuf> = sleep_adapter
+            .timeouts
+            .lock()
+            .unwrap()
+            .iter()
+            .skip(1)
+            .map(|(path, _)| path.clone())
+            .collect();
+        assert_eq!(extracted, vec![documents[1].clone(), documents[2].clone()]);
+
+        let result_paths: Vec<&PathBuf> = results.iter().map(|result| &result.file_path).collect();
+        assert_eq!(result_paths, vec![&documents[0], &documents[1], &documents[2]]);
+        assert!(results.iter().all(|result| result.success));
+
+        let streamed = crate::output::read_jsonl(&stream_path).unwrap();
+        assert_eq!(streamed.len(), 3);
+        assert!(streamed.iter().all(|result| result.success));
+    }
+
+    #[test]
+    fn test_parse_timeout_overrides() {
+        let overrides = crate::config::parse_timeout_overrides(&["pdf=300", " .TXT = 10 "]).unwrap();
+        assert_eq!(overrides.get("pdf"), Some(&Duration::from_secs(300)));
+        assert_eq!(overrides.get("txt"), Some(&Duration::from_secs(10)));
+
+        assert!(crate::config::parse_timeout_overrides(&["pdf"]).is_err());
+        assert!(crate::config::parse_timeout_overrides(&["=10"]).is_err());
+        assert!(crate::config::parse_timeout_overrides(&["pdf=abc"]).is_err());
+        assert!(crate::config::parse_timeout_overrides(&["pdf=0"]).is_err());
+    }
+
     #[test]
     fn test_calculate_amplified_iterations() {
         assert_eq!(calculate_amplified_iterations(100, 1000), 10);
@@ -918,6 +1260,7 @@ mod tests {
             success: true,
             error_message: None,
             error_kind: ErrorKind::None,
+            timed_out: false,
             duration: Duration::from_millis(100),
             extraction_duration: None,
             subprocess_overhead: None,
@@ -941,6 +1284,7 @@ mod tests {
             success: true,
             error_message: None,
             error_kind: ErrorKind::None,
+            timed_out: false,
             duration: Duration::from_millis(100),
             extraction_duration: None,
             subprocess_overhead: None,
@@ -964,6 +1308,7 @@ mod tests {
             success: true,
             error_message: None,
             error_kind: ErrorKind::None,
+            timed_out: false,
             duration: Duration::from_millis(100),
             extraction_duration: None,
             subprocess_overhead: None,
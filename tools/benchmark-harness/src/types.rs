@@ -58,6 +58,10 @@ pub struct BenchmarkResult {
     #[serde(default)]
     pub error_kind: ErrorKind,
 
+    /// Whether the extraction was aborted because it exceeded its timeout
+    #[serde(default)]
+    pub timed_out: bool,
+
     /// Total wall-clock duration (process spawn + extraction)
     /// For single iteration: the actual duration
     /// For multiple iterations: mean duration across all iterations
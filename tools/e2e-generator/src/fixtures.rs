@@ -487,6 +487,29 @@ pub fn load_fixtures(fixtures_dir: &Utf8Path) -> Result<Vec<Fixture>> {
     Ok(fixtures)
 }
 
+/// Keep only fixtures in `category`.
+///
+/// Errors if no loaded fixture has that category, listing the categories that exist.
+pub fn filter_by_category(fixtures: Vec<Fixture>, category: &str) -> Result<Vec<Fixture>> {
+    let available = fixtures
+        .iter()
+        .map(|fixture| fixture.category().to_string())
+        .unique()
+        .collect::<Vec<_>>();
+
+    if !available.iter().any(|name| name == category) {
+        bail!(
+            "No fixtures found in category '{category}'. Available categories: {}",
+            available.join(", ")
+        );
+    }
+
+    Ok(fixtures
+        .into_iter()
+        .filter(|fixture| fixture.category() == category)
+        .collect())
+}
+
 /// Determines whether a fixture should be included for a given WASM target.
 ///
 /// This function filters fixtures based on WASM target-specific constraints:
@@ -522,3 +545,59 @@ pub fn should_include_for_wasm(fixture: &Fixture, target: WasmTarget) -> bool {
 
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace_fixtures() -> Vec<Fixture> {
+        load_fixtures(&Utf8PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../fixtures")).unwrap()
+    }
+
+    #[test]
+    fn filter_by_category_rejects_unknown_category() {
+        let err = filter_by_category(workspace_fixtures(), "does_not_exist").unwrap_err();
+        assert!(err.to_string().contains("Available categories:"));
+        assert!(err.to_string().contains("pdf"));
+    }
+
+    #[test]
+    fn generate_with_category_filter_emits_only_matching_fixtures() {
+        let all = workspace_fixtures();
+        let pdf_ids: Vec<String> = all
+            .iter()
+            .filter(|f| f.category() == "pdf")
+            .map(|f| f.id.clone())
+            .collect();
+        let other_ids: Vec<String> = all
+            .iter()
+            .filter(|f| f.category() != "pdf")
+            .map(|f| f.id.clone())
+            .collect();
+
+        let fixtures = filter_by_category(all, "pdf").unwrap();
+        assert_eq!(fixtures.len(), pdf_ids.len());
+
+        let output = Utf8PathBuf::from_path_buf(std::env::temp_dir())
+            .unwrap()
+            .join(format!("e2e-category-filter-{}", std::process::id()));
+        crate::rust::generate(&fixtures, &output).unwrap();
+
+        let tests_dir = output.join("rust/tests");
+        let files: Vec<String> = std::fs::read_dir(&tests_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(files, vec!["pdf_test.rs".to_string()]);
+
+        let content = std::fs::read_to_string(tests_dir.join("pdf_test.rs")).unwrap();
+        for id in &pdf_ids {
+            assert!(content.contains(&format!("fn test_{id}(")), "missing {id}");
+        }
+        for id in &other_ids {
+            assert!(!content.contains(&format!("fn test_{id}(")), "unexpected {id}");
+        }
+
+        std::fs::remove_dir_all(&output).unwrap();
+    }
+}
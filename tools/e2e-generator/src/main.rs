@@ -3,6 +3,7 @@ mod elixir;
 mod fixtures;
 mod go;
 mod java;
+mod manifest;
 mod php;
 mod python;
 mod ruby;
@@ -14,7 +15,7 @@ mod wasm_workers;
 use anyhow::Result;
 use camino::{Utf8Path, Utf8PathBuf};
 use clap::{Parser, Subcommand, ValueEnum};
-use fixtures::load_fixtures;
+use fixtures::{filter_by_category, load_fixtures};
 
 #[derive(Parser)]
 #[command(author, version, about = "Generate language-specific E2E suites from fixtures")]
@@ -36,6 +37,19 @@ enum Commands {
         /// Output directory (defaults to workspace e2e/).
         #[arg(long, default_value = "e2e")]
         output: Utf8PathBuf,
+        /// Only emit fixtures in this category (e.g. `ocr`). Tests generated for other
+        /// categories are removed from the suite, so regenerate without it before committing.
+        #[arg(long, visible_alias = "only-category")]
+        category: Option<String>,
+    },
+    /// Write a JSON manifest of which language suites include each fixture.
+    Manifest {
+        /// Fixture directory (defaults to workspace fixtures/).
+        #[arg(long, default_value = "fixtures")]
+        fixtures: Utf8PathBuf,
+        /// Manifest output path (defaults to e2e/manifest.json).
+        #[arg(long, default_value = "e2e/manifest.json")]
+        output: Utf8PathBuf,
     },
     /// List fixtures (for quick inspection).
     List {
@@ -64,8 +78,16 @@ fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Generate { lang, fixtures, output } => {
-            let fixtures = load_fixtures(fixtures.as_path())?;
+        Commands::Generate {
+            lang,
+            fixtures,
+            output,
+            category,
+        } => {
+            let mut fixtures = load_fixtures(fixtures.as_path())?;
+            if let Some(category) = category {
+                fixtures = filter_by_category(fixtures, &category)?;
+            }
             match lang {
                 Language::Rust => {
                     rust::generate(&fixtures, output.as_path())?;
@@ -95,6 +117,11 @@ fn main() -> Result<()> {
                 }
             };
         }
+        Commands::Manifest { fixtures, output } => {
+            let fixtures = load_fixtures(fixtures.as_path())?;
+            manifest::write(&fixtures, output.as_path())?;
+            println!("Wrote manifest for {} fixtures to {output}", fixtures.len());
+        }
         Commands::List { fixtures } => {
             let fixtures = load_fixtures(fixtures.as_path())?;
             for fixture in fixtures {
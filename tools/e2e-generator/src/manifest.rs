@@ -0,0 +1,128 @@
+use crate::Language;
+use crate::fixtures::{Fixture, WasmTarget, should_include_for_wasm};
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fs;
+
+/// Cross-language coverage manifest: which generated suites include each fixture.
+#[derive(Debug, Serialize)]
+pub struct Manifest {
+    pub languages: Vec<String>,
+    pub fixtures: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ManifestEntry {
+    pub id: String,
+    pub category: String,
+    /// `document_extraction` or `plugin_api`
+    pub kind: &'static str,
+    pub source: String,
+    /// Language suites that include this fixture
+    pub languages: Vec<String>,
+    /// Language suites that do not include this fixture
+    pub missing_languages: Vec<String>,
+}
+
+/// Whether the generator for `lang` emits a test for `fixture`.
+///
+/// Mirrors the fixture selection in each language's `generate` function.
+pub fn language_includes(lang: Language, fixture: &Fixture) -> bool {
+    if fixture.is_plugin_api() {
+        return !matches!(lang, Language::Csharp);
+    }
+
+    match lang {
+        Language::WasmDeno => should_include_for_wasm(fixture, WasmTarget::Deno),
+        Language::WasmWorkers => should_include_for_wasm(fixture, WasmTarget::Workers),
+        _ => true,
+    }
+}
+
+fn language_name(lang: Language) -> String {
+    lang.to_possible_value()
+        .expect("language variants are not skipped")
+        .get_name()
+        .to_string()
+}
+
+pub fn build(fixtures: &[Fixture]) -> Manifest {
+    let languages = Language::value_variants();
+
+    let entries = fixtures
+        .iter()
+        .map(|fixture| {
+            let (included, missing): (Vec<Language>, Vec<Language>) = languages
+                .iter()
+                .copied()
+                .partition(|lang| language_includes(*lang, fixture));
+
+            ManifestEntry {
+                id: fixture.id.clone(),
+                category: fixture.category().to_string(),
+                kind: if fixture.is_plugin_api() {
+                    "plugin_api"
+                } else {
+                    "document_extraction"
+                },
+                source: fixture.source.to_string(),
+                languages: included.into_iter().map(language_name).collect(),
+                missing_languages: missing.into_iter().map(language_name).collect(),
+            }
+        })
+        .collect();
+
+    Manifest {
+        languages: languages.iter().copied().map(language_name).collect(),
+        fixtures: entries,
+    }
+}
+
+pub fn write(fixtures: &[Fixture], output: &Utf8Path) -> Result<()> {
+    if let Some(parent) = output.parent()
+        && !parent.as_str().is_empty()
+    {
+        fs::create_dir_all(parent).with_context(|| format!("Failed to create {parent}"))?;
+    }
+
+    let manifest = build(fixtures);
+    let json = serde_json::to_string_pretty(&manifest).context("Failed to serialize manifest")?;
+    fs::write(output, json + "\n").with_context(|| format!("Failed to write manifest {output}"))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::load_fixtures;
+    use camino::Utf8PathBuf;
+
+    #[test]
+    fn manifest_lists_every_fixture() {
+        let fixtures_dir = Utf8PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../fixtures");
+        let fixtures = load_fixtures(&fixtures_dir).unwrap();
+        assert!(fixtures.iter().any(Fixture::is_document_extraction));
+        assert!(fixtures.iter().any(Fixture::is_plugin_api));
+
+        let manifest = build(&fixtures);
+
+        let fixture_ids: Vec<&str> = fixtures.iter().map(|f| f.id.as_str()).collect();
+        let manifest_ids: Vec<&str> = manifest.fixtures.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(manifest_ids, fixture_ids);
+
+        for entry in &manifest.fixtures {
+            assert_eq!(
+                entry.languages.len() + entry.missing_languages.len(),
+                manifest.languages.len()
+            );
+            if entry.kind == "plugin_api" {
+                assert!(entry.missing_languages.contains(&"csharp".to_string()));
+            } else {
+                assert!(entry.languages.contains(&"rust".to_string()));
+            }
+        }
+    }
+}